@@ -50,6 +50,7 @@ function M.build_parser()
 		"-o", output,
 		"-I", src,
 		src .. "/parser.c",
+		src .. "/scanner.c",
 		"-O2",
 	}
 
@@ -0,0 +1,45 @@
+; Tree-sitter folding queries for GSX DSL
+; This file marks the nodes an editor can fold
+
+; ====================
+; Components & Elements
+; ====================
+
+(component_body) @fold
+(element_with_children) @fold
+
+; ====================
+; Control Flow
+; ====================
+
+(block) @fold
+(switch_statement) @fold
+(case_clause) @fold
+(default_clause) @fold
+
+; Plain Go statements are one token, so a multi-line `for` or `switch`
+; folds as a whole
+(go_statement) @fold
+
+; ====================
+; Go Declarations
+; ====================
+
+(struct_body) @fold
+(function_body) @fold
+(go_brace_body) @fold
+(go_paren_body) @fold
+
+; ====================
+; Imports
+; ====================
+
+(import_spec_list) @fold
+
+; ====================
+; Comments
+; ====================
+
+; Single-line comments can't fold on their own, so only block comments
+; spanning several lines do
+(comment) @fold
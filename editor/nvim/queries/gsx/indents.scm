@@ -0,0 +1,62 @@
+; Tree-sitter indentation queries for GSX DSL
+; This file marks the nodes that indent their children (@indent.begin) and
+; the closing delimiters that end them (@indent.end). A line starting with an
+; @indent.branch sits at the level of the node it closes, like `}` and
+; `</div>` in `tui fmt` output.
+
+; ====================
+; Components & Elements
+; ====================
+
+(component_body) @indent.begin
+(component_body "}" @indent.branch @indent.end)
+
+(element_with_children) @indent.begin
+(element_with_children "</" @indent.branch @indent.end)
+
+; ====================
+; Control Flow
+; ====================
+
+(block) @indent.begin
+(block "}" @indent.branch @indent.end)
+
+; Clauses line up with their switch, as in gofmt
+(case_clause) @indent.begin
+(default_clause) @indent.begin
+
+; ====================
+; Go Code
+; ====================
+
+(struct_body) @indent.begin
+(struct_body "}" @indent.branch @indent.end)
+
+(function_body) @indent.begin
+(function_body "}" @indent.branch @indent.end)
+
+(go_brace_body) @indent.begin
+(go_brace_body "}" @indent.branch @indent.end)
+
+(go_paren_body) @indent.begin
+(go_paren_body ")" @indent.branch @indent.end)
+
+(import_spec_list) @indent.begin
+(import_spec_list ")" @indent.branch @indent.end)
+
+; Braces inside opaque Go code, e.g. an `if` in a function body
+(nested_braces) @indent.begin
+(nested_braces "}" @indent.branch @indent.end)
+
+; ====================
+; Expressions
+; ====================
+
+(parameter_list) @indent.begin
+(parameter_list ")" @indent.branch @indent.end)
+
+(argument_list) @indent.begin
+(argument_list ")" @indent.branch @indent.end)
+
+(literal_value) @indent.begin
+(literal_value "}" @indent.branch @indent.end)
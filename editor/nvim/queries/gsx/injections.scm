@@ -12,6 +12,14 @@
   (#set! injection.language "go")
   (#set! injection.include-children))
 
+; ====================
+; Go Statement Injection
+; ====================
+
+; Inject Go language into plain Go statements in component bodies
+((go_statement) @injection.content
+  (#set! injection.language "go"))
+
 ; ====================
 ; Function Body Injection
 ; ====================
@@ -0,0 +1,534 @@
+//! ANSI terminal output for highlighted `.gsx` files.
+//!
+//! [`render`] highlights a buffer with [`HIGHLIGHTS_QUERY`], the Go code
+//! [`INJECTIONS_QUERY`] marks included, and writes it back out with SGR
+//! escape codes, colouring every span with the [`Style`] its capture name
+//! maps to in a [`Theme`]. A theme entry for `keyword` also covers
+//! `keyword.function` unless that has an entry of its own. Colours are
+//! written as 24-bit, 256 or 16 colour codes depending on the
+//! [`ColorMode`], and every line ends with a reset, so the output survives
+//! being cut into lines by a pager or diff tool.
+//!
+//! This module needs the `highlight` feature.
+//!
+//! ```
+//! use tree_sitter_gsx::ansi::{render, ColorMode, Theme};
+//!
+//! let theme = Theme::parse("@keyword = #ff0000 bold").unwrap();
+//! let out = render("templ Hello() {}\n", &theme, ColorMode::TrueColor);
+//! assert!(out.starts_with("\x1b[1;38;2;255;0;0mtempl\x1b[0m"));
+//! ```
+//!
+//! [`HIGHLIGHTS_QUERY`]: crate::HIGHLIGHTS_QUERY
+//! [`INJECTIONS_QUERY`]: crate::INJECTIONS_QUERY
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::highlight::{highlight, Event, HIGHLIGHT_NAMES};
+
+/// A 24-bit colour.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl FromStr for Rgb {
+    type Err = ();
+
+    /// Parses `#rrggbb` or `#rgb`.
+    fn from_str(s: &str) -> Result<Self, ()> {
+        let hex = s.strip_prefix('#').ok_or(())?;
+        if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(());
+        }
+        let digit = |i: usize| u8::from_str_radix(&hex[i..i + 1], 16).unwrap();
+        let pair = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).unwrap();
+        match hex.len() {
+            3 => Ok(Rgb(digit(0) * 17, digit(1) * 17, digit(2) * 17)),
+            6 => Ok(Rgb(pair(0), pair(2), pair(4))),
+            _ => Err(()),
+        }
+    }
+}
+
+/// How a [`Style`] is drawn: its colours and text attributes. The default
+/// style draws plain text.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Style {
+    pub foreground: Option<Rgb>,
+    pub background: Option<Rgb>,
+    pub bold: bool,
+    pub dim: bool,
+    pub italic: bool,
+    pub underline: bool,
+}
+
+impl Style {
+    /// A style with only a foreground colour.
+    pub const fn fg(color: Rgb) -> Self {
+        Style {
+            foreground: Some(color),
+            background: None,
+            bold: false,
+            dim: false,
+            italic: false,
+            underline: false,
+        }
+    }
+
+    /// Returns the SGR escape that switches to this style from plain text,
+    /// or an empty string for the default style.
+    pub fn escape(&self, mode: ColorMode) -> String {
+        let mut codes = Vec::new();
+        for (on, code) in [
+            (self.bold, "1"),
+            (self.dim, "2"),
+            (self.italic, "3"),
+            (self.underline, "4"),
+        ] {
+            if on {
+                codes.push(code.to_string());
+            }
+        }
+        if let Some(color) = self.foreground {
+            codes.push(mode.code(color, false));
+        }
+        if let Some(color) = self.background {
+            codes.push(mode.code(color, true));
+        }
+        match codes.is_empty() {
+            true => String::new(),
+            false => format!("\x1b[{}m", codes.join(";")),
+        }
+    }
+}
+
+impl FromStr for Style {
+    type Err = String;
+
+    /// Parses whitespace-separated words: a `#rrggbb` foreground, `on
+    /// #rrggbb` for a background, and any of `bold`, `dim`, `italic` and
+    /// `underline`. `none` on its own is the plain style.
+    fn from_str(s: &str) -> Result<Self, String> {
+        let mut style = Style::default();
+        let mut words = s.split_whitespace();
+        if s.trim() == "none" {
+            return Ok(style);
+        }
+        while let Some(word) = words.next() {
+            match word {
+                "bold" => style.bold = true,
+                "dim" => style.dim = true,
+                "italic" => style.italic = true,
+                "underline" => style.underline = true,
+                "on" => {
+                    let color = words.next().ok_or("expected a colour after `on`")?;
+                    style.background = Some(color.parse().map_err(|()| bad_color(color))?);
+                }
+                _ if word.starts_with('#') => {
+                    style.foreground = Some(word.parse().map_err(|()| bad_color(word))?);
+                }
+                _ => return Err(format!("unknown style `{word}`")),
+            }
+        }
+        Ok(style)
+    }
+}
+
+fn bad_color(color: &str) -> String {
+    format!("invalid colour `{color}`, expected #rrggbb")
+}
+
+/// The colours a terminal can show.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ColorMode {
+    /// 24-bit colour, written as is.
+    #[default]
+    TrueColor,
+    /// The xterm 256 colour palette.
+    Ansi256,
+    /// The 16 basic colours.
+    Ansi16,
+}
+
+impl ColorMode {
+    /// Returns the SGR parameters that set `color` as the foreground, or
+    /// the background when `background` is true.
+    fn code(self, color: Rgb, background: bool) -> String {
+        let Rgb(r, g, b) = color;
+        let base: u8 = if background { 40 } else { 30 };
+        match self {
+            ColorMode::TrueColor => format!("{};2;{r};{g};{b}", base + 8),
+            ColorMode::Ansi256 => format!("{};5;{}", base + 8, ansi256(color)),
+            ColorMode::Ansi16 => match ansi16(color) {
+                i @ 0..=7 => (base + i).to_string(),
+                i => (base + 60 + i - 8).to_string(),
+            },
+        }
+    }
+}
+
+impl FromStr for ColorMode {
+    type Err = String;
+
+    /// Parses `truecolor` (or `24bit`), `256` or `16`.
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "truecolor" | "24bit" => Ok(ColorMode::TrueColor),
+            "256" => Ok(ColorMode::Ansi256),
+            "16" => Ok(ColorMode::Ansi16),
+            _ => Err(format!(
+                "unknown colour mode `{s}`, expected truecolor, 256 or 16"
+            )),
+        }
+    }
+}
+
+/// The channel levels of the 6x6x6 cube in the 256 colour palette.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+fn distance(a: Rgb, b: Rgb) -> u32 {
+    let d = |x: u8, y: u8| (i32::from(x) - i32::from(y)).unsigned_abs().pow(2);
+    d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
+}
+
+/// Returns the 256 colour palette index closest to `color`: the nearest
+/// entry of the colour cube or of the grey ramp.
+fn ansi256(color: Rgb) -> u8 {
+    let level = |c: u8| {
+        (0..6)
+            .min_by_key(|&i| (i32::from(CUBE_LEVELS[i]) - i32::from(c)).abs())
+            .unwrap()
+    };
+    let (r, g, b) = (level(color.0), level(color.1), level(color.2));
+    let cube = Rgb(CUBE_LEVELS[r], CUBE_LEVELS[g], CUBE_LEVELS[b]);
+    let average = (u32::from(color.0) + u32::from(color.1) + u32::from(color.2)) / 3;
+    let step = (average.saturating_sub(3) / 10).min(23) as u8;
+    let grey = 8 + 10 * step;
+    if distance(color, Rgb(grey, grey, grey)) < distance(color, cube) {
+        232 + step
+    } else {
+        16 + 36 * r as u8 + 6 * g as u8 + b as u8
+    }
+}
+
+/// Returns the index of the basic colour for `color`. Greys map to black,
+/// white or their bright variants by lightness. Other colours turn on the
+/// channels above the midpoint of their darkest and brightest channel, so
+/// muted colours keep their hue, and light ones pick the bright variant.
+fn ansi16(color: Rgb) -> u8 {
+    let Rgb(r, g, b) = color;
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    if max - min < 32 {
+        let lightness = (u16::from(r) + u16::from(g) + u16::from(b)) / 3;
+        return match lightness {
+            0..=63 => 0,
+            64..=159 => 8,
+            160..=223 => 7,
+            _ => 15,
+        };
+    }
+    let mid = (u16::from(max) + u16::from(min)) / 2;
+    let on = |c: u8, bit: u8| u8::from(u16::from(c) > mid) << bit;
+    let index = on(r, 0) | on(g, 1) | on(b, 2);
+    if max >= 192 {
+        index + 8
+    } else {
+        index
+    }
+}
+
+/// An error in a theme, with the 1-based line it is on.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThemeError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ThemeError {}
+
+/// Maps capture names to styles. Names may be written with or without the
+/// leading `@` of the query files.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Theme {
+    styles: Vec<(String, Style)>,
+}
+
+impl Theme {
+    /// An empty theme, which leaves everything plain.
+    pub fn new() -> Self {
+        Theme::default()
+    }
+
+    /// The theme `gsx-highlight` uses unless told otherwise, a dark
+    /// palette in the style of One Dark.
+    pub fn dark() -> Self {
+        let mut theme = Theme::new();
+        for (name, style) in [
+            (
+                "comment",
+                Style {
+                    italic: true,
+                    ..Style::fg(Rgb(0x7f, 0x84, 0x8e))
+                },
+            ),
+            ("keyword", Style::fg(Rgb(0xc6, 0x78, 0xdd))),
+            ("operator", Style::fg(Rgb(0x56, 0xb6, 0xc2))),
+            ("string", Style::fg(Rgb(0x98, 0xc3, 0x79))),
+            ("string.special", Style::fg(Rgb(0x56, 0xb6, 0xc2))),
+            ("escape", Style::fg(Rgb(0x56, 0xb6, 0xc2))),
+            ("character", Style::fg(Rgb(0x98, 0xc3, 0x79))),
+            ("number", Style::fg(Rgb(0xd1, 0x9a, 0x66))),
+            ("boolean", Style::fg(Rgb(0xd1, 0x9a, 0x66))),
+            ("constant.builtin", Style::fg(Rgb(0xd1, 0x9a, 0x66))),
+            ("function", Style::fg(Rgb(0x61, 0xaf, 0xef))),
+            ("function.builtin", Style::fg(Rgb(0x56, 0xb6, 0xc2))),
+            ("type", Style::fg(Rgb(0xe5, 0xc0, 0x7b))),
+            ("module", Style::fg(Rgb(0xe5, 0xc0, 0x7b))),
+            ("tag", Style::fg(Rgb(0xe0, 0x6c, 0x75))),
+            ("tag.delimiter", Style::fg(Rgb(0xab, 0xb2, 0xbf))),
+            ("property", Style::fg(Rgb(0xd1, 0x9a, 0x66))),
+            ("variable.parameter", Style::fg(Rgb(0xe0, 0x6c, 0x75))),
+            ("punctuation.special", Style::fg(Rgb(0xc6, 0x78, 0xdd))),
+        ] {
+            theme.set(name, style);
+        }
+        theme
+    }
+
+    /// Parses a theme with one `capture = style` entry per line, such as
+    /// `@keyword.function = #c678dd bold`. See [`Style`]'s `FromStr` for
+    /// the style words. Blank lines and lines starting with `#` or `//`
+    /// are skipped.
+    pub fn parse(text: &str) -> Result<Self, ThemeError> {
+        let mut theme = Theme::new();
+        theme.extend_from(text)?;
+        Ok(theme)
+    }
+
+    /// Parses `text` like [`parse`](Theme::parse) and adds its entries to
+    /// this theme, replacing the styles of names it already has.
+    pub fn extend_from(&mut self, text: &str) -> Result<(), ThemeError> {
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with("//") {
+                continue;
+            }
+            let error = |message: String| ThemeError {
+                line: i + 1,
+                message,
+            };
+            let (name, style) = line
+                .split_once('=')
+                .ok_or_else(|| error("expected `capture = style`".to_string()))?;
+            let name = name.trim();
+            if name.trim_start_matches('@').is_empty() {
+                return Err(error("missing capture name".to_string()));
+            }
+            self.set(name, style.parse().map_err(error)?);
+        }
+        Ok(())
+    }
+
+    /// Sets the style for `name` and the capture names under it.
+    pub fn set(&mut self, name: &str, style: Style) {
+        let name = name.trim_start_matches('@');
+        match self.styles.iter_mut().find(|(n, _)| n == name) {
+            Some(entry) => entry.1 = style,
+            None => self.styles.push((name.to_string(), style)),
+        }
+    }
+
+    /// Returns the style of the longest name in the theme that is a
+    /// dot-separated prefix of `capture`.
+    pub fn style(&self, capture: &str) -> Option<Style> {
+        let capture = capture.trim_start_matches('@');
+        self.styles
+            .iter()
+            .filter(|(name, _)| {
+                capture
+                    .strip_prefix(name.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+            })
+            .max_by_key(|(name, _)| name.len())
+            .map(|&(_, style)| style)
+    }
+}
+
+/// Highlights a `.gsx` buffer and renders it with `theme`.
+pub fn render(source: &str, theme: &Theme, mode: ColorMode) -> String {
+    render_events(source, &highlight(source), HIGHLIGHT_NAMES, theme, mode)
+}
+
+/// Renders `events` over `source`, where each highlight indexes `names`.
+/// A span takes the style of its innermost highlight the theme has one
+/// for; spans without any are left plain.
+fn render_events(
+    source: &str,
+    events: &[Event],
+    names: &[&str],
+    theme: &Theme,
+    mode: ColorMode,
+) -> String {
+    let escapes: Vec<Option<String>> = names
+        .iter()
+        .map(|name| theme.style(name).map(|style| style.escape(mode)))
+        .collect();
+    let mut writer = Writer {
+        out: String::with_capacity(source.len() * 2),
+        open: "",
+    };
+    let mut stack: Vec<&str> = Vec::new();
+    for event in events {
+        match *event {
+            Event::Start(h) => {
+                let outer = stack.last().copied().unwrap_or_default();
+                match escapes.get(h) {
+                    Some(Some(escape)) => stack.push(escape),
+                    _ => stack.push(outer),
+                }
+            }
+            Event::End => {
+                stack.pop();
+            }
+            Event::Source { start, end } => {
+                let escape = stack.last().copied().unwrap_or_default();
+                writer.write(&source[start..end], escape);
+            }
+        }
+    }
+    writer.close();
+    writer.out
+}
+
+/// Accumulates styled text, switching escapes only when the style changes
+/// and resetting before every newline, so no style is left open across one.
+struct Writer<'a> {
+    out: String,
+    open: &'a str,
+}
+
+impl<'a> Writer<'a> {
+    fn write(&mut self, text: &str, escape: &'a str) {
+        for (i, line) in text.split('\n').enumerate() {
+            if i > 0 {
+                self.close();
+                self.out.push('\n');
+            }
+            if line.is_empty() {
+                continue;
+            }
+            if escape != self.open {
+                self.close();
+                self.out.push_str(escape);
+                self.open = escape;
+            }
+            self.out.push_str(line);
+        }
+    }
+
+    fn close(&mut self) {
+        if !self.open.is_empty() {
+            self.out.push_str("\x1b[0m");
+            self.open = "";
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_themes() {
+        let theme = Theme::parse(
+            "# comment\n\
+             @keyword = #c678dd bold\n\
+             keyword.function = #f00 on #000000 italic underline\n\
+             @variable = none\n",
+        )
+        .unwrap();
+        let keyword = Style {
+            bold: true,
+            ..Style::fg(Rgb(0xc6, 0x78, 0xdd))
+        };
+        assert_eq!(theme.style("@keyword"), Some(keyword));
+        assert_eq!(theme.style("keyword.repeat"), Some(keyword));
+        assert_eq!(
+            theme.style("keyword.function"),
+            Some(Style {
+                background: Some(Rgb(0, 0, 0)),
+                italic: true,
+                underline: true,
+                ..Style::fg(Rgb(255, 0, 0))
+            })
+        );
+        assert_eq!(theme.style("variable.parameter"), Some(Style::default()));
+        assert_eq!(theme.style("keywords"), None);
+
+        let err = Theme::parse("\n@tag = #12345 bold\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "2: invalid colour `#12345`, expected #rrggbb"
+        );
+        let err = Theme::parse("@tag #ffffff\n").unwrap_err();
+        assert_eq!(err.to_string(), "1: expected `capture = style`");
+    }
+
+    #[test]
+    fn converts_colors() {
+        let style = Style {
+            bold: true,
+            ..Style::fg(Rgb(0xe0, 0x6c, 0x75))
+        };
+        assert_eq!(
+            style.escape(ColorMode::TrueColor),
+            "\x1b[1;38;2;224;108;117m"
+        );
+        assert_eq!(style.escape(ColorMode::Ansi256), "\x1b[1;38;5;168m");
+        assert_eq!(style.escape(ColorMode::Ansi16), "\x1b[1;91m");
+        assert_eq!(ansi256(Rgb(0x80, 0x80, 0x80)), 244);
+        assert_eq!(ansi256(Rgb(0, 0, 0)), 16);
+        assert_eq!(ansi16(Rgb(0, 0, 0x80)), 4);
+        assert_eq!(ColorMode::Ansi16.code(Rgb(0, 0, 0), true), "40");
+        assert_eq!(Style::default().escape(ColorMode::TrueColor), "");
+    }
+
+    #[test]
+    fn resets_at_newlines() {
+        let mut writer = Writer {
+            out: String::new(),
+            open: "",
+        };
+        writer.write("/* a\n", "\x1b[3m");
+        writer.write("b */", "\x1b[3m");
+        writer.write(" x", "");
+        writer.close();
+        assert_eq!(writer.out, "\x1b[3m/* a\x1b[0m\n\x1b[3mb */\x1b[0m x");
+    }
+
+    #[test]
+    fn renders_highlights() {
+        let theme = Theme::parse(
+            "@keyword = #ff0000\n\
+             @tag = #00ff00\n\
+             @function = #0000ff\n",
+        )
+        .unwrap();
+        let code = "templ Hello(name string) {\n\t<b>{fmt.Sprint(name)}</b>\n}\n";
+        let out = render(code, &theme, ColorMode::Ansi16);
+        assert_eq!(
+            out,
+            "\x1b[91mtempl\x1b[0m \x1b[94mHello\x1b[0m(name string) {\n\
+             \t\x1b[92m<b>\x1b[0m{fmt.\x1b[94mSprint\x1b[0m(name)}\x1b[92m</b>\x1b[0m\n\
+             }\n"
+        );
+        assert_eq!(render(code, &Theme::new(), ColorMode::TrueColor), code);
+    }
+}
@@ -0,0 +1,361 @@
+//! Strongly typed wrappers over the GSX concrete syntax tree.
+//!
+//! There is one struct per named node kind in `src/node-types.json`, generated
+//! at build time, with an accessor per field. Fields that accept several node
+//! kinds return an enum such as [`Expression`], so a grammar rename shows up as
+//! a compile error instead of a lookup that silently returns `None`.
+//!
+//! ```
+//! use tree_sitter_gsx::ast::{AstNode, SourceFile};
+//!
+//! let code = "templ Header(title string) {\n\t<span>{title}</span>\n}\n";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&tree_sitter_gsx::language()).unwrap();
+//! let tree = parser.parse(code, None).unwrap();
+//!
+//! let file = SourceFile::cast(tree.root_node()).unwrap();
+//! let component = file
+//!     .children()
+//!     .find_map(|item| match item {
+//!         tree_sitter_gsx::ast::SourceFileChild::ComponentDeclaration(c) => Some(c),
+//!         _ => None,
+//!     })
+//!     .unwrap();
+//! let name = component.name().unwrap();
+//! assert_eq!(name.utf8_text(code.as_bytes()).unwrap(), "Header");
+//! ```
+
+use std::marker::PhantomData;
+
+use tree_sitter::{Node, TreeCursor};
+
+/// A typed view of a syntax node.
+pub trait AstNode<'tree>: Sized + Copy {
+    /// Reports whether nodes of `kind` can be wrapped by this type.
+    fn can_cast(kind: &str) -> bool;
+
+    /// Wraps `node` if its kind matches, or returns `None`.
+    fn cast(node: Node<'tree>) -> Option<Self>;
+
+    /// Returns the underlying syntax node.
+    fn syntax(&self) -> Node<'tree>;
+
+    /// Returns the source text covered by this node.
+    fn utf8_text<'a>(&self, source: &'a [u8]) -> Result<&'a str, std::str::Utf8Error> {
+        self.syntax().utf8_text(source)
+    }
+}
+
+impl<'tree> AstNode<'tree> for Node<'tree> {
+    fn can_cast(_kind: &str) -> bool {
+        true
+    }
+
+    fn cast(node: Node<'tree>) -> Option<Self> {
+        Some(node)
+    }
+
+    fn syntax(&self) -> Node<'tree> {
+        *self
+    }
+}
+
+/// Iterates the named children of a node that either belong to one field or to
+/// no field at all, skipping any that don't cast to `T`.
+pub struct AstChildren<'tree, T> {
+    cursor: TreeCursor<'tree>,
+    field: Option<&'static str>,
+    started: bool,
+    done: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<'tree, T: AstNode<'tree>> AstChildren<'tree, T> {
+    pub(crate) fn field(node: Node<'tree>, field: &'static str) -> Self {
+        Self::new(node, Some(field))
+    }
+
+    pub(crate) fn unfielded(node: Node<'tree>) -> Self {
+        Self::new(node, None)
+    }
+
+    fn new(node: Node<'tree>, field: Option<&'static str>) -> Self {
+        Self {
+            cursor: node.walk(),
+            field,
+            started: false,
+            done: false,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'tree, T: AstNode<'tree>> Iterator for AstChildren<'tree, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while !self.done {
+            let moved = if self.started {
+                self.cursor.goto_next_sibling()
+            } else {
+                self.started = true;
+                self.cursor.goto_first_child()
+            };
+            if !moved {
+                self.done = true;
+                break;
+            }
+            let node = self.cursor.node();
+            if !node.is_named() || node.is_extra() || self.cursor.field_name() != self.field {
+                continue;
+            }
+            if let Some(item) = T::cast(node) {
+                return Some(item);
+            }
+        }
+        None
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/ast.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(code: &str) -> tree_sitter::Tree {
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&crate::language()).unwrap();
+        parser.parse(code, None).unwrap()
+    }
+
+    fn component<'tree>(file: SourceFile<'tree>) -> ComponentDeclaration<'tree> {
+        file.children()
+            .find_map(|item| match item {
+                SourceFileChild::ComponentDeclaration(c) => Some(c),
+                _ => None,
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn test_component_declaration_fields() {
+        let code =
+            "package main\n\ntempl Row(label string, count int) {\n\t<span>{label}</span>\n}\n";
+        let tree = parse(code);
+        let file = SourceFile::cast(tree.root_node()).unwrap();
+        let decl = component(file);
+
+        assert_eq!(
+            decl.name().unwrap().utf8_text(code.as_bytes()).unwrap(),
+            "Row"
+        );
+        assert!(decl.receiver().is_none());
+
+        let params: Vec<_> = decl
+            .parameters()
+            .unwrap()
+            .children()
+            .filter_map(|p| match p {
+                ParameterListChild::Parameter(p) => p.name().next(),
+                ParameterListChild::VariadicParameter(_) => None,
+            })
+            .map(|name| name.utf8_text(code.as_bytes()).unwrap())
+            .collect();
+        assert_eq!(params, ["label", "count"]);
+
+        let body = decl.body().unwrap();
+        let Some(Child::Element(element)) = body.children().next() else {
+            panic!("expected element");
+        };
+        let Some(ElementKind::ElementWithChildren(span)) = element.child() else {
+            panic!("expected element with children");
+        };
+        let text = |id: Identifier| id.utf8_text(code.as_bytes()).unwrap();
+        assert_eq!(text(span.tag().unwrap()), "span");
+        assert_eq!(text(span.closing_tag().unwrap()), "span");
+    }
+
+    #[test]
+    fn test_type_parameters() {
+        let code = "templ Table[K comparable, V ~int | fmt.Stringer](rows map[K]V) {\n}\n";
+        let tree = parse(code);
+        let decl = component(SourceFile::cast(tree.root_node()).unwrap());
+        let text = |node: tree_sitter::Node| node.utf8_text(code.as_bytes()).unwrap();
+
+        let params: Vec<_> = decl.type_parameters().unwrap().children().collect();
+        assert_eq!(params.len(), 2);
+        let names: Vec<_> = params[0].name().map(|id| text(id.syntax())).collect();
+        assert_eq!(names, ["K"]);
+
+        let terms: Vec<_> = params[1].constraint().unwrap().children().collect();
+        assert!(matches!(terms[0], TypeConstraintChild::UnderlyingType(_)));
+        let TypeConstraintChild::TypeExpression(iface) = terms[1] else {
+            panic!("expected type expression");
+        };
+        assert_eq!(text(iface.syntax()), "fmt.Stringer");
+    }
+
+    #[test]
+    fn test_grouped_and_variadic_parameters() {
+        let code = "templ Row(label, value string, each func(int, [4]byte), opts ...Option) {\n}\n";
+        let tree = parse(code);
+        let decl = component(SourceFile::cast(tree.root_node()).unwrap());
+        let text = |node: tree_sitter::Node| node.utf8_text(code.as_bytes()).unwrap();
+
+        let params: Vec<_> = decl.parameters().unwrap().children().collect();
+        assert_eq!(params.len(), 3);
+        let ParameterListChild::Parameter(grouped) = params[0] else {
+            panic!("expected parameter");
+        };
+        let names: Vec<_> = grouped.name().map(|id| text(id.syntax())).collect();
+        assert_eq!(names, ["label", "value"]);
+
+        // func(int, [4]byte) has unnamed parameters
+        let ParameterListChild::Parameter(each) = params[1] else {
+            panic!("expected parameter");
+        };
+        let Some(TypeExpressionKind::FuncType(func)) = each.r#type().unwrap().child() else {
+            panic!("expected func type");
+        };
+        let inner: Vec<_> = func.parameters().unwrap().children().collect();
+        let ParameterListChild::Parameter(array) = inner[1] else {
+            panic!("expected parameter");
+        };
+        assert_eq!(array.name().count(), 0);
+        let Some(TypeExpressionKind::ArrayType(array)) = array.r#type().unwrap().child() else {
+            panic!("expected array type");
+        };
+        assert_eq!(text(array.length().unwrap().syntax()), "4");
+
+        let ParameterListChild::VariadicParameter(variadic) = params[2] else {
+            panic!("expected variadic parameter");
+        };
+        assert_eq!(text(variadic.name().unwrap().syntax()), "opts");
+        assert_eq!(text(variadic.r#type().unwrap().syntax()), "Option");
+    }
+
+    #[test]
+    fn test_method_component_receiver() {
+        let code = "templ (s *Sidebar) Render() {\n\t<div />\n}\n";
+        let tree = parse(code);
+        let decl = component(SourceFile::cast(tree.root_node()).unwrap());
+
+        let receiver = decl.receiver().unwrap();
+        assert_eq!(
+            receiver.name().unwrap().utf8_text(code.as_bytes()).unwrap(),
+            "s"
+        );
+        assert_eq!(
+            receiver
+                .r#type()
+                .unwrap()
+                .utf8_text(code.as_bytes())
+                .unwrap(),
+            "*Sidebar"
+        );
+        assert!(decl.parameters().is_none());
+    }
+
+    #[test]
+    fn test_qualified_component_call() {
+        let code = "templ (s *Sidebar) Render() {\n\t@layout.Card()\n\t@Item()\n}\n";
+        let tree = parse(code);
+        let decl = component(SourceFile::cast(tree.root_node()).unwrap());
+
+        let callees: Vec<_> = decl
+            .body()
+            .unwrap()
+            .children()
+            .map(|child| {
+                let Child::ComponentCall(call) = child else {
+                    panic!("expected component call");
+                };
+                let text = |id: Identifier| id.utf8_text(code.as_bytes()).unwrap();
+                (call.qualifier().map(text), text(call.name().unwrap()))
+            })
+            .collect();
+        assert_eq!(callees, [(Some("layout"), "Card"), (None, "Item")]);
+    }
+
+    #[test]
+    fn test_shared_expression_enum() {
+        let code = "templ List(items []string) {\n\tfor i, item := range items {\n\t\tif i > 0 {\n\t\t\t<span>{item}</span>\n\t\t}\n\t}\n}\n";
+        let tree = parse(code);
+        let decl = component(SourceFile::cast(tree.root_node()).unwrap());
+
+        let Some(Child::ForStatement(for_stmt)) = decl.body().unwrap().children().next() else {
+            panic!("expected for statement");
+        };
+        let clause = for_stmt.clause().unwrap();
+        assert!(matches!(
+            clause.collection(),
+            Some(Expression::Identifier(_))
+        ));
+
+        let Some(Child::IfStatement(if_stmt)) = for_stmt.body().unwrap().children().next() else {
+            panic!("expected if statement");
+        };
+        let Some(Expression::BinaryExpression(cond)) = if_stmt.condition() else {
+            panic!("expected binary expression");
+        };
+        assert!(matches!(cond.left(), Some(Expression::Identifier(_))));
+        assert_eq!(cond.operator().unwrap().kind(), ">");
+        assert!(matches!(cond.right(), Some(Expression::Number(_))));
+        assert!(if_stmt.alternative().is_none());
+    }
+
+    #[test]
+    fn test_switch_clauses() {
+        let code = "templ Status(n int) {\n\tswitch n {\n\tcase 0, 1:\n\t\t<span>few</span>\n\tdefault:\n\t\t@Count(n)\n\t}\n}\n";
+        let tree = parse(code);
+        let decl = component(SourceFile::cast(tree.root_node()).unwrap());
+
+        let Some(Child::SwitchStatement(switch)) = decl.body().unwrap().children().next() else {
+            panic!("expected switch statement");
+        };
+        assert!(matches!(switch.value(), Some(Expression::Identifier(_))));
+        let clauses: Vec<_> = switch.children().collect();
+        assert_eq!(clauses.len(), 2);
+
+        let SwitchStatementChild::CaseClause(case) = clauses[0] else {
+            panic!("expected case clause");
+        };
+        assert_eq!(case.value().count(), 2);
+        assert!(matches!(case.children().next(), Some(Child::Element(_))));
+
+        let SwitchStatementChild::DefaultClause(default) = clauses[1] else {
+            panic!("expected default clause");
+        };
+        assert!(matches!(
+            default.children().next(),
+            Some(Child::ComponentCall(_))
+        ));
+    }
+
+    #[test]
+    fn test_cast_rejects_other_kinds() {
+        let tree = parse("package main\n");
+        let root = tree.root_node();
+        assert!(SourceFile::cast(root).is_some());
+        assert!(ComponentDeclaration::cast(root).is_none());
+        assert!(Expression::can_cast("identifier"));
+        assert!(!Expression::can_cast("element"));
+    }
+
+    #[test]
+    fn test_multiple_field_iterates_in_order() {
+        let code = "var _ tui.Component = (*foo)(nil)\n";
+        let tree = parse(code);
+        let file = SourceFile::cast(tree.root_node()).unwrap();
+        let Some(SourceFileChild::GoDeclaration(decl)) = file.children().next() else {
+            panic!("expected go declaration");
+        };
+        assert_eq!(decl.keyword().unwrap().kind(), "var");
+        let preamble: Vec<_> = decl
+            .preamble()
+            .map(|id| id.utf8_text(code.as_bytes()).unwrap())
+            .collect();
+        assert_eq!(preamble, ["_", "tui", "Component"]);
+    }
+}
@@ -0,0 +1,360 @@
+//! Generates the typed AST wrappers in `ast.rs` from `src/node-types.json`.
+//!
+//! Every named node kind gets a wrapper struct with one accessor per field.
+//! Fields and child lists that admit several node kinds get an enum. When the
+//! kind set matches a hidden choice rule in `src/grammar.json` (for example
+//! `_expression`), the enum is named after that rule so it can be shared.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+use serde_json::Value;
+
+/// Rust keywords that can't be used as plain method names.
+const KEYWORDS: &[&str] = &[
+    "as", "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn", "for",
+    "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return",
+    "self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use", "where",
+    "while", "async", "await", "dyn",
+];
+
+struct NodeType {
+    kind: String,
+    fields: BTreeMap<String, ChildInfo>,
+    children: Option<ChildInfo>,
+}
+
+struct ChildInfo {
+    multiple: bool,
+    required: bool,
+    named: BTreeSet<String>,
+    has_anonymous: bool,
+}
+
+pub fn generate(node_types: &str, grammar: &str) -> String {
+    let node_types: Value = serde_json::from_str(node_types).expect("invalid node-types.json");
+    let grammar: Value = serde_json::from_str(grammar).expect("invalid grammar.json");
+
+    let nodes = parse_node_types(&node_types);
+    let hidden = hidden_choice_sets(&grammar);
+
+    let mut enums = hidden;
+    let mut used = BTreeSet::new();
+
+    let mut out = String::new();
+    out.push_str(
+        "// Generated by bindings/rust/ast_gen.rs from src/node-types.json. Do not edit.\n\n",
+    );
+
+    let struct_names: BTreeSet<String> = nodes.iter().map(|n| camel(&n.kind)).collect();
+
+    for node in &nodes {
+        let name = camel(&node.kind);
+        let _ = writeln!(out, "/// A `{}` node.", node.kind);
+        out.push_str("#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]\n");
+        let _ = writeln!(out, "pub struct {name}<'tree>(Node<'tree>);\n");
+
+        let _ = writeln!(out, "impl<'tree> {name}<'tree> {{");
+        let _ = writeln!(out, "    /// The node kind this wrapper accepts.");
+        let _ = writeln!(out, "    pub const KIND: &'static str = {:?};", node.kind);
+
+        let mut methods = BTreeSet::new();
+        for (field, info) in &node.fields {
+            let method = method_name(field);
+            assert!(
+                methods.insert(method.clone()),
+                "duplicate accessor {name}::{method}"
+            );
+            let ty = child_type(&name, field, info, &mut enums, &mut used, &struct_names);
+            out.push('\n');
+            emit_field(&mut out, field, &method, info, &ty);
+        }
+        if let Some(info) = &node.children {
+            // A lone child of several kinds is the variant of a choice rule
+            // like `element`; several children are items of a list.
+            let suffix = if info.multiple { "child" } else { "kind" };
+            let ty = child_type(&name, suffix, info, &mut enums, &mut used, &struct_names);
+            let method = if info.multiple { "children" } else { "child" };
+            assert!(
+                methods.insert(method.to_string()),
+                "accessor {name}::{method} collides with a field"
+            );
+            out.push('\n');
+            emit_children(&mut out, method, info, &ty);
+        }
+        out.push_str("}\n\n");
+
+        let _ = writeln!(out, "impl<'tree> AstNode<'tree> for {name}<'tree> {{");
+        out.push_str(
+            "    fn can_cast(kind: &str) -> bool {\n        kind == Self::KIND\n    }\n\n",
+        );
+        out.push_str("    fn cast(node: Node<'tree>) -> Option<Self> {\n");
+        out.push_str("        Self::can_cast(node.kind()).then_some(Self(node))\n    }\n\n");
+        out.push_str("    fn syntax(&self) -> Node<'tree> {\n        self.0\n    }\n}\n\n");
+    }
+
+    for (set, name) in &enums {
+        if used.contains(name) {
+            emit_enum(&mut out, name, set);
+        }
+    }
+
+    out
+}
+
+fn parse_node_types(value: &Value) -> Vec<NodeType> {
+    let mut nodes = Vec::new();
+    for entry in value.as_array().expect("node-types.json must be an array") {
+        if !entry["named"].as_bool().unwrap_or(false) {
+            continue;
+        }
+        let kind = entry["type"].as_str().unwrap().to_string();
+        let mut fields = BTreeMap::new();
+        if let Some(map) = entry.get("fields").and_then(Value::as_object) {
+            for (field, info) in map {
+                fields.insert(field.clone(), parse_child_info(info));
+            }
+        }
+        let children = entry.get("children").map(parse_child_info);
+        nodes.push(NodeType {
+            kind,
+            fields,
+            children,
+        });
+    }
+    nodes
+}
+
+fn parse_child_info(value: &Value) -> ChildInfo {
+    let mut named = BTreeSet::new();
+    let mut has_anonymous = false;
+    for ty in value["types"].as_array().unwrap() {
+        if ty["named"].as_bool().unwrap() {
+            named.insert(ty["type"].as_str().unwrap().to_string());
+        } else {
+            has_anonymous = true;
+        }
+    }
+    ChildInfo {
+        multiple: value["multiple"].as_bool().unwrap(),
+        required: value["required"].as_bool().unwrap(),
+        named,
+        has_anonymous,
+    }
+}
+
+/// Collects the visible kinds of every hidden rule that is a plain choice of
+/// symbols, keyed by kind set and mapped to the enum name for that rule.
+fn hidden_choice_sets(grammar: &Value) -> BTreeMap<BTreeSet<String>, String> {
+    let rules = grammar["rules"].as_object().unwrap();
+    let mut sets = BTreeMap::new();
+    for name in rules.keys().filter(|n| n.starts_with('_')) {
+        if let Some(set) = choice_symbols(rules, name, &mut BTreeSet::new()) {
+            if set.len() > 1 {
+                sets.entry(set)
+                    .or_insert_with(|| camel(name.trim_start_matches('_')));
+            }
+        }
+    }
+    sets
+}
+
+fn choice_symbols(
+    rules: &serde_json::Map<String, Value>,
+    name: &str,
+    seen: &mut BTreeSet<String>,
+) -> Option<BTreeSet<String>> {
+    if !seen.insert(name.to_string()) {
+        return Some(BTreeSet::new());
+    }
+    let rule = &rules[name];
+    let members: Vec<&Value> = match rule["type"].as_str()? {
+        "CHOICE" => rule["members"].as_array()?.iter().collect(),
+        "SYMBOL" => vec![rule],
+        _ => return None,
+    };
+    let mut set = BTreeSet::new();
+    for member in members {
+        let sym = member_symbol(member)?;
+        if sym.starts_with('_') {
+            set.extend(choice_symbols(rules, sym, seen)?);
+        } else {
+            set.insert(sym.to_string());
+        }
+    }
+    Some(set)
+}
+
+/// Returns the symbol a choice member stands for: the member itself, or the
+/// one symbol in a sequence that otherwise only holds punctuation, such as
+/// `seq($.go_statement, optional(";"))`.
+fn member_symbol(member: &Value) -> Option<&str> {
+    match member["type"].as_str()? {
+        "SYMBOL" => member["name"].as_str(),
+        "SEQ" => {
+            let mut symbol = None;
+            for part in member["members"].as_array()? {
+                match part["type"].as_str()? {
+                    "SYMBOL" if symbol.is_none() => symbol = part["name"].as_str(),
+                    "STRING" => {}
+                    "CHOICE"
+                        if part["members"]
+                            .as_array()?
+                            .iter()
+                            .all(|m| matches!(m["type"].as_str(), Some("STRING" | "BLANK"))) => {}
+                    _ => return None,
+                }
+            }
+            symbol
+        }
+        _ => None,
+    }
+}
+
+/// Returns the Rust type for a field or child list, registering an enum when
+/// several kinds are possible.
+fn child_type(
+    parent: &str,
+    field: &str,
+    info: &ChildInfo,
+    enums: &mut BTreeMap<BTreeSet<String>, String>,
+    used: &mut BTreeSet<String>,
+    struct_names: &BTreeSet<String>,
+) -> String {
+    if info.has_anonymous || info.named.is_empty() {
+        return "Node<'tree>".to_string();
+    }
+    if info.named.len() == 1 {
+        return format!("{}<'tree>", camel(info.named.iter().next().unwrap()));
+    }
+    let name = match enums.get(&info.named) {
+        Some(name) => name.clone(),
+        None => {
+            let name = format!("{parent}{}", camel(field));
+            assert!(
+                !enums.values().any(|existing| *existing == name),
+                "enum {name} is generated for two different kind sets"
+            );
+            enums.insert(info.named.clone(), name.clone());
+            name
+        }
+    };
+    assert!(
+        !struct_names.contains(&name),
+        "enum {name} collides with a node struct"
+    );
+    used.insert(name.clone());
+    format!("{name}<'tree>")
+}
+
+fn emit_field(out: &mut String, field: &str, method: &str, info: &ChildInfo, ty: &str) {
+    let raw = ty == "Node<'tree>";
+    if info.multiple {
+        let _ = writeln!(out, "    /// Returns the nodes in the `{field}` field.");
+        if raw {
+            let _ = writeln!(
+                out,
+                "    pub fn {method}(&self) -> AstChildren<'tree, Node<'tree>> {{"
+            );
+        } else {
+            let _ = writeln!(
+                out,
+                "    pub fn {method}(&self) -> AstChildren<'tree, {ty}> {{"
+            );
+        }
+        let _ = writeln!(out, "        AstChildren::field(self.0, {field:?})\n    }}");
+        return;
+    }
+    if info.required {
+        let _ = writeln!(
+            out,
+            "    /// Returns the `{field}` field. This is only `None` when the tree has errors."
+        );
+    } else {
+        let _ = writeln!(out, "    /// Returns the optional `{field}` field.");
+    }
+    let _ = writeln!(out, "    pub fn {method}(&self) -> Option<{ty}> {{");
+    if raw {
+        let _ = writeln!(out, "        self.0.child_by_field_name({field:?})\n    }}");
+    } else {
+        let base = ty.trim_end_matches("<'tree>");
+        let _ = writeln!(
+            out,
+            "        self.0.child_by_field_name({field:?}).and_then({base}::cast)\n    }}"
+        );
+    }
+}
+
+fn emit_children(out: &mut String, method: &str, info: &ChildInfo, ty: &str) {
+    if info.multiple {
+        let _ = writeln!(
+            out,
+            "    /// Returns the named children that aren't in a field."
+        );
+        let _ = writeln!(
+            out,
+            "    pub fn {method}(&self) -> AstChildren<'tree, {ty}> {{"
+        );
+        let _ = writeln!(out, "        AstChildren::unfielded(self.0)\n    }}");
+    } else {
+        let _ = writeln!(
+            out,
+            "    /// Returns the named child that isn't in a field."
+        );
+        let _ = writeln!(out, "    pub fn {method}(&self) -> Option<{ty}> {{");
+        let _ = writeln!(out, "        AstChildren::unfielded(self.0).next()\n    }}");
+    }
+}
+
+fn emit_enum(out: &mut String, name: &str, kinds: &BTreeSet<String>) {
+    let _ = writeln!(out, "/// One of the node kinds that can appear as {name}.");
+    out.push_str("#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]\n");
+    let _ = writeln!(out, "pub enum {name}<'tree> {{");
+    for kind in kinds {
+        let variant = camel(kind);
+        let _ = writeln!(out, "    {variant}({variant}<'tree>),");
+    }
+    out.push_str("}\n\n");
+
+    let _ = writeln!(out, "impl<'tree> AstNode<'tree> for {name}<'tree> {{");
+    out.push_str("    fn can_cast(kind: &str) -> bool {\n        matches!(kind, ");
+    let patterns: Vec<String> = kinds.iter().map(|k| format!("{k:?}")).collect();
+    out.push_str(&patterns.join(" | "));
+    out.push_str(")\n    }\n\n");
+
+    out.push_str("    fn cast(node: Node<'tree>) -> Option<Self> {\n        match node.kind() {\n");
+    for kind in kinds {
+        let variant = camel(kind);
+        let _ = writeln!(
+            out,
+            "            {kind:?} => Some(Self::{variant}({variant}(node))),"
+        );
+    }
+    out.push_str("            _ => None,\n        }\n    }\n\n");
+
+    out.push_str("    fn syntax(&self) -> Node<'tree> {\n        match self {\n");
+    for kind in kinds {
+        let variant = camel(kind);
+        let _ = writeln!(out, "            Self::{variant}(node) => node.0,");
+    }
+    out.push_str("        }\n    }\n}\n\n");
+}
+
+fn method_name(field: &str) -> String {
+    if KEYWORDS.contains(&field) {
+        format!("r#{field}")
+    } else {
+        field.to_string()
+    }
+}
+
+fn camel(kind: &str) -> String {
+    kind.split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            let first = chars.next().unwrap().to_ascii_uppercase();
+            std::iter::once(first).chain(chars).collect::<String>()
+        })
+        .collect()
+}
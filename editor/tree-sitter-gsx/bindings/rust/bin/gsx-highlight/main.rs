@@ -0,0 +1,157 @@
+//! Prints `.gsx` files with ANSI syntax highlighting, for terminals, pagers
+//! and review diffs.
+//!
+//! ```text
+//! gsx-highlight [--color=truecolor|256|16] [--theme=FILE] [FILE...]
+//! ```
+//!
+//! With no files, or `-`, it reads standard input. Without `--color` it
+//! uses 24-bit colour when `COLORTERM` says the terminal supports it, and
+//! the 256 colour palette otherwise. A theme file holds `@capture = style`
+//! lines, see [`Theme::parse`], layered over the built-in dark theme.
+
+use std::error::Error;
+use std::io::{self, Read, Write};
+use std::process::ExitCode;
+use std::{env, fs};
+
+use tree_sitter_gsx::ansi::{render, ColorMode, Theme};
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+const USAGE: &str = "usage: gsx-highlight [--color=truecolor|256|16] [--theme=FILE] [FILE...]";
+
+fn main() -> ExitCode {
+    match run(env::args().skip(1)) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("gsx-highlight: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// The parsed command line.
+#[derive(Debug, PartialEq)]
+struct Options {
+    mode: ColorMode,
+    theme: Option<String>,
+    files: Vec<String>,
+}
+
+fn run(args: impl Iterator<Item = String>) -> Result<()> {
+    let Some(options) = parse_args(args, env::var("COLORTERM").ok().as_deref())? else {
+        println!("{USAGE}");
+        return Ok(());
+    };
+    let mut theme = Theme::dark();
+    if let Some(path) = &options.theme {
+        let text = fs::read_to_string(path).map_err(|err| format!("{path}: {err}"))?;
+        theme
+            .extend_from(&text)
+            .map_err(|err| format!("{path}:{err}"))?;
+    }
+
+    let mut stdout = io::stdout().lock();
+    for file in &options.files {
+        let source = if file == "-" {
+            let mut source = String::new();
+            io::stdin().read_to_string(&mut source)?;
+            source
+        } else {
+            fs::read_to_string(file).map_err(|err| format!("{file}: {err}"))?
+        };
+        let out = render(&source, &theme, options.mode);
+        match stdout.write_all(out.as_bytes()) {
+            // A pager that quits early closes the pipe; that's not an error.
+            Err(err) if err.kind() == io::ErrorKind::BrokenPipe => return Ok(()),
+            result => result?,
+        }
+    }
+    stdout.flush().or_else(|err| match err.kind() {
+        io::ErrorKind::BrokenPipe => Ok(()),
+        _ => Err(err.into()),
+    })
+}
+
+/// Parses the arguments after the program name. Returns `None` when help
+/// was asked for. `colorterm` is the value of `COLORTERM`.
+fn parse_args(
+    mut args: impl Iterator<Item = String>,
+    colorterm: Option<&str>,
+) -> Result<Option<Options>> {
+    let mut mode = None;
+    let mut theme = None;
+    let mut files = Vec::new();
+    while let Some(arg) = args.next() {
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => (flag, Some(value.to_string())),
+            _ => (arg.as_str(), None),
+        };
+        let mut value = || {
+            inline
+                .clone()
+                .or_else(|| args.next())
+                .ok_or_else(|| format!("{flag} needs a value\n{USAGE}"))
+        };
+        match flag {
+            "-h" | "--help" => return Ok(None),
+            "--color" => mode = Some(value()?.parse::<ColorMode>()?),
+            "--theme" => theme = Some(value()?),
+            "--" => {
+                files.extend(args.by_ref());
+                break;
+            }
+            _ if flag.starts_with('-') && flag != "-" => {
+                return Err(format!("unknown option {flag}\n{USAGE}").into());
+            }
+            _ => files.push(arg),
+        }
+    }
+    if files.is_empty() {
+        files.push("-".to_string());
+    }
+    let mode = mode.unwrap_or(match colorterm {
+        Some("truecolor" | "24bit") => ColorMode::TrueColor,
+        _ => ColorMode::Ansi256,
+    });
+    Ok(Some(Options { mode, theme, files }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str], colorterm: Option<&str>) -> Result<Option<Options>> {
+        parse_args(args.iter().map(|arg| arg.to_string()), colorterm)
+    }
+
+    #[test]
+    fn parses_arguments() {
+        let options = parse(&["--color=16", "--theme", "dark.theme", "a.gsx", "-"], None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            options,
+            Options {
+                mode: ColorMode::Ansi16,
+                theme: Some("dark.theme".to_string()),
+                files: vec!["a.gsx".to_string(), "-".to_string()],
+            }
+        );
+
+        let options = parse(&[], Some("truecolor")).unwrap().unwrap();
+        assert_eq!(options.mode, ColorMode::TrueColor);
+        assert_eq!(options.files, ["-"]);
+        assert_eq!(parse(&[], None).unwrap().unwrap().mode, ColorMode::Ansi256);
+        assert_eq!(
+            parse(&["--", "--color"], None).unwrap().unwrap().files,
+            ["--color"]
+        );
+
+        assert!(parse(&["-h"], None).unwrap().is_none());
+        assert!(parse(&["--color=8"], None).is_err());
+        assert!(parse(&["--theme"], None).is_err());
+        assert!(parse(&["--verbose"], None).is_err());
+    }
+}
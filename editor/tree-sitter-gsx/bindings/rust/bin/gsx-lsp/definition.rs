@@ -0,0 +1,70 @@
+//! Go to definition for locals, using [`tree_sitter_gsx::locals`].
+
+use lsp_types::{Location, Position, Uri};
+
+use crate::document::{Document, Encoding};
+
+/// Returns the definition of the local referenced at `position`. A position
+/// on a definition returns that definition.
+pub fn definition(
+    doc: &Document,
+    uri: &Uri,
+    position: Position,
+    encoding: Encoding,
+) -> Option<Location> {
+    let offset = doc.offset(position, encoding);
+    // Go to definition is rare enough to parse the Go regions on demand.
+    let go = tree_sitter_gsx::go::parse_with_go(doc.text());
+    let references = tree_sitter_gsx::locals::resolve_references(&go, doc.text());
+    let covers = |node: tree_sitter::Node| node.start_byte() <= offset && offset <= node.end_byte();
+    references
+        .iter()
+        .find(|reference| covers(reference.node) || covers(reference.definition))
+        .map(|reference| Location::new(uri.clone(), doc.node_range(reference.definition, encoding)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use lsp_types::Range;
+    use tree_sitter::Parser;
+
+    use super::*;
+
+    #[test]
+    fn test_loop_variable_definition() {
+        let code = "templ List(items []string) {\n\tfor _, item := range items {\n\t\t@Row(item)\n\t}\n}\n";
+        let mut parser = Parser::new();
+        parser.set_language(&tree_sitter_gsx::language()).unwrap();
+        let doc = Document::new(&mut parser, code.to_string());
+        let uri = Uri::from_str("file:///a.gsx").unwrap();
+
+        let item = Range::new(Position::new(1, 8), Position::new(1, 12));
+        let found = definition(&doc, &uri, Position::new(2, 8), Encoding::Utf16).unwrap();
+        assert_eq!(found.range, item);
+        let found = definition(&doc, &uri, Position::new(1, 9), Encoding::Utf16).unwrap();
+        assert_eq!(found.range, item);
+
+        let items = Range::new(Position::new(0, 11), Position::new(0, 16));
+        let found = definition(&doc, &uri, Position::new(1, 24), Encoding::Utf16).unwrap();
+        assert_eq!(found.range, items);
+        assert_eq!(
+            definition(&doc, &uri, Position::new(2, 3), Encoding::Utf16),
+            None
+        );
+    }
+
+    #[test]
+    fn test_expression_definition() {
+        let code = "templ List(items []string) {\n\tfor _, item := range items {\n\t\t<span>{item}</span>\n\t}\n}\n";
+        let mut parser = Parser::new();
+        parser.set_language(&tree_sitter_gsx::language()).unwrap();
+        let doc = Document::new(&mut parser, code.to_string());
+        let uri = Uri::from_str("file:///a.gsx").unwrap();
+
+        let item = Range::new(Position::new(1, 8), Position::new(1, 12));
+        let found = definition(&doc, &uri, Position::new(2, 10), Encoding::Utf16).unwrap();
+        assert_eq!(found.range, item);
+    }
+}
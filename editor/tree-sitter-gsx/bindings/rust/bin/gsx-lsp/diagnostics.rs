@@ -0,0 +1,83 @@
+//! Publishes the syntax errors found by [`tree_sitter_gsx::diagnostics`].
+
+use lsp_types::{Diagnostic, DiagnosticRelatedInformation, DiagnosticSeverity, Location, Uri};
+
+use crate::document::{Document, Encoding};
+
+/// Returns an LSP diagnostic for each syntax error in `doc`.
+pub fn syntax_diagnostics(doc: &Document, uri: &Uri, encoding: Encoding) -> Vec<Diagnostic> {
+    tree_sitter_gsx::diagnostics::diagnostics(doc.tree(), doc.text())
+        .into_iter()
+        .map(|diag| {
+            let mut message = diag.message;
+            if let Some(hint) = diag.hint {
+                message = format!("{message} ({hint})");
+            }
+            let related: Vec<_> = diag
+                .related
+                .into_iter()
+                .map(|related| DiagnosticRelatedInformation {
+                    location: Location::new(
+                        uri.clone(),
+                        doc.range(related.range.start_byte, related.range.end_byte, encoding),
+                    ),
+                    message: related.message,
+                })
+                .collect();
+            Diagnostic {
+                range: doc.range(diag.range.start_byte, diag.range.end_byte, encoding),
+                severity: Some(DiagnosticSeverity::ERROR),
+                source: Some("gsx".to_string()),
+                message,
+                related_information: (!related.is_empty()).then_some(related),
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use lsp_types::{Position, Range};
+    use tree_sitter::Parser;
+
+    use super::*;
+
+    fn diagnostics(code: &str) -> Vec<Diagnostic> {
+        let mut parser = Parser::new();
+        parser.set_language(&tree_sitter_gsx::language()).unwrap();
+        let doc = Document::new(&mut parser, code.to_string());
+        let uri = Uri::from_str("file:///a.gsx").unwrap();
+        syntax_diagnostics(&doc, &uri, Encoding::Utf16)
+    }
+
+    #[test]
+    fn test_clean_file_has_no_diagnostics() {
+        assert!(diagnostics("templ A() {\n\t<hr />\n}\n").is_empty());
+    }
+
+    #[test]
+    fn test_diagnostic_conversion() {
+        let missing = diagnostics("templ A(x int {\n\t<hr />\n}\n");
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].message, "missing ')' in parameter_list");
+        assert_eq!(missing[0].source.as_deref(), Some("gsx"));
+        assert_eq!(
+            missing[0].range,
+            Range::new(Position::new(0, 13), Position::new(0, 13))
+        );
+        let related = missing[0].related_information.as_ref().unwrap();
+        assert_eq!(
+            related[0].location.range,
+            Range::new(Position::new(0, 7), Position::new(0, 8))
+        );
+
+        let unclosed = diagnostics("templ A() {\n\t<div>\n}\n");
+        assert_eq!(
+            unclosed[0].message,
+            "unclosed <div> element (add </div> or use <div /> for an empty element)"
+        );
+    }
+}
@@ -0,0 +1,227 @@
+//! Open documents and the conversions between LSP positions and byte offsets.
+
+use lsp_types::{Position, Range};
+use tree_sitter::{InputEdit, Node, Parser, Point, Tree};
+
+/// The unit a client counts `Position::character` in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Encoding {
+    Utf8,
+    Utf16,
+}
+
+/// The text of an open document and its syntax tree.
+pub struct Document {
+    text: String,
+    tree: Tree,
+    line_starts: Vec<usize>,
+}
+
+impl Document {
+    /// Parses `text` from scratch.
+    pub fn new(parser: &mut Parser, text: String) -> Self {
+        let tree = parser.parse(&text, None).expect("parser has a language");
+        let line_starts = line_starts(&text);
+        Self {
+            text,
+            tree,
+            line_starts,
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn tree(&self) -> &Tree {
+        &self.tree
+    }
+
+    /// Replaces `range` with `new_text`, or the whole document when `range`
+    /// is `None`, and re-parses incrementally from the edited tree.
+    pub fn edit(
+        &mut self,
+        parser: &mut Parser,
+        range: Option<Range>,
+        new_text: &str,
+        encoding: Encoding,
+    ) {
+        let Some(range) = range else {
+            *self = Self::new(parser, new_text.to_string());
+            return;
+        };
+
+        let start_byte = self.offset(range.start, encoding);
+        let old_end_byte = self.offset(range.end, encoding).max(start_byte);
+        let start_position = self.point(start_byte);
+        let old_end_position = self.point(old_end_byte);
+
+        self.text.replace_range(start_byte..old_end_byte, new_text);
+        self.line_starts = line_starts(&self.text);
+
+        let new_end_byte = start_byte + new_text.len();
+        self.tree.edit(&InputEdit {
+            start_byte,
+            old_end_byte,
+            new_end_byte,
+            start_position,
+            old_end_position,
+            new_end_position: self.point(new_end_byte),
+        });
+        self.tree = parser
+            .parse(&self.text, Some(&self.tree))
+            .expect("parser has a language");
+    }
+
+    /// Converts a byte offset to an LSP position.
+    pub fn position(&self, offset: usize, encoding: Encoding) -> Position {
+        let offset = offset.min(self.text.len());
+        let line = self.line_of(offset);
+        let prefix = &self.text[self.line_starts[line]..offset];
+        let character = match encoding {
+            Encoding::Utf8 => prefix.len(),
+            Encoding::Utf16 => prefix.encode_utf16().count(),
+        };
+        Position::new(line as u32, character as u32)
+    }
+
+    /// Converts an LSP position to a byte offset. Positions past the end of a
+    /// line resolve to the end of that line, and lines past the end of the
+    /// document resolve to its end.
+    pub fn offset(&self, position: Position, encoding: Encoding) -> usize {
+        let line = position.line as usize;
+        let Some(&start) = self.line_starts.get(line) else {
+            return self.text.len();
+        };
+        let end = self
+            .line_starts
+            .get(line + 1)
+            .copied()
+            .unwrap_or(self.text.len());
+        let content = self.text[start..end].trim_end_matches('\n');
+        let content = content.trim_end_matches('\r');
+
+        let mut units = 0;
+        for (i, ch) in content.char_indices() {
+            if units >= position.character as usize {
+                return start + i;
+            }
+            units += match encoding {
+                Encoding::Utf8 => ch.len_utf8(),
+                Encoding::Utf16 => ch.len_utf16(),
+            };
+        }
+        start + content.len()
+    }
+
+    /// Returns the LSP range covered by `node`.
+    pub fn node_range(&self, node: Node, encoding: Encoding) -> Range {
+        self.range(node.start_byte(), node.end_byte(), encoding)
+    }
+
+    /// Returns the LSP range between two byte offsets.
+    pub fn range(&self, start: usize, end: usize, encoding: Encoding) -> Range {
+        Range::new(self.position(start, encoding), self.position(end, encoding))
+    }
+
+    /// Converts a byte offset to a tree-sitter point.
+    pub fn point(&self, offset: usize) -> Point {
+        let row = self.line_of(offset);
+        Point::new(row, offset - self.line_starts[row])
+    }
+
+    fn line_of(&self, offset: usize) -> usize {
+        self.line_starts.partition_point(|&start| start <= offset) - 1
+    }
+}
+
+fn line_starts(text: &str) -> Vec<usize> {
+    std::iter::once(0)
+        .chain(text.match_indices('\n').map(|(i, _)| i + 1))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parser() -> Parser {
+        let mut parser = Parser::new();
+        parser.set_language(&tree_sitter_gsx::language()).unwrap();
+        parser
+    }
+
+    #[test]
+    fn test_position_round_trip() {
+        let mut parser = parser();
+        let doc = Document::new(&mut parser, "a\n\"é😀\" b\n".to_string());
+
+        // `b` is preceded by a quote, two bytes of `é`, four bytes of the
+        // emoji, a quote and a space.
+        let b = doc.text().find('b').unwrap();
+        assert_eq!(doc.position(b, Encoding::Utf8), Position::new(1, 9));
+        assert_eq!(doc.position(b, Encoding::Utf16), Position::new(1, 6));
+        assert_eq!(doc.offset(Position::new(1, 9), Encoding::Utf8), b);
+        assert_eq!(doc.offset(Position::new(1, 6), Encoding::Utf16), b);
+
+        assert_eq!(doc.offset(Position::new(0, 40), Encoding::Utf16), 1);
+        assert_eq!(
+            doc.offset(Position::new(9, 0), Encoding::Utf16),
+            doc.text().len()
+        );
+        assert_eq!(
+            doc.position(doc.text().len(), Encoding::Utf16),
+            Position::new(2, 0)
+        );
+    }
+
+    #[test]
+    fn test_incremental_edit_matches_full_parse() {
+        let mut parser = parser();
+        let mut doc = Document::new(
+            &mut parser,
+            "templ Card(title string) {\n\t<span>{title}</span>\n}\n".to_string(),
+        );
+
+        // Rename the tag on both ends, then insert a sibling element.
+        doc.edit(
+            &mut parser,
+            Some(Range::new(Position::new(1, 2), Position::new(1, 6))),
+            "text",
+            Encoding::Utf16,
+        );
+        doc.edit(
+            &mut parser,
+            Some(Range::new(Position::new(1, 16), Position::new(1, 20))),
+            "text",
+            Encoding::Utf16,
+        );
+        doc.edit(
+            &mut parser,
+            Some(Range::new(Position::new(2, 0), Position::new(2, 0))),
+            "\t<hr />\n",
+            Encoding::Utf16,
+        );
+
+        let expected = "templ Card(title string) {\n\t<text>{title}</text>\n\t<hr />\n}\n";
+        assert_eq!(doc.text(), expected);
+        let fresh = parser.parse(expected, None).unwrap();
+        assert_eq!(
+            doc.tree().root_node().to_sexp(),
+            fresh.root_node().to_sexp()
+        );
+        assert!(!doc.tree().root_node().has_error());
+    }
+
+    #[test]
+    fn test_full_replacement() {
+        let mut parser = parser();
+        let mut doc = Document::new(&mut parser, "package main\n".to_string());
+        doc.edit(&mut parser, None, "package other\n", Encoding::Utf8);
+        assert_eq!(doc.text(), "package other\n");
+        assert_eq!(
+            doc.tree().root_node().to_sexp(),
+            "(source_file (package_clause name: (identifier)))"
+        );
+    }
+}
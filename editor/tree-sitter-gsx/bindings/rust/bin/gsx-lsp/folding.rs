@@ -0,0 +1,53 @@
+//! Folding ranges, from [`tree_sitter_gsx::folding`].
+
+use lsp_types::{FoldingRange, FoldingRangeKind};
+use tree_sitter_gsx::folding;
+
+use crate::document::Document;
+
+/// Returns the folding ranges of `doc`, ordered by start line.
+pub fn folding_ranges(doc: &Document) -> Vec<FoldingRange> {
+    folding::folding_ranges(doc.tree())
+        .into_iter()
+        .map(|range| FoldingRange {
+            start_line: range.start_line as u32,
+            start_character: None,
+            end_line: range.end_line as u32,
+            end_character: None,
+            kind: Some(match range.kind {
+                folding::FoldingRangeKind::Region => FoldingRangeKind::Region,
+                folding::FoldingRangeKind::Imports => FoldingRangeKind::Imports,
+                folding::FoldingRangeKind::Comment => FoldingRangeKind::Comment,
+            }),
+            collapsed_text: None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter::Parser;
+
+    use super::*;
+
+    #[test]
+    fn test_folding_ranges() {
+        let code = "import (\n\t\"fmt\"\n)\n\n// Card renders a card.\n// It has a title.\ntempl Card() {\n\t<div>\n\t\t<hr />\n\t</div>\n}\n";
+        let mut parser = Parser::new();
+        parser.set_language(&tree_sitter_gsx::language()).unwrap();
+        let doc = Document::new(&mut parser, code.to_string());
+        let folds: Vec<_> = folding_ranges(&doc)
+            .into_iter()
+            .map(|r| (r.start_line, r.end_line, r.kind))
+            .collect();
+        assert_eq!(
+            folds,
+            [
+                (0, 1, Some(FoldingRangeKind::Imports)),
+                (4, 5, Some(FoldingRangeKind::Comment)),
+                (6, 9, Some(FoldingRangeKind::Region)),
+                (7, 8, Some(FoldingRangeKind::Region)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,341 @@
+//! A language server for `.gsx` files built on the tree-sitter grammar.
+//!
+//! It speaks LSP over stdio and offers document symbols, folding ranges,
+//! selection ranges, go to definition for locals, semantic tokens and syntax
+//! diagnostics. Documents are re-parsed incrementally on every edit and all
+//! features read the syntax tree, so unlike `tui lsp` it doesn't need a Go
+//! toolchain.
+
+mod definition;
+mod diagnostics;
+mod document;
+mod folding;
+mod selection;
+mod semantic;
+mod symbols;
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, LogMessage,
+    Notification as _, PublishDiagnostics,
+};
+use lsp_types::request::{
+    DocumentSymbolRequest, FoldingRangeRequest, GotoDefinition, Request as _,
+    SelectionRangeRequest, SemanticTokensFullRequest, SemanticTokensRangeRequest,
+};
+use lsp_types::{
+    DidChangeTextDocumentParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
+    DocumentSymbolParams, DocumentSymbolResponse, FoldingRangeParams,
+    FoldingRangeProviderCapability, GotoDefinitionParams, GotoDefinitionResponse, InitializeParams,
+    LogMessageParams, MessageType, OneOf, PositionEncodingKind, PublishDiagnosticsParams,
+    SelectionRangeParams, SelectionRangeProviderCapability, SemanticTokensFullOptions,
+    SemanticTokensOptions, SemanticTokensParams, SemanticTokensRangeParams,
+    SemanticTokensRangeResult, SemanticTokensResult, SemanticTokensServerCapabilities,
+    ServerCapabilities, ServerInfo, TextDocumentSyncCapability, TextDocumentSyncKind, Uri,
+};
+use tree_sitter::Parser;
+
+use crate::document::{Document, Encoding};
+
+type Result<T> = std::result::Result<T, Box<dyn Error + Send + Sync>>;
+
+fn main() -> Result<()> {
+    let (connection, io_threads) = Connection::stdio();
+
+    let (id, params) = connection.initialize_start()?;
+    let params: InitializeParams = serde_json::from_value(params)?;
+    let encoding = negotiate_encoding(&params);
+    let result = serde_json::json!({
+        "capabilities": capabilities(encoding),
+        "serverInfo": ServerInfo {
+            name: "gsx-lsp".to_string(),
+            version: Some(env!("CARGO_PKG_VERSION").to_string()),
+        },
+    });
+    connection.initialize_finish(id, result)?;
+
+    Server::new(encoding).run(&connection)?;
+
+    drop(connection);
+    io_threads.join()?;
+    Ok(())
+}
+
+/// Picks UTF-8 positions when the client offers them and falls back to the
+/// UTF-16 default otherwise.
+fn negotiate_encoding(params: &InitializeParams) -> Encoding {
+    let offered = params
+        .capabilities
+        .general
+        .as_ref()
+        .and_then(|general| general.position_encodings.as_ref());
+    match offered {
+        Some(kinds) if kinds.contains(&PositionEncodingKind::UTF8) => Encoding::Utf8,
+        _ => Encoding::Utf16,
+    }
+}
+
+fn capabilities(encoding: Encoding) -> ServerCapabilities {
+    ServerCapabilities {
+        position_encoding: Some(match encoding {
+            Encoding::Utf8 => PositionEncodingKind::UTF8,
+            Encoding::Utf16 => PositionEncodingKind::UTF16,
+        }),
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(
+            TextDocumentSyncKind::INCREMENTAL,
+        )),
+        document_symbol_provider: Some(OneOf::Left(true)),
+        folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
+        selection_range_provider: Some(SelectionRangeProviderCapability::Simple(true)),
+        definition_provider: Some(OneOf::Left(true)),
+        semantic_tokens_provider: Some(SemanticTokensServerCapabilities::SemanticTokensOptions(
+            SemanticTokensOptions {
+                legend: semantic::legend(),
+                range: Some(true),
+                full: Some(SemanticTokensFullOptions::Bool(true)),
+                ..Default::default()
+            },
+        )),
+        ..Default::default()
+    }
+}
+
+struct Server {
+    parser: Parser,
+    documents: HashMap<Uri, Document>,
+    encoding: Encoding,
+}
+
+impl Server {
+    fn new(encoding: Encoding) -> Self {
+        let mut parser = Parser::new();
+        parser
+            .set_language(&tree_sitter_gsx::language())
+            .expect("Error loading Gsx parser");
+        Self {
+            parser,
+            documents: HashMap::new(),
+            encoding,
+        }
+    }
+
+    fn run(&mut self, connection: &Connection) -> Result<()> {
+        for message in &connection.receiver {
+            match message {
+                Message::Request(request) => {
+                    if connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+                    let response = self.handle_request(request);
+                    connection.sender.send(Message::Response(response))?;
+                }
+                Message::Notification(notification) => {
+                    let method = notification.method.clone();
+                    let reply = match self.handle_notification(notification) {
+                        Ok(Some(uri)) => Notification::new(
+                            PublishDiagnostics::METHOD.to_string(),
+                            self.diagnostics(uri),
+                        ),
+                        Ok(None) => continue,
+                        // A notification has no response to carry the error,
+                        // so it's logged and the server carries on.
+                        Err(err) => Notification::new(
+                            LogMessage::METHOD.to_string(),
+                            LogMessageParams {
+                                typ: MessageType::ERROR,
+                                message: format!("invalid {method} params: {err}"),
+                            },
+                        ),
+                    };
+                    connection.sender.send(Message::Notification(reply))?;
+                }
+                Message::Response(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_request(&mut self, request: Request) -> Response {
+        let Request { id, method, params } = request;
+        let result = match method.as_str() {
+            DocumentSymbolRequest::METHOD => {
+                serde_json::from_value(params).map(|params: DocumentSymbolParams| {
+                    let symbols = self
+                        .documents
+                        .get(&params.text_document.uri)
+                        .map(|doc| symbols::document_symbols(doc, self.encoding))
+                        .unwrap_or_default();
+                    serde_json::json!(DocumentSymbolResponse::Nested(symbols))
+                })
+            }
+            FoldingRangeRequest::METHOD => {
+                serde_json::from_value(params).map(|params: FoldingRangeParams| {
+                    let ranges = self
+                        .documents
+                        .get(&params.text_document.uri)
+                        .map(folding::folding_ranges)
+                        .unwrap_or_default();
+                    serde_json::json!(ranges)
+                })
+            }
+            SelectionRangeRequest::METHOD => {
+                serde_json::from_value(params).map(|params: SelectionRangeParams| {
+                    let ranges = self
+                        .documents
+                        .get(&params.text_document.uri)
+                        .map(|doc| {
+                            selection::selection_ranges(doc, &params.positions, self.encoding)
+                        })
+                        .unwrap_or_default();
+                    serde_json::json!(ranges)
+                })
+            }
+            GotoDefinition::METHOD => {
+                serde_json::from_value(params).map(|params: GotoDefinitionParams| {
+                    let position = params.text_document_position_params;
+                    let location =
+                        self.documents
+                            .get(&position.text_document.uri)
+                            .and_then(|doc| {
+                                definition::definition(
+                                    doc,
+                                    &position.text_document.uri,
+                                    position.position,
+                                    self.encoding,
+                                )
+                            });
+                    serde_json::json!(location.map(GotoDefinitionResponse::Scalar))
+                })
+            }
+            SemanticTokensFullRequest::METHOD => {
+                serde_json::from_value(params).map(|params: SemanticTokensParams| {
+                    let tokens = self
+                        .documents
+                        .get(&params.text_document.uri)
+                        .map(|doc| semantic::semantic_tokens(doc, None, self.encoding));
+                    serde_json::json!(tokens.map(SemanticTokensResult::Tokens))
+                })
+            }
+            SemanticTokensRangeRequest::METHOD => {
+                serde_json::from_value(params).map(|params: SemanticTokensRangeParams| {
+                    let tokens = self.documents.get(&params.text_document.uri).map(|doc| {
+                        semantic::semantic_tokens(doc, Some(params.range), self.encoding)
+                    });
+                    serde_json::json!(tokens.map(SemanticTokensRangeResult::Tokens))
+                })
+            }
+            _ => {
+                return Response::new_err(
+                    id,
+                    ErrorCode::MethodNotFound as i32,
+                    format!("unhandled method {method}"),
+                )
+            }
+        };
+        match result {
+            Ok(value) => Response::new_ok(id, value),
+            Err(err) => Response::new_err(id, ErrorCode::InvalidParams as i32, err.to_string()),
+        }
+    }
+
+    /// Applies a document notification and returns the URI whose diagnostics
+    /// need republishing.
+    fn handle_notification(&mut self, notification: Notification) -> Result<Option<Uri>> {
+        let Notification { method, params } = notification;
+        match method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params: DidOpenTextDocumentParams = serde_json::from_value(params)?;
+                let doc = Document::new(&mut self.parser, params.text_document.text);
+                self.documents.insert(params.text_document.uri.clone(), doc);
+                Ok(Some(params.text_document.uri))
+            }
+            DidChangeTextDocument::METHOD => {
+                let params: DidChangeTextDocumentParams = serde_json::from_value(params)?;
+                let uri = params.text_document.uri;
+                let Some(doc) = self.documents.get_mut(&uri) else {
+                    return Ok(None);
+                };
+                for change in params.content_changes {
+                    doc.edit(&mut self.parser, change.range, &change.text, self.encoding);
+                }
+                Ok(Some(uri))
+            }
+            DidCloseTextDocument::METHOD => {
+                let params: DidCloseTextDocumentParams = serde_json::from_value(params)?;
+                self.documents.remove(&params.text_document.uri);
+                // Publishing for a closed document clears its diagnostics.
+                Ok(Some(params.text_document.uri))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn diagnostics(&self, uri: Uri) -> PublishDiagnosticsParams {
+        let diagnostics = self
+            .documents
+            .get(&uri)
+            .map(|doc| diagnostics::syntax_diagnostics(doc, &uri, self.encoding))
+            .unwrap_or_default();
+        PublishDiagnosticsParams::new(uri, diagnostics, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bad_notification_keeps_running() {
+        let (server, client) = Connection::memory();
+        let thread = std::thread::spawn(move || Server::new(Encoding::Utf16).run(&server));
+
+        let notify = |method: &str, params: serde_json::Value| {
+            let notification = Notification::new(method.to_string(), params);
+            client
+                .sender
+                .send(Message::Notification(notification))
+                .unwrap();
+        };
+        let uri = "file:///a.gsx";
+        notify(
+            DidChangeTextDocument::METHOD,
+            serde_json::json!({ "textDocument": { "uri": uri } }),
+        );
+        notify(
+            DidOpenTextDocument::METHOD,
+            serde_json::json!({
+                "textDocument": { "uri": uri, "languageId": "gsx", "version": 1, "text": "templ A() {\n}\n" },
+            }),
+        );
+
+        let Message::Notification(log) = client.receiver.recv().unwrap() else {
+            panic!("expected a notification");
+        };
+        assert_eq!(log.method, LogMessage::METHOD);
+        let log: LogMessageParams = serde_json::from_value(log.params).unwrap();
+        assert_eq!(log.typ, MessageType::ERROR);
+        assert!(
+            log.message
+                .starts_with("invalid textDocument/didChange params: "),
+            "{}",
+            log.message
+        );
+
+        let Message::Notification(published) = client.receiver.recv().unwrap() else {
+            panic!("expected a notification");
+        };
+        assert_eq!(published.method, PublishDiagnostics::METHOD);
+
+        let shutdown = Request::new(1.into(), "shutdown".to_string(), serde_json::Value::Null);
+        client.sender.send(Message::Request(shutdown)).unwrap();
+        assert!(matches!(
+            client.receiver.recv().unwrap(),
+            Message::Response(_)
+        ));
+        notify("exit", serde_json::Value::Null);
+        thread.join().unwrap().unwrap();
+    }
+}
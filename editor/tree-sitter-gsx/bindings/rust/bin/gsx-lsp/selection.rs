@@ -0,0 +1,83 @@
+//! Selection ranges.
+//!
+//! Each position expands through the syntax nodes that contain it, from the
+//! innermost token out to the whole file. Nodes with the same extent as their
+//! child are skipped so every step grows the selection.
+
+use lsp_types::{Position, SelectionRange};
+
+use crate::document::{Document, Encoding};
+
+/// Returns the selection range chain for each of `positions`.
+pub fn selection_ranges(
+    doc: &Document,
+    positions: &[Position],
+    encoding: Encoding,
+) -> Vec<SelectionRange> {
+    positions
+        .iter()
+        .map(|&position| selection_range(doc, position, encoding))
+        .collect()
+}
+
+fn selection_range(doc: &Document, position: Position, encoding: Encoding) -> SelectionRange {
+    let offset = doc.offset(position, encoding);
+    let root = doc.tree().root_node();
+    let mut node = root
+        .descendant_for_byte_range(offset, offset)
+        .unwrap_or(root);
+
+    let mut spans = vec![node.byte_range()];
+    while let Some(parent) = node.parent() {
+        node = parent;
+        if spans.last() != Some(&node.byte_range()) {
+            spans.push(node.byte_range());
+        }
+    }
+
+    let mut chain: Option<SelectionRange> = None;
+    for span in spans.into_iter().rev() {
+        chain = Some(SelectionRange {
+            range: doc.range(span.start, span.end, encoding),
+            parent: chain.map(Box::new),
+        });
+    }
+    chain.expect("the root is always in the chain")
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::Range;
+    use tree_sitter::Parser;
+
+    use super::*;
+
+    #[test]
+    fn test_selection_expands_to_root() {
+        let code = "templ Card(title string) {\n\t<span>{title}</span>\n}\n";
+        let mut parser = Parser::new();
+        parser.set_language(&tree_sitter_gsx::language()).unwrap();
+        let doc = Document::new(&mut parser, code.to_string());
+
+        let ranges = selection_ranges(&doc, &[Position::new(1, 9)], Encoding::Utf16);
+        let mut chain = Vec::new();
+        let mut current = Some(&ranges[0]);
+        while let Some(range) = current {
+            chain.push(range.range);
+            current = range.parent.as_deref();
+        }
+
+        let r = |sl, sc, el, ec| Range::new(Position::new(sl, sc), Position::new(el, ec));
+        assert_eq!(
+            chain,
+            [
+                r(1, 8, 1, 13), // title
+                r(1, 7, 1, 14), // {title}
+                r(1, 1, 1, 21), // <span>{title}</span>
+                r(0, 25, 2, 1), // component body
+                r(0, 0, 2, 1),  // component declaration
+                r(0, 0, 3, 0),  // source file
+            ]
+        );
+    }
+}
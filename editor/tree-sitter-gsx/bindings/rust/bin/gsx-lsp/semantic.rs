@@ -0,0 +1,88 @@
+//! Semantic tokens, from [`tree_sitter_gsx::semantic`].
+
+use lsp_types::{
+    Position, Range, SemanticToken, SemanticTokenModifier, SemanticTokenType, SemanticTokens,
+    SemanticTokensLegend,
+};
+use tree_sitter_gsx::semantic;
+
+use crate::document::{Document, Encoding};
+
+/// Returns the legend, which is the one `tui lsp` advertises.
+pub fn legend() -> SemanticTokensLegend {
+    SemanticTokensLegend {
+        token_types: semantic::TOKEN_TYPES.map(SemanticTokenType::new).to_vec(),
+        token_modifiers: semantic::TOKEN_MODIFIERS
+            .map(SemanticTokenModifier::new)
+            .to_vec(),
+    }
+}
+
+/// Returns the delta-encoded tokens of `doc`, or of the tokens starting in
+/// `range`.
+pub fn semantic_tokens(doc: &Document, range: Option<Range>, encoding: Encoding) -> SemanticTokens {
+    let tokens = match range {
+        Some(range) => semantic::semantic_tokens_range(
+            doc.tree(),
+            doc.text(),
+            doc.point(doc.offset(range.start, encoding)),
+            doc.point(doc.offset(range.end, encoding)),
+        ),
+        None => semantic::semantic_tokens(doc.tree(), doc.text()),
+    };
+    let tokens: Vec<_> = tokens
+        .into_iter()
+        .map(|token| {
+            let line_start = doc.offset(Position::new(token.line as u32, 0), encoding);
+            let start = line_start + token.start;
+            let first = doc.position(start, encoding).character as usize;
+            let last = doc.position(start + token.length, encoding).character as usize;
+            semantic::SemanticToken {
+                start: first,
+                length: last - first,
+                ..token
+            }
+        })
+        .collect();
+    let data = semantic::encode(&tokens)
+        .chunks_exact(5)
+        .map(|chunk| SemanticToken {
+            delta_line: chunk[0],
+            delta_start: chunk[1],
+            length: chunk[2],
+            token_type: chunk[3],
+            token_modifiers_bitset: chunk[4],
+        })
+        .collect();
+    SemanticTokens {
+        result_id: None,
+        data,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter::Parser;
+
+    use super::*;
+
+    #[test]
+    fn test_columns_follow_the_encoding() {
+        let code = "templ A() {\n\t<span>{\"é\" + x}</span>\n}\n";
+        let mut parser = Parser::new();
+        parser.set_language(&tree_sitter_gsx::language()).unwrap();
+        let doc = Document::new(&mut parser, code.to_string());
+
+        // The string is four bytes but three UTF-16 units wide.
+        let range = Range::new(Position::new(1, 0), Position::new(2, 0));
+        let tokens = semantic_tokens(&doc, Some(range), Encoding::Utf16).data;
+        let spans: Vec<_> = tokens
+            .iter()
+            .map(|t| (t.delta_line, t.delta_start, t.length, t.token_type))
+            .collect();
+        assert_eq!(spans, [(1, 2, 4, 7), (0, 6, 3, 8)]);
+
+        let tokens = semantic_tokens(&doc, Some(range), Encoding::Utf8).data;
+        assert_eq!(tokens[1].length, 4);
+    }
+}
@@ -0,0 +1,242 @@
+//! Document symbols.
+//!
+//! The outline follows the tuigen-based server: one function symbol per
+//! component and per Go function, with the let bindings and `id`-carrying
+//! elements at the top of a component body nested under it.
+
+use lsp_types::{DocumentSymbol, SymbolKind};
+use tree_sitter::Node;
+use tree_sitter_gsx::ast::{
+    AstNode, AttributeValue, Child, ComponentDeclaration, ElementKind, ElementWithChildrenChild,
+    FunctionDeclaration, ParameterListChild, SourceFile, SourceFileChild,
+};
+
+use crate::document::{Document, Encoding};
+
+/// Returns the outline of `doc`.
+pub fn document_symbols(doc: &Document, encoding: Encoding) -> Vec<DocumentSymbol> {
+    let Some(file) = SourceFile::cast(doc.tree().root_node()) else {
+        return Vec::new();
+    };
+    let cx = Context { doc, encoding };
+
+    let mut components = Vec::new();
+    let mut funcs = Vec::new();
+    for item in file.children() {
+        match item {
+            SourceFileChild::ComponentDeclaration(decl) => components.extend(cx.component(decl)),
+            SourceFileChild::FunctionDeclaration(decl) => funcs.extend(cx.function(decl)),
+            _ => {}
+        }
+    }
+    components.extend(funcs);
+    components
+}
+
+struct Context<'a> {
+    doc: &'a Document,
+    encoding: Encoding,
+}
+
+impl Context<'_> {
+    fn text(&self, node: Node) -> &str {
+        &self.doc.text()[node.byte_range()]
+    }
+
+    fn symbol(
+        &self,
+        name: String,
+        detail: String,
+        kind: SymbolKind,
+        node: Node,
+        selection: Node,
+    ) -> DocumentSymbol {
+        #[allow(deprecated)]
+        DocumentSymbol {
+            name,
+            detail: Some(detail),
+            kind,
+            tags: None,
+            deprecated: None,
+            range: self.doc.node_range(node, self.encoding),
+            selection_range: self.doc.node_range(selection, self.encoding),
+            children: None,
+        }
+    }
+
+    fn component(&self, decl: ComponentDeclaration) -> Option<DocumentSymbol> {
+        let name = decl.name()?;
+        let params: Vec<String> = decl
+            .parameters()
+            .into_iter()
+            .flat_map(|list| list.children())
+            .map(|param| match param {
+                ParameterListChild::Parameter(param) => {
+                    let names: Vec<&str> = param.name().map(|n| self.text(n.syntax())).collect();
+                    let ty = param.r#type().map_or("", |t| self.text(t.syntax()));
+                    if names.is_empty() {
+                        ty.to_string()
+                    } else {
+                        format!("{} {ty}", names.join(", "))
+                    }
+                }
+                ParameterListChild::VariadicParameter(param) => {
+                    let name = param.name().map_or("", |n| self.text(n.syntax()));
+                    let ty = param.r#type().map_or("", |t| self.text(t.syntax()));
+                    format!("{name} ...{ty}")
+                }
+            })
+            .collect();
+
+        let mut symbol = self.symbol(
+            self.text(name.syntax()).to_string(),
+            format!("({})", params.join(", ")),
+            SymbolKind::FUNCTION,
+            decl.syntax(),
+            name.syntax(),
+        );
+        let children: Vec<DocumentSymbol> = decl
+            .body()
+            .into_iter()
+            .flat_map(|body| body.children())
+            .filter_map(|child| self.body_child(child))
+            .collect();
+        if !children.is_empty() {
+            symbol.children = Some(children);
+        }
+        Some(symbol)
+    }
+
+    fn body_child(&self, child: Child) -> Option<DocumentSymbol> {
+        match child {
+            Child::LetBinding(binding) => {
+                let name = binding.name()?;
+                Some(self.symbol(
+                    self.text(name.syntax()).to_string(),
+                    "let binding".to_string(),
+                    SymbolKind::VARIABLE,
+                    binding.syntax(),
+                    name.syntax(),
+                ))
+            }
+            Child::Element(element) => {
+                let (tag, attrs) = match element.child()? {
+                    ElementKind::SelfClosingElement(e) => (e.tag()?, e.children().collect()),
+                    ElementKind::ElementWithChildren(e) => (
+                        e.tag()?,
+                        e.children()
+                            .filter_map(|c| match c {
+                                ElementWithChildrenChild::Attribute(a) => Some(a),
+                                _ => None,
+                            })
+                            .collect::<Vec<_>>(),
+                    ),
+                };
+                let (attr, id) = attrs.into_iter().find_map(|attr| {
+                    let name = attr.name()?;
+                    if self.text(name.syntax()) != "id" {
+                        return None;
+                    }
+                    match attr.value()? {
+                        AttributeValue::String(s) => Some((attr, s)),
+                        _ => None,
+                    }
+                })?;
+                let id = self.text(id.syntax());
+                Some(self.symbol(
+                    id[1..id.len() - 1].to_string(),
+                    format!("<{}>", self.text(tag.syntax())),
+                    SymbolKind::FIELD,
+                    element.syntax(),
+                    attr.syntax(),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    fn function(&self, decl: FunctionDeclaration) -> Option<DocumentSymbol> {
+        let name = decl.name()?;
+        Some(self.symbol(
+            self.text(name.syntax()).to_string(),
+            "func".to_string(),
+            SymbolKind::FUNCTION,
+            decl.syntax(),
+            name.syntax(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::{Position, Range};
+    use tree_sitter::Parser;
+
+    use super::*;
+
+    fn symbols(code: &str) -> Vec<DocumentSymbol> {
+        let mut parser = Parser::new();
+        parser.set_language(&tree_sitter_gsx::language()).unwrap();
+        let doc = Document::new(&mut parser, code.to_string());
+        document_symbols(&doc, Encoding::Utf16)
+    }
+
+    #[test]
+    fn test_component_and_func_symbols() {
+        let code = "package main\n\nfunc helper(s string) string {\n\treturn s\n}\n\ntempl Card(title string, count int) {\n\tlabel := <span>{title}</span>\n\t<div id=\"main\" class=\"p-1\">\n\t\t<span id=\"nested\" />\n\t</div>\n\t<hr />\n}\n";
+        let symbols = symbols(code);
+
+        let names: Vec<_> = symbols.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["Card", "helper"]);
+
+        let card = &symbols[0];
+        assert_eq!(card.kind, SymbolKind::FUNCTION);
+        assert_eq!(card.detail.as_deref(), Some("(title string, count int)"));
+        assert_eq!(
+            card.range,
+            Range::new(Position::new(6, 0), Position::new(12, 1))
+        );
+        assert_eq!(
+            card.selection_range,
+            Range::new(Position::new(6, 6), Position::new(6, 10))
+        );
+
+        let children = card.children.as_ref().unwrap();
+        let summary: Vec<_> = children
+            .iter()
+            .map(|s| (s.name.as_str(), s.detail.as_deref().unwrap(), s.kind))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("label", "let binding", SymbolKind::VARIABLE),
+                ("main", "<div>", SymbolKind::FIELD),
+            ]
+        );
+        assert_eq!(
+            children[1].selection_range,
+            Range::new(Position::new(8, 6), Position::new(8, 15))
+        );
+
+        let helper = &symbols[1];
+        assert_eq!(helper.detail.as_deref(), Some("func"));
+        assert!(helper.children.is_none());
+    }
+
+    #[test]
+    fn test_component_without_params() {
+        let symbols = symbols("templ (s *Sidebar) Render() {\n\t<div />\n}\n");
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].name, "Render");
+        assert_eq!(symbols[0].detail.as_deref(), Some("()"));
+    }
+
+    #[test]
+    fn test_grouped_and_variadic_params() {
+        let symbols = symbols("templ Row(label, value string, opts ...Option) {\n\t<hr />\n}\n");
+        assert_eq!(
+            symbols[0].detail.as_deref(),
+            Some("(label, value string, opts ...Option)")
+        );
+    }
+}
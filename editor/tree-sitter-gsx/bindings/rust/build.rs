@@ -1,3 +1,6 @@
+#[path = "ast_gen.rs"]
+mod ast_gen;
+
 fn main() {
     let src_dir = std::path::Path::new("src");
 
@@ -11,12 +14,31 @@ fn main() {
     c_config.file(&parser_path);
     println!("cargo:rerun-if-changed={}", parser_path.to_str().unwrap());
 
-    // NOTE: if your language uses an external scanner, uncomment this block:
-    /*
     let scanner_path = src_dir.join("scanner.c");
     c_config.file(&scanner_path);
     println!("cargo:rerun-if-changed={}", scanner_path.to_str().unwrap());
-    */
 
     c_config.compile("tree-sitter-tui");
+
+    generate_ast(src_dir);
+}
+
+fn generate_ast(src_dir: &std::path::Path) {
+    let node_types_path = src_dir.join("node-types.json");
+    let grammar_path = src_dir.join("grammar.json");
+    println!(
+        "cargo:rerun-if-changed={}",
+        node_types_path.to_str().unwrap()
+    );
+    println!("cargo:rerun-if-changed={}", grammar_path.to_str().unwrap());
+    println!("cargo:rerun-if-changed=bindings/rust/ast_gen.rs");
+
+    let node_types = std::fs::read_to_string(&node_types_path).unwrap();
+    let grammar = std::fs::read_to_string(&grammar_path).unwrap();
+    let out_dir = std::path::PathBuf::from(std::env::var("OUT_DIR").unwrap());
+    std::fs::write(
+        out_dir.join("ast.rs"),
+        ast_gen::generate(&node_types, &grammar),
+    )
+    .unwrap();
 }
@@ -0,0 +1,4195 @@
+//! Generates Go code from GSX source the same way `tui generate` does.
+//!
+//! [`generate`] produces the `_gsx.go` file the Go `internal/tuigen` generator
+//! writes: a view struct and constructor for every function component, a
+//! `Render` method with `UpdateProps`, `BindApp` and `UnbindApp` helpers for
+//! every method component, reactive bindings for `tui.State` values, and the
+//! file's own Go declarations passed through unchanged.
+//!
+//! `tui generate` finishes by running its output through `goimports`, which
+//! needs the Go toolchain. The parts the generator relies on are reproduced
+//! here: unused imports are dropped, `fmt` and the go-tui import are added
+//! when generated code needs them, imports are sorted into groups, the
+//! generated lines are aligned the way `gofmt` prints them, and binary
+//! expressions and one-line function bodies are spaced the way `gofmt`
+//! spaces them. Otherwise Go code copied from the source (expressions,
+//! statements, top-level declarations) is emitted as written, so the output
+//! matches `tui generate` byte for byte only when that code is already
+//! `gofmt`-formatted: struct fields, `var` blocks and composite literals
+//! aren't re-aligned.
+//!
+//! A `switch` whose cases only hold Go statements is copied out as written,
+//! as tuigen does. `tui generate` copies every switch that way, so cases
+//! holding elements are generated here like the branches of an `if`.
+//!
+//! Component expressions such as `@c.textarea`, which render a component
+//! stored in a field, aren't supported by the grammar yet and are reported
+//! as syntax errors.
+//!
+//! Unlike `tui generate`, the analyzer's validation isn't run, so a template
+//! with unknown tags or attributes still generates code. A component with
+//! type parameters is an error, because tuigen has no generic components.
+//!
+//! ```
+//! use tree_sitter_gsx::codegen;
+//!
+//! let source = "package main\n\ntempl Hello() {\n\t<span>Hello</span>\n}\n";
+//! let go = codegen::generate(source, "hello.gsx").unwrap();
+//! assert!(go.starts_with("// Code generated by tui generate. DO NOT EDIT.\n"));
+//! assert!(go.contains("func Hello() *HelloView {"));
+//! ```
+
+use std::collections::{BTreeSet, HashSet};
+use std::fmt;
+use std::ops::Range;
+
+use tree_sitter::{Node, Parser, Point, Tree};
+
+use crate::format::{
+    collect_kind, field_text, first_error, go_expression_code, import_group, parse_number, unquote,
+    Number, TUI_IMPORT_PATH,
+};
+use crate::sourcemap::{SourceMap, SourceMapping};
+use crate::tailwind::{parse_classes, text_style_option};
+
+/// An error that prevents code from being generated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CodegenError {
+    /// The file has no `package` clause.
+    MissingPackage,
+    /// The parser couldn't make sense of the source at this position.
+    Syntax { line: usize, column: usize },
+    /// A component declares type parameters, starting at this position.
+    TypeParameters { line: usize, column: usize },
+}
+
+impl fmt::Display for CodegenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CodegenError::MissingPackage => write!(f, "expected package declaration"),
+            CodegenError::Syntax { line, column } => write!(f, "{line}:{column}: syntax error"),
+            CodegenError::TypeParameters { line, column } => {
+                write!(f, "{line}:{column}: components can't have type parameters")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CodegenError {}
+
+/// Parses `source` and generates the Go file for it. `source_file` is the
+/// name written in the `// Source:` header line; pass an empty string to
+/// leave the line out.
+pub fn generate(source: &str, source_file: &str) -> Result<String, CodegenError> {
+    generate_with_source_map(source, source_file).map(|(code, _)| code)
+}
+
+/// Generates the Go file for an already parsed tree. `source` must be the
+/// text `tree` was parsed from.
+pub fn generate_tree(tree: &Tree, source: &str, source_file: &str) -> Result<String, CodegenError> {
+    generate_tree_with_source_map(tree, source, source_file).map(|(code, _)| code)
+}
+
+/// Like [`generate`], but also returns a source map from the generated file
+/// back to `source`. The map covers the Go code copied from the source line
+/// by line: top-level declarations and functions, and Go statements in
+/// component bodies.
+pub fn generate_with_source_map(
+    source: &str,
+    source_file: &str,
+) -> Result<(String, SourceMap), CodegenError> {
+    let mut parser = Parser::new();
+    parser
+        .set_language(&crate::language())
+        .expect("Error loading GSX grammar");
+    let tree = parser
+        .parse(source, None)
+        .expect("parsing without a timeout or cancellation flag can't fail");
+    generate_tree_with_source_map(&tree, source, source_file)
+}
+
+/// Like [`generate_tree`], but also returns a source map as
+/// [`generate_with_source_map`] does.
+pub fn generate_tree_with_source_map(
+    tree: &Tree,
+    source: &str,
+    source_file: &str,
+) -> Result<(String, SourceMap), CodegenError> {
+    let root = tree.root_node();
+    if let Some(node) = first_error(root) {
+        let pos = node.start_position();
+        return Err(CodegenError::Syntax {
+            line: pos.row + 1,
+            column: pos.column + 1,
+        });
+    }
+
+    let mut file = Builder { source }.file(root)?;
+    analyze(&mut file);
+    Ok(Generator::new(&file).file(source_file))
+}
+
+// The document model below mirrors the parts of the tuigen AST the Go
+// generator reads.
+
+struct File {
+    package: String,
+    imports: Vec<Import>,
+    decls: Vec<GoDecl>,
+    funcs: Vec<GoCode>,
+    components: Vec<Component>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Import {
+    alias: String,
+    path: String,
+}
+
+/// A top-level `type`, `var` or `const` declaration.
+struct GoDecl {
+    kind: String,
+    code: GoCode,
+}
+
+/// Go code copied from the source: a top-level declaration or function, or
+/// a statement in a component body.
+struct GoCode {
+    text: String,
+    /// Where each line of `text` starts in the source.
+    starts: Vec<Point>,
+}
+
+struct Component {
+    name: String,
+    receiver: Option<Receiver>,
+    params: Vec<Param>,
+    body: Vec<Item>,
+    accepts_children: bool,
+}
+
+struct Receiver {
+    name: String,
+    ty: String,
+}
+
+struct Param {
+    name: String,
+    ty: String,
+}
+
+enum Item {
+    Element(Element),
+    For(ForLoop),
+    If(IfStmt),
+    Switch(SwitchStmt),
+    Let(LetBinding),
+    Call(ComponentCall),
+    Expr(String),
+    /// A `{name}` reference to a let binding, added as a child as is.
+    RawExpr(String),
+    Text(String),
+    Code(GoCode),
+    ChildrenSlot,
+}
+
+struct Element {
+    tag: String,
+    attrs: Vec<Attribute>,
+    ref_expr: Option<String>,
+    key_expr: Option<String>,
+    children: Vec<Item>,
+}
+
+struct Attribute {
+    name: String,
+    value: AttrValue,
+}
+
+enum AttrValue {
+    Str(String),
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    Expr(String),
+}
+
+struct ForLoop {
+    index: String,
+    value: String,
+    iterable: String,
+    body: Vec<Item>,
+}
+
+struct IfStmt {
+    condition: String,
+    then: Vec<Item>,
+    r#else: Vec<Item>,
+}
+
+/// A switch whose clauses hold elements. A switch of Go statements only is
+/// kept as [`Item::Code`].
+struct SwitchStmt {
+    /// The expression after `switch`, empty for a tagless switch.
+    tag: String,
+    clauses: Vec<SwitchClause>,
+}
+
+struct SwitchClause {
+    /// `case a, b` or `default`, without the colon.
+    label: String,
+    items: Vec<Item>,
+}
+
+struct LetBinding {
+    name: String,
+    value: LetValue,
+}
+
+enum LetValue {
+    Element(Element),
+    Call(ComponentCall),
+}
+
+struct ComponentCall {
+    name: String,
+    args: String,
+    children: Vec<Item>,
+}
+
+/// Builds the document model from a syntax tree.
+struct Builder<'s> {
+    source: &'s str,
+}
+
+impl<'s> Builder<'s> {
+    fn text(&self, node: Node<'_>) -> &'s str {
+        &self.source[node.byte_range()]
+    }
+
+    fn file(&self, root: Node<'_>) -> Result<File, CodegenError> {
+        let mut cursor = root.walk();
+        let children: Vec<Node<'_>> = root
+            .named_children(&mut cursor)
+            .filter(|n| !n.is_extra())
+            .collect();
+
+        let package = children
+            .iter()
+            .find(|n| n.kind() == "package_clause")
+            .ok_or(CodegenError::MissingPackage)?;
+        let mut file = File {
+            package: field_text(*package, "name", self.source).to_string(),
+            imports: Vec::new(),
+            decls: Vec::new(),
+            funcs: Vec::new(),
+            components: Vec::new(),
+        };
+
+        for &child in &children {
+            match child.kind() {
+                "import_section" => {
+                    let mut specs = Vec::new();
+                    collect_kind(child, "import_spec", &mut specs);
+                    for spec in specs {
+                        file.imports.push(Import {
+                            alias: field_text(spec, "alias", self.source).to_string(),
+                            path: field_text(spec, "path", self.source)
+                                .trim_matches('"')
+                                .to_string(),
+                        });
+                    }
+                }
+                "component_declaration" => file.components.push(self.component(child)?),
+                "function_declaration" => file.funcs.push(self.top_level(child, self.text(child))),
+                "type_struct_declaration" => file.decls.push(GoDecl {
+                    kind: "type".to_string(),
+                    code: self.top_level(child, self.text(child)),
+                }),
+                "go_declaration" => {
+                    // tuigen captures a declaration without a body up to the
+                    // end of its line, trailing comment included.
+                    let code = if child.child_by_field_name("body").is_some() {
+                        self.text(child)
+                    } else {
+                        let rest = &self.source[child.end_byte()..];
+                        let end = child.end_byte() + rest.find('\n').unwrap_or(rest.len());
+                        self.source[child.start_byte()..end].trim_end()
+                    };
+                    file.decls.push(GoDecl {
+                        kind: field_text(child, "keyword", self.source).to_string(),
+                        code: self.top_level(child, code),
+                    });
+                }
+                _ => {}
+            }
+        }
+        Ok(file)
+    }
+
+    fn component(&self, node: Node<'_>) -> Result<Component, CodegenError> {
+        if let Some(list) = node.child_by_field_name("type_parameters") {
+            let pos = list.start_position();
+            return Err(CodegenError::TypeParameters {
+                line: pos.row + 1,
+                column: pos.column + 1,
+            });
+        }
+        let receiver = node.child_by_field_name("receiver").map(|recv| Receiver {
+            name: field_text(recv, "name", self.source).to_string(),
+            ty: field_text(recv, "type", self.source).to_string(),
+        });
+
+        let mut params = Vec::new();
+        if let Some(list) = node.child_by_field_name("parameters") {
+            let mut cursor = list.walk();
+            for param in list.named_children(&mut cursor) {
+                let ty = field_text(param, "type", self.source);
+                let ty = match param.kind() {
+                    "parameter" => ty.to_string(),
+                    "variadic_parameter" => format!("...{ty}"),
+                    _ => continue,
+                };
+                // Grouped names like `a, b int` become one parameter each.
+                let mut names = param.walk();
+                let names: Vec<&str> = param
+                    .children_by_field_name("name", &mut names)
+                    .map(|name| self.text(name))
+                    .collect();
+                if names.is_empty() {
+                    params.push(Param {
+                        name: "_".to_string(),
+                        ty: ty.clone(),
+                    });
+                }
+                for name in names {
+                    params.push(Param {
+                        name: name.to_string(),
+                        ty: ty.clone(),
+                    });
+                }
+            }
+        }
+
+        let body = node
+            .child_by_field_name("body")
+            .map(|body| self.block(body))
+            .unwrap_or_default();
+        Ok(Component {
+            name: field_text(node, "name", self.source).to_string(),
+            receiver,
+            params,
+            body,
+            accepts_children: false,
+        })
+    }
+
+    /// Reads the items of a `{ ... }` block.
+    fn block(&self, node: Node<'_>) -> Vec<Item> {
+        let mut cursor = node.walk();
+        let children: Vec<Node<'_>> = node
+            .named_children(&mut cursor)
+            .filter(|n| !n.is_extra())
+            .collect();
+        children.into_iter().filter_map(|n| self.item(n)).collect()
+    }
+
+    fn item(&self, node: Node<'_>) -> Option<Item> {
+        Some(match node.kind() {
+            "element" => Item::Element(self.element(node.named_child(0)?)),
+            "for_statement" => {
+                let clause = node.child_by_field_name("clause")?;
+                Item::For(ForLoop {
+                    index: field_text(clause, "index", self.source).to_string(),
+                    value: field_text(clause, "value", self.source).to_string(),
+                    iterable: field_text(clause, "collection", self.source).to_string(),
+                    body: self.block(node.child_by_field_name("body")?),
+                })
+            }
+            "if_statement" => Item::If(self.if_stmt(node)),
+            "let_binding" => {
+                let value = node.child_by_field_name("value")?;
+                let value = if value.kind() == "component_call" {
+                    LetValue::Call(self.call(value))
+                } else {
+                    LetValue::Element(self.element(value.named_child(0)?))
+                };
+                Item::Let(LetBinding {
+                    name: field_text(node, "name", self.source).to_string(),
+                    value,
+                })
+            }
+            "component_call" => Item::Call(self.call(node)),
+            "go_expression" => match go_expression_code(node, self.source) {
+                // The grammar lexes `{children...}` as an expression, so the
+                // slot is recognised the way tuigen's parser does it.
+                code if code == "children..." => Item::ChildrenSlot,
+                code => Item::Expr(code),
+            },
+            // tuigen keeps a switch as a raw Go statement, so one that only
+            // holds Go statements is copied out the same way.
+            "switch_statement" => match self.switch_stmt(node) {
+                stmt if stmt.clauses.iter().all(|c| only_code(&c.items)) => {
+                    Item::Code(self.code(node))
+                }
+                stmt => Item::Switch(stmt),
+            },
+            "state_declaration" | "go_statement" => Item::Code(self.code(node)),
+            "children_slot" => Item::ChildrenSlot,
+            "text_content" => {
+                let text = unescape_text(self.text(node));
+                Item::Text(text.split_whitespace().collect::<Vec<_>>().join(" "))
+            }
+            _ => return None,
+        })
+    }
+
+    fn if_stmt(&self, node: Node<'_>) -> IfStmt {
+        let then = node
+            .child_by_field_name("consequence")
+            .map(|block| self.block(block))
+            .unwrap_or_default();
+        let r#else = match node.child_by_field_name("alternative") {
+            Some(alt) if alt.kind() == "if_statement" => vec![Item::If(self.if_stmt(alt))],
+            Some(alt) => self.block(alt),
+            None => Vec::new(),
+        };
+        IfStmt {
+            condition: field_text(node, "condition", self.source).to_string(),
+            then,
+            r#else,
+        }
+    }
+
+    fn switch_stmt(&self, node: Node<'_>) -> SwitchStmt {
+        let mut cursor = node.walk();
+        let clauses = node
+            .named_children(&mut cursor)
+            .filter(|n| matches!(n.kind(), "case_clause" | "default_clause"))
+            .map(|clause| {
+                let mut cursor = clause.walk();
+                let mut children = clause.children(&mut cursor);
+                let colon = children.by_ref().find(|n| n.kind() == ":");
+                let label_end = colon.map_or(clause.end_byte(), |n| n.start_byte());
+                let items = children
+                    .filter(|n| n.is_named() && !n.is_extra())
+                    .filter_map(|n| self.item(n))
+                    .collect();
+                SwitchClause {
+                    label: self.source[clause.start_byte()..label_end]
+                        .trim_end()
+                        .to_string(),
+                    items,
+                }
+            })
+            .collect();
+        SwitchStmt {
+            tag: field_text(node, "value", self.source).to_string(),
+            clauses,
+        }
+    }
+
+    fn call(&self, node: Node<'_>) -> ComponentCall {
+        let mut name = field_text(node, "name", self.source).to_string();
+        if let Some(qualifier) = node.child_by_field_name("qualifier") {
+            name = format!("{}.{name}", self.text(qualifier));
+        }
+        let args = node.child_by_field_name("arguments").map_or("", |args| {
+            self.source[args.start_byte() + 1..args.end_byte() - 1].trim()
+        });
+        let children = node
+            .child_by_field_name("children")
+            .map(|block| self.block(block))
+            .unwrap_or_default();
+        ComponentCall {
+            name,
+            args: args.to_string(),
+            children,
+        }
+    }
+
+    fn element(&self, node: Node<'_>) -> Element {
+        let mut attrs = Vec::new();
+        let mut items = Vec::new();
+        let mut in_attrs = true;
+        let mut in_children = false;
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            if child.is_extra() {
+                continue;
+            }
+            match child.kind() {
+                "attribute" if in_attrs => attrs.push(self.attribute(child)),
+                "/" | ">" if in_attrs => {
+                    in_attrs = false;
+                    in_children = child.kind() == ">";
+                }
+                "</" => in_children = false,
+                _ if in_children && child.is_named() => items.extend(self.item(child)),
+                _ => {}
+            }
+        }
+
+        let ref_expr = take_expr_attr(&mut attrs, "ref");
+        let key_expr = take_expr_attr(&mut attrs, "key");
+        Element {
+            tag: field_text(node, "tag", self.source).to_string(),
+            attrs,
+            ref_expr,
+            key_expr,
+            children: items,
+        }
+    }
+
+    fn attribute(&self, node: Node<'_>) -> Attribute {
+        let value = match node.child_by_field_name("value") {
+            None => AttrValue::Bool(true),
+            Some(value) => match value.kind() {
+                "string" => AttrValue::Str(unquote(self.text(value))),
+                "raw_string" => {
+                    let text = self.text(value);
+                    AttrValue::Str(text[1..text.len() - 1].to_string())
+                }
+                "number" => match parse_number(self.text(value)) {
+                    Number::Int(n) => AttrValue::Int(n),
+                    Number::Float(f) => AttrValue::Float(f),
+                    Number::Go => AttrValue::Expr(self.text(value).to_string()),
+                },
+                "go_expression" => AttrValue::Expr(go_expression_code(value, self.source)),
+                "true" => AttrValue::Bool(true),
+                "false" => AttrValue::Bool(false),
+                _ => AttrValue::Expr(self.text(value).to_string()),
+            },
+        };
+        Attribute {
+            name: field_text(node, "name", self.source).to_string(),
+            value,
+        }
+    }
+
+    /// Returns top-level Go code starting at `node`.
+    fn top_level(&self, node: Node<'_>, text: &str) -> GoCode {
+        let start = node.start_position();
+        let starts = (0..text.split('\n').count())
+            .map(|i| match i {
+                0 => start,
+                i => Point::new(start.row + i, 0),
+            })
+            .collect();
+        GoCode {
+            text: text.to_string(),
+            starts,
+        }
+    }
+
+    /// Returns a Go statement with its continuation lines stripped of the
+    /// indentation of the line it starts on.
+    fn code(&self, node: Node<'_>) -> GoCode {
+        let start = node.start_position();
+        let prefix = &self.source[node.start_byte() - start.column..node.start_byte()];
+        let indent = &prefix[..prefix.len() - prefix.trim_start().len()];
+        let mut lines = Vec::new();
+        let mut starts = Vec::new();
+        for (i, line) in self.text(node).split('\n').enumerate() {
+            if i == 0 {
+                lines.push(line);
+                starts.push(start);
+            } else if let Some(stripped) = line.strip_prefix(indent) {
+                lines.push(stripped);
+                starts.push(Point::new(start.row + i, indent.len()));
+            } else {
+                lines.push(line);
+                starts.push(Point::new(start.row + i, 0));
+            }
+        }
+        GoCode {
+            text: lines.join("\n"),
+            starts,
+        }
+    }
+}
+
+/// Moves the first `name={expr}` attribute out of `attrs`.
+fn take_expr_attr(attrs: &mut Vec<Attribute>, name: &str) -> Option<String> {
+    let index = attrs
+        .iter()
+        .position(|attr| attr.name == name && matches!(attr.value, AttrValue::Expr(_)))?;
+    match attrs.remove(index).value {
+        AttrValue::Expr(code) => Some(code),
+        _ => unreachable!(),
+    }
+}
+
+/// Replaces the escapes the scanner accepts in element text.
+fn unescape_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if ch == '\\' {
+            if let Some(&next) = chars.peek() {
+                if matches!(next, '{' | '}' | '@' | '<' | '>' | '\\') {
+                    out.push(next);
+                    chars.next();
+                    continue;
+                }
+            }
+        }
+        out.push(ch);
+    }
+    out
+}
+
+/// Runs the parts of the tuigen analyzer the generator depends on: children
+/// slot detection, rewriting `{name}` references to let bindings, and adding
+/// the go-tui import.
+fn analyze(file: &mut File) {
+    let mut let_names = HashSet::new();
+    for comp in &mut file.components {
+        comp.accepts_children = contains_children_slot(&comp.body);
+        collect_let_names(&comp.body, &mut let_names);
+    }
+    for comp in &mut file.components {
+        transform_let_refs(&mut comp.body, &let_names);
+    }
+
+    let has_tui = file.imports.iter().any(|imp| imp.path == TUI_IMPORT_PATH);
+    if !file.components.is_empty() && !has_tui {
+        file.imports.push(Import {
+            alias: "tui".to_string(),
+            path: TUI_IMPORT_PATH.to_string(),
+        });
+    }
+}
+
+fn contains_children_slot(items: &[Item]) -> bool {
+    items.iter().any(|item| match item {
+        Item::ChildrenSlot => true,
+        Item::Element(e) => contains_children_slot(&e.children),
+        Item::Let(l) => match &l.value {
+            LetValue::Element(e) => contains_children_slot(&e.children),
+            LetValue::Call(c) => contains_children_slot(&c.children),
+        },
+        Item::For(f) => contains_children_slot(&f.body),
+        Item::If(i) => contains_children_slot(&i.then) || contains_children_slot(&i.r#else),
+        Item::Switch(s) => s.clauses.iter().any(|c| contains_children_slot(&c.items)),
+        Item::Call(c) => contains_children_slot(&c.children),
+        _ => false,
+    })
+}
+
+/// Reports whether `items` are all Go statements.
+fn only_code(items: &[Item]) -> bool {
+    items.iter().all(|item| matches!(item, Item::Code(_)))
+}
+
+fn collect_let_names(items: &[Item], names: &mut HashSet<String>) {
+    for item in items {
+        match item {
+            Item::Let(l) => {
+                names.insert(l.name.clone());
+                match &l.value {
+                    LetValue::Element(e) => collect_let_names(&e.children, names),
+                    LetValue::Call(c) => collect_let_names(&c.children, names),
+                }
+            }
+            Item::Element(e) => collect_let_names(&e.children, names),
+            Item::For(f) => collect_let_names(&f.body, names),
+            Item::If(i) => {
+                collect_let_names(&i.then, names);
+                collect_let_names(&i.r#else, names);
+            }
+            Item::Switch(s) => {
+                for clause in &s.clauses {
+                    collect_let_names(&clause.items, names);
+                }
+            }
+            Item::Call(c) => collect_let_names(&c.children, names),
+            _ => {}
+        }
+    }
+}
+
+fn transform_let_refs(items: &mut [Item], names: &HashSet<String>) {
+    for item in items {
+        match item {
+            Item::Expr(code) if is_simple_identifier(code) && names.contains(code.as_str()) => {
+                *item = Item::RawExpr(std::mem::take(code));
+            }
+            Item::Element(e) => transform_let_refs(&mut e.children, names),
+            Item::Let(l) => match &mut l.value {
+                LetValue::Element(e) => transform_let_refs(&mut e.children, names),
+                LetValue::Call(c) => transform_let_refs(&mut c.children, names),
+            },
+            Item::For(f) => transform_let_refs(&mut f.body, names),
+            Item::If(i) => {
+                transform_let_refs(&mut i.then, names);
+                transform_let_refs(&mut i.r#else, names);
+            }
+            Item::Switch(s) => {
+                for clause in &mut s.clauses {
+                    transform_let_refs(&mut clause.items, names);
+                }
+            }
+            Item::Call(c) => transform_let_refs(&mut c.children, names),
+            _ => {}
+        }
+    }
+}
+
+/// Reports whether `s` is an ASCII Go identifier.
+fn is_simple_identifier(s: &str) -> bool {
+    let mut bytes = s.bytes();
+    bytes
+        .next()
+        .is_some_and(|b| b.is_ascii_alphabetic() || b == b'_')
+        && bytes.all(is_word_byte)
+}
+
+fn is_word_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// Skips the ASCII whitespace `\s` matches, requiring at least `min` bytes.
+fn skip_space(s: &str, min: usize) -> Option<&str> {
+    let rest = s.trim_start_matches(|c: char| c.is_ascii_whitespace());
+    (s.len() - rest.len() >= min).then_some(rest)
+}
+
+/// Returns the run of word characters that `s` ends with.
+fn trailing_word(s: &str) -> &str {
+    &s[s.trim_end_matches(is_word_char).len()..]
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum RefKind {
+    Single,
+    List,
+    Map,
+}
+
+/// An element reference declared with `ref={name}`.
+struct RefInfo {
+    name: String,
+    export_name: String,
+    kind: RefKind,
+    key_type: &'static str,
+    in_conditional: bool,
+}
+
+fn collect_refs(items: &[Item], in_loop: bool, in_conditional: bool, refs: &mut Vec<RefInfo>) {
+    for item in items {
+        match item {
+            Item::Element(e) => {
+                if let Some(name) = &e.ref_expr {
+                    let mut chars = name.chars();
+                    let export_name = chars
+                        .next()
+                        .map(|first| first.to_uppercase().chain(chars).collect())
+                        .unwrap_or_default();
+                    let (kind, key_type) = match &e.key_expr {
+                        Some(key) => (RefKind::Map, infer_key_type(key)),
+                        None if in_loop => (RefKind::List, ""),
+                        None => (RefKind::Single, ""),
+                    };
+                    refs.push(RefInfo {
+                        name: name.clone(),
+                        export_name,
+                        kind,
+                        key_type,
+                        in_conditional,
+                    });
+                }
+                collect_refs(&e.children, in_loop, in_conditional, refs);
+            }
+            Item::Let(l) => match &l.value {
+                LetValue::Element(e) => collect_refs(&e.children, in_loop, in_conditional, refs),
+                LetValue::Call(c) => collect_refs(&c.children, in_loop, in_conditional, refs),
+            },
+            Item::For(f) => collect_refs(&f.body, true, in_conditional, refs),
+            Item::If(i) => {
+                collect_refs(&i.then, in_loop, true, refs);
+                collect_refs(&i.r#else, in_loop, true, refs);
+            }
+            Item::Switch(s) => {
+                for clause in &s.clauses {
+                    collect_refs(&clause.items, in_loop, true, refs);
+                }
+            }
+            Item::Call(c) => collect_refs(&c.children, in_loop, in_conditional, refs),
+            _ => {}
+        }
+    }
+}
+
+fn infer_key_type(expr: &str) -> &'static str {
+    if expr.ends_with(".ID") || expr.ends_with(".Id") {
+        "string"
+    } else if expr.contains("int") || expr.contains("Int") {
+        "int"
+    } else {
+        "string"
+    }
+}
+
+/// A `tui.State` value: a `*tui.State[T]` parameter or a local
+/// `x := tui.NewState(...)` declaration.
+struct StateVar {
+    name: String,
+    ty: String,
+    is_param: bool,
+}
+
+fn detect_state_vars(comp: &Component) -> Vec<StateVar> {
+    let mut vars = Vec::new();
+    for param in &comp.params {
+        if let Some(ty) = state_param_type(&param.ty) {
+            vars.push(StateVar {
+                name: param.name.clone(),
+                ty: ty.to_string(),
+                is_param: true,
+            });
+        }
+    }
+    for item in &comp.body {
+        if let Item::Code(code) = item {
+            for (name, init) in constructor_calls(&code.text, "tui.NewState") {
+                vars.push(StateVar {
+                    name: name.to_string(),
+                    ty: infer_type(init),
+                    is_param: false,
+                });
+            }
+        }
+    }
+    vars
+}
+
+/// Returns the names of local `x := tui.NewEvents(...)` declarations.
+fn detect_events_vars(comp: &Component) -> Vec<String> {
+    let mut vars = Vec::new();
+    for item in &comp.body {
+        if let Item::Code(code) = item {
+            for (name, _) in constructor_calls(&code.text, "tui.NewEvents") {
+                vars.push(name.to_string());
+            }
+        }
+    }
+    vars
+}
+
+/// Matches `\*?tui\.State\[(.+)\]$`, returning the element type.
+fn state_param_type(ty: &str) -> Option<&str> {
+    let end = ty.strip_suffix(']')?.len();
+    ty.match_indices("tui.State[")
+        .map(|(i, m)| i + m.len())
+        .find(|&start| start < end)
+        .map(|start| &ty[start..end])
+}
+
+/// Finds `name := <function>(args)` declarations, returning each name and
+/// the argument text. For `tui.NewState` the arguments run to the last `)`
+/// on the line, like the greedy Go regex; for `tui.NewEvents` an optional
+/// type argument list is allowed and the arguments end at the first `)`.
+fn constructor_calls<'c>(code: &'c str, function: &str) -> Vec<(&'c str, &'c str)> {
+    let mut calls = Vec::new();
+    for (at, _) in code.match_indices(function) {
+        let Some(before) = code[..at]
+            .trim_end_matches(|c: char| c.is_ascii_whitespace())
+            .strip_suffix(":=")
+        else {
+            continue;
+        };
+        let name = trailing_word(before.trim_end_matches(|c: char| c.is_ascii_whitespace()));
+        if name.is_empty() {
+            continue;
+        }
+        let rest = &code[at + function.len()..];
+        let args = if function == "tui.NewState" {
+            rest.strip_prefix('(').and_then(|rest| {
+                let line = &rest[..rest.find('\n').unwrap_or(rest.len())];
+                line.rfind(')').filter(|&i| i > 0).map(|i| &line[..i])
+            })
+        } else {
+            events_args(rest)
+        };
+        if let Some(args) = args {
+            calls.push((name, args));
+        }
+    }
+    calls
+}
+
+/// Matches `(?:\[.+\])?\([^)]*\)` at the start of `rest`.
+fn events_args(rest: &str) -> Option<&str> {
+    let call = |s: &str| -> Option<usize> {
+        let inner = s.strip_prefix('(')?;
+        inner.find(')').map(|i| i + 1)
+    };
+    if let Some(types) = rest.strip_prefix('[') {
+        let line = &types[..types.find('\n').unwrap_or(types.len())];
+        for (i, _) in line.rmatch_indices(']').filter(|&(i, _)| i > 0) {
+            if let Some(len) = call(&types[i + 1..]) {
+                return Some(&types[i + 2..i + 1 + len]);
+            }
+        }
+        return None;
+    }
+    call(rest).map(|len| &rest[1..len])
+}
+
+/// Guesses the type of a `tui.NewState` initializer, like tuigen's
+/// `inferTypeFromExpr`.
+fn infer_type(expr: &str) -> String {
+    let expr = expr.trim();
+    let digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    let unsigned = expr.strip_prefix('-').unwrap_or(expr);
+    if digits(unsigned) {
+        return "int".to_string();
+    }
+    if let Some((whole, frac)) = unsigned.split_once('.') {
+        if digits(whole) && digits(frac) {
+            return "float64".to_string();
+        }
+    }
+    if expr == "true" || expr == "false" {
+        return "bool".to_string();
+    }
+    if (expr.len() >= 2 && expr.starts_with('"') && expr.ends_with('"'))
+        || (expr.len() >= 2 && expr.starts_with('`') && expr.ends_with('`'))
+        || expr == "\""
+        || expr == "`"
+    {
+        return "string".to_string();
+    }
+    if expr == "nil" {
+        return "any".to_string();
+    }
+    if let Some(name) = expr.strip_prefix("[]").and_then(type_name_before_brace) {
+        return format!("[]{name}");
+    }
+    if let Some(rest) = expr.strip_prefix("map[") {
+        let key_len = rest.len() - rest.trim_start_matches(is_word_char).len();
+        if key_len > 0 {
+            if let Some(value) = rest[key_len..]
+                .strip_prefix(']')
+                .and_then(type_name_before_brace)
+            {
+                return format!("map[{}]{value}", &rest[..key_len]);
+            }
+        }
+    }
+    if let Some(name) = expr.strip_prefix('&').and_then(type_name_before_brace) {
+        return format!("*{name}");
+    }
+    if let Some(name) = type_name_before_brace(expr) {
+        return name.to_string();
+    }
+    "any".to_string()
+}
+
+/// Matches `^(\w+(?:\.\w+)?)\{`, returning the type name.
+fn type_name_before_brace(s: &str) -> Option<&str> {
+    let word = |s: &str| s.len() - s.trim_start_matches(is_word_char).len();
+    let mut len = word(s);
+    if len == 0 {
+        return None;
+    }
+    if let Some(rest) = s[len..].strip_prefix('.') {
+        let more = word(rest);
+        if more > 0 && rest[more..].starts_with('{') {
+            len += 1 + more;
+        }
+    }
+    s[len..].starts_with('{').then(|| &s[..len])
+}
+
+/// Returns the state names used through `x.Get()` or `(*x).Get()` in `expr`,
+/// in order of first use.
+fn get_calls(expr: &str, state_names: &HashSet<&str>) -> Vec<String> {
+    let mut deps: Vec<String> = Vec::new();
+    for (at, _) in expr.match_indices(".Get()") {
+        let before = &expr[..at];
+        let name = match before.strip_suffix(')') {
+            Some(inner) => {
+                let name = trailing_word(inner);
+                let open = &inner[..inner.len() - name.len()];
+                if name.is_empty() || !open.ends_with("(*") {
+                    continue;
+                }
+                name
+            }
+            None => trailing_word(before),
+        };
+        if state_names.contains(name) && !deps.iter().any(|d| d == name) {
+            deps.push(name.to_string());
+        }
+    }
+    deps
+}
+
+/// A reactive text binding: `element.SetText(expr)` whenever a dependency
+/// changes.
+struct StateBinding {
+    deps: Vec<String>,
+    element: String,
+    expr: String,
+}
+
+/// Finds the text bindings tuigen's `DetectStateBindings` reports, naming
+/// elements by the same counter. Bindings for a dynamic `class` are left out
+/// because the generator has no setter for them.
+struct BindingScan<'a> {
+    state_names: &'a HashSet<&'a str>,
+    index: usize,
+    bindings: Vec<StateBinding>,
+}
+
+impl BindingScan<'_> {
+    fn items(&mut self, items: &[Item], in_loop: bool) {
+        for item in items {
+            match item {
+                Item::Element(e) => self.element(e, in_loop),
+                Item::Let(l) => match &l.value {
+                    LetValue::Element(e) => self.items(&e.children, in_loop),
+                    LetValue::Call(c) => self.items(&c.children, in_loop),
+                },
+                Item::For(f) => self.items(&f.body, true),
+                Item::If(i) => {
+                    // Elements under a reactive if are rebuilt by its update
+                    // function instead.
+                    let in_loop = in_loop || !get_calls(&i.condition, self.state_names).is_empty();
+                    self.items(&i.then, in_loop);
+                    self.items(&i.r#else, in_loop);
+                }
+                Item::Switch(s) => {
+                    let in_loop = in_loop
+                        || std::iter::once(&s.tag)
+                            .chain(s.clauses.iter().map(|c| &c.label))
+                            .any(|code| !get_calls(code, self.state_names).is_empty());
+                    for clause in &s.clauses {
+                        self.items(&clause.items, in_loop);
+                    }
+                }
+                Item::Call(c) => self.items(&c.children, in_loop),
+                _ => {}
+            }
+        }
+    }
+
+    fn element(&mut self, e: &Element, in_loop: bool) {
+        let explicit = e
+            .attrs
+            .iter()
+            .find(|attr| attr.name == "deps")
+            .and_then(|attr| explicit_deps(attr, self.state_names));
+        let skip_children = matches!(e.tag.as_str(), "span" | "p") && e.children.len() == 1;
+        let creates_element = |item: &Item| matches!(item, Item::Expr(_) | Item::Text(_));
+
+        if !in_loop {
+            let mut child_index = self.index + 1;
+            for child in &e.children {
+                if let Item::Expr(code) = child {
+                    let deps = explicit
+                        .clone()
+                        .unwrap_or_else(|| get_calls(code, self.state_names));
+                    if !deps.is_empty() {
+                        let target = if skip_children {
+                            self.index
+                        } else {
+                            child_index
+                        };
+                        self.bindings.push(StateBinding {
+                            deps,
+                            element: format!("__tui_{target}"),
+                            expr: code.clone(),
+                        });
+                    }
+                }
+                if !skip_children && creates_element(child) {
+                    child_index += 1;
+                }
+            }
+        }
+
+        self.index += 1;
+        if !skip_children {
+            self.index += e.children.iter().filter(|c| creates_element(c)).count();
+        }
+        self.items(&e.children, in_loop);
+    }
+}
+
+/// Reads `deps={[a, b]}`, keeping the names of known state values.
+fn explicit_deps(attr: &Attribute, state_names: &HashSet<&str>) -> Option<Vec<String>> {
+    let AttrValue::Expr(code) = &attr.value else {
+        return None;
+    };
+    let inner = code.trim().strip_prefix('[')?.strip_suffix(']')?;
+    let deps: Vec<String> = inner
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty() && state_names.contains(name))
+        .map(str::to_string)
+        .collect();
+    (!deps.is_empty()).then_some(deps)
+}
+
+/// Collects the state values a reactive block depends on, in order of first
+/// use.
+struct DepCollector<'a> {
+    state_names: &'a HashSet<&'a str>,
+    deps: Vec<String>,
+}
+
+impl DepCollector<'_> {
+    fn expr(&mut self, expr: &str) {
+        for dep in get_calls(expr, self.state_names) {
+            if !self.deps.contains(&dep) {
+                self.deps.push(dep);
+            }
+        }
+    }
+
+    fn switch(&mut self, s: &SwitchStmt) {
+        self.expr(&s.tag);
+        for clause in &s.clauses {
+            self.expr(&clause.label);
+            self.items(&clause.items);
+        }
+    }
+
+    fn items(&mut self, items: &[Item]) {
+        for item in items {
+            match item {
+                Item::Element(e) => {
+                    for attr in &e.attrs {
+                        if let AttrValue::Expr(code) = &attr.value {
+                            self.expr(code);
+                        }
+                    }
+                    self.items(&e.children);
+                }
+                Item::Expr(code) => self.expr(code),
+                Item::If(i) => {
+                    self.expr(&i.condition);
+                    self.items(&i.then);
+                    self.items(&i.r#else);
+                }
+                Item::Switch(s) => self.switch(s),
+                Item::For(f) => {
+                    self.expr(&f.iterable);
+                    self.items(&f.body);
+                }
+                Item::Call(c) => {
+                    self.expr(&c.args);
+                    self.items(&c.children);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// A function component call, tracked so its view's watchers and app
+/// binding can be wired up once the body is generated.
+struct ComponentVar {
+    name: String,
+    component: String,
+    in_conditional: bool,
+    in_for: bool,
+}
+
+/// One segment of a mount key: a loop index or a `key={...}` expression.
+#[derive(Clone)]
+struct KeySegment {
+    expr: String,
+    from_loop: bool,
+}
+
+/// A struct field parsed from a passthrough `type T struct { ... }`.
+struct StructField {
+    name: String,
+    ty: String,
+}
+
+/// Generates the Go file from the document model, following the Go
+/// generator line for line.
+struct Generator<'f> {
+    file: &'f File,
+    out: Writer,
+    tui_alias: String,
+    is_tui_package: bool,
+    function_templs: HashSet<&'f str>,
+    var_counter: usize,
+    cond_counter: usize,
+    loop_counter: usize,
+    mount_index: usize,
+    loop_depth: usize,
+    mount_key_parts: Vec<KeySegment>,
+    receiver: String,
+    component_vars: Vec<ComponentVar>,
+    refs: Vec<RefInfo>,
+    state_vars: Vec<StateVar>,
+    bindings: Vec<StateBinding>,
+    events_vars: Vec<String>,
+}
+
+impl<'f> Generator<'f> {
+    fn new(file: &'f File) -> Self {
+        let tui_alias = file
+            .imports
+            .iter()
+            .find(|imp| imp.path == TUI_IMPORT_PATH)
+            .map(|imp| imp.alias.as_str())
+            .filter(|alias| !alias.is_empty())
+            .unwrap_or("tui")
+            .to_string();
+        Self {
+            file,
+            out: Writer::new(),
+            tui_alias,
+            is_tui_package: file.package == "tui",
+            function_templs: file
+                .components
+                .iter()
+                .filter(|comp| comp.receiver.is_none())
+                .map(|comp| comp.name.as_str())
+                .collect(),
+            var_counter: 0,
+            cond_counter: 0,
+            loop_counter: 0,
+            mount_index: 0,
+            loop_depth: 0,
+            mount_key_parts: Vec::new(),
+            receiver: String::new(),
+            component_vars: Vec::new(),
+            refs: Vec::new(),
+            state_vars: Vec::new(),
+            bindings: Vec::new(),
+            events_vars: Vec::new(),
+        }
+    }
+
+    fn file(mut self, source_file: &str) -> (String, SourceMap) {
+        for decl in &self.file.decls {
+            self.out.passthrough(&decl.code);
+        }
+        for func in &self.file.funcs {
+            self.out.passthrough(func);
+        }
+        for comp in &self.file.components {
+            self.component(comp);
+        }
+        self.interface_checks();
+
+        let body = gofmt_spacing(&std::mem::take(&mut self.out.buf), &mut self.out.marks);
+        let mut out = String::from("// Code generated by tui generate. DO NOT EDIT.\n");
+        if !source_file.is_empty() {
+            out.push_str(&format!("// Source: {source_file}\n"));
+        }
+        out.push('\n');
+        out.push_str(&format!("package {}\n\n", self.file.package));
+        out.push_str(&import_block(&self.file.imports, &body));
+        let header_lines = out.matches('\n').count();
+        out.push_str(&body);
+        let (code, lines) = tidy(&out);
+
+        let mut map = SourceMap::new(source_file);
+        let mut line = header_lines;
+        let mut counted = 0;
+        for mark in &self.out.marks {
+            line += body[counted..mark.pos].matches('\n').count();
+            counted = mark.pos;
+            map.add_mapping(SourceMapping {
+                go_line: lines[line],
+                go_col: mark.go_col,
+                gsx_line: mark.gsx.row,
+                gsx_col: mark.gsx.column,
+                length: mark.length,
+            });
+        }
+        (code, map)
+    }
+
+    fn line(&mut self, s: &str) {
+        self.out.line(s);
+    }
+
+    fn next_var(&mut self) -> String {
+        let name = format!("__tui_{}", self.var_counter);
+        self.var_counter += 1;
+        name
+    }
+
+    fn push_loop_index(&mut self, index: &str) -> String {
+        let var = if !index.is_empty() && index != "_" {
+            index.to_string()
+        } else {
+            format!("__idx_{}", self.loop_depth)
+        };
+        self.loop_depth += 1;
+        self.mount_key_parts.push(KeySegment {
+            expr: var.clone(),
+            from_loop: true,
+        });
+        var
+    }
+
+    fn pop_loop_index(&mut self) {
+        self.loop_depth = self.loop_depth.saturating_sub(1);
+        self.mount_key_parts.pop();
+    }
+
+    /// Scopes a `key={...}` over the mounts generated until the returned
+    /// segments are restored. The key replaces the innermost loop index and
+    /// nests under another key.
+    fn push_element_key(&mut self, key: &str) -> Vec<KeySegment> {
+        let saved = self.mount_key_parts.clone();
+        let segment = KeySegment {
+            expr: key.to_string(),
+            from_loop: false,
+        };
+        match self.mount_key_parts.last_mut() {
+            Some(last) if last.from_loop => *last = segment,
+            _ => self.mount_key_parts.push(segment),
+        }
+        saved
+    }
+
+    fn mount_key_expr(&self, base: usize) -> String {
+        if self.mount_key_parts.is_empty() {
+            return base.to_string();
+        }
+        let parts: Vec<&str> = self
+            .mount_key_parts
+            .iter()
+            .map(|part| part.expr.as_str())
+            .collect();
+        format!("tui.MountKey({base}, {})", parts.join(", "))
+    }
+
+    fn state_names(&self) -> HashSet<&str> {
+        self.state_vars.iter().map(|sv| sv.name.as_str()).collect()
+    }
+
+    fn state_type(&self, name: &str) -> &str {
+        self.state_vars
+            .iter()
+            .rev()
+            .find(|sv| sv.name == name)
+            .map_or("", |sv| sv.ty.as_str())
+    }
+
+    /// Writes `head(args...)`, one argument per line.
+    fn call_lines(&mut self, head: &str, args: &[String]) {
+        if args.is_empty() {
+            self.line(&format!("{head}()"));
+            return;
+        }
+        self.line(&format!("{head}("));
+        self.out.indent += 1;
+        for arg in args {
+            self.line(&format!("{arg},"));
+        }
+        self.out.indent -= 1;
+        self.line(")");
+    }
+
+    fn component(&mut self, comp: &'f Component) {
+        self.var_counter = 0;
+        self.cond_counter = 0;
+        self.loop_counter = 0;
+        self.mount_index = 0;
+        self.loop_depth = 0;
+        self.mount_key_parts.clear();
+        self.receiver.clear();
+        self.component_vars.clear();
+        self.state_vars.clear();
+        self.bindings.clear();
+        self.events_vars.clear();
+
+        match &comp.receiver {
+            Some(recv) => self.method_component(comp, recv),
+            None => self.function_component(comp),
+        }
+    }
+
+    fn method_component(&mut self, comp: &Component, recv: &Receiver) {
+        self.receiver = recv.name.clone();
+        self.line(&format!(
+            "func ({} {}) Render(app *tui.App) *tui.Element {{",
+            recv.name, recv.ty
+        ));
+        self.out.indent += 1;
+
+        let (root, root_is_component) = self.body(&comp.body, true);
+        self.line("");
+        if root.is_empty() {
+            self.line("return nil");
+        } else if root_is_component {
+            self.line(&format!("return {root}.Root"));
+        } else {
+            self.line(&format!("return {root}"));
+        }
+        self.out.indent -= 1;
+        self.line("}");
+        self.line("");
+
+        self.update_props(recv);
+        self.bind_app(recv);
+        self.unbind_app(recv);
+        self.receiver.clear();
+    }
+
+    /// Generates the top-level body items, returning the root variable and
+    /// whether it holds a view rather than an element.
+    fn body(&mut self, items: &[Item], is_method: bool) -> (String, bool) {
+        let mut root = String::new();
+        let mut root_is_component = false;
+        for item in items {
+            match item {
+                Item::Element(e) => {
+                    let var = self.element(e, "", false, false, false);
+                    if root.is_empty() {
+                        root = var;
+                    }
+                }
+                Item::Let(l) => self.let_binding(l, "", false, false),
+                Item::For(f) => {
+                    if root.is_empty() {
+                        root = self.next_var();
+                        self.line(&format!("var {root} *tui.Element"));
+                    }
+                    self.for_to_root(f, &root, false);
+                }
+                Item::If(i) => {
+                    if root.is_empty() {
+                        root = self.next_var();
+                        self.line(&format!("var {root} *tui.Element"));
+                    }
+                    self.if_to_root(i, &root, false);
+                }
+                Item::Switch(s) => {
+                    if root.is_empty() {
+                        root = self.next_var();
+                        self.line(&format!("var {root} *tui.Element"));
+                    }
+                    self.switch_to_root(s, &root, false);
+                }
+                Item::Code(code) => self.out.go_code(code),
+                Item::Expr(code) => self.out.code(code),
+                Item::Call(c) => {
+                    let var = self.call(c, "", false, false);
+                    if root.is_empty() {
+                        root = var;
+                        // Struct mounts return the element itself.
+                        root_is_component = !is_method || !self.returns_element(c);
+                    }
+                }
+                Item::RawExpr(_) | Item::Text(_) | Item::ChildrenSlot => {}
+            }
+        }
+        (root, root_is_component)
+    }
+
+    fn function_component(&mut self, comp: &Component) {
+        let mut refs = Vec::new();
+        collect_refs(&comp.body, false, false, &mut refs);
+        self.refs = refs;
+        self.state_vars = detect_state_vars(comp);
+        self.events_vars = detect_events_vars(comp);
+        let state_names = self.state_names();
+        let mut scan = BindingScan {
+            state_names: &state_names,
+            index: 0,
+            bindings: Vec::new(),
+        };
+        scan.items(&comp.body, false);
+        self.bindings = scan.bindings;
+
+        let view = format!("{}View", comp.name);
+        self.view_struct(&view);
+
+        let mut params: Vec<String> = comp
+            .params
+            .iter()
+            .map(|param| format!("{} {}", param.name, param.ty))
+            .collect();
+        if comp.accepts_children {
+            params.push("children []*tui.Element".to_string());
+        }
+        self.line(&format!(
+            "func {}({}) *{view} {{",
+            comp.name,
+            params.join(", ")
+        ));
+        self.out.indent += 1;
+        self.line(&format!("var view {view}"));
+        self.line("var watchers []tui.Watcher");
+        self.line("");
+
+        let body_start = self.out.pos();
+        let (root, root_is_component) = self.body(&comp.body, false);
+        self.splice_hoists(body_start);
+
+        if !self.component_vars.is_empty() {
+            self.line("");
+            let vars: Vec<(String, bool, bool)> = self
+                .component_vars
+                .iter()
+                .map(|cv| (cv.name.clone(), cv.in_for, cv.in_conditional))
+                .collect();
+            for (name, in_for, in_conditional) in vars {
+                if in_for {
+                    self.line(&format!("for _, __cv := range {name}_views {{"));
+                    self.out.indent += 1;
+                    self.line("watchers = append(watchers, __cv.GetWatchers()...)");
+                    self.out.indent -= 1;
+                    self.line("}");
+                } else if in_conditional {
+                    self.line(&format!("if {name} != nil {{"));
+                    self.out.indent += 1;
+                    self.line(&format!(
+                        "watchers = append(watchers, {name}.GetWatchers()...)"
+                    ));
+                    self.out.indent -= 1;
+                    self.line("}");
+                } else {
+                    self.line(&format!(
+                        "watchers = append(watchers, {name}.GetWatchers()...)"
+                    ));
+                }
+            }
+        }
+
+        self.state_bindings();
+        self.bind_app_closure();
+
+        self.line("");
+        self.line(&format!("view = {view}{{"));
+        self.out.indent += 1;
+        let root = if root.is_empty() {
+            "nil".to_string()
+        } else if root_is_component {
+            format!("{root}.Root")
+        } else {
+            root
+        };
+        let mut rows = vec![
+            vec!["Root:".to_string(), format!("{root},")],
+            vec!["watchers:".to_string(), "watchers,".to_string()],
+            vec!["bindApp:".to_string(), "__bindApp,".to_string()],
+            vec!["unbindApp:".to_string(), "__unbindApp,".to_string()],
+        ];
+        for r in &self.refs {
+            let accessor = if r.kind == RefKind::Single {
+                "El"
+            } else {
+                "All"
+            };
+            rows.push(vec![
+                format!("{}:", r.export_name),
+                format!("{}.{accessor}(),", r.name),
+            ]);
+        }
+        self.out.rows(&rows);
+        self.out.indent -= 1;
+        self.line("}");
+        self.line("return &view");
+        self.out.indent -= 1;
+        self.line("}");
+        self.line("");
+    }
+
+    fn view_struct(&mut self, view: &str) {
+        self.line(&format!("type {view} struct {{"));
+        self.out.indent += 1;
+        let mut rows = vec![
+            vec!["Root".to_string(), "*tui.Element".to_string()],
+            vec!["watchers".to_string(), "[]tui.Watcher".to_string()],
+            vec!["bindApp".to_string(), "func(*tui.App)".to_string()],
+            vec!["unbindApp".to_string(), "func()".to_string()],
+        ];
+        for r in &self.refs {
+            let mut row = vec![r.export_name.clone()];
+            match r.kind {
+                RefKind::Single => {
+                    row.push("*tui.Element".to_string());
+                    if r.in_conditional {
+                        row.push("// may be nil".to_string());
+                    }
+                }
+                RefKind::List => row.push("[]*tui.Element".to_string()),
+                RefKind::Map => row.push(format!("map[{}]*tui.Element", r.key_type)),
+            }
+            rows.push(row);
+        }
+        self.out.rows(&rows);
+        self.out.indent -= 1;
+        self.line("}");
+        self.line("");
+
+        let lines = [
+            format!("func (v *{view}) UnbindApp() {{"),
+            "\tif v.unbindApp != nil {".to_string(),
+            "\t\tv.unbindApp()".to_string(),
+            "\t}".to_string(),
+            "}".to_string(),
+            String::new(),
+            format!("func (v *{view}) GetRoot() *tui.Element {{ return v.Root }}"),
+            String::new(),
+            format!("func (v *{view}) GetWatchers() []tui.Watcher {{ return v.watchers }}"),
+            String::new(),
+            format!("func (v *{view}) Render(app *tui.App) *tui.Element {{ return v.Root }}"),
+            String::new(),
+            format!("func (v *{view}) BindApp(app *tui.App) {{"),
+            "\tif v.bindApp != nil {".to_string(),
+            "\t\tv.bindApp(app)".to_string(),
+            "\t}".to_string(),
+            "}".to_string(),
+            String::new(),
+            format!("func (v *{view}) UpdateProps(fresh tui.Component) {{"),
+            format!("\tf, ok := fresh.(*{view})"),
+            "\tif !ok {".to_string(),
+            "\t\treturn".to_string(),
+            "\t}".to_string(),
+            "\tv.Root = f.Root".to_string(),
+            "\tv.watchers = f.watchers".to_string(),
+            "\tv.bindApp = f.bindApp".to_string(),
+            "\tv.unbindApp = f.unbindApp".to_string(),
+        ];
+        for line in &lines {
+            self.line(line);
+        }
+        let ref_lines: Vec<String> = self
+            .refs
+            .iter()
+            .map(|r| format!("\tv.{0} = f.{0}", r.export_name))
+            .collect();
+        for line in &ref_lines {
+            self.line(line);
+        }
+        self.line("}");
+        self.line("");
+        for iface in ["AppBinder", "AppUnbinder", "PropsUpdater"] {
+            self.line(&format!("var _ tui.{iface} = (*{view})(nil)"));
+            self.line("");
+        }
+    }
+
+    /// Inserts function-scope declarations for component views created
+    /// inside blocks, which the watcher and binding code refers to.
+    fn splice_hoists(&mut self, pos: usize) {
+        let lines: Vec<String> = self
+            .component_vars
+            .iter()
+            .filter_map(|cv| {
+                if cv.in_for {
+                    Some(format!(
+                        "var {}_views []{}",
+                        cv.name,
+                        view_type_name(&cv.component)
+                    ))
+                } else if cv.in_conditional {
+                    Some(format!("var {} {}", cv.name, view_type_name(&cv.component)))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        self.out.insert_lines(pos, &lines);
+    }
+
+    /// Inserts resets for the view slices of for loops generated since
+    /// `prev_count`, so a reactive update doesn't keep appending to them.
+    fn splice_resets(&mut self, pos: usize, prev_count: usize) {
+        let lines: Vec<String> = self.component_vars[prev_count..]
+            .iter()
+            .filter(|cv| cv.in_for)
+            .map(|cv| format!("{0}_views = {0}_views[:0]", cv.name))
+            .collect();
+        self.out.insert_lines(pos, &lines);
+    }
+
+    fn state_bindings(&mut self) {
+        if self.bindings.is_empty() {
+            return;
+        }
+        self.line("");
+        self.line("// State bindings");
+        let bindings = std::mem::take(&mut self.bindings);
+        for b in &bindings {
+            if let [dep] = b.deps.as_slice() {
+                let ty = self.state_type(dep).to_string();
+                self.line(&format!("{dep}.Bind(func(_ {ty}) {{"));
+                self.out.indent += 1;
+                self.line(&format!("{}.SetText({})", b.element, b.expr));
+                self.out.indent -= 1;
+                self.line("})");
+            } else {
+                let update = format!("__update_{}", b.element);
+                self.line(&format!(
+                    "{update} := func() {{ {}.SetText({}) }}",
+                    b.element, b.expr
+                ));
+                for dep in &b.deps {
+                    let ty = self.state_type(dep).to_string();
+                    self.line(&format!("{dep}.Bind(func(_ {ty}) {{ {update}() }})"));
+                }
+            }
+        }
+        self.bindings = bindings;
+    }
+
+    fn bind_app_closure(&mut self) {
+        let mut bind = Vec::new();
+        for sv in self.state_vars.iter().filter(|sv| !sv.is_param) {
+            bind.push(format!("{}.BindApp(app)", sv.name));
+        }
+        for name in &self.events_vars {
+            bind.push(format!("{name}.BindApp(app)"));
+        }
+        let mut unbind: Vec<String> = self
+            .events_vars
+            .iter()
+            .map(|name| format!("{name}.UnbindApp()"))
+            .collect();
+        for cv in &self.component_vars {
+            bind.extend(view_call(cv, "binder", "AppBinder", "BindApp(app)"));
+            unbind.extend(view_call(cv, "unbinder", "AppUnbinder", "UnbindApp()"));
+        }
+
+        self.line("");
+        self.line("__bindApp := func(app *tui.App) {");
+        self.out.indent += 1;
+        for line in &bind {
+            self.line(line);
+        }
+        self.out.indent -= 1;
+        self.line("}");
+        self.line("");
+        self.line("__unbindApp := func() {");
+        self.out.indent += 1;
+        for line in &unbind {
+            self.line(line);
+        }
+        self.out.indent -= 1;
+        self.line("}");
+    }
+
+    fn update_props(&mut self, recv: &Receiver) {
+        let Some(fields) = self.struct_fields(&recv.ty) else {
+            return;
+        };
+        let props: Vec<&StructField> = fields
+            .iter()
+            .filter(|f| !self.is_internal_state_type(&f.ty))
+            .collect();
+        if props.is_empty() {
+            return;
+        }
+        let mut fresh = "f".to_string();
+        while fresh == recv.name {
+            fresh.push('f');
+        }
+
+        let mut lines = vec![
+            format!(
+                "func ({} {}) UpdateProps(fresh tui.Component) {{",
+                recv.name, recv.ty
+            ),
+            format!("\t{fresh}, ok := fresh.({})", recv.ty),
+            "\tif !ok {".to_string(),
+            "\t\treturn".to_string(),
+            "\t}".to_string(),
+        ];
+        for f in props {
+            lines.push(format!("\t{0}.{1} = {fresh}.{1}", recv.name, f.name));
+        }
+        lines.push("}".to_string());
+        lines.push(String::new());
+        lines.push(format!(
+            "var _ tui.PropsUpdater = (*{})(nil)",
+            strip_pointer(&recv.ty)
+        ));
+        lines.push(String::new());
+        for line in &lines {
+            self.line(line);
+        }
+    }
+
+    fn bind_app(&mut self, recv: &Receiver) {
+        let Some(fields) = self.struct_fields(&recv.ty) else {
+            return;
+        };
+        let app_fields: Vec<&StructField> = fields.iter().filter(|f| f.ty == "*tui.App").collect();
+        let bindable: Vec<&StructField> = fields
+            .iter()
+            .filter(|f| self.is_tui_type(&f.ty, &["State", "Events", "TextArea"]))
+            .collect();
+        if app_fields.is_empty() && bindable.is_empty() {
+            return;
+        }
+
+        let mut lines = vec![
+            "// bindAppFields is generated. It wires the component's *tui.App,".to_string(),
+            "// State, Events, and TextArea fields to app. When you override BindApp,".to_string(),
+            "// call this helper instead of hand-maintaining the delegation list.".to_string(),
+            format!(
+                "func ({} {}) bindAppFields(app *tui.App) {{",
+                recv.name, recv.ty
+            ),
+        ];
+        for f in app_fields {
+            lines.push(format!("\t{}.{} = app", recv.name, f.name));
+        }
+        for f in bindable {
+            lines.push(format!("\tif {}.{} != nil {{", recv.name, f.name));
+            lines.push(format!("\t\t{}.{}.BindApp(app)", recv.name, f.name));
+            lines.push("\t}".to_string());
+        }
+        lines.push("}".to_string());
+        lines.push(String::new());
+
+        if !self.user_declares_method(&recv.ty, "BindApp") {
+            lines.push(format!(
+                "func ({} {}) BindApp(app *tui.App) {{",
+                recv.name, recv.ty
+            ));
+            lines.push(format!("\t{}.bindAppFields(app)", recv.name));
+            lines.push("}".to_string());
+            lines.push(String::new());
+            lines.push(format!(
+                "var _ tui.AppBinder = (*{})(nil)",
+                strip_pointer(&recv.ty)
+            ));
+            lines.push(String::new());
+        }
+        for line in &lines {
+            self.line(line);
+        }
+    }
+
+    fn unbind_app(&mut self, recv: &Receiver) {
+        let Some(fields) = self.struct_fields(&recv.ty) else {
+            return;
+        };
+        let events: Vec<&StructField> = fields
+            .iter()
+            .filter(|f| self.is_tui_type(&f.ty, &["Events"]))
+            .collect();
+        if events.is_empty() {
+            return;
+        }
+
+        let mut lines = vec![
+            "// unbindAppFields is generated. It detaches topic-based Events".to_string(),
+            "// subscriptions and any component-expression AppUnbinder fields.".to_string(),
+            "// Call this from your UnbindApp if you override it.".to_string(),
+            format!("func ({} {}) unbindAppFields() {{", recv.name, recv.ty),
+        ];
+        for f in events {
+            lines.push(format!("\tif {}.{} != nil {{", recv.name, f.name));
+            lines.push(format!("\t\t{}.{}.UnbindApp()", recv.name, f.name));
+            lines.push("\t}".to_string());
+        }
+        lines.push("}".to_string());
+        lines.push(String::new());
+
+        if !self.user_declares_method(&recv.ty, "UnbindApp") {
+            lines.push(format!("func ({} {}) UnbindApp() {{", recv.name, recv.ty));
+            lines.push(format!("\t{}.unbindAppFields()", recv.name));
+            lines.push("}".to_string());
+            lines.push(String::new());
+            lines.push(format!(
+                "var _ tui.AppUnbinder = (*{})(nil)",
+                strip_pointer(&recv.ty)
+            ));
+            lines.push(String::new());
+        }
+        for line in &lines {
+            self.line(line);
+        }
+    }
+
+    /// Parses the fields of the passthrough struct declaration for a
+    /// receiver type, or returns `None` if there is none or it's empty.
+    fn struct_fields(&self, receiver_type: &str) -> Option<Vec<StructField>> {
+        let type_name = strip_pointer(receiver_type);
+        let decl = self
+            .file
+            .decls
+            .iter()
+            .find(|decl| decl.kind == "type" && declares_struct(&decl.code.text, type_name))?;
+        let fields = parse_struct_fields(&decl.code.text);
+        (!fields.is_empty()).then_some(fields)
+    }
+
+    fn user_declares_method(&self, receiver_type: &str, method: &str) -> bool {
+        let type_name = strip_pointer(receiver_type);
+        self.file
+            .funcs
+            .iter()
+            .any(|code| declares_method(&code.text, type_name, method))
+    }
+
+    /// Reports whether `ty` is one of the named go-tui types, ignoring a
+    /// pointer and type arguments.
+    fn is_tui_type(&self, ty: &str, names: &[&str]) -> bool {
+        let ty = strip_pointer(ty);
+        let ty = &ty[..ty.find('[').unwrap_or(ty.len())];
+        match ty.split_once('.') {
+            Some((prefix, name)) => prefix == self.tui_alias && names.contains(&name),
+            None => (self.tui_alias == "." || self.is_tui_package) && names.contains(&ty),
+        }
+    }
+
+    /// Reports whether a field holds runtime state that `UpdateProps` must
+    /// not overwrite.
+    fn is_internal_state_type(&self, ty: &str) -> bool {
+        self.is_tui_type(ty, &["Ref", "RefList", "RefMap", "State"])
+            || ty.starts_with("chan ")
+            || ty.starts_with("<-chan ")
+            || ty.starts_with("chan<-")
+            || ty == "chan"
+            || ty.starts_with("func(")
+            || ty == "func"
+    }
+
+    /// Emits compile-time checks for the optional interfaces method
+    /// components implement through passthrough methods.
+    fn interface_checks(&mut self) {
+        let types: BTreeSet<&str> = self
+            .file
+            .components
+            .iter()
+            .filter_map(|comp| comp.receiver.as_ref())
+            .map(|recv| strip_pointer(&recv.ty))
+            .collect();
+        const INTERFACES: &[(&str, &str)] = &[
+            ("KeyMap", "tui.KeyListener"),
+            ("HandleMouse", "tui.MouseListener"),
+            ("Init", "tui.Initializer"),
+            ("Watchers", "tui.WatcherProvider"),
+        ];
+        let mut rows: Vec<Vec<String>> = Vec::new();
+        for (method, iface) in INTERFACES {
+            for ty in &types {
+                if self.user_declares_method(ty, method) {
+                    rows.push(vec![
+                        "_".to_string(),
+                        iface.to_string(),
+                        format!("= (*{ty})(nil)"),
+                    ]);
+                }
+            }
+        }
+        if rows.is_empty() {
+            return;
+        }
+        self.line("// Compile-time interface satisfaction checks.");
+        self.line("var (");
+        self.out.indent += 1;
+        self.out.rows(&rows);
+        self.out.indent -= 1;
+        self.line(")");
+        self.line("");
+    }
+
+    fn element(
+        &mut self,
+        e: &Element,
+        parent: &str,
+        in_loop: bool,
+        in_conditional: bool,
+        in_for: bool,
+    ) -> String {
+        if is_component_element(&e.tag) {
+            return self.component_element(e, parent, in_loop);
+        }
+        let var = self.next_var();
+        let options = self.element_options(e);
+        self.call_lines(&format!("{var} := tui.New"), &options);
+        self.ref_binding(e, &var, in_loop);
+
+        if !skip_text_children(e) {
+            let saved = e.key_expr.as_ref().map(|key| self.push_element_key(key));
+            self.children(&var, &e.children, in_loop, in_conditional, in_for);
+            if let Some(saved) = saved {
+                self.mount_key_parts = saved;
+            }
+        }
+        if !parent.is_empty() {
+            self.line(&format!("{parent}.AddChild({var})"));
+        }
+        var
+    }
+
+    fn ref_binding(&mut self, e: &Element, var: &str, in_loop: bool) {
+        let Some(name) = &e.ref_expr else {
+            return;
+        };
+        match &e.key_expr {
+            Some(key) => self.line(&format!("{name}.Put({key}, {var})")),
+            None if in_loop => self.line(&format!("{name}.Append({var})")),
+            None => self.line(&format!("{name}.Set({var})")),
+        }
+    }
+
+    fn element_options(&self, e: &Element) -> Vec<String> {
+        let mut options = Vec::new();
+        match e.tag.as_str() {
+            "hr" => options.push("tui.WithHR()".to_string()),
+            "br" => {
+                options.push("tui.WithWidth(0)".to_string());
+                options.push("tui.WithHeight(1)".to_string());
+            }
+            "table" | "tr" => {
+                let direction = if e.tag == "table" { "Column" } else { "Row" };
+                options.push(format!("tui.WithTag({:?})", e.tag));
+                options.push("tui.WithDisplay(tui.DisplayFlex)".to_string());
+                options.push(format!("tui.WithDirection(tui.{direction})"));
+            }
+            "td" | "th" => options.push(format!("tui.WithTag({:?})", e.tag)),
+            _ => {}
+        }
+        if matches!(e.tag.as_str(), "span" | "p" | "td" | "th") {
+            if let Some(text) = extract_text_content(&e.children) {
+                options.push(format!("tui.WithText({text})"));
+            }
+        }
+
+        let mut text_methods = Vec::new();
+        for attr in &e.attrs {
+            if attr.name == "class" {
+                if let AttrValue::Str(classes) = &attr.value {
+                    let parsed = parse_classes(classes);
+                    options.extend(parsed.options);
+                    text_methods.extend(parsed.text_methods);
+                }
+                continue;
+            }
+            if let Some(function) = lookup(HANDLER_OPTIONS, &attr.name) {
+                let value = attribute_value(&attr.value);
+                if !value.is_empty() {
+                    options.push(format!("{function}({value})"));
+                }
+                continue;
+            }
+            if let Some(option) = attribute_option(attr) {
+                options.push(option);
+            }
+        }
+        options.extend(text_style_option(&text_methods));
+        options
+    }
+
+    /// Mounts `<input>`, `<textarea>`, `<modal>` and `<markdown>`, which are
+    /// backed by go-tui components.
+    fn component_element(&mut self, e: &Element, parent: &str, in_loop: bool) -> String {
+        let var = self.next_var();
+        let base = self.mount_index;
+        self.mount_index += 1;
+
+        // An own key scopes over the mount and its children.
+        let saved = e.key_expr.as_ref().map(|key| self.push_element_key(key));
+        let index = self.mount_key_expr(base);
+
+        let mut options = component_element_options(e);
+        if e.tag == "modal" {
+            let mut class_options = Vec::new();
+            for attr in &e.attrs {
+                if let ("class", AttrValue::Str(classes)) = (attr.name.as_str(), &attr.value) {
+                    let parsed = parse_classes(classes);
+                    class_options.extend(parsed.options);
+                    class_options.extend(text_style_option(&parsed.text_methods));
+                }
+            }
+            if !class_options.is_empty() {
+                options.push(format!(
+                    "tui.WithModalElementOptions({})",
+                    class_options.join(", ")
+                ));
+            }
+        }
+
+        // Data-derived keys use the sweepable Mount so they can't leak.
+        let mount = if self.mount_key_parts.is_empty() {
+            "app.MountPersistent"
+        } else {
+            "app.Mount"
+        };
+        self.line(&format!(
+            "{var} := {mount}({}, {index}, func() tui.Component {{",
+            self.receiver
+        ));
+        self.out.indent += 1;
+        let constructor = match e.tag.as_str() {
+            "textarea" => "tui.NewTextArea".to_string(),
+            "input" => "tui.NewInput".to_string(),
+            "modal" => "tui.NewModal".to_string(),
+            "markdown" => "tui.NewMarkdown".to_string(),
+            tag => format!("UNKNOWN_COMPONENT_{tag}"),
+        };
+        self.call_lines(&format!("return {constructor}"), &options);
+        self.out.indent -= 1;
+        self.line("})");
+
+        self.ref_binding(e, &var, in_loop);
+        if !e.children.is_empty() {
+            self.children(&var, &e.children, in_loop, false, false);
+        }
+        if !parent.is_empty() {
+            self.line(&format!("{parent}.AddChild({var})"));
+        }
+        if let Some(saved) = saved {
+            self.mount_key_parts = saved;
+        }
+        var
+    }
+
+    fn children_expr(&self) -> String {
+        if self.receiver.is_empty() {
+            "children".to_string()
+        } else {
+            format!("{}.children", self.receiver)
+        }
+    }
+
+    fn children_slot(&mut self, parent: &str, children: &str) {
+        self.line(&format!("for _, __child := range {children} {{"));
+        self.out.indent += 1;
+        self.line(&format!("{parent}.AddChild(__child)"));
+        self.out.indent -= 1;
+        self.line("}");
+    }
+
+    fn text_child(&mut self, parent: &str, text: &str) {
+        let var = self.next_var();
+        self.line(&format!("{var} := tui.New(tui.WithText({text}))"));
+        self.line(&format!("{parent}.AddChild({var})"));
+    }
+
+    fn children(
+        &mut self,
+        parent: &str,
+        items: &[Item],
+        in_loop: bool,
+        in_conditional: bool,
+        in_for: bool,
+    ) {
+        for item in items {
+            match item {
+                Item::Element(e) => {
+                    self.element(e, parent, in_loop, in_conditional, in_for);
+                }
+                Item::Let(l) => self.let_binding(l, parent, in_conditional, in_for),
+                Item::For(f) => self.for_loop(f, parent, in_loop, in_conditional),
+                Item::If(i) => self.if_stmt(i, parent, in_loop, in_for),
+                Item::Switch(s) => self.switch_stmt(s, parent, in_loop, in_for),
+                Item::Expr(code) => self.text_child(parent, &text_expr(code)),
+                Item::Text(text) => self.text_child(parent, &go_quote(text)),
+                Item::RawExpr(code) => self.line(&format!("{parent}.AddChild({code})")),
+                Item::Call(c) => {
+                    self.call(c, parent, in_conditional, in_for);
+                }
+                Item::ChildrenSlot => {
+                    let children = self.children_expr();
+                    self.children_slot(parent, &children);
+                }
+                Item::Code(_) => {}
+            }
+        }
+    }
+
+    /// Generates an item of an if or else block.
+    fn block_item(
+        &mut self,
+        item: &Item,
+        parent: &str,
+        in_loop: bool,
+        in_conditional: bool,
+        in_for: bool,
+    ) {
+        match item {
+            Item::Code(code) => self.out.go_code(code),
+            Item::Expr(code) if parent.is_empty() => self.out.code(code),
+            Item::Text(_) | Item::RawExpr(_) | Item::ChildrenSlot if parent.is_empty() => {}
+            _ => self.children(
+                parent,
+                std::slice::from_ref(item),
+                in_loop,
+                in_conditional,
+                in_for,
+            ),
+        }
+    }
+
+    fn let_binding(&mut self, l: &LetBinding, parent: &str, in_conditional: bool, in_for: bool) {
+        match &l.value {
+            LetValue::Call(c) => {
+                let var = self.call(c, "", in_conditional, in_for);
+                if self.returns_element(c) {
+                    self.line(&format!("{} := {var}", l.name));
+                } else {
+                    self.line(&format!("{} := {var}.Root", l.name));
+                }
+            }
+            LetValue::Element(e) => {
+                let options = self.element_options(e);
+                self.call_lines(&format!("{} := tui.New", l.name), &options);
+                if !skip_text_children(e) {
+                    let saved = e.key_expr.as_ref().map(|key| self.push_element_key(key));
+                    self.children(&l.name, &e.children, false, false, false);
+                    if let Some(saved) = saved {
+                        self.mount_key_parts = saved;
+                    }
+                }
+            }
+        }
+        if !parent.is_empty() {
+            self.line(&format!("{parent}.AddChild({})", l.name));
+        }
+    }
+
+    fn for_loop(&mut self, f: &ForLoop, parent: &str, in_loop: bool, _in_conditional: bool) {
+        if !in_loop && !parent.is_empty() {
+            let state_names = self.state_names();
+            let mut deps = DepCollector {
+                state_names: &state_names,
+                deps: Vec::new(),
+            };
+            deps.expr(&f.iterable);
+            deps.items(&f.body);
+            let deps = deps.deps;
+            if !deps.is_empty() {
+                self.reactive_for(f, parent, &deps);
+                return;
+            }
+        }
+
+        let index = self.push_loop_index(&f.index);
+        self.line(&format!(
+            "for {index}, {} := range {} {{",
+            f.value, f.iterable
+        ));
+        self.out.indent += 1;
+        self.line(&format!("_ = {index}"));
+        for item in &f.body {
+            match item {
+                Item::Element(e) => {
+                    self.element(e, parent, true, true, true);
+                }
+                Item::Let(l) => self.let_binding(l, parent, true, true),
+                Item::For(inner) => self.for_loop(inner, parent, true, true),
+                Item::If(i) => self.if_stmt(i, parent, true, true),
+                Item::Switch(s) => self.switch_stmt(s, parent, true, true),
+                Item::Code(code) => self.out.go_code(code),
+                Item::Expr(code) if parent.is_empty() => self.out.code(code),
+                Item::Expr(code) => self.text_child(parent, &text_expr(code)),
+                Item::Call(c) => {
+                    self.call(c, parent, true, true);
+                }
+                Item::ChildrenSlot if !parent.is_empty() => {
+                    self.children_slot(parent, "children");
+                }
+                _ => {}
+            }
+        }
+        self.out.indent -= 1;
+        self.line("}");
+        self.pop_loop_index();
+    }
+
+    fn if_stmt(&mut self, stmt: &IfStmt, parent: &str, in_loop: bool, in_for: bool) {
+        if !in_loop && !parent.is_empty() {
+            let state_names = self.state_names();
+            let mut deps = DepCollector {
+                state_names: &state_names,
+                deps: Vec::new(),
+            };
+            deps.expr(&stmt.condition);
+            deps.items(&stmt.then);
+            deps.items(&stmt.r#else);
+            let deps = deps.deps;
+            if !deps.is_empty() {
+                self.reactive_conditional(parent, &deps, |g, cond| {
+                    g.if_stmt(stmt, cond, true, false)
+                });
+                return;
+            }
+        }
+
+        self.line(&format!("if {} {{", stmt.condition));
+        self.out.indent += 1;
+        for item in &stmt.then {
+            self.block_item(item, parent, in_loop, true, in_for);
+        }
+        self.out.indent -= 1;
+
+        if stmt.r#else.is_empty() {
+            self.line("}");
+            return;
+        }
+        self.out.else_();
+        if let [Item::If(else_if)] = stmt.r#else.as_slice() {
+            self.if_stmt(else_if, parent, in_loop, in_for);
+            return;
+        }
+        self.line("{");
+        self.out.indent += 1;
+        for item in &stmt.r#else {
+            self.block_item(item, parent, in_loop, true, in_for);
+        }
+        self.out.indent -= 1;
+        self.line("}");
+    }
+
+    fn switch_stmt(&mut self, stmt: &SwitchStmt, parent: &str, in_loop: bool, in_for: bool) {
+        if !in_loop && !parent.is_empty() {
+            let state_names = self.state_names();
+            let mut deps = DepCollector {
+                state_names: &state_names,
+                deps: Vec::new(),
+            };
+            deps.switch(stmt);
+            let deps = deps.deps;
+            if !deps.is_empty() {
+                self.reactive_conditional(parent, &deps, |g, cond| {
+                    g.switch_stmt(stmt, cond, true, false)
+                });
+                return;
+            }
+        }
+
+        self.switch_clauses(stmt, |g, items| {
+            for item in items {
+                g.block_item(item, parent, in_loop, true, in_for);
+            }
+        });
+    }
+
+    /// Writes `stmt` with the items of each clause generated by `clause`.
+    fn switch_clauses(&mut self, stmt: &SwitchStmt, mut clause: impl FnMut(&mut Self, &[Item])) {
+        if stmt.tag.is_empty() {
+            self.line("switch {");
+        } else {
+            self.line(&format!("switch {} {{", stmt.tag));
+        }
+        for c in &stmt.clauses {
+            self.line(&format!("{}:", c.label));
+            self.out.indent += 1;
+            clause(self, &c.items);
+            self.out.indent -= 1;
+        }
+        self.line("}");
+    }
+
+    /// Generates a top-level switch whose first rendered element becomes the
+    /// component root.
+    fn switch_to_root(&mut self, stmt: &SwitchStmt, root: &str, in_loop: bool) {
+        self.switch_clauses(stmt, |g, items| g.items_to_root(items, root, in_loop));
+    }
+
+    /// Generates a top-level if statement whose first rendered element
+    /// becomes the component root.
+    fn if_to_root(&mut self, stmt: &IfStmt, root: &str, in_loop: bool) {
+        self.line(&format!("if {} {{", stmt.condition));
+        self.out.indent += 1;
+        self.items_to_root(&stmt.then, root, in_loop);
+        self.out.indent -= 1;
+
+        if stmt.r#else.is_empty() {
+            self.line("}");
+            return;
+        }
+        self.out.else_();
+        if let [Item::If(else_if)] = stmt.r#else.as_slice() {
+            self.if_to_root(else_if, root, in_loop);
+            return;
+        }
+        self.line("{");
+        self.out.indent += 1;
+        self.items_to_root(&stmt.r#else, root, in_loop);
+        self.out.indent -= 1;
+        self.line("}");
+    }
+
+    /// Generates a top-level for loop inside a container that becomes the
+    /// root, since a component can't return several siblings.
+    fn for_to_root(&mut self, f: &ForLoop, root: &str, in_loop: bool) {
+        let container = self.next_var();
+        self.line(&format!("{container} := tui.New()"));
+        self.for_loop(f, &container, in_loop, false);
+        self.assign_if_nil(root, &container);
+    }
+
+    fn items_to_root(&mut self, items: &[Item], root: &str, in_loop: bool) {
+        for item in items {
+            match item {
+                Item::Element(e) => {
+                    let var = self.element(e, "", in_loop, true, false);
+                    self.assign_if_nil(root, &var);
+                }
+                Item::Call(c) => {
+                    let var = self.call(c, "", true, false);
+                    if self.returns_element(c) {
+                        self.assign_if_nil(root, &var);
+                    } else {
+                        self.assign_if_nil(root, &format!("{var}.Root"));
+                    }
+                }
+                Item::If(i) => self.if_to_root(i, root, in_loop),
+                Item::Switch(s) => self.switch_to_root(s, root, in_loop),
+                Item::For(f) => self.for_to_root(f, root, in_loop),
+                Item::Let(l) => self.let_binding(l, "", true, false),
+                Item::Code(code) => self.out.go_code(code),
+                Item::Expr(code) => self.out.code(code),
+                Item::RawExpr(_) | Item::Text(_) | Item::ChildrenSlot => {}
+            }
+        }
+    }
+
+    fn assign_if_nil(&mut self, root: &str, value: &str) {
+        self.line(&format!("if {root} == nil {{"));
+        self.out.indent += 1;
+        self.line(&format!("{root} = {value}"));
+        self.out.indent -= 1;
+        self.line("}");
+    }
+
+    /// Generates an if or switch statement, written by `branch` into the
+    /// wrapper element it's given, that rebuilds its children whenever a
+    /// state value it reads changes.
+    fn reactive_conditional(
+        &mut self,
+        parent: &str,
+        deps: &[String],
+        branch: impl FnOnce(&mut Self, &str),
+    ) {
+        let cond = format!("__cond_{}", self.cond_counter);
+        self.cond_counter += 1;
+        let update = format!("__update_{cond}");
+
+        self.line(&format!("{cond} := tui.New()"));
+        self.line(&format!("{parent}.AddChild({cond})"));
+        self.line(&format!("{update} := func() {{"));
+        self.out.indent += 1;
+        self.line(&format!("{cond}.RemoveAllChildren()"));
+        let reset_pos = self.out.pos();
+        let prev_count = self.component_vars.len();
+        branch(self, &cond);
+        self.splice_resets(reset_pos, prev_count);
+        self.out.indent -= 1;
+        self.line("}");
+        self.line(&format!("{update}()"));
+        self.bind_deps(deps, &update);
+    }
+
+    /// Generates a for loop that rebuilds its children in a wrapper element
+    /// whenever a state value it reads changes. The wrapper copies the
+    /// parent's direction and gap so the children lay out the same way.
+    fn reactive_for(&mut self, f: &ForLoop, parent: &str, deps: &[String]) {
+        let wrapper = format!("__loop_{}", self.loop_counter);
+        self.loop_counter += 1;
+        let update = format!("__update_{wrapper}");
+
+        self.line(&format!("{wrapper}_style := {parent}.LayoutStyle()"));
+        self.line(&format!(
+            "{wrapper} := tui.New(tui.WithDirection({wrapper}_style.Direction), tui.WithGap({wrapper}_style.Gap))"
+        ));
+        self.line(&format!("{parent}.AddChild({wrapper})"));
+        self.line(&format!("{update} := func() {{"));
+        self.out.indent += 1;
+        self.line(&format!("{wrapper}.RemoveAllChildren()"));
+        let reset_pos = self.out.pos();
+        let prev_count = self.component_vars.len();
+        self.for_loop(f, &wrapper, true, false);
+        self.splice_resets(reset_pos, prev_count);
+        self.out.indent -= 1;
+        self.line("}");
+        self.line(&format!("{update}()"));
+        self.bind_deps(deps, &update);
+    }
+
+    fn bind_deps(&mut self, deps: &[String], update: &str) {
+        for dep in deps {
+            let ty = self.state_type(dep).to_string();
+            self.line(&format!("{dep}.Bind(func(_ {ty}) {{ {update}() }})"));
+        }
+    }
+
+    /// Reports whether a call mounts a struct component, which produces an
+    /// element rather than a view. Calls in method components mount unless
+    /// they name a function component from the same file.
+    fn returns_element(&self, c: &ComponentCall) -> bool {
+        !self.receiver.is_empty() && !self.function_templs.contains(c.name.as_str())
+    }
+
+    fn call(
+        &mut self,
+        c: &ComponentCall,
+        parent: &str,
+        in_conditional: bool,
+        in_for: bool,
+    ) -> String {
+        if self.returns_element(c) {
+            self.struct_mount(c, parent)
+        } else {
+            self.function_call(c, parent, in_conditional, in_for)
+        }
+    }
+
+    fn struct_mount(&mut self, c: &ComponentCall, parent: &str) -> String {
+        let var = self.next_var();
+        let base = self.mount_index;
+        self.mount_index += 1;
+        let index = self.mount_key_expr(base);
+
+        let mut args = c.args.clone();
+        if !c.children.is_empty() {
+            let children = format!("{var}_children");
+            self.line(&format!("{children} := []*tui.Element{{}}"));
+            self.slice_children(&c.children, &children);
+            args = join_args(&args, &children);
+        }
+
+        self.line(&format!(
+            "{var} := app.Mount({}, {index}, func() tui.Component {{",
+            self.receiver
+        ));
+        self.out.indent += 1;
+        self.line(&format!("return {}({args})", c.name));
+        self.out.indent -= 1;
+        self.line("})");
+        if !parent.is_empty() {
+            self.line(&format!("{parent}.AddChild({var})"));
+        }
+        var
+    }
+
+    fn function_call(
+        &mut self,
+        c: &ComponentCall,
+        parent: &str,
+        in_conditional: bool,
+        in_for: bool,
+    ) -> String {
+        let var = self.next_var();
+        // Inside an if block the view is hoisted to function scope so the
+        // watcher and binding code can reach it.
+        let decl = if in_conditional && !in_for && self.receiver.is_empty() {
+            "="
+        } else {
+            ":="
+        };
+
+        if c.children.is_empty() {
+            self.line(&format!("{var} {decl} {}({})", c.name, c.args));
+        } else {
+            let children = format!("{}_children", self.next_var());
+            self.line(&format!("{children} := []*tui.Element{{}}"));
+            self.slice_children(&c.children, &children);
+            self.line(&format!(
+                "{var} {decl} {}({})",
+                c.name,
+                join_args(&c.args, &children)
+            ));
+        }
+
+        self.component_vars.push(ComponentVar {
+            name: var.clone(),
+            component: c.name.clone(),
+            in_conditional: in_conditional || in_for,
+            in_for,
+        });
+        if !parent.is_empty() {
+            self.line(&format!("{parent}.AddChild({var}.Root)"));
+        }
+        if in_for && self.receiver.is_empty() {
+            self.line(&format!("{var}_views = append({var}_views, {var})"));
+        }
+        var
+    }
+
+    fn append(&mut self, slice: &str, value: &str) {
+        self.line(&format!("{slice} = append({slice}, {value})"));
+    }
+
+    fn append_call(&mut self, slice: &str, c: &ComponentCall, var: &str) {
+        if self.returns_element(c) {
+            self.append(slice, var);
+        } else {
+            self.append(slice, &format!("{var}.Root"));
+        }
+    }
+
+    fn append_text(&mut self, slice: &str, text: &str) {
+        let var = self.next_var();
+        self.line(&format!("{var} := tui.New(tui.WithText({text}))"));
+        self.append(slice, &var);
+    }
+
+    /// Builds the children passed to a component call into `slice`.
+    fn slice_children(&mut self, items: &[Item], slice: &str) {
+        for item in items {
+            match item {
+                Item::Element(e) => {
+                    let var = self.element(e, "", false, false, false);
+                    self.append(slice, &var);
+                }
+                Item::Call(c) => {
+                    let var = self.call(c, "", false, false);
+                    self.append_call(slice, c, &var);
+                }
+                Item::Let(l) => {
+                    self.let_binding(l, "", false, false);
+                    self.append(slice, &l.name);
+                }
+                Item::For(f) => self.for_slice(f, slice),
+                Item::If(i) => self.if_slice(i, slice),
+                Item::Switch(s) => self.switch_clauses(s, |g, items| g.slice_block(items, slice)),
+                Item::Expr(code) => self.append_text(slice, &text_expr(code)),
+                Item::Text(text) => self.append_text(slice, &go_quote(text)),
+                Item::RawExpr(code) => self.append(slice, code),
+                Item::Code(_) | Item::ChildrenSlot => {}
+            }
+        }
+    }
+
+    /// Generates the items of a loop or if block inside call children.
+    fn slice_block(&mut self, items: &[Item], slice: &str) {
+        for item in items {
+            match item {
+                Item::Element(e) => {
+                    let var = self.element(e, "", false, false, false);
+                    self.append(slice, &var);
+                }
+                Item::Call(c) => {
+                    let var = self.call(c, "", true, false);
+                    self.append_call(slice, c, &var);
+                }
+                Item::Let(l) => {
+                    self.let_binding(l, "", true, false);
+                    self.append(slice, &l.name);
+                }
+                Item::For(f) => self.for_slice(f, slice),
+                Item::If(i) => self.if_slice(i, slice),
+                Item::Switch(s) => self.switch_clauses(s, |g, items| g.slice_block(items, slice)),
+                Item::Code(code) => self.out.go_code(code),
+                Item::Expr(code) => self.append_text(slice, &text_expr(code)),
+                Item::Text(_) | Item::RawExpr(_) | Item::ChildrenSlot => {}
+            }
+        }
+    }
+
+    fn for_slice(&mut self, f: &ForLoop, slice: &str) {
+        let index = self.push_loop_index(&f.index);
+        self.line(&format!(
+            "for {index}, {} := range {} {{",
+            f.value, f.iterable
+        ));
+        self.out.indent += 1;
+        self.line(&format!("_ = {index}"));
+        self.slice_block(&f.body, slice);
+        self.out.indent -= 1;
+        self.line("}");
+        self.pop_loop_index();
+    }
+
+    fn if_slice(&mut self, stmt: &IfStmt, slice: &str) {
+        self.line(&format!("if {} {{", stmt.condition));
+        self.out.indent += 1;
+        self.slice_block(&stmt.then, slice);
+        self.out.indent -= 1;
+
+        if stmt.r#else.is_empty() {
+            self.line("}");
+            return;
+        }
+        self.out.else_();
+        if let [Item::If(else_if)] = stmt.r#else.as_slice() {
+            self.if_slice(else_if, slice);
+            return;
+        }
+        self.line("{");
+        self.out.indent += 1;
+        self.slice_block(&stmt.r#else, slice);
+        self.out.indent -= 1;
+        self.line("}");
+    }
+}
+
+/// Returns the bind or unbind statements for a child component view.
+fn view_call(cv: &ComponentVar, var: &str, iface: &str, call: &str) -> Vec<String> {
+    let assert = |value: &str| {
+        vec![
+            format!("if {var}, ok := any({value}).(tui.{iface}); ok {{"),
+            format!("\t{var}.{call}"),
+            "}".to_string(),
+        ]
+    };
+    let (open, inner) = if cv.in_for {
+        (
+            format!("for _, __cv := range {}_views {{", cv.name),
+            assert("__cv"),
+        )
+    } else if cv.in_conditional {
+        (format!("if {} != nil {{", cv.name), assert(&cv.name))
+    } else {
+        return assert(&cv.name);
+    };
+    let mut lines = vec![open];
+    lines.extend(inner.into_iter().map(|line| format!("\t{line}")));
+    lines.push("}".to_string());
+    lines
+}
+
+/// Returns the view type a component call produces, e.g. `*pkg.CardView`.
+fn view_type_name(component: &str) -> String {
+    format!("*{component}View")
+}
+
+fn strip_pointer(ty: &str) -> &str {
+    ty.strip_prefix('*').unwrap_or(ty)
+}
+
+fn join_args(args: &str, extra: &str) -> String {
+    if args.is_empty() {
+        extra.to_string()
+    } else {
+        format!("{args}, {extra}")
+    }
+}
+
+/// Matches `type\s+T\s+struct\s*\{` anywhere in `code`.
+fn declares_struct(code: &str, type_name: &str) -> bool {
+    code.match_indices("type").any(|(i, _)| {
+        skip_space(&code[i + 4..], 1)
+            .and_then(|rest| rest.strip_prefix(type_name))
+            .and_then(|rest| skip_space(rest, 1))
+            .and_then(|rest| rest.strip_prefix("struct"))
+            .and_then(|rest| skip_space(rest, 0))
+            .is_some_and(|rest| rest.starts_with('{'))
+    })
+}
+
+/// Matches `func\s*\(\s*\w+\s+\*?T\s*\)\s*Method\s*\(` anywhere in `code`.
+fn declares_method(code: &str, type_name: &str, method: &str) -> bool {
+    code.match_indices("func").any(|(i, _)| {
+        skip_space(&code[i + 4..], 0)
+            .and_then(|rest| rest.strip_prefix('('))
+            .and_then(|rest| skip_space(rest, 0))
+            .and_then(|rest| {
+                let name = rest.len() - rest.trim_start_matches(is_word_char).len();
+                (name > 0).then(|| &rest[name..])
+            })
+            .and_then(|rest| skip_space(rest, 1))
+            .map(|rest| rest.strip_prefix('*').unwrap_or(rest))
+            .and_then(|rest| rest.strip_prefix(type_name))
+            .and_then(|rest| skip_space(rest, 0))
+            .and_then(|rest| rest.strip_prefix(')'))
+            .and_then(|rest| skip_space(rest, 0))
+            .and_then(|rest| rest.strip_prefix(method))
+            .and_then(|rest| skip_space(rest, 0))
+            .is_some_and(|rest| rest.starts_with('('))
+    })
+}
+
+/// Reads `name Type` fields from a struct declaration line by line, the way
+/// tuigen's `parseStructFields` does.
+fn parse_struct_fields(code: &str) -> Vec<StructField> {
+    let (Some(start), Some(end)) = (code.find('{'), code.rfind('}')) else {
+        return Vec::new();
+    };
+    if start >= end {
+        return Vec::new();
+    }
+    let mut fields = Vec::new();
+    for line in code[start + 1..end].lines() {
+        let mut line = line.trim();
+        if line.is_empty() || line.starts_with("//") {
+            continue;
+        }
+        if let Some(i) = line.find("//") {
+            line = line[..i].trim();
+        }
+        let name_len = line.len() - line.trim_start_matches(is_word_char).len();
+        if name_len == 0 {
+            continue;
+        }
+        let Some(ty) = skip_space(&line[name_len..], 1) else {
+            continue;
+        };
+        if !ty.is_empty() {
+            fields.push(StructField {
+                name: line[..name_len].to_string(),
+                ty: ty.trim().to_string(),
+            });
+        }
+    }
+    fields
+}
+
+fn is_component_element(tag: &str) -> bool {
+    matches!(tag, "textarea" | "input" | "modal" | "markdown")
+}
+
+/// Reports whether a text element's only child was already turned into a
+/// `tui.WithText` option.
+fn skip_text_children(e: &Element) -> bool {
+    matches!(e.tag.as_str(), "span" | "p" | "td" | "th")
+        && matches!(e.children.as_slice(), [Item::Text(_) | Item::Expr(_)])
+}
+
+fn extract_text_content(children: &[Item]) -> Option<String> {
+    match children {
+        [Item::Expr(code)] => Some(text_expr(code)),
+        [Item::Text(text)] => Some(go_quote(text)),
+        _ => None,
+    }
+}
+
+/// Wraps a numeric literal in `fmt.Sprint` so it can be used as text.
+fn text_expr(code: &str) -> String {
+    let s = code.trim();
+    let unsigned = s.strip_prefix('-').unwrap_or(s);
+    match unsigned.bytes().next() {
+        Some(b) if b.is_ascii_digit() || b == b'.' => format!("fmt.Sprint({s})"),
+        _ => code.to_string(),
+    }
+}
+
+const HANDLER_OPTIONS: &[(&str, &str)] = &[
+    ("onFocus", "tui.WithOnFocus"),
+    ("onBlur", "tui.WithOnBlur"),
+    ("onActivate", "tui.WithOnActivate"),
+];
+
+const ATTRIBUTE_OPTIONS: &[(&str, &str)] = &[
+    ("width", "tui.WithWidth"),
+    ("widthPercent", "tui.WithWidthPercent"),
+    ("height", "tui.WithHeight"),
+    ("heightPercent", "tui.WithHeightPercent"),
+    ("minWidth", "tui.WithMinWidth"),
+    ("minHeight", "tui.WithMinHeight"),
+    ("maxWidth", "tui.WithMaxWidth"),
+    ("maxHeight", "tui.WithMaxHeight"),
+    ("direction", "tui.WithDirection"),
+    ("justify", "tui.WithJustify"),
+    ("align", "tui.WithAlign"),
+    ("gap", "tui.WithGap"),
+    ("flexWrap", "tui.WithFlexWrap"),
+    ("alignContent", "tui.WithAlignContent"),
+    ("flexGrow", "tui.WithFlexGrow"),
+    ("flexShrink", "tui.WithFlexShrink"),
+    ("alignSelf", "tui.WithAlignSelf"),
+    ("padding", "tui.WithPadding"),
+    ("margin", "tui.WithMargin"),
+    ("border", "tui.WithBorder"),
+    ("borderStyle", "tui.WithBorderStyle"),
+    ("borderTitle", "tui.WithBorderTitle"),
+    ("background", "tui.WithBackground"),
+    ("backgroundGradient", "tui.WithBackgroundGradient"),
+    ("text", "tui.WithText"),
+    ("textStyle", "tui.WithTextStyle"),
+    ("textAlign", "tui.WithTextAlign"),
+    ("focusable", "tui.WithFocusable"),
+    ("autoFocus", "tui.WithAutoFocus"),
+    ("scrollable", "tui.WithScrollable"),
+    ("scrollOffset", "tui.WithScrollOffset"),
+    ("hideScrollbar", "tui.WithScrollbarHidden"),
+];
+
+const INPUT_OPTIONS: &[(&str, &str)] = &[
+    ("width", "tui.WithInputWidth"),
+    ("border", "tui.WithInputBorder"),
+    ("textStyle", "tui.WithInputTextStyle"),
+    ("value", "tui.WithInputValue"),
+    ("placeholder", "tui.WithInputPlaceholder"),
+    ("placeholderStyle", "tui.WithInputPlaceholderStyle"),
+    ("cursor", "tui.WithInputCursor"),
+    ("focusColor", "tui.WithInputFocusColor"),
+    ("borderGradient", "tui.WithInputBorderGradient"),
+    ("focusGradient", "tui.WithInputFocusGradient"),
+    ("autoFocus", "tui.WithInputAutoFocus"),
+];
+
+const INPUT_HANDLERS: &[(&str, &str)] = &[
+    ("onSubmit", "tui.WithInputOnSubmit"),
+    ("onChange", "tui.WithInputOnChange"),
+];
+
+const TEXTAREA_OPTIONS: &[(&str, &str)] = &[
+    ("width", "tui.WithTextAreaWidth"),
+    ("maxHeight", "tui.WithTextAreaMaxHeight"),
+    ("border", "tui.WithTextAreaBorder"),
+    ("textStyle", "tui.WithTextAreaTextStyle"),
+    ("value", "tui.WithTextAreaValue"),
+    ("placeholder", "tui.WithTextAreaPlaceholder"),
+    ("placeholderStyle", "tui.WithTextAreaPlaceholderStyle"),
+    ("cursor", "tui.WithTextAreaCursor"),
+    ("focusColor", "tui.WithTextAreaFocusColor"),
+    ("borderGradient", "tui.WithTextAreaBorderGradient"),
+    ("focusGradient", "tui.WithTextAreaFocusGradient"),
+    ("submitKey", "tui.WithTextAreaSubmitKey"),
+    ("autoFocus", "tui.WithTextAreaAutoFocus"),
+];
+
+const TEXTAREA_HANDLERS: &[(&str, &str)] = &[("onSubmit", "tui.WithTextAreaOnSubmit")];
+
+const MODAL_OPTIONS: &[(&str, &str)] = &[
+    ("open", "tui.WithModalOpen"),
+    ("backdrop", "tui.WithModalBackdrop"),
+    ("closeOnEscape", "tui.WithModalCloseOnEscape"),
+    ("closeOnBackdropClick", "tui.WithModalCloseOnBackdropClick"),
+    ("trapFocus", "tui.WithModalTrapFocus"),
+    ("keyMap", "tui.WithModalKeyMap"),
+];
+
+const MARKDOWN_OPTIONS: &[(&str, &str)] = &[
+    ("source", "tui.WithMarkdownSource"),
+    ("state", "tui.WithMarkdownState"),
+    ("width", "tui.WithMarkdownWidth"),
+    ("theme", "tui.WithMarkdownTheme"),
+];
+
+fn lookup(table: &[(&str, &'static str)], name: &str) -> Option<&'static str> {
+    table
+        .iter()
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| *value)
+}
+
+/// Converts a plain element attribute into its `tui.With*` option.
+fn attribute_option(attr: &Attribute) -> Option<String> {
+    if let ("width" | "height", AttrValue::Str(value)) = (attr.name.as_str(), &attr.value) {
+        if let Some(percent) = value.strip_suffix('%') {
+            let function = if attr.name == "width" {
+                "tui.WithWidthPercent"
+            } else {
+                "tui.WithHeightPercent"
+            };
+            return Some(format!("{function}({percent})"));
+        }
+    }
+    let function = lookup(ATTRIBUTE_OPTIONS, &attr.name)?;
+    Some(format!("{function}({})", attribute_value(&attr.value)))
+}
+
+fn component_element_options(e: &Element) -> Vec<String> {
+    let (options, handlers) = match e.tag.as_str() {
+        "input" => (INPUT_OPTIONS, INPUT_HANDLERS),
+        "textarea" => (TEXTAREA_OPTIONS, TEXTAREA_HANDLERS),
+        "modal" => (MODAL_OPTIONS, &[][..]),
+        "markdown" => (MARKDOWN_OPTIONS, &[][..]),
+        _ => (&[][..], &[][..]),
+    };
+
+    let mut result = Vec::new();
+    for attr in &e.attrs {
+        if matches!(attr.name.as_str(), "class" | "id" | "ref" | "key" | "deps") {
+            continue;
+        }
+        let value = attribute_value(&attr.value);
+        if let Some(function) = lookup(handlers, &attr.name) {
+            if !value.is_empty() {
+                result.push(format!("{function}({value})"));
+            }
+            continue;
+        }
+        let Some(function) = lookup(options, &attr.name) else {
+            continue;
+        };
+        if value.is_empty() {
+            continue;
+        }
+        // value and open take state; literals are wrapped in a new one.
+        let wrap = match (&attr.value, attr.name.as_str()) {
+            (AttrValue::Str(_), "value") => true,
+            (AttrValue::Bool(_), "open") => e.tag == "modal",
+            _ => false,
+        };
+        if wrap {
+            result.push(format!("{function}(tui.NewState({value}))"));
+        } else {
+            result.push(format!("{function}({value})"));
+        }
+    }
+    result
+}
+
+fn attribute_value(value: &AttrValue) -> String {
+    match value {
+        AttrValue::Str(s) => go_quote(s),
+        AttrValue::Int(n) => n.to_string(),
+        AttrValue::Float(f) => f.to_string(),
+        AttrValue::Bool(b) => b.to_string(),
+        AttrValue::Expr(code) => code.clone(),
+    }
+}
+
+/// Quotes a string the way Go's `strconv.Quote` does.
+fn go_quote(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for ch in s.chars() {
+        match ch {
+            '\x07' => out.push_str("\\a"),
+            '\x08' => out.push_str("\\b"),
+            '\x0c' => out.push_str("\\f"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\x0b' => out.push_str("\\v"),
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            ch if is_go_printable(ch) => out.push(ch),
+            ch if (ch as u32) < 0x80 => out.push_str(&format!("\\x{:02x}", ch as u32)),
+            ch if (ch as u32) < 0x10000 => out.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => out.push_str(&format!("\\U{:08x}", ch as u32)),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Approximates `strconv.IsPrint`: control characters, spaces other than
+/// U+0020 and invisible format characters are escaped.
+fn is_go_printable(ch: char) -> bool {
+    if ch == ' ' {
+        return true;
+    }
+    !(ch.is_control()
+        || ch.is_whitespace()
+        || matches!(ch, '\u{ad}' | '\u{200b}'..='\u{200f}' | '\u{2028}'..='\u{202e}' | '\u{2060}'..='\u{2064}' | '\u{feff}'))
+}
+
+/// Collects generated lines, tracking indentation the way the Go generator
+/// does.
+struct Writer {
+    buf: String,
+    indent: usize,
+    at_line_start: bool,
+    marks: Vec<Mark>,
+}
+
+/// A line of Go code copied from the source, recorded for the source map.
+struct Mark {
+    /// The byte offset of the start of the line in the buffer.
+    pos: usize,
+    go_col: usize,
+    gsx: Point,
+    length: usize,
+}
+
+impl Writer {
+    fn new() -> Self {
+        Self {
+            buf: String::new(),
+            indent: 0,
+            at_line_start: true,
+            marks: Vec::new(),
+        }
+    }
+
+    fn pos(&self) -> usize {
+        self.buf.len()
+    }
+
+    fn write_indent(&mut self) {
+        if self.at_line_start {
+            for _ in 0..self.indent {
+                self.buf.push('\t');
+            }
+        }
+        self.at_line_start = false;
+    }
+
+    /// Writes one line at the current indentation. Block comments after a
+    /// comma are moved in front of it, as `gofmt` prints them.
+    fn line(&mut self, s: &str) {
+        if !s.is_empty() {
+            self.write_indent();
+            self.buf.push_str(&comments_before_commas(s));
+        }
+        self.buf.push('\n');
+        self.at_line_start = true;
+    }
+
+    /// Writes `} else ` and leaves the line open for what follows.
+    fn else_(&mut self) {
+        self.write_indent();
+        self.buf.push_str("} else ");
+    }
+
+    /// Writes Go code from the template, re-indenting its lines.
+    fn code(&mut self, code: &str) {
+        for line in code.split('\n') {
+            self.line(line);
+        }
+    }
+
+    /// Writes a Go statement from a component body, re-indenting its lines.
+    fn go_code(&mut self, code: &GoCode) {
+        for (line, &gsx) in code.text.split('\n').zip(&code.starts) {
+            self.mark(self.indent, gsx, line);
+            self.line(line);
+        }
+    }
+
+    /// Writes top-level Go code as is, followed by a blank line.
+    fn passthrough(&mut self, code: &GoCode) {
+        for (line, &gsx) in code.text.split('\n').zip(&code.starts) {
+            self.mark(0, gsx, line);
+            self.buf.push_str(line);
+            self.buf.push('\n');
+        }
+        self.buf.push('\n');
+    }
+
+    fn mark(&mut self, go_col: usize, gsx: Point, line: &str) {
+        let length = line.trim_end().len();
+        if length > 0 {
+            self.marks.push(Mark {
+                pos: self.buf.len(),
+                go_col,
+                gsx,
+                length,
+            });
+        }
+    }
+
+    /// Writes rows of cells aligned into columns like `gofmt`'s tabwriter:
+    /// every cell but a row's last is padded to the widest cell of its
+    /// column among the adjacent rows that continue past it.
+    fn rows(&mut self, rows: &[Vec<String>]) {
+        let mut lines: Vec<String> = vec![String::new(); rows.len()];
+        let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+        for column in 0..columns {
+            let mut i = 0;
+            while i < rows.len() {
+                if rows[i].len() <= column + 1 {
+                    if rows[i].len() == column + 1 {
+                        lines[i].push_str(&rows[i][column]);
+                    }
+                    i += 1;
+                    continue;
+                }
+                let start = i;
+                while i < rows.len() && rows[i].len() > column + 1 {
+                    i += 1;
+                }
+                let width = rows[start..i]
+                    .iter()
+                    .map(|row| row[column].chars().count())
+                    .max()
+                    .unwrap_or(0)
+                    + 1;
+                for (line, row) in lines[start..i].iter_mut().zip(&rows[start..i]) {
+                    let cell = &row[column];
+                    line.push_str(cell);
+                    line.extend(std::iter::repeat_n(' ', width - cell.chars().count()));
+                }
+            }
+        }
+        for line in &lines {
+            self.line(line);
+        }
+    }
+
+    /// Inserts lines at the current indentation at byte `pos`.
+    fn insert_lines(&mut self, pos: usize, lines: &[String]) {
+        let indent = "\t".repeat(self.indent);
+        let text: String = lines
+            .iter()
+            .map(|line| format!("{indent}{line}\n"))
+            .collect();
+        self.buf.insert_str(pos, &text);
+        for mark in self.marks.iter_mut().filter(|mark| mark.pos >= pos) {
+            mark.pos += text.len();
+        }
+    }
+}
+
+/// Rewrites `, /* c */ x` as ` /* c */, x`, skipping string literals.
+fn comments_before_commas(line: &str) -> String {
+    if !line.contains("/*") {
+        return line.to_string();
+    }
+    let mut out = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(ch) = rest.chars().next() {
+        match ch {
+            '"' | '\'' | '`' => {
+                let len = literal_len(rest);
+                out.push_str(&rest[..len]);
+                rest = &rest[len..];
+            }
+            ',' => {
+                let after = rest[1..].trim_start_matches([' ', '\t']);
+                let comment_end = after
+                    .strip_prefix("/*")
+                    .and_then(|body| body.find("*/"))
+                    .map(|i| i + 4);
+                match comment_end {
+                    Some(end) => {
+                        out.push(' ');
+                        out.push_str(&after[..end]);
+                        out.push(',');
+                        rest = &after[end..];
+                        if !rest.starts_with([' ', '\t']) && !rest.is_empty() {
+                            out.push(' ');
+                        }
+                    }
+                    None => {
+                        out.push(',');
+                        rest = &rest[1..];
+                    }
+                }
+            }
+            _ => {
+                out.push(ch);
+                rest = &rest[ch.len_utf8()..];
+            }
+        }
+    }
+    out
+}
+
+/// Returns the byte length of the string, rune or raw string literal at the
+/// start of `s`, or all of `s` if it's unterminated.
+fn literal_len(s: &str) -> usize {
+    let quote = s.as_bytes()[0];
+    let bytes = s.as_bytes();
+    let mut i = 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' if quote != b'`' => i += 2,
+            b if b == quote => return i + 1,
+            _ => i += 1,
+        }
+    }
+    s.len()
+}
+
+/// Collapses runs of blank lines, strips trailing whitespace and ends the
+/// output with a single newline, as `gofmt` does. Also returns the output
+/// line each input line ended up on.
+fn tidy(code: &str) -> (String, Vec<usize>) {
+    let mut out = String::with_capacity(code.len());
+    let mut lines = Vec::new();
+    let mut line_count = 0;
+    let mut blank_run = false;
+    for line in code.split('\n') {
+        lines.push(line_count);
+        let line = line.trim_end();
+        if line.is_empty() {
+            if !blank_run && !out.is_empty() {
+                out.push('\n');
+                line_count += 1;
+            }
+            blank_run = true;
+            continue;
+        }
+        blank_run = false;
+        out.push_str(line);
+        out.push('\n');
+        line_count += 1;
+    }
+    while out.ends_with("\n\n") {
+        out.pop();
+    }
+    (out, lines)
+}
+
+/// Respaces the binary expressions and one-line function bodies in `code`
+/// the way `gofmt` prints them, moving `marks` along with the text. Go code
+/// copied from the source is otherwise left as written.
+fn gofmt_spacing(code: &str, marks: &mut [Mark]) -> String {
+    let mut parser = Parser::new();
+    parser
+        .set_language(&tree_sitter_go::LANGUAGE.into())
+        .expect("Go grammar is compatible");
+    let tree = parser
+        .parse(code, None)
+        .expect("parsing without a timeout or cancellation flag can't fail");
+    let mut edits = Vec::new();
+    respace(tree.root_node(), 1, code, &mut edits);
+    edits.sort_by_key(|(range, _)| range.start);
+
+    let mut out = String::with_capacity(code.len());
+    let mut copied = 0;
+    for (range, blank) in &edits {
+        out.push_str(&code[copied..range.start]);
+        out.push_str(blank);
+        copied = range.end;
+    }
+    out.push_str(&code[copied..]);
+
+    // Edits never cross a line, so a mark's line moves by the edits before
+    // it and its length changes by the edits on it.
+    let shift = |edits: &[(Range<usize>, &str)]| -> isize {
+        edits
+            .iter()
+            .map(|(range, blank)| blank.len() as isize - range.len() as isize)
+            .sum()
+    };
+    for mark in marks {
+        let start = edits.partition_point(|(range, _)| range.start < mark.pos);
+        let end =
+            edits.partition_point(|(range, _)| range.start < mark.pos + mark.go_col + mark.length);
+        mark.pos = mark.pos.saturating_add_signed(shift(&edits[..start]));
+        mark.length = mark.length.saturating_add_signed(shift(&edits[start..end]));
+    }
+    out
+}
+
+/// Collects the whitespace edits for `node`. `depth` is the nesting depth
+/// `go/printer` tracks to decide whether a binary expression gets blanks
+/// around its operator: it grows inside calls with several arguments, in
+/// indexes and on the right of operators, and parentheses undo one level.
+fn respace(node: Node, depth: usize, code: &str, edits: &mut Vec<(Range<usize>, &'static str)>) {
+    if node.is_error() {
+        return;
+    }
+    let mut cursor = node.walk();
+    match node.kind() {
+        "binary_expression" => {
+            let (Some(left), Some(op), Some(right)) = (
+                node.child_by_field_name("left"),
+                node.child_by_field_name("operator"),
+                node.child_by_field_name("right"),
+            ) else {
+                return;
+            };
+            let prec = precedence(node);
+            let blank = if prec < cutoff(node, depth) { " " } else { "" };
+            set_gap(left.end_byte()..op.start_byte(), blank, code, edits);
+            set_gap(op.end_byte()..right.start_byte(), blank, code, edits);
+            let same = usize::from(precedence(left) != prec);
+            respace(left, depth + same, code, edits);
+            respace(right, depth + 1, code, edits);
+        }
+        "parenthesized_expression" => {
+            for child in node.named_children(&mut cursor) {
+                respace(child, depth.saturating_sub(1).max(1), code, edits);
+            }
+        }
+        "call_expression" | "type_conversion_expression" => {
+            let arguments = node.child_by_field_name("arguments");
+            let count = arguments.map_or(0, |args| {
+                let mut cursor = args.walk();
+                let count = args
+                    .named_children(&mut cursor)
+                    .filter(|arg| !arg.is_extra())
+                    .count();
+                count
+            });
+            let depth = depth + usize::from(count > 1);
+            for child in node.named_children(&mut cursor) {
+                if Some(child) == arguments {
+                    let mut cursor = child.walk();
+                    for arg in child.named_children(&mut cursor) {
+                        respace(arg, depth, code, edits);
+                    }
+                } else {
+                    respace(child, depth, code, edits);
+                }
+            }
+        }
+        "unary_expression"
+        | "selector_expression"
+        | "type_assertion_expression"
+        | "variadic_argument" => {
+            // A pointer dereference starts over, like any other expression.
+            let pointer = node
+                .child_by_field_name("operator")
+                .is_some_and(|op| op.kind() == "*");
+            let depth = if pointer { 1 } else { depth };
+            for child in node.named_children(&mut cursor) {
+                respace(child, depth, code, edits);
+            }
+        }
+        "index_expression" | "slice_expression" => {
+            let operand = node.child_by_field_name("operand");
+            for child in node.named_children(&mut cursor) {
+                let depth = if Some(child) == operand { 1 } else { depth + 1 };
+                respace(child, depth, code, edits);
+            }
+        }
+        kind => {
+            if matches!(
+                kind,
+                "func_literal" | "function_declaration" | "method_declaration"
+            ) {
+                if let Some(body) = node.child_by_field_name("body") {
+                    one_line_body(body, code, edits);
+                }
+            }
+            for child in node.named_children(&mut cursor) {
+                respace(child, 1, code, edits);
+            }
+        }
+    }
+}
+
+/// Prints a function body kept on one line as `{ stmt }`, or `{}` when
+/// it's empty.
+fn one_line_body(body: Node, code: &str, edits: &mut Vec<(Range<usize>, &'static str)>) {
+    if body.start_position().row != body.end_position().row {
+        return;
+    }
+    let mut cursor = body.walk();
+    let tokens: Vec<Node> = body.children(&mut cursor).collect();
+    let [open, .., close] = tokens[..] else {
+        return;
+    };
+    if open.kind() != "{" || close.kind() != "}" {
+        return;
+    }
+    match body.named_child(0) {
+        Some(statements) => {
+            set_gap(open.end_byte()..statements.start_byte(), " ", code, edits);
+            set_gap(statements.end_byte()..close.start_byte(), " ", code, edits);
+        }
+        None => set_gap(open.end_byte()..close.start_byte(), "", code, edits),
+    }
+}
+
+/// Replaces the spaces between two tokens on the same line with `blank`.
+fn set_gap(
+    gap: Range<usize>,
+    blank: &'static str,
+    code: &str,
+    edits: &mut Vec<(Range<usize>, &'static str)>,
+) {
+    let text = &code[gap.clone()];
+    if text != blank && text.bytes().all(|b| matches!(b, b' ' | b'\t')) {
+        edits.push((gap, blank));
+    }
+}
+
+/// Returns the precedence of a binary expression's operator, or 0 for any
+/// other node.
+fn precedence(node: Node) -> usize {
+    if node.kind() != "binary_expression" {
+        return 0;
+    }
+    match node.child_by_field_name("operator").map(|op| op.kind()) {
+        Some("*" | "/" | "%" | "<<" | ">>" | "&" | "&^") => 5,
+        Some("+" | "-" | "|" | "^") => 4,
+        Some("==" | "!=" | "<" | "<=" | ">" | ">=") => 3,
+        Some("&&") => 2,
+        Some("||") => 1,
+        _ => 0,
+    }
+}
+
+/// Returns the precedence below which `go/printer` puts blanks around the
+/// operators of a binary expression at `depth`.
+fn cutoff(node: Node, depth: usize) -> usize {
+    let (has4, has5, max_problem) = walk_binary(node);
+    if max_problem > 0 {
+        return max_problem + 1;
+    }
+    match (has4 && has5, depth == 1) {
+        (true, true) => 5,
+        (false, true) => 6,
+        _ => 4,
+    }
+}
+
+/// Reports whether a binary expression, not counting parts in parentheses,
+/// has operators of precedence 4 and 5, and the highest precedence that
+/// must keep a blank so adjacent operators don't read as one, as in `a / *p`.
+fn walk_binary(node: Node) -> (bool, bool, usize) {
+    let prec = precedence(node);
+    let (mut has4, mut has5, mut max_problem) = (prec == 4, prec == 5, 0);
+    let op = node
+        .child_by_field_name("operator")
+        .map_or("", |op| op.kind());
+    if let Some(left) = node.child_by_field_name("left") {
+        if precedence(left) >= prec {
+            let (h4, h5, problem) = walk_binary(left);
+            (has4, has5, max_problem) = (has4 || h4, has5 || h5, max_problem.max(problem));
+        }
+    }
+    if let Some(right) = node.child_by_field_name("right") {
+        if precedence(right) > prec {
+            let (h4, h5, problem) = walk_binary(right);
+            (has4, has5, max_problem) = (has4 || h4, has5 || h5, max_problem.max(problem));
+        } else if right.kind() == "unary_expression" {
+            let unary = right
+                .child_by_field_name("operator")
+                .map_or("", |op| op.kind());
+            match (op, unary) {
+                ("/", "*") | ("&", "&") | ("&", "^") => max_problem = 5,
+                ("+", "+") | ("-", "-") => max_problem = max_problem.max(4),
+                _ => {}
+            }
+        }
+    }
+    (has4, has5, max_problem)
+}
+
+/// Builds the import block the way `goimports` leaves it after the Go
+/// generator's output: unused imports dropped, missing `fmt` and go-tui
+/// imports added, and each group sorted with standard library packages
+/// first.
+fn import_block(imports: &[Import], body: &str) -> String {
+    let tui_import = Import {
+        alias: "tui".to_string(),
+        path: TUI_IMPORT_PATH.to_string(),
+    };
+    // The generator writes the file's imports as one group and the go-tui
+    // import as a second group when it's missing.
+    let mut groups = if imports.is_empty() {
+        vec![vec![tui_import]]
+    } else if imports.iter().any(|imp| imp.path == TUI_IMPORT_PATH) {
+        vec![imports.to_vec()]
+    } else {
+        vec![imports.to_vec(), vec![tui_import]]
+    };
+
+    let used = used_packages(body);
+    for group in &mut groups {
+        group.retain(|imp| imp.alias == "_" || imp.alias == "." || used.contains(import_name(imp)));
+    }
+    groups.retain(|group| !group.is_empty());
+
+    let names: HashSet<&str> = groups.iter().flatten().map(import_name).collect();
+    let add_fmt = used.contains("fmt") && !names.contains("fmt");
+    let add_tui = used.contains("tui") && !names.contains("tui");
+    if add_fmt {
+        if groups.is_empty() {
+            groups.push(Vec::new());
+        }
+        groups[0].push(Import {
+            alias: String::new(),
+            path: "fmt".to_string(),
+        });
+    }
+    if add_tui {
+        if groups.is_empty() {
+            groups.push(Vec::new());
+        }
+        let target = groups
+            .iter()
+            .position(|group| group.iter().any(|imp| imp.path == TUI_IMPORT_PATH))
+            .or_else(|| {
+                groups
+                    .iter()
+                    .position(|group| group.iter().any(|imp| import_group(&imp.path) == 1))
+            })
+            .unwrap_or(0);
+        groups[target].push(Import {
+            alias: String::new(),
+            path: TUI_IMPORT_PATH.to_string(),
+        });
+    }
+    if groups.is_empty() {
+        return String::new();
+    }
+
+    let mut out = String::from("import (\n");
+    for (i, group) in groups.iter_mut().enumerate() {
+        group.sort_by(|a, b| {
+            (import_group(&a.path), &a.path, &a.alias).cmp(&(
+                import_group(&b.path),
+                &b.path,
+                &b.alias,
+            ))
+        });
+        group.dedup();
+        if i > 0 {
+            out.push('\n');
+        }
+        for (j, imp) in group.iter().enumerate() {
+            if j > 0 && import_group(&imp.path) != import_group(&group[j - 1].path) {
+                out.push('\n');
+            }
+            out.push('\t');
+            if !imp.alias.is_empty() {
+                out.push_str(&imp.alias);
+                out.push(' ');
+            }
+            out.push_str(&go_quote(&imp.path));
+            out.push('\n');
+        }
+    }
+    out.push_str(")\n\n");
+    out
+}
+
+/// Returns the name an import is referred to by: its alias, or the package
+/// name `goimports` assumes from the path.
+fn import_name(imp: &Import) -> &str {
+    if !imp.alias.is_empty() {
+        return &imp.alias;
+    }
+    let mut parts = imp.path.rsplit('/');
+    let mut base = parts.next().unwrap_or_default();
+    let is_version = base
+        .strip_prefix('v')
+        .is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()));
+    if is_version {
+        if let Some(parent) = parts.next() {
+            base = parent;
+        }
+    }
+    let base = base.strip_prefix("go-").unwrap_or(base);
+    let end = base
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(base.len());
+    &base[..end]
+}
+
+/// Finds the package-like names used as `name.Selector` in Go code, skipping
+/// literals and comments.
+fn used_packages(code: &str) -> HashSet<&str> {
+    let mut used = HashSet::new();
+    let bytes = code.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if code[i..].starts_with("//") {
+            i += code[i..].find('\n').unwrap_or(code.len() - i);
+        } else if code[i..].starts_with("/*") {
+            i += code[i + 2..]
+                .find("*/")
+                .map_or(code.len() - i, |end| end + 4);
+        } else if matches!(b, b'"' | b'\'' | b'`') {
+            i += literal_len(&code[i..]);
+        } else if b.is_ascii_alphabetic() || b == b'_' {
+            let start = i;
+            while i < bytes.len() && is_word_byte(bytes[i]) {
+                i += 1;
+            }
+            let qualified = start > 0 && bytes[start - 1] == b'.';
+            let selects = bytes.get(i) == Some(&b'.')
+                && bytes
+                    .get(i + 1)
+                    .is_some_and(|&b| b.is_ascii_alphabetic() || b == b'_');
+            if selects && !qualified {
+                used.insert(&code[start..i]);
+            }
+        } else if b.is_ascii_digit() {
+            while i < bytes.len() && (is_word_byte(bytes[i]) || bytes[i] == b'.') {
+                i += 1;
+            }
+        } else {
+            i += 1;
+        }
+    }
+    used
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Runs a golden file, copied from the Go generator's testdata or the
+    /// examples, through [`generate`].
+    fn check_golden(name: &str, source: &str, want: &str) {
+        let got = generate(source, &format!("{name}.gsx"))
+            .unwrap_or_else(|err| panic!("{name}: generate failed: {err}"));
+        assert_eq!(got, want, "{name}: output mismatch");
+    }
+
+    #[test]
+    fn test_golden_files() {
+        let cases = [
+            (
+                "simple",
+                include_str!("../../test/codegen/testdata/simple.gsx"),
+                include_str!("../../test/codegen/testdata/simple_gsx.go"),
+            ),
+            (
+                "other",
+                include_str!("../../test/codegen/testdata/other.gsx"),
+                include_str!("../../test/codegen/testdata/other_gsx.go"),
+            ),
+            (
+                "input",
+                include_str!("../../test/codegen/testdata/input.gsx"),
+                include_str!("../../test/codegen/testdata/input_gsx.go"),
+            ),
+            (
+                "textarea",
+                include_str!("../../test/codegen/testdata/textarea.gsx"),
+                include_str!("../../test/codegen/testdata/textarea_gsx.go"),
+            ),
+            (
+                "markdown",
+                include_str!("../../test/codegen/testdata/markdown.gsx"),
+                include_str!("../../test/codegen/testdata/markdown_gsx.go"),
+            ),
+            (
+                "modal",
+                include_str!("../../test/codegen/testdata/modal.gsx"),
+                include_str!("../../test/codegen/testdata/modal_gsx.go"),
+            ),
+            (
+                "complex",
+                include_str!("../../test/codegen/testdata/complex.gsx"),
+                include_str!("../../test/codegen/testdata/complex_gsx.go"),
+            ),
+            (
+                "alias",
+                include_str!("../../test/codegen/testdata/alias.gsx"),
+                include_str!("../../test/codegen/testdata/alias_gsx.go"),
+            ),
+            (
+                "watchers",
+                include_str!("../../test/codegen/testdata/watchers.gsx"),
+                include_str!("../../test/codegen/testdata/watchers_gsx.go"),
+            ),
+            (
+                "sidebar",
+                include_str!("../../test/codegen/testdata/sidebar.gsx"),
+                include_str!("../../test/codegen/testdata/sidebar_gsx.go"),
+            ),
+            (
+                "dump",
+                include_str!("../../test/codegen/testdata/dump.gsx"),
+                include_str!("../../test/codegen/testdata/dump_gsx.go"),
+            ),
+        ];
+        for (name, source, want) in cases {
+            check_golden(name, source, want);
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            generate("templ A() {\n}\n", ""),
+            Err(CodegenError::MissingPackage)
+        );
+        assert_eq!(
+            generate("package x\n\ntempl A( {\n}\n", ""),
+            Err(CodegenError::Syntax { line: 3, column: 9 })
+        );
+        assert_eq!(
+            generate("package x\n\ntempl List[T any](items []T) {\n}\n", ""),
+            Err(CodegenError::TypeParameters {
+                line: 3,
+                column: 11
+            })
+        );
+    }
+
+    #[test]
+    fn test_gofmt_spacing() {
+        let cases = [
+            ("x := a+b\n", "x := a + b\n"),
+            ("f(a + b, c)\n", "f(a+b, c)\n"),
+            ("x := a*b+c\n", "x := a*b + c\n"),
+            ("x := (a+b) * c\n", "x := (a + b) * c\n"),
+            ("f(a, b / *p)\n", "f(a, b / *p)\n"),
+            ("f(a, xs[i + 1])\n", "f(a, xs[i+1])\n"),
+            ("x := a /* c */ + b\n", "x := a /* c */ + b\n"),
+            ("f(func() {g() })\n", "f(func() { g() })\n"),
+            ("f(func() { })\n", "f(func() {})\n"),
+            ("x := \"a+b\" +\n\tc\n", "x := \"a+b\" +\n\tc\n"),
+        ];
+        for (code, want) in cases {
+            assert_eq!(gofmt_spacing(code, &mut []), want, "{code}");
+        }
+
+        // Marks move with the text, and grow with the edits on their line.
+        let mut marks = [Mark {
+            pos: 9,
+            go_col: 1,
+            gsx: Point::new(3, 0),
+            length: 8,
+        }];
+        let code = "x := a+b\n\ty := c+d\n";
+        assert_eq!(
+            gofmt_spacing(code, &mut marks),
+            "x := a + b\n\ty := c + d\n"
+        );
+        assert_eq!((marks[0].pos, marks[0].length), (11, 10));
+    }
+
+    #[test]
+    fn test_switch() {
+        // A switch is Go code, copied out as written.
+        let source = "package x\n\ntempl Label(n int) {\n\tlabel := \"\"\n\tswitch n {\n\tcase 1:\n\t\tlabel = \"one\"\n\tdefault:\n\t\tlabel = \"many\"\n\t}\n\t<span>{label}</span>\n}\n";
+        let got = generate(source, "").unwrap();
+        assert!(
+            got.contains("\tswitch n {\n\tcase 1:\n\t\tlabel = \"one\"\n\tdefault:\n\t\tlabel = \"many\"\n\t}\n"),
+            "{got}"
+        );
+
+        // Cases holding elements are generated like the branches of an if.
+        let source = "package x\n\ntempl Label(n int) {\n\t<div>\n\t\tswitch n {\n\t\tcase 0, 1:\n\t\t\tlabel := \"few\"\n\t\t\t<span>{label}</span>\n\t\tdefault:\n\t\t\t<hr />\n\t\t}\n\t</div>\n}\n";
+        let got = generate(source, "").unwrap();
+        assert!(
+            got.contains(
+                "\t__tui_0 := tui.New()
+\tswitch n {
+\tcase 0, 1:
+\t\tlabel := \"few\"
+\t\t__tui_1 := tui.New(
+\t\t\ttui.WithText(label),
+\t\t)
+\t\t__tui_0.AddChild(__tui_1)
+\tdefault:
+\t\t__tui_2 := tui.New(
+\t\t\ttui.WithHR(),
+\t\t)
+\t\t__tui_0.AddChild(__tui_2)
+\t}
+"
+            ),
+            "{got}"
+        );
+
+        // A tagless switch at the top of the body picks the root.
+        let source = "package x\n\ntempl Label(n int) {\n\tswitch {\n\tcase n > 1:\n\t\t<span>many</span>\n\t}\n}\n";
+        let got = generate(source, "").unwrap();
+        assert!(
+            got.contains("\tvar __tui_0 *tui.Element\n\tswitch {\n\tcase n > 1:\n"),
+            "{got}"
+        );
+        assert!(
+            got.contains("\t\tif __tui_0 == nil {\n\t\t\t__tui_0 = __tui_1\n\t\t}\n\t}\n"),
+            "{got}"
+        );
+
+        // Switching on a state value rebuilds the cases when it changes.
+        let source = "package x\n\ntempl Live() {\n\tcount := tui.NewState(0)\n\t<div>\n\t\tswitch count.Get() {\n\t\tcase 0:\n\t\t\t<span>none</span>\n\t\t}\n\t</div>\n}\n";
+        let got = generate(source, "").unwrap();
+        assert!(
+            got.contains("\t\t__cond_0.RemoveAllChildren()\n\t\tswitch count.Get() {\n"),
+            "{got}"
+        );
+        assert!(
+            got.contains("\tcount.Bind(func(_ int) { __update___cond_0() })\n"),
+            "{got}"
+        );
+    }
+
+    #[test]
+    fn test_state_bindings() {
+        let source = "package x\n\ntempl Counter() {\n\tcount := tui.NewState(0)\n\tname := tui.NewState(\"a\")\n\t<span>{fmt.Sprintf(\"%d\", count.Get())}</span>\n\t<div>{name.Get() + fmt.Sprint(count.Get())}</div>\n}\n";
+        let got = generate(source, "").unwrap();
+        let want = "\tcount := tui.NewState(0)
+	name := tui.NewState(\"a\")
+	__tui_0 := tui.New(
+		tui.WithText(fmt.Sprintf(\"%d\", count.Get())),
+	)
+	__tui_1 := tui.New()
+	__tui_2 := tui.New(tui.WithText(name.Get() + fmt.Sprint(count.Get())))
+	__tui_1.AddChild(__tui_2)
+
+	// State bindings
+	count.Bind(func(_ int) {
+		__tui_0.SetText(fmt.Sprintf(\"%d\", count.Get()))
+	})
+	__update___tui_2 := func() { __tui_2.SetText(name.Get() + fmt.Sprint(count.Get())) }
+	name.Bind(func(_ string) { __update___tui_2() })
+	count.Bind(func(_ int) { __update___tui_2() })
+
+	__bindApp := func(app *tui.App) {
+		count.BindApp(app)
+		name.BindApp(app)
+	}
+";
+        assert!(got.contains(want), "{got}");
+    }
+
+    #[test]
+    fn test_reactive_if_and_for() {
+        let source = "package x\n\ntempl List(items *tui.State[[]string], show *tui.State[bool]) {\n\t<div gap={1}>\n\t\tif show.Get() {\n\t\t\t<span>Shown</span>\n\t\t}\n\t\tfor _, item := range items.Get() {\n\t\t\t<span>{item}</span>\n\t\t}\n\t</div>\n}\n";
+        let got = generate(source, "").unwrap();
+        let want = "\t__cond_0 := tui.New()
+	__tui_0.AddChild(__cond_0)
+	__update___cond_0 := func() {
+		__cond_0.RemoveAllChildren()
+		if show.Get() {
+			__tui_1 := tui.New(
+				tui.WithText(\"Shown\"),
+			)
+			__cond_0.AddChild(__tui_1)
+		}
+	}
+	__update___cond_0()
+	show.Bind(func(_ bool) { __update___cond_0() })
+	__loop_0_style := __tui_0.LayoutStyle()
+	__loop_0 := tui.New(tui.WithDirection(__loop_0_style.Direction), tui.WithGap(__loop_0_style.Gap))
+	__tui_0.AddChild(__loop_0)
+	__update___loop_0 := func() {
+		__loop_0.RemoveAllChildren()
+		for __idx_0, item := range items.Get() {
+			_ = __idx_0
+			__tui_2 := tui.New(
+				tui.WithText(item),
+			)
+			__loop_0.AddChild(__tui_2)
+		}
+	}
+	__update___loop_0()
+	items.Bind(func(_ []string) { __update___loop_0() })
+";
+        assert!(got.contains(want), "{got}");
+        // Parameters are bound by the caller.
+        assert!(got.contains("\t__bindApp := func(app *tui.App) {\n\t}\n"));
+    }
+
+    #[test]
+    fn test_refs_and_component_views() {
+        let source = "package x\n\ntempl Card(title string) {\n\t<div>\n\t\t<span>{title}</span>\n\t\t{children...}\n\t</div>\n}\n\ntempl Page(rows []string, wide bool) {\n\t<div ref={box}>\n\t\tfor i, row := range rows {\n\t\t\t<span ref={cells} key={i}>{row}</span>\n\t\t\t@Card(row)\n\t\t}\n\t\tif wide {\n\t\t\t<hr ref={rule} />\n\t\t\t@Card(\"wide\") {\n\t\t\t\t<span>Body</span>\n\t\t\t}\n\t\t}\n\t</div>\n}\n";
+        let got = generate(source, "").unwrap();
+        assert!(got.contains("func Card(title string, children []*tui.Element) *CardView {"));
+        assert!(got.contains(
+            "type PageView struct {
+	Root      *tui.Element
+	watchers  []tui.Watcher
+	bindApp   func(*tui.App)
+	unbindApp func()
+	Box       *tui.Element
+	Cells     map[string]*tui.Element
+	Rule      *tui.Element // may be nil
+}
+"
+        ));
+        let want = "\tvar __tui_2_views []*CardView
+	var __tui_4 *CardView
+	__tui_0 := tui.New()
+	box.Set(__tui_0)
+	for i, row := range rows {
+		_ = i
+		__tui_1 := tui.New(
+			tui.WithText(row),
+		)
+		cells.Put(i, __tui_1)
+		__tui_0.AddChild(__tui_1)
+		__tui_2 := Card(row)
+		__tui_0.AddChild(__tui_2.Root)
+		__tui_2_views = append(__tui_2_views, __tui_2)
+	}
+";
+        assert!(got.contains(want), "{got}");
+        assert!(got.contains(
+            "\tif wide {
+		__tui_3 := tui.New(
+			tui.WithHR(),
+		)
+		rule.Set(__tui_3)
+		__tui_0.AddChild(__tui_3)
+		__tui_5_children := []*tui.Element{}
+		__tui_6 := tui.New(
+			tui.WithText(\"Body\"),
+		)
+		__tui_5_children = append(__tui_5_children, __tui_6)
+		__tui_4 = Card(\"wide\", __tui_5_children)
+		__tui_0.AddChild(__tui_4.Root)
+	}
+
+	for _, __cv := range __tui_2_views {
+		watchers = append(watchers, __cv.GetWatchers()...)
+	}
+	if __tui_4 != nil {
+		watchers = append(watchers, __tui_4.GetWatchers()...)
+	}
+"
+        ));
+        assert!(got.contains(
+            "\tview = PageView{
+		Root:      __tui_0,
+		watchers:  watchers,
+		bindApp:   __bindApp,
+		unbindApp: __unbindApp,
+		Box:       box.El(),
+		Cells:     cells.All(),
+		Rule:      rule.El(),
+	}
+"
+        ));
+    }
+
+    #[test]
+    fn test_method_component() {
+        let source = "package x\n\nimport tui \"github.com/grindlemire/go-tui\"\n\ntype list struct {\n\titems    []string\n\tevents   *tui.Events[string]\n\tselected *tui.State[int]\n\tonPick   func(string)\n}\n\nfunc (l *list) KeyMap() tui.KeyMap {\n\treturn nil\n}\n\ntempl Row(text string) {\n\t<span>{text}</span>\n}\n\ntempl (l *list) Render() {\n\tfor _, item := range l.items {\n\t\t@Row(item)\n\t\t@Other(item)\n\t}\n}\n";
+        let got = generate(source, "").unwrap();
+        let want = "func (l *list) Render(app *tui.App) *tui.Element {
+	var __tui_0 *tui.Element
+	__tui_1 := tui.New()
+	for __idx_0, item := range l.items {
+		_ = __idx_0
+		__tui_2 := Row(item)
+		__tui_1.AddChild(__tui_2.Root)
+		__tui_3 := app.Mount(l, tui.MountKey(0, __idx_0), func() tui.Component {
+			return Other(item)
+		})
+		__tui_1.AddChild(__tui_3)
+	}
+	if __tui_0 == nil {
+		__tui_0 = __tui_1
+	}
+
+	return __tui_0
+}
+
+func (l *list) UpdateProps(fresh tui.Component) {
+	f, ok := fresh.(*list)
+	if !ok {
+		return
+	}
+	l.items = f.items
+	l.events = f.events
+}
+
+var _ tui.PropsUpdater = (*list)(nil)
+
+// bindAppFields is generated. It wires the component's *tui.App,
+// State, Events, and TextArea fields to app. When you override BindApp,
+// call this helper instead of hand-maintaining the delegation list.
+func (l *list) bindAppFields(app *tui.App) {
+	if l.events != nil {
+		l.events.BindApp(app)
+	}
+	if l.selected != nil {
+		l.selected.BindApp(app)
+	}
+}
+
+func (l *list) BindApp(app *tui.App) {
+	l.bindAppFields(app)
+}
+
+var _ tui.AppBinder = (*list)(nil)
+
+// unbindAppFields is generated. It detaches topic-based Events
+// subscriptions and any component-expression AppUnbinder fields.
+// Call this from your UnbindApp if you override it.
+func (l *list) unbindAppFields() {
+	if l.events != nil {
+		l.events.UnbindApp()
+	}
+}
+
+func (l *list) UnbindApp() {
+	l.unbindAppFields()
+}
+
+var _ tui.AppUnbinder = (*list)(nil)
+
+// Compile-time interface satisfaction checks.
+var (
+	_ tui.KeyListener = (*list)(nil)
+)
+";
+        assert!(got.ends_with(want), "{got}");
+    }
+
+    #[test]
+    fn test_let_bindings_and_children_slot() {
+        let source = "package x\n\ntempl Panel(n int) {\n\theader := <p>{n}</p>\n\t<div>\n\t\t{header}\n\t\t<span>a \\{b\\} c</span>\n\t\t<br />\n\t\t{children...}\n\t</div>\n}\n";
+        let got = generate(source, "").unwrap();
+        let want = "\theader := tui.New(
+		tui.WithText(n),
+	)
+	__tui_0 := tui.New()
+	__tui_0.AddChild(header)
+	__tui_1 := tui.New(
+		tui.WithText(\"a {b} c\"),
+	)
+	__tui_0.AddChild(__tui_1)
+	__tui_2 := tui.New(
+		tui.WithWidth(0),
+		tui.WithHeight(1),
+	)
+	__tui_0.AddChild(__tui_2)
+	for _, __child := range children {
+		__tui_0.AddChild(__child)
+	}
+";
+        assert!(got.contains(want), "{got}");
+    }
+
+    #[test]
+    fn test_imports() {
+        let source = "package x\n\nimport (\n\t\"strings\"\n\t\"os\"\n\t\"github.com/a/b/v2\"\n)\n\ntempl A(s string) {\n\t<span>{b.Upper(s)}</span>\n\t<span>{42}</span>\n}\n";
+        let got = generate(source, "").unwrap();
+        assert!(
+            got.contains("import (\n\t\"fmt\"\n\n\t\"github.com/a/b/v2\"\n\ttui \"github.com/grindlemire/go-tui\"\n)\n"),
+            "{got}"
+        );
+        assert!(got.contains("tui.WithText(fmt.Sprint(42))"));
+    }
+
+    #[test]
+    fn test_source_map() {
+        let source = "package x\n\nimport \"fmt\"\n\ntype model struct {\n\tn int\n}\n\nfunc helper() string {\n\treturn fmt.Sprint(1)\n}\n\ntempl Card() {\n\t<div>{children...}</div>\n}\n\ntempl View(items []string) {\n\tcount := tui.NewState(len(items))\n\t<div>\n\t\tif len(items) > 0 {\n\t\t\t@Card()\n\t\t}\n\t</div>\n}\n";
+        let (go, map) = generate_with_source_map(source, "x.gsx").unwrap();
+        assert_eq!(go, generate(source, "x.gsx").unwrap());
+        assert_eq!(map.source_file, "x.gsx");
+
+        let go_lines: Vec<&str> = go.lines().collect();
+        let gsx_lines: Vec<&str> = source.lines().collect();
+        let mapped: Vec<(&str, usize)> = map
+            .mappings
+            .iter()
+            .map(|m| {
+                let go_text = &go_lines[m.go_line][m.go_col..m.go_col + m.length];
+                let gsx_text = &gsx_lines[m.gsx_line][m.gsx_col..m.gsx_col + m.length];
+                assert_eq!(go_text, gsx_text);
+                (go_text, m.gsx_line)
+            })
+            .collect();
+        assert_eq!(
+            mapped,
+            [
+                ("type model struct {", 4),
+                ("\tn int", 5),
+                ("}", 6),
+                ("func helper() string {", 8),
+                ("\treturn fmt.Sprint(1)", 9),
+                ("}", 10),
+                ("count := tui.NewState(len(items))", 17),
+            ]
+        );
+
+        // The statement comes after the hoisted view declaration.
+        let (go_line, go_col) = map.gsx_to_go(17, 10).unwrap();
+        assert_eq!(go_lines[go_line - 1], "\tvar __tui_1 *CardView");
+        assert_eq!(&go_lines[go_line][go_col..], "tui.NewState(len(items))");
+        assert_eq!(map.go_to_gsx(go_line, go_col), Some((17, 10)));
+    }
+
+    #[test]
+    fn test_helpers() {
+        assert_eq!(
+            go_quote("a\"b\\\n\u{7f}é\u{200b}"),
+            "\"a\\\"b\\\\\\n\\x7fé\\u200b\""
+        );
+        assert_eq!(text_expr(" -3.5 "), "fmt.Sprint(-3.5)");
+        assert_eq!(text_expr("x"), "x");
+        assert_eq!(infer_type("[]pkg.Item{}"), "[]pkg.Item");
+        assert_eq!(infer_type("map[string]int{}"), "map[string]int");
+        assert_eq!(infer_type("&Model{a: 1}"), "*Model");
+        assert_eq!(infer_type("-1.25"), "float64");
+        assert_eq!(infer_type("f()"), "any");
+        assert_eq!(state_param_type("*tui.State[[]int]"), Some("[]int"));
+        assert_eq!(state_param_type("tui.State[]"), None);
+        assert_eq!(
+            comments_before_commas("f(\"a, /* x */\", /* c */ b)"),
+            "f(\"a, /* x */\" /* c */, b)"
+        );
+        assert_eq!(
+            import_name(&Import {
+                alias: String::new(),
+                path: "gopkg.in/yaml.v3".to_string()
+            }),
+            "yaml"
+        );
+    }
+}
@@ -0,0 +1,509 @@
+//! Human-readable syntax errors for a parsed GSX tree.
+//!
+//! Tree-sitter recovers from syntax errors by wrapping unparseable input in
+//! `ERROR` nodes and inserting zero-width `MISSING` nodes for tokens it had to
+//! assume. [`diagnostics`] turns those into messages worded like the errors
+//! from `tui generate`, e.g. "unclosed <div> element" or "missing ')' in
+//! parameter_list". Closing tags that name a different element parse fine,
+//! so they are checked separately; [`check_tag_balance`] reports just the tag
+//! errors.
+//!
+//! ```
+//! let code = "templ Header(title string {\n}\n";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&tree_sitter_gsx::language()).unwrap();
+//! let tree = parser.parse(code, None).unwrap();
+//!
+//! let errors = tree_sitter_gsx::diagnostics::diagnostics(&tree, code);
+//! assert_eq!(errors[0].to_string(), "1:26: error: missing ')' in parameter_list");
+//! ```
+
+use std::fmt;
+
+use tree_sitter::{Node, Range, Tree};
+
+/// A syntax error with its source location.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// The byte and line/column span the error covers. Missing tokens have an
+    /// empty span where the token was expected.
+    pub range: Range,
+    pub message: String,
+    /// An optional suggestion for fixing the error.
+    pub hint: Option<String>,
+    /// Other locations that help explain the error, such as the opening
+    /// delimiter of an unclosed pair.
+    pub related: Vec<Related>,
+}
+
+/// A secondary location attached to a [`Diagnostic`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Related {
+    pub range: Range,
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}: error: {}",
+            self.range.start_point.row + 1,
+            self.range.start_point.column + 1,
+            self.message
+        )?;
+        if let Some(hint) = &self.hint {
+            write!(f, " ({hint})")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Diagnostic {}
+
+/// Returns one diagnostic per `ERROR` or `MISSING` node and per mismatched
+/// closing tag in `tree`, in source order. Errors nested inside an `ERROR`
+/// node are not reported separately.
+pub fn diagnostics(tree: &Tree, source: &str) -> Vec<Diagnostic> {
+    let mut out = Vec::new();
+    walk(tree, |node| {
+        if node.is_error() {
+            out.push(error_diagnostic(node, source));
+            false
+        } else if node.is_missing() {
+            out.push(missing_diagnostic(node));
+            false
+        } else {
+            out.extend(mismatched_tag(node, source));
+            true
+        }
+    });
+    out
+}
+
+/// Returns the mismatched closing tags and unclosed elements in `tree`, in
+/// source order. Each diagnostic points at the offending tag and relates it
+/// to the tag it should pair with.
+///
+/// ```
+/// let code = "templ Card() {\n\t<box>\n\t\t<span>hi</span>\n\t</div>\n}\n";
+/// let mut parser = tree_sitter::Parser::new();
+/// parser.set_language(&tree_sitter_gsx::language()).unwrap();
+/// let tree = parser.parse(code, None).unwrap();
+///
+/// let errors = tree_sitter_gsx::diagnostics::check_tag_balance(&tree, code);
+/// assert_eq!(
+///     errors[0].to_string(),
+///     "4:2: error: mismatched closing tag: expected </box>, got </div>"
+/// );
+/// assert_eq!(errors[0].related[0].message, "to match this <box>");
+/// ```
+pub fn check_tag_balance(tree: &Tree, source: &str) -> Vec<Diagnostic> {
+    let mut out = Vec::new();
+    walk(tree, |node| {
+        if node.is_error() {
+            let mut cursor = node.walk();
+            let children: Vec<Node> = node.children(&mut cursor).collect();
+            out.extend(unclosed_tag(&children, node.next_sibling(), source));
+            false
+        } else {
+            out.extend(mismatched_tag(node, source));
+            true
+        }
+    });
+    out
+}
+
+/// Visits the nodes of `tree` in source order, descending into a node's
+/// children when `visit` returns true.
+fn walk(tree: &Tree, mut visit: impl FnMut(Node) -> bool) {
+    let mut cursor = tree.walk();
+    let mut entering = true;
+    loop {
+        let descend = entering && visit(cursor.node());
+        if descend && cursor.goto_first_child() {
+            continue;
+        }
+        if cursor.goto_next_sibling() {
+            entering = true;
+            continue;
+        }
+        if !cursor.goto_parent() {
+            break;
+        }
+        entering = false;
+    }
+}
+
+fn missing_diagnostic(node: Node) -> Diagnostic {
+    let parent = node.parent().map_or("source_file", |p| p.kind());
+    let message = if node.is_named() {
+        format!("missing {} in {parent}", node.kind())
+    } else {
+        format!("missing '{}' in {parent}", node.kind())
+    };
+
+    let mut related = Vec::new();
+    if let Some(opener) = opener_for(node.kind()).and_then(|open| matching_opener(node, open)) {
+        related.push(Related {
+            range: opener.range(),
+            message: format!("to match this '{}'", opener.kind()),
+        });
+    }
+
+    Diagnostic {
+        range: node.range(),
+        message,
+        hint: None,
+        related,
+    }
+}
+
+fn error_diagnostic(node: Node, source: &str) -> Diagnostic {
+    let parent = node.parent().map_or("source_file", |p| p.kind());
+    let mut cursor = node.walk();
+    let children: Vec<Node> = node.children(&mut cursor).collect();
+    let next = node.next_sibling();
+
+    if let Some(diagnostic) = unclosed_tag(&children, next, source) {
+        return diagnostic;
+    }
+
+    if let Some(opener) = unclosed_delimiter(&children) {
+        let close = closer_for(opener.kind()).unwrap_or_default();
+        return Diagnostic {
+            range: opener.range(),
+            message: format!("unclosed '{}' in {parent}", opener.kind()),
+            hint: None,
+            related: next
+                .map(|next| Related {
+                    range: next.range(),
+                    message: format!("expected '{close}' before {}", describe(next, source)),
+                })
+                .into_iter()
+                .collect(),
+        };
+    }
+
+    let first = first_leaf(node);
+    let found = if first != node && first.is_named() {
+        describe(first, source)
+    } else {
+        let text = source[node.byte_range()].split_whitespace().next();
+        format!("'{}'", text.unwrap_or_default())
+    };
+    let message = if parent == "source_file" {
+        format!("unexpected {found}, expected func, templ, type, const, or var")
+    } else {
+        format!("unexpected {found} in {parent}")
+    };
+    Diagnostic {
+        range: node.range(),
+        message,
+        hint: None,
+        related: Vec::new(),
+    }
+}
+
+/// Recognises an `ERROR` that starts with an element whose start tag or
+/// element body was never closed.
+fn unclosed_tag(children: &[Node], next: Option<Node>, source: &str) -> Option<Diagnostic> {
+    let open = children
+        .iter()
+        .position(|c| !c.is_named() && c.kind() == "<")?;
+    let tag = children
+        .get(open + 1)
+        .filter(|c| c.kind() == "identifier")?;
+    let tag = &source[tag.byte_range()];
+    let start = children[open];
+
+    let close = children[open + 1..]
+        .iter()
+        .find(|c| !c.is_named() && matches!(c.kind(), ">" | "/>"));
+    let Some(close) = close else {
+        let last = children.last().unwrap_or(&start);
+        return Some(Diagnostic {
+            range: span(start, *last),
+            message: format!("unclosed <{tag}> tag, expected '>' or '/>'"),
+            hint: None,
+            related: Vec::new(),
+        });
+    };
+
+    Some(Diagnostic {
+        range: span(start, *close),
+        message: format!("unclosed <{tag}> element"),
+        hint: Some(format!(
+            "add </{tag}> or use <{tag} /> for an empty element"
+        )),
+        related: next
+            .map(|next| Related {
+                range: next.range(),
+                message: format!("expected </{tag}> before {}", describe(next, source)),
+            })
+            .into_iter()
+            .collect(),
+    })
+}
+
+/// Reports an `element_with_children` whose closing tag names a different
+/// element.
+fn mismatched_tag(node: Node, source: &str) -> Option<Diagnostic> {
+    if node.kind() != "element_with_children" {
+        return None;
+    }
+    let tag = node.child_by_field_name("tag")?;
+    let closing = node.child_by_field_name("closing_tag")?;
+    let (tag_name, found) = (&source[tag.byte_range()], &source[closing.byte_range()]);
+    if tag_name == found || closing.is_missing() {
+        return None;
+    }
+
+    let mut cursor = node.walk();
+    let children: Vec<Node> = node.children(&mut cursor).collect();
+    let start_tag_end = children.iter().find(|c| !c.is_named() && c.kind() == ">")?;
+    let end_tag_start = children
+        .iter()
+        .rfind(|c| !c.is_named() && c.kind() == "</")?;
+    let end_tag_end = children.last()?;
+    Some(Diagnostic {
+        range: span(*end_tag_start, *end_tag_end),
+        message: format!("mismatched closing tag: expected </{tag_name}>, got </{found}>"),
+        hint: None,
+        related: vec![Related {
+            range: span(children[0], *start_tag_end),
+            message: format!("to match this <{tag_name}>"),
+        }],
+    })
+}
+
+/// Returns the last opening delimiter among `children` that has no closer.
+fn unclosed_delimiter<'tree>(children: &[Node<'tree>]) -> Option<Node<'tree>> {
+    let mut stack = Vec::new();
+    for child in children.iter().filter(|c| !c.is_named()) {
+        if closer_for(child.kind()).is_some() {
+            stack.push(*child);
+        } else if opener_for(child.kind()).is_some() {
+            stack.pop();
+        }
+    }
+    stack.pop()
+}
+
+/// Walks back over the siblings of a `MISSING` closer to find its opener.
+fn matching_opener<'tree>(node: Node<'tree>, open: &str) -> Option<Node<'tree>> {
+    let close = node.kind();
+    let mut depth = 0;
+    let mut current = node.prev_sibling();
+    while let Some(sibling) = current {
+        if !sibling.is_named() {
+            if sibling.kind() == close {
+                depth += 1;
+            } else if sibling.kind() == open {
+                if depth == 0 {
+                    return Some(sibling);
+                }
+                depth -= 1;
+            }
+        }
+        current = sibling.prev_sibling();
+    }
+    None
+}
+
+fn opener_for(close: &str) -> Option<&'static str> {
+    match close {
+        ")" => Some("("),
+        "}" => Some("{"),
+        "]" => Some("["),
+        _ => None,
+    }
+}
+
+fn closer_for(open: &str) -> Option<&'static str> {
+    match open {
+        "(" => Some(")"),
+        "{" => Some("}"),
+        "[" => Some("]"),
+        _ => None,
+    }
+}
+
+fn first_leaf(node: Node) -> Node {
+    let mut node = node;
+    while let Some(child) = node.child(0) {
+        node = child;
+    }
+    node
+}
+
+/// Describes a token for a message: `'}'` for punctuation, `identifier 'x'`
+/// for named tokens.
+fn describe(node: Node, source: &str) -> String {
+    let text = source[node.byte_range()].lines().next().unwrap_or_default();
+    if node.is_named() && node.child_count() == 0 && node.kind() != text {
+        format!("{} '{text}'", node.kind())
+    } else {
+        format!("'{text}'")
+    }
+}
+
+fn span(start: Node, end: Node) -> Range {
+    Range {
+        start_byte: start.start_byte(),
+        end_byte: end.end_byte(),
+        start_point: start.start_position(),
+        end_point: end.end_position(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(code: &str) -> Vec<Diagnostic> {
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&crate::language()).unwrap();
+        let tree = parser.parse(code, None).unwrap();
+        diagnostics(&tree, code)
+    }
+
+    fn text<'a>(code: &'a str, range: &Range) -> &'a str {
+        &code[range.start_byte..range.end_byte]
+    }
+
+    #[test]
+    fn test_valid_file_has_no_diagnostics() {
+        assert!(check("package main\n\ntempl A() {\n\t<hr />\n}\n").is_empty());
+    }
+
+    #[test]
+    fn test_missing_token() {
+        let code = "templ A(x int {\n}\n";
+        let diags = check(code);
+        assert_eq!(diags.len(), 1);
+        let diag = &diags[0];
+        assert_eq!(diag.message, "missing ')' in parameter_list");
+        assert_eq!(diag.range.start_byte, 13);
+        assert_eq!(diag.range.start_byte, diag.range.end_byte);
+        assert_eq!(
+            diag.to_string(),
+            "1:14: error: missing ')' in parameter_list"
+        );
+
+        assert_eq!(diag.related.len(), 1);
+        assert_eq!(diag.related[0].message, "to match this '('");
+        assert_eq!(diag.related[0].range.start_byte, 7);
+    }
+
+    #[test]
+    fn test_missing_named_node() {
+        let diags = check("templ A() {\n\tfor x := range {\n\t}\n}\n");
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].message, "missing identifier in for_clause");
+        assert!(diags[0].related.is_empty());
+    }
+
+    #[test]
+    fn test_unclosed_element() {
+        let code = "templ A() {\n\t<div>\n\t\t<span>x</span>\n}\n";
+        let diags = check(code);
+        assert_eq!(diags.len(), 1);
+        let diag = &diags[0];
+        assert_eq!(diag.message, "unclosed <div> element");
+        assert_eq!(text(code, &diag.range), "<div>");
+        assert_eq!(diag.range.start_point, tree_sitter::Point::new(1, 1));
+        assert_eq!(
+            diag.to_string(),
+            "2:2: error: unclosed <div> element (add </div> or use <div /> for an empty element)"
+        );
+        assert_eq!(diag.related.len(), 1);
+        assert_eq!(diag.related[0].message, "expected </div> before '}'");
+        assert_eq!(diag.related[0].range.start_point.row, 3);
+    }
+
+    #[test]
+    fn test_mismatched_closing_tag() {
+        let code = "templ A() {\n\t<box class=\"p-1\">\n\t\t<span>x</span>\n\t</div>\n}\n";
+        let diags = check(code);
+        assert_eq!(diags.len(), 1);
+        let diag = &diags[0];
+        assert_eq!(
+            diag.message,
+            "mismatched closing tag: expected </box>, got </div>"
+        );
+        assert_eq!(text(code, &diag.range), "</div>");
+        assert_eq!(diag.related.len(), 1);
+        assert_eq!(diag.related[0].message, "to match this <box>");
+        assert_eq!(text(code, &diag.related[0].range), "<box class=\"p-1\">");
+    }
+
+    #[test]
+    fn test_check_tag_balance() {
+        let code =
+            "templ A(x int {\n\t<div>\n\t\t<span>x</box>\n\t</div>\n}\n\ntempl B() {\n\t<div>\n}\n";
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&crate::language()).unwrap();
+        let tree = parser.parse(code, None).unwrap();
+
+        // The missing ')' is a syntax error, not a tag error.
+        let messages: Vec<_> = check_tag_balance(&tree, code)
+            .into_iter()
+            .map(|d| d.message)
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                "mismatched closing tag: expected </span>, got </box>",
+                "unclosed <div> element"
+            ]
+        );
+        assert_eq!(diagnostics(&tree, code).len(), 3);
+    }
+
+    #[test]
+    fn test_unclosed_start_tag() {
+        let code = "templ A() {\n\t<div class=\"a\"\n}\n";
+        let diags = check(code);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].message, "unclosed <div> tag, expected '>' or '/>'");
+        assert_eq!(text(code, &diags[0].range), "<div class=\"a\"");
+    }
+
+    #[test]
+    fn test_unclosed_delimiter() {
+        let code = "templ A() {\n\t@Foo(1 +\n}\n";
+        let diags = check(code);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].message, "unclosed '(' in component_body");
+        assert_eq!(text(code, &diags[0].range), "(");
+        assert_eq!(diags[0].related[0].message, "expected ')' before '}'");
+    }
+
+    #[test]
+    fn test_unexpected_input() {
+        let diags = check("templ A() {\n\t@@\n}\n");
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].message, "unexpected '@@' in component_body");
+
+        let diags = check("foo bar\ntempl A() {\n}\n");
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].message,
+            "unexpected identifier 'foo', expected func, templ, type, const, or var"
+        );
+    }
+
+    #[test]
+    fn test_diagnostics_in_source_order() {
+        let code = "templ A(x int {\n}\n\ntempl B() {\n\t@@\n}\n";
+        let messages: Vec<_> = check(code).into_iter().map(|d| d.message).collect();
+        assert_eq!(
+            messages,
+            [
+                "missing ')' in parameter_list",
+                "unexpected '@@' in component_body"
+            ]
+        );
+    }
+}
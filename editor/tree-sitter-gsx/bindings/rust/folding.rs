@@ -0,0 +1,199 @@
+//! Foldable line ranges.
+//!
+//! [`folding_ranges`] folds the nodes [`FOLDS_QUERY`] captures. Bracketed
+//! bodies and elements fold up to the line before their closing delimiter,
+//! so the `}` or closing tag stays visible. Switch clauses have no closing
+//! delimiter and fold through their last line. Runs of line comments and
+//! multi-line block comments fold as a whole.
+//!
+//! ```
+//! use tree_sitter_gsx::folding::{folding_ranges, FoldingRangeKind};
+//!
+//! let code = "templ Card() {\n\t<div>\n\t\t<hr />\n\t</div>\n}\n";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&tree_sitter_gsx::language()).unwrap();
+//! let tree = parser.parse(code, None).unwrap();
+//!
+//! let ranges = folding_ranges(&tree);
+//! assert_eq!((ranges[1].start_line, ranges[1].end_line), (1, 2));
+//! assert_eq!(ranges[1].kind, FoldingRangeKind::Region);
+//! ```
+//!
+//! [`FOLDS_QUERY`]: crate::FOLDS_QUERY
+
+use std::sync::OnceLock;
+
+use tree_sitter::{Node, Query, QueryCursor, StreamingIterator, Tree};
+
+/// What a [`FoldingRange`] covers, named after the LSP folding range kinds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FoldingRangeKind {
+    /// A body, element or clause.
+    Region,
+    /// The parenthesised list of an `import (...)`.
+    Imports,
+    /// A block comment or a run of line comments.
+    Comment,
+}
+
+impl FoldingRangeKind {
+    /// Returns the kind's LSP name, e.g. `imports`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FoldingRangeKind::Region => "region",
+            FoldingRangeKind::Imports => "imports",
+            FoldingRangeKind::Comment => "comment",
+        }
+    }
+}
+
+/// A range of zero-based lines that folds into its first line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FoldingRange {
+    pub start_line: usize,
+    /// The last line folded away.
+    pub end_line: usize,
+    pub kind: FoldingRangeKind,
+}
+
+fn query() -> &'static Query {
+    static QUERY: OnceLock<Query> = OnceLock::new();
+    QUERY.get_or_init(|| {
+        Query::new(&crate::language(), crate::FOLDS_QUERY).expect("folds query is valid")
+    })
+}
+
+/// Returns the folding ranges of `tree`, ordered by start line.
+pub fn folding_ranges(tree: &Tree) -> Vec<FoldingRange> {
+    let mut ranges = Vec::new();
+    let mut comments: Option<(usize, usize)> = None;
+    let mut cursor = QueryCursor::new();
+    // The query has no predicates, so it never looks at the text.
+    let mut captures = cursor.captures(query(), tree.root_node(), &[][..]);
+    while let Some((m, index)) = captures.next() {
+        let node = m.captures[*index].node;
+        if node.kind() == "comment" {
+            comments = comment_group(node, comments, &mut ranges);
+            continue;
+        }
+        let start = node.start_position().row;
+        let end = match node.kind() {
+            "case_clause" | "default_clause" => node.end_position().row,
+            _ => node.end_position().row.saturating_sub(1),
+        };
+        let kind = match node.kind() {
+            "import_spec_list" => FoldingRangeKind::Imports,
+            _ => FoldingRangeKind::Region,
+        };
+        push(start, end, kind, &mut ranges);
+    }
+    if let Some((start, end)) = comments {
+        push(start, end, FoldingRangeKind::Comment, &mut ranges);
+    }
+
+    ranges.sort_by_key(|r| (r.start_line, std::cmp::Reverse(r.end_line)));
+    ranges
+}
+
+/// Extends the current run of line comments with `node`, or flushes it and
+/// starts a new one. Comments spanning several lines fold on their own.
+fn comment_group(
+    node: Node,
+    group: Option<(usize, usize)>,
+    ranges: &mut Vec<FoldingRange>,
+) -> Option<(usize, usize)> {
+    let start = node.start_position().row;
+    let end = node.end_position().row;
+    if end > start {
+        if let Some((s, e)) = group {
+            push(s, e, FoldingRangeKind::Comment, ranges);
+        }
+        push(start, end, FoldingRangeKind::Comment, ranges);
+        return None;
+    }
+    match group {
+        Some((s, e)) if e + 1 == start && !code_before(node) => Some((s, end)),
+        Some((s, e)) => {
+            push(s, e, FoldingRangeKind::Comment, ranges);
+            Some((start, end))
+        }
+        None if code_before(node) => None,
+        None => Some((start, end)),
+    }
+}
+
+/// Reports whether a comment trails code on its line.
+fn code_before(node: Node) -> bool {
+    let row = node.start_position().row;
+    node.prev_sibling()
+        .is_some_and(|prev| prev.end_position().row == row)
+}
+
+fn push(start: usize, end: usize, kind: FoldingRangeKind, ranges: &mut Vec<FoldingRange>) {
+    if end > start {
+        ranges.push(FoldingRange {
+            start_line: start,
+            end_line: end,
+            kind,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter::Parser;
+
+    use super::*;
+
+    fn folds(code: &str) -> Vec<(usize, usize, &'static str)> {
+        let mut parser = Parser::new();
+        parser.set_language(&crate::language()).unwrap();
+        let tree = parser.parse(code, None).unwrap();
+        folding_ranges(&tree)
+            .into_iter()
+            .map(|r| (r.start_line, r.end_line, r.kind.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_folding_ranges() {
+        let code = "package main\n\nimport (\n\t\"fmt\"\n\t\"strings\"\n)\n\n// Card renders a card.\n// It has a title.\ntempl Card(title string) {\n\t<div>\n\t\t<span>{title}</span>\n\t</div>\n\t<p>one line</p>\n\tif title != \"\" {\n\t\t<hr />\n\t}\n}\n";
+        assert_eq!(
+            folds(code),
+            [
+                (2, 4, "imports"),
+                (7, 8, "comment"),
+                (9, 16, "region"),
+                (10, 11, "region"),
+                (14, 15, "region"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_go_bodies_and_block_comments() {
+        let code = "/*\nLicense\n*/\n\ntype Props struct {\n\tName string\n}\n\nfunc helper() string {\n\treturn \"x\"\n}\n\nvar (\n\ta = 1\n\tb = 2\n)\n";
+        assert_eq!(
+            folds(code),
+            [
+                (0, 2, "comment"),
+                (4, 5, "region"),
+                (8, 9, "region"),
+                (12, 14, "region"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_switch_folds() {
+        let code = "templ A(n int) {\n\tswitch n {\n\tcase 0:\n\t\t<hr />\n\t\t<br />\n\tdefault:\n\t\t<hr />\n\t}\n}\n";
+        let regions: Vec<_> = folds(code).into_iter().map(|(s, e, _)| (s, e)).collect();
+        assert_eq!(regions, [(0, 7), (1, 6), (2, 4), (5, 6)]);
+    }
+
+    #[test]
+    fn test_trailing_comments_do_not_group() {
+        let code = "templ A() {\n\t<hr /> // one\n\t// two\n\t// three\n\t<br />\n}\n";
+        assert_eq!(folds(code), [(0, 4, "region"), (2, 3, "comment")]);
+    }
+}
@@ -0,0 +1,2130 @@
+//! Formats GSX source the same way `tui fmt` does.
+//!
+//! The output matches the Go `internal/formatter` package: elements are
+//! indented one level per nesting depth, attribute values are normalised
+//! (`ref` and `key` move to the front, bare booleans become `={true}`, numbers
+//! become `{n}`), user layout choices such as multi-line attributes, inline
+//! children and blank lines between siblings are preserved, and comments stay
+//! attached to the node that follows them.
+//!
+//! Two parts of `tui fmt` need the Go toolchain and are approximated here.
+//! Top-level Go functions and declarations are emitted verbatim instead of
+//! being run through `gofmt`, and import fixing only sorts, de-duplicates and
+//! adds the go-tui import that generated code needs; it can't resolve new
+//! packages or drop unused ones without type information.
+//!
+//! ```
+//! use tree_sitter_gsx::format::Formatter;
+//!
+//! let source = "package main\n\ntempl Hello() {\n<span>Hello</span>\n}\n";
+//! let mut formatter = Formatter::new();
+//! formatter.fix_imports = false;
+//! assert_eq!(
+//!     formatter.format(source).unwrap(),
+//!     "package main\n\ntempl Hello() {\n\t<span>Hello</span>\n}\n",
+//! );
+//! ```
+
+use std::fmt;
+
+use tree_sitter::{Node, Parser, Tree};
+
+pub(crate) const TUI_IMPORT_PATH: &str = "github.com/grindlemire/go-tui";
+
+/// Formats `.gsx` source code.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Formatter {
+    /// The string used for one level of indentation.
+    pub indent: String,
+    /// Sort and de-duplicate imports, and add the go-tui import when the file
+    /// declares components.
+    pub fix_imports: bool,
+}
+
+/// The result of [`Formatter::format_with_result`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FormatResult {
+    /// The formatted source.
+    pub content: String,
+    /// Whether the formatted source differs from the input.
+    pub changed: bool,
+}
+
+/// An error that prevents a file from being formatted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FormatError {
+    /// The file has no `package` clause.
+    MissingPackage,
+    /// The parser couldn't make sense of the source at this position.
+    Syntax { line: usize, column: usize },
+    /// A closing tag doesn't match the element it closes.
+    MismatchedTag {
+        line: usize,
+        column: usize,
+        expected: String,
+        found: String,
+    },
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatError::MissingPackage => write!(f, "expected package declaration"),
+            FormatError::Syntax { line, column } => write!(f, "{line}:{column}: syntax error"),
+            FormatError::MismatchedTag {
+                line,
+                column,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{line}:{column}: mismatched closing tag: expected </{expected}>, got </{found}>"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for FormatError {}
+
+impl Default for Formatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Formatter {
+    /// Creates a formatter with the same defaults as `tui fmt`: tab
+    /// indentation and import fixing enabled.
+    pub fn new() -> Self {
+        Self {
+            indent: "\t".to_string(),
+            fix_imports: true,
+        }
+    }
+
+    /// Parses and formats `source`.
+    pub fn format(&self, source: &str) -> Result<String, FormatError> {
+        let mut parser = Parser::new();
+        parser
+            .set_language(&crate::language())
+            .expect("Error loading GSX grammar");
+        let tree = parser
+            .parse(source, None)
+            .expect("parsing without a timeout or cancellation flag can't fail");
+        self.format_tree(&tree, source)
+    }
+
+    /// Formats `source`, reporting whether the output differs from the input.
+    pub fn format_with_result(&self, source: &str) -> Result<FormatResult, FormatError> {
+        let content = self.format(source)?;
+        let changed = content != source;
+        Ok(FormatResult { content, changed })
+    }
+
+    /// Formats an already parsed tree. `source` must be the text `tree` was
+    /// parsed from.
+    pub fn format_tree(&self, tree: &Tree, source: &str) -> Result<String, FormatError> {
+        let root = tree.root_node();
+        if let Some(node) = first_error(root) {
+            let pos = node.start_position();
+            return Err(FormatError::Syntax {
+                line: pos.row + 1,
+                column: pos.column + 1,
+            });
+        }
+
+        let mut builder = Builder::new(root, source);
+        let mut file = builder.file(root)?;
+        if self.fix_imports {
+            fix_imports(&mut file);
+        }
+
+        let mut printer = Printer {
+            indent: &self.indent,
+            depth: 0,
+            buf: String::with_capacity(source.len()),
+        };
+        printer.file(&file);
+        Ok(printer.buf)
+    }
+}
+
+/// Returns the first `ERROR` or `MISSING` node in document order.
+pub(crate) fn first_error(node: Node<'_>) -> Option<Node<'_>> {
+    if !node.has_error() {
+        return None;
+    }
+    if node.is_error() || node.is_missing() {
+        return Some(node);
+    }
+    let mut cursor = node.walk();
+    let found = node.children(&mut cursor).find_map(first_error);
+    found.or(Some(node))
+}
+
+// The document model below mirrors the parts of the tuigen AST the Go printer
+// reads, including the layout flags its parser records.
+
+struct File {
+    leading: Vec<Comment>,
+    package: String,
+    imports: Vec<Import>,
+    decls: Vec<Decl>,
+    orphans: Vec<Comment>,
+}
+
+#[derive(Clone, PartialEq, Eq)]
+struct Import {
+    alias: String,
+    path: String,
+}
+
+enum Decl {
+    Component(Component),
+    Go { leading: Vec<Comment>, code: String },
+}
+
+struct Component {
+    leading: Vec<Comment>,
+    trailing: Option<Comment>,
+    name: String,
+    /// The `[T any]` list of a generic component, as written.
+    type_params: String,
+    receiver: Option<String>,
+    /// Parameters as `(names, type)`. Grouped names are joined with `, `,
+    /// a variadic type keeps its `...`, and an unnamed parameter has no names.
+    params: Vec<(String, String)>,
+    orphans: Vec<Comment>,
+    body: Vec<BodyNode>,
+}
+
+struct BodyNode {
+    leading: Vec<Comment>,
+    trailing: Option<Comment>,
+    blank_line_before: bool,
+    item: Item,
+}
+
+enum Item {
+    Element(Element),
+    For(ForLoop),
+    If(IfStmt),
+    Let(LetBinding),
+    Call(ComponentCall),
+    Expr(String),
+    Code(String),
+    Text(String),
+    ChildrenSlot,
+}
+
+struct Element {
+    tag: String,
+    attrs: Vec<Attribute>,
+    ref_expr: Option<String>,
+    key_expr: Option<String>,
+    self_close: bool,
+    multi_line_attrs: bool,
+    closing_bracket_newline: bool,
+    inline_children: bool,
+    children: Vec<BodyNode>,
+    orphans: Vec<Comment>,
+}
+
+struct Attribute {
+    name: String,
+    value: AttrValue,
+    row: usize,
+}
+
+enum AttrValue {
+    Bool(bool),
+    Str(String),
+    Int(i64),
+    Float(f64),
+    Expr(String),
+}
+
+struct ForLoop {
+    index: String,
+    value: String,
+    iterable: String,
+    orphans: Vec<Comment>,
+    body: Vec<BodyNode>,
+}
+
+struct IfStmt {
+    condition: String,
+    orphans: Vec<Comment>,
+    then: Vec<BodyNode>,
+    r#else: Vec<BodyNode>,
+}
+
+struct LetBinding {
+    name: String,
+    value: LetValue,
+}
+
+enum LetValue {
+    Element(Element),
+    Call { name: String, args: String },
+}
+
+struct ComponentCall {
+    /// The callee, including any `pkg.` or receiver qualifier.
+    name: String,
+    args: String,
+    multi_line_args: bool,
+    children: Vec<BodyNode>,
+}
+
+#[derive(Clone)]
+struct Comment {
+    text: String,
+    is_block: bool,
+    blank_line_before: bool,
+    start_byte: usize,
+    start_row: usize,
+}
+
+/// A `{ ... }` body with the comment after its opening brace and the ones
+/// left before its closing brace.
+struct Body {
+    trailing: Option<Comment>,
+    orphans: Vec<Comment>,
+    nodes: Vec<BodyNode>,
+}
+
+/// How a list of children is parsed. Only element children record blank
+/// lines, and only some bodies keep comments left over before the closing
+/// brace.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum BodyKind {
+    Element,
+    Block,
+    Discard,
+}
+
+/// Builds the document model from a syntax tree, handing out comments in
+/// source order the way the tuigen lexer queues them.
+struct Builder<'s> {
+    source: &'s str,
+    comments: Vec<Comment>,
+    next_comment: usize,
+}
+
+impl<'s> Builder<'s> {
+    fn new(root: Node<'_>, source: &'s str) -> Self {
+        let mut nodes = Vec::new();
+        collect_comment_nodes(root, &mut nodes);
+
+        let mut comments: Vec<Comment> = Vec::with_capacity(nodes.len());
+        let mut prev_end: Option<(usize, usize)> = None;
+        for node in nodes {
+            let text = &source[node.byte_range()];
+            // A blank line only separates two comments when nothing but
+            // whitespace sits between them.
+            let blank_line_before = prev_end.is_some_and(|(end_byte, end_row)| {
+                source[end_byte..node.start_byte()].trim().is_empty()
+                    && node.start_position().row > end_row + 1
+            });
+            comments.push(Comment {
+                text: text.to_string(),
+                is_block: text.starts_with("/*"),
+                blank_line_before,
+                start_byte: node.start_byte(),
+                start_row: node.start_position().row,
+            });
+            prev_end = Some((node.end_byte(), node.end_position().row));
+        }
+
+        Self {
+            source,
+            comments,
+            next_comment: 0,
+        }
+    }
+
+    fn text(&self, node: Node<'_>) -> &'s str {
+        &self.source[node.byte_range()]
+    }
+
+    /// Takes every queued comment that starts before `byte`.
+    fn take_comments_before(&mut self, byte: usize) -> Vec<Comment> {
+        let start = self.next_comment;
+        while self.next_comment < self.comments.len()
+            && self.comments[self.next_comment].start_byte < byte
+        {
+            self.next_comment += 1;
+        }
+        self.comments[start..self.next_comment].to_vec()
+    }
+
+    /// Takes the next comment if it follows `byte` on the same line.
+    fn take_trailing_comment(&mut self, byte: usize, row: usize) -> Option<Comment> {
+        let comment = self.comments.get(self.next_comment)?;
+        if comment.start_row != row || !self.source[byte..comment.start_byte].trim().is_empty() {
+            return None;
+        }
+        self.next_comment += 1;
+        Some(comment.clone())
+    }
+
+    fn file(&mut self, root: Node<'_>) -> Result<File, FormatError> {
+        let mut cursor = root.walk();
+        let children: Vec<Node<'_>> = root
+            .named_children(&mut cursor)
+            .filter(|n| !n.is_extra())
+            .collect();
+
+        let package = children
+            .iter()
+            .find(|n| n.kind() == "package_clause")
+            .ok_or(FormatError::MissingPackage)?;
+        let leading = self.take_comments_before(package.start_byte());
+        let package_name = package
+            .child_by_field_name("name")
+            .map(|n| self.text(n).to_string())
+            .unwrap_or_default();
+
+        let mut imports = Vec::new();
+        let mut decls = Vec::new();
+        for &child in &children {
+            match child.kind() {
+                "import_section" => self.imports(child, &mut imports),
+                "component_declaration" => {
+                    let leading = self.take_comments_before(child.start_byte());
+                    decls.push(Decl::Component(self.component(child, leading)?));
+                }
+                "function_declaration" | "type_struct_declaration" | "go_declaration" => {
+                    let leading = self.take_comments_before(child.start_byte());
+                    decls.push(Decl::Go {
+                        leading,
+                        code: self.text(child).to_string(),
+                    });
+                    // tuigen discards comments after a braced declaration's
+                    // closing bracket along with the captured Go code.
+                    let braced = child.kind() != "go_declaration"
+                        || child.child_by_field_name("body").is_some();
+                    if braced {
+                        self.take_trailing_comment(child.end_byte(), child.end_position().row);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let orphans = self.take_comments_before(usize::MAX);
+        Ok(File {
+            leading,
+            package: package_name,
+            imports,
+            decls,
+            orphans,
+        })
+    }
+
+    fn imports(&mut self, section: Node<'_>, imports: &mut Vec<Import>) {
+        let mut specs = Vec::new();
+        collect_kind(section, "import_spec", &mut specs);
+        for spec in specs {
+            let alias = spec
+                .child_by_field_name("alias")
+                .map(|n| self.text(n).to_string())
+                .unwrap_or_default();
+            let path = spec
+                .child_by_field_name("path")
+                .map(|n| self.text(n).trim_matches('"').to_string())
+                .unwrap_or_default();
+            imports.push(Import { alias, path });
+        }
+    }
+
+    fn component(
+        &mut self,
+        node: Node<'_>,
+        leading: Vec<Comment>,
+    ) -> Result<Component, FormatError> {
+        let name = field_text(node, "name", self.source).to_string();
+        let type_params = field_text(node, "type_parameters", self.source).to_string();
+        let receiver = node.child_by_field_name("receiver").map(|recv| {
+            format!(
+                "{} {}",
+                field_text(recv, "name", self.source),
+                field_text(recv, "type", self.source)
+            )
+        });
+        let mut params = Vec::new();
+        if let Some(list) = node.child_by_field_name("parameters") {
+            let mut cursor = list.walk();
+            for param in list.named_children(&mut cursor) {
+                let ty = field_text(param, "type", self.source);
+                let ty = match param.kind() {
+                    "parameter" => ty.to_string(),
+                    "variadic_parameter" => format!("...{ty}"),
+                    _ => continue,
+                };
+                let mut names = param.walk();
+                let names: Vec<&str> = param
+                    .children_by_field_name("name", &mut names)
+                    .map(|name| self.text(name))
+                    .collect();
+                params.push((names.join(", "), ty));
+            }
+        }
+
+        let body = node
+            .child_by_field_name("body")
+            .expect("checked by first_error");
+        let body = self.braced_body(body, BodyKind::Block)?;
+        Ok(Component {
+            leading,
+            trailing: body.trailing,
+            name,
+            type_params,
+            receiver,
+            params,
+            orphans: body.orphans,
+            body: body.nodes,
+        })
+    }
+
+    /// Reads a `{ ... }` body: the comment trailing the opening brace, the
+    /// children, and the comments left before the closing brace.
+    fn braced_body(&mut self, node: Node<'_>, kind: BodyKind) -> Result<Body, FormatError> {
+        let trailing = match kind {
+            BodyKind::Discard => None,
+            _ => node.child(0).and_then(|open| {
+                self.take_trailing_comment(open.end_byte(), open.end_position().row)
+            }),
+        };
+        let mut cursor = node.walk();
+        let items: Vec<Node<'_>> = node
+            .named_children(&mut cursor)
+            .filter(|n| !n.is_extra())
+            .collect();
+        let (children, orphans) = self.children(&items, node.end_byte() - 1, kind)?;
+        Ok(Body {
+            trailing,
+            orphans,
+            nodes: children,
+        })
+    }
+
+    /// Builds a list of body nodes followed by the comments left before
+    /// `close_byte`.
+    fn children(
+        &mut self,
+        items: &[Node<'_>],
+        close_byte: usize,
+        kind: BodyKind,
+    ) -> Result<(Vec<BodyNode>, Vec<Comment>), FormatError> {
+        let mut nodes = Vec::new();
+        // The end of the previous child, unless tuigen skipped the newlines
+        // after it while looking ahead.
+        let mut prev_end: Option<usize> = None;
+        for &child in items {
+            let leading = self.take_comments_before(child.start_byte());
+            let blank_line_before = kind == BodyKind::Element
+                && !nodes.is_empty()
+                && prev_end
+                    .is_some_and(|end| newlines_between(self.source, end, child.start_byte()) >= 2);
+
+            let (item, trailing) = self.item(child)?;
+            let leading = if matches!(item, Item::Text(_)) {
+                Vec::new()
+            } else {
+                leading
+            };
+            prev_end = (!skips_newlines_after(child)).then(|| child.end_byte());
+            nodes.push(BodyNode {
+                leading,
+                trailing,
+                blank_line_before,
+                item,
+            });
+        }
+        let orphans = self.take_comments_before(close_byte);
+        let orphans = if kind == BodyKind::Discard {
+            Vec::new()
+        } else {
+            orphans
+        };
+        Ok((nodes, orphans))
+    }
+
+    fn item(&mut self, node: Node<'_>) -> Result<(Item, Option<Comment>), FormatError> {
+        let node = if node.kind() == "element" {
+            node.named_child(0).expect("checked by first_error")
+        } else {
+            node
+        };
+        Ok(match node.kind() {
+            "self_closing_element" | "element_with_children" => {
+                let (element, trailing) = self.element(node)?;
+                (Item::Element(element), trailing)
+            }
+            "for_statement" => {
+                let clause = node
+                    .child_by_field_name("clause")
+                    .expect("checked by first_error");
+                let index = clause
+                    .child_by_field_name("index")
+                    .map(|n| self.text(n).to_string())
+                    .unwrap_or_default();
+                let value = field_text(clause, "value", self.source).to_string();
+                let iterable = field_text(clause, "collection", self.source).to_string();
+                let body = node
+                    .child_by_field_name("body")
+                    .expect("checked by first_error");
+                let body = self.braced_body(body, BodyKind::Block)?;
+                let item = Item::For(ForLoop {
+                    index,
+                    value,
+                    iterable,
+                    orphans: body.orphans,
+                    body: body.nodes,
+                });
+                (item, body.trailing)
+            }
+            "if_statement" => {
+                let (stmt, trailing) = self.if_stmt(node)?;
+                (Item::If(stmt), trailing)
+            }
+            "let_binding" => {
+                let name = field_text(node, "name", self.source).to_string();
+                let value = node
+                    .child_by_field_name("value")
+                    .expect("checked by first_error");
+                let value = if value.kind() == "component_call" {
+                    let call = self.call(value)?;
+                    LetValue::Call {
+                        name: call.name,
+                        args: call.args,
+                    }
+                } else {
+                    // The element's own trailing comment is consumed but
+                    // never printed, as in tuigen.
+                    let element = value.named_child(0).expect("checked by first_error");
+                    LetValue::Element(self.element(element)?.0)
+                };
+                (Item::Let(LetBinding { name, value }), None)
+            }
+            "component_call" => (Item::Call(self.call(node)?), None),
+            "go_expression" => (Item::Expr(go_expression_code(node, self.source)), None),
+            // tuigen reads a switch as a raw Go statement and keeps it verbatim
+            "state_declaration" | "switch_statement" | "go_statement" => {
+                (Item::Code(self.text(node).to_string()), None)
+            }
+            "children_slot" => (Item::ChildrenSlot, None),
+            "text_content" => {
+                let text = self.text(node).split_whitespace().collect::<Vec<_>>();
+                (Item::Text(text.join(" ")), None)
+            }
+            _ => unreachable!("unexpected body node {}", node.kind()),
+        })
+    }
+
+    fn if_stmt(&mut self, node: Node<'_>) -> Result<(IfStmt, Option<Comment>), FormatError> {
+        let condition = field_text(node, "condition", self.source).to_string();
+        let consequence = node
+            .child_by_field_name("consequence")
+            .expect("checked by first_error");
+        let then = self.braced_body(consequence, BodyKind::Block)?;
+
+        let r#else = match node.child_by_field_name("alternative") {
+            Some(alt) if alt.kind() == "if_statement" => {
+                let (stmt, trailing) = self.if_stmt(alt)?;
+                vec![BodyNode {
+                    leading: Vec::new(),
+                    trailing,
+                    blank_line_before: false,
+                    item: Item::If(stmt),
+                }]
+            }
+            Some(alt) => self.braced_body(alt, BodyKind::Discard)?.nodes,
+            None => Vec::new(),
+        };
+
+        let stmt = IfStmt {
+            condition,
+            orphans: then.orphans,
+            then: then.nodes,
+            r#else,
+        };
+        Ok((stmt, then.trailing))
+    }
+
+    fn call(&mut self, node: Node<'_>) -> Result<ComponentCall, FormatError> {
+        let mut name = field_text(node, "name", self.source).to_string();
+        if let Some(qualifier) = node.child_by_field_name("qualifier") {
+            name = format!("{}.{name}", &self.source[qualifier.byte_range()]);
+        }
+        let arguments = node
+            .child_by_field_name("arguments")
+            .expect("checked by first_error");
+        let inner = (arguments.start_byte() + 1)..(arguments.end_byte() - 1);
+        let args = self.source[inner].trim().to_string();
+        let multi_line_args = arguments.start_position().row != arguments.end_position().row;
+
+        let children = match node.child_by_field_name("children") {
+            Some(block) => self.braced_body(block, BodyKind::Discard)?.nodes,
+            None => Vec::new(),
+        };
+        Ok(ComponentCall {
+            name,
+            args,
+            multi_line_args,
+            children,
+        })
+    }
+
+    fn element(&mut self, node: Node<'_>) -> Result<(Element, Option<Comment>), FormatError> {
+        let open_row = node.start_position().row;
+        let tag = field_text(node, "tag", self.source).to_string();
+
+        let mut attrs = Vec::new();
+        let mut close_bracket = None;
+        let mut items = Vec::new();
+        let closing_tag = node.child_by_field_name("closing_tag");
+        let mut in_children = false;
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            if child.is_extra() {
+                continue;
+            }
+            match child.kind() {
+                "attribute" if !in_children => attrs.push(self.attribute(child)),
+                "/" | ">" if close_bracket.is_none() => {
+                    close_bracket = Some(child);
+                    in_children = child.kind() == ">";
+                }
+                "</" => in_children = false,
+                _ if in_children && child.is_named() => items.push(child),
+                _ => {}
+            }
+        }
+        let close_bracket = close_bracket.expect("checked by first_error");
+
+        if let Some(closing) = closing_tag {
+            let found = self.text(closing);
+            if found != tag {
+                let pos = closing.start_position();
+                return Err(FormatError::MismatchedTag {
+                    line: pos.row + 1,
+                    column: pos.column + 1,
+                    expected: tag,
+                    found: found.to_string(),
+                });
+            }
+        }
+
+        let multi_line_attrs = attrs.last().is_some_and(|attr| attr.row != open_row);
+        let ref_expr = extract_expr_attr(&mut attrs, "ref");
+        let key_expr = extract_expr_attr(&mut attrs, "key");
+        let last_row = attrs.last().map_or(open_row, |attr| attr.row);
+        let close_row = close_bracket.start_position().row;
+
+        let mut element = Element {
+            tag,
+            attrs,
+            ref_expr,
+            key_expr,
+            self_close: node.kind() == "self_closing_element",
+            multi_line_attrs,
+            closing_bracket_newline: close_row > last_row,
+            inline_children: true,
+            children: Vec::new(),
+            orphans: Vec::new(),
+        };
+        if !element.self_close {
+            let close_byte = closing_tag.map_or(node.end_byte(), |n| n.start_byte());
+            let (children, orphans) = self.children(&items, close_byte, BodyKind::Element)?;
+            if let Some(first) = items.first() {
+                element.inline_children = first.start_position().row == close_row;
+            }
+            element.children = children;
+            element.orphans = orphans;
+        }
+
+        let trailing = self.take_trailing_comment(node.end_byte(), node.end_position().row);
+        Ok((element, trailing))
+    }
+
+    fn attribute(&self, node: Node<'_>) -> Attribute {
+        let name = field_text(node, "name", self.source).to_string();
+        let value = match node.child_by_field_name("value") {
+            None => AttrValue::Bool(true),
+            Some(value) => match value.kind() {
+                "string" => AttrValue::Str(unquote(self.text(value))),
+                // tuigen only reads interpreted strings, so a raw string is
+                // rewritten as one
+                "raw_string" => {
+                    let text = self.text(value);
+                    AttrValue::Str(text[1..text.len() - 1].to_string())
+                }
+                "number" => match parse_number(self.text(value)) {
+                    Number::Int(n) => AttrValue::Int(n),
+                    Number::Float(f) => AttrValue::Float(f),
+                    Number::Go => AttrValue::Expr(self.text(value).to_string()),
+                },
+                "go_expression" => AttrValue::Expr(go_expression_code(value, self.source)),
+                "true" => AttrValue::Bool(true),
+                "false" => AttrValue::Bool(false),
+                _ => AttrValue::Expr(self.text(value).to_string()),
+            },
+        };
+        Attribute {
+            name,
+            value,
+            row: node.start_position().row,
+        }
+    }
+}
+
+/// Collects the comments tuigen would see as tokens, skipping the ones
+/// inside Go code it captures as raw text.
+fn collect_comment_nodes<'t>(node: Node<'t>, out: &mut Vec<Node<'t>>) {
+    if node.kind() == "comment" {
+        out.push(node);
+        return;
+    }
+    if matches!(
+        node.kind(),
+        "go_expression"
+            | "argument_list"
+            | "switch_statement"
+            | "function_declaration"
+            | "type_struct_declaration"
+            | "go_declaration"
+    ) {
+        return;
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_comment_nodes(child, out);
+    }
+}
+
+pub(crate) fn collect_kind<'t>(node: Node<'t>, kind: &str, out: &mut Vec<Node<'t>>) {
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        if child.kind() == kind {
+            out.push(child);
+        } else {
+            collect_kind(child, kind, out);
+        }
+    }
+}
+
+pub(crate) fn field_text<'s>(node: Node<'_>, field: &str, source: &'s str) -> &'s str {
+    node.child_by_field_name(field)
+        .map_or("", |n| &source[n.byte_range()])
+}
+
+/// Returns the trimmed code between the braces of a `go_expression`.
+pub(crate) fn go_expression_code(node: Node<'_>, source: &str) -> String {
+    source[node.start_byte() + 1..node.end_byte() - 1]
+        .trim()
+        .to_string()
+}
+
+/// Reports whether tuigen skips the newlines after `node` while looking for
+/// an `else` or a children block, which hides any blank line that follows.
+fn skips_newlines_after(node: Node<'_>) -> bool {
+    match node.kind() {
+        "element" => node.named_child(0).is_some_and(skips_newlines_after),
+        "let_binding" => node
+            .child_by_field_name("value")
+            .is_some_and(skips_newlines_after),
+        "self_closing_element" => true,
+        "component_call" => node.child_by_field_name("children").is_none(),
+        "if_statement" => match node.child_by_field_name("alternative") {
+            Some(alt) if alt.kind() == "if_statement" => skips_newlines_after(alt),
+            Some(_) => false,
+            None => true,
+        },
+        _ => false,
+    }
+}
+
+/// Counts the line breaks between two children, ignoring those inside block
+/// comments.
+fn newlines_between(source: &str, start: usize, end: usize) -> usize {
+    let gap = &source[start..end];
+    let mut count = 0;
+    let mut rest = gap;
+    while let Some(ch) = rest.chars().next() {
+        if rest.starts_with("/*") {
+            rest = rest.find("*/").map_or("", |i| &rest[i + 2..]);
+            continue;
+        }
+        if ch == '\n' {
+            count += 1;
+        }
+        rest = &rest[ch.len_utf8()..];
+    }
+    count
+}
+
+/// Moves the first `name={expr}` attribute out of `attrs`.
+fn extract_expr_attr(attrs: &mut Vec<Attribute>, name: &str) -> Option<String> {
+    let index = attrs
+        .iter()
+        .position(|attr| attr.name == name && matches!(attr.value, AttrValue::Expr(_)))?;
+    match attrs.remove(index).value {
+        AttrValue::Expr(code) => Some(code),
+        _ => unreachable!(),
+    }
+}
+
+/// A `number` literal as the tuigen lexer reads it.
+pub(crate) enum Number {
+    Int(i64),
+    Float(f64),
+    /// A literal only Go reads, such as `0x1F`, `1_000` or `2i`.
+    Go,
+}
+
+/// Reads a `number` the way the tuigen lexer does: decimal digits with an
+/// optional fraction and exponent, where a fraction or exponent makes it a
+/// float. Like strconv.ParseInt, an int saturates on overflow.
+pub(crate) fn parse_number(literal: &str) -> Number {
+    let decimal = literal
+        .bytes()
+        .all(|b| b.is_ascii_digit() || matches!(b, b'.' | b'e' | b'E' | b'+' | b'-'));
+    if !decimal {
+        Number::Go
+    } else if literal.contains(['.', 'e', 'E']) {
+        Number::Float(literal.parse().unwrap_or_default())
+    } else {
+        Number::Int(literal.parse().unwrap_or(i64::MAX))
+    }
+}
+
+/// Decodes a string literal the way the tuigen lexer does: known escapes are
+/// replaced and unknown ones are kept with their backslash.
+pub(crate) fn unquote(literal: &str) -> String {
+    let inner = literal
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .unwrap_or(literal);
+    let mut out = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            out.push(ch);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some('r') => out.push('\r'),
+            Some('\\') => out.push('\\'),
+            Some('"') => out.push('"'),
+            Some('0') => out.push('\0'),
+            Some(other) => {
+                out.push('\\');
+                out.push(other);
+            }
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+/// Sorts imports into standard library and third-party groups, drops
+/// duplicates, and appends the go-tui import when components need it.
+fn fix_imports(file: &mut File) {
+    let mut imports = std::mem::take(&mut file.imports);
+    imports.sort_by(|a, b| {
+        (import_group(&a.path), &a.path, &a.alias).cmp(&(import_group(&b.path), &b.path, &b.alias))
+    });
+    imports.dedup();
+
+    let has_components = file
+        .decls
+        .iter()
+        .any(|decl| matches!(decl, Decl::Component(_)));
+    let has_tui = imports.iter().any(|imp| imp.path == TUI_IMPORT_PATH);
+    if has_components && !has_tui && file.package != "tui" {
+        imports.push(Import {
+            alias: "tui".to_string(),
+            path: TUI_IMPORT_PATH.to_string(),
+        });
+    }
+    file.imports = imports;
+}
+
+/// Standard library packages sort before everything else, as in goimports.
+pub(crate) fn import_group(path: &str) -> u8 {
+    let first = path.split('/').next().unwrap_or_default();
+    u8::from(first.contains('.'))
+}
+
+/// Writes the document model back out as source text.
+struct Printer<'a> {
+    indent: &'a str,
+    depth: usize,
+    buf: String,
+}
+
+impl Printer<'_> {
+    fn file(&mut self, file: &File) {
+        self.leading_comments(&file.leading);
+        self.write("package ");
+        self.write(&file.package);
+        self.newline();
+        self.newline();
+
+        if !file.imports.is_empty() {
+            self.imports(&file.imports);
+            self.newline();
+        }
+
+        for (i, decl) in file.decls.iter().enumerate() {
+            if i > 0 {
+                self.newline();
+            }
+            match decl {
+                Decl::Component(component) => self.component(component),
+                Decl::Go { leading, code } => {
+                    self.leading_comments(leading);
+                    self.write(code);
+                    self.newline();
+                }
+            }
+        }
+
+        self.leading_comments(&file.orphans);
+    }
+
+    fn imports(&mut self, imports: &[Import]) {
+        if let [import] = imports {
+            self.write("import ");
+            self.import_spec(import);
+            self.newline();
+            return;
+        }
+
+        self.write("import (");
+        self.newline();
+        self.depth += 1;
+        for import in imports {
+            self.write_indent();
+            self.import_spec(import);
+            self.newline();
+        }
+        self.depth -= 1;
+        self.write(")");
+        self.newline();
+    }
+
+    fn import_spec(&mut self, import: &Import) {
+        if !import.alias.is_empty() {
+            self.write(&import.alias);
+            self.write(" ");
+        }
+        self.write("\"");
+        self.write(&import.path);
+        self.write("\"");
+    }
+
+    fn component(&mut self, component: &Component) {
+        self.leading_comments(&component.leading);
+        self.write("templ ");
+        if let Some(receiver) = &component.receiver {
+            self.write("(");
+            self.write(receiver);
+            self.write(") ");
+            self.write(&component.name);
+            self.write("()");
+        } else {
+            self.write(&component.name);
+            self.write(&component.type_params);
+            self.write("(");
+            for (i, (name, ty)) in component.params.iter().enumerate() {
+                if i > 0 {
+                    self.write(", ");
+                }
+                if !name.is_empty() {
+                    self.write(name);
+                    self.write(" ");
+                }
+                self.write(ty);
+            }
+            self.write(")");
+        }
+        self.write(" {");
+        self.trailing_comment(component.trailing.as_ref());
+        self.newline();
+
+        self.depth += 1;
+        self.leading_comments(&component.orphans);
+        self.body(&component.body);
+        self.depth -= 1;
+
+        self.write("}");
+        self.newline();
+    }
+
+    fn body(&mut self, nodes: &[BodyNode]) {
+        for node in nodes {
+            if node.blank_line_before {
+                self.newline();
+            }
+            self.node(node);
+        }
+    }
+
+    fn node(&mut self, node: &BodyNode) {
+        self.leading_comments(&node.leading);
+        match &node.item {
+            Item::Element(element) => self.element(element, node.trailing.as_ref()),
+            Item::For(for_loop) => self.for_loop(for_loop, node.trailing.as_ref()),
+            Item::If(stmt) => self.if_stmt(stmt, node.trailing.as_ref()),
+            Item::Let(binding) => self.let_binding(binding),
+            Item::Call(call) => self.component_call(call),
+            Item::Expr(code) => {
+                self.write_indent();
+                self.write("{");
+                self.write(&format_inline_block_comments(code));
+                self.write("}");
+                self.newline();
+            }
+            Item::Code(code) => {
+                self.write_indent();
+                self.write(&format_inline_block_comments(code));
+                self.newline();
+            }
+            Item::Text(text) => {
+                self.write_indent();
+                self.write(text);
+                self.newline();
+            }
+            Item::ChildrenSlot => {
+                self.write_indent();
+                self.write("{children...}");
+                self.newline();
+            }
+        }
+    }
+
+    fn for_loop(&mut self, for_loop: &ForLoop, trailing: Option<&Comment>) {
+        self.write_indent();
+        self.write("for ");
+        if !for_loop.index.is_empty() {
+            self.write(&for_loop.index);
+            self.write(", ");
+        }
+        self.write(&for_loop.value);
+        self.write(" := range ");
+        self.write(&for_loop.iterable);
+        self.write(" {");
+        self.trailing_comment(trailing);
+        self.newline();
+
+        self.depth += 1;
+        self.leading_comments(&for_loop.orphans);
+        self.body(&for_loop.body);
+        self.depth -= 1;
+
+        self.write_indent();
+        self.write("}");
+        self.newline();
+    }
+
+    fn if_stmt(&mut self, stmt: &IfStmt, trailing: Option<&Comment>) {
+        self.write_indent();
+        self.write("if ");
+        self.if_branch(stmt, trailing);
+    }
+
+    /// Writes `cond { ... }` and any else chain after an `if ` keyword.
+    fn if_branch(&mut self, stmt: &IfStmt, trailing: Option<&Comment>) {
+        self.write(&stmt.condition);
+        self.write(" {");
+        self.trailing_comment(trailing);
+        self.newline();
+
+        self.depth += 1;
+        self.leading_comments(&stmt.orphans);
+        self.body(&stmt.then);
+        self.depth -= 1;
+
+        self.write_indent();
+        self.write("}");
+        if stmt.r#else.is_empty() {
+            self.newline();
+            return;
+        }
+
+        self.write(" else ");
+        if let [BodyNode {
+            item: Item::If(else_if),
+            trailing,
+            ..
+        }] = stmt.r#else.as_slice()
+        {
+            self.write("if ");
+            self.if_branch(else_if, trailing.as_ref());
+            return;
+        }
+
+        self.write("{");
+        self.newline();
+        self.depth += 1;
+        self.body(&stmt.r#else);
+        self.depth -= 1;
+        self.write_indent();
+        self.write("}");
+        self.newline();
+    }
+
+    fn let_binding(&mut self, binding: &LetBinding) {
+        self.write_indent();
+        self.write(&binding.name);
+        self.write(" := ");
+
+        let element = match &binding.value {
+            LetValue::Call { name, args } => {
+                self.write("@");
+                self.write(name);
+                self.write("(");
+                self.write(&format_inline_block_comments(args));
+                self.write(")");
+                self.newline();
+                return;
+            }
+            LetValue::Element(element) => element,
+        };
+
+        // Bindings keep only `ref`; the rest of the element prints on one line.
+        self.write("<");
+        self.write(&element.tag);
+        if let Some(code) = &element.ref_expr {
+            self.write(" ref={");
+            self.write(code);
+            self.write("}");
+        }
+        for attr in &element.attrs {
+            self.write(" ");
+            self.attribute(attr);
+        }
+
+        if element.self_close {
+            self.write(" />");
+            self.newline();
+            return;
+        }
+        self.write(">");
+
+        if element.inline_children && can_inline(&element.children) {
+            self.children_inline(&element.children);
+            self.closing_tag(&element.tag);
+            self.newline();
+            return;
+        }
+
+        self.newline();
+        self.depth += 1;
+        self.body(&element.children);
+        self.depth -= 1;
+        self.write_indent();
+        self.closing_tag(&element.tag);
+        self.newline();
+    }
+
+    fn component_call(&mut self, call: &ComponentCall) {
+        self.write_indent();
+        self.write("@");
+        self.write(&call.name);
+        self.write("(");
+
+        if call.multi_line_args {
+            let args = split_top_level_args(&call.args);
+            self.newline();
+            self.depth += 1;
+            for (i, arg) in args.iter().enumerate() {
+                self.write_indent();
+                self.write(arg);
+                self.write(",");
+                if i + 1 < args.len() {
+                    self.newline();
+                }
+            }
+            self.depth -= 1;
+            self.newline();
+            self.write_indent();
+        } else {
+            self.write(&format_inline_block_comments(&call.args));
+        }
+        self.write(")");
+
+        if !call.children.is_empty() {
+            self.write(" {");
+            self.newline();
+            self.depth += 1;
+            self.body(&call.children);
+            self.depth -= 1;
+            self.write_indent();
+            self.write("}");
+        }
+        self.newline();
+    }
+
+    fn element(&mut self, element: &Element, trailing: Option<&Comment>) {
+        self.write_indent();
+        self.write("<");
+        self.write(&element.tag);
+
+        let special = [("ref", &element.ref_expr), ("key", &element.key_expr)];
+        if element.multi_line_attrs || element.closing_bracket_newline {
+            self.depth += 1;
+            for (name, code) in special {
+                if let Some(code) = code {
+                    self.newline();
+                    self.write_indent();
+                    self.write(name);
+                    self.write("={");
+                    self.write(code);
+                    self.write("}");
+                }
+            }
+            for attr in &element.attrs {
+                self.newline();
+                self.write_indent();
+                self.attribute(attr);
+            }
+            self.depth -= 1;
+        } else {
+            for (name, code) in special {
+                if let Some(code) = code {
+                    self.write(" ");
+                    self.write(name);
+                    self.write("={");
+                    self.write(code);
+                    self.write("}");
+                }
+            }
+            for attr in &element.attrs {
+                self.write(" ");
+                self.attribute(attr);
+            }
+        }
+
+        if element.self_close {
+            if element.closing_bracket_newline {
+                self.newline();
+                self.write_indent();
+                self.write("/>");
+            } else {
+                self.write(" />");
+            }
+            self.trailing_comment(trailing);
+            self.newline();
+            return;
+        }
+
+        if element.closing_bracket_newline {
+            self.newline();
+            self.write_indent();
+        }
+        self.write(">");
+
+        if element.inline_children && element.orphans.is_empty() && can_inline(&element.children) {
+            self.children_inline(&element.children);
+            self.closing_tag(&element.tag);
+            self.trailing_comment(trailing);
+            self.newline();
+            return;
+        }
+
+        // tuigen records the comment after the closing tag, but prints it
+        // after the opening tag when children go on their own lines.
+        self.trailing_comment(trailing);
+        self.newline();
+        self.depth += 1;
+        self.body(&element.children);
+        self.leading_comments(&element.orphans);
+        self.depth -= 1;
+        self.write_indent();
+        self.closing_tag(&element.tag);
+        self.newline();
+    }
+
+    fn closing_tag(&mut self, tag: &str) {
+        self.write("</");
+        self.write(tag);
+        self.write(">");
+    }
+
+    fn attribute(&mut self, attr: &Attribute) {
+        self.write(&attr.name);
+        self.write("=");
+        match &attr.value {
+            AttrValue::Bool(value) => self.write(&format!("{{{value}}}")),
+            AttrValue::Str(value) => {
+                self.write("\"");
+                self.write(&escape_string(value));
+                self.write("\"");
+            }
+            AttrValue::Int(value) => self.write(&format!("{{{value}}}")),
+            AttrValue::Float(value) => self.write(&format!("{{{}}}", format_float(*value))),
+            AttrValue::Expr(code) => {
+                self.write("{");
+                self.write(&format_inline_block_comments(code));
+                self.write("}");
+            }
+        }
+    }
+
+    fn children_inline(&mut self, children: &[BodyNode]) {
+        for child in children {
+            match &child.item {
+                Item::Expr(code) => {
+                    self.write("{");
+                    self.write(&format_inline_block_comments(code));
+                    self.write("}");
+                }
+                Item::Text(text) => self.write(text),
+                _ => {}
+            }
+        }
+    }
+
+    fn leading_comments(&mut self, comments: &[Comment]) {
+        for comment in comments {
+            if comment.blank_line_before {
+                self.newline();
+            }
+            self.write_indent();
+            self.write(&format_comment(comment));
+            self.newline();
+        }
+    }
+
+    fn trailing_comment(&mut self, comment: Option<&Comment>) {
+        if let Some(comment) = comment {
+            self.write("  ");
+            self.write(&format_comment(comment));
+        }
+    }
+
+    fn write(&mut self, s: &str) {
+        self.buf.push_str(s);
+    }
+
+    fn newline(&mut self) {
+        self.buf.push('\n');
+    }
+
+    fn write_indent(&mut self) {
+        for _ in 0..self.depth {
+            self.buf.push_str(self.indent);
+        }
+    }
+}
+
+/// Reports whether children can share a line with their element's tags.
+fn can_inline(children: &[BodyNode]) -> bool {
+    children.iter().all(|child| match &child.item {
+        Item::Expr(code) | Item::Text(code) => !code.contains('\n'),
+        _ => false,
+    })
+}
+
+/// Escapes a string attribute value for output.
+fn escape_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for ch in s.chars() {
+        match ch {
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+fn format_comment(comment: &Comment) -> String {
+    if comment.is_block {
+        format_block_comment(&comment.text)
+    } else {
+        format_line_comment(&comment.text)
+    }
+}
+
+/// Puts single-line block comment text between `/* ` and ` */`, and
+/// multi-line text on its own lines between `/*` and `*/`.
+fn format_block_comment(text: &str) -> String {
+    let Some(content) = text
+        .strip_prefix("/*")
+        .and_then(|rest| rest.strip_suffix("*/"))
+    else {
+        return text.to_string();
+    };
+    let lines: Vec<&str> = content
+        .split('\n')
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect();
+    match lines.as_slice() {
+        [] => "/* */".to_string(),
+        [line] => format!("/* {line} */"),
+        _ => {
+            let mut out = String::from("/*\n");
+            for line in lines {
+                out.push_str(line);
+                out.push('\n');
+            }
+            out.push_str("*/");
+            out
+        }
+    }
+}
+
+/// Ensures a space follows `//`.
+fn format_line_comment(text: &str) -> String {
+    match text.strip_prefix("//") {
+        Some(content) if !content.is_empty() && !content.starts_with([' ', '\t']) => {
+            format!("// {content}")
+        }
+        _ => text.to_string(),
+    }
+}
+
+/// Formats the block comments inside Go code, leaving string literals alone.
+fn format_inline_block_comments(code: &str) -> String {
+    let bytes = code.as_bytes();
+    let mut out = String::with_capacity(code.len());
+    let mut copied = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                let start = i;
+                i = code[i + 2..]
+                    .find("*/")
+                    .map_or(bytes.len(), |end| i + 2 + end + 2);
+                out.push_str(&code[copied..start]);
+                out.push_str(&format_block_comment(&code[start..i]));
+                copied = i;
+            }
+            b'"' => {
+                i += 1;
+                while i < bytes.len() && bytes[i] != b'"' {
+                    i += if bytes[i] == b'\\' { 2 } else { 1 };
+                }
+                i += 1;
+            }
+            b'`' => {
+                i += 1;
+                while i < bytes.len() && bytes[i] != b'`' {
+                    i += 1;
+                }
+                i += 1;
+            }
+            _ => i += 1,
+        }
+    }
+    out.push_str(&code[copied..]);
+    out
+}
+
+/// Splits Go call arguments on top-level commas.
+fn split_top_level_args(args: &str) -> Vec<&str> {
+    let bytes = args.as_bytes();
+    let mut result = Vec::new();
+    let mut depth = 0i32;
+    let mut quote = None;
+    let mut start = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let ch = bytes[i];
+        match quote {
+            Some(b'`') if ch == b'`' => quote = None,
+            Some(b'`') => {}
+            Some(_) if ch == b'\\' => i += 1,
+            Some(q) if ch == q => quote = None,
+            Some(_) => {}
+            None => match ch {
+                b'"' | b'\'' | b'`' => quote = Some(ch),
+                b'(' | b'[' | b'{' => depth += 1,
+                b')' | b']' | b'}' => depth -= 1,
+                b',' if depth == 0 => {
+                    let arg = args[start..i].trim();
+                    if !arg.is_empty() {
+                        result.push(arg);
+                    }
+                    start = i + 1;
+                }
+                _ => {}
+            },
+        }
+        i += 1;
+    }
+    let last = args[start.min(args.len())..].trim();
+    if !last.is_empty() {
+        result.push(last);
+    }
+    result
+}
+
+/// Formats a float like Go's `%g` verb: the shortest representation, in
+/// exponent form when the exponent is below -4 or at least 6.
+fn format_float(value: f64) -> String {
+    let sci = format!("{value:e}");
+    let (mantissa, exp) = sci.split_once('e').expect("{:e} always has an exponent");
+    let exp: i32 = exp.parse().expect("{:e} exponent is an integer");
+    let (sign, mantissa) = match mantissa.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", mantissa),
+    };
+    let digits: String = mantissa.chars().filter(|c| *c != '.').collect();
+
+    if !(-4..6).contains(&exp) {
+        let mut out = format!("{sign}{}", &digits[..1]);
+        if digits.len() > 1 {
+            out.push('.');
+            out.push_str(&digits[1..]);
+        }
+        let exp_sign = if exp < 0 { '-' } else { '+' };
+        out.push_str(&format!("e{exp_sign}{:02}", exp.abs()));
+        return out;
+    }
+
+    if exp < 0 {
+        let zeros = "0".repeat((-exp - 1) as usize);
+        return format!("{sign}0.{zeros}{digits}");
+    }
+    let int_len = exp as usize + 1;
+    if digits.len() <= int_len {
+        format!("{sign}{digits}{}", "0".repeat(int_len - digits.len()))
+    } else {
+        format!("{sign}{}.{}", &digits[..int_len], &digits[int_len..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A formatter that leaves imports alone, like the Go tests'
+    /// `newTestFormatter`.
+    fn formatter() -> Formatter {
+        Formatter {
+            fix_imports: false,
+            ..Formatter::new()
+        }
+    }
+
+    /// Checks each case formats to `want` and that `want` is a fixed point.
+    fn check_golden(cases: &[(&str, &str, &str)]) {
+        let fmtr = formatter();
+        for (name, input, want) in cases {
+            let got = fmtr
+                .format(input)
+                .unwrap_or_else(|err| panic!("{name}: format failed: {err}"));
+            assert_eq!(got, *want, "{name}: format mismatch");
+            let again = fmtr
+                .format(&got)
+                .unwrap_or_else(|err| panic!("{name}: second format failed: {err}\n{got}"));
+            assert_eq!(again, got, "{name}: format is not idempotent");
+        }
+    }
+
+    /// Checks that formatting each case twice gives the same output.
+    fn check_idempotent(fmtr: &Formatter, cases: &[(&str, &str)]) {
+        for (name, input) in cases {
+            let first = fmtr
+                .format(input)
+                .unwrap_or_else(|err| panic!("{name}: first format failed: {err}"));
+            let second = fmtr
+                .format(&first)
+                .unwrap_or_else(|err| panic!("{name}: second format failed: {err}"));
+            assert_eq!(first, second, "{name}: format is not idempotent");
+        }
+    }
+
+    #[test]
+    fn test_format() {
+        check_golden(&[
+            (
+                "simple package and component",
+                "package main\n\ntempl Hello() {\n<span>Hello</span>\n}\n",
+                "package main\n\ntempl Hello() {\n\t<span>Hello</span>\n}\n",
+            ),
+            (
+                "single import",
+                "package main\n\nimport \"fmt\"\n\ntempl Hello() {\n<span>{fmt.Sprintf(\"hi\")}</span>\n}\n",
+                "package main\n\nimport \"fmt\"\n\ntempl Hello() {\n\t<span>{fmt.Sprintf(\"hi\")}</span>\n}\n",
+            ),
+            (
+                "multiple imports",
+                "package main\n\nimport (\n\"fmt\"\n\"strings\"\n)\n\ntempl Hello() {\n<span>{strings.ToUpper(fmt.Sprintf(\"hi\"))}</span>\n}\n",
+                "package main\n\nimport (\n\t\"fmt\"\n\t\"strings\"\n)\n\ntempl Hello() {\n\t<span>{strings.ToUpper(fmt.Sprintf(\"hi\"))}</span>\n}\n",
+            ),
+            (
+                "import with alias",
+                "package main\n\nimport (\ntui \"github.com/grindlemire/go-tui\"\n)\n\ntempl Hello() {\n<div border={tui.BorderSingle}></div>\n}\n",
+                "package main\n\nimport tui \"github.com/grindlemire/go-tui\"\n\ntempl Hello() {\n\t<div border={tui.BorderSingle}></div>\n}\n",
+            ),
+            (
+                "component with parameters",
+                "package main\n\ntempl Card(title string, count int) {\n<span>{title}</span>\n}\n",
+                "package main\n\ntempl Card(title string, count int) {\n\t<span>{title}</span>\n}\n",
+            ),
+            (
+                "nested elements",
+                "package main\n\ntempl Layout() {\n<div>\n<div>\n<span>Hello</span>\n</div>\n</div>\n}\n",
+                "package main\n\ntempl Layout() {\n\t<div>\n\t\t<div>\n\t\t\t<span>Hello</span>\n\t\t</div>\n\t</div>\n}\n",
+            ),
+            (
+                "self-closing element",
+                "package main\n\ntempl Divider() {\n<hr />\n}\n",
+                "package main\n\ntempl Divider() {\n\t<hr />\n}\n",
+            ),
+            (
+                "for loop",
+                "package main\n\ntempl List(items []string) {\nfor i, item := range items {\n<span>{item}</span>\n}\n}\n",
+                "package main\n\ntempl List(items []string) {\n\tfor i, item := range items {\n\t\t<span>{item}</span>\n\t}\n}\n",
+            ),
+            (
+                "if statement",
+                "package main\n\ntempl Cond(show bool) {\nif show {\n<span>Visible</span>\n}\n}\n",
+                "package main\n\ntempl Cond(show bool) {\n\tif show {\n\t\t<span>Visible</span>\n\t}\n}\n",
+            ),
+            (
+                "if-else statement",
+                "package main\n\ntempl Cond(show bool) {\nif show {\n<span>Yes</span>\n} else {\n<span>No</span>\n}\n}\n",
+                "package main\n\ntempl Cond(show bool) {\n\tif show {\n\t\t<span>Yes</span>\n\t} else {\n\t\t<span>No</span>\n\t}\n}\n",
+            ),
+            (
+                "switch kept verbatim",
+                "package main\n\ntempl Status(n int) {\nswitch n {\n\tcase 0:\n\t\t<span>None</span>\n\tdefault:\n\t\t<span>Some</span>\n\t}\n}\n",
+                "package main\n\ntempl Status(n int) {\n\tswitch n {\n\tcase 0:\n\t\t<span>None</span>\n\tdefault:\n\t\t<span>Some</span>\n\t}\n}\n",
+            ),
+            (
+                "let binding",
+                "package main\n\ntempl WithLet() {\nx := <span>Hello</span>\n{x}\n}\n",
+                "package main\n\ntempl WithLet() {\n\tx := <span>Hello</span>\n\t{x}\n}\n",
+            ),
+            (
+                "component call",
+                "package main\n\ntempl Parent() {\n@Child(\"arg1\", \"arg2\")\n}\n\ntempl Child(a string, b string) {\n<span>{a}</span>\n}\n",
+                "package main\n\ntempl Parent() {\n\t@Child(\"arg1\", \"arg2\")\n}\n\ntempl Child(a string, b string) {\n\t<span>{a}</span>\n}\n",
+            ),
+            (
+                "component call with children",
+                "package main\n\ntempl Parent() {\n@Card(\"Title\") {\n<span>Content</span>\n}\n}\n\ntempl Card(title string) {\n<div>\n<span>{title}</span>\n{children...}\n</div>\n}\n",
+                "package main\n\ntempl Parent() {\n\t@Card(\"Title\") {\n\t\t<span>Content</span>\n\t}\n}\n\ntempl Card(title string) {\n\t<div>\n\t\t<span>{title}</span>\n\t\t{children...}\n\t</div>\n}\n",
+            ),
+            (
+                "qualified component calls",
+                "package main\n\ntempl (p *page) Render() {\n@layout.Card(\"Title\") {\n@p.Body()\n}\n}\n",
+                "package main\n\ntempl (p *page) Render() {\n\t@layout.Card(\"Title\") {\n\t\t@p.Body()\n\t}\n}\n",
+            ),
+            (
+                "multiple attributes",
+                "package main\n\ntempl Box() {\n<div border={1} padding={2} margin={1}>\n<span>Content</span>\n</div>\n}\n",
+                "package main\n\ntempl Box() {\n\t<div border={1} padding={2} margin={1}>\n\t\t<span>Content</span>\n\t</div>\n}\n",
+            ),
+            (
+                "string attribute",
+                "package main\n\ntempl Styled() {\n<div class=\"flex-col gap-1\">\n<span>Content</span>\n</div>\n}\n",
+                "package main\n\ntempl Styled() {\n\t<div class=\"flex-col gap-1\">\n\t\t<span>Content</span>\n\t</div>\n}\n",
+            ),
+            (
+                "generic component keeps its type parameters",
+                "package main\n\ntempl List[T any](items []T) {\nfor _, item := range items {\n<span>{item}</span>\n}\n}\n",
+                "package main\n\ntempl List[T any](items []T) {\n\tfor _, item := range items {\n\t\t<span>{item}</span>\n\t}\n}\n",
+            ),
+            (
+                "grouped and variadic parameters",
+                "package main\n\ntempl Row(label,value string,opts ...Option) {\n<span>{label}</span>\n}\n",
+                "package main\n\ntempl Row(label, value string, opts ...Option) {\n\t<span>{label}</span>\n}\n",
+            ),
+            (
+                "raw string attribute rewritten as a string",
+                "package main\n\ntempl Path() {\n<span text=`C:\\dir \"x\"` />\n}\n",
+                "package main\n\ntempl Path() {\n\t<span text=\"C:\\\\dir \\\"x\\\"\" />\n}\n",
+            ),
+            (
+                "ref attribute",
+                "package main\n\ntempl App() {\n<div ref={content} class=\"flex-col\"></div>\n}\n",
+                "package main\n\ntempl App() {\n\t<div ref={content} class=\"flex-col\"></div>\n}\n",
+            ),
+            (
+                "ref attribute with children",
+                "package main\n\ntempl App() {\n<div ref={wrapper}>\n<span ref={title}>Hello</span>\n</div>\n}\n",
+                "package main\n\ntempl App() {\n\t<div ref={wrapper}>\n\t\t<span ref={title}>Hello</span>\n\t</div>\n}\n",
+            ),
+            (
+                "method component receiver syntax",
+                "package main\n\ntempl (s *sidebar) Render() {\n<div>\n<span>Hello</span>\n</div>\n}\n",
+                "package main\n\ntempl (s *sidebar) Render() {\n\t<div>\n\t\t<span>Hello</span>\n\t</div>\n}\n",
+            ),
+            (
+                "go type declaration preserved",
+                "package main\n\ntype myApp struct {\n\tquery string\n}\n\ntempl Hello() {\n\t<span>Hello</span>\n}\n",
+                "package main\n\ntype myApp struct {\n\tquery string\n}\n\ntempl Hello() {\n\t<span>Hello</span>\n}\n",
+            ),
+            (
+                "interleaved type func and method templ",
+                "package main\n\nimport tui \"github.com/grindlemire/go-tui\"\n\ntype myApp struct {\n\tquery *tui.State[string]\n}\n\nfunc MyApp() *myApp {\n\treturn &myApp{\n\t\tquery: tui.NewState(\"\"),\n\t}\n}\n\nfunc (a *myApp) KeyMap() tui.KeyMap {\n\treturn nil\n}\n\ntempl (a *myApp) Render() {\n\t<div>\n\t\t<span>Hello</span>\n\t</div>\n}\n",
+                "package main\n\nimport tui \"github.com/grindlemire/go-tui\"\n\ntype myApp struct {\n\tquery *tui.State[string]\n}\n\nfunc MyApp() *myApp {\n\treturn &myApp{\n\t\tquery: tui.NewState(\"\"),\n\t}\n}\n\nfunc (a *myApp) KeyMap() tui.KeyMap {\n\treturn nil\n}\n\ntempl (a *myApp) Render() {\n\t<div>\n\t\t<span>Hello</span>\n\t</div>\n}\n",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_format_go_statements() {
+        check_golden(&[
+            (
+                "go statements kept verbatim",
+                "package main\n\ntempl A() {\ncount := 1\nfmt.Println(\"hi\")\n<span>{count}</span>\n}\n",
+                "package main\n\ntempl A() {\n\tcount := 1\n\tfmt.Println(\"hi\")\n\t<span>{count}</span>\n}\n",
+            ),
+            (
+                "go statement with leading comment",
+                "package main\n\ntempl A() {\n// compute a label\nlabel := \"hi\"\n<span>{label}</span>\n}\n",
+                "package main\n\ntempl A() {\n\t// compute a label\n\tlabel := \"hi\"\n\t<span>{label}</span>\n}\n",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_format_multi_line_args() {
+        check_golden(&[(
+            "multi-line args end with a trailing comma",
+            "package main\n\ntempl Parent() {\n@Child(\n\"arg1\",\n      \"arg2\"\n)\n}\n",
+            "package main\n\ntempl Parent() {\n\t@Child(\n\t\t\"arg1\",\n\t\t\"arg2\",\n\t)\n}\n",
+        )]);
+        assert_eq!(
+            split_top_level_args("a, f(b, c), \"d,e\", `f,g`, 'h', []int{1, 2}"),
+            ["a", "f(b, c)", "\"d,e\"", "`f,g`", "'h'", "[]int{1, 2}"]
+        );
+    }
+
+    #[test]
+    fn test_format_layout_preservation() {
+        check_golden(&[
+            (
+                "single-line attrs stay single-line even if long",
+                "package main\n\ntempl Test() {\n<div class=\"very-long-class-name-that-exceeds-100-chars\" id=\"also-very-long-identifier\">\n<span>Content</span>\n</div>\n}\n",
+                "package main\n\ntempl Test() {\n\t<div class=\"very-long-class-name-that-exceeds-100-chars\" id=\"also-very-long-identifier\">\n\t\t<span>Content</span>\n\t</div>\n}\n",
+            ),
+            (
+                "multi-line attrs indented one tab deeper",
+                "package main\n\ntempl Test() {\n<div class=\"a\"\nid=\"b\">\n<span>Content</span>\n</div>\n}\n",
+                "package main\n\ntempl Test() {\n\t<div\n\t\tclass=\"a\"\n\t\tid=\"b\">\n\t\t<span>Content</span>\n\t</div>\n}\n",
+            ),
+            (
+                "closing bracket on own line preserved",
+                "package main\n\ntempl Test() {\n<div class=\"a\"\nid=\"b\"\n>\n<span>Content</span>\n</div>\n}\n",
+                "package main\n\ntempl Test() {\n\t<div\n\t\tclass=\"a\"\n\t\tid=\"b\"\n\t>\n\t\t<span>Content</span>\n\t</div>\n}\n",
+            ),
+            (
+                "inline children preserved",
+                "package main\n\ntempl Test() {\n<span>hello world</span>\n}\n",
+                "package main\n\ntempl Test() {\n\t<span>hello world</span>\n}\n",
+            ),
+            (
+                "multi-line children preserved even if short",
+                "package main\n\ntempl Test() {\n<span>\nhello\n</span>\n}\n",
+                "package main\n\ntempl Test() {\n\t<span>\n\t\thello\n\t</span>\n}\n",
+            ),
+            (
+                "self-closing with closing bracket on own line",
+                "package main\n\ntempl Test() {\n<input class=\"text\"\nvalue=\"hello\"\n/>\n}\n",
+                "package main\n\ntempl Test() {\n\t<input\n\t\tclass=\"text\"\n\t\tvalue=\"hello\"\n\t/>\n}\n",
+            ),
+            (
+                "self-closing with closing bracket on same line",
+                "package main\n\ntempl Test() {\n<input class=\"text\"\nvalue=\"hello\" />\n}\n",
+                "package main\n\ntempl Test() {\n\t<input\n\t\tclass=\"text\"\n\t\tvalue=\"hello\" />\n}\n",
+            ),
+            (
+                "empty element stays inline",
+                "package main\n\ntempl Test() {\n<div></div>\n}\n",
+                "package main\n\ntempl Test() {\n\t<div></div>\n}\n",
+            ),
+            (
+                "blank lines between siblings preserved",
+                "package main\n\ntempl Test() {\n<div>\n<span>First</span>\n\n<span>Second</span>\n</div>\n}\n",
+                "package main\n\ntempl Test() {\n\t<div>\n\t\t<span>First</span>\n\n\t\t<span>Second</span>\n\t</div>\n}\n",
+            ),
+            (
+                "ref attribute in multi-line mode",
+                "package main\n\ntempl Test() {\n<div\nref={content}\nclass=\"flex-col\">\n<span>Hello</span>\n</div>\n}\n",
+                "package main\n\ntempl Test() {\n\t<div\n\t\tref={content}\n\t\tclass=\"flex-col\">\n\t\t<span>Hello</span>\n\t</div>\n}\n",
+            ),
+            (
+                "closing bracket on own line forces multi-line",
+                "package main\n\ntempl Test() {\n<div class=\"a\"\n>\n<span>Content</span>\n</div>\n}\n",
+                "package main\n\ntempl Test() {\n\t<div\n\t\tclass=\"a\"\n\t>\n\t\t<span>Content</span>\n\t</div>\n}\n",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_format_attribute_normalisation() {
+        check_golden(&[
+            (
+                "bare boolean and numbers",
+                "package main\n\ntempl Test() {\n<input disabled width=10 grow=1.5 scale=2.0 />\n}\n",
+                "package main\n\ntempl Test() {\n\t<input disabled={true} width={10} grow={1.5} scale={2} />\n}\n",
+            ),
+            (
+                "exponents are floats and Go-only literals are expressions",
+                "package main\n\ntempl Test() {\n<input width=1e2 height=0x10 />\n}\n",
+                "package main\n\ntempl Test() {\n\t<input width={100} height={0x10} />\n}\n",
+            ),
+            (
+                "key moves after ref",
+                "package main\n\ntempl Test(id string) {\n<div class=\"row\" key={id} ref={row}></div>\n}\n",
+                "package main\n\ntempl Test(id string) {\n\t<div ref={row} key={id} class=\"row\"></div>\n}\n",
+            ),
+            (
+                "expression whitespace trimmed",
+                "package main\n\ntempl Test(x int) {\n<span width={  x  }>{  x  }</span>\n}\n",
+                "package main\n\ntempl Test(x int) {\n\t<span width={x}>{x}</span>\n}\n",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_format_comments() {
+        check_golden(&[
+            (
+                "leading comment before element",
+                "package main\n\ntempl Hello() {\n\t// Comment before element\n\t<span>Hello</span>\n}\n",
+                "package main\n\ntempl Hello() {\n\t// Comment before element\n\t<span>Hello</span>\n}\n",
+            ),
+            (
+                "leading comment before if",
+                "package main\n\ntempl Hello(show bool) {\n\t// Comment before if\n\tif show {\n\t\t<span>Hello</span>\n\t}\n}\n",
+                "package main\n\ntempl Hello(show bool) {\n\t// Comment before if\n\tif show {\n\t\t<span>Hello</span>\n\t}\n}\n",
+            ),
+            (
+                "leading block comment",
+                "package main\n\n/* Block comment\n   spanning multiple lines */\ntempl Hello() {\n\t<span>Hello</span>\n}\n",
+                "package main\n\n/*\nBlock comment\nspanning multiple lines\n*/\ntempl Hello() {\n\t<span>Hello</span>\n}\n",
+            ),
+            (
+                "leading comment before package",
+                "// File-level comment\npackage main\n\ntempl Hello() {\n\t<span>Hello</span>\n}\n",
+                "// File-level comment\npackage main\n\ntempl Hello() {\n\t<span>Hello</span>\n}\n",
+            ),
+            (
+                "trailing comment on element",
+                "package main\n\ntempl Hello() {\n\t<span>Hello</span>  // trailing\n}\n",
+                "package main\n\ntempl Hello() {\n\t<span>Hello</span>  // trailing\n}\n",
+            ),
+            (
+                "trailing comment on self-closing element",
+                "package main\n\ntempl Hello() {\n\t<hr />  // divider\n}\n",
+                "package main\n\ntempl Hello() {\n\t<hr />  // divider\n}\n",
+            ),
+            (
+                "preserve blank line between comment groups",
+                "package main\n\n// Unassigned block comment\n// For package comment\n\n// ItemList test\ntempl Hello() {\n\t<span>Hello</span>\n}\n",
+                "package main\n\n// Unassigned block comment\n// For package comment\n\n// ItemList test\ntempl Hello() {\n\t<span>Hello</span>\n}\n",
+            ),
+            (
+                "preserve blank line inside component",
+                "package main\n\ntempl Hello() {\n\t// First comment\n\n\t// Second comment\n\t<span>Hello</span>\n}\n",
+                "package main\n\ntempl Hello() {\n\t// First comment\n\n\t// Second comment\n\t<span>Hello</span>\n}\n",
+            ),
+            (
+                "missing space after //",
+                "package main\n\n//comment without space\ntempl Hello() {\n\t<span>Hello</span>\n}\n",
+                "package main\n\n// comment without space\ntempl Hello() {\n\t<span>Hello</span>\n}\n",
+            ),
+            (
+                "trailing comment missing space",
+                "package main\n\ntempl Hello() {\n\t<span>Hello</span>  //trailing\n}\n",
+                "package main\n\ntempl Hello() {\n\t<span>Hello</span>  // trailing\n}\n",
+            ),
+            (
+                "inline block comment missing spaces",
+                "package main\n\ntempl Hello(x int) {\n\t<span>{/*test comment*/ x}</span>\n}\n",
+                "package main\n\ntempl Hello(x int) {\n\t<span>{/* test comment */ x}</span>\n}\n",
+            ),
+            (
+                "inline block comment inside call",
+                "package main\n\nimport \"fmt\"\n\ntempl Hello(item string) {\n\t<span>{fmt.Sprintf(\"> %s\", /* ItemList item*/ item)}</span>\n}\n",
+                "package main\n\nimport \"fmt\"\n\ntempl Hello(item string) {\n\t<span>{fmt.Sprintf(\"> %s\", /* ItemList item */ item)}</span>\n}\n",
+            ),
+            (
+                "empty block comment",
+                "package main\n\ntempl Hello(x int) {\n\t<span>{/**/ x}</span>\n}\n",
+                "package main\n\ntempl Hello(x int) {\n\t<span>{/* */ x}</span>\n}\n",
+            ),
+            (
+                "comments at various positions",
+                "// File-level comment\npackage main\n\nimport \"fmt\"\n\n// Main component documentation\n// Multiple lines\ntempl Main(items []string, selected int) {\n\t// Loop through items\n\tfor i, item := range items {\n\t\t// Conditional rendering\n\t\tif i == selected {\n\t\t\t// Container span\n\t\t\t<span class=\"selected\">{item}</span>\n\t\t} else {\n\t\t\t<span>{item}</span>\n\t\t}\n\t}\n}\n\n// Helper function\nfunc helper(s string) string {\n\treturn fmt.Sprintf(\"[%s]\", s)\n}\n",
+                "// File-level comment\npackage main\n\nimport \"fmt\"\n\n// Main component documentation\n// Multiple lines\ntempl Main(items []string, selected int) {\n\t// Loop through items\n\tfor i, item := range items {\n\t\t// Conditional rendering\n\t\tif i == selected {\n\t\t\t// Container span\n\t\t\t<span class=\"selected\">{item}</span>\n\t\t} else {\n\t\t\t<span>{item}</span>\n\t\t}\n\t}\n}\n\n// Helper function\nfunc helper(s string) string {\n\treturn fmt.Sprintf(\"[%s]\", s)\n}\n",
+            ),
+            (
+                "comments before closing tag stay inside element",
+                "package main\n\ntempl Foo() {\n\t<div>\n\t\t<span>Hello</span>\n\t\t// comment before closing\n\t</div>\n}\n",
+                "package main\n\ntempl Foo() {\n\t<div>\n\t\t<span>Hello</span>\n\t\t// comment before closing\n\t</div>\n}\n",
+            ),
+            (
+                "comments as only children stay inside element",
+                "package main\n\ntempl Foo() {\n\t<div>\n\t\t// only comments as children\n\t\t// another comment\n\t</div>\n}\n",
+                "package main\n\ntempl Foo() {\n\t<div>\n\t\t// only comments as children\n\t\t// another comment\n\t</div>\n}\n",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_format_roundtrip() {
+        let cases = [
+            (
+                "complex nested",
+                "package main\n\nimport (\n\t\"fmt\"\n)\n\ntempl Complex(items []string, selected int) {\n\t<div border={1}>\n\t\tfor i, item := range items {\n\t\t\tif i == selected {\n\t\t\t\t<span class=\"bold\">{fmt.Sprintf(\"> %s\", item)}</span>\n\t\t\t} else {\n\t\t\t\t<span>{item}</span>\n\t\t\t}\n\t\t}\n\t</div>\n}\n",
+            ),
+            (
+                "multi-line attrs",
+                "package main\n\ntempl Test() {\n\t<div\n\t\tclass=\"a\"\n\t\tid=\"b\">\n\t\t<span>Content</span>\n\t</div>\n}\n",
+            ),
+            (
+                "file with trailing comments",
+                "package main\n\ntempl Hello() {\n\t<span>Hello</span>  // inline\n}\n",
+            ),
+            (
+                "orphan in inner div and outer div stay in place",
+                "package main\n\ntempl Foo() {\n\t<div>\n\t\t<div>\n\t\t\t<span>Text</span>\n\t\t\t// orphan inside inner div\n\t\t</div>\n\t\t<span>After inner div</span>\n\t\t// orphan inside outer div\n\t</div>\n}\n",
+            ),
+            (
+                "orphan comments in component body",
+                "package main\n\ntempl Foo() {\n\t// orphan at start\n\t<div>\n\t\t<span>Text</span>\n\t</div>\n\t// orphan at end\n}\n",
+            ),
+        ];
+        check_idempotent(&formatter(), &cases);
+
+        // The roundtrip corpus runs with import fixing on.
+        let cases = [
+            (
+                "simple component",
+                "package test\n\ntempl Hello() {\n\t<div class=\"flex-col\">\n\t\t<span>Hello</span>\n\t</div>\n}\n",
+            ),
+            (
+                "component with imports",
+                "package test\n\nimport \"fmt\"\n\ntempl Hello(name string) {\n\t<span>{fmt.Sprintf(\"Hello %s\", name)}</span>\n}\n",
+            ),
+            (
+                "if else",
+                "package test\n\ntempl Cond(show bool) {\n\t<div>\n\t\tif show {\n\t\t\t<span>Yes</span>\n\t\t} else {\n\t\t\t<span>No</span>\n\t\t}\n\t</div>\n}\n",
+            ),
+            (
+                "for loop",
+                "package test\n\ntempl List(items []string) {\n\t<div class=\"flex-col\">\n\t\tfor _, item := range items {\n\t\t\t<span>{item}</span>\n\t\t}\n\t</div>\n}\n",
+            ),
+            (
+                "self-closing elements",
+                "package test\n\ntempl Divider() {\n\t<div>\n\t\t<hr />\n\t\t<br />\n\t</div>\n}\n",
+            ),
+            (
+                "let binding",
+                "package test\n\nimport \"fmt\"\n\ntempl Counter(count int) {\n\tcountText := <span>{fmt.Sprintf(\"Count: %d\", count)}</span>\n\t<div>{countText}</div>\n}\n",
+            ),
+        ];
+        check_idempotent(&Formatter::new(), &cases);
+    }
+
+    #[test]
+    fn test_fix_imports() {
+        let fmtr = Formatter::new();
+        let got = fmtr
+            .format("package main\n\ntempl Hello() {\n\t<span>Hello</span>\n}\n")
+            .unwrap();
+        assert_eq!(
+            got,
+            "package main\n\nimport tui \"github.com/grindlemire/go-tui\"\n\ntempl Hello() {\n\t<span>Hello</span>\n}\n"
+        );
+
+        let got = fmtr
+            .format("package main\n\nimport (\n\t\"github.com/acme/widgets\"\n\t\"strings\"\n\t\"fmt\"\n\t\"strings\"\n)\n\nfunc f() {}\n")
+            .unwrap();
+        assert_eq!(
+            got,
+            "package main\n\nimport (\n\t\"fmt\"\n\t\"strings\"\n\t\"github.com/acme/widgets\"\n)\n\nfunc f() {}\n"
+        );
+    }
+
+    #[test]
+    fn test_format_with_result() {
+        let fmtr = formatter();
+        let formatted = "package main\n\ntempl Hello() {\n\t<span>Hello</span>\n}\n";
+        assert!(!fmtr.format_with_result(formatted).unwrap().changed);
+        let messy = "package main\n\ntempl Hello() {\n<span>Hello</span>\n}\n";
+        let result = fmtr.format_with_result(messy).unwrap();
+        assert!(result.changed);
+        assert_eq!(result.content, formatted);
+    }
+
+    #[test]
+    fn test_format_errors() {
+        let fmtr = formatter();
+        assert_eq!(
+            fmtr.format("templ Hello() {\n\t<span>Hello</span>\n}\n"),
+            Err(FormatError::MissingPackage)
+        );
+        assert_eq!(fmtr.format(""), Err(FormatError::MissingPackage));
+        assert!(matches!(
+            fmtr.format("package main\n\n@component Hello( {\n\t<span>Hello</span>\n}\n"),
+            Err(FormatError::Syntax { .. })
+        ));
+        assert_eq!(
+            fmtr.format("package main\n\ntempl A() {\n\t<div></span>\n}\n"),
+            Err(FormatError::MismatchedTag {
+                line: 4,
+                column: 9,
+                expected: "div".to_string(),
+                found: "span".to_string(),
+            })
+        );
+        assert_eq!(fmtr.format("package test\n").unwrap(), "package test\n\n");
+    }
+
+    #[test]
+    fn test_escape_string() {
+        let cases = [
+            ("hello world", "hello world"),
+            ("hello\nworld", "hello\\nworld"),
+            ("hello\tworld", "hello\\tworld"),
+            ("hello \"world\"", "hello \\\"world\\\""),
+            ("hello\\world", "hello\\\\world"),
+            (
+                "line1\nline2\ttab\"quote\"",
+                "line1\\nline2\\ttab\\\"quote\\\"",
+            ),
+        ];
+        for (input, want) in cases {
+            assert_eq!(escape_string(input), want, "escape_string({input:?})");
+        }
+    }
+
+    #[test]
+    fn test_format_float() {
+        let cases = [
+            (1.5, "1.5"),
+            (2.0, "2"),
+            (0.25, "0.25"),
+            (123456.0, "123456"),
+            (1000000.0, "1e+06"),
+            (1234567.5, "1.2345675e+06"),
+            (0.0001, "0.0001"),
+            (0.00001, "1e-05"),
+        ];
+        for (value, want) in cases {
+            assert_eq!(format_float(value), want, "format_float({value})");
+        }
+    }
+}
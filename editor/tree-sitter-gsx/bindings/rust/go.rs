@@ -0,0 +1,284 @@
+//! Go syntax trees for the code embedded in a GSX file.
+//!
+//! The GSX grammar keeps `{...}` expressions, function bodies and Go
+//! declarations as opaque text. [`parse_with_go`] parses each of those
+//! regions on its own with tree-sitter-go, restricted to the region through
+//! included ranges, so the byte offsets and positions of every Go node are
+//! already those of the `.gsx` file.
+//!
+//! ```
+//! use tree_sitter_gsx::go::parse_with_go;
+//!
+//! let code = "templ Count(items []string) {\n\t<span>{len(items)}</span>\n}\n";
+//! let doc = parse_with_go(code);
+//!
+//! let expr = doc.tree().root_node().named_descendant_for_byte_range(38, 38).unwrap();
+//! assert_eq!(expr.kind(), "expression_content");
+//! let go = doc.go_subtree(expr).unwrap();
+//! assert_eq!(go.kind(), "source_file");
+//!
+//! let items = go.descendant_for_byte_range(42, 47).unwrap();
+//! assert_eq!(items.kind(), "identifier");
+//! assert_eq!(&code[items.byte_range()], "items");
+//! ```
+
+use std::cmp::Ordering;
+
+use tree_sitter::{Node, Parser, Range, Tree};
+
+/// A GSX tree together with a Go tree for each embedded Go region.
+pub struct GsxDocument {
+    tree: Tree,
+    regions: Vec<GoRegion>,
+}
+
+/// One embedded Go region and its Go syntax tree.
+pub struct GoRegion {
+    /// The id of the GSX node the region belongs to: a `go_expression`,
+    /// `function_body` or `go_declaration`.
+    host: usize,
+    range: Range,
+    tree: Tree,
+}
+
+impl GoRegion {
+    /// The span of the Go code in the `.gsx` file, without the braces of an
+    /// expression or function body.
+    pub fn range(&self) -> Range {
+        self.range
+    }
+
+    pub fn tree(&self) -> &Tree {
+        &self.tree
+    }
+
+    /// The `source_file` node of the Go tree.
+    pub fn root_node(&self) -> Node<'_> {
+        self.tree.root_node()
+    }
+}
+
+impl GsxDocument {
+    /// The GSX syntax tree.
+    pub fn tree(&self) -> &Tree {
+        &self.tree
+    }
+
+    /// The embedded Go regions, in source order.
+    pub fn regions(&self) -> &[GoRegion] {
+        &self.regions
+    }
+
+    /// Returns the root of the Go tree for `node`, which is a `go_expression`,
+    /// `function_body` or `go_declaration`, or the `expression_content` or
+    /// `go_code_content` inside one. Other nodes have no Go subtree.
+    pub fn go_subtree(&self, node: Node<'_>) -> Option<Node<'_>> {
+        let mut host = node;
+        while matches!(
+            host.kind(),
+            "expression_content" | "go_code_content" | "go_brace_body" | "go_paren_body"
+        ) {
+            host = host.parent()?;
+        }
+        self.regions
+            .iter()
+            .find(|region| region.host == host.id())
+            .map(GoRegion::root_node)
+    }
+
+    /// Returns the smallest Go node spanning `start..end` of the `.gsx` file,
+    /// or `None` when the range isn't inside a single Go region.
+    pub fn go_descendant_for_byte_range(&self, start: usize, end: usize) -> Option<Node<'_>> {
+        let index = self
+            .regions
+            .partition_point(|region| region.range.start_byte <= start)
+            .checked_sub(1)?;
+        let region = &self.regions[index];
+        if end > region.range.end_byte {
+            return None;
+        }
+        region.root_node().descendant_for_byte_range(start, end)
+    }
+}
+
+/// Parses `source` as GSX and every embedded Go region as Go.
+///
+/// The regions are the content of `{...}` expressions, the bodies of Go
+/// functions, and whole Go declarations such as `type Props interface {...}`.
+/// Each region is parsed separately, so an error in one doesn't leak into the
+/// next. Regions the Go parser gives up on are left out.
+pub fn parse_with_go(source: &str) -> GsxDocument {
+    let mut parser = Parser::new();
+    parser
+        .set_language(&crate::language())
+        .expect("GSX grammar is compatible");
+    let tree = parser.parse(source, None).expect("parser has a language");
+
+    let mut go = Parser::new();
+    go.set_language(&tree_sitter_go::LANGUAGE.into())
+        .expect("Go grammar is compatible");
+
+    let mut regions = Vec::new();
+    let mut cursor = tree.walk();
+    'walk: loop {
+        let node = cursor.node();
+        let region = go_region(node);
+        if let Some((host, content)) = region {
+            let range = content.range();
+            if let Some(tree) = parse_region(&mut go, source, range) {
+                regions.push(GoRegion {
+                    host: host.id(),
+                    range,
+                    tree,
+                });
+            }
+        }
+        // Regions don't nest, so there's no need to look inside one.
+        if region.is_none() && cursor.goto_first_child() {
+            continue;
+        }
+        while !cursor.goto_next_sibling() {
+            if !cursor.goto_parent() {
+                break 'walk;
+            }
+        }
+    }
+    drop(cursor);
+
+    GsxDocument { tree, regions }
+}
+
+/// Parses `range` of `source` as Go.
+///
+/// Go wants a newline or `;` after the last statement, which an expression
+/// followed by `}` doesn't have, so the parser reads a newline right after
+/// the region.
+pub(crate) fn parse_region(go: &mut Parser, source: &str, range: Range) -> Option<Tree> {
+    let bytes = source.as_bytes();
+    let end = range.end_byte;
+    let mut padded = range;
+    padded.end_byte += 1;
+    padded.end_point.column += 1;
+    go.set_included_ranges(&[padded]).ok()?;
+    go.parse_with_options(
+        &mut |i, _| match i.cmp(&end) {
+            Ordering::Less => &bytes[i..end],
+            Ordering::Equal => &b"\n"[..],
+            Ordering::Greater => &[],
+        },
+        None,
+        None,
+    )
+}
+
+/// Returns the host node and the Go content of a Go region.
+fn go_region(node: Node<'_>) -> Option<(Node<'_>, Node<'_>)> {
+    match node.kind() {
+        "go_expression" | "function_body" => {
+            let mut cursor = node.walk();
+            let content = node
+                .named_children(&mut cursor)
+                .find(|child| matches!(child.kind(), "expression_content" | "go_code_content"))?;
+            Some((node, content))
+        }
+        "go_declaration" => Some((node, node)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identifiers<'a>(node: Node<'_>, source: &'a str) -> Vec<&'a str> {
+        let mut names = Vec::new();
+        let mut cursor = node.walk();
+        loop {
+            let node = cursor.node();
+            if node.kind() == "identifier" {
+                names.push(&source[node.byte_range()]);
+            }
+            if cursor.goto_first_child() {
+                continue;
+            }
+            while !cursor.goto_next_sibling() {
+                if !cursor.goto_parent() {
+                    return names;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_regions() {
+        let code = r#"package ui
+
+type Labeler interface {
+	Label() string
+}
+
+func title(s string) string {
+	return strings.ToUpper(s)
+}
+
+templ Row(item Labeler, count int) {
+	<span>{title(item.Label())}</span>
+	<text>{fmt.Sprintf("%d", count)}</text>
+}
+"#;
+        let doc = parse_with_go(code);
+        let regions: Vec<_> = doc
+            .regions()
+            .iter()
+            .map(|region| {
+                let root = region.root_node();
+                assert!(!root.has_error(), "{}", root.to_sexp());
+                identifiers(root, code)
+            })
+            .collect();
+        assert_eq!(
+            regions,
+            [
+                vec![],
+                vec!["strings", "s"],
+                vec!["title", "item"],
+                vec!["fmt", "count"],
+            ]
+        );
+
+        let text = doc.regions()[3].range();
+        assert_eq!(
+            &code[text.start_byte..text.end_byte],
+            "fmt.Sprintf(\"%d\", count)"
+        );
+        assert_eq!(text.start_point.row, 12);
+    }
+
+    #[test]
+    fn test_go_subtree() {
+        let code = "func f() int {\n\treturn 1\n}\n\ntempl A() {\n\t<span>{x}</span>\n}\n";
+        let doc = parse_with_go(code);
+        let root = doc.tree().root_node();
+
+        let body = root
+            .child(0)
+            .and_then(|func| func.child_by_field_name("body"))
+            .unwrap();
+        let go = doc.go_subtree(body).unwrap();
+        let ret = go.named_child(0).unwrap();
+        assert_eq!(ret.kind(), "return_statement");
+        assert_eq!(ret.start_position().row, 1);
+
+        let x = code.find("{x}").unwrap() + 1;
+        let expr = root.named_descendant_for_byte_range(x, x + 1).unwrap();
+        assert_eq!(expr.kind(), "expression_content");
+        assert_eq!(doc.go_subtree(expr), doc.go_subtree(expr.parent().unwrap()));
+        assert_eq!(
+            doc.go_descendant_for_byte_range(x, x + 1).map(|n| n.kind()),
+            Some("identifier")
+        );
+
+        assert!(doc.go_subtree(root).is_none());
+        assert!(doc.go_descendant_for_byte_range(0, 4).is_none());
+    }
+}
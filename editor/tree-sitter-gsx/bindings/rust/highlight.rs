@@ -0,0 +1,402 @@
+//! Syntax highlighting with the Go code injected, for [`crate::ansi`].
+//!
+//! [`highlight`] runs [`HIGHLIGHTS_QUERY`] over a `.gsx` buffer, parses every
+//! region [`INJECTIONS_QUERY`] marks as Go with tree-sitter-go, and merges
+//! both into one stream of nested [`Event`]s, so `{...}` expressions and
+//! function bodies come out coloured too. The first pattern that captures a
+//! node wins, and a capture takes the longest of [`HIGHLIGHT_NAMES`] that is
+//! a dot-separated prefix of it.
+//!
+//! tree-sitter-highlight would do this, but no release of it works with the
+//! tree-sitter version this grammar is generated for, so the engine stays
+//! internal.
+//!
+//! [`HIGHLIGHTS_QUERY`]: crate::HIGHLIGHTS_QUERY
+//! [`INJECTIONS_QUERY`]: crate::INJECTIONS_QUERY
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use tree_sitter::{Language, Node, Parser, Query, QueryCursor, Range, StreamingIterator, Tree};
+
+/// The highlight names: every capture name the GSX and Go highlight queries
+/// use. An [`Event::Start`] indexes this list.
+pub(crate) const HIGHLIGHT_NAMES: &[&str] = &[
+    "boolean",
+    "character",
+    "comment",
+    "constant.builtin",
+    "embedded",
+    "escape",
+    "function",
+    "function.builtin",
+    "function.call",
+    "function.definition",
+    "function.method",
+    "function.method.call",
+    "keyword",
+    "keyword.conditional",
+    "keyword.function",
+    "keyword.import",
+    "keyword.repeat",
+    "module",
+    "number",
+    "operator",
+    "property",
+    "property.definition",
+    "punctuation.bracket",
+    "punctuation.delimiter",
+    "punctuation.special",
+    "string",
+    "string.special",
+    "tag",
+    "tag.delimiter",
+    "type",
+    "type.definition",
+    "variable",
+    "variable.definition",
+    "variable.parameter",
+];
+
+/// One step of a highlighted buffer. Highlights nest: each `Start` is
+/// closed by a later `End`, and the innermost open highlight applies to a
+/// `Source` span.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Event {
+    /// The bytes `start..end` of the source.
+    Source {
+        start: usize,
+        end: usize,
+    },
+    /// Starts the highlight at this index of [`HIGHLIGHT_NAMES`].
+    Start(usize),
+    End,
+}
+
+/// A language with its compiled queries.
+struct Config {
+    language: Language,
+    query: Query,
+    injections: Option<Query>,
+    /// The highlight of each capture of `query`.
+    highlights: Vec<Option<usize>>,
+}
+
+impl Config {
+    fn new(language: Language, highlights_query: &str, injection_query: Option<&str>) -> Self {
+        let query = Query::new(&language, highlights_query).expect("highlight query is valid");
+        let injections = injection_query
+            .map(|query| Query::new(&language, query).expect("injection query is valid"));
+        let highlights = capture_highlights(query.capture_names(), HIGHLIGHT_NAMES);
+        Self {
+            language,
+            query,
+            injections,
+            highlights,
+        }
+    }
+
+    /// Returns the configuration of an injected language, or `None` for one
+    /// GSX doesn't inject.
+    fn injected(name: &str) -> Option<&'static Config> {
+        static GO: OnceLock<Config> = OnceLock::new();
+        match name {
+            "go" => Some(GO.get_or_init(|| {
+                Config::new(
+                    tree_sitter_go::LANGUAGE.into(),
+                    tree_sitter_go::HIGHLIGHTS_QUERY,
+                    None,
+                )
+            })),
+            _ => None,
+        }
+    }
+}
+
+/// Maps each capture name to the index of the longest of `names` that is a
+/// dot-separated prefix of it, so `keyword` also covers `keyword.function`
+/// unless that is in `names` itself.
+fn capture_highlights(captures: &[&str], names: &[&str]) -> Vec<Option<usize>> {
+    captures
+        .iter()
+        .map(|capture| {
+            let parts: Vec<_> = capture.split('.').collect();
+            names
+                .iter()
+                .enumerate()
+                .filter_map(|(i, name)| {
+                    let name: Vec<_> = name.split('.').collect();
+                    parts.starts_with(&name).then_some((name.len(), i))
+                })
+                .max_by_key(|&(len, i)| (len, std::cmp::Reverse(i)))
+                .map(|(_, i)| i)
+        })
+        .collect()
+}
+
+/// Highlights a `.gsx` buffer, embedded Go included.
+pub(crate) fn highlight(source: &str) -> Vec<Event> {
+    static GSX: OnceLock<Config> = OnceLock::new();
+    let config = GSX.get_or_init(|| {
+        Config::new(
+            crate::language(),
+            crate::HIGHLIGHTS_QUERY,
+            Some(crate::INJECTIONS_QUERY),
+        )
+    });
+    let mut parser = Parser::new();
+    let mut spans = Vec::new();
+    parser
+        .set_language(&config.language)
+        .expect("GSX grammar is compatible");
+    if let Some(tree) = parser.parse(source, None) {
+        collect(config, &tree, source, 0, &mut parser, &mut spans);
+    }
+    spans.sort_by_key(|s| (s.start, std::cmp::Reverse(s.end), s.depth));
+    events(&spans, source.len())
+}
+
+/// A highlighted byte range. `depth` counts the injections it is nested in.
+struct Span {
+    start: usize,
+    end: usize,
+    depth: usize,
+    highlight: usize,
+}
+
+/// Adds the highlights of `tree` to `spans`, then parses and highlights its
+/// injections one level deeper.
+fn collect(
+    config: &Config,
+    tree: &Tree,
+    source: &str,
+    depth: usize,
+    parser: &mut Parser,
+    spans: &mut Vec<Span>,
+) {
+    // The first pattern that captures a node decides its highlight, even
+    // when that capture isn't recognized.
+    let mut chosen: Vec<(usize, Option<usize>, Node)> = Vec::new();
+    let mut by_node: HashMap<usize, usize> = HashMap::new();
+    let mut cursor = QueryCursor::new();
+    let names = config.query.capture_names();
+    let mut captures = cursor.captures(&config.query, tree.root_node(), source.as_bytes());
+    while let Some((m, index)) = captures.next() {
+        let capture = m.captures[*index];
+        if names[capture.index as usize].starts_with('_') {
+            continue;
+        }
+        let highlight = config.highlights[capture.index as usize];
+        match by_node.get(&capture.node.id()) {
+            Some(&i) if chosen[i].0 > m.pattern_index => {
+                chosen[i] = (m.pattern_index, highlight, capture.node);
+            }
+            Some(_) => {}
+            None => {
+                by_node.insert(capture.node.id(), chosen.len());
+                chosen.push((m.pattern_index, highlight, capture.node));
+            }
+        }
+    }
+    spans.extend(chosen.into_iter().filter_map(|(_, highlight, node)| {
+        Some(Span {
+            start: node.start_byte(),
+            end: node.end_byte(),
+            depth,
+            highlight: highlight?,
+        })
+    }));
+
+    for (language, ranges) in injections(config, tree, source) {
+        let Some(injected) = Config::injected(&language) else {
+            continue;
+        };
+        if parser.set_language(&injected.language).is_err() {
+            continue;
+        }
+        let tree = match ranges[..] {
+            [range] => crate::go::parse_region(parser, source, range),
+            _ => parser
+                .set_included_ranges(&ranges)
+                .ok()
+                .and_then(|()| parser.parse(source, None)),
+        };
+        if let Some(tree) = tree {
+            collect(injected, &tree, source, depth + 1, parser, spans);
+        }
+    }
+}
+
+/// Returns the language and ranges of each injection in `tree`. Without
+/// `injection.include-children` the content's child nodes are left out.
+fn injections(config: &Config, tree: &Tree, source: &str) -> Vec<(String, Vec<Range>)> {
+    let Some(query) = &config.injections else {
+        return Vec::new();
+    };
+    let Some(content) = query.capture_index_for_name("injection.content") else {
+        return Vec::new();
+    };
+    let mut out = Vec::new();
+    let mut cursor = QueryCursor::new();
+    let mut matches = cursor.matches(query, tree.root_node(), source.as_bytes());
+    while let Some(m) = matches.next() {
+        let settings = query.property_settings(m.pattern_index);
+        let language = settings
+            .iter()
+            .find(|p| &*p.key == "injection.language")
+            .and_then(|p| p.value.as_deref());
+        let include_children = settings
+            .iter()
+            .any(|p| &*p.key == "injection.include-children");
+        let Some(language) = language else {
+            continue;
+        };
+        for capture in m.captures.iter().filter(|c| c.index == content) {
+            let ranges = if include_children {
+                vec![capture.node.range()]
+            } else {
+                ranges_without_children(capture.node)
+            };
+            if !ranges.is_empty() {
+                out.push((language.to_string(), ranges));
+            }
+        }
+    }
+    out
+}
+
+/// Returns the parts of `node` not covered by its children.
+fn ranges_without_children(node: Node) -> Vec<Range> {
+    let mut ranges = Vec::new();
+    let mut range = node.range();
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.start_byte() > range.start_byte {
+            ranges.push(Range {
+                end_byte: child.start_byte(),
+                end_point: child.start_position(),
+                ..range
+            });
+        }
+        range.start_byte = child.end_byte();
+        range.start_point = child.end_position();
+    }
+    if range.end_byte > range.start_byte {
+        ranges.push(range);
+    }
+    ranges
+}
+
+/// Turns spans ordered by start, outermost first, into nested events.
+/// A span that overlaps the end of an enclosing one is cut off there.
+fn events(spans: &[Span], len: usize) -> Vec<Event> {
+    let mut events = Vec::new();
+    let mut open: Vec<usize> = Vec::new();
+    let mut pos = 0;
+    for span in spans {
+        while let Some(&end) = open.last().filter(|&&end| end <= span.start) {
+            source(&mut events, &mut pos, end);
+            events.push(Event::End);
+            open.pop();
+        }
+        let end = open.last().map_or(span.end, |&outer| span.end.min(outer));
+        if span.start >= end {
+            continue;
+        }
+        source(&mut events, &mut pos, span.start);
+        events.push(Event::Start(span.highlight));
+        open.push(end);
+    }
+    while let Some(end) = open.pop() {
+        source(&mut events, &mut pos, end);
+        events.push(Event::End);
+    }
+    source(&mut events, &mut pos, len);
+    events
+}
+
+/// Emits the source between `pos` and `to`, if any, and moves `pos` there.
+fn source(events: &mut Vec<Event>, pos: &mut usize, to: usize) {
+    if to > *pos {
+        events.push(Event::Source {
+            start: *pos,
+            end: to,
+        });
+        *pos = to;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns each highlighted source span with its innermost highlight.
+    fn spans(code: &str) -> Vec<(&str, &'static str)> {
+        let mut stack = Vec::new();
+        let mut out = Vec::new();
+        for event in highlight(code) {
+            match event {
+                Event::Start(h) => stack.push(h),
+                Event::End => {
+                    stack.pop();
+                }
+                Event::Source { start, end } => {
+                    if let Some(h) = stack.last() {
+                        out.push((&code[start..end], HIGHLIGHT_NAMES[*h]));
+                    }
+                }
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_go_regions_are_highlighted() {
+        let code = "templ Count(n int) {\n\t<span>{fmt.Sprintf(\"%d\", n)}</span>\n}\n\nfunc double(x int) int {\n\treturn x * 2\n}\n";
+        let spans = spans(code);
+        for want in [
+            ("templ", "keyword.function"),
+            ("Count", "function.definition"),
+            ("span", "tag"),
+            ("Sprintf", "function.method"),
+            ("\"%d\"", "string"),
+            ("double", "function.definition"),
+            ("return", "keyword"),
+            ("2", "number"),
+        ] {
+            assert!(spans.contains(&want), "{want:?} not in {spans:?}");
+        }
+    }
+
+    #[test]
+    fn test_events_cover_the_source() {
+        let code = "templ A() {\n\t<hr />\n\t{x + 1}\n}\n";
+        let mut depth = 0i32;
+        let mut text = String::new();
+        for event in highlight(code) {
+            match event {
+                Event::Start(_) => depth += 1,
+                Event::End => depth -= 1,
+                Event::Source { start, end } => text.push_str(&code[start..end]),
+            }
+            assert!(depth >= 0);
+        }
+        assert_eq!(depth, 0);
+        assert_eq!(text, code);
+    }
+
+    #[test]
+    fn test_longest_prefix_wins() {
+        let names = ["keyword", "keyword.function", "tag"];
+        let captures = [
+            "keyword.repeat",
+            "keyword.function",
+            "tag.delimiter",
+            "module",
+        ];
+        assert_eq!(
+            capture_highlights(&captures, &names),
+            [Some(0), Some(1), Some(2), None]
+        );
+    }
+}
@@ -0,0 +1,288 @@
+//! Auto-indentation.
+//!
+//! [`indent_for_line`] applies [`INDENTS_QUERY`]: a line is indented one
+//! level for every `@indent.begin` node that started on an earlier line and
+//! is still open, minus one when the line starts with an `@indent.branch`
+//! such as the `}` or `</div>` that closes the innermost of them. `tui fmt`
+//! indents each level with a tab, so the result is the number of tabs.
+//!
+//! While typing, the tree is usually incomplete. A closing delimiter the
+//! parser had to assume (a `MISSING` node) doesn't close anything, and the
+//! delimiters and start tags left open inside an `ERROR` node each indent
+//! one level too.
+//!
+//! ```
+//! use tree_sitter_gsx::indent::indent_for_line;
+//!
+//! let code = "templ Card() {\n\t<div>\n\n\t</div>\n}\n";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&tree_sitter_gsx::language()).unwrap();
+//! let tree = parser.parse(code, None).unwrap();
+//!
+//! assert_eq!(indent_for_line(&tree, code, 2), 2);
+//! assert_eq!(indent_for_line(&tree, code, 3), 1);
+//! ```
+//!
+//! [`INDENTS_QUERY`]: crate::INDENTS_QUERY
+
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+use tree_sitter::{Node, Query, QueryCursor, StreamingIterator, Tree};
+
+fn query() -> &'static Query {
+    static QUERY: OnceLock<Query> = OnceLock::new();
+    QUERY.get_or_init(|| {
+        Query::new(&crate::language(), crate::INDENTS_QUERY).expect("indents query is valid")
+    })
+}
+
+/// The nodes captured by each of the query's captures.
+#[derive(Default)]
+struct Captures {
+    begin: HashSet<usize>,
+    end: HashSet<usize>,
+    branch: HashSet<usize>,
+}
+
+impl Captures {
+    fn new(tree: &Tree, source: &str) -> Self {
+        let query = query();
+        let names = query.capture_names();
+        let mut captures = Captures::default();
+        let mut cursor = QueryCursor::new();
+        let mut matches = cursor.captures(query, tree.root_node(), source.as_bytes());
+        while let Some((m, index)) = matches.next() {
+            let capture = m.captures[*index];
+            let set = match names[capture.index as usize] {
+                "indent.begin" => &mut captures.begin,
+                "indent.end" => &mut captures.end,
+                "indent.branch" => &mut captures.branch,
+                _ => continue,
+            };
+            set.insert(capture.node.id());
+        }
+        captures
+    }
+
+    /// Reports whether `node` has its closing delimiter, not counting one the
+    /// parser assumed.
+    fn is_closed(&self, node: Node) -> bool {
+        let mut cursor = node.walk();
+        let closed = node
+            .children(&mut cursor)
+            .any(|child| self.end.contains(&child.id()) && !child.is_missing());
+        closed
+    }
+}
+
+/// Returns the number of levels to indent the zero-based `line` of `source`.
+///
+/// A line with text is indented for the node at its first character. A blank
+/// line, or one past the end of the file, is indented as if it were typed
+/// right after the previous non-blank line, so a new line after `<div>`
+/// lands one level deeper.
+pub fn indent_for_line(tree: &Tree, source: &str, line: usize) -> usize {
+    let captures = Captures::new(tree, source);
+    let root = tree.root_node();
+
+    let line_start = line_offset(source, line);
+    let text = source[line_start..].split('\n').next().unwrap_or("");
+    let first = text.len() - text.trim_start().len();
+    let pos = line_start + first;
+    let (node, closing) = if first < text.trim_end().len() {
+        let node = root.descendant_for_byte_range(pos, pos + 1);
+        (node, closed_by_branch(node, pos, &captures))
+    } else {
+        // The last character before the line.
+        let before = source[..line_start].trim_end().len();
+        let node = before
+            .checked_sub(1)
+            .and_then(|pos| root.descendant_for_byte_range(pos, pos + 1));
+        (node, None)
+    };
+
+    let mut level: usize = 0;
+    let mut child = None;
+    let mut ancestor = node;
+    while let Some(node) = ancestor {
+        if captures.begin.contains(&node.id())
+            && node.start_position().row < line
+            && (node.end_position().row >= line || !captures.is_closed(node))
+        {
+            level += 1;
+        }
+        // A branch closes whatever its node left open.
+        if closing != Some(node) {
+            level += unclosed_in_errors(node, child, pos, line);
+        }
+        child = Some(node);
+        ancestor = node.parent();
+    }
+    if closing.is_some() {
+        level = level.saturating_sub(1);
+    }
+    level
+}
+
+/// Counts the delimiters and start tags opened before `line` and still open
+/// at `pos` in the `ERROR` nodes among the children of `node` that end by
+/// `pos`, or in all such children when `node` is an `ERROR` itself. `skip` is
+/// the child already counted on the way up.
+fn unclosed_in_errors(node: Node, skip: Option<Node>, pos: usize, line: usize) -> usize {
+    let mut open = Vec::new();
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.end_byte() > pos || Some(child) == skip {
+            break;
+        }
+        if node.is_error() || child.is_error() {
+            scan_delimiters(child, &mut open);
+        }
+    }
+    open.iter().filter(|(_, row)| *row < line).count()
+}
+
+/// Pushes the opening delimiters among the tokens of `node` onto `open`,
+/// with their rows, and pops them again at their closing delimiters.
+fn scan_delimiters(node: Node, open: &mut Vec<(&'static str, usize)>) {
+    if node.child_count() > 0 {
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            scan_delimiters(child, open);
+        }
+        return;
+    }
+    if node.is_missing() {
+        return;
+    }
+    let row = node.start_position().row;
+    let closes = match node.kind() {
+        "{" => return open.push(("{", row)),
+        "(" => return open.push(("(", row)),
+        "[" => return open.push(("[", row)),
+        // A start tag, not a comparison
+        "<" if node
+            .parent()
+            .is_none_or(|p| p.kind() != "binary_expression") =>
+        {
+            return open.push(("<", row));
+        }
+        "}" => "{",
+        ")" => "(",
+        "]" => "[",
+        "/>" | "</" => "<",
+        _ => return,
+    };
+    if let Some(i) = open.iter().rposition(|(kind, _)| *kind == closes) {
+        open.truncate(i);
+    }
+}
+
+/// Returns the node closed by the branch the line starting at `pos` starts
+/// with, if it does.
+fn closed_by_branch<'tree>(
+    node: Option<Node<'tree>>,
+    pos: usize,
+    captures: &Captures,
+) -> Option<Node<'tree>> {
+    let mut node = node;
+    while let Some(n) = node.filter(|n| n.start_byte() == pos) {
+        if captures.branch.contains(&n.id()) {
+            return n
+                .parent()
+                .filter(|parent| captures.begin.contains(&parent.id()));
+        }
+        node = n.parent();
+    }
+    None
+}
+
+/// Returns the byte offset of the start of `line`, or the end of `source` if
+/// it has fewer lines.
+fn line_offset(source: &str, line: usize) -> usize {
+    if line == 0 {
+        return 0;
+    }
+    source
+        .match_indices('\n')
+        .nth(line - 1)
+        .map_or(source.len(), |(i, _)| i + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter::Parser;
+
+    use super::*;
+
+    fn parse(code: &str) -> Tree {
+        let mut parser = Parser::new();
+        parser.set_language(&crate::language()).unwrap();
+        parser.parse(code, None).unwrap()
+    }
+
+    fn indents(code: &str) -> Vec<usize> {
+        let tree = parse(code);
+        (0..code.lines().count())
+            .map(|line| indent_for_line(&tree, code, line))
+            .collect()
+    }
+
+    /// Checks that every line of `tui fmt` output is indented as formatted.
+    fn check_formatted(code: &str) {
+        let formatted = crate::format::Formatter::new().format(code).unwrap();
+        assert_eq!(formatted, code);
+        let expected: Vec<usize> = code
+            .lines()
+            .map(|line| line.len() - line.trim_start_matches('\t').len())
+            .collect();
+        assert_eq!(indents(code), expected, "{code}");
+    }
+
+    #[test]
+    fn test_matches_formatter_output() {
+        check_formatted(
+            "package main\n\nimport (\n\t\"fmt\"\n\ttui \"github.com/grindlemire/go-tui\"\n)\n\ntempl Card(title string, items []string) {\n\t<div class=\"flex-col\">\n\t\t<span>{title}</span>\n\t\t<box>\n\t\t\t<hr />\n\t\t</box>\n\t</div>\n\tfor _, item := range items {\n\t\t<span>{item}</span>\n\t}\n\tif title == \"\" {\n\t\t<hr />\n\t} else {\n\t\t<br />\n\t}\n}\n",
+        );
+        check_formatted(
+            "package main\n\ntype Props struct {\n\tName string\n}\n\nfunc label(p Props) string {\n\tif p.Name == \"\" {\n\t\treturn \"none\"\n\t}\n\treturn p.Name\n}\n",
+        );
+        check_formatted(
+            "package main\n\nimport tui \"github.com/grindlemire/go-tui\"\n\ntempl A(n int) {\n\tswitch n {\n\tcase 0:\n\t\t<hr />\n\tdefault:\n\t\t<br />\n\t}\n}\n",
+        );
+    }
+
+    #[test]
+    fn test_blank_lines() {
+        let code = "templ A() {\n\t<div>\n\n\t</div>\n\n}\n";
+        assert_eq!(indents(code), [0, 1, 2, 1, 1, 0]);
+
+        // A new last line inside a switch clause continues the clause
+        let code = "templ A(n int) {\n\tswitch n {\n\tcase 0:\n\n\t}\n}\n";
+        assert_eq!(indent_for_line(&parse(code), code, 3), 2);
+    }
+
+    #[test]
+    fn test_incomplete_input() {
+        // An element that isn't closed yet
+        let code = "templ A() {\n\t<div>\n\n}\n";
+        assert_eq!(indents(code), [0, 1, 2, 0]);
+
+        // A body whose `}` the parser had to assume
+        let code = "templ A() {\n";
+        assert_eq!(indent_for_line(&parse(code), code, 1), 1);
+
+        // Nested elements, neither closed
+        let code = "templ A() {\n\t<div>\n\t\t<span>\n\n";
+        assert_eq!(indents(code), [0, 1, 2, 3]);
+        let code = "templ A() {\n\t<div>\n\t\t<span>x</span>\n\t\t<hr />\n\n";
+        assert_eq!(indents(code), [0, 1, 2, 2, 2]);
+    }
+
+    #[test]
+    fn test_past_end_of_file() {
+        let code = "templ A() {\n\t<hr />\n}\n";
+        assert_eq!(indent_for_line(&parse(code), code, 10), 0);
+    }
+}
@@ -19,6 +19,25 @@
 
 use tree_sitter::Language;
 
+#[cfg(feature = "highlight")]
+pub mod ansi;
+pub mod ast;
+pub mod codegen;
+pub mod diagnostics;
+pub mod folding;
+pub mod format;
+pub mod go;
+#[cfg(feature = "highlight")]
+mod highlight;
+pub mod indent;
+pub mod locals;
+pub mod schema;
+pub mod semantic;
+pub mod sourcemap;
+pub mod tags;
+pub mod tailwind;
+pub mod workspace;
+
 extern "C" {
     fn tree_sitter_gsx() -> Language;
 }
@@ -35,12 +54,23 @@ pub fn language() -> Language {
 /// [`node-types.json`]: https://tree-sitter.github.io/tree-sitter/using-parsers#static-node-types
 pub const NODE_TYPES: &str = include_str!("../../src/node-types.json");
 
-// Uncomment these to include any queries that this grammar contains
+/// The syntax highlighting query for this language.
+pub const HIGHLIGHTS_QUERY: &str = include_str!("../../queries/highlights.scm");
+
+/// The language injection query for this language.
+pub const INJECTIONS_QUERY: &str = include_str!("../../queries/injections.scm");
+
+/// The code folding query for this language.
+pub const FOLDS_QUERY: &str = include_str!("../../queries/folds.scm");
+
+/// The auto-indentation query for this language.
+pub const INDENTS_QUERY: &str = include_str!("../../queries/indents.scm");
 
-// pub const HIGHLIGHTS_QUERY: &str = include_str!("../../queries/highlights.scm");
-// pub const INJECTIONS_QUERY: &str = include_str!("../../queries/injections.scm");
-// pub const LOCALS_QUERY: &str = include_str!("../../queries/locals.scm");
-// pub const TAGS_QUERY: &str = include_str!("../../queries/tags.scm");
+/// The local variable query for this language.
+pub const LOCALS_QUERY: &str = include_str!("../../queries/locals.scm");
+
+/// The symbol tagging query for this language.
+pub const TAGS_QUERY: &str = include_str!("../../queries/tags.scm");
 
 #[cfg(test)]
 mod tests {
@@ -51,4 +81,78 @@ mod tests {
             .set_language(&super::language())
             .expect("Error loading GSX grammar");
     }
+
+    #[test]
+    fn test_external_scanner_tokens() {
+        let code = "templ A() {\n\t<span a=\"say \\\"hi\\\"\" b=`x\ny`>Press \\{enter\\} \\@home</span>\n\t@Key('\\'')\n}\n";
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&super::language()).unwrap();
+        let tree = parser.parse(code, None).unwrap();
+        assert!(!tree.root_node().has_error());
+
+        let mut tokens = Vec::new();
+        let mut cursor = tree.walk();
+        loop {
+            let node = cursor.node();
+            if matches!(
+                node.kind(),
+                "string" | "raw_string" | "rune" | "text_content"
+            ) {
+                tokens.push((node.kind(), &code[node.byte_range()]));
+            }
+            if cursor.goto_first_child() {
+                continue;
+            }
+            while !cursor.goto_next_sibling() {
+                if !cursor.goto_parent() {
+                    assert_eq!(
+                        tokens,
+                        [
+                            ("string", "\"say \\\"hi\\\"\""),
+                            ("raw_string", "`x\ny`"),
+                            ("text_content", "Press \\{enter\\} \\@home"),
+                            ("rune", "'\\''"),
+                        ]
+                    );
+                    return;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_highlights_query_compiles() {
+        tree_sitter::Query::new(&super::language(), super::HIGHLIGHTS_QUERY)
+            .expect("Error compiling highlights query");
+    }
+
+    #[test]
+    fn test_injections_query_compiles() {
+        tree_sitter::Query::new(&super::language(), super::INJECTIONS_QUERY)
+            .expect("Error compiling injections query");
+    }
+
+    #[test]
+    fn test_folds_query_compiles() {
+        tree_sitter::Query::new(&super::language(), super::FOLDS_QUERY)
+            .expect("Error compiling folds query");
+    }
+
+    #[test]
+    fn test_indents_query_compiles() {
+        tree_sitter::Query::new(&super::language(), super::INDENTS_QUERY)
+            .expect("Error compiling indents query");
+    }
+
+    #[test]
+    fn test_locals_query_compiles() {
+        tree_sitter::Query::new(&super::language(), super::LOCALS_QUERY)
+            .expect("Error compiling locals query");
+    }
+
+    #[test]
+    fn test_tags_query_compiles() {
+        tree_sitter::Query::new(&super::language(), super::TAGS_QUERY)
+            .expect("Error compiling tags query");
+    }
 }
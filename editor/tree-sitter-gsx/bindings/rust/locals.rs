@@ -0,0 +1,298 @@
+//! Resolves identifiers to the local definitions they refer to.
+//!
+//! Scopes, definitions and references come from [`LOCALS_QUERY`]. A
+//! definition is visible in its scope from the end of the construct that
+//! declares it, so in `for i, item := range items` the `items` reference
+//! never resolves to the loop variables. References to package-level names,
+//! imports and Go builtins have no local definition and are left out.
+//!
+//! The grammar keeps `{...}` expressions as opaque text, so the identifiers
+//! in them come from the Go trees of a [`GsxDocument`] instead. Those
+//! references are Go nodes; their definitions are always GSX nodes.
+//!
+//! ```
+//! let code = "templ List(items []string) {\n\tfor _, item := range items {\n\t\t<span>{item}</span>\n\t}\n}\n";
+//! let doc = tree_sitter_gsx::go::parse_with_go(code);
+//!
+//! let refs = tree_sitter_gsx::locals::resolve_references(&doc, code);
+//! let item = &refs[1];
+//! assert_eq!(&code[item.node.byte_range()], "item");
+//! assert_eq!(item.definition.start_position().row, 1);
+//! ```
+//!
+//! [`LOCALS_QUERY`]: crate::LOCALS_QUERY
+
+use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
+
+use tree_sitter::{Node, Query, QueryCursor, StreamingIterator};
+
+use crate::go::GsxDocument;
+
+/// An identifier and the definition it resolves to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Reference<'tree> {
+    /// The referencing identifier, in the Go tree of its region when it is
+    /// inside a `{...}` expression.
+    pub node: Node<'tree>,
+    /// The defining identifier, e.g. the `name` of a `let_binding` or the
+    /// `value` of a `for_clause`.
+    pub definition: Node<'tree>,
+}
+
+struct Definition<'tree> {
+    node: Node<'tree>,
+    /// The byte offset the definition is visible from.
+    visible_from: usize,
+}
+
+fn query() -> &'static Query {
+    static QUERY: OnceLock<Query> = OnceLock::new();
+    QUERY.get_or_init(|| {
+        Query::new(&crate::language(), crate::LOCALS_QUERY).expect("locals query is valid")
+    })
+}
+
+/// Returns every reference in `doc` that resolves to a local definition, in
+/// source order.
+pub fn resolve_references<'doc>(doc: &'doc GsxDocument, source: &str) -> Vec<Reference<'doc>> {
+    let tree = doc.tree();
+    let query = query();
+    let names = query.capture_names();
+
+    let mut scopes = HashSet::new();
+    let mut definitions = Vec::new();
+    let mut references = Vec::new();
+    let mut cursor = QueryCursor::new();
+    let mut captures = cursor.captures(query, tree.root_node(), source.as_bytes());
+    while let Some((m, index)) = captures.next() {
+        let capture = m.captures[*index];
+        match names[capture.index as usize] {
+            "local.scope" => {
+                scopes.insert(capture.node.id());
+            }
+            "local.definition" => definitions.push(capture.node),
+            // A reference is looked up from the scopes around it.
+            "local.reference" => references.push((capture.node, capture.node)),
+            _ => {}
+        }
+    }
+
+    // Identifiers in `{...}` expressions are looked up from the scopes around
+    // the expression.
+    let mut expressions = Vec::new();
+    crate::format::collect_kind(tree.root_node(), "go_expression", &mut expressions);
+    for expression in expressions {
+        if let Some(root) = doc.go_subtree(expression) {
+            let mut found = Vec::new();
+            go_references(root, source, &mut Vec::new(), &mut found);
+            references.extend(found.into_iter().map(|node| (node, expression)));
+        }
+    }
+
+    // Definitions by the scope that contains them and their name.
+    let mut by_scope: HashMap<(usize, &str), Vec<Definition>> = HashMap::new();
+    for node in definitions {
+        let declaration = node.parent().unwrap_or(node);
+        by_scope
+            .entry((enclosing_scope(node, &scopes), &source[node.byte_range()]))
+            .or_default()
+            .push(Definition {
+                node,
+                visible_from: declaration.end_byte(),
+            });
+    }
+
+    let mut resolved = Vec::new();
+    for (node, anchor) in references {
+        let name = &source[node.byte_range()];
+        let mut scope = Some(anchor);
+        while let Some(current) = scope {
+            let found = by_scope.get(&(current.id(), name)).and_then(|defs| {
+                defs.iter()
+                    .rev()
+                    .find(|def| def.visible_from <= node.start_byte())
+            });
+            if let Some(def) = found {
+                resolved.push(Reference {
+                    node,
+                    definition: def.node,
+                });
+                break;
+            }
+            scope = current.parent();
+        }
+    }
+    resolved.sort_by_key(|r| r.node.start_byte());
+    resolved
+}
+
+/// Collects the identifiers in a Go tree that can refer to a local: not the
+/// names Go code declares itself, struct literal keys, or uses of a name a
+/// function literal declares. `shadowed` holds the names declared by the
+/// function literals around `node`.
+fn go_references<'doc>(
+    node: Node<'doc>,
+    source: &str,
+    shadowed: &mut Vec<String>,
+    out: &mut Vec<Node<'doc>>,
+) {
+    if node.kind() == "identifier" {
+        let name = &source[node.byte_range()];
+        if !is_go_definition(node) && !shadowed.iter().any(|s| s == name) {
+            out.push(node);
+        }
+        return;
+    }
+    let depth = shadowed.len();
+    if node.kind() == "func_literal" {
+        go_definitions(node, source, shadowed);
+    }
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        go_references(child, source, shadowed, out);
+    }
+    shadowed.truncate(depth);
+}
+
+fn go_definitions(node: Node, source: &str, out: &mut Vec<String>) {
+    if node.kind() == "identifier" && is_go_definition(node) {
+        out.push(source[node.byte_range()].to_string());
+    }
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        go_definitions(child, source, out);
+    }
+}
+
+/// Reports whether a Go identifier names something rather than using it: a
+/// parameter, a variable declared with `var`, `const` or `:=`, or the key of
+/// a struct literal.
+fn is_go_definition(node: Node) -> bool {
+    let Some(parent) = node.parent() else {
+        return false;
+    };
+    match parent.kind() {
+        "parameter_declaration" | "variadic_parameter_declaration" | "var_spec" | "const_spec" => {
+            true
+        }
+        "expression_list" => parent.parent().is_some_and(|p| {
+            matches!(p.kind(), "short_var_declaration" | "range_clause")
+                && p.child_by_field_name("left") == Some(parent)
+        }),
+        "literal_element" => parent.parent().is_some_and(|p| {
+            p.kind() == "keyed_element" && p.child_by_field_name("key") == Some(parent)
+        }),
+        _ => false,
+    }
+}
+
+/// Returns the id of the innermost scope containing `node`, or of the root
+/// node when it is at the top level.
+fn enclosing_scope(node: Node, scopes: &HashSet<usize>) -> usize {
+    let mut current = node;
+    while let Some(parent) = current.parent() {
+        if scopes.contains(&parent.id()) {
+            return parent.id();
+        }
+        current = parent;
+    }
+    current.id()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolve(code: &str) -> Vec<(String, (usize, usize))> {
+        let doc = crate::go::parse_with_go(code);
+        assert!(!doc.tree().root_node().has_error(), "{code}");
+        resolve_references(&doc, code)
+            .into_iter()
+            .map(|r| {
+                let def = r.definition.start_position();
+                (code[r.node.byte_range()].to_string(), (def.row, def.column))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_resolves_locals() {
+        let code = r#"templ Counter(items []string, n int) {
+	count := tui.NewState(0)
+	header := <span>x</span>
+	for i, item := range items {
+		@Row(i, item, n)
+	}
+	if n > 0 {
+		@Row(count, header, fmt.Sprint(n))
+	}
+}
+"#;
+        let refs = resolve(code);
+        assert_eq!(
+            refs,
+            [
+                ("items".to_string(), (0, 14)),
+                ("i".to_string(), (3, 5)),
+                ("item".to_string(), (3, 8)),
+                ("n".to_string(), (0, 30)),
+                ("n".to_string(), (0, 30)),
+                ("count".to_string(), (1, 1)),
+                ("header".to_string(), (2, 1)),
+                ("n".to_string(), (0, 30)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_scoping() {
+        let code = r#"templ A(x int) {
+	for x := range x {
+		@Row(x)
+	}
+	@Row(x)
+}
+
+templ B() {
+	@Row(x)
+}
+"#;
+        // The loop variable shadows the parameter inside the loop only, and
+        // the parameter isn't visible in B.
+        let refs = resolve(code);
+        assert_eq!(
+            refs,
+            [
+                ("x".to_string(), (0, 8)),
+                ("x".to_string(), (1, 5)),
+                ("x".to_string(), (0, 8)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_go_expressions() {
+        let code = r#"templ List(items []string, header string) {
+	for _, item := range items {
+		<span>{item}</span>
+	}
+	<input value={header} onChange={func(item string) { use(item, header) }}/>
+	<span>{Item{item: header}.Label()}</span>
+}
+"#;
+        // The function literal's own `item` and the struct key aren't
+        // references to the loop variable.
+        let refs = resolve(code);
+        assert_eq!(
+            refs,
+            [
+                ("items".to_string(), (0, 11)),
+                ("item".to_string(), (1, 8)),
+                ("header".to_string(), (0, 27)),
+                ("header".to_string(), (0, 27)),
+                ("header".to_string(), (0, 27)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,1319 @@
+//! The built-in GSX elements and their attributes, and a validator that
+//! checks a tree against them.
+//!
+//! The table mirrors `internal/lsp/schema/schema.go`, which is the source of
+//! truth for the Go language server. [`validate`] reports the same problems
+//! the `tui generate` analyzer does: unknown element tags, unknown attributes
+//! and attribute values of the wrong kind, with "did you mean" hints.
+//!
+//! ```
+//! use tree_sitter_gsx::schema::{self, ValueKind};
+//!
+//! let attr = schema::attribute("div", "flexGrow").unwrap();
+//! assert_eq!(attr.kind, ValueKind::Float);
+//!
+//! let code = "templ A() {\n\t<dvi flexGrow=\"1\" />\n}\n";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&tree_sitter_gsx::language()).unwrap();
+//! let tree = parser.parse(code, None).unwrap();
+//!
+//! let errors = schema::validate(&tree, code);
+//! assert_eq!(errors[0].message, "unknown element tag <dvi>");
+//! assert_eq!(errors[0].hint.as_deref(), Some("did you mean <div>?"));
+//! ```
+
+use tree_sitter::{Node, Tree};
+
+use crate::diagnostics::Diagnostic;
+use crate::format::{parse_number, Number};
+
+/// The kind of value an attribute accepts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ValueKind {
+    String,
+    Int,
+    Float,
+    Bool,
+    /// Any Go expression, written as `{...}`.
+    Expression,
+    Direction,
+    Justify,
+    Align,
+    Border,
+    Color,
+    Style,
+    Func,
+}
+
+impl ValueKind {
+    /// Returns the type name used in `schema.go`, e.g. `"float"`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::String => "string",
+            Self::Int => "int",
+            Self::Float => "float",
+            Self::Bool => "bool",
+            Self::Expression => "expression",
+            Self::Direction => "direction",
+            Self::Justify => "justify",
+            Self::Align => "align",
+            Self::Border => "border",
+            Self::Color => "color",
+            Self::Style => "style",
+            Self::Func => "func",
+        }
+    }
+
+    fn describe(self) -> &'static str {
+        match self {
+            Self::String => "a string",
+            Self::Int => "an int",
+            Self::Float => "a float",
+            Self::Bool => "a bool",
+            Self::Expression => "a Go expression",
+            Self::Direction => "a tui.Direction expression",
+            Self::Justify => "a tui.Justify expression",
+            Self::Align => "a tui.Align expression",
+            Self::Border => "a tui.BorderStyle expression",
+            Self::Color => "a tui.Color expression",
+            Self::Style => "a tui.Style expression",
+            Self::Func => "a func expression",
+        }
+    }
+}
+
+/// How an element is grouped in completion lists and docs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ElementCategory {
+    Container,
+    Text,
+    Input,
+    Display,
+}
+
+/// How an attribute is grouped in completion lists and docs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AttributeCategory {
+    Layout,
+    Visual,
+    Event,
+    Ref,
+    Spacing,
+    Flex,
+    Text,
+    Scroll,
+    Generic,
+}
+
+/// A built-in GSX element.
+#[derive(Debug)]
+pub struct ElementDef {
+    pub tag: &'static str,
+    pub description: &'static str,
+    /// Void elements can't have children.
+    pub self_closing: bool,
+    pub category: ElementCategory,
+    groups: &'static [&'static [AttributeDef]],
+}
+
+impl ElementDef {
+    /// Returns the attributes this element accepts.
+    pub fn attributes(&self) -> impl Iterator<Item = &'static AttributeDef> + Clone {
+        let groups = self.groups;
+        groups.iter().flat_map(|group| group.iter())
+    }
+
+    /// Returns the definition of the attribute `name`, if this element
+    /// accepts it.
+    pub fn attribute(&self, name: &str) -> Option<&'static AttributeDef> {
+        self.attributes().find(|attr| attr.name == name)
+    }
+}
+
+/// An attribute of a built-in element.
+#[derive(Debug)]
+pub struct AttributeDef {
+    pub name: &'static str,
+    pub kind: ValueKind,
+    pub description: &'static str,
+    pub category: AttributeCategory,
+}
+
+/// An event handler attribute.
+#[derive(Debug)]
+pub struct EventHandlerDef {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// The handler signature the attribute expects.
+    pub signature: &'static str,
+}
+
+/// Returns the definition for `tag`, or `None` if it isn't a built-in element.
+pub fn element(tag: &str) -> Option<&'static ElementDef> {
+    ELEMENTS.iter().find(|elem| elem.tag == tag)
+}
+
+/// Returns the definition of attribute `name` on `tag`.
+pub fn attribute(tag: &str, name: &str) -> Option<&'static AttributeDef> {
+    element(tag)?.attribute(name)
+}
+
+/// Returns the event handler definition for `name`.
+pub fn event_handler(name: &str) -> Option<&'static EventHandlerDef> {
+    EVENT_HANDLERS.iter().find(|handler| handler.name == name)
+}
+
+/// Reports whether `tag` is a void element that can't have children.
+pub fn is_void_element(tag: &str) -> bool {
+    element(tag).is_some_and(|elem| elem.self_closing)
+}
+
+/// Checks every element in `tree` against the schema and returns the
+/// problems found, in source order.
+pub fn validate(tree: &Tree, source: &str) -> Vec<Diagnostic> {
+    let mut out = Vec::new();
+    validate_node(tree.root_node(), source, &mut out);
+    out
+}
+
+fn validate_node(node: Node, source: &str, out: &mut Vec<Diagnostic>) {
+    if matches!(
+        node.kind(),
+        "self_closing_element" | "element_with_children"
+    ) {
+        validate_element(node, source, out);
+    }
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        validate_node(child, source, out);
+    }
+}
+
+fn validate_element(node: Node, source: &str, out: &mut Vec<Diagnostic>) {
+    let Some(tag_node) = node.child_by_field_name("tag") else {
+        return;
+    };
+    let tag = &source[tag_node.byte_range()];
+    let Some(elem) = element(tag) else {
+        out.push(Diagnostic {
+            range: tag_node.range(),
+            message: format!("unknown element tag <{tag}>"),
+            hint: suggest(tag, ELEMENTS.iter().map(|e| e.tag))
+                .map(|similar| format!("did you mean <{similar}>?")),
+            related: Vec::new(),
+        });
+        return;
+    };
+
+    let mut cursor = node.walk();
+    let mut has_children = false;
+    for child in node.named_children(&mut cursor) {
+        match child.kind() {
+            "attribute" => validate_attribute(elem, child, source, out),
+            "identifier" | "comment" => {}
+            _ => has_children = true,
+        }
+    }
+    if elem.self_closing && has_children {
+        out.push(Diagnostic {
+            range: tag_node.range(),
+            message: format!("<{tag}> is a void element and cannot have children"),
+            hint: None,
+            related: Vec::new(),
+        });
+    }
+}
+
+fn validate_attribute(elem: &ElementDef, node: Node, source: &str, out: &mut Vec<Diagnostic>) {
+    let Some(name_node) = node.child_by_field_name("name") else {
+        return;
+    };
+    let name = &source[name_node.byte_range()];
+    let Some(def) = elem.attribute(name) else {
+        let hint = match suggest(name, elem.attributes().map(|a| a.name)) {
+            Some(similar) => Some(format!("did you mean {similar}?")),
+            None if ELEMENTS.iter().any(|e| e.attribute(name).is_some()) => {
+                Some(format!("{name} is not supported on <{}>", elem.tag))
+            }
+            None => None,
+        };
+        out.push(Diagnostic {
+            range: name_node.range(),
+            message: format!("unknown attribute {name}"),
+            hint,
+            related: Vec::new(),
+        });
+        return;
+    };
+
+    let value = node.child_by_field_name("value");
+    let found = match value.map(|v| (v.kind(), &source[v.byte_range()])) {
+        // A Go expression can produce any kind; the Go compiler checks it.
+        Some(("go_expression", _)) => return,
+        Some(("string" | "raw_string", _)) if def.kind == ValueKind::String => return,
+        Some(("number", text)) => match (parse_number(text), def.kind) {
+            // tuigen doesn't read Go-only literals like `0x1F`, so they are
+            // left to the Go compiler as well.
+            (Number::Go, _) => return,
+            (Number::Int(_), ValueKind::Int | ValueKind::Float) => return,
+            (Number::Float(_), ValueKind::Float) => return,
+            (Number::Float(_), _) => "a float literal",
+            (Number::Int(_), _) => "an int literal",
+        },
+        None if def.kind == ValueKind::Bool => return,
+        Some(("string" | "raw_string", _)) => "a string literal",
+        Some(_) => return,
+        None => "no value",
+    };
+
+    let hint = match def.kind {
+        ValueKind::String => Some(format!("use {name}=\"...\"")),
+        ValueKind::Int | ValueKind::Float => None,
+        ValueKind::Bool => Some(format!("write {name} on its own or use {name}={{...}}")),
+        _ => Some(format!(
+            "use {name}={{...}} with a Go expression, not a literal"
+        )),
+    };
+    out.push(Diagnostic {
+        range: value.unwrap_or(node).range(),
+        message: format!(
+            "attribute {name} expects {}, got {found}",
+            def.kind.describe()
+        ),
+        hint,
+        related: Vec::new(),
+    });
+}
+
+/// Misspellings that edit distance alone doesn't catch, from the Go
+/// analyzer's `attributeSimilar` table. There is no `color` attribute; people
+/// who write it usually want `background`.
+const SIMILAR: &[(&str, &str)] = &[("colour", "background"), ("color", "background")];
+
+/// Returns the candidate closest to `name`, if any is close enough to be a
+/// likely typo.
+pub(crate) fn suggest<'a>(
+    name: &str,
+    candidates: impl Iterator<Item = &'a str> + Clone,
+) -> Option<&'a str> {
+    let lower = name.to_lowercase();
+    if let Some(exact) = candidates.clone().find(|c| c.to_lowercase() == lower) {
+        return Some(exact);
+    }
+    if let Some((_, similar)) = SIMILAR.iter().find(|(from, _)| *from == lower) {
+        if let Some(found) = candidates.clone().find(|c| c == similar) {
+            return Some(found);
+        }
+    }
+    let max = if name.chars().count() <= 4 { 1 } else { 2 };
+    candidates
+        .map(|c| (edit_distance(&lower, &c.to_lowercase()), c))
+        .filter(|(distance, _)| *distance <= max)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, c)| c)
+}
+
+/// Returns the edit distance between `a` and `b`, counted in chars. Swapping
+/// two adjacent chars counts as one edit, so `dvi` is one edit from `div`.
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    rows[0] = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut best = (rows[i - 1][j - 1] + cost)
+                .min(rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                best = best.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = best;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+/// The built-in elements, in the order `schema.go` lists them.
+pub static ELEMENTS: &[ElementDef] = &[
+    ElementDef {
+        tag: "div",
+        description: "A block container with flexbox layout. The primary building block for layouts.",
+        groups: CONTAINER_ATTRS,
+        self_closing: false,
+        category: ElementCategory::Container,
+    },
+    ElementDef {
+        tag: "span",
+        description: "An inline text container for styling text content.",
+        groups: TEXT_ELEMENT_ATTRS,
+        self_closing: false,
+        category: ElementCategory::Text,
+    },
+    ElementDef {
+        tag: "p",
+        description: "A paragraph element for text blocks.",
+        groups: TEXT_ELEMENT_ATTRS,
+        self_closing: false,
+        category: ElementCategory::Text,
+    },
+    ElementDef {
+        tag: "ul",
+        description: "An unordered list container. Use with `<li>` children.",
+        groups: CONTAINER_ATTRS,
+        self_closing: false,
+        category: ElementCategory::Container,
+    },
+    ElementDef {
+        tag: "li",
+        description: "A list item. Should be a child of `<ul>`.",
+        groups: CONTAINER_ATTRS,
+        self_closing: false,
+        category: ElementCategory::Container,
+    },
+    ElementDef {
+        tag: "button",
+        description: "A clickable button element that can receive focus and handle events.",
+        groups: BUTTON_ATTRS,
+        self_closing: false,
+        category: ElementCategory::Input,
+    },
+    ElementDef {
+        tag: "input",
+        description: "A single-line text input with cursor management, placeholder support, and submit handling.",
+        groups: &[INPUT_ATTRS],
+        self_closing: true,
+        category: ElementCategory::Input,
+    },
+    ElementDef {
+        tag: "textarea",
+        description: "A multi-line text input with word wrapping, cursor management, and submit handling.",
+        groups: &[TEXTAREA_ATTRS],
+        self_closing: true,
+        category: ElementCategory::Input,
+    },
+    ElementDef {
+        tag: "table",
+        description: "A table container for tabular data.",
+        groups: CONTAINER_ATTRS,
+        self_closing: false,
+        category: ElementCategory::Display,
+    },
+    ElementDef {
+        tag: "tr",
+        description: "A table row. Must be a child of `<table>`. Contains `<td>` or `<th>` children.",
+        groups: CONTAINER_ATTRS,
+        self_closing: false,
+        category: ElementCategory::Display,
+    },
+    ElementDef {
+        tag: "td",
+        description: "A table data cell. Must be a child of `<tr>`.",
+        groups: TABLE_CELL_ATTRS,
+        self_closing: false,
+        category: ElementCategory::Display,
+    },
+    ElementDef {
+        tag: "th",
+        description: "A table header cell. Renders bold by default. Must be a child of `<tr>`.",
+        groups: TABLE_CELL_ATTRS,
+        self_closing: false,
+        category: ElementCategory::Display,
+    },
+    ElementDef {
+        tag: "progress",
+        description: "A progress bar element showing completion status.",
+        groups: &[PROGRESS_ATTRS],
+        self_closing: true,
+        category: ElementCategory::Display,
+    },
+    ElementDef {
+        tag: "hr",
+        description: "A horizontal dividing line.",
+        groups: &[DIVIDER_ATTRS],
+        self_closing: true,
+        category: ElementCategory::Display,
+    },
+    ElementDef {
+        tag: "br",
+        description: "An empty line break.",
+        groups: &[DIVIDER_ATTRS],
+        self_closing: true,
+        category: ElementCategory::Display,
+    },
+    ElementDef {
+        tag: "modal",
+        description: "A modal overlay that renders on top of all other content. Supports backdrop dimming, focus trapping, and close-on-escape.",
+        groups: &[MODAL_ATTRS],
+        self_closing: false,
+        category: ElementCategory::Container,
+    },
+    ElementDef {
+        tag: "markdown",
+        description: "Renders a markdown string into the widget tree (headings, bold/italic, inline code, fenced code blocks, tables, lists, blockquotes, links). A pure content renderer: wrap it in a scrollable container to scroll long documents.",
+        groups: &[MARKDOWN_ATTRS],
+        self_closing: true,
+        category: ElementCategory::Display,
+    },
+];
+
+/// The event handler attributes.
+pub static EVENT_HANDLERS: &[EventHandlerDef] = &[
+    EventHandlerDef {
+        name: "onFocus",
+        description: "Called when the element gains focus.",
+        signature: "func()",
+    },
+    EventHandlerDef {
+        name: "onBlur",
+        description: "Called when the element loses focus.",
+        signature: "func()",
+    },
+];
+
+// Attribute sets, composed the same way as the helpers in schema.go.
+
+const CONTAINER_ATTRS: &[&[AttributeDef]] = &[
+    GENERIC_ATTRS,
+    LAYOUT_ATTRS,
+    FLEX_ATTRS,
+    SPACING_ATTRS,
+    VISUAL_ATTRS,
+    EVENT_ATTRS,
+    SCROLL_ATTRS,
+];
+
+const TABLE_CELL_ATTRS: &[&[AttributeDef]] = &[
+    GENERIC_ATTRS,
+    TEXT_ATTRS,
+    LAYOUT_ATTRS,
+    SPACING_ATTRS,
+    VISUAL_ATTRS,
+    EVENT_ATTRS,
+];
+
+const TEXT_ELEMENT_ATTRS: &[&[AttributeDef]] = TABLE_CELL_ATTRS;
+
+const BUTTON_ATTRS: &[&[AttributeDef]] = TABLE_CELL_ATTRS;
+
+const DIVIDER_ATTRS: &[AttributeDef] = &[
+    AttributeDef {
+        name: "id",
+        kind: ValueKind::String,
+        description: "Unique identifier for the element",
+        category: AttributeCategory::Generic,
+    },
+    AttributeDef {
+        name: "class",
+        kind: ValueKind::String,
+        description: "Tailwind-style CSS classes",
+        category: AttributeCategory::Generic,
+    },
+];
+
+const GENERIC_ATTRS: &[AttributeDef] = &[
+    AttributeDef {
+        name: "id",
+        kind: ValueKind::String,
+        description: "Unique identifier for the element",
+        category: AttributeCategory::Generic,
+    },
+    AttributeDef {
+        name: "class",
+        kind: ValueKind::String,
+        description: "Tailwind-style CSS classes",
+        category: AttributeCategory::Generic,
+    },
+    AttributeDef {
+        name: "disabled",
+        kind: ValueKind::Bool,
+        description: "Whether the element is disabled",
+        category: AttributeCategory::Generic,
+    },
+    AttributeDef {
+        name: "deps",
+        kind: ValueKind::Expression,
+        description: "Explicit state dependencies for reactive bindings",
+        category: AttributeCategory::Generic,
+    },
+    AttributeDef {
+        name: "ref",
+        kind: ValueKind::Expression,
+        description: "Bind this element to a ref variable (tui.NewRef/NewRefList/NewRefMap)",
+        category: AttributeCategory::Ref,
+    },
+    AttributeDef {
+        name: "key",
+        kind: ValueKind::Expression,
+        description: "Stable identity for a loop item, unique among siblings of the innermost loop (like React keys): used as the mount cache key for component elements and as the RefMap key when combined with ref; on a container element it keys descendant component mounts",
+        category: AttributeCategory::Generic,
+    },
+];
+
+const LAYOUT_ATTRS: &[AttributeDef] = &[
+    AttributeDef {
+        name: "width",
+        kind: ValueKind::Int,
+        description: "Fixed width in characters",
+        category: AttributeCategory::Layout,
+    },
+    AttributeDef {
+        name: "widthPercent",
+        kind: ValueKind::Int,
+        description: "Width as percentage of parent",
+        category: AttributeCategory::Layout,
+    },
+    AttributeDef {
+        name: "height",
+        kind: ValueKind::Int,
+        description: "Fixed height in rows",
+        category: AttributeCategory::Layout,
+    },
+    AttributeDef {
+        name: "heightPercent",
+        kind: ValueKind::Int,
+        description: "Height as percentage of parent",
+        category: AttributeCategory::Layout,
+    },
+    AttributeDef {
+        name: "minWidth",
+        kind: ValueKind::Int,
+        description: "Minimum width",
+        category: AttributeCategory::Layout,
+    },
+    AttributeDef {
+        name: "minHeight",
+        kind: ValueKind::Int,
+        description: "Minimum height",
+        category: AttributeCategory::Layout,
+    },
+    AttributeDef {
+        name: "maxWidth",
+        kind: ValueKind::Int,
+        description: "Maximum width",
+        category: AttributeCategory::Layout,
+    },
+    AttributeDef {
+        name: "maxHeight",
+        kind: ValueKind::Int,
+        description: "Maximum height",
+        category: AttributeCategory::Layout,
+    },
+];
+
+const FLEX_ATTRS: &[AttributeDef] = &[
+    AttributeDef {
+        name: "display",
+        kind: ValueKind::String,
+        description: "Display mode (block, flex)",
+        category: AttributeCategory::Flex,
+    },
+    AttributeDef {
+        name: "direction",
+        kind: ValueKind::Direction,
+        description: "Flex direction (row, column)",
+        category: AttributeCategory::Flex,
+    },
+    AttributeDef {
+        name: "justify",
+        kind: ValueKind::Justify,
+        description: "Justify content (start, center, end, between, around)",
+        category: AttributeCategory::Flex,
+    },
+    AttributeDef {
+        name: "align",
+        kind: ValueKind::Align,
+        description: "Align items (start, center, end, stretch)",
+        category: AttributeCategory::Flex,
+    },
+    AttributeDef {
+        name: "gap",
+        kind: ValueKind::Int,
+        description: "Gap between children",
+        category: AttributeCategory::Flex,
+    },
+    AttributeDef {
+        name: "flexGrow",
+        kind: ValueKind::Float,
+        description: "Flex grow factor",
+        category: AttributeCategory::Flex,
+    },
+    AttributeDef {
+        name: "flexShrink",
+        kind: ValueKind::Float,
+        description: "Flex shrink factor",
+        category: AttributeCategory::Flex,
+    },
+    AttributeDef {
+        name: "alignSelf",
+        kind: ValueKind::Align,
+        description: "Override parent's align for this item",
+        category: AttributeCategory::Flex,
+    },
+];
+
+const SPACING_ATTRS: &[AttributeDef] = &[
+    AttributeDef {
+        name: "padding",
+        kind: ValueKind::Int,
+        description: "Padding on all sides",
+        category: AttributeCategory::Spacing,
+    },
+    AttributeDef {
+        name: "margin",
+        kind: ValueKind::Int,
+        description: "Margin on all sides",
+        category: AttributeCategory::Spacing,
+    },
+];
+
+const VISUAL_ATTRS: &[AttributeDef] = &[
+    AttributeDef {
+        name: "border",
+        kind: ValueKind::Border,
+        description: "Border style (none, single, double, rounded, thick)",
+        category: AttributeCategory::Visual,
+    },
+    AttributeDef {
+        name: "borderStyle",
+        kind: ValueKind::String,
+        description: "Border style name",
+        category: AttributeCategory::Visual,
+    },
+    AttributeDef {
+        name: "background",
+        kind: ValueKind::Color,
+        description: "Background color",
+        category: AttributeCategory::Visual,
+    },
+    AttributeDef {
+        name: "borderTitle",
+        kind: ValueKind::String,
+        description: "Title text drawn in the top border",
+        category: AttributeCategory::Visual,
+    },
+];
+
+const TEXT_ATTRS: &[AttributeDef] = &[
+    AttributeDef {
+        name: "text",
+        kind: ValueKind::String,
+        description: "Text content",
+        category: AttributeCategory::Text,
+    },
+    AttributeDef {
+        name: "textStyle",
+        kind: ValueKind::Style,
+        description: "Text styling",
+        category: AttributeCategory::Text,
+    },
+    AttributeDef {
+        name: "textAlign",
+        kind: ValueKind::String,
+        description: "Text alignment (left, center, right)",
+        category: AttributeCategory::Text,
+    },
+    AttributeDef {
+        name: "wrap",
+        kind: ValueKind::Bool,
+        description: "Enable or disable text wrapping (enabled by default)",
+        category: AttributeCategory::Text,
+    },
+];
+
+const EVENT_ATTRS: &[AttributeDef] = &[
+    AttributeDef {
+        name: "onFocus",
+        kind: ValueKind::Func,
+        description: "Focus gained handler",
+        category: AttributeCategory::Event,
+    },
+    AttributeDef {
+        name: "onBlur",
+        kind: ValueKind::Func,
+        description: "Focus lost handler",
+        category: AttributeCategory::Event,
+    },
+    AttributeDef {
+        name: "onActivate",
+        kind: ValueKind::Func,
+        description: "Called when Enter is pressed while focused: func()",
+        category: AttributeCategory::Event,
+    },
+    AttributeDef {
+        name: "focusable",
+        kind: ValueKind::Bool,
+        description: "Whether the element can receive focus",
+        category: AttributeCategory::Event,
+    },
+    AttributeDef {
+        name: "autoFocus",
+        kind: ValueKind::Bool,
+        description: "Automatically focus this element on startup",
+        category: AttributeCategory::Event,
+    },
+];
+
+const SCROLL_ATTRS: &[AttributeDef] = &[
+    AttributeDef {
+        name: "scrollable",
+        kind: ValueKind::Bool,
+        description: "Enable scrolling for overflow content",
+        category: AttributeCategory::Scroll,
+    },
+    AttributeDef {
+        name: "scrollbarStyle",
+        kind: ValueKind::Style,
+        description: "Style for the scrollbar track",
+        category: AttributeCategory::Scroll,
+    },
+    AttributeDef {
+        name: "scrollbarThumbStyle",
+        kind: ValueKind::Style,
+        description: "Style for the scrollbar thumb",
+        category: AttributeCategory::Scroll,
+    },
+    AttributeDef {
+        name: "hideScrollbar",
+        kind: ValueKind::Bool,
+        description: "Hide the scrollbar and reclaim its gutter width",
+        category: AttributeCategory::Scroll,
+    },
+];
+
+const INPUT_ATTRS: &[AttributeDef] = &[
+    AttributeDef {
+        name: "id",
+        kind: ValueKind::String,
+        description: "Unique identifier for the element",
+        category: AttributeCategory::Generic,
+    },
+    AttributeDef {
+        name: "class",
+        kind: ValueKind::String,
+        description: "Tailwind-style CSS classes",
+        category: AttributeCategory::Generic,
+    },
+    AttributeDef {
+        name: "value",
+        kind: ValueKind::Expression,
+        description: "Bind to a *State[string] for reactive two-way text binding",
+        category: AttributeCategory::Generic,
+    },
+    AttributeDef {
+        name: "placeholder",
+        kind: ValueKind::String,
+        description: "Placeholder text shown when empty and unfocused",
+        category: AttributeCategory::Generic,
+    },
+    AttributeDef {
+        name: "placeholderStyle",
+        kind: ValueKind::Style,
+        description: "Placeholder text styling (default: dim)",
+        category: AttributeCategory::Visual,
+    },
+    AttributeDef {
+        name: "width",
+        kind: ValueKind::Int,
+        description: "Input width in characters (default 20)",
+        category: AttributeCategory::Layout,
+    },
+    AttributeDef {
+        name: "border",
+        kind: ValueKind::Border,
+        description: "Border style",
+        category: AttributeCategory::Visual,
+    },
+    AttributeDef {
+        name: "textStyle",
+        kind: ValueKind::Style,
+        description: "Text styling",
+        category: AttributeCategory::Visual,
+    },
+    AttributeDef {
+        name: "cursor",
+        kind: ValueKind::Expression,
+        description: "Cursor rune (default '▌')",
+        category: AttributeCategory::Visual,
+    },
+    AttributeDef {
+        name: "focusColor",
+        kind: ValueKind::Expression,
+        description: "Border color when focused (default tui.Cyan)",
+        category: AttributeCategory::Visual,
+    },
+    AttributeDef {
+        name: "borderGradient",
+        kind: ValueKind::Expression,
+        description: "Border gradient when unfocused (tui.Gradient)",
+        category: AttributeCategory::Visual,
+    },
+    AttributeDef {
+        name: "focusGradient",
+        kind: ValueKind::Expression,
+        description: "Border gradient when focused (tui.Gradient)",
+        category: AttributeCategory::Visual,
+    },
+    AttributeDef {
+        name: "onSubmit",
+        kind: ValueKind::Func,
+        description: "Callback when Enter is pressed: func(string)",
+        category: AttributeCategory::Event,
+    },
+    AttributeDef {
+        name: "onChange",
+        kind: ValueKind::Func,
+        description: "Callback when text changes: func(string)",
+        category: AttributeCategory::Event,
+    },
+    AttributeDef {
+        name: "autoFocus",
+        kind: ValueKind::Bool,
+        description: "Automatically focus this input on startup",
+        category: AttributeCategory::Event,
+    },
+    AttributeDef {
+        name: "ref",
+        kind: ValueKind::Expression,
+        description: "Bind this element to a ref variable",
+        category: AttributeCategory::Ref,
+    },
+    AttributeDef {
+        name: "key",
+        kind: ValueKind::Expression,
+        description: "Stable identity for a loop item, unique among siblings of the innermost loop (like React keys): used as the mount cache key for component elements and as the RefMap key when combined with ref; on a container element it keys descendant component mounts",
+        category: AttributeCategory::Generic,
+    },
+    AttributeDef {
+        name: "deps",
+        kind: ValueKind::Expression,
+        description: "Explicit state dependencies for reactive bindings",
+        category: AttributeCategory::Generic,
+    },
+];
+
+const TEXTAREA_ATTRS: &[AttributeDef] = &[
+    AttributeDef {
+        name: "id",
+        kind: ValueKind::String,
+        description: "Unique identifier for the element",
+        category: AttributeCategory::Generic,
+    },
+    AttributeDef {
+        name: "class",
+        kind: ValueKind::String,
+        description: "Tailwind-style CSS classes",
+        category: AttributeCategory::Generic,
+    },
+    AttributeDef {
+        name: "value",
+        kind: ValueKind::Expression,
+        description: "Bind to a *State[string] for reactive two-way text binding",
+        category: AttributeCategory::Generic,
+    },
+    AttributeDef {
+        name: "placeholder",
+        kind: ValueKind::String,
+        description: "Placeholder text shown when empty and unfocused",
+        category: AttributeCategory::Generic,
+    },
+    AttributeDef {
+        name: "width",
+        kind: ValueKind::Int,
+        description: "Text area width in characters (default 40)",
+        category: AttributeCategory::Layout,
+    },
+    AttributeDef {
+        name: "maxHeight",
+        kind: ValueKind::Int,
+        description: "Maximum height in rows (0 = unlimited)",
+        category: AttributeCategory::Layout,
+    },
+    AttributeDef {
+        name: "border",
+        kind: ValueKind::Border,
+        description: "Border style (none, single, double, rounded, thick)",
+        category: AttributeCategory::Visual,
+    },
+    AttributeDef {
+        name: "textStyle",
+        kind: ValueKind::Style,
+        description: "Text styling",
+        category: AttributeCategory::Visual,
+    },
+    AttributeDef {
+        name: "placeholderStyle",
+        kind: ValueKind::Style,
+        description: "Placeholder text styling (default: dim)",
+        category: AttributeCategory::Visual,
+    },
+    AttributeDef {
+        name: "cursor",
+        kind: ValueKind::Expression,
+        description: "Cursor rune (default '▌')",
+        category: AttributeCategory::Visual,
+    },
+    AttributeDef {
+        name: "focusColor",
+        kind: ValueKind::Expression,
+        description: "Border color when focused (default tui.Cyan)",
+        category: AttributeCategory::Visual,
+    },
+    AttributeDef {
+        name: "borderGradient",
+        kind: ValueKind::Expression,
+        description: "Border gradient when unfocused (tui.Gradient)",
+        category: AttributeCategory::Visual,
+    },
+    AttributeDef {
+        name: "focusGradient",
+        kind: ValueKind::Expression,
+        description: "Border gradient when focused (tui.Gradient)",
+        category: AttributeCategory::Visual,
+    },
+    AttributeDef {
+        name: "submitKey",
+        kind: ValueKind::Expression,
+        description: "Key that triggers submit (default KeyEnter)",
+        category: AttributeCategory::Event,
+    },
+    AttributeDef {
+        name: "onSubmit",
+        kind: ValueKind::Func,
+        description: "Callback when submit key is pressed: func(string)",
+        category: AttributeCategory::Event,
+    },
+    AttributeDef {
+        name: "autoFocus",
+        kind: ValueKind::Bool,
+        description: "Automatically focus this text area on startup",
+        category: AttributeCategory::Event,
+    },
+    AttributeDef {
+        name: "ref",
+        kind: ValueKind::Expression,
+        description: "Bind this element to a ref variable",
+        category: AttributeCategory::Ref,
+    },
+    AttributeDef {
+        name: "key",
+        kind: ValueKind::Expression,
+        description: "Stable identity for a loop item, unique among siblings of the innermost loop (like React keys): used as the mount cache key for component elements and as the RefMap key when combined with ref; on a container element it keys descendant component mounts",
+        category: AttributeCategory::Generic,
+    },
+    AttributeDef {
+        name: "deps",
+        kind: ValueKind::Expression,
+        description: "Explicit state dependencies for reactive bindings",
+        category: AttributeCategory::Generic,
+    },
+];
+
+const MODAL_ATTRS: &[AttributeDef] = &[
+    AttributeDef {
+        name: "id",
+        kind: ValueKind::String,
+        description: "Unique identifier for the element",
+        category: AttributeCategory::Generic,
+    },
+    AttributeDef {
+        name: "class",
+        kind: ValueKind::String,
+        description: "Tailwind-style CSS classes for positioning and styling",
+        category: AttributeCategory::Generic,
+    },
+    AttributeDef {
+        name: "open",
+        kind: ValueKind::Expression,
+        description: "Bind to a *State[bool] to control modal visibility (required)",
+        category: AttributeCategory::Generic,
+    },
+    AttributeDef {
+        name: "backdrop",
+        kind: ValueKind::String,
+        description: "Backdrop style: \"dim\" (default), \"blank\", or \"none\"",
+        category: AttributeCategory::Visual,
+    },
+    AttributeDef {
+        name: "closeOnEscape",
+        kind: ValueKind::Bool,
+        description: "Escape key closes the modal (default true)",
+        category: AttributeCategory::Event,
+    },
+    AttributeDef {
+        name: "closeOnBackdropClick",
+        kind: ValueKind::Bool,
+        description: "Clicking backdrop closes the modal (default true)",
+        category: AttributeCategory::Event,
+    },
+    AttributeDef {
+        name: "trapFocus",
+        kind: ValueKind::Bool,
+        description: "Tab/Shift+Tab restricted to modal children; when true, also blocks unhandled keys from reaching parent components (default true)",
+        category: AttributeCategory::Event,
+    },
+    AttributeDef {
+        name: "keyMap",
+        kind: ValueKind::Expression,
+        description: "Custom KeyMap bindings for the modal; fire before the catch-all when trapFocus is true",
+        category: AttributeCategory::Event,
+    },
+    AttributeDef {
+        name: "ref",
+        kind: ValueKind::Expression,
+        description: "Bind this element to a ref variable",
+        category: AttributeCategory::Ref,
+    },
+    AttributeDef {
+        name: "key",
+        kind: ValueKind::Expression,
+        description: "Stable identity for a loop item, unique among siblings of the innermost loop (like React keys): used as the mount cache key for component elements and as the RefMap key when combined with ref; on a container element it keys descendant component mounts",
+        category: AttributeCategory::Generic,
+    },
+    AttributeDef {
+        name: "deps",
+        kind: ValueKind::Expression,
+        description: "Explicit state dependencies for reactive bindings",
+        category: AttributeCategory::Generic,
+    },
+];
+
+const MARKDOWN_ATTRS: &[AttributeDef] = &[
+    AttributeDef {
+        name: "id",
+        kind: ValueKind::String,
+        description: "Unique identifier for the element",
+        category: AttributeCategory::Generic,
+    },
+    AttributeDef {
+        name: "class",
+        kind: ValueKind::String,
+        description: "Tailwind-style CSS classes",
+        category: AttributeCategory::Generic,
+    },
+    AttributeDef {
+        name: "source",
+        kind: ValueKind::Expression,
+        description: "Static markdown content (string expression)",
+        category: AttributeCategory::Generic,
+    },
+    AttributeDef {
+        name: "state",
+        kind: ValueKind::Expression,
+        description: "Reactive *State[string] markdown source; re-renders on change",
+        category: AttributeCategory::Generic,
+    },
+    AttributeDef {
+        name: "width",
+        kind: ValueKind::Int,
+        description: "Fixed render width in characters (0 = fill available width)",
+        category: AttributeCategory::Layout,
+    },
+    AttributeDef {
+        name: "theme",
+        kind: ValueKind::Expression,
+        description: "tui.MarkdownTheme overriding the default styling",
+        category: AttributeCategory::Visual,
+    },
+    AttributeDef {
+        name: "ref",
+        kind: ValueKind::Expression,
+        description: "Bind this element to a ref variable",
+        category: AttributeCategory::Ref,
+    },
+    AttributeDef {
+        name: "key",
+        kind: ValueKind::Expression,
+        description: "Stable identity for a loop item, unique among siblings of the innermost loop (like React keys): used as the mount cache key for component elements and as the RefMap key when combined with ref; on a container element it keys descendant component mounts",
+        category: AttributeCategory::Generic,
+    },
+    AttributeDef {
+        name: "deps",
+        kind: ValueKind::Expression,
+        description: "Explicit state dependencies for reactive bindings",
+        category: AttributeCategory::Generic,
+    },
+];
+
+const PROGRESS_ATTRS: &[AttributeDef] = &[
+    AttributeDef {
+        name: "id",
+        kind: ValueKind::String,
+        description: "Unique identifier for the element",
+        category: AttributeCategory::Generic,
+    },
+    AttributeDef {
+        name: "class",
+        kind: ValueKind::String,
+        description: "Tailwind-style CSS classes",
+        category: AttributeCategory::Generic,
+    },
+    AttributeDef {
+        name: "value",
+        kind: ValueKind::Int,
+        description: "Current progress value (0 to max)",
+        category: AttributeCategory::Generic,
+    },
+    AttributeDef {
+        name: "max",
+        kind: ValueKind::Int,
+        description: "Maximum progress value",
+        category: AttributeCategory::Generic,
+    },
+    AttributeDef {
+        name: "width",
+        kind: ValueKind::Int,
+        description: "Progress bar width in characters",
+        category: AttributeCategory::Layout,
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(body: &str) -> Vec<Diagnostic> {
+        let code = format!("templ A() {{\n{body}\n}}\n");
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&crate::language()).unwrap();
+        let tree = parser.parse(&code, None).unwrap();
+        assert!(!tree.root_node().has_error(), "{code}");
+        validate(&tree, &code)
+    }
+
+    fn messages(body: &str) -> Vec<String> {
+        check(body).into_iter().map(|d| d.to_string()).collect()
+    }
+
+    #[test]
+    fn test_element_table() {
+        let tags: Vec<_> = ELEMENTS.iter().map(|e| e.tag).collect();
+        assert_eq!(
+            tags,
+            [
+                "div", "span", "p", "ul", "li", "button", "input", "textarea", "table", "tr", "td",
+                "th", "progress", "hr", "br", "modal", "markdown"
+            ]
+        );
+
+        assert_eq!(attribute("div", "flexGrow").unwrap().kind, ValueKind::Float);
+        assert_eq!(
+            attribute("div", "borderStyle").unwrap().kind,
+            ValueKind::String
+        );
+        assert_eq!(
+            attribute("button", "onActivate").unwrap().kind,
+            ValueKind::Func
+        );
+        assert_eq!(
+            attribute("li", "ref").unwrap().category,
+            AttributeCategory::Ref
+        );
+        assert_eq!(
+            attribute("modal", "key").unwrap().kind,
+            ValueKind::Expression
+        );
+        assert_eq!(attribute("th", "textStyle").unwrap().kind, ValueKind::Style);
+        assert!(attribute("div", "scrollable").is_some());
+        assert!(attribute("span", "scrollable").is_none());
+        assert!(attribute("hr", "width").is_none());
+        assert!(attribute("blink", "id").is_none());
+
+        assert_eq!(element("div").unwrap().attributes().count(), 37);
+        assert!(is_void_element("input"));
+        assert!(is_void_element("markdown"));
+        assert!(!is_void_element("modal"));
+        assert_eq!(event_handler("onBlur").unwrap().signature, "func()");
+        assert_eq!(ValueKind::Direction.as_str(), "direction");
+    }
+
+    #[test]
+    fn test_valid_elements() {
+        let body = "\t<div flexGrow={1.5} width=10 direction={tui.Row} focusable>\n\t\t<span class=\"font-bold\" textStyle={style}>hi</span>\n\t\t<input ref={nameRef} onSubmit={s.submit} />\n\t</div>";
+        assert!(check(body).is_empty());
+    }
+
+    #[test]
+    fn test_unknown_tags() {
+        assert_eq!(
+            messages("\t<dvi />\n\t<widget />"),
+            [
+                "2:3: error: unknown element tag <dvi> (did you mean <div>?)",
+                "3:3: error: unknown element tag <widget>",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unknown_attributes() {
+        assert_eq!(
+            messages("\t<div flexgrow={1} color={tui.Red} placeholder=\"x\" bogus=\"y\" />"),
+            [
+                "2:7: error: unknown attribute flexgrow (did you mean flexGrow?)",
+                "2:20: error: unknown attribute color (did you mean background?)",
+                "2:36: error: unknown attribute placeholder (placeholder is not supported on <div>)",
+                "2:52: error: unknown attribute bogus",
+            ]
+        );
+        assert_eq!(
+            messages("\t<span onActivte={f} />"),
+            ["2:8: error: unknown attribute onActivte (did you mean onActivate?)"]
+        );
+    }
+
+    #[test]
+    fn test_wrong_value_kinds() {
+        assert_eq!(
+            messages(
+                "\t<div flexGrow=\"1\" width=1.5 key=\"row\" id=3 gap scrollable=1 flexShrink=2 />"
+            ),
+            [
+                "2:16: error: attribute flexGrow expects a float, got a string literal",
+                "2:26: error: attribute width expects an int, got a float literal",
+                "2:34: error: attribute key expects a Go expression, got a string literal (use key={...} with a Go expression, not a literal)",
+                "2:43: error: attribute id expects a string, got an int literal (use id=\"...\")",
+                "2:45: error: attribute gap expects an int, got no value",
+                "2:60: error: attribute scrollable expects a bool, got an int literal (write scrollable on its own or use scrollable={...})",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_void_element_children() {
+        assert_eq!(
+            messages("\t<hr>\n\t\t<span />\n\t</hr>\n\t<input></input>"),
+            ["2:3: error: <hr> is a void element and cannot have children"]
+        );
+    }
+
+    #[test]
+    fn test_nested_elements_are_checked() {
+        let body = "\tfor _, x := range xs {\n\t\t<lable />\n\t}\n\trow := <dvi />\n\t@Card() {\n\t\t<p widht=1 />\n\t}";
+        let found: Vec<_> = check(body).into_iter().map(|d| d.message).collect();
+        assert_eq!(
+            found,
+            [
+                "unknown element tag <lable>",
+                "unknown element tag <dvi>",
+                "unknown attribute widht",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_suggest() {
+        let names = ["flexGrow", "flexShrink", "gap"];
+        assert_eq!(suggest("FLEXGROW", names.iter().copied()), Some("flexGrow"));
+        assert_eq!(suggest("flexGorw", names.iter().copied()), Some("flexGrow"));
+        assert_eq!(suggest("gpa", names.iter().copied()), Some("gap"));
+        assert_eq!(suggest("gxy", names.iter().copied()), None);
+        assert_eq!(suggest("zzzzzz", names.iter().copied()), None);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("dvi", "div"), 1);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+}
@@ -0,0 +1,1144 @@
+//! Semantic tokens with the legend and rules of `tui lsp`.
+//!
+//! [`semantic_tokens`] classifies a file with the token types in
+//! [`TOKEN_TYPES`] and the modifiers in [`TOKEN_MODIFIERS`], the legend the Go
+//! server advertises, so an editor colours a file the same whichever server
+//! it talks to. Declarations come from the syntax tree. Go code inside
+//! expressions, function bodies and top-level declarations is classified by
+//! the same lexical scan the Go server uses: component parameters and locals,
+//! calls, builtin types, literals, and `fmt` verbs inside strings, which are
+//! split out as `regexp` tokens.
+//!
+//! Columns and lengths are in bytes. A server converts them to the client's
+//! position encoding and then delta-encodes them with [`encode`].
+//!
+//! ```
+//! use tree_sitter_gsx::semantic::{semantic_tokens, TokenType, DECLARATION};
+//!
+//! let code = "templ Greeting(name string) {\n\t<span>{name}</span>\n}\n";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&tree_sitter_gsx::language()).unwrap();
+//! let tree = parser.parse(code, None).unwrap();
+//!
+//! let tokens = semantic_tokens(&tree, code);
+//! let name = tokens.iter().find(|t| (t.line, t.start) == (1, 8)).unwrap();
+//! assert_eq!(name.token_type, TokenType::Parameter);
+//! assert_eq!(tokens[2].modifiers, DECLARATION);
+//! ```
+
+use std::collections::HashSet;
+
+use tree_sitter::{Node, Point, Tree};
+
+use crate::schema;
+
+/// The token type names, indexed by [`TokenType::index`].
+pub const TOKEN_TYPES: [&str; 16] = [
+    "namespace",
+    "type",
+    "class",
+    "function",
+    "parameter",
+    "variable",
+    "property",
+    "keyword",
+    "string",
+    "number",
+    "operator",
+    "decorator",
+    "regexp",
+    "comment",
+    "label",
+    "typeParameter",
+];
+
+/// The token modifier names, in bit order.
+pub const TOKEN_MODIFIERS: [&str; 5] = [
+    "declaration",
+    "definition",
+    "readonly",
+    "modification",
+    "defaultLibrary",
+];
+
+/// Marks where a name is declared.
+pub const DECLARATION: u32 = 1 << 0;
+/// Marks where a component or function is defined.
+pub const DEFINITION: u32 = 1 << 1;
+/// Marks let bindings and state variables.
+pub const READONLY: u32 = 1 << 2;
+/// Marks where a variable is modified. Part of the legend but never set.
+pub const MODIFICATION: u32 = 1 << 3;
+/// Marks Go's predeclared types such as `int` and `string`.
+pub const DEFAULT_LIBRARY: u32 = 1 << 4;
+
+/// The kind of a [`SemanticToken`], in legend order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TokenType {
+    /// Package names and import aliases.
+    Namespace,
+    Type,
+    /// Component names.
+    Class,
+    /// Functions, calls and attribute names.
+    Function,
+    Parameter,
+    Variable,
+    Property,
+    /// Go keywords, `templ` and element tags.
+    Keyword,
+    String,
+    /// Numbers, `true`, `false` and `nil`.
+    Number,
+    Operator,
+    /// The `@` of component calls and event handler attributes.
+    Decorator,
+    /// Format verbs such as `%d` inside strings.
+    Regexp,
+    Comment,
+    Label,
+    TypeParameter,
+}
+
+impl TokenType {
+    /// Returns the type's index in [`TOKEN_TYPES`].
+    pub fn index(self) -> u32 {
+        self as u32
+    }
+
+    /// Returns the type's LSP name, e.g. `typeParameter`.
+    pub fn as_str(self) -> &'static str {
+        TOKEN_TYPES[self as usize]
+    }
+}
+
+/// A classified span of a single line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SemanticToken {
+    /// The zero-based line.
+    pub line: usize,
+    /// The column of the first byte.
+    pub start: usize,
+    pub length: usize,
+    pub token_type: TokenType,
+    /// A bit set of modifiers such as [`DECLARATION`].
+    pub modifiers: u32,
+}
+
+/// Go's predeclared type names, which get [`DEFAULT_LIBRARY`] as in gopls.
+const BUILTIN_TYPES: &[&str] = &[
+    "bool",
+    "byte",
+    "complex64",
+    "complex128",
+    "error",
+    "float32",
+    "float64",
+    "int",
+    "int8",
+    "int16",
+    "int32",
+    "int64",
+    "rune",
+    "string",
+    "uint",
+    "uint8",
+    "uint16",
+    "uint32",
+    "uint64",
+    "uintptr",
+    "any",
+    "comparable",
+];
+
+/// The Go keywords highlighted inside Go code.
+const GO_KEYWORDS: &[&str] = &[
+    "func",
+    "return",
+    "if",
+    "else",
+    "for",
+    "range",
+    "switch",
+    "case",
+    "default",
+    "defer",
+    "go",
+    "select",
+    "chan",
+    "map",
+    "struct",
+    "interface",
+    "type",
+    "var",
+    "const",
+    "break",
+    "continue",
+    "fallthrough",
+    "goto",
+];
+
+/// Returns the semantic tokens of `tree`, ordered by position. Where two
+/// rules classify the same span, the first one wins, so declarations keep
+/// their modifiers.
+pub fn semantic_tokens(tree: &Tree, source: &str) -> Vec<SemanticToken> {
+    let root = tree.root_node();
+    let mut collector = Collector {
+        source,
+        tokens: Vec::new(),
+    };
+    collector.comments(root);
+    let mut cursor = root.walk();
+    for node in root.named_children(&mut cursor) {
+        match node.kind() {
+            "package_clause" => collector.package(node),
+            "import_section" => collector.imports(node),
+            "component_declaration" => collector.component(node),
+            "function_declaration" => collector.function(node),
+            "type_struct_declaration" | "go_declaration" => collector.declaration(node),
+            _ => {}
+        }
+    }
+
+    let mut tokens = collector.tokens;
+    tokens.sort_by_key(|t| (t.line, t.start));
+    tokens.dedup_by_key(|t| (t.line, t.start));
+    tokens
+}
+
+/// Returns the tokens of [`semantic_tokens`] that start between `start` and
+/// `end`.
+pub fn semantic_tokens_range(
+    tree: &Tree,
+    source: &str,
+    start: Point,
+    end: Point,
+) -> Vec<SemanticToken> {
+    semantic_tokens(tree, source)
+        .into_iter()
+        .filter(|t| {
+            let at = Point::new(t.line, t.start);
+            start <= at && at < end
+        })
+        .collect()
+}
+
+/// Delta-encodes `tokens` as LSP `SemanticTokens` data: five integers per
+/// token holding the line and start relative to the previous token, the
+/// length, the type index and the modifiers. `tokens` must be ordered by
+/// position. Columns are encoded as they are, so convert them to the
+/// client's position encoding first.
+pub fn encode(tokens: &[SemanticToken]) -> Vec<u32> {
+    let mut data = Vec::with_capacity(tokens.len() * 5);
+    let (mut line, mut start) = (0, 0);
+    for token in tokens {
+        let delta_line = token.line - line;
+        let delta_start = if delta_line == 0 {
+            token.start - start
+        } else {
+            token.start
+        };
+        data.extend([
+            delta_line as u32,
+            delta_start as u32,
+            token.length as u32,
+            token.token_type.index(),
+            token.modifiers,
+        ]);
+        (line, start) = (token.line, token.start);
+    }
+    data
+}
+
+struct Collector<'s> {
+    source: &'s str,
+    tokens: Vec<SemanticToken>,
+}
+
+impl<'s> Collector<'s> {
+    fn text(&self, node: Node) -> &'s str {
+        &self.source[node.byte_range()]
+    }
+
+    fn push(&mut self, at: Point, length: usize, token_type: TokenType, modifiers: u32) {
+        if length > 0 {
+            self.tokens.push(SemanticToken {
+                line: at.row,
+                start: at.column,
+                length,
+                token_type,
+                modifiers,
+            });
+        }
+    }
+
+    fn push_node(&mut self, node: Option<Node>, token_type: TokenType, modifiers: u32) {
+        if let Some(node) = node {
+            self.push(
+                node.start_position(),
+                node.end_byte() - node.start_byte(),
+                token_type,
+                modifiers,
+            );
+        }
+    }
+
+    fn keyword(&mut self, node: Option<Node>) {
+        self.push_node(node, TokenType::Keyword, 0);
+    }
+
+    /// Emits every comment in `node`. Block comments get one token per line,
+    /// starting after the indentation of continuation lines.
+    fn comments(&mut self, node: Node) {
+        if node.kind() == "comment" {
+            let at = node.start_position();
+            for (i, line) in self.text(node).split('\n').enumerate() {
+                let line = line.trim_end_matches('\r');
+                if i == 0 {
+                    self.push(at, line.len(), TokenType::Comment, 0);
+                    continue;
+                }
+                let body = line.trim_start_matches([' ', '\t']);
+                let indent = line.len() - body.len();
+                self.push(
+                    Point::new(at.row + i, indent),
+                    body.len(),
+                    TokenType::Comment,
+                    0,
+                );
+            }
+            return;
+        }
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            self.comments(child);
+        }
+    }
+
+    fn package(&mut self, node: Node) {
+        self.keyword(node.child(0));
+        self.push_node(
+            node.child_by_field_name("name"),
+            TokenType::Namespace,
+            DECLARATION,
+        );
+    }
+
+    fn imports(&mut self, node: Node) {
+        let mut specs = Vec::new();
+        crate::format::collect_kind(node, "import_spec", &mut specs);
+        let mut cursor = node.walk();
+        for decl in node.named_children(&mut cursor) {
+            self.keyword(decl.child(0));
+        }
+        for spec in specs {
+            self.push_node(spec.child_by_field_name("alias"), TokenType::Namespace, 0);
+            self.push_node(spec.child_by_field_name("path"), TokenType::String, 0);
+        }
+    }
+
+    fn component(&mut self, node: Node) {
+        self.keyword(node.child(0));
+        if let Some(receiver) = node.child_by_field_name("receiver") {
+            self.receiver(receiver);
+        }
+        self.push_node(
+            node.child_by_field_name("name"),
+            TokenType::Class,
+            DECLARATION | DEFINITION,
+        );
+        if let Some(list) = node.child_by_field_name("type_parameters") {
+            self.type_parameters(list);
+        }
+        // The receiver is left out so that its uses keep the default
+        // colouring while its declaration is highlighted as a parameter.
+        let mut params = HashSet::new();
+        if let Some(list) = node.child_by_field_name("parameters") {
+            self.parameters(list, &mut params);
+        }
+        let mut locals = HashSet::new();
+        if let Some(body) = node.child_by_field_name("body") {
+            self.children(body, &params, &mut locals);
+        }
+    }
+
+    fn function(&mut self, node: Node) {
+        self.keyword(node.child(0));
+        if let Some(receiver) = node.child_by_field_name("receiver") {
+            self.receiver(receiver);
+        }
+        self.push_node(
+            node.child_by_field_name("name"),
+            TokenType::Function,
+            DECLARATION | DEFINITION,
+        );
+        if let Some(list) = node.child_by_field_name("type_parameters") {
+            self.type_parameters(list);
+        }
+        let mut params = HashSet::new();
+        if let Some(list) = node.child_by_field_name("parameters") {
+            self.parameters(list, &mut params);
+        }
+        if let Some(returns) = node.child_by_field_name("return_type") {
+            self.type_tokens(returns);
+        }
+        let Some(code) = node
+            .child_by_field_name("body")
+            .and_then(|body| body.named_child(0))
+            .filter(|code| code.kind() == "go_code_content")
+        else {
+            return;
+        };
+
+        let mut locals = HashSet::new();
+        for (line, at) in lines(self.text(code), code.start_position()) {
+            for (name, offset) in var_declarations(line) {
+                locals.insert(name.to_string());
+                self.push(
+                    Point::new(at.row, at.column + offset),
+                    name.len(),
+                    TokenType::Variable,
+                    DECLARATION,
+                );
+            }
+            self.go_line(line, at, &params, &locals);
+        }
+    }
+
+    /// Emits the keyword of a top-level `type`, `var` or `const` and scans
+    /// the rest as Go code.
+    fn declaration(&mut self, node: Node) {
+        self.keyword(node.child(0));
+        self.go_code(node, &HashSet::new(), &HashSet::new());
+    }
+
+    fn receiver(&mut self, node: Node) {
+        self.push_node(
+            node.child_by_field_name("name"),
+            TokenType::Parameter,
+            DECLARATION,
+        );
+        if let Some(ty) = node.child_by_field_name("type") {
+            self.type_tokens(ty);
+        }
+    }
+
+    fn parameters(&mut self, list: Node, names: &mut HashSet<String>) {
+        let mut cursor = list.walk();
+        for param in list.named_children(&mut cursor) {
+            let mut names_cursor = param.walk();
+            for name in param.children_by_field_name("name", &mut names_cursor) {
+                names.insert(self.text(name).to_string());
+                self.push_node(Some(name), TokenType::Parameter, DECLARATION);
+            }
+            if let Some(ty) = param.child_by_field_name("type") {
+                self.type_tokens(ty);
+            }
+        }
+    }
+
+    fn children(&mut self, node: Node, params: &HashSet<String>, locals: &mut HashSet<String>) {
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            self.child(child, params, locals);
+        }
+    }
+
+    fn child(&mut self, node: Node, params: &HashSet<String>, locals: &mut HashSet<String>) {
+        match node.kind() {
+            "element" => self.children(node, params, locals),
+            "self_closing_element" | "element_with_children" => self.element(node, params, locals),
+            "go_expression" => self.expression(node, params, locals),
+            "children_slot" => {
+                self.keyword(node.child(1));
+                self.push_node(node.child(2), TokenType::Operator, 0);
+            }
+            "for_statement" => self.for_statement(node, params, locals),
+            "if_statement" => self.if_statement(node, params, locals),
+            "switch_statement" => self.switch_statement(node, params, locals),
+            "go_statement" => self.go_code(node, params, locals),
+            "let_binding" => {
+                if node.child(0).is_some_and(|c| c.kind() == "var") {
+                    self.keyword(node.child(0));
+                }
+                if let Some(name) = node.child_by_field_name("name") {
+                    locals.insert(self.text(name).to_string());
+                    self.push_node(Some(name), TokenType::Variable, DECLARATION | READONLY);
+                }
+                if let Some(value) = node.child_by_field_name("value") {
+                    self.child(value, params, locals);
+                }
+            }
+            "state_declaration" => {
+                if let Some(name) = node.child_by_field_name("name") {
+                    let is_state = node
+                        .child_by_field_name("initializer")
+                        .and_then(|call| call.child_by_field_name("function"))
+                        .is_some_and(|callee| self.text(callee) == "tui.NewState");
+                    let modifiers = if is_state {
+                        DECLARATION | READONLY
+                    } else {
+                        DECLARATION
+                    };
+                    locals.insert(self.text(name).to_string());
+                    self.push_node(Some(name), TokenType::Variable, modifiers);
+                }
+                self.go_code(node, params, locals);
+            }
+            "component_call" => {
+                let at = node.start_position();
+                self.push(at, 1, TokenType::Decorator, 0);
+                if let Some(name) = node.child_by_field_name("name") {
+                    let start = node.child_by_field_name("qualifier").unwrap_or(name);
+                    self.push(
+                        start.start_position(),
+                        name.end_byte() - start.start_byte(),
+                        TokenType::Class,
+                        0,
+                    );
+                }
+                if let Some(arguments) = node.child_by_field_name("arguments") {
+                    self.go_code(arguments, params, locals);
+                }
+                if let Some(block) = node.child_by_field_name("children") {
+                    self.children(block, params, locals);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn element(&mut self, node: Node, params: &HashSet<String>, locals: &mut HashSet<String>) {
+        self.keyword(node.child_by_field_name("tag"));
+        let mut cursor = node.walk();
+        for attribute in node.named_children(&mut cursor) {
+            if attribute.kind() != "attribute" {
+                continue;
+            }
+            let Some(name) = attribute.child_by_field_name("name") else {
+                continue;
+            };
+            let value = attribute
+                .child_by_field_name("value")
+                .filter(|value| value.kind() == "go_expression")
+                .and_then(|value| value.named_child(0));
+            let name_text = self.text(name);
+            if name_text == "ref" {
+                self.push_node(Some(name), TokenType::Function, 0);
+                if let Some(content) = value {
+                    let (at, length) = trimmed(self.text(content), content.start_position());
+                    self.push(at, length, TokenType::Variable, DECLARATION);
+                }
+                continue;
+            }
+            let token_type = if schema::event_handler(name_text).is_some() {
+                TokenType::Decorator
+            } else {
+                TokenType::Function
+            };
+            self.push_node(Some(name), token_type, 0);
+            if let Some(content) = value {
+                self.go_code(content, params, locals);
+            }
+        }
+        self.children(node, params, locals);
+    }
+
+    /// Scans a `{...}` expression, or emits the keyword and operator of a
+    /// `{children...}` slot, which parses as an expression.
+    fn expression(&mut self, node: Node, params: &HashSet<String>, locals: &HashSet<String>) {
+        let Some(content) = node.named_child(0) else {
+            return;
+        };
+        if self.text(content).trim() == "children..." {
+            let (at, _) = trimmed(self.text(content), content.start_position());
+            self.push(at, "children".len(), TokenType::Keyword, 0);
+            let dots = Point::new(at.row, at.column + "children".len());
+            self.push(dots, "...".len(), TokenType::Operator, 0);
+            return;
+        }
+        self.go_code(content, params, locals);
+    }
+
+    fn for_statement(&mut self, node: Node, params: &HashSet<String>, locals: &HashSet<String>) {
+        self.keyword(node.child(0));
+        let mut scope = locals.clone();
+        if let Some(clause) = node.child_by_field_name("clause") {
+            let index = clause
+                .child_by_field_name("index")
+                .filter(|index| self.text(*index) != "_");
+            for var in [index, clause.child_by_field_name("value")]
+                .into_iter()
+                .flatten()
+            {
+                scope.insert(self.text(var).to_string());
+                self.push_node(Some(var), TokenType::Variable, DECLARATION);
+            }
+            if let Some(collection) = clause.child_by_field_name("collection") {
+                self.go_code(collection, params, &scope);
+            }
+        }
+        if let Some(body) = node.child_by_field_name("body") {
+            self.children(body, params, &mut scope);
+        }
+    }
+
+    fn if_statement(&mut self, node: Node, params: &HashSet<String>, locals: &mut HashSet<String>) {
+        self.keyword(node.child(0));
+        if let Some(condition) = node.child_by_field_name("condition") {
+            self.go_code(condition, params, locals);
+        }
+        if let Some(block) = node.child_by_field_name("consequence") {
+            self.children(block, params, locals);
+        }
+        let mut cursor = node.walk();
+        let keyword = node.children(&mut cursor).find(|c| c.kind() == "else");
+        self.keyword(keyword);
+        if let Some(alternative) = node.child_by_field_name("alternative") {
+            match alternative.kind() {
+                "block" => self.children(alternative, params, locals),
+                _ => self.child(alternative, params, locals),
+            }
+        }
+    }
+
+    /// Emits a switch and its clauses. The Go server has no switch node; the
+    /// tag and case values are scanned like any other Go expression.
+    fn switch_statement(
+        &mut self,
+        node: Node,
+        params: &HashSet<String>,
+        locals: &mut HashSet<String>,
+    ) {
+        self.keyword(node.child(0));
+        if let Some(value) = node.child_by_field_name("value") {
+            self.go_code(value, params, locals);
+        }
+        let mut cursor = node.walk();
+        for clause in node.named_children(&mut cursor) {
+            if !matches!(clause.kind(), "case_clause" | "default_clause") {
+                continue;
+            }
+            self.keyword(clause.child(0));
+            let mut values = clause.walk();
+            for value in clause.children_by_field_name("value", &mut values) {
+                self.go_code(value, params, locals);
+            }
+            self.children(clause, params, locals);
+        }
+    }
+
+    fn go_code(&mut self, node: Node, params: &HashSet<String>, locals: &HashSet<String>) {
+        for (line, at) in lines(self.text(node), node.start_position()) {
+            self.go_line(line, at, params, locals);
+        }
+    }
+
+    /// Classifies one line of Go code starting at `at`.
+    fn go_line(
+        &mut self,
+        code: &str,
+        at: Point,
+        params: &HashSet<String>,
+        locals: &HashSet<String>,
+    ) {
+        let b = code.as_bytes();
+        let point = |i: usize| Point::new(at.row, at.column + i);
+        let mut brackets = 0usize;
+        let mut i = 0;
+        while i < b.len() {
+            let ch = b[i];
+            match ch {
+                b'[' => {
+                    brackets += 1;
+                    i += 1;
+                }
+                b']' => {
+                    brackets = brackets.saturating_sub(1);
+                    i += 1;
+                }
+                b'/' if b.get(i + 1) == Some(&b'*') => {
+                    let start = i;
+                    i = code[i + 2..]
+                        .find("*/")
+                        .map_or(b.len(), |end| i + 2 + end + 2);
+                    self.push(point(start), i - start, TokenType::Comment, 0);
+                }
+                b'/' if b.get(i + 1) == Some(&b'/') => {
+                    self.push(point(i), b.len() - i, TokenType::Comment, 0);
+                    i = b.len();
+                }
+                b'"' | b'`' | b'\'' => {
+                    let start = i;
+                    i += 1;
+                    while i < b.len() && b[i] != ch {
+                        i += if ch != b'`' && b[i] == b'\\' && i + 1 < b.len() {
+                            2
+                        } else {
+                            1
+                        };
+                    }
+                    i = (i + 1).min(b.len());
+                    if ch == b'\'' {
+                        self.push(point(start), i - start, TokenType::String, 0);
+                    } else {
+                        self.string(&code[start..i], point(start));
+                    }
+                }
+                b'0'..=b'9' => {
+                    let start = i;
+                    i = number_end(b, i);
+                    self.push(point(start), i - start, TokenType::Number, 0);
+                }
+                b'.' if b.get(i + 1).is_some_and(u8::is_ascii_digit) => {
+                    let start = i;
+                    i = number_end(b, i);
+                    self.push(point(start), i - start, TokenType::Number, 0);
+                }
+                b':' if b.get(i + 1) == Some(&b'=') => {
+                    self.push(point(i), 2, TokenType::Operator, 0);
+                    i += 2;
+                }
+                _ if is_word_start(ch) => {
+                    let start = i;
+                    while i < b.len() && is_word(b[i]) {
+                        i += 1;
+                    }
+                    let ident = &code[start..i];
+                    let next = b[i..].iter().find(|&&c| c != b' ' && c != b'\t');
+                    let classified = match ident {
+                        "true" | "false" | "nil" => Some((TokenType::Number, 0)),
+                        _ if GO_KEYWORDS.contains(&ident) => Some((TokenType::Keyword, 0)),
+                        _ if params.contains(ident) => Some((TokenType::Parameter, 0)),
+                        _ if locals.contains(ident) => Some((TokenType::Variable, 0)),
+                        // A package name.
+                        _ if next == Some(&b'.') => None,
+                        _ if (start > 0 && b[start - 1] == b'.') || next == Some(&b'(') => {
+                            Some((TokenType::Function, 0))
+                        }
+                        // A type argument or map key type.
+                        _ if brackets > 0 => Some((TokenType::Type, builtin_modifiers(ident))),
+                        _ if BUILTIN_TYPES.contains(&ident) => {
+                            Some((TokenType::Type, DEFAULT_LIBRARY))
+                        }
+                        _ => None,
+                    };
+                    if let Some((token_type, modifiers)) = classified {
+                        self.push(point(start), ident.len(), token_type, modifiers);
+                    }
+                }
+                _ => i += 1,
+            }
+        }
+    }
+
+    /// Emits a string literal, splitting out its format verbs.
+    fn string(&mut self, literal: &str, at: Point) {
+        let b = literal.as_bytes();
+        let point = |i: usize| Point::new(at.row, at.column + i);
+        let mut plain = 0;
+        let mut i = 0;
+        while i < b.len() {
+            let Some(len) = format_verb_len(&b[i..]) else {
+                i += 1;
+                continue;
+            };
+            self.push(point(plain), i - plain, TokenType::String, 0);
+            self.push(point(i), len, TokenType::Regexp, 0);
+            i += len;
+            plain = i;
+        }
+        self.push(point(plain), b.len() - plain, TokenType::String, 0);
+    }
+
+    /// Emits the tokens of a Go type: `*`, brackets and `<-` as operators,
+    /// `func`, `map`, `chan` and `interface` as keywords and type names
+    /// without their package.
+    fn type_tokens(&mut self, node: Node) {
+        for (line, at) in lines(self.text(node), node.start_position()) {
+            let b = line.as_bytes();
+            let point = |i: usize| Point::new(at.row, at.column + i);
+            let mut i = 0;
+            while i < b.len() {
+                match b[i] {
+                    b'*' | b'[' | b']' => {
+                        self.push(point(i), 1, TokenType::Operator, 0);
+                        i += 1;
+                    }
+                    b'<' if b.get(i + 1) == Some(&b'-') => {
+                        self.push(point(i), 2, TokenType::Operator, 0);
+                        i += 2;
+                    }
+                    ch if is_word_start(ch) => {
+                        let start = i;
+                        while i < b.len() && is_word(b[i]) {
+                            i += 1;
+                        }
+                        let ident = &line[start..i];
+                        if b.get(i) == Some(&b'.') {
+                            i += 1;
+                        } else if matches!(ident, "func" | "map" | "chan" | "interface") {
+                            self.push(point(start), ident.len(), TokenType::Keyword, 0);
+                        } else {
+                            let modifiers = builtin_modifiers(ident);
+                            self.push(point(start), ident.len(), TokenType::Type, modifiers);
+                        }
+                    }
+                    _ => i += 1,
+                }
+            }
+        }
+    }
+
+    /// Emits the tokens of a `[T any, K comparable]` list: the first name of
+    /// each comma-separated group as a parameter declaration and the
+    /// constraints as types, keywords and `|`, `~` and `*` operators.
+    fn type_parameters(&mut self, node: Node) {
+        let mut expect_name = true;
+        for (line, at) in lines(self.text(node), node.start_position()) {
+            let b = line.as_bytes();
+            let point = |i: usize| Point::new(at.row, at.column + i);
+            let mut i = 0;
+            while i < b.len() {
+                match b[i] {
+                    b',' => {
+                        expect_name = true;
+                        i += 1;
+                    }
+                    b'|' | b'~' | b'*' => {
+                        self.push(point(i), 1, TokenType::Operator, 0);
+                        i += 1;
+                    }
+                    ch if is_word_start(ch) => {
+                        let start = i;
+                        while i < b.len() && is_word(b[i]) {
+                            i += 1;
+                        }
+                        let ident = &line[start..i];
+                        if expect_name {
+                            self.push(point(start), ident.len(), TokenType::Parameter, DECLARATION);
+                            expect_name = false;
+                        } else if matches!(ident, "interface" | "struct" | "func" | "map" | "chan")
+                        {
+                            self.push(point(start), ident.len(), TokenType::Keyword, 0);
+                        } else if b.get(i) == Some(&b'.') {
+                            i += 1;
+                        } else {
+                            let modifiers = builtin_modifiers(ident);
+                            self.push(point(start), ident.len(), TokenType::Type, modifiers);
+                        }
+                    }
+                    _ => i += 1,
+                }
+            }
+        }
+    }
+}
+
+/// Splits `text`, which starts at `at`, into lines paired with their start.
+fn lines(text: &str, at: Point) -> impl Iterator<Item = (&str, Point)> {
+    text.split('\n').enumerate().map(move |(i, line)| {
+        let column = if i == 0 { at.column } else { 0 };
+        (line, Point::new(at.row + i, column))
+    })
+}
+
+/// Returns the start and length of `text` without surrounding whitespace.
+fn trimmed(text: &str, at: Point) -> (Point, usize) {
+    let start = text.len() - text.trim_start().len();
+    let length = text.trim().len();
+    let rows = text[..start].matches('\n').count();
+    let column = match text[..start].rfind('\n') {
+        Some(newline) => start - newline - 1,
+        None => at.column + start,
+    };
+    (Point::new(at.row + rows, column), length)
+}
+
+/// Returns the names declared by a `:=` or `var` statement on one line, with
+/// their byte offsets.
+fn var_declarations(code: &str) -> Vec<(&str, usize)> {
+    if let Some(assign) = code.find(":=").filter(|&i| i > 0) {
+        return names(&code[..assign], 0, str::trim);
+    }
+    if !code.trim_start().starts_with("var ") {
+        return Vec::new();
+    }
+    let start = code.find("var ").unwrap_or_default() + "var ".len();
+    let rest = &code[start..];
+    match rest.find('=').filter(|&i| i > 0) {
+        Some(eq) => names(&rest[..eq], start, |part| {
+            part.split_whitespace().next().unwrap_or_default()
+        }),
+        None => Vec::new(),
+    }
+}
+
+/// Returns the identifiers `name_of` picks from each comma-separated part of
+/// `lhs`, skipping blanks, with their offsets plus `base`.
+fn names(lhs: &str, base: usize, name_of: fn(&str) -> &str) -> Vec<(&str, usize)> {
+    let mut decls = Vec::new();
+    let mut pos = 0;
+    for part in lhs.split(',') {
+        let name = name_of(part);
+        if name != "_" && is_identifier(name) {
+            if let Some(offset) = lhs[pos..].find(name) {
+                let at = pos + offset;
+                decls.push((&lhs[at..at + name.len()], base + at));
+            }
+        }
+        pos += part.len() + 1;
+    }
+    decls
+}
+
+/// Returns the length of the `fmt` verb at the start of `b`, such as `%-8s`
+/// or `%.2f`.
+fn format_verb_len(b: &[u8]) -> Option<usize> {
+    if b.first() != Some(&b'%') {
+        return None;
+    }
+    let mut i = 1;
+    while b.get(i).is_some_and(|c| b"-+# 0".contains(c)) {
+        i += 1;
+    }
+    if b.get(i) == Some(&b'*') {
+        i += 1;
+    } else {
+        while b.get(i).is_some_and(u8::is_ascii_digit) {
+            i += 1;
+        }
+    }
+    if b.get(i) == Some(&b'.') {
+        if b.get(i + 1) == Some(&b'*') {
+            i += 2;
+        } else if b.get(i + 1).is_some_and(u8::is_ascii_digit) {
+            i += 1;
+            while b.get(i).is_some_and(u8::is_ascii_digit) {
+                i += 1;
+            }
+        }
+    }
+    b.get(i)
+        .filter(|c| b"vTtbcdoqxXUeEfFgGsp%".contains(c))
+        .map(|_| i + 1)
+}
+
+/// Returns the end of the number literal starting at `start`.
+fn number_end(b: &[u8], start: usize) -> usize {
+    let radix = match (b[start], b.get(start + 1)) {
+        (b'0', Some(b'x' | b'X')) => 16,
+        (b'0', Some(b'b' | b'B')) => 2,
+        (b'0', Some(b'o' | b'O')) => 8,
+        _ => 10,
+    };
+    let mut i = start;
+    if radix != 10 {
+        i += 2;
+        while b.get(i).is_some_and(|&c| char::from(c).is_digit(radix)) {
+            i += 1;
+        }
+        return i;
+    }
+    while let Some(&c) = b.get(i) {
+        let sign = matches!(c, b'+' | b'-') && i > 0 && matches!(b[i - 1], b'e' | b'E');
+        if !(c.is_ascii_digit() || matches!(c, b'.' | b'e' | b'E') || sign) {
+            break;
+        }
+        i += 1;
+    }
+    i
+}
+
+fn builtin_modifiers(ident: &str) -> u32 {
+    if BUILTIN_TYPES.contains(&ident) {
+        DEFAULT_LIBRARY
+    } else {
+        0
+    }
+}
+
+fn is_word_start(c: u8) -> bool {
+    c.is_ascii_alphabetic() || c == b'_'
+}
+
+fn is_word(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || c == b'_'
+}
+
+fn is_identifier(s: &str) -> bool {
+    s.bytes().next().is_some_and(is_word_start) && s.bytes().all(is_word)
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter::Parser;
+
+    use super::*;
+
+    fn tokens(code: &str) -> Vec<(String, TokenType, u32)> {
+        let mut parser = Parser::new();
+        parser.set_language(&crate::language()).unwrap();
+        let tree = parser.parse(code, None).unwrap();
+        let lines: Vec<_> = code.lines().collect();
+        semantic_tokens(&tree, code)
+            .into_iter()
+            .map(|t| {
+                let text = lines[t.line][t.start..t.start + t.length].to_string();
+                (text, t.token_type, t.modifiers)
+            })
+            .collect()
+    }
+
+    fn tokens_of(tokens: &[(String, TokenType, u32)], text: &str) -> Vec<(TokenType, u32)> {
+        tokens
+            .iter()
+            .filter(|t| t.0 == text)
+            .map(|t| (t.1, t.2))
+            .collect()
+    }
+
+    #[test]
+    fn test_header_and_signature() {
+        let code = "package main\n\nimport (\n\tfoo \"fmt\"\n)\n\ntempl Card(title string, count *tui.State[int]) {\n}\n";
+        let got: Vec<_> = tokens(code)
+            .into_iter()
+            .map(|(text, ty, mods)| (text, ty.as_str(), mods))
+            .collect();
+        let want = [
+            ("package", "keyword", 0),
+            ("main", "namespace", DECLARATION),
+            ("import", "keyword", 0),
+            ("foo", "namespace", 0),
+            ("\"fmt\"", "string", 0),
+            ("templ", "keyword", 0),
+            ("Card", "class", DECLARATION | DEFINITION),
+            ("title", "parameter", DECLARATION),
+            ("string", "type", DEFAULT_LIBRARY),
+            ("count", "parameter", DECLARATION),
+            ("*", "operator", 0),
+            ("State", "type", 0),
+            ("[", "operator", 0),
+            ("int", "type", DEFAULT_LIBRARY),
+            ("]", "operator", 0),
+        ];
+        let want: Vec<_> = want
+            .into_iter()
+            .map(|(text, ty, mods)| (text.to_string(), ty, mods))
+            .collect();
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn test_component_body() {
+        let code = "templ List(items []string) {\n\tcount := tui.NewState(0)\n\t<div onFocus={focus} ref={box}>\n\t\tfor i, item := range items {\n\t\t\t<span>{fmt.Sprintf(\"%d: %-8s\", i, item)}</span>\n\t\t}\n\t\tif count.Get() > 0 {\n\t\t\t<hr />\n\t\t} else {\n\t\t\t@ui.Empty(nil) {\n\t\t\t\t{children...}\n\t\t\t}\n\t\t}\n\t</div>\n\tswitch count.Get() {\n\tcase 1:\n\t\t<hr />\n\t}\n}\n";
+        let tokens = tokens(code);
+        use TokenType::*;
+
+        assert_eq!(
+            tokens_of(&tokens, "count"),
+            [
+                (Variable, DECLARATION | READONLY),
+                (Variable, 0),
+                (Variable, 0),
+            ]
+        );
+        assert_eq!(tokens_of(&tokens, "NewState"), [(Function, 0)]);
+        assert_eq!(tokens_of(&tokens, "tui"), []);
+        assert_eq!(tokens_of(&tokens, "div"), [(Keyword, 0)]);
+        assert_eq!(tokens_of(&tokens, "onFocus"), [(Decorator, 0)]);
+        assert_eq!(tokens_of(&tokens, "focus"), []);
+        assert_eq!(tokens_of(&tokens, "ref"), [(Function, 0)]);
+        assert_eq!(tokens_of(&tokens, "box"), [(Variable, DECLARATION)]);
+        assert_eq!(
+            tokens_of(&tokens, "items"),
+            [(Parameter, DECLARATION), (Parameter, 0)]
+        );
+        assert_eq!(
+            tokens_of(&tokens, "i"),
+            [(Variable, DECLARATION), (Variable, 0)]
+        );
+        assert_eq!(tokens_of(&tokens, "%d"), [(Regexp, 0)]);
+        assert_eq!(tokens_of(&tokens, "%-8s"), [(Regexp, 0)]);
+        assert_eq!(tokens_of(&tokens, ": "), [(String, 0)]);
+        assert_eq!(tokens_of(&tokens, "else"), [(Keyword, 0)]);
+        assert_eq!(tokens_of(&tokens, "@"), [(Decorator, 0)]);
+        assert_eq!(tokens_of(&tokens, "ui.Empty"), [(Class, 0)]);
+        assert_eq!(tokens_of(&tokens, "nil"), [(Number, 0)]);
+        assert_eq!(tokens_of(&tokens, "children"), [(Keyword, 0)]);
+        assert_eq!(tokens_of(&tokens, "switch"), [(Keyword, 0)]);
+        assert_eq!(tokens_of(&tokens, "case"), [(Keyword, 0)]);
+        assert_eq!(tokens_of(&tokens, "..."), [(Operator, 0)]);
+    }
+
+    #[test]
+    fn test_functions_and_declarations() {
+        let code = "func sum[T int | ~float64](xs []T) T {\n\tvar total T = 0\n\tn, _ := 0x1F, 1.5e-3\n\treturn total + n\n}\n\nfunc (c *Counter) Inc() {\n\tc.count++\n}\n\nvar _ tui.Component = (*Counter)(nil)\n";
+        let tokens = tokens(code);
+        use TokenType::*;
+
+        assert_eq!(
+            tokens_of(&tokens, "sum"),
+            [(Function, DECLARATION | DEFINITION)]
+        );
+        assert_eq!(
+            tokens_of(&tokens, "T"),
+            [(Parameter, DECLARATION), (Type, 0), (Type, 0)]
+        );
+        assert_eq!(tokens_of(&tokens, "~"), [(Operator, 0)]);
+        assert_eq!(tokens_of(&tokens, "float64"), [(Type, DEFAULT_LIBRARY)]);
+        assert_eq!(
+            tokens_of(&tokens, "total"),
+            [(Variable, DECLARATION), (Variable, 0)]
+        );
+        assert_eq!(
+            tokens_of(&tokens, "n"),
+            [(Variable, DECLARATION), (Variable, 0)]
+        );
+        assert_eq!(tokens_of(&tokens, "0x1F"), [(Number, 0)]);
+        assert_eq!(tokens_of(&tokens, "1.5e-3"), [(Number, 0)]);
+        assert_eq!(tokens_of(&tokens, "return"), [(Keyword, 0)]);
+        assert_eq!(tokens_of(&tokens, "c"), [(Parameter, DECLARATION)]);
+        assert_eq!(tokens_of(&tokens, "Counter"), [(Type, 0)]);
+        assert_eq!(tokens_of(&tokens, "var"), [(Keyword, 0), (Keyword, 0)]);
+        assert_eq!(tokens_of(&tokens, "nil"), [(Number, 0)]);
+    }
+
+    #[test]
+    fn test_block_comments_split_by_line() {
+        let code = "/* one\n   two */\ntempl A() {\n}\n";
+        let mut parser = Parser::new();
+        parser.set_language(&crate::language()).unwrap();
+        let tree = parser.parse(code, None).unwrap();
+        let comments: Vec<_> = semantic_tokens(&tree, code)
+            .into_iter()
+            .filter(|t| t.token_type == TokenType::Comment)
+            .map(|t| (t.line, t.start, t.length))
+            .collect();
+        assert_eq!(comments, [(0, 0, 6), (1, 3, 6)]);
+    }
+
+    #[test]
+    fn test_encode_and_range() {
+        let code = "templ A(x int) {\n\t<span>{x}</span>\n}\n";
+        let mut parser = Parser::new();
+        parser.set_language(&crate::language()).unwrap();
+        let tree = parser.parse(code, None).unwrap();
+
+        let tokens = semantic_tokens_range(&tree, code, Point::new(1, 0), Point::new(2, 0));
+        assert_eq!(
+            encode(&tokens),
+            [
+                1, 2, 4, 7, 0, // span
+                0, 6, 1, 4, 0, // x
+            ]
+        );
+        assert_eq!(
+            encode(&semantic_tokens(&tree, code))[..10],
+            [
+                0, 0, 5, 7, 0, // templ
+                0, 6, 1, 2, 3, // A
+            ]
+        );
+    }
+}
@@ -0,0 +1,209 @@
+//! Position mappings between `.gsx` files and the Go code generated from
+//! them, in the JSON format of tuigen's `SourceMap`.
+//!
+//! Lines and columns are 0-based. Columns are byte offsets, which is what
+//! the Go toolchain reports in compiler errors and panics.
+//!
+//! With the `serde` feature, [`SourceMap`] implements `Serialize` and
+//! `Deserialize` with the camelCase keys Go's `encoding/json` uses for it.
+//!
+//! ```
+//! use tree_sitter_gsx::sourcemap::{SourceMap, SourceMapping};
+//!
+//! let mut map = SourceMap::new("app.gsx");
+//! map.add_mapping(SourceMapping {
+//!     go_line: 12,
+//!     go_col: 1,
+//!     gsx_line: 4,
+//!     gsx_col: 1,
+//!     length: 20,
+//! });
+//! assert_eq!(map.go_to_gsx(12, 5), Some((4, 5)));
+//! assert_eq!(map.gsx_to_go(4, 21), Some((12, 21)));
+//! ```
+
+/// Maps regions of a generated `.go` file back to the `.gsx` source.
+///
+/// Missing keys default to zero and unknown ones are ignored when reading,
+/// like `json.Unmarshal`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, rename_all = "camelCase")
+)]
+pub struct SourceMap {
+    /// The path of the `.gsx` file.
+    pub source_file: String,
+    /// The mapped regions, in the order they were added.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "nil_slice"))]
+    pub mappings: Vec<SourceMapping>,
+}
+
+/// A region of `length` bytes on one line, starting at the given Go and
+/// GSX positions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, rename_all = "camelCase")
+)]
+pub struct SourceMapping {
+    pub go_line: usize,
+    pub go_col: usize,
+    pub gsx_line: usize,
+    pub gsx_col: usize,
+    pub length: usize,
+}
+
+/// Reads a slice Go may have marshalled as `null`.
+#[cfg(feature = "serde")]
+fn nil_slice<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::Deserialize<'de>,
+{
+    use serde::Deserialize;
+    Ok(Option::<Vec<T>>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+impl SourceMap {
+    /// Creates an empty source map for `source_file`.
+    pub fn new(source_file: &str) -> Self {
+        Self {
+            source_file: source_file.to_string(),
+            mappings: Vec::new(),
+        }
+    }
+
+    /// Adds a region. Regions may overlap; lookups use the first one added.
+    pub fn add_mapping(&mut self, mapping: SourceMapping) {
+        self.mappings.push(mapping);
+    }
+
+    /// Translates a position in the `.go` file to the `.gsx` file. The first
+    /// mapping whose region contains the column wins; a region includes the
+    /// column just past its end, as in tuigen. Returns `None` when no region
+    /// contains the position or the translated column doesn't fit a `usize`.
+    pub fn go_to_gsx(&self, go_line: usize, go_col: usize) -> Option<(usize, usize)> {
+        self.mappings
+            .iter()
+            .find(|m| {
+                m.go_line == go_line
+                    && go_col >= m.go_col
+                    && m.go_col
+                        .checked_add(m.length)
+                        .is_none_or(|end| go_col <= end)
+            })
+            .and_then(|m| Some((m.gsx_line, m.gsx_col.checked_add(go_col - m.go_col)?)))
+    }
+
+    /// Translates a position in the `.gsx` file to the `.go` file, the same
+    /// way as [`go_to_gsx`](Self::go_to_gsx).
+    pub fn gsx_to_go(&self, gsx_line: usize, gsx_col: usize) -> Option<(usize, usize)> {
+        self.mappings
+            .iter()
+            .find(|m| {
+                m.gsx_line == gsx_line
+                    && gsx_col >= m.gsx_col
+                    && m.gsx_col
+                        .checked_add(m.length)
+                        .is_none_or(|end| gsx_col <= end)
+            })
+            .and_then(|m| Some((m.go_line, m.go_col.checked_add(gsx_col - m.gsx_col)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mapping(
+        go_line: usize,
+        go_col: usize,
+        gsx_line: usize,
+        gsx_col: usize,
+        length: usize,
+    ) -> SourceMapping {
+        SourceMapping {
+            go_line,
+            go_col,
+            gsx_line,
+            gsx_col,
+            length,
+        }
+    }
+
+    #[test]
+    fn test_lookups() {
+        let mut map = SourceMap::new("a.gsx");
+        map.add_mapping(mapping(10, 1, 3, 4, 5));
+        map.add_mapping(mapping(10, 8, 7, 0, 3));
+
+        assert_eq!(map.go_to_gsx(10, 1), Some((3, 4)));
+        assert_eq!(map.go_to_gsx(10, 6), Some((3, 9)));
+        assert_eq!(map.go_to_gsx(10, 9), Some((7, 1)));
+        assert_eq!(map.go_to_gsx(10, 0), None);
+        assert_eq!(map.go_to_gsx(11, 1), None);
+
+        assert_eq!(map.gsx_to_go(3, 6), Some((10, 3)));
+        assert_eq!(map.gsx_to_go(7, 3), Some((10, 11)));
+        assert_eq!(map.gsx_to_go(7, 4), None);
+    }
+
+    #[test]
+    fn test_lookups_near_overflow() {
+        let mut map = SourceMap::new("a.gsx");
+        map.add_mapping(mapping(0, usize::MAX - 1, 0, 2, 5));
+        map.add_mapping(mapping(1, 2, 1, usize::MAX - 1, 5));
+
+        assert_eq!(map.go_to_gsx(0, usize::MAX), Some((0, 3)));
+        assert_eq!(map.go_to_gsx(0, 0), None);
+        assert_eq!(map.gsx_to_go(1, usize::MAX), Some((1, 3)));
+        // The region runs past usize::MAX on the GSX side, so its last
+        // columns have no GSX position.
+        assert_eq!(map.go_to_gsx(1, 3), Some((1, usize::MAX)));
+        assert_eq!(map.go_to_gsx(1, 4), None);
+        assert_eq!(map.go_to_gsx(1, 7), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_matches_go() {
+        let mut map = SourceMap::new("dir/a.gsx");
+        map.add_mapping(mapping(1, 2, 3, 4, 5));
+        map.add_mapping(mapping(6, 0, 7, 0, 8));
+        let json = serde_json::to_string(&map).unwrap();
+        assert_eq!(
+            json,
+            r#"{"sourceFile":"dir/a.gsx","mappings":[{"goLine":1,"goCol":2,"gsxLine":3,"gsxCol":4,"length":5},{"goLine":6,"goCol":0,"gsxLine":7,"gsxCol":0,"length":8}]}"#
+        );
+        assert_eq!(serde_json::from_str::<SourceMap>(&json).unwrap(), map);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_from_json() {
+        // Go escapes `<`, `>` and `&` in strings, and writes a nil slice as
+        // null.
+        let json = r#"
+            {
+                "mappings": [
+                    {"length": 2, "goLine": 1, "extra": [1, {"a": null}], "gsxCol": 3}
+                ],
+                "sourceFile": "\u003ca\u003e \u0026.gsx",
+                "version": 2
+            }
+        "#;
+        let map: SourceMap = serde_json::from_str(json).unwrap();
+        assert_eq!(map.source_file, "<a> &.gsx");
+        assert_eq!(map.mappings, vec![mapping(1, 0, 0, 3, 2)]);
+
+        let null: SourceMap =
+            serde_json::from_str(r#"{"sourceFile":"a","mappings":null}"#).unwrap();
+        assert!(null.mappings.is_empty());
+
+        let negative = r#"{"sourceFile":"a","mappings":[{"goLine":-1}]}"#;
+        assert!(serde_json::from_str::<SourceMap>(negative).is_err());
+    }
+}
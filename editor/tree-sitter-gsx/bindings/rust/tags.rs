@@ -0,0 +1,260 @@
+//! ctags-style definitions and references for code navigation.
+//!
+//! [`tags`] runs [`TAGS_QUERY`] over a file and yields one [`Tag`] per
+//! component, function, struct type and component call. Definitions carry
+//! the `//` comments directly above them as their docstring, like `go doc`.
+//!
+//! ```
+//! let code = "// Header shows the title.\ntempl Header(title string) {\n\t@Title(title)\n}\n";
+//!
+//! let tags: Vec<_> = tree_sitter_gsx::tags::tags(code).collect();
+//! assert_eq!(tags[0].name, "Header");
+//! assert_eq!(tags[0].docstring.as_deref(), Some("Header shows the title."));
+//! assert_eq!(tags[1].name, "Title");
+//! assert!(!tags[1].kind.is_definition());
+//! ```
+//!
+//! [`TAGS_QUERY`]: crate::TAGS_QUERY
+
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+use tree_sitter::{Node, Parser, Query, QueryCursor, Range, StreamingIterator};
+
+/// What a [`Tag`] marks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TagKind {
+    /// A component or function declaration.
+    Function,
+    /// A component or function declared with a receiver.
+    Method,
+    /// A struct type declaration.
+    Type,
+    /// A component call.
+    Call,
+}
+
+impl TagKind {
+    /// Returns the kind's name as used in the query captures, e.g. `method`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TagKind::Function => "function",
+            TagKind::Method => "method",
+            TagKind::Type => "type",
+            TagKind::Call => "call",
+        }
+    }
+
+    pub fn is_definition(self) -> bool {
+        self != TagKind::Call
+    }
+}
+
+/// A named definition or reference.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Tag {
+    pub name: String,
+    pub kind: TagKind,
+    /// The whole declaration or call.
+    pub range: Range,
+    /// The name identifier.
+    pub name_range: Range,
+    /// The comment lines directly above a definition, without their `//`
+    /// markers. Always `None` for references.
+    pub docstring: Option<String>,
+}
+
+fn query() -> &'static Query {
+    static QUERY: OnceLock<Query> = OnceLock::new();
+    QUERY.get_or_init(|| {
+        Query::new(&crate::language(), crate::TAGS_QUERY).expect("tags query is valid")
+    })
+}
+
+/// Parses `source` and returns its tags in source order.
+pub fn tags(source: &str) -> impl Iterator<Item = Tag> {
+    let mut parser = Parser::new();
+    parser
+        .set_language(&crate::language())
+        .expect("Error loading GSX grammar");
+    let tree = parser.parse(source, None).expect("parser has a language");
+
+    let query = query();
+    let names = query.capture_names();
+    let mut seen = HashSet::new();
+    let mut tags = Vec::new();
+    let mut cursor = QueryCursor::new();
+    let mut matches = cursor.matches(query, tree.root_node(), source.as_bytes());
+    while let Some(m) = matches.next() {
+        let mut name = None;
+        let mut tagged = None;
+        for capture in m.captures {
+            let kind = match names[capture.index as usize] {
+                "name" => {
+                    name = Some(capture.node);
+                    continue;
+                }
+                "definition.function" => TagKind::Function,
+                "definition.method" => TagKind::Method,
+                "definition.type" => TagKind::Type,
+                "reference.call" => TagKind::Call,
+                _ => continue,
+            };
+            tagged = Some((capture.node, kind));
+        }
+        let (Some(name), Some((node, kind))) = (name, tagged) else {
+            continue;
+        };
+        // Patterns with a `(comment)*` prefix can match the same declaration
+        // once per run of comments.
+        if !seen.insert(node.id()) {
+            continue;
+        }
+        tags.push(Tag {
+            name: source[name.byte_range()].to_string(),
+            kind,
+            range: node.range(),
+            name_range: name.range(),
+            docstring: kind
+                .is_definition()
+                .then(|| docstring(node, source))
+                .flatten(),
+        });
+    }
+    tags.sort_by_key(|tag| tag.range.start_byte);
+    tags.into_iter()
+}
+
+/// Joins the comments that end on the lines directly above `node`, each
+/// touching the next, with their comment markers removed.
+fn docstring(node: Node, source: &str) -> Option<String> {
+    let mut lines = Vec::new();
+    let mut row = node.start_position().row;
+    let mut sibling = node.prev_sibling();
+    while let Some(comment) = sibling.filter(|s| s.kind() == "comment") {
+        if comment.end_position().row + 1 != row {
+            break;
+        }
+        row = comment.start_position().row;
+        let text = &source[comment.byte_range()];
+        let text = match text.strip_prefix("//") {
+            Some(line) => line.strip_prefix(' ').unwrap_or(line),
+            None => text.trim_start_matches("/*").trim_end_matches("*/").trim(),
+        };
+        lines.push(text.trim_end());
+        sibling = comment.prev_sibling();
+    }
+    if lines.is_empty() {
+        return None;
+    }
+    lines.reverse();
+    Some(lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(source: &str) -> Vec<(&'static str, String, usize, Option<String>)> {
+        tags(source)
+            .map(|tag| {
+                (
+                    tag.kind.as_str(),
+                    tag.name,
+                    tag.name_range.start_point.row,
+                    tag.docstring,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_tags() {
+        let code = r#"package ui
+
+// Sidebar holds the navigation state.
+type Sidebar struct {
+	open bool
+}
+
+// Render draws the sidebar.
+//
+// It is a method component.
+templ (s *Sidebar) Render() {
+	@Item("home")
+}
+
+// A detached comment.
+
+templ Item(label string) {
+	<span>{label}</span>
+}
+
+/* helper formats a label. */
+func helper(label string) string {
+	return label
+}
+
+func (s *Sidebar) Toggle() {
+	s.open = !s.open
+}
+"#;
+        assert_eq!(
+            summary(code),
+            [
+                (
+                    "type",
+                    "Sidebar".to_string(),
+                    3,
+                    Some("Sidebar holds the navigation state.".to_string())
+                ),
+                (
+                    "method",
+                    "Render".to_string(),
+                    10,
+                    Some("Render draws the sidebar.\n\nIt is a method component.".to_string())
+                ),
+                ("call", "Item".to_string(), 11, None),
+                ("function", "Item".to_string(), 16, None),
+                (
+                    "function",
+                    "helper".to_string(),
+                    21,
+                    Some("helper formats a label.".to_string())
+                ),
+                ("method", "Toggle".to_string(), 25, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tag_ranges() {
+        let code = "templ A() {\n\t@B()\n}\n";
+        let tags: Vec<_> = tags(code).collect();
+        let bytes = |range: Range| range.start_byte..range.end_byte;
+        assert_eq!(bytes(tags[0].range), 0..code.len() - 1);
+        assert_eq!(bytes(tags[0].name_range), 6..7);
+        assert_eq!(&code[bytes(tags[1].range)], "@B()");
+        assert_eq!(&code[bytes(tags[1].name_range)], "B");
+
+        // A qualified call is tagged by its name part.
+        let code = "templ A() {\n\t@layout.B()\n}\n";
+        let call = super::tags(code).nth(1).unwrap();
+        assert_eq!(call.name, "B");
+        assert_eq!(&code[bytes(call.range)], "@layout.B()");
+        assert_eq!(&code[bytes(call.name_range)], "B");
+
+        // Generic declarations are tagged by name, not by a type parameter.
+        let code = "func Map[T, U any](xs []T) []U {\n\treturn nil\n}\n\ntempl List[T any](items []T) {\n}\n";
+        let names: Vec<_> = super::tags(code)
+            .map(|tag| (tag.kind, code[bytes(tag.name_range)].to_string()))
+            .collect();
+        assert_eq!(
+            names,
+            [
+                (TagKind::Function, "Map".to_string()),
+                (TagKind::Function, "List".to_string())
+            ]
+        );
+    }
+}
@@ -0,0 +1,1541 @@
+//! Tailwind-style utility classes in `class` attributes.
+//!
+//! go-tui maps classes such as `flex-col`, `p-2` or `text-bright-red` to
+//! element options at generate time. This module ports the class table from
+//! `internal/tuigen/tailwind_data.go` and the checks from
+//! `tailwind_validation.go`, so unknown classes can be reported without the
+//! Go analyzer.
+//!
+//! ```
+//! use tree_sitter_gsx::tailwind;
+//!
+//! let classes = tailwind::split_classes("flex-col  gapp-1", 0);
+//! assert_eq!(classes[1].name, "gapp-1");
+//! assert_eq!(classes[1].range, 10..16);
+//! assert!(!classes[1].valid);
+//! assert_eq!(classes[1].suggestion, Some("gap-1"));
+//! ```
+
+use std::ops::Range;
+
+use tree_sitter::{Node, Point, Tree};
+
+use crate::diagnostics::Diagnostic;
+
+/// What a class from the static table generates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClassMapping {
+    /// The element options to add, e.g. `tui.WithDirection(tui.Column)`.
+    /// Empty for text style classes.
+    pub option: &'static str,
+    /// The package the generated code needs, or an empty string.
+    pub needs_import: &'static str,
+    /// Whether the class is a text style modifier. Text style classes are
+    /// combined into one `tui.WithTextStyle` option.
+    pub is_text_style: bool,
+    /// The method chained onto `tui.NewStyle()` for a text style class,
+    /// e.g. `Bold()`.
+    pub text_method: &'static str,
+}
+
+/// One class token from a `class` attribute value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Class<'a> {
+    pub name: &'a str,
+    /// The byte range of the token, including the offset passed to
+    /// [`split_classes`].
+    pub range: Range<usize>,
+    pub valid: bool,
+    /// A likely intended class when `valid` is false.
+    pub suggestion: Option<&'static str>,
+}
+
+/// Returns the static mapping for `class`. Classes built from a pattern,
+/// such as `p-2` or `w-1/2`, have no static mapping.
+pub fn class_mapping(class: &str) -> Option<&'static ClassMapping> {
+    CLASSES
+        .iter()
+        .find(|(name, _)| *name == class)
+        .map(|(_, mapping)| mapping)
+}
+
+/// Reports whether `class` is a known static class or matches one of the
+/// class patterns.
+pub fn is_valid_class(class: &str) -> bool {
+    let class = class.trim();
+    if class.is_empty() {
+        return false;
+    }
+    if class_mapping(class).is_some() {
+        return true;
+    }
+
+    const NUMBERED: &[&str] = &[
+        "gap-",
+        "p-",
+        "px-",
+        "py-",
+        "m-",
+        "w-",
+        "h-",
+        "min-w-",
+        "max-w-",
+        "min-h-",
+        "max-h-",
+        "flex-grow-",
+        "flex-shrink-",
+        "pt-",
+        "pr-",
+        "pb-",
+        "pl-",
+        "mt-",
+        "mr-",
+        "mb-",
+        "ml-",
+        "mx-",
+        "my-",
+    ];
+    if NUMBERED
+        .iter()
+        .any(|prefix| class.strip_prefix(prefix).is_some_and(is_number))
+    {
+        return true;
+    }
+
+    for prefix in ["w-", "h-"] {
+        if let Some(rest) = class.strip_prefix(prefix) {
+            if matches!(rest, "full" | "auto") {
+                return true;
+            }
+            if let Some((num, den)) = rest.split_once('/') {
+                if is_number(num) && is_number(den) && den.bytes().any(|b| b != b'0') {
+                    return true;
+                }
+            }
+        }
+    }
+
+    for prefix in ["text-", "bg-", "border-", "scrollbar-", "scrollbar-thumb-"] {
+        let hex = class
+            .strip_prefix(prefix)
+            .and_then(|rest| rest.strip_prefix("[#"))
+            .and_then(|rest| rest.strip_suffix(']'));
+        if hex.is_some_and(|hex| {
+            matches!(hex.len(), 3 | 6) && hex.bytes().all(|b| b.is_ascii_hexdigit())
+        }) {
+            return true;
+        }
+    }
+
+    ["text-gradient-", "bg-gradient-", "border-gradient-"]
+        .iter()
+        .filter_map(|prefix| class.strip_prefix(prefix))
+        .any(is_gradient)
+}
+
+/// Returns a likely intended class for an unknown `class`: a known
+/// alternative spelling first, then the closest known class within three
+/// edits.
+pub fn suggest_class(class: &str) -> Option<&'static str> {
+    if let Some((_, suggestion)) = SIMILAR_CLASSES.iter().find(|(from, _)| *from == class) {
+        return Some(suggestion);
+    }
+
+    let mut best = None;
+    let mut best_distance = 4;
+    let known = CLASSES.iter().map(|(name, _)| *name);
+    for name in known.chain(PATTERN_EXAMPLES.iter().copied()) {
+        let distance = levenshtein(class, name);
+        if distance < best_distance {
+            best_distance = distance;
+            best = Some(name);
+        }
+    }
+    best
+}
+
+/// Splits a `class` attribute value into tokens and validates each one.
+/// Tokens are separated by spaces and tabs, and `offset` is added to every
+/// range so callers can pass the byte offset of the value in the file.
+pub fn split_classes(value: &str, offset: usize) -> Vec<Class<'_>> {
+    let mut classes = Vec::new();
+    let bytes = value.as_bytes();
+    let mut pos = 0;
+    while pos < bytes.len() {
+        while pos < bytes.len() && matches!(bytes[pos], b' ' | b'\t') {
+            pos += 1;
+        }
+        let start = pos;
+        while pos < bytes.len() && !matches!(bytes[pos], b' ' | b'\t') {
+            pos += 1;
+        }
+        if start == pos {
+            continue;
+        }
+
+        let name = &value[start..pos];
+        let valid = is_valid_class(name);
+        classes.push(Class {
+            name,
+            range: offset + start..offset + pos,
+            valid,
+            suggestion: if valid { None } else { suggest_class(name) },
+        });
+    }
+    classes
+}
+
+/// Returns the class tokens of an `attribute` node named `class` with a
+/// string value, or `None` for any other attribute.
+pub fn attribute_classes<'a>(attribute: Node, source: &'a str) -> Option<Vec<Class<'a>>> {
+    let name = attribute.child_by_field_name("name")?;
+    let value = attribute.child_by_field_name("value")?;
+    if &source[name.byte_range()] != "class" || value.kind() != "string" {
+        return None;
+    }
+    let content = value.start_byte() + 1..value.end_byte() - 1;
+    Some(split_classes(&source[content.clone()], content.start))
+}
+
+/// Reports every unknown class in a string-valued `class` attribute, in
+/// source order.
+pub fn validate(tree: &Tree, source: &str) -> Vec<Diagnostic> {
+    let mut out = Vec::new();
+    validate_node(tree.root_node(), source, &mut out);
+    out
+}
+
+fn validate_node(node: Node, source: &str, out: &mut Vec<Diagnostic>) {
+    if node.kind() == "attribute" {
+        let Some(classes) = attribute_classes(node, source) else {
+            return;
+        };
+        // Strings can't span lines, so every token is on the value's row.
+        let value = node.child_by_field_name("value").unwrap();
+        let point = |byte: usize| {
+            let start = value.start_position();
+            Point::new(start.row, start.column + byte - value.start_byte())
+        };
+        for class in classes.into_iter().filter(|class| !class.valid) {
+            out.push(Diagnostic {
+                range: tree_sitter::Range {
+                    start_byte: class.range.start,
+                    end_byte: class.range.end,
+                    start_point: point(class.range.start),
+                    end_point: point(class.range.end),
+                },
+                message: format!("unknown Tailwind class \"{}\"", class.name),
+                hint: class
+                    .suggestion
+                    .map(|suggestion| format!("did you mean \"{suggestion}\"?")),
+                related: Vec::new(),
+            });
+        }
+        return;
+    }
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        validate_node(child, source, out);
+    }
+}
+
+/// The element options generated from a `class` attribute value.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParsedClasses {
+    /// Options passed straight to `tui.New`, e.g. `tui.WithGap(1)`.
+    pub options: Vec<String>,
+    /// Methods to chain onto `tui.NewStyle()`, combined by
+    /// [`text_style_option`].
+    pub text_methods: Vec<String>,
+}
+
+/// Converts a `class` attribute value into element options the way
+/// `ParseTailwindClasses` does. Unknown classes are skipped, and individual
+/// padding and margin sides (`pt-1`, `mx-2`, ...) are merged into one
+/// `tui.WithPaddingTRBL` or `tui.WithMarginTRBL` option after the others.
+///
+/// ```
+/// use tree_sitter_gsx::tailwind;
+///
+/// let parsed = tailwind::parse_classes("gap-1 pt-2 font-bold bogus px-1");
+/// assert_eq!(parsed.options, ["tui.WithGap(1)", "tui.WithPaddingTRBL(2, 1, 0, 1)"]);
+/// assert_eq!(parsed.text_methods, ["Bold()"]);
+/// ```
+pub fn parse_classes(classes: &str) -> ParsedClasses {
+    let mut parsed = ParsedClasses::default();
+    let mut padding = Sides::default();
+    let mut margin = Sides::default();
+    for class in classes.split_whitespace() {
+        if let Some((is_padding, side, value)) = individual_spacing(class) {
+            let sides = if is_padding {
+                &mut padding
+            } else {
+                &mut margin
+            };
+            sides.merge(side, value);
+            continue;
+        }
+        match parse_class(class) {
+            Some(Generated::Option(option)) => parsed.options.push(option),
+            Some(Generated::TextMethod(method)) => parsed.text_methods.push(method),
+            None => {}
+        }
+    }
+    if let Some(option) = padding.option("tui.WithPaddingTRBL") {
+        parsed.options.push(option);
+    }
+    if let Some(option) = margin.option("tui.WithMarginTRBL") {
+        parsed.options.push(option);
+    }
+    parsed
+}
+
+/// Combines text style methods into one `tui.WithTextStyle` option, or
+/// returns `None` when there are none.
+pub fn text_style_option(methods: &[String]) -> Option<String> {
+    if methods.is_empty() {
+        return None;
+    }
+    let mut option = String::from("tui.WithTextStyle(tui.NewStyle()");
+    for method in methods {
+        option.push('.');
+        option.push_str(method);
+    }
+    option.push(')');
+    Some(option)
+}
+
+enum Generated {
+    Option(String),
+    TextMethod(String),
+}
+
+/// The sides of a padding or margin set by individual side classes.
+#[derive(Default)]
+struct Sides {
+    top: Option<i64>,
+    right: Option<i64>,
+    bottom: Option<i64>,
+    left: Option<i64>,
+}
+
+impl Sides {
+    fn merge(&mut self, side: &str, value: i64) {
+        match side {
+            "t" => self.top = Some(value),
+            "r" => self.right = Some(value),
+            "b" => self.bottom = Some(value),
+            "l" => self.left = Some(value),
+            "x" => (self.left, self.right) = (Some(value), Some(value)),
+            "y" => (self.top, self.bottom) = (Some(value), Some(value)),
+            _ => {}
+        }
+    }
+
+    fn option(&self, function: &str) -> Option<String> {
+        let sides = [self.top, self.right, self.bottom, self.left];
+        if sides.iter().all(Option::is_none) {
+            return None;
+        }
+        let [t, r, b, l] = sides.map(Option::unwrap_or_default);
+        Some(format!("{function}({t}, {r}, {b}, {l})"))
+    }
+}
+
+/// Matches `pt-N` through `my-N`, returning whether it's padding, the side
+/// letter and the value.
+fn individual_spacing(class: &str) -> Option<(bool, &str, i64)> {
+    let (prefix, value) = class.split_once('-')?;
+    if prefix.len() != 2 || !is_number(value) {
+        return None;
+    }
+    let is_padding = match &prefix[..1] {
+        "p" => true,
+        "m" => false,
+        _ => return None,
+    };
+    let side = &prefix[1..];
+    if !matches!(side, "t" | "r" | "b" | "l" | "x" | "y") {
+        return None;
+    }
+    Some((is_padding, side, atoi(value)))
+}
+
+/// Parses a run of digits like `strconv.Atoi`, saturating on overflow.
+fn atoi(digits: &str) -> i64 {
+    digits.parse().unwrap_or(i64::MAX)
+}
+
+/// Returns what a single class generates, following `ParseTailwindClass`.
+fn parse_class(class: &str) -> Option<Generated> {
+    if let Some(mapping) = class_mapping(class) {
+        return Some(if mapping.is_text_style {
+            Generated::TextMethod(mapping.text_method.to_string())
+        } else {
+            Generated::Option(mapping.option.to_string())
+        });
+    }
+
+    const NUMBERED: &[(&str, &str)] = &[
+        ("gap-", "tui.WithGap"),
+        ("p-", "tui.WithPadding"),
+        ("m-", "tui.WithMargin"),
+        ("w-", "tui.WithWidth"),
+        ("h-", "tui.WithHeight"),
+        ("min-w-", "tui.WithMinWidth"),
+        ("max-w-", "tui.WithMaxWidth"),
+        ("min-h-", "tui.WithMinHeight"),
+        ("max-h-", "tui.WithMaxHeight"),
+        ("flex-grow-", "tui.WithFlexGrow"),
+        ("flex-shrink-", "tui.WithFlexShrink"),
+    ];
+    for (prefix, function) in NUMBERED {
+        if let Some(n) = class.strip_prefix(prefix).filter(|n| is_number(n)) {
+            return Some(Generated::Option(format!("{function}({})", atoi(n))));
+        }
+    }
+
+    for (prefix, dimension) in [("w-", "Width"), ("h-", "Height")] {
+        let Some(rest) = class.strip_prefix(prefix) else {
+            continue;
+        };
+        match rest {
+            "full" => {
+                return Some(Generated::Option(format!(
+                    "tui.With{dimension}Percent(100.00)"
+                )))
+            }
+            "auto" => return Some(Generated::Option(format!("tui.With{dimension}Auto()"))),
+            _ => {}
+        }
+        if let Some((num, den)) = rest.split_once('/') {
+            if is_number(num) && is_number(den) && atoi(den) != 0 {
+                let percent = atoi(num) as f64 / atoi(den) as f64 * 100.0;
+                return Some(Generated::Option(format!(
+                    "tui.With{dimension}Percent({percent:.2})"
+                )));
+            }
+        }
+    }
+
+    if let Some((r, g, b)) = hex_color(class, "text-") {
+        return Some(Generated::TextMethod(format!(
+            "Foreground(tui.RGBColor({r}, {g}, {b}))"
+        )));
+    }
+    const HEX: &[(&str, &str)] = &[
+        ("bg-", "tui.WithBackground(tui.NewStyle().Background"),
+        ("border-", "tui.WithBorderStyle(tui.NewStyle().Foreground"),
+        (
+            "scrollbar-",
+            "tui.WithScrollbarStyle(tui.NewStyle().Foreground",
+        ),
+        (
+            "scrollbar-thumb-",
+            "tui.WithScrollbarThumbStyle(tui.NewStyle().Foreground",
+        ),
+    ];
+    for (prefix, style) in HEX {
+        if let Some((r, g, b)) = hex_color(class, prefix) {
+            return Some(Generated::Option(format!(
+                "{style}(tui.RGBColor({r}, {g}, {b})))"
+            )));
+        }
+    }
+
+    const GRADIENTS: &[(&str, &str)] = &[
+        ("text-gradient-", "tui.WithTextGradient"),
+        ("bg-gradient-", "tui.WithBackgroundGradient"),
+        ("border-gradient-", "tui.WithBorderGradient"),
+    ];
+    for (prefix, function) in GRADIENTS {
+        if let Some(rest) = class.strip_prefix(prefix).filter(|rest| is_gradient(rest)) {
+            let (start, end, direction) = gradient_parts(rest, *prefix == "text-gradient-");
+            return Some(Generated::Option(format!(
+                "{function}(tui.NewGradient({}, {}).WithDirection({direction}))",
+                color_constant(start),
+                color_constant(end),
+            )));
+        }
+    }
+
+    None
+}
+
+/// Reads the RGB components of a `<prefix>[#rgb]` or `<prefix>[#rrggbb]`
+/// class.
+fn hex_color(class: &str, prefix: &str) -> Option<(u8, u8, u8)> {
+    let hex = class
+        .strip_prefix(prefix)?
+        .strip_prefix("[#")?
+        .strip_suffix(']')?;
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let hex = match hex.len() {
+        3 => hex.chars().flat_map(|c| [c, c]).collect(),
+        6 => hex.to_string(),
+        _ => return None,
+    };
+    let component = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some((component(0)?, component(2)?, component(4)?))
+}
+
+const COLOR_NAMES: &[&str] = &[
+    "bright-red",
+    "bright-green",
+    "bright-blue",
+    "bright-cyan",
+    "bright-magenta",
+    "bright-yellow",
+    "bright-white",
+    "bright-black",
+    "red",
+    "green",
+    "blue",
+    "cyan",
+    "magenta",
+    "yellow",
+    "white",
+    "black",
+];
+
+/// Splits the part of a gradient class after its prefix into start colour,
+/// end colour and direction, reproducing the Go regexes' captures.
+fn gradient_parts(rest: &str, is_text: bool) -> (&str, &str, &'static str) {
+    const DIRECTIONS: &[(&str, &str)] = &[
+        ("-h", "tui.GradientHorizontal"),
+        ("-v", "tui.GradientVertical"),
+        ("-dd", "tui.GradientDiagonalDown"),
+        ("-du", "tui.GradientDiagonalUp"),
+    ];
+    if let Some((colors, direction)) = DIRECTIONS
+        .iter()
+        .find_map(|(suffix, direction)| Some((rest.strip_suffix(suffix)?, *direction)))
+    {
+        // Without a hyphen between two colours the suffix alternative of the
+        // Go regex fails, leaving both captures empty.
+        return match split_gradient(colors) {
+            Some((start, end)) => (start, end, direction),
+            None => ("", "", "tui.GradientHorizontal"),
+        };
+    }
+
+    for name in COLOR_NAMES {
+        if let Some(start) = rest
+            .strip_suffix(name)
+            .and_then(|start| start.strip_suffix('-'))
+        {
+            return (start, name, "tui.GradientHorizontal");
+        }
+    }
+    let split = if is_text {
+        split_gradient(rest)
+    } else {
+        rest.rfind('-')
+            .filter(|&i| i > 0)
+            .map(|i| (&rest[..i], &rest[i + 1..]))
+    };
+    let (start, end) = split.unwrap_or_default();
+    (start, end, "tui.GradientHorizontal")
+}
+
+/// Splits at the last hyphen that has text on both sides, like a greedy
+/// `([\w-]+)-([\w-]+)` match.
+fn split_gradient(colors: &str) -> Option<(&str, &str)> {
+    let bytes = colors.as_bytes();
+    (1..bytes.len().saturating_sub(1))
+        .rev()
+        .find(|&i| bytes[i] == b'-')
+        .map(|i| (&colors[..i], &colors[i + 1..]))
+}
+
+/// Maps a colour name to its `tui` constant, defaulting to `tui.Black`.
+fn color_constant(name: &str) -> String {
+    if !COLOR_NAMES.contains(&name) {
+        return "tui.Black".to_string();
+    }
+    let mut constant = String::from("tui.");
+    for word in name.split('-') {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            constant.push(first.to_ascii_uppercase());
+            constant.push_str(chars.as_str());
+        }
+    }
+    constant
+}
+
+fn is_number(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Matches the `<start>-<end>[-<direction>]` part of a gradient class: word
+/// characters and hyphens, with a hyphen somewhere in the middle.
+fn is_gradient(rest: &str) -> bool {
+    let bytes = rest.as_bytes();
+    bytes
+        .iter()
+        .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'_' | b'-'))
+        && bytes.len() >= 3
+        && bytes[1..bytes.len() - 1].contains(&b'-')
+}
+
+/// Returns the byte-wise Levenshtein distance between `a` and `b`, as
+/// tuigen's `levenshteinDistance` computes it. Unlike
+/// [`crate::schema::edit_distance`], swapping two adjacent bytes costs two
+/// edits.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b = b.as_bytes();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut row = vec![0; b.len() + 1];
+    for (i, ca) in a.bytes().enumerate() {
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            row[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(row[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut row);
+    }
+    prev[b.len()]
+}
+
+const fn option(option: &'static str, needs_import: &'static str) -> ClassMapping {
+    ClassMapping {
+        option,
+        needs_import,
+        is_text_style: false,
+        text_method: "",
+    }
+}
+
+const fn text_style(text_method: &'static str, needs_import: &'static str) -> ClassMapping {
+    ClassMapping {
+        option: "",
+        needs_import,
+        is_text_style: true,
+        text_method,
+    }
+}
+
+/// The static classes, in the order `tailwind_data.go` lists them.
+pub static CLASSES: &[(&str, ClassMapping)] = &[
+    // Layout - display mode and flex direction
+    ("block", option("tui.WithDisplay(tui.DisplayBlock)", "tui")),
+    (
+        "flex",
+        option(
+            "tui.WithDisplay(tui.DisplayFlex), tui.WithDirection(tui.Row)",
+            "tui",
+        ),
+    ),
+    (
+        "flex-row",
+        option(
+            "tui.WithDisplay(tui.DisplayFlex), tui.WithDirection(tui.Row)",
+            "tui",
+        ),
+    ),
+    (
+        "flex-col",
+        option(
+            "tui.WithDisplay(tui.DisplayFlex), tui.WithDirection(tui.Column)",
+            "tui",
+        ),
+    ),
+    // Flex wrap
+    ("flex-wrap", option("tui.WithFlexWrap(tui.Wrap)", "tui")),
+    (
+        "flex-wrap-reverse",
+        option("tui.WithFlexWrap(tui.WrapReverse)", "tui"),
+    ),
+    (
+        "flex-nowrap",
+        option("tui.WithFlexWrap(tui.WrapNone)", "tui"),
+    ),
+    // Align content (cross-axis line distribution for wrapped flex)
+    (
+        "content-start",
+        option("tui.WithAlignContent(tui.ContentStart)", "tui"),
+    ),
+    (
+        "content-end",
+        option("tui.WithAlignContent(tui.ContentEnd)", "tui"),
+    ),
+    (
+        "content-center",
+        option("tui.WithAlignContent(tui.ContentCenter)", "tui"),
+    ),
+    (
+        "content-stretch",
+        option("tui.WithAlignContent(tui.ContentStretch)", "tui"),
+    ),
+    (
+        "content-between",
+        option("tui.WithAlignContent(tui.ContentSpaceBetween)", "tui"),
+    ),
+    (
+        "content-around",
+        option("tui.WithAlignContent(tui.ContentSpaceAround)", "tui"),
+    ),
+    // Flex grow/shrink (Tailwind standard)
+    ("grow", option("tui.WithFlexGrow(1)", "")),
+    ("grow-0", option("tui.WithFlexGrow(0)", "")),
+    ("shrink", option("tui.WithFlexShrink(1)", "")),
+    ("shrink-0", option("tui.WithFlexShrink(0)", "")),
+    // Flex shorthand (Tailwind standard)
+    // flex-1: grow=1, shrink=1 - takes available space
+    // flex-auto: grow=1, shrink=1 - like flex-1 but respects content size
+    // flex-initial: grow=0, shrink=1 - doesn't grow but can shrink
+    // flex-none: grow=0, shrink=0 - fixed size
+    (
+        "flex-1",
+        option("tui.WithFlexGrow(1), tui.WithFlexShrink(1)", ""),
+    ),
+    (
+        "flex-auto",
+        option("tui.WithFlexGrow(1), tui.WithFlexShrink(1)", ""),
+    ),
+    (
+        "flex-initial",
+        option("tui.WithFlexGrow(0), tui.WithFlexShrink(1)", ""),
+    ),
+    (
+        "flex-none",
+        option("tui.WithFlexGrow(0), tui.WithFlexShrink(0)", ""),
+    ),
+    // Legacy flex properties (keep for backwards compatibility)
+    ("flex-grow", option("tui.WithFlexGrow(1)", "")),
+    ("flex-shrink", option("tui.WithFlexShrink(1)", "")),
+    // Justify content
+    (
+        "justify-start",
+        option("tui.WithJustify(tui.JustifyStart)", "tui"),
+    ),
+    (
+        "justify-center",
+        option("tui.WithJustify(tui.JustifyCenter)", "tui"),
+    ),
+    (
+        "justify-end",
+        option("tui.WithJustify(tui.JustifyEnd)", "tui"),
+    ),
+    (
+        "justify-between",
+        option("tui.WithJustify(tui.JustifySpaceBetween)", "tui"),
+    ),
+    (
+        "justify-evenly",
+        option("tui.WithJustify(tui.JustifySpaceEvenly)", "tui"),
+    ),
+    (
+        "justify-around",
+        option("tui.WithJustify(tui.JustifySpaceAround)", "tui"),
+    ),
+    // Align items
+    (
+        "items-start",
+        option("tui.WithAlign(tui.AlignStart)", "tui"),
+    ),
+    (
+        "items-center",
+        option("tui.WithAlign(tui.AlignCenter)", "tui"),
+    ),
+    ("items-end", option("tui.WithAlign(tui.AlignEnd)", "tui")),
+    (
+        "items-stretch",
+        option("tui.WithAlign(tui.AlignStretch)", "tui"),
+    ),
+    // Self-alignment
+    (
+        "self-start",
+        option("tui.WithAlignSelf(tui.AlignStart)", "tui"),
+    ),
+    ("self-end", option("tui.WithAlignSelf(tui.AlignEnd)", "tui")),
+    (
+        "self-center",
+        option("tui.WithAlignSelf(tui.AlignCenter)", "tui"),
+    ),
+    (
+        "self-stretch",
+        option("tui.WithAlignSelf(tui.AlignStretch)", "tui"),
+    ),
+    // Text alignment
+    (
+        "text-left",
+        option("tui.WithTextAlign(tui.TextAlignLeft)", ""),
+    ),
+    (
+        "text-center",
+        option("tui.WithTextAlign(tui.TextAlignCenter)", ""),
+    ),
+    (
+        "text-right",
+        option("tui.WithTextAlign(tui.TextAlignRight)", ""),
+    ),
+    // Borders
+    ("border", option("tui.WithBorder(tui.BorderSingle)", "tui")),
+    (
+        "border-single",
+        option("tui.WithBorder(tui.BorderSingle)", "tui"),
+    ),
+    (
+        "border-rounded",
+        option("tui.WithBorder(tui.BorderRounded)", "tui"),
+    ),
+    (
+        "border-double",
+        option("tui.WithBorder(tui.BorderDouble)", "tui"),
+    ),
+    (
+        "border-thick",
+        option("tui.WithBorder(tui.BorderThick)", "tui"),
+    ),
+    // Border colors
+    (
+        "border-red",
+        option(
+            "tui.WithBorderStyle(tui.NewStyle().Foreground(tui.Red))",
+            "tui",
+        ),
+    ),
+    (
+        "border-green",
+        option(
+            "tui.WithBorderStyle(tui.NewStyle().Foreground(tui.Green))",
+            "tui",
+        ),
+    ),
+    (
+        "border-blue",
+        option(
+            "tui.WithBorderStyle(tui.NewStyle().Foreground(tui.Blue))",
+            "tui",
+        ),
+    ),
+    (
+        "border-cyan",
+        option(
+            "tui.WithBorderStyle(tui.NewStyle().Foreground(tui.Cyan))",
+            "tui",
+        ),
+    ),
+    (
+        "border-magenta",
+        option(
+            "tui.WithBorderStyle(tui.NewStyle().Foreground(tui.Magenta))",
+            "tui",
+        ),
+    ),
+    (
+        "border-yellow",
+        option(
+            "tui.WithBorderStyle(tui.NewStyle().Foreground(tui.Yellow))",
+            "tui",
+        ),
+    ),
+    (
+        "border-white",
+        option(
+            "tui.WithBorderStyle(tui.NewStyle().Foreground(tui.White))",
+            "tui",
+        ),
+    ),
+    (
+        "border-black",
+        option(
+            "tui.WithBorderStyle(tui.NewStyle().Foreground(tui.Black))",
+            "tui",
+        ),
+    ),
+    // Text styles
+    ("font-bold", text_style("Bold()", "")),
+    ("font-dim", text_style("Dim()", "")),
+    ("text-dim", text_style("Dim()", "")),
+    ("italic", text_style("Italic()", "")),
+    ("underline", text_style("Underline()", "")),
+    ("blink", text_style("Blink()", "")),
+    ("reverse", text_style("Reverse()", "")),
+    ("strikethrough", text_style("Strikethrough()", "")),
+    // Text colors
+    ("text-red", text_style("Foreground(tui.Red)", "tui")),
+    ("text-green", text_style("Foreground(tui.Green)", "tui")),
+    ("text-blue", text_style("Foreground(tui.Blue)", "tui")),
+    ("text-cyan", text_style("Foreground(tui.Cyan)", "tui")),
+    ("text-magenta", text_style("Foreground(tui.Magenta)", "tui")),
+    ("text-yellow", text_style("Foreground(tui.Yellow)", "tui")),
+    ("text-white", text_style("Foreground(tui.White)", "tui")),
+    ("text-black", text_style("Foreground(tui.Black)", "tui")),
+    // Bright text colors
+    (
+        "text-bright-red",
+        text_style("Foreground(tui.BrightRed)", "tui"),
+    ),
+    (
+        "text-bright-green",
+        text_style("Foreground(tui.BrightGreen)", "tui"),
+    ),
+    (
+        "text-bright-blue",
+        text_style("Foreground(tui.BrightBlue)", "tui"),
+    ),
+    (
+        "text-bright-cyan",
+        text_style("Foreground(tui.BrightCyan)", "tui"),
+    ),
+    (
+        "text-bright-magenta",
+        text_style("Foreground(tui.BrightMagenta)", "tui"),
+    ),
+    (
+        "text-bright-yellow",
+        text_style("Foreground(tui.BrightYellow)", "tui"),
+    ),
+    (
+        "text-bright-white",
+        text_style("Foreground(tui.BrightWhite)", "tui"),
+    ),
+    (
+        "text-bright-black",
+        text_style("Foreground(tui.BrightBlack)", "tui"),
+    ),
+    // Background colors
+    (
+        "bg-red",
+        option(
+            "tui.WithBackground(tui.NewStyle().Background(tui.Red))",
+            "tui",
+        ),
+    ),
+    (
+        "bg-green",
+        option(
+            "tui.WithBackground(tui.NewStyle().Background(tui.Green))",
+            "tui",
+        ),
+    ),
+    (
+        "bg-blue",
+        option(
+            "tui.WithBackground(tui.NewStyle().Background(tui.Blue))",
+            "tui",
+        ),
+    ),
+    (
+        "bg-cyan",
+        option(
+            "tui.WithBackground(tui.NewStyle().Background(tui.Cyan))",
+            "tui",
+        ),
+    ),
+    (
+        "bg-magenta",
+        option(
+            "tui.WithBackground(tui.NewStyle().Background(tui.Magenta))",
+            "tui",
+        ),
+    ),
+    (
+        "bg-yellow",
+        option(
+            "tui.WithBackground(tui.NewStyle().Background(tui.Yellow))",
+            "tui",
+        ),
+    ),
+    (
+        "bg-white",
+        option(
+            "tui.WithBackground(tui.NewStyle().Background(tui.White))",
+            "tui",
+        ),
+    ),
+    (
+        "bg-black",
+        option(
+            "tui.WithBackground(tui.NewStyle().Background(tui.Black))",
+            "tui",
+        ),
+    ),
+    // Bright background colors
+    (
+        "bg-bright-red",
+        option(
+            "tui.WithBackground(tui.NewStyle().Background(tui.BrightRed))",
+            "tui",
+        ),
+    ),
+    (
+        "bg-bright-green",
+        option(
+            "tui.WithBackground(tui.NewStyle().Background(tui.BrightGreen))",
+            "tui",
+        ),
+    ),
+    (
+        "bg-bright-blue",
+        option(
+            "tui.WithBackground(tui.NewStyle().Background(tui.BrightBlue))",
+            "tui",
+        ),
+    ),
+    (
+        "bg-bright-cyan",
+        option(
+            "tui.WithBackground(tui.NewStyle().Background(tui.BrightCyan))",
+            "tui",
+        ),
+    ),
+    (
+        "bg-bright-magenta",
+        option(
+            "tui.WithBackground(tui.NewStyle().Background(tui.BrightMagenta))",
+            "tui",
+        ),
+    ),
+    (
+        "bg-bright-yellow",
+        option(
+            "tui.WithBackground(tui.NewStyle().Background(tui.BrightYellow))",
+            "tui",
+        ),
+    ),
+    (
+        "bg-bright-white",
+        option(
+            "tui.WithBackground(tui.NewStyle().Background(tui.BrightWhite))",
+            "tui",
+        ),
+    ),
+    (
+        "bg-bright-black",
+        option(
+            "tui.WithBackground(tui.NewStyle().Background(tui.BrightBlack))",
+            "tui",
+        ),
+    ),
+    // Scroll
+    (
+        "overflow-scroll",
+        option("tui.WithScrollable(tui.ScrollBoth)", ""),
+    ),
+    (
+        "overflow-y-scroll",
+        option("tui.WithScrollable(tui.ScrollVertical)", ""),
+    ),
+    (
+        "overflow-x-scroll",
+        option("tui.WithScrollable(tui.ScrollHorizontal)", ""),
+    ),
+    // Focus
+    ("focusable", option("tui.WithFocusable(true)", "")),
+    // Visibility
+    ("hidden", option("tui.WithHidden(true)", "")),
+    // Overflow
+    (
+        "overflow-hidden",
+        option("tui.WithOverflow(tui.OverflowHidden)", "tui"),
+    ),
+    // Text overflow
+    ("truncate", option("tui.WithTruncate(true)", "")),
+    // Text wrapping
+    ("nowrap", option("tui.WithWrap(false)", "")),
+    ("wrap", option("tui.WithWrap(true)", "")),
+    // Scrollbar visibility
+    (
+        "scrollbar-hidden",
+        option("tui.WithScrollbarHidden(true)", "tui"),
+    ),
+    // Scrollbar track colors
+    (
+        "scrollbar-red",
+        option(
+            "tui.WithScrollbarStyle(tui.NewStyle().Foreground(tui.Red))",
+            "tui",
+        ),
+    ),
+    (
+        "scrollbar-green",
+        option(
+            "tui.WithScrollbarStyle(tui.NewStyle().Foreground(tui.Green))",
+            "tui",
+        ),
+    ),
+    (
+        "scrollbar-blue",
+        option(
+            "tui.WithScrollbarStyle(tui.NewStyle().Foreground(tui.Blue))",
+            "tui",
+        ),
+    ),
+    (
+        "scrollbar-cyan",
+        option(
+            "tui.WithScrollbarStyle(tui.NewStyle().Foreground(tui.Cyan))",
+            "tui",
+        ),
+    ),
+    (
+        "scrollbar-magenta",
+        option(
+            "tui.WithScrollbarStyle(tui.NewStyle().Foreground(tui.Magenta))",
+            "tui",
+        ),
+    ),
+    (
+        "scrollbar-yellow",
+        option(
+            "tui.WithScrollbarStyle(tui.NewStyle().Foreground(tui.Yellow))",
+            "tui",
+        ),
+    ),
+    (
+        "scrollbar-white",
+        option(
+            "tui.WithScrollbarStyle(tui.NewStyle().Foreground(tui.White))",
+            "tui",
+        ),
+    ),
+    (
+        "scrollbar-black",
+        option(
+            "tui.WithScrollbarStyle(tui.NewStyle().Foreground(tui.Black))",
+            "tui",
+        ),
+    ),
+    (
+        "scrollbar-bright-red",
+        option(
+            "tui.WithScrollbarStyle(tui.NewStyle().Foreground(tui.BrightRed))",
+            "tui",
+        ),
+    ),
+    (
+        "scrollbar-bright-green",
+        option(
+            "tui.WithScrollbarStyle(tui.NewStyle().Foreground(tui.BrightGreen))",
+            "tui",
+        ),
+    ),
+    (
+        "scrollbar-bright-blue",
+        option(
+            "tui.WithScrollbarStyle(tui.NewStyle().Foreground(tui.BrightBlue))",
+            "tui",
+        ),
+    ),
+    (
+        "scrollbar-bright-cyan",
+        option(
+            "tui.WithScrollbarStyle(tui.NewStyle().Foreground(tui.BrightCyan))",
+            "tui",
+        ),
+    ),
+    (
+        "scrollbar-bright-magenta",
+        option(
+            "tui.WithScrollbarStyle(tui.NewStyle().Foreground(tui.BrightMagenta))",
+            "tui",
+        ),
+    ),
+    (
+        "scrollbar-bright-yellow",
+        option(
+            "tui.WithScrollbarStyle(tui.NewStyle().Foreground(tui.BrightYellow))",
+            "tui",
+        ),
+    ),
+    (
+        "scrollbar-bright-white",
+        option(
+            "tui.WithScrollbarStyle(tui.NewStyle().Foreground(tui.BrightWhite))",
+            "tui",
+        ),
+    ),
+    (
+        "scrollbar-bright-black",
+        option(
+            "tui.WithScrollbarStyle(tui.NewStyle().Foreground(tui.BrightBlack))",
+            "tui",
+        ),
+    ),
+    // Scrollbar thumb colors
+    (
+        "scrollbar-thumb-red",
+        option(
+            "tui.WithScrollbarThumbStyle(tui.NewStyle().Foreground(tui.Red))",
+            "tui",
+        ),
+    ),
+    (
+        "scrollbar-thumb-green",
+        option(
+            "tui.WithScrollbarThumbStyle(tui.NewStyle().Foreground(tui.Green))",
+            "tui",
+        ),
+    ),
+    (
+        "scrollbar-thumb-blue",
+        option(
+            "tui.WithScrollbarThumbStyle(tui.NewStyle().Foreground(tui.Blue))",
+            "tui",
+        ),
+    ),
+    (
+        "scrollbar-thumb-cyan",
+        option(
+            "tui.WithScrollbarThumbStyle(tui.NewStyle().Foreground(tui.Cyan))",
+            "tui",
+        ),
+    ),
+    (
+        "scrollbar-thumb-magenta",
+        option(
+            "tui.WithScrollbarThumbStyle(tui.NewStyle().Foreground(tui.Magenta))",
+            "tui",
+        ),
+    ),
+    (
+        "scrollbar-thumb-yellow",
+        option(
+            "tui.WithScrollbarThumbStyle(tui.NewStyle().Foreground(tui.Yellow))",
+            "tui",
+        ),
+    ),
+    (
+        "scrollbar-thumb-white",
+        option(
+            "tui.WithScrollbarThumbStyle(tui.NewStyle().Foreground(tui.White))",
+            "tui",
+        ),
+    ),
+    (
+        "scrollbar-thumb-black",
+        option(
+            "tui.WithScrollbarThumbStyle(tui.NewStyle().Foreground(tui.Black))",
+            "tui",
+        ),
+    ),
+    (
+        "scrollbar-thumb-bright-red",
+        option(
+            "tui.WithScrollbarThumbStyle(tui.NewStyle().Foreground(tui.BrightRed))",
+            "tui",
+        ),
+    ),
+    (
+        "scrollbar-thumb-bright-green",
+        option(
+            "tui.WithScrollbarThumbStyle(tui.NewStyle().Foreground(tui.BrightGreen))",
+            "tui",
+        ),
+    ),
+    (
+        "scrollbar-thumb-bright-blue",
+        option(
+            "tui.WithScrollbarThumbStyle(tui.NewStyle().Foreground(tui.BrightBlue))",
+            "tui",
+        ),
+    ),
+    (
+        "scrollbar-thumb-bright-cyan",
+        option(
+            "tui.WithScrollbarThumbStyle(tui.NewStyle().Foreground(tui.BrightCyan))",
+            "tui",
+        ),
+    ),
+    (
+        "scrollbar-thumb-bright-magenta",
+        option(
+            "tui.WithScrollbarThumbStyle(tui.NewStyle().Foreground(tui.BrightMagenta))",
+            "tui",
+        ),
+    ),
+    (
+        "scrollbar-thumb-bright-yellow",
+        option(
+            "tui.WithScrollbarThumbStyle(tui.NewStyle().Foreground(tui.BrightYellow))",
+            "tui",
+        ),
+    ),
+    (
+        "scrollbar-thumb-bright-white",
+        option(
+            "tui.WithScrollbarThumbStyle(tui.NewStyle().Foreground(tui.BrightWhite))",
+            "tui",
+        ),
+    ),
+    (
+        "scrollbar-thumb-bright-black",
+        option(
+            "tui.WithScrollbarThumbStyle(tui.NewStyle().Foreground(tui.BrightBlack))",
+            "tui",
+        ),
+    ),
+];
+
+/// Common typos and alternative spellings, mapped to the intended class.
+const SIMILAR_CLASSES: &[(&str, &str)] = &[
+    ("flex-column", "flex-col"),
+    ("flex-columns", "flex-col"),
+    ("flex-rows", "flex-row"),
+    ("gap", "gap-1"),
+    ("padding", "p-1"),
+    ("margin", "m-1"),
+    ("bold", "font-bold"),
+    ("italic", "italic"),
+    ("dim", "font-dim"),
+    ("width", "w-1"),
+    ("height", "h-1"),
+    ("center", "text-center"),
+    ("left", "text-left"),
+    ("right", "text-right"),
+    ("align-center", "text-center"),
+    ("align-left", "text-left"),
+    ("align-right", "text-right"),
+    ("no-grow", "grow-0"),
+    ("no-shrink", "shrink-0"),
+    ("padding-top", "pt-1"),
+    ("padding-bottom", "pb-1"),
+    ("padding-left", "pl-1"),
+    ("padding-right", "pr-1"),
+    ("margin-top", "mt-1"),
+    ("margin-bottom", "mb-1"),
+    ("margin-left", "ml-1"),
+    ("margin-right", "mr-1"),
+    ("col", "flex-col"),
+    ("row", "flex-row"),
+    ("column", "flex-col"),
+    ("columns", "flex-col"),
+    ("rows", "flex-row"),
+    ("focus", "focusable"),
+    ("hide", "hidden"),
+    ("invisible", "hidden"),
+    ("visible", "hidden"),
+    ("clip", "overflow-hidden"),
+    ("ellipsis", "truncate"),
+    ("text-truncate", "truncate"),
+    ("text-ellipsis", "truncate"),
+    ("display-block", "block"),
+    ("display-flex", "flex"),
+    ("wrap-reverse", "flex-wrap-reverse"),
+    ("no-wrap", "flex-nowrap"),
+    ("align-content", "content-start"),
+];
+
+/// Representative classes for the patterns, offered as suggestions alongside
+/// the static classes.
+const PATTERN_EXAMPLES: &[&str] = &[
+    "gap-1",
+    "gap-2",
+    "gap-3",
+    "gap-4",
+    "p-1",
+    "p-2",
+    "p-3",
+    "p-4",
+    "px-1",
+    "px-2",
+    "px-3",
+    "px-4",
+    "py-1",
+    "py-2",
+    "py-3",
+    "py-4",
+    "pt-1",
+    "pt-2",
+    "pt-3",
+    "pt-4",
+    "pr-1",
+    "pr-2",
+    "pr-3",
+    "pr-4",
+    "pb-1",
+    "pb-2",
+    "pb-3",
+    "pb-4",
+    "pl-1",
+    "pl-2",
+    "pl-3",
+    "pl-4",
+    "m-1",
+    "m-2",
+    "m-3",
+    "m-4",
+    "mt-1",
+    "mt-2",
+    "mt-3",
+    "mt-4",
+    "mr-1",
+    "mr-2",
+    "mr-3",
+    "mr-4",
+    "mb-1",
+    "mb-2",
+    "mb-3",
+    "mb-4",
+    "ml-1",
+    "ml-2",
+    "ml-3",
+    "ml-4",
+    "mx-1",
+    "mx-2",
+    "mx-3",
+    "mx-4",
+    "my-1",
+    "my-2",
+    "my-3",
+    "my-4",
+    "w-1",
+    "w-10",
+    "w-20",
+    "w-50",
+    "w-100",
+    "w-full",
+    "w-auto",
+    "w-1/2",
+    "w-1/3",
+    "w-2/3",
+    "w-1/4",
+    "w-3/4",
+    "h-1",
+    "h-10",
+    "h-20",
+    "h-50",
+    "h-100",
+    "h-full",
+    "h-auto",
+    "h-1/2",
+    "h-1/3",
+    "h-2/3",
+    "h-1/4",
+    "h-3/4",
+    "min-w-1",
+    "min-w-10",
+    "max-w-50",
+    "max-w-100",
+    "min-h-1",
+    "min-h-10",
+    "max-h-50",
+    "max-h-100",
+    "flex-grow-0",
+    "flex-grow-1",
+    "flex-grow-2",
+    "flex-shrink-0",
+    "flex-shrink-1",
+    "flex-shrink-2",
+    // Tailwind standard flex utilities
+    "grow",
+    "grow-0",
+    "shrink",
+    "shrink-0",
+    "flex-1",
+    "flex-auto",
+    "flex-initial",
+    "flex-none",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(body: &str) -> Vec<String> {
+        let code = format!("templ A() {{\n{body}\n}}\n");
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&crate::language()).unwrap();
+        let tree = parser.parse(&code, None).unwrap();
+        assert!(!tree.root_node().has_error(), "{code}");
+        validate(&tree, &code)
+            .into_iter()
+            .map(|d| d.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_class_table() {
+        assert_eq!(CLASSES.len(), 135);
+        assert_eq!(
+            class_mapping("flex-col"),
+            Some(&option(
+                "tui.WithDisplay(tui.DisplayFlex), tui.WithDirection(tui.Column)",
+                "tui"
+            ))
+        );
+        let bold = class_mapping("font-bold").unwrap();
+        assert!(bold.is_text_style);
+        assert_eq!(bold.text_method, "Bold()");
+        assert_eq!(class_mapping("p-2"), None);
+    }
+
+    #[test]
+    fn test_patterns() {
+        for class in [
+            "gap-2",
+            "p-10",
+            "min-w-0",
+            "flex-shrink-1",
+            "w-1/2",
+            "h-full",
+            "w-auto",
+            "text-[#fff]",
+            "bg-[#1a2B3c]",
+            "scrollbar-thumb-[#abc]",
+            "bg-gradient-red-blue",
+            "text-gradient-bright-cyan-magenta-h",
+        ] {
+            assert!(is_valid_class(class), "{class}");
+        }
+        for class in [
+            "",
+            "p-",
+            "p-x",
+            "gap--1",
+            "w-1/0",
+            "w-full/2",
+            "text-[#ffff]",
+            "text-[fff]",
+            "bg-gradient-red",
+            "bg-gradient-red-",
+            "border-gradient-a.b",
+        ] {
+            assert!(!is_valid_class(class), "{class}");
+        }
+    }
+
+    #[test]
+    fn test_suggestions() {
+        assert_eq!(suggest_class("flex-column"), Some("flex-col"));
+        assert_eq!(suggest_class("bold"), Some("font-bold"));
+        assert_eq!(suggest_class("justfy-center"), Some("justify-center"));
+        assert_eq!(suggest_class("gapp-1"), Some("gap-1"));
+        assert_eq!(suggest_class("completely-unrelated"), None);
+
+        // Like tuigen, a swap of adjacent bytes costs two edits, so two swaps
+        // are too far from any class.
+        assert_eq!(suggest_class("fxle-col"), Some("flex-col"));
+        assert_eq!(suggest_class("fxle-clo"), None);
+        assert_eq!(suggest_class("txet-rde"), None);
+        assert_eq!(levenshtein("flxe", "flex"), 2);
+        assert_eq!(levenshtein("ítalic", "italic"), 2);
+    }
+
+    #[test]
+    fn test_split_classes() {
+        let classes = split_classes("\tflex  p-1 nope\t", 7);
+        let names: Vec<_> = classes.iter().map(|c| (c.name, c.range.clone())).collect();
+        assert_eq!(names, [("flex", 8..12), ("p-1", 14..17), ("nope", 18..22)]);
+        assert!(classes[0].valid && classes[1].valid && !classes[2].valid);
+        assert!(split_classes(" \t ", 0).is_empty());
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(check("\t<div class=\"flex-col gap-1 font-bold\" />").is_empty());
+        assert_eq!(
+            check("\t<div class=\"flex-column p-1 bogus-class-name\">\n\t\t<span class={c} />\n\t</div>"),
+            [
+                "2:14: error: unknown Tailwind class \"flex-column\" (did you mean \"flex-col\"?)",
+                "2:30: error: unknown Tailwind class \"bogus-class-name\"",
+            ]
+        );
+    }
+}
@@ -0,0 +1,567 @@
+//! A cross-file index of the components and functions in a tree of `.gsx`
+//! files.
+//!
+//! A `component_call` such as `@Header(...)` usually names a component
+//! declared in another file of the same package. [`Workspace`] parses every
+//! file once, groups files into packages by directory and `package` clause,
+//! and answers where a component is defined and who calls it. Only `.gsx`
+//! files are indexed, so a call to a function declared in a `.go` file is
+//! reported as unresolved.
+//!
+//! A qualified call is resolved through the calling file's imports when the
+//! qualifier names one, so `@layout.Sidebar()` finds `Sidebar` in the
+//! indexed package whose directory matches the import path. Otherwise the
+//! qualifier is taken to be a receiver and the call resolves to a method
+//! component of the same package on the same base type, so a method on
+//! `*Page` can call one on `Page`.
+//!
+//! ```
+//! use tree_sitter_gsx::workspace::Workspace;
+//!
+//! let mut workspace = Workspace::new();
+//! workspace.update("ui/header.gsx", "package ui\n\ntempl Header() {\n\t<hr />\n}\n");
+//! workspace.update("ui/page.gsx", "package ui\n\ntempl Page() {\n\t@Header()\n\t@Footer()\n}\n");
+//!
+//! let missing: Vec<_> = workspace.unresolved_calls().map(|call| &call.name).collect();
+//! assert_eq!(missing, ["Footer"]);
+//! ```
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use tree_sitter::{Node, Parser, Range};
+
+use crate::ast::{AstNode, ImportDeclarationKind, ImportSpec, SourceFile, SourceFileChild};
+
+/// A Go package: the files in one directory with the same `package` clause.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Package {
+    pub dir: PathBuf,
+    /// The package name, or an empty string for files without a `package`
+    /// clause.
+    pub name: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeclarationKind {
+    Component,
+    Function,
+}
+
+/// A top-level component or function declaration.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Declaration {
+    pub name: String,
+    pub kind: DeclarationKind,
+    /// The receiver type of a method, e.g. `*Sidebar`.
+    pub receiver: Option<String>,
+    pub path: PathBuf,
+    /// The range of the declaration's name.
+    pub range: Range,
+}
+
+/// A `component_call`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Call {
+    /// The name of the called component.
+    pub name: String,
+    /// The package or receiver the component is selected from, e.g. `layout`
+    /// in `@layout.Sidebar()`.
+    pub qualifier: Option<String>,
+    /// The receiver type of the enclosing method component when `qualifier`
+    /// names its receiver, e.g. `*Sidebar`.
+    pub receiver: Option<String>,
+    pub path: PathBuf,
+    /// The range of the whole call, from `@` to the closing parenthesis or
+    /// children block.
+    pub range: Range,
+}
+
+/// An import of the file, keyed by the name it is referred to by.
+struct Import {
+    name: String,
+    path: String,
+}
+
+struct FileIndex {
+    package: Package,
+    imports: Vec<Import>,
+    declarations: Vec<Declaration>,
+    calls: Vec<Call>,
+}
+
+/// The declarations and calls of a set of `.gsx` files.
+pub struct Workspace {
+    parser: Parser,
+    files: BTreeMap<PathBuf, FileIndex>,
+}
+
+impl Default for Workspace {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Workspace {
+    /// Creates an empty workspace.
+    pub fn new() -> Self {
+        let mut parser = Parser::new();
+        parser
+            .set_language(&crate::language())
+            .expect("Error loading GSX grammar");
+        Self {
+            parser,
+            files: BTreeMap::new(),
+        }
+    }
+
+    /// Indexes every `.gsx` file under `root`. Hidden directories such as
+    /// `.git` are skipped and symlinks are not followed.
+    pub fn load(root: impl AsRef<Path>) -> io::Result<Self> {
+        let mut workspace = Self::new();
+        let mut dirs = vec![root.as_ref().to_path_buf()];
+        while let Some(dir) = dirs.pop() {
+            for entry in fs::read_dir(&dir)? {
+                let entry = entry?;
+                let path = entry.path();
+                let file_type = entry.file_type()?;
+                if file_type.is_dir() {
+                    if !entry.file_name().to_string_lossy().starts_with('.') {
+                        dirs.push(path);
+                    }
+                } else if file_type.is_file() && path.extension().is_some_and(|ext| ext == "gsx") {
+                    workspace.reload(&path)?;
+                }
+            }
+        }
+        Ok(workspace)
+    }
+
+    /// Re-reads `path` from disk and re-indexes it, or drops it from the
+    /// index if it no longer exists.
+    pub fn reload(&mut self, path: &Path) -> io::Result<()> {
+        match fs::read_to_string(path) {
+            Ok(source) => {
+                self.update(path, &source);
+                Ok(())
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                self.remove(path);
+                Ok(())
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Indexes `source` as the contents of `path`, replacing what was indexed
+    /// for it before. Only this file is re-parsed.
+    pub fn update(&mut self, path: impl Into<PathBuf>, source: &str) {
+        let path = path.into();
+        let tree = self
+            .parser
+            .parse(source, None)
+            .expect("parser has a language");
+        let index = index_file(&path, tree.root_node(), source);
+        self.files.insert(path, index);
+    }
+
+    /// Drops `path` from the index and reports whether it was indexed.
+    pub fn remove(&mut self, path: &Path) -> bool {
+        self.files.remove(path).is_some()
+    }
+
+    /// Returns the indexed files in path order.
+    pub fn files(&self) -> impl Iterator<Item = &Path> {
+        self.files.keys().map(PathBuf::as_path)
+    }
+
+    /// Returns the package `path` belongs to, if it is indexed.
+    pub fn package(&self, path: &Path) -> Option<&Package> {
+        self.files.get(path).map(|file| &file.package)
+    }
+
+    /// Returns the functions and components named `name` in `package` that
+    /// a call without a receiver can refer to.
+    pub fn definitions<'a>(
+        &'a self,
+        package: &'a Package,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a Declaration> + 'a {
+        self.package_files(package)
+            .flat_map(|file| &file.declarations)
+            .filter(move |decl| decl.name == name && decl.receiver.is_none())
+    }
+
+    /// Returns the declaration `call` refers to.
+    pub fn resolve<'a>(&'a self, call: &'a Call) -> Option<&'a Declaration> {
+        let file = self.files.get(&call.path)?;
+        let Some(qualifier) = &call.qualifier else {
+            return self.definitions(&file.package, &call.name).next();
+        };
+        if let Some(import) = file.imports.iter().find(|import| import.name == *qualifier) {
+            let package = self.imported_package(&import.path)?;
+            return self.definitions(package, &call.name).next();
+        }
+        self.package_files(&file.package)
+            .flat_map(|file| &file.declarations)
+            .find(|decl| {
+                decl.name == call.name
+                    && decl.receiver.as_deref().is_some_and(|receiver| {
+                        call.receiver
+                            .as_deref()
+                            .is_none_or(|call| base_type(call) == base_type(receiver))
+                    })
+            })
+    }
+
+    /// Returns every call, from any package, that resolves to `name` in
+    /// `package`.
+    pub fn callers<'a>(
+        &'a self,
+        package: &'a Package,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a Call> + 'a {
+        self.files
+            .values()
+            .flat_map(|file| &file.calls)
+            .filter(move |call| {
+                call.name == name
+                    && self
+                        .resolve(call)
+                        .is_some_and(|decl| self.package(&decl.path) == Some(package))
+            })
+    }
+
+    /// Returns the calls whose component isn't declared in their package.
+    pub fn unresolved_calls(&self) -> impl Iterator<Item = &Call> {
+        self.files
+            .values()
+            .flat_map(|file| &file.calls)
+            .filter(|call| self.resolve(call).is_none())
+    }
+
+    /// Returns the indexed package an import path refers to: the one whose
+    /// directory shares the most trailing path components with it.
+    fn imported_package(&self, import_path: &str) -> Option<&Package> {
+        let segments: Vec<&str> = import_path.rsplit('/').collect();
+        self.files
+            .values()
+            .map(|file| &file.package)
+            .filter_map(|package| {
+                let matched = package
+                    .dir
+                    .components()
+                    .rev()
+                    .zip(&segments)
+                    .take_while(|(component, segment)| component.as_os_str() == **segment)
+                    .count();
+                (matched > 0).then_some((matched, package))
+            })
+            .max_by_key(|(matched, _)| *matched)
+            .map(|(_, package)| package)
+    }
+
+    fn package_files<'a>(&'a self, package: &'a Package) -> impl Iterator<Item = &'a FileIndex> {
+        self.files
+            .values()
+            .filter(move |file| file.package == *package)
+    }
+}
+
+fn index_file(path: &Path, root: Node, source: &str) -> FileIndex {
+    let text = |node: Node| source[node.byte_range()].to_string();
+    let mut package = Package {
+        dir: path.parent().map(Path::to_path_buf).unwrap_or_default(),
+        name: String::new(),
+    };
+    let mut imports = Vec::new();
+    let mut declarations = Vec::new();
+
+    let file = SourceFile::cast(root).expect("root is a source_file");
+    for item in file.children() {
+        let (kind, name, receiver) = match item {
+            SourceFileChild::PackageClause(clause) => {
+                if let Some(name) = clause.name() {
+                    package.name = text(name.syntax());
+                }
+                continue;
+            }
+            SourceFileChild::ImportSection(section) => {
+                for decl in section.children() {
+                    match decl.child() {
+                        Some(ImportDeclarationKind::ImportSpec(spec)) => {
+                            imports.extend(import(spec, source));
+                        }
+                        Some(ImportDeclarationKind::ImportSpecList(list)) => {
+                            imports.extend(list.children().filter_map(|spec| import(spec, source)));
+                        }
+                        None => {}
+                    }
+                }
+                continue;
+            }
+            SourceFileChild::ComponentDeclaration(decl) => {
+                (DeclarationKind::Component, decl.name(), decl.receiver())
+            }
+            SourceFileChild::FunctionDeclaration(decl) => {
+                (DeclarationKind::Function, decl.name(), decl.receiver())
+            }
+            _ => continue,
+        };
+        let Some(name) = name else {
+            continue;
+        };
+        declarations.push(Declaration {
+            name: text(name.syntax()),
+            kind,
+            receiver: receiver
+                .and_then(|r| r.r#type())
+                .map(|ty| text(ty.syntax())),
+            path: path.to_path_buf(),
+            range: name.syntax().range(),
+        });
+    }
+
+    let mut calls = Vec::new();
+    collect_calls(root, &mut |node| {
+        if let Some(name) = node.child_by_field_name("name") {
+            let qualifier = node.child_by_field_name("qualifier").map(text);
+            let receiver = qualifier
+                .as_deref()
+                .and_then(|qualifier| enclosing_receiver(node, qualifier, source));
+            calls.push(Call {
+                name: text(name),
+                qualifier,
+                receiver,
+                path: path.to_path_buf(),
+                range: node.range(),
+            });
+        }
+    });
+
+    FileIndex {
+        package,
+        imports,
+        declarations,
+        calls,
+    }
+}
+
+/// Returns the name an import is referred to by and its path. Blank imports
+/// can't qualify a call and are skipped.
+fn import(spec: ImportSpec, source: &str) -> Option<Import> {
+    let path = &source[spec.path()?.syntax().byte_range()];
+    let path = path.trim_matches(|c| c == '"' || c == '`').to_string();
+    let name = match spec.alias() {
+        Some(alias) => source[alias.syntax().byte_range()].to_string(),
+        None => path.rsplit('/').next().unwrap_or_default().to_string(),
+    };
+    (name != "_" && !name.is_empty()).then_some(Import { name, path })
+}
+
+/// Returns the receiver type of the method component around `node` if its
+/// receiver is named `qualifier`.
+fn enclosing_receiver(node: Node, qualifier: &str, source: &str) -> Option<String> {
+    let mut decl = node.parent()?;
+    while decl.kind() != "component_declaration" {
+        decl = decl.parent()?;
+    }
+    let receiver = decl.child_by_field_name("receiver")?;
+    let name = receiver.child_by_field_name("name")?;
+    if &source[name.byte_range()] != qualifier {
+        return None;
+    }
+    let ty = receiver.child_by_field_name("type")?;
+    Some(source[ty.byte_range()].to_string())
+}
+
+/// Returns the type a receiver type is declared on, without the pointer or
+/// type arguments: `Page` for `*Page` and `List` for `*List[T]`.
+fn base_type(receiver: &str) -> &str {
+    let ty = receiver.trim_start_matches(|c: char| c == '*' || c.is_whitespace());
+    ty.split('[').next().unwrap_or(ty).trim_end()
+}
+
+fn collect_calls<'tree>(node: Node<'tree>, f: &mut impl FnMut(Node<'tree>)) {
+    if node.kind() == "component_call" {
+        f(node);
+    }
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect_calls(child, f);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names<'a>(calls: impl Iterator<Item = &'a Call>) -> Vec<(String, String)> {
+        calls
+            .map(|call| (call.path.display().to_string(), call.name.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_resolution_by_package() {
+        let mut workspace = Workspace::new();
+        workspace.update(
+            "ui/header.gsx",
+            "package ui\n\ntempl Header() {\n\t@Title()\n}\n\ntempl Title() {\n\t<hr />\n}\n",
+        );
+        workspace.update(
+            "ui/page.gsx",
+            "package ui\n\ntempl Page() {\n\t@Header()\n\t@Render()\n}\n\ntempl (s *Sidebar) Render() {\n\t@Header()\n}\n",
+        );
+        workspace.update(
+            "admin/page.gsx",
+            "package admin\n\nfunc Header() {}\n\ntempl Page() {\n\t@Header()\n\t@Title()\n}\n",
+        );
+
+        let ui = workspace.package(Path::new("ui/page.gsx")).unwrap().clone();
+        assert_eq!(ui.name, "ui");
+        let header: Vec<_> = workspace.definitions(&ui, "Header").collect();
+        assert_eq!(header.len(), 1);
+        assert_eq!(header[0].path, Path::new("ui/header.gsx"));
+        assert_eq!(header[0].kind, DeclarationKind::Component);
+        assert_eq!(header[0].range.start_point.row, 2);
+
+        assert_eq!(
+            names(workspace.callers(&ui, "Header")),
+            [
+                ("ui/page.gsx".to_string(), "Header".to_string()),
+                ("ui/page.gsx".to_string(), "Header".to_string()),
+            ]
+        );
+
+        // Methods need a receiver, and each package has its own namespace.
+        assert_eq!(
+            names(workspace.unresolved_calls()),
+            [
+                ("admin/page.gsx".to_string(), "Title".to_string()),
+                ("ui/page.gsx".to_string(), "Render".to_string()),
+            ]
+        );
+        let admin = workspace.package(Path::new("admin/page.gsx")).unwrap();
+        let header = workspace.definitions(admin, "Header").next().unwrap();
+        assert_eq!(header.kind, DeclarationKind::Function);
+    }
+
+    #[test]
+    fn test_qualified_calls() {
+        let mut workspace = Workspace::new();
+        workspace.update(
+            "app/layout/sidebar.gsx",
+            "package layout\n\ntempl Sidebar() {\n\t<hr />\n}\n",
+        );
+        workspace.update(
+            "app/ui/page.gsx",
+            concat!(
+                "package ui\n\n",
+                "import (\n\t\"example.com/app/layout\"\n\tw \"example.com/app/widgets\"\n)\n\n",
+                "templ (p *Page) Render() {\n\t@layout.Sidebar()\n\t@p.Header()\n\t@w.Button()\n}\n\n",
+                "templ (p Page) Header() {\n\t<hr />\n}\n\n",
+                "templ (c *Card) Header() {\n\t<hr />\n}\n\n",
+                "templ (l *List[T]) Render() {\n\t@l.Row()\n}\n\n",
+                "templ (l List[T]) Row() {\n\t<hr />\n}\n",
+            ),
+        );
+
+        let calls: Vec<_> = workspace.files[Path::new("app/ui/page.gsx")]
+            .calls
+            .iter()
+            .map(|call| {
+                (
+                    call.qualifier.as_deref(),
+                    call.name.as_str(),
+                    call.receiver.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            calls,
+            [
+                (Some("layout"), "Sidebar", None),
+                (Some("p"), "Header", Some("*Page")),
+                (Some("w"), "Button", None),
+                (Some("l"), "Row", Some("*List[T]")),
+            ]
+        );
+
+        let layout = workspace
+            .package(Path::new("app/layout/sidebar.gsx"))
+            .unwrap();
+        let callers: Vec<_> = workspace.callers(layout, "Sidebar").collect();
+        assert_eq!(callers.len(), 1);
+        assert_eq!(
+            workspace.resolve(callers[0]).unwrap().path,
+            Path::new("app/layout/sidebar.gsx")
+        );
+
+        let ui = workspace.package(Path::new("app/ui/page.gsx")).unwrap();
+        let header = workspace.callers(ui, "Header").next().unwrap();
+        let decl = workspace.resolve(header).unwrap();
+        // A pointer receiver calls a method on the value type.
+        assert_eq!(decl.receiver.as_deref(), Some("Page"));
+        assert_eq!(decl.range.start_point.row, 13);
+        let row = workspace.callers(ui, "Row").next().unwrap();
+        assert_eq!(
+            workspace.resolve(row).unwrap().receiver.as_deref(),
+            Some("List[T]")
+        );
+
+        // The widgets package isn't indexed.
+        assert_eq!(
+            names(workspace.unresolved_calls()),
+            [("app/ui/page.gsx".to_string(), "Button".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_incremental_update() {
+        let mut workspace = Workspace::new();
+        workspace.update("a.gsx", "package ui\n\ntempl A() {\n\t@B()\n}\n");
+        assert_eq!(workspace.unresolved_calls().count(), 1);
+
+        workspace.update("b.gsx", "package ui\n\ntempl B() {\n\t<hr />\n}\n");
+        assert_eq!(workspace.unresolved_calls().count(), 0);
+
+        workspace.update("b.gsx", "package ui\n\ntempl C() {\n\t<hr />\n}\n");
+        assert_eq!(workspace.unresolved_calls().count(), 1);
+
+        workspace.update("b.gsx", "package ui\n\ntempl B() {\n\t<hr />\n}\n");
+        assert!(workspace.remove(Path::new("b.gsx")));
+        assert_eq!(workspace.unresolved_calls().count(), 1);
+        assert_eq!(workspace.files().collect::<Vec<_>>(), [Path::new("a.gsx")]);
+    }
+
+    #[test]
+    fn test_load() {
+        let root = std::env::temp_dir().join(format!("gsx-workspace-{}", std::process::id()));
+        fs::create_dir_all(root.join("ui")).unwrap();
+        fs::create_dir_all(root.join(".cache")).unwrap();
+        fs::write(
+            root.join("ui/a.gsx"),
+            "package ui\n\ntempl A() {\n\t@B()\n}\n",
+        )
+        .unwrap();
+        fs::write(root.join("ui/b.gsx"), "package ui\n\ntempl B() {}\n").unwrap();
+        fs::write(root.join("ui/b.go"), "package ui\n").unwrap();
+        fs::write(root.join(".cache/c.gsx"), "package ui\n").unwrap();
+
+        let mut workspace = Workspace::load(&root).unwrap();
+        assert_eq!(
+            workspace.files().collect::<Vec<_>>(),
+            [root.join("ui/a.gsx"), root.join("ui/b.gsx")]
+        );
+        assert_eq!(workspace.unresolved_calls().count(), 0);
+
+        fs::remove_file(root.join("ui/b.gsx")).unwrap();
+        workspace.reload(&root.join("ui/b.gsx")).unwrap();
+        assert_eq!(workspace.unresolved_calls().count(), 1);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -2,11 +2,40 @@
  * Tree-sitter grammar for GSX DSL (.gsx files)
  */
 
+// Go operator precedences, from the Go spec. Primary expressions (calls,
+// selectors, indexing) bind tightest. Composite literals are below zero so
+// that `if x {` reads the brace as the body, as in Go.
+const PREC = {
+  primary: 7,
+  unary: 6,
+  multiplicative: 5,
+  additive: 4,
+  comparative: 3,
+  and: 2,
+  or: 1,
+  composite_literal: -1,
+};
+
+const multiplicativeOperators = ["*", "/", "%", "<<", ">>", "&", "&^"];
+const additiveOperators = ["+", "-", "|", "^"];
+const comparativeOperators = ["==", "!=", "<", "<=", ">", ">="];
+
 module.exports = grammar({
   name: "gsx",
 
   extras: ($) => [/\s/, $.comment],
 
+  // Go string literals and element text need escape handling that a regex
+  // can't express cleanly, and a Go statement ends at a newline, which the
+  // extras skip; src/scanner.c tokenises them.
+  externals: ($) => [
+    $.string,
+    $.raw_string,
+    $.rune,
+    $.text_content,
+    $.go_statement,
+  ],
+
   word: ($) => $.identifier,
 
   // state_declaration (name := call_expression) overlaps with _expression since
@@ -14,7 +43,15 @@ module.exports = grammar({
   // this by preferring the longer match (state_declaration) when both apply.
   // This is intentional: inside a component body, `x := tui.NewState(0)` should
   // parse as a state_declaration, not as an expression.
-  conflicts: ($) => [[$.state_declaration, $._expression], [$.func_type]],
+  conflicts: ($) => [
+    [$.state_declaration, $._expression],
+    [$.func_type],
+    [$.parameter, $.type_expression],
+    [$.type_expression, $.generic_type],
+    [$.generic_type, $._expression],
+    [$.type_expression, $._expression],
+    [$.qualified_type, $._expression],
+  ],
 
   rules: {
     source_file: ($) =>
@@ -47,12 +84,14 @@ module.exports = grammar({
 
     // Component declaration: supports both function and method forms
     //   templ Name(params) { body }
+    //   templ List[T any](params) { body }
     //   templ (s *Type) Render() { body }
     component_declaration: ($) =>
       choice(
         seq(
           "templ",
           field("name", $.identifier),
+          optional(field("type_parameters", $.type_parameter_list)),
           field("parameters", $.parameter_list),
           field("body", $.component_body),
         ),
@@ -75,17 +114,67 @@ module.exports = grammar({
         ")",
       ),
 
+    // Type parameters: [T any], [K comparable, V any], [T, U ~int | ~string]
+    type_parameter_list: ($) =>
+      seq(
+        "[",
+        $.type_parameter_declaration,
+        repeat(seq(",", $.type_parameter_declaration)),
+        optional(","),
+        "]",
+      ),
+
+    type_parameter_declaration: ($) =>
+      seq(
+        field("name", $.identifier),
+        repeat(seq(",", field("name", $.identifier))),
+        field("constraint", $.type_constraint),
+      ),
+
+    // A union of type terms; `any`, `comparable` and interfaces like
+    // fmt.Stringer are single-term constraints
+    type_constraint: ($) =>
+      seq(
+        choice($.type_expression, $.underlying_type),
+        repeat(seq("|", choice($.type_expression, $.underlying_type))),
+      ),
+
+    // ~T: any type whose underlying type is T
+    underlying_type: ($) => seq("~", $.type_expression),
+
     parameter_list: ($) =>
       seq(
         "(",
         optional(
-          seq($.parameter, repeat(seq(",", $.parameter)), optional(",")),
+          seq(
+            choice($.parameter, $.variadic_parameter),
+            repeat(seq(",", choice($.parameter, $.variadic_parameter))),
+            optional(","),
+          ),
         ),
         ")",
       ),
 
+    // `label string`, grouped `label, value string`, or an unnamed `string`
+    // as in func types. `(a, b int)` is ambiguous until the type is seen;
+    // the unnamed form loses so that the names stay grouped.
     parameter: ($) =>
-      seq(field("name", $.identifier), field("type", $.type_expression)),
+      choice(
+        seq(
+          field("name", $.identifier),
+          repeat(seq(",", field("name", $.identifier))),
+          field("type", $.type_expression),
+        ),
+        prec.dynamic(-1, field("type", $.type_expression)),
+      ),
+
+    // opts ...Option
+    variadic_parameter: ($) =>
+      seq(
+        optional(field("name", $.identifier)),
+        "...",
+        field("type", $.type_expression),
+      ),
 
     type_expression: ($) =>
       choice(
@@ -96,6 +185,10 @@ module.exports = grammar({
         $.generic_type,
         $.map_type,
         $.func_type,
+        $.array_type,
+        $.channel_type,
+        $.struct_type,
+        $.interface_type,
       ),
 
     qualified_type: ($) => seq($.identifier, ".", $.identifier),
@@ -103,15 +196,44 @@ module.exports = grammar({
     pointer_type: ($) => seq("*", $.type_expression),
     // Map type: map[K]V
     map_type: ($) => seq("map", "[", $.type_expression, "]", $.type_expression),
-    // Function type: func(), func(T), func(T1, T2) R, func() (R1, R2)
+    // Function type: func(), func(T), func(T1, T2) R, func(name T) (R1, R2)
     func_type: ($) =>
       seq(
         "func",
-        "(",
-        optional(seq($.type_expression, repeat(seq(",", $.type_expression)))),
-        ")",
+        field("parameters", $.parameter_list),
         optional($.return_type),
       ),
+    // Array type: [N]T
+    array_type: ($) =>
+      seq("[", field("length", $._expression), "]", $.type_expression),
+    // Channel type: chan T, chan<- T, <-chan T. As in Go, `chan<- chan T`
+    // is a send-only channel of `chan T`.
+    channel_type: ($) =>
+      choice(
+        prec.right(1, seq("chan", $.type_expression)),
+        prec.right(seq("chan", "<-", $.type_expression)),
+        prec.right(seq("<-", "chan", $.type_expression)),
+      ),
+    // Anonymous struct type: struct{}, struct{ name string }
+    struct_type: ($) => seq("struct", $.struct_body),
+    // Interface type: interface{}, interface{ String() string }, or a
+    // constraint like interface{ ~int | ~string }
+    interface_type: ($) =>
+      seq(
+        "interface",
+        "{",
+        repeat(choice($.method_spec, $.type_constraint)),
+        "}",
+      ),
+
+    method_spec: ($) =>
+      prec.right(
+        seq(
+          field("name", $.identifier),
+          field("parameters", $.parameter_list),
+          optional(field("return_type", $.return_type)),
+        ),
+      ),
     // Generic type: Type[T] or pkg.Type[T]
     generic_type: ($) =>
       seq(
@@ -137,8 +259,13 @@ module.exports = grammar({
 
     struct_body: ($) => seq("{", repeat($.struct_field), "}"),
 
+    // One or more names sharing a type: name string, or r, g, b int
     struct_field: ($) =>
-      seq(field("name", $.identifier), field("type", $.type_expression)),
+      seq(
+        field("name", $.identifier),
+        repeat(seq(",", field("name", $.identifier))),
+        field("type", $.type_expression),
+      ),
 
     component_body: ($) => seq("{", repeat($._child), "}"),
 
@@ -147,11 +274,16 @@ module.exports = grammar({
         $.element,
         $.for_statement,
         $.if_statement,
+        $.switch_statement,
         $.let_binding,
         $.state_declaration,
         $.component_call,
         $.children_slot,
         $.go_expression,
+        // A plain Go statement, kept as opaque text the way tuigen keeps
+        // its GoCode: `x := 1`, `fmt.Println(x)`, `for i := 0; i < n; i++ {}`.
+        // The scanner ends it before a `;`, which may follow.
+        seq($.go_statement, optional(";")),
       ),
 
     // Elements
@@ -168,7 +300,7 @@ module.exports = grammar({
         ">",
         repeat($._element_child),
         "</",
-        $.identifier,
+        field("closing_tag", $.identifier),
         ">",
       ),
 
@@ -177,6 +309,7 @@ module.exports = grammar({
         $.element,
         $.for_statement,
         $.if_statement,
+        $.switch_statement,
         $.let_binding,
         $.component_call,
         $.children_slot,
@@ -184,15 +317,14 @@ module.exports = grammar({
         $.text_content,
       ),
 
-    text_content: ($) => /[^<>{}@\s][^<>{}@\n]*/,
-
     attribute: ($) =>
       seq(
         field("name", $.identifier),
         optional(seq("=", field("value", $._attribute_value))),
       ),
 
-    _attribute_value: ($) => choice($.string, $.go_expression, $.number),
+    _attribute_value: ($) =>
+      choice($.string, $.raw_string, $.go_expression, $.number),
 
     // Control flow
     for_statement: ($) =>
@@ -217,6 +349,28 @@ module.exports = grammar({
         ),
       ),
 
+    // switch with an optional tag; each clause holds body nodes, which may
+    // be Go statements
+    switch_statement: ($) =>
+      seq(
+        "switch",
+        optional(field("value", $._expression)),
+        "{",
+        repeat(choice($.case_clause, $.default_clause)),
+        "}",
+      ),
+
+    case_clause: ($) =>
+      seq(
+        "case",
+        field("value", $._expression),
+        repeat(seq(",", field("value", $._expression))),
+        ":",
+        repeat($._child),
+      ),
+
+    default_clause: ($) => seq("default", ":", repeat($._child)),
+
     let_binding: ($) =>
       choice(
         // := syntax
@@ -243,6 +397,8 @@ module.exports = grammar({
       ),
 
     // Component call: @Name(args) or @Name(args) { children }
+    // The callee may be qualified by a package or receiver: @pkg.Card(),
+    // @s.Render().
     // prec(1) ensures that a '{' after the argument list is parsed as a
     // children block rather than a separate go_expression sibling.
     component_call: ($) =>
@@ -250,6 +406,7 @@ module.exports = grammar({
         1,
         seq(
           "@",
+          optional(seq(field("qualifier", $.identifier), ".")),
           field("name", $.identifier),
           field("arguments", $.argument_list),
           optional(field("children", $.block)),
@@ -269,7 +426,8 @@ module.exports = grammar({
       repeat1(
         choice(
           $.go_string_literal, // Handle string literals so braces inside them don't confuse parsing
-          /[^{}"'`]+/, // Non-brace, non-quote content
+          /[^{}"'`/]+/, // Non-brace, non-quote content
+          "/", // A slash that doesn't start a comment
           $.nested_braces,
         ),
       ),
@@ -287,68 +445,237 @@ module.exports = grammar({
     nested_braces: ($) =>
       seq(
         "{",
-        repeat(choice($.go_string_literal, /[^{}"'`]+/, $.nested_braces)),
+        repeat(choice($.go_string_literal, /[^{}"'`/]+/, "/", $.nested_braces)),
         "}",
       ),
 
-    // Expressions (simplified)
+    // Go expressions
     _expression: ($) =>
       choice(
         $.identifier,
         $.number,
         $.string,
+        $.raw_string,
+        $.rune,
         $.true,
         $.false,
+        $.unary_expression,
         $.binary_expression,
         $.call_expression,
         $.selector_expression,
+        $.index_expression,
+        $.slice_expression,
+        $.type_assertion_expression,
+        $.composite_literal,
+        $.func_literal,
         $.parenthesized_expression,
       ),
 
-    binary_expression: ($) =>
-      prec.left(
-        1,
+    unary_expression: ($) =>
+      prec(
+        PREC.unary,
+        seq(
+          field("operator", choice("+", "-", "!", "^", "*", "&", "<-")),
+          field("operand", $._expression),
+        ),
+      ),
+
+    binary_expression: ($) => {
+      const table = [
+        [PREC.multiplicative, choice(...multiplicativeOperators)],
+        [PREC.additive, choice(...additiveOperators)],
+        [PREC.comparative, choice(...comparativeOperators)],
+        [PREC.and, "&&"],
+        [PREC.or, "||"],
+      ];
+      return choice(
+        ...table.map(([precedence, operator]) =>
+          prec.left(
+            precedence,
+            seq(
+              field("left", $._expression),
+              field("operator", operator),
+              field("right", $._expression),
+            ),
+          ),
+        ),
+      );
+    },
+
+    call_expression: ($) =>
+      prec(
+        PREC.primary,
+        seq(
+          field("function", $._expression),
+          field("arguments", $.argument_list),
+        ),
+      ),
+
+    selector_expression: ($) =>
+      prec(
+        PREC.primary,
+        seq(
+          field("operand", $._expression),
+          ".",
+          field("field", $.identifier),
+        ),
+      ),
+
+    index_expression: ($) =>
+      prec(
+        PREC.primary,
         seq(
-          $._expression,
+          field("operand", $._expression),
+          "[",
+          field("index", $._expression),
+          "]",
+        ),
+      ),
+
+    // s[lo:hi] and s[lo:hi:max]; every bound of the two-index form is
+    // optional
+    slice_expression: ($) =>
+      prec(
+        PREC.primary,
+        seq(
+          field("operand", $._expression),
+          "[",
           choice(
-            "==",
-            "!=",
-            "<",
-            ">",
-            "<=",
-            ">=",
-            "+",
-            "-",
-            "*",
-            "/",
-            "&&",
-            "||",
+            seq(
+              optional(field("start", $._expression)),
+              ":",
+              optional(field("end", $._expression)),
+            ),
+            seq(
+              optional(field("start", $._expression)),
+              ":",
+              field("end", $._expression),
+              ":",
+              field("capacity", $._expression),
+            ),
           ),
-          $._expression,
+          "]",
         ),
       ),
 
-    call_expression: ($) =>
+    // x.(T)
+    type_assertion_expression: ($) =>
       prec(
-        2,
-        seq(choice($.identifier, $.selector_expression), $.argument_list),
+        PREC.primary,
+        seq(
+          field("operand", $._expression),
+          ".",
+          "(",
+          field("type", $.type_expression),
+          ")",
+        ),
       ),
 
-    selector_expression: ($) => prec(3, seq($._expression, ".", $.identifier)),
+    // Item{Name: n}, tui.Style{}, []int{1, 2}, map[string]int{"a": 1}
+    composite_literal: ($) =>
+      prec(
+        PREC.composite_literal,
+        seq(
+          field(
+            "type",
+            choice(
+              $.identifier,
+              $.selector_expression,
+              $.slice_type,
+              $.map_type,
+            ),
+          ),
+          field("body", $.literal_value),
+        ),
+      ),
+
+    literal_value: ($) =>
+      seq(
+        "{",
+        optional(
+          seq(
+            choice($.literal_element, $.keyed_element),
+            repeat(seq(",", choice($.literal_element, $.keyed_element))),
+            optional(","),
+          ),
+        ),
+        "}",
+      ),
+
+    literal_element: ($) => choice($._expression, $.literal_value),
+
+    keyed_element: ($) =>
+      seq(
+        field("key", $.literal_element),
+        ":",
+        field("value", $.literal_element),
+      ),
+
+    // func(x int) bool { return x > 0 }
+    func_literal: ($) =>
+      seq(
+        "func",
+        field("parameters", $.parameter_list),
+        optional(field("return_type", $.return_type)),
+        field("body", $.function_body),
+      ),
 
     parenthesized_expression: ($) => seq("(", $._expression, ")"),
 
+    // The last argument may be spread with `...`; make and new take a type
+    // as their first argument.
     argument_list: ($) =>
       seq(
         "(",
-        optional(seq($._expression, repeat(seq(",", $._expression)))),
+        optional(
+          seq(
+            choice(
+              // A lone spread: @List(items...)
+              $.variadic_argument,
+              seq(
+                choice($._expression, $.slice_type, $.map_type),
+                repeat(seq(",", $._expression)),
+                optional(seq(",", $.variadic_argument)),
+              ),
+            ),
+            optional(","),
+          ),
+        ),
         ")",
       ),
 
+    variadic_argument: ($) => seq($._expression, "..."),
+
     // Literals
     identifier: ($) => /[a-zA-Z_][a-zA-Z0-9_]*/,
-    number: ($) => /\d+(\.\d+)?/,
-    string: ($) => /"[^"]*"/,
+    // Go's integer, floating-point and imaginary literals: 42, 1_000, 0x1F,
+    // 0o755, 0b101, 1.5, .5, 1e9, 0x1p-2, 2i
+    number: ($) => {
+      const decimalDigits = /[0-9](_?[0-9])*/;
+      const hexDigits = /[0-9a-fA-F](_?[0-9a-fA-F])*/;
+      const decimalExponent = seq(/[eE][+-]?/, decimalDigits);
+      const int = choice(
+        seq(/0[xX]_?/, hexDigits),
+        seq(/0[oO]?_?/, /[0-7](_?[0-7])*/),
+        seq(/0[bB]_?/, /[01](_?[01])*/),
+        /0|[1-9](_?[0-9])*/,
+      );
+      const float = choice(
+        seq(decimalDigits, ".", optional(decimalDigits), optional(decimalExponent)),
+        seq(decimalDigits, decimalExponent),
+        seq(".", decimalDigits, optional(decimalExponent)),
+        seq(
+          /0[xX]/,
+          choice(
+            seq(optional("_"), hexDigits, optional(seq(".", optional(hexDigits)))),
+            seq(".", hexDigits),
+          ),
+          /[pP][+-]?/,
+          decimalDigits,
+        ),
+      );
+      return token(seq(choice(int, float, decimalDigits), optional("i")));
+    },
     true: ($) => "true",
     false: ($) => "false",
 
@@ -372,6 +699,7 @@ module.exports = grammar({
         seq(
           "func",
           field("name", $.identifier),
+          optional(field("type_parameters", $.type_parameter_list)),
           field("parameters", $.parameter_list),
           optional(field("return_type", $.return_type)),
           field("body", $.function_body),
@@ -399,7 +727,14 @@ module.exports = grammar({
           field("keyword", choice("type", "var", "const")),
           field(
             "preamble",
-            repeat1(choice($.identifier, /[^\sa-zA-Z_0-9{()"'`\n]+/)),
+            repeat1(
+              choice(
+                $.identifier,
+                /[0-9][0-9a-zA-Z_.]*/,
+                $.go_string_literal,
+                /[^\sa-zA-Z_0-9{()"'`\n]+/,
+              ),
+            ),
           ),
           field("body", $.go_brace_body),
         ),
@@ -408,12 +743,19 @@ module.exports = grammar({
           field("keyword", choice("type", "var", "const")),
           field("body", $.go_paren_body),
         ),
-        // Single-line form: var _ tui.Component = (*foo)(nil)
+        // Single-line form: var _ tui.Component = (*foo)(nil), const max = 500
         seq(
           field("keyword", choice("type", "var", "const")),
           field(
             "preamble",
-            repeat1(choice($.identifier, /[^\sa-zA-Z_0-9{()"'`\n]+/)),
+            repeat1(
+              choice(
+                $.identifier,
+                /[0-9][0-9a-zA-Z_.]*/,
+                $.go_string_literal,
+                /[^\sa-zA-Z_0-9{()"'`\n]+/,
+              ),
+            ),
           ),
           // Parenthesized expressions in single-line form
           optional(seq("(", /[^)]*/, ")")),
@@ -428,14 +770,14 @@ module.exports = grammar({
     go_paren_body: ($) =>
       seq(
         "(",
-        repeat(choice($.go_string_literal, /[^()"'`]+/, $.nested_parens)),
+        repeat(choice($.go_string_literal, /[^()"'`/]+/, "/", $.nested_parens)),
         ")",
       ),
 
     nested_parens: ($) =>
       seq(
         "(",
-        repeat(choice($.go_string_literal, /[^()"'`]+/, $.nested_parens)),
+        repeat(choice($.go_string_literal, /[^()"'`/]+/, "/", $.nested_parens)),
         ")",
       ),
 
@@ -444,7 +786,7 @@ module.exports = grammar({
     // Named node for Go code content inside function/brace bodies.
     // Having a named node enables Go language injection for syntax highlighting.
     go_code_content: ($) =>
-      repeat1(choice($.go_string_literal, /[^{}"'`]+/, $.nested_braces)),
+      repeat1(choice($.go_string_literal, /[^{}"'`/]+/, "/", $.nested_braces)),
 
     // Comments (for explicit use in the AST)
     comment: ($) => choice(/\/\/.*/, /\/\*[^*]*\*+([^/*][^*]*\*+)*\//),
@@ -14,6 +14,9 @@
 "for" @keyword.repeat
 "if" @keyword.conditional
 "else" @keyword.conditional
+"switch" @keyword.conditional
+"case" @keyword.conditional
+"default" @keyword.conditional
 
 ; Function keyword
 "func" @keyword.function
@@ -45,6 +48,11 @@
   "@" @punctuation.special
   name: (identifier) @function.call)
 
+; Package or receiver of a qualified component call: @pkg.Card(), @s.Render()
+(component_call
+  qualifier: (identifier) @module
+  "." @punctuation.delimiter)
+
 ; ====================
 ; Elements (Tags)
 ; ====================
@@ -58,7 +66,7 @@
 
 ; Closing tags
 (element_with_children
-  (identifier) @tag)
+  closing_tag: (identifier) @tag)
 
 ; Tag delimiters (context-specific to avoid operator conflicts)
 (self_closing_element "<" @tag.delimiter)
@@ -87,6 +95,10 @@
 ; Struct keyword
 "struct" @keyword
 
+; Type keywords
+"interface" @keyword
+"chan" @keyword
+
 ; Struct field name and type
 (struct_field
   name: (identifier) @property.definition)
@@ -115,12 +127,11 @@
 
 ; Function calls in expressions
 (call_expression
-  (identifier) @function.call)
+  function: (identifier) @function.call)
 
 (call_expression
-  (selector_expression
-    (_)
-    (identifier) @function.method.call))
+  function: (selector_expression
+    field: (identifier) @function.method.call))
 
 ; ====================
 ; Types
@@ -144,6 +155,17 @@
   "[" @punctuation.bracket
   "]" @punctuation.bracket)
 
+(array_type
+  "[" @punctuation.bracket
+  "]" @punctuation.bracket)
+
+(channel_type
+  "<-" @operator)
+
+; Interface methods: interface{ String() string }
+(method_spec
+  name: (identifier) @function.method)
+
 ; ====================
 ; Variables & Parameters
 ; ====================
@@ -152,6 +174,22 @@
 (parameter
   name: (identifier) @variable.parameter)
 
+(variadic_parameter
+  name: (identifier) @variable.parameter)
+
+(variadic_parameter
+  "..." @operator)
+
+; Type parameter names and constraints: [T any, N ~int | ~float64]
+(type_parameter_declaration
+  name: (identifier) @type.definition)
+
+(underlying_type
+  "~" @operator)
+
+(type_constraint
+  "|" @operator)
+
 ; For loop variables
 (for_clause
   index: (identifier) @variable)
@@ -173,8 +211,12 @@
 
 ; Selector expressions
 (selector_expression
-  (identifier) @variable
-  (identifier) @property)
+  field: (identifier) @property)
+
+; Struct literal field names
+(keyed_element
+  key: (literal_element
+    (identifier) @property))
 
 ; Generic identifiers
 (identifier) @variable
@@ -185,6 +227,8 @@
 
 ; Strings
 (string) @string
+(raw_string) @string
+(rune) @character
 
 ; Numbers
 (number) @number
@@ -201,19 +245,16 @@
 ":=" @operator
 "=" @operator
 
-; Comparison and arithmetic operators (context-specific)
-(binary_expression "<" @operator)
-(binary_expression ">" @operator)
-(binary_expression "<=" @operator)
-(binary_expression ">=" @operator)
-(binary_expression "*" @operator)
-(binary_expression "/" @operator)
-(binary_expression "+" @operator)
-(binary_expression "-" @operator)
-(binary_expression "==" @operator)
-(binary_expression "!=" @operator)
-(binary_expression "&&" @operator)
-(binary_expression "||" @operator)
+; Expression operators (context-specific, since "<", ">" and "*" also
+; appear in tags and types)
+(unary_expression
+  operator: _ @operator)
+
+(binary_expression
+  operator: _ @operator)
+
+(variadic_argument
+  "..." @operator)
 
 ; ====================
 ; Punctuation
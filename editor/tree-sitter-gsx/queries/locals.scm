@@ -0,0 +1,115 @@
+; Tree-sitter locals queries for GSX DSL
+; This file defines scopes, local definitions and references to them
+
+; ====================
+; Scopes
+; ====================
+
+; A component declaration scopes its receiver and parameters, so they don't
+; leak into other components
+(component_declaration) @local.scope
+
+; Function parameters and type parameters belong to the function
+(function_declaration) @local.scope
+
+(component_body) @local.scope
+(block) @local.scope
+
+; The loop variables are visible in the loop body only
+(for_statement) @local.scope
+
+; Bindings in one switch clause are not visible in the next
+(case_clause) @local.scope
+(default_clause) @local.scope
+
+; Function literal parameters don't leak into the surrounding component
+(func_literal) @local.scope
+
+; ====================
+; Definitions
+; ====================
+
+(receiver
+  name: (identifier) @local.definition)
+
+(parameter
+  name: (identifier) @local.definition)
+
+(variadic_parameter
+  name: (identifier) @local.definition)
+
+(type_parameter_declaration
+  name: (identifier) @local.definition)
+
+(for_clause
+  index: (identifier) @local.definition)
+
+(for_clause
+  value: (identifier) @local.definition)
+
+(let_binding
+  name: (identifier) @local.definition)
+
+(state_declaration
+  name: (identifier) @local.definition)
+
+; ====================
+; References
+; ====================
+
+; Identifiers in expression position. Tag names, attribute names, types and
+; selected fields are not references to locals.
+(for_clause
+  collection: (identifier) @local.reference)
+
+(if_statement
+  condition: (identifier) @local.reference)
+
+(switch_statement
+  value: (identifier) @local.reference)
+
+(case_clause
+  value: (identifier) @local.reference)
+
+(unary_expression
+  operand: (identifier) @local.reference)
+
+(binary_expression
+  (identifier) @local.reference)
+
+(parenthesized_expression
+  (identifier) @local.reference)
+
+(argument_list
+  (identifier) @local.reference)
+
+(variadic_argument
+  (identifier) @local.reference)
+
+(call_expression
+  function: (identifier) @local.reference)
+
+(selector_expression
+  operand: (identifier) @local.reference)
+
+(index_expression
+  (identifier) @local.reference)
+
+(slice_expression
+  (identifier) @local.reference)
+
+(type_assertion_expression
+  operand: (identifier) @local.reference)
+
+; Array lengths are expressions: [size]string
+(array_type
+  length: (identifier) @local.reference)
+
+; Keys of struct literals name fields, so only values are references
+(keyed_element
+  value: (literal_element
+    (identifier) @local.reference))
+
+(literal_value
+  (literal_element
+    (identifier) @local.reference))
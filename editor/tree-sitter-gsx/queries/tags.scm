@@ -0,0 +1,71 @@
+; Tree-sitter tags queries for GSX DSL
+; This file marks definitions and references for code navigation
+
+; ====================
+; Components
+; ====================
+
+(
+  (comment)* @doc
+  .
+  (component_declaration
+    !receiver
+    name: (identifier) @name) @definition.function
+  (#strip! @doc "^//\\s*")
+  (#select-adjacent! @doc @definition.function)
+)
+
+; Method components: templ (s *Sidebar) Render()
+(
+  (comment)* @doc
+  .
+  (component_declaration
+    receiver: (receiver)
+    name: (identifier) @name) @definition.method
+  (#strip! @doc "^//\\s*")
+  (#select-adjacent! @doc @definition.method)
+)
+
+; ====================
+; Functions
+; ====================
+
+(
+  (comment)* @doc
+  .
+  (function_declaration
+    !receiver
+    name: (identifier) @name) @definition.function
+  (#strip! @doc "^//\\s*")
+  (#select-adjacent! @doc @definition.function)
+)
+
+(
+  (comment)* @doc
+  .
+  (function_declaration
+    receiver: (receiver)
+    name: (identifier) @name) @definition.method
+  (#strip! @doc "^//\\s*")
+  (#select-adjacent! @doc @definition.method)
+)
+
+; ====================
+; Types
+; ====================
+
+(
+  (comment)* @doc
+  .
+  (type_struct_declaration
+    name: (identifier) @name) @definition.type
+  (#strip! @doc "^//\\s*")
+  (#select-adjacent! @doc @definition.type)
+)
+
+; ====================
+; References
+; ====================
+
+(component_call
+  name: (identifier) @name) @reference.call
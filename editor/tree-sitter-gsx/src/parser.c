@@ -7,16 +7,16 @@
 #endif
 
 #define LANGUAGE_VERSION 15
-#define STATE_COUNT 332
+#define STATE_COUNT 602
 #define LARGE_STATE_COUNT 2
-#define SYMBOL_COUNT 124
+#define SYMBOL_COUNT 175
 #define ALIAS_COUNT 0
-#define TOKEN_COUNT 56
-#define EXTERNAL_TOKEN_COUNT 0
-#define FIELD_COUNT 21
+#define TOKEN_COUNT 77
+#define EXTERNAL_TOKEN_COUNT 5
+#define FIELD_COUNT 36
 #define MAX_ALIAS_SEQUENCE_LENGTH 8
 #define MAX_RESERVED_WORD_SET_SIZE 0
-#define PRODUCTION_ID_COUNT 27
+#define PRODUCTION_ID_COUNT 64
 #define SUPERTYPE_COUNT 0
 
 enum ts_symbol_identifiers {
@@ -26,123 +26,174 @@ enum ts_symbol_identifiers {
   anon_sym_LPAREN = 4,
   anon_sym_RPAREN = 5,
   anon_sym_templ = 6,
-  anon_sym_COMMA = 7,
-  anon_sym_DOT = 8,
-  anon_sym_LBRACK = 9,
-  anon_sym_RBRACK = 10,
-  anon_sym_STAR = 11,
-  anon_sym_map = 12,
-  anon_sym_func = 13,
-  anon_sym_type = 14,
-  anon_sym_struct = 15,
-  anon_sym_LBRACE = 16,
-  anon_sym_RBRACE = 17,
-  anon_sym_LT = 18,
-  anon_sym_SLASH = 19,
-  anon_sym_GT = 20,
-  anon_sym_LT_SLASH = 21,
-  sym_text_content = 22,
-  anon_sym_EQ = 23,
-  anon_sym_for = 24,
-  anon_sym_COLON_EQ = 25,
-  anon_sym_range = 26,
-  anon_sym_if = 27,
-  anon_sym_else = 28,
-  anon_sym_var = 29,
-  anon_sym_AT = 30,
-  anon_sym_children = 31,
-  anon_sym_DOT_DOT_DOT = 32,
-  aux_sym_expression_content_token1 = 33,
-  aux_sym_go_string_literal_token1 = 34,
-  aux_sym_go_string_literal_token2 = 35,
-  aux_sym_go_string_literal_token3 = 36,
-  aux_sym_go_string_literal_token4 = 37,
-  anon_sym_EQ_EQ = 38,
-  anon_sym_BANG_EQ = 39,
-  anon_sym_LT_EQ = 40,
-  anon_sym_GT_EQ = 41,
-  anon_sym_PLUS = 42,
-  anon_sym_DASH = 43,
-  anon_sym_AMP_AMP = 44,
-  anon_sym_PIPE_PIPE = 45,
-  sym_number = 46,
-  sym_string = 47,
-  sym_true = 48,
-  sym_false = 49,
-  anon_sym_const = 50,
-  aux_sym_go_declaration_token1 = 51,
-  aux_sym_go_declaration_token2 = 52,
-  aux_sym_go_paren_body_token1 = 53,
-  aux_sym_comment_token1 = 54,
-  aux_sym_comment_token2 = 55,
-  sym_source_file = 56,
-  sym_package_clause = 57,
-  sym_import_section = 58,
-  sym_import_declaration = 59,
-  sym_import_spec_list = 60,
-  sym_import_spec = 61,
-  sym_component_declaration = 62,
-  sym_receiver = 63,
-  sym_parameter_list = 64,
-  sym_parameter = 65,
-  sym_type_expression = 66,
-  sym_qualified_type = 67,
-  sym_slice_type = 68,
-  sym_pointer_type = 69,
-  sym_map_type = 70,
-  sym_func_type = 71,
-  sym_generic_type = 72,
-  sym_type_struct_declaration = 73,
-  sym_struct_body = 74,
-  sym_struct_field = 75,
-  sym_component_body = 76,
-  sym__child = 77,
-  sym_element = 78,
-  sym_self_closing_element = 79,
-  sym_element_with_children = 80,
-  sym__element_child = 81,
-  sym_attribute = 82,
-  sym__attribute_value = 83,
-  sym_for_statement = 84,
-  sym_for_clause = 85,
-  sym_if_statement = 86,
-  sym_let_binding = 87,
-  sym_state_declaration = 88,
-  sym_component_call = 89,
-  sym_children_slot = 90,
-  sym_block = 91,
-  sym_go_expression = 92,
-  sym_expression_content = 93,
-  sym_go_string_literal = 94,
-  sym_nested_braces = 95,
-  sym__expression = 96,
-  sym_binary_expression = 97,
-  sym_call_expression = 98,
-  sym_selector_expression = 99,
-  sym_parenthesized_expression = 100,
-  sym_argument_list = 101,
-  sym_return_type = 102,
-  sym_function_declaration = 103,
-  sym_go_declaration = 104,
-  sym_go_brace_body = 105,
-  sym_go_paren_body = 106,
-  sym_nested_parens = 107,
-  sym_function_body = 108,
-  sym_go_code_content = 109,
-  sym_comment = 110,
-  aux_sym_source_file_repeat1 = 111,
-  aux_sym_import_section_repeat1 = 112,
-  aux_sym_import_spec_list_repeat1 = 113,
-  aux_sym_parameter_list_repeat1 = 114,
-  aux_sym_func_type_repeat1 = 115,
-  aux_sym_struct_body_repeat1 = 116,
-  aux_sym_component_body_repeat1 = 117,
-  aux_sym_self_closing_element_repeat1 = 118,
-  aux_sym_element_with_children_repeat1 = 119,
-  aux_sym_expression_content_repeat1 = 120,
-  aux_sym_argument_list_repeat1 = 121,
-  aux_sym_go_declaration_repeat1 = 122,
-  aux_sym_go_paren_body_repeat1 = 123,
+  anon_sym_LBRACK = 7,
+  anon_sym_COMMA = 8,
+  anon_sym_RBRACK = 9,
+  anon_sym_PIPE = 10,
+  anon_sym_TILDE = 11,
+  anon_sym_DOT_DOT_DOT = 12,
+  anon_sym_DOT = 13,
+  anon_sym_STAR = 14,
+  anon_sym_map = 15,
+  anon_sym_func = 16,
+  anon_sym_chan = 17,
+  anon_sym_LT_DASH = 18,
+  anon_sym_struct = 19,
+  anon_sym_interface = 20,
+  anon_sym_LBRACE = 21,
+  anon_sym_RBRACE = 22,
+  anon_sym_type = 23,
+  anon_sym_SEMI = 24,
+  anon_sym_LT = 25,
+  anon_sym_SLASH = 26,
+  anon_sym_GT = 27,
+  anon_sym_LT_SLASH = 28,
+  anon_sym_EQ = 29,
+  anon_sym_for = 30,
+  anon_sym_COLON_EQ = 31,
+  anon_sym_range = 32,
+  anon_sym_if = 33,
+  anon_sym_else = 34,
+  anon_sym_switch = 35,
+  anon_sym_case = 36,
+  anon_sym_COLON = 37,
+  anon_sym_default = 38,
+  anon_sym_var = 39,
+  anon_sym_AT = 40,
+  anon_sym_children = 41,
+  aux_sym_expression_content_token1 = 42,
+  aux_sym_go_string_literal_token1 = 43,
+  aux_sym_go_string_literal_token2 = 44,
+  aux_sym_go_string_literal_token3 = 45,
+  aux_sym_go_string_literal_token4 = 46,
+  anon_sym_PLUS = 47,
+  anon_sym_DASH = 48,
+  anon_sym_BANG = 49,
+  anon_sym_CARET = 50,
+  anon_sym_AMP = 51,
+  anon_sym_PERCENT = 52,
+  anon_sym_LT_LT = 53,
+  anon_sym_GT_GT = 54,
+  anon_sym_AMP_CARET = 55,
+  anon_sym_EQ_EQ = 56,
+  anon_sym_BANG_EQ = 57,
+  anon_sym_LT_EQ = 58,
+  anon_sym_GT_EQ = 59,
+  anon_sym_AMP_AMP = 60,
+  anon_sym_PIPE_PIPE = 61,
+  sym_number = 62,
+  sym_true = 63,
+  sym_false = 64,
+  anon_sym_const = 65,
+  aux_sym_go_declaration_token1 = 66,
+  aux_sym_go_declaration_token2 = 67,
+  aux_sym_go_declaration_token3 = 68,
+  aux_sym_go_paren_body_token1 = 69,
+  aux_sym_comment_token1 = 70,
+  aux_sym_comment_token2 = 71,
+  sym_string = 72,
+  sym_raw_string = 73,
+  sym_rune = 74,
+  sym_text_content = 75,
+  sym_go_statement = 76,
+  sym_source_file = 77,
+  sym_package_clause = 78,
+  sym_import_section = 79,
+  sym_import_declaration = 80,
+  sym_import_spec_list = 81,
+  sym_import_spec = 82,
+  sym_component_declaration = 83,
+  sym_receiver = 84,
+  sym_type_parameter_list = 85,
+  sym_type_parameter_declaration = 86,
+  sym_type_constraint = 87,
+  sym_underlying_type = 88,
+  sym_parameter_list = 89,
+  sym_parameter = 90,
+  sym_variadic_parameter = 91,
+  sym_type_expression = 92,
+  sym_qualified_type = 93,
+  sym_slice_type = 94,
+  sym_pointer_type = 95,
+  sym_map_type = 96,
+  sym_func_type = 97,
+  sym_array_type = 98,
+  sym_channel_type = 99,
+  sym_struct_type = 100,
+  sym_interface_type = 101,
+  sym_method_spec = 102,
+  sym_generic_type = 103,
+  sym_type_struct_declaration = 104,
+  sym_struct_body = 105,
+  sym_struct_field = 106,
+  sym_component_body = 107,
+  sym__child = 108,
+  sym_element = 109,
+  sym_self_closing_element = 110,
+  sym_element_with_children = 111,
+  sym__element_child = 112,
+  sym_attribute = 113,
+  sym__attribute_value = 114,
+  sym_for_statement = 115,
+  sym_for_clause = 116,
+  sym_if_statement = 117,
+  sym_switch_statement = 118,
+  sym_case_clause = 119,
+  sym_default_clause = 120,
+  sym_let_binding = 121,
+  sym_state_declaration = 122,
+  sym_component_call = 123,
+  sym_children_slot = 124,
+  sym_block = 125,
+  sym_go_expression = 126,
+  sym_expression_content = 127,
+  sym_go_string_literal = 128,
+  sym_nested_braces = 129,
+  sym__expression = 130,
+  sym_unary_expression = 131,
+  sym_binary_expression = 132,
+  sym_call_expression = 133,
+  sym_selector_expression = 134,
+  sym_index_expression = 135,
+  sym_slice_expression = 136,
+  sym_type_assertion_expression = 137,
+  sym_composite_literal = 138,
+  sym_literal_value = 139,
+  sym_literal_element = 140,
+  sym_keyed_element = 141,
+  sym_func_literal = 142,
+  sym_parenthesized_expression = 143,
+  sym_argument_list = 144,
+  sym_variadic_argument = 145,
+  sym_return_type = 146,
+  sym_function_declaration = 147,
+  sym_go_declaration = 148,
+  sym_go_brace_body = 149,
+  sym_go_paren_body = 150,
+  sym_nested_parens = 151,
+  sym_function_body = 152,
+  sym_go_code_content = 153,
+  sym_comment = 154,
+  aux_sym_source_file_repeat1 = 155,
+  aux_sym_import_section_repeat1 = 156,
+  aux_sym_import_spec_list_repeat1 = 157,
+  aux_sym_type_parameter_list_repeat1 = 158,
+  aux_sym_type_parameter_declaration_repeat1 = 159,
+  aux_sym_type_constraint_repeat1 = 160,
+  aux_sym_parameter_list_repeat1 = 161,
+  aux_sym_interface_type_repeat1 = 162,
+  aux_sym_generic_type_repeat1 = 163,
+  aux_sym_struct_body_repeat1 = 164,
+  aux_sym_component_body_repeat1 = 165,
+  aux_sym_self_closing_element_repeat1 = 166,
+  aux_sym_element_with_children_repeat1 = 167,
+  aux_sym_switch_statement_repeat1 = 168,
+  aux_sym_case_clause_repeat1 = 169,
+  aux_sym_expression_content_repeat1 = 170,
+  aux_sym_literal_value_repeat1 = 171,
+  aux_sym_argument_list_repeat1 = 172,
+  aux_sym_go_declaration_repeat1 = 173,
+  aux_sym_go_paren_body_repeat1 = 174,
 };
 
 static const char * const ts_symbol_names[] = {
@@ -153,55 +204,76 @@ static const char * const ts_symbol_names[] = {
   [anon_sym_LPAREN] = "(",
   [anon_sym_RPAREN] = ")",
   [anon_sym_templ] = "templ",
-  [anon_sym_COMMA] = ",",
-  [anon_sym_DOT] = ".",
   [anon_sym_LBRACK] = "[",
+  [anon_sym_COMMA] = ",",
   [anon_sym_RBRACK] = "]",
+  [anon_sym_PIPE] = "|",
+  [anon_sym_TILDE] = "~",
+  [anon_sym_DOT_DOT_DOT] = "...",
+  [anon_sym_DOT] = ".",
   [anon_sym_STAR] = "*",
   [anon_sym_map] = "map",
   [anon_sym_func] = "func",
-  [anon_sym_type] = "type",
+  [anon_sym_chan] = "chan",
+  [anon_sym_LT_DASH] = "<-",
   [anon_sym_struct] = "struct",
+  [anon_sym_interface] = "interface",
   [anon_sym_LBRACE] = "{",
   [anon_sym_RBRACE] = "}",
+  [anon_sym_type] = "type",
+  [anon_sym_SEMI] = ";",
   [anon_sym_LT] = "<",
   [anon_sym_SLASH] = "/",
   [anon_sym_GT] = ">",
   [anon_sym_LT_SLASH] = "</",
-  [sym_text_content] = "text_content",
   [anon_sym_EQ] = "=",
   [anon_sym_for] = "for",
   [anon_sym_COLON_EQ] = ":=",
   [anon_sym_range] = "range",
   [anon_sym_if] = "if",
   [anon_sym_else] = "else",
+  [anon_sym_switch] = "switch",
+  [anon_sym_case] = "case",
+  [anon_sym_COLON] = ":",
+  [anon_sym_default] = "default",
   [anon_sym_var] = "var",
   [anon_sym_AT] = "@",
   [anon_sym_children] = "children",
-  [anon_sym_DOT_DOT_DOT] = "...",
   [aux_sym_expression_content_token1] = "expression_content_token1",
   [aux_sym_go_string_literal_token1] = "go_string_literal_token1",
   [aux_sym_go_string_literal_token2] = "go_string_literal_token2",
   [aux_sym_go_string_literal_token3] = "go_string_literal_token3",
   [aux_sym_go_string_literal_token4] = "go_string_literal_token4",
+  [anon_sym_PLUS] = "+",
+  [anon_sym_DASH] = "-",
+  [anon_sym_BANG] = "!",
+  [anon_sym_CARET] = "^",
+  [anon_sym_AMP] = "&",
+  [anon_sym_PERCENT] = "%",
+  [anon_sym_LT_LT] = "<<",
+  [anon_sym_GT_GT] = ">>",
+  [anon_sym_AMP_CARET] = "&^",
   [anon_sym_EQ_EQ] = "==",
   [anon_sym_BANG_EQ] = "!=",
   [anon_sym_LT_EQ] = "<=",
   [anon_sym_GT_EQ] = ">=",
-  [anon_sym_PLUS] = "+",
-  [anon_sym_DASH] = "-",
   [anon_sym_AMP_AMP] = "&&",
   [anon_sym_PIPE_PIPE] = "||",
   [sym_number] = "number",
-  [sym_string] = "string",
   [sym_true] = "true",
   [sym_false] = "false",
   [anon_sym_const] = "const",
   [aux_sym_go_declaration_token1] = "go_declaration_token1",
   [aux_sym_go_declaration_token2] = "go_declaration_token2",
+  [aux_sym_go_declaration_token3] = "go_declaration_token3",
   [aux_sym_go_paren_body_token1] = "go_paren_body_token1",
   [aux_sym_comment_token1] = "comment_token1",
   [aux_sym_comment_token2] = "comment_token2",
+  [sym_string] = "string",
+  [sym_raw_string] = "raw_string",
+  [sym_rune] = "rune",
+  [sym_text_content] = "text_content",
+  [sym_go_statement] = "go_statement",
   [sym_source_file] = "source_file",
   [sym_package_clause] = "package_clause",
   [sym_import_section] = "import_section",
@@ -210,14 +282,24 @@ static const char * const ts_symbol_names[] = {
   [sym_import_spec] = "import_spec",
   [sym_component_declaration] = "component_declaration",
   [sym_receiver] = "receiver",
+  [sym_type_parameter_list] = "type_parameter_list",
+  [sym_type_parameter_declaration] = "type_parameter_declaration",
+  [sym_type_constraint] = "type_constraint",
+  [sym_underlying_type] = "underlying_type",
   [sym_parameter_list] = "parameter_list",
   [sym_parameter] = "parameter",
+  [sym_variadic_parameter] = "variadic_parameter",
   [sym_type_expression] = "type_expression",
   [sym_qualified_type] = "qualified_type",
   [sym_slice_type] = "slice_type",
   [sym_pointer_type] = "pointer_type",
   [sym_map_type] = "map_type",
   [sym_func_type] = "func_type",
+  [sym_array_type] = "array_type",
+  [sym_channel_type] = "channel_type",
+  [sym_struct_type] = "struct_type",
+  [sym_interface_type] = "interface_type",
+  [sym_method_spec] = "method_spec",
   [sym_generic_type] = "generic_type",
   [sym_type_struct_declaration] = "type_struct_declaration",
   [sym_struct_body] = "struct_body",
@@ -233,6 +315,9 @@ static const char * const ts_symbol_names[] = {
   [sym_for_statement] = "for_statement",
   [sym_for_clause] = "for_clause",
   [sym_if_statement] = "if_statement",
+  [sym_switch_statement] = "switch_statement",
+  [sym_case_clause] = "case_clause",
+  [sym_default_clause] = "default_clause",
   [sym_let_binding] = "let_binding",
   [sym_state_declaration] = "state_declaration",
   [sym_component_call] = "component_call",
@@ -243,11 +328,21 @@ static const char * const ts_symbol_names[] = {
   [sym_go_string_literal] = "go_string_literal",
   [sym_nested_braces] = "nested_braces",
   [sym__expression] = "_expression",
+  [sym_unary_expression] = "unary_expression",
   [sym_binary_expression] = "binary_expression",
   [sym_call_expression] = "call_expression",
   [sym_selector_expression] = "selector_expression",
+  [sym_index_expression] = "index_expression",
+  [sym_slice_expression] = "slice_expression",
+  [sym_type_assertion_expression] = "type_assertion_expression",
+  [sym_composite_literal] = "composite_literal",
+  [sym_literal_value] = "literal_value",
+  [sym_literal_element] = "literal_element",
+  [sym_keyed_element] = "keyed_element",
+  [sym_func_literal] = "func_literal",
   [sym_parenthesized_expression] = "parenthesized_expression",
   [sym_argument_list] = "argument_list",
+  [sym_variadic_argument] = "variadic_argument",
   [sym_return_type] = "return_type",
   [sym_function_declaration] = "function_declaration",
   [sym_go_declaration] = "go_declaration",
@@ -260,13 +355,20 @@ static const char * const ts_symbol_names[] = {
   [aux_sym_source_file_repeat1] = "source_file_repeat1",
   [aux_sym_import_section_repeat1] = "import_section_repeat1",
   [aux_sym_import_spec_list_repeat1] = "import_spec_list_repeat1",
+  [aux_sym_type_parameter_list_repeat1] = "type_parameter_list_repeat1",
+  [aux_sym_type_parameter_declaration_repeat1] = "type_parameter_declaration_repeat1",
+  [aux_sym_type_constraint_repeat1] = "type_constraint_repeat1",
   [aux_sym_parameter_list_repeat1] = "parameter_list_repeat1",
-  [aux_sym_func_type_repeat1] = "func_type_repeat1",
+  [aux_sym_interface_type_repeat1] = "interface_type_repeat1",
+  [aux_sym_generic_type_repeat1] = "generic_type_repeat1",
   [aux_sym_struct_body_repeat1] = "struct_body_repeat1",
   [aux_sym_component_body_repeat1] = "component_body_repeat1",
   [aux_sym_self_closing_element_repeat1] = "self_closing_element_repeat1",
   [aux_sym_element_with_children_repeat1] = "element_with_children_repeat1",
+  [aux_sym_switch_statement_repeat1] = "switch_statement_repeat1",
+  [aux_sym_case_clause_repeat1] = "case_clause_repeat1",
   [aux_sym_expression_content_repeat1] = "expression_content_repeat1",
+  [aux_sym_literal_value_repeat1] = "literal_value_repeat1",
   [aux_sym_argument_list_repeat1] = "argument_list_repeat1",
   [aux_sym_go_declaration_repeat1] = "go_declaration_repeat1",
   [aux_sym_go_paren_body_repeat1] = "go_paren_body_repeat1",
@@ -280,55 +382,76 @@ static const TSSymbol ts_symbol_map[] = {
   [anon_sym_LPAREN] = anon_sym_LPAREN,
   [anon_sym_RPAREN] = anon_sym_RPAREN,
   [anon_sym_templ] = anon_sym_templ,
-  [anon_sym_COMMA] = anon_sym_COMMA,
-  [anon_sym_DOT] = anon_sym_DOT,
   [anon_sym_LBRACK] = anon_sym_LBRACK,
+  [anon_sym_COMMA] = anon_sym_COMMA,
   [anon_sym_RBRACK] = anon_sym_RBRACK,
+  [anon_sym_PIPE] = anon_sym_PIPE,
+  [anon_sym_TILDE] = anon_sym_TILDE,
+  [anon_sym_DOT_DOT_DOT] = anon_sym_DOT_DOT_DOT,
+  [anon_sym_DOT] = anon_sym_DOT,
   [anon_sym_STAR] = anon_sym_STAR,
   [anon_sym_map] = anon_sym_map,
   [anon_sym_func] = anon_sym_func,
-  [anon_sym_type] = anon_sym_type,
+  [anon_sym_chan] = anon_sym_chan,
+  [anon_sym_LT_DASH] = anon_sym_LT_DASH,
   [anon_sym_struct] = anon_sym_struct,
+  [anon_sym_interface] = anon_sym_interface,
   [anon_sym_LBRACE] = anon_sym_LBRACE,
   [anon_sym_RBRACE] = anon_sym_RBRACE,
+  [anon_sym_type] = anon_sym_type,
+  [anon_sym_SEMI] = anon_sym_SEMI,
   [anon_sym_LT] = anon_sym_LT,
   [anon_sym_SLASH] = anon_sym_SLASH,
   [anon_sym_GT] = anon_sym_GT,
   [anon_sym_LT_SLASH] = anon_sym_LT_SLASH,
-  [sym_text_content] = sym_text_content,
   [anon_sym_EQ] = anon_sym_EQ,
   [anon_sym_for] = anon_sym_for,
   [anon_sym_COLON_EQ] = anon_sym_COLON_EQ,
   [anon_sym_range] = anon_sym_range,
   [anon_sym_if] = anon_sym_if,
   [anon_sym_else] = anon_sym_else,
+  [anon_sym_switch] = anon_sym_switch,
+  [anon_sym_case] = anon_sym_case,
+  [anon_sym_COLON] = anon_sym_COLON,
+  [anon_sym_default] = anon_sym_default,
   [anon_sym_var] = anon_sym_var,
   [anon_sym_AT] = anon_sym_AT,
   [anon_sym_children] = anon_sym_children,
-  [anon_sym_DOT_DOT_DOT] = anon_sym_DOT_DOT_DOT,
   [aux_sym_expression_content_token1] = aux_sym_expression_content_token1,
   [aux_sym_go_string_literal_token1] = aux_sym_go_string_literal_token1,
   [aux_sym_go_string_literal_token2] = aux_sym_go_string_literal_token2,
   [aux_sym_go_string_literal_token3] = aux_sym_go_string_literal_token3,
   [aux_sym_go_string_literal_token4] = aux_sym_go_string_literal_token4,
+  [anon_sym_PLUS] = anon_sym_PLUS,
+  [anon_sym_DASH] = anon_sym_DASH,
+  [anon_sym_BANG] = anon_sym_BANG,
+  [anon_sym_CARET] = anon_sym_CARET,
+  [anon_sym_AMP] = anon_sym_AMP,
+  [anon_sym_PERCENT] = anon_sym_PERCENT,
+  [anon_sym_LT_LT] = anon_sym_LT_LT,
+  [anon_sym_GT_GT] = anon_sym_GT_GT,
+  [anon_sym_AMP_CARET] = anon_sym_AMP_CARET,
   [anon_sym_EQ_EQ] = anon_sym_EQ_EQ,
   [anon_sym_BANG_EQ] = anon_sym_BANG_EQ,
   [anon_sym_LT_EQ] = anon_sym_LT_EQ,
   [anon_sym_GT_EQ] = anon_sym_GT_EQ,
-  [anon_sym_PLUS] = anon_sym_PLUS,
-  [anon_sym_DASH] = anon_sym_DASH,
   [anon_sym_AMP_AMP] = anon_sym_AMP_AMP,
   [anon_sym_PIPE_PIPE] = anon_sym_PIPE_PIPE,
   [sym_number] = sym_number,
-  [sym_string] = sym_string,
   [sym_true] = sym_true,
   [sym_false] = sym_false,
   [anon_sym_const] = anon_sym_const,
   [aux_sym_go_declaration_token1] = aux_sym_go_declaration_token1,
   [aux_sym_go_declaration_token2] = aux_sym_go_declaration_token2,
+  [aux_sym_go_declaration_token3] = aux_sym_go_declaration_token3,
   [aux_sym_go_paren_body_token1] = aux_sym_go_paren_body_token1,
   [aux_sym_comment_token1] = aux_sym_comment_token1,
   [aux_sym_comment_token2] = aux_sym_comment_token2,
+  [sym_string] = sym_string,
+  [sym_raw_string] = sym_raw_string,
+  [sym_rune] = sym_rune,
+  [sym_text_content] = sym_text_content,
+  [sym_go_statement] = sym_go_statement,
   [sym_source_file] = sym_source_file,
   [sym_package_clause] = sym_package_clause,
   [sym_import_section] = sym_import_section,
@@ -337,14 +460,24 @@ static const TSSymbol ts_symbol_map[] = {
   [sym_import_spec] = sym_import_spec,
   [sym_component_declaration] = sym_component_declaration,
   [sym_receiver] = sym_receiver,
+  [sym_type_parameter_list] = sym_type_parameter_list,
+  [sym_type_parameter_declaration] = sym_type_parameter_declaration,
+  [sym_type_constraint] = sym_type_constraint,
+  [sym_underlying_type] = sym_underlying_type,
   [sym_parameter_list] = sym_parameter_list,
   [sym_parameter] = sym_parameter,
+  [sym_variadic_parameter] = sym_variadic_parameter,
   [sym_type_expression] = sym_type_expression,
   [sym_qualified_type] = sym_qualified_type,
   [sym_slice_type] = sym_slice_type,
   [sym_pointer_type] = sym_pointer_type,
   [sym_map_type] = sym_map_type,
   [sym_func_type] = sym_func_type,
+  [sym_array_type] = sym_array_type,
+  [sym_channel_type] = sym_channel_type,
+  [sym_struct_type] = sym_struct_type,
+  [sym_interface_type] = sym_interface_type,
+  [sym_method_spec] = sym_method_spec,
   [sym_generic_type] = sym_generic_type,
   [sym_type_struct_declaration] = sym_type_struct_declaration,
   [sym_struct_body] = sym_struct_body,
@@ -360,6 +493,9 @@ static const TSSymbol ts_symbol_map[] = {
   [sym_for_statement] = sym_for_statement,
   [sym_for_clause] = sym_for_clause,
   [sym_if_statement] = sym_if_statement,
+  [sym_switch_statement] = sym_switch_statement,
+  [sym_case_clause] = sym_case_clause,
+  [sym_default_clause] = sym_default_clause,
   [sym_let_binding] = sym_let_binding,
   [sym_state_declaration] = sym_state_declaration,
   [sym_component_call] = sym_component_call,
@@ -370,11 +506,21 @@ static const TSSymbol ts_symbol_map[] = {
   [sym_go_string_literal] = sym_go_string_literal,
   [sym_nested_braces] = sym_nested_braces,
   [sym__expression] = sym__expression,
+  [sym_unary_expression] = sym_unary_expression,
   [sym_binary_expression] = sym_binary_expression,
   [sym_call_expression] = sym_call_expression,
   [sym_selector_expression] = sym_selector_expression,
+  [sym_index_expression] = sym_index_expression,
+  [sym_slice_expression] = sym_slice_expression,
+  [sym_type_assertion_expression] = sym_type_assertion_expression,
+  [sym_composite_literal] = sym_composite_literal,
+  [sym_literal_value] = sym_literal_value,
+  [sym_literal_element] = sym_literal_element,
+  [sym_keyed_element] = sym_keyed_element,
+  [sym_func_literal] = sym_func_literal,
   [sym_parenthesized_expression] = sym_parenthesized_expression,
   [sym_argument_list] = sym_argument_list,
+  [sym_variadic_argument] = sym_variadic_argument,
   [sym_return_type] = sym_return_type,
   [sym_function_declaration] = sym_function_declaration,
   [sym_go_declaration] = sym_go_declaration,
@@ -387,13 +533,20 @@ static const TSSymbol ts_symbol_map[] = {
   [aux_sym_source_file_repeat1] = aux_sym_source_file_repeat1,
   [aux_sym_import_section_repeat1] = aux_sym_import_section_repeat1,
   [aux_sym_import_spec_list_repeat1] = aux_sym_import_spec_list_repeat1,
+  [aux_sym_type_parameter_list_repeat1] = aux_sym_type_parameter_list_repeat1,
+  [aux_sym_type_parameter_declaration_repeat1] = aux_sym_type_parameter_declaration_repeat1,
+  [aux_sym_type_constraint_repeat1] = aux_sym_type_constraint_repeat1,
   [aux_sym_parameter_list_repeat1] = aux_sym_parameter_list_repeat1,
-  [aux_sym_func_type_repeat1] = aux_sym_func_type_repeat1,
+  [aux_sym_interface_type_repeat1] = aux_sym_interface_type_repeat1,
+  [aux_sym_generic_type_repeat1] = aux_sym_generic_type_repeat1,
   [aux_sym_struct_body_repeat1] = aux_sym_struct_body_repeat1,
   [aux_sym_component_body_repeat1] = aux_sym_component_body_repeat1,
   [aux_sym_self_closing_element_repeat1] = aux_sym_self_closing_element_repeat1,
   [aux_sym_element_with_children_repeat1] = aux_sym_element_with_children_repeat1,
+  [aux_sym_switch_statement_repeat1] = aux_sym_switch_statement_repeat1,
+  [aux_sym_case_clause_repeat1] = aux_sym_case_clause_repeat1,
   [aux_sym_expression_content_repeat1] = aux_sym_expression_content_repeat1,
+  [aux_sym_literal_value_repeat1] = aux_sym_literal_value_repeat1,
   [aux_sym_argument_list_repeat1] = aux_sym_argument_list_repeat1,
   [aux_sym_go_declaration_repeat1] = aux_sym_go_declaration_repeat1,
   [aux_sym_go_paren_body_repeat1] = aux_sym_go_paren_body_repeat1,
@@ -428,19 +581,31 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_LBRACK] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_COMMA] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_DOT] = {
+  [anon_sym_RBRACK] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_LBRACK] = {
+  [anon_sym_PIPE] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_RBRACK] = {
+  [anon_sym_TILDE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DOT_DOT_DOT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DOT] = {
     .visible = true,
     .named = false,
   },
@@ -456,7 +621,11 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_type] = {
+  [anon_sym_chan] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LT_DASH] = {
     .visible = true,
     .named = false,
   },
@@ -464,6 +633,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_interface] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_LBRACE] = {
     .visible = true,
     .named = false,
@@ -472,6 +645,14 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_type] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_SEMI] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_LT] = {
     .visible = true,
     .named = false,
@@ -488,10 +669,6 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
-  [sym_text_content] = {
-    .visible = true,
-    .named = true,
-  },
   [anon_sym_EQ] = {
     .visible = true,
     .named = false,
@@ -516,19 +693,31 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_var] = {
+  [anon_sym_switch] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_AT] = {
+  [anon_sym_case] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_children] = {
+  [anon_sym_COLON] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_DOT_DOT_DOT] = {
+  [anon_sym_default] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_var] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_AT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_children] = {
     .visible = true,
     .named = false,
   },
@@ -552,27 +741,55 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = false,
     .named = false,
   },
-  [anon_sym_EQ_EQ] = {
+  [anon_sym_PLUS] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_BANG_EQ] = {
+  [anon_sym_DASH] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_LT_EQ] = {
+  [anon_sym_BANG] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_GT_EQ] = {
+  [anon_sym_CARET] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_PLUS] = {
+  [anon_sym_AMP] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_DASH] = {
+  [anon_sym_PERCENT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LT_LT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_GT_GT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_AMP_CARET] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_EQ_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_BANG_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LT_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_GT_EQ] = {
     .visible = true,
     .named = false,
   },
@@ -588,10 +805,6 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
-  [sym_string] = {
-    .visible = true,
-    .named = true,
-  },
   [sym_true] = {
     .visible = true,
     .named = true,
@@ -612,6 +825,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = false,
     .named = false,
   },
+  [aux_sym_go_declaration_token3] = {
+    .visible = false,
+    .named = false,
+  },
   [aux_sym_go_paren_body_token1] = {
     .visible = false,
     .named = false,
@@ -624,6 +841,26 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = false,
     .named = false,
   },
+  [sym_string] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_raw_string] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_rune] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_text_content] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_go_statement] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_source_file] = {
     .visible = true,
     .named = true,
@@ -656,6 +893,22 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_type_parameter_list] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_type_parameter_declaration] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_type_constraint] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_underlying_type] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_parameter_list] = {
     .visible = true,
     .named = true,
@@ -664,6 +917,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_variadic_parameter] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_type_expression] = {
     .visible = true,
     .named = true,
@@ -688,6 +945,26 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_array_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_channel_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_struct_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_interface_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_method_spec] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_generic_type] = {
     .visible = true,
     .named = true,
@@ -748,6 +1025,18 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_switch_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_case_clause] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_default_clause] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_let_binding] = {
     .visible = true,
     .named = true,
@@ -788,6 +1077,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = false,
     .named = true,
   },
+  [sym_unary_expression] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_binary_expression] = {
     .visible = true,
     .named = true,
@@ -800,6 +1093,38 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_index_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_slice_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_type_assertion_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_composite_literal] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_literal_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_literal_element] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyed_element] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_func_literal] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_parenthesized_expression] = {
     .visible = true,
     .named = true,
@@ -808,6 +1133,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_variadic_argument] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_return_type] = {
     .visible = true,
     .named = true,
@@ -856,11 +1185,27 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = false,
     .named = false,
   },
+  [aux_sym_type_parameter_list_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_type_parameter_declaration_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_type_constraint_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
   [aux_sym_parameter_list_repeat1] = {
     .visible = false,
     .named = false,
   },
-  [aux_sym_func_type_repeat1] = {
+  [aux_sym_interface_type_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_generic_type_repeat1] = {
     .visible = false,
     .named = false,
   },
@@ -880,10 +1225,22 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = false,
     .named = false,
   },
+  [aux_sym_switch_statement_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_case_clause_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
   [aux_sym_expression_content_repeat1] = {
     .visible = false,
     .named = false,
   },
+  [aux_sym_literal_value_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
   [aux_sym_argument_list_repeat1] = {
     .visible = false,
     .named = false,
@@ -903,23 +1260,38 @@ enum ts_field_identifiers {
   field_alternative = 2,
   field_arguments = 3,
   field_body = 4,
-  field_children = 5,
-  field_clause = 6,
-  field_collection = 7,
-  field_condition = 8,
-  field_consequence = 9,
-  field_index = 10,
-  field_initializer = 11,
-  field_keyword = 12,
-  field_name = 13,
-  field_parameters = 14,
-  field_path = 15,
-  field_preamble = 16,
-  field_receiver = 17,
-  field_return_type = 18,
-  field_tag = 19,
-  field_type = 20,
-  field_value = 21,
+  field_capacity = 5,
+  field_children = 6,
+  field_clause = 7,
+  field_closing_tag = 8,
+  field_collection = 9,
+  field_condition = 10,
+  field_consequence = 11,
+  field_constraint = 12,
+  field_end = 13,
+  field_field = 14,
+  field_function = 15,
+  field_index = 16,
+  field_initializer = 17,
+  field_key = 18,
+  field_keyword = 19,
+  field_left = 20,
+  field_length = 21,
+  field_name = 22,
+  field_operand = 23,
+  field_operator = 24,
+  field_parameters = 25,
+  field_path = 26,
+  field_preamble = 27,
+  field_qualifier = 28,
+  field_receiver = 29,
+  field_return_type = 30,
+  field_right = 31,
+  field_start = 32,
+  field_tag = 33,
+  field_type = 34,
+  field_type_parameters = 35,
+  field_value = 36,
 };
 
 static const char * const ts_field_names[] = {
@@ -928,22 +1300,37 @@ static const char * const ts_field_names[] = {
   [field_alternative] = "alternative",
   [field_arguments] = "arguments",
   [field_body] = "body",
+  [field_capacity] = "capacity",
   [field_children] = "children",
   [field_clause] = "clause",
+  [field_closing_tag] = "closing_tag",
   [field_collection] = "collection",
   [field_condition] = "condition",
   [field_consequence] = "consequence",
+  [field_constraint] = "constraint",
+  [field_end] = "end",
+  [field_field] = "field",
+  [field_function] = "function",
   [field_index] = "index",
   [field_initializer] = "initializer",
+  [field_key] = "key",
   [field_keyword] = "keyword",
+  [field_left] = "left",
+  [field_length] = "length",
   [field_name] = "name",
+  [field_operand] = "operand",
+  [field_operator] = "operator",
   [field_parameters] = "parameters",
   [field_path] = "path",
   [field_preamble] = "preamble",
+  [field_qualifier] = "qualifier",
   [field_receiver] = "receiver",
   [field_return_type] = "return_type",
+  [field_right] = "right",
+  [field_start] = "start",
   [field_tag] = "tag",
   [field_type] = "type",
+  [field_type_parameters] = "type_parameters",
   [field_value] = "value",
 };
 
@@ -954,26 +1341,63 @@ static const TSMapSlice ts_field_map_slices[PRODUCTION_ID_COUNT] = {
   [4] = {.index = 4, .length = 2},
   [5] = {.index = 6, .length = 2},
   [6] = {.index = 8, .length = 3},
-  [7] = {.index = 11, .length = 3},
-  [8] = {.index = 14, .length = 2},
-  [9] = {.index = 16, .length = 2},
-  [10] = {.index = 18, .length = 2},
-  [11] = {.index = 20, .length = 4},
-  [12] = {.index = 24, .length = 4},
-  [13] = {.index = 28, .length = 3},
-  [14] = {.index = 31, .length = 5},
-  [15] = {.index = 36, .length = 1},
-  [16] = {.index = 37, .length = 2},
-  [17] = {.index = 39, .length = 2},
-  [18] = {.index = 41, .length = 2},
-  [19] = {.index = 43, .length = 2},
-  [20] = {.index = 45, .length = 2},
-  [21] = {.index = 47, .length = 1},
-  [22] = {.index = 48, .length = 2},
-  [23] = {.index = 50, .length = 3},
-  [24] = {.index = 53, .length = 2},
-  [25] = {.index = 55, .length = 3},
-  [26] = {.index = 58, .length = 3},
+  [7] = {.index = 11, .length = 1},
+  [8] = {.index = 12, .length = 3},
+  [9] = {.index = 15, .length = 2},
+  [10] = {.index = 17, .length = 1},
+  [11] = {.index = 18, .length = 2},
+  [12] = {.index = 20, .length = 1},
+  [13] = {.index = 21, .length = 2},
+  [14] = {.index = 23, .length = 2},
+  [15] = {.index = 25, .length = 4},
+  [16] = {.index = 29, .length = 4},
+  [17] = {.index = 33, .length = 4},
+  [18] = {.index = 37, .length = 2},
+  [19] = {.index = 39, .length = 2},
+  [20] = {.index = 41, .length = 2},
+  [21] = {.index = 43, .length = 2},
+  [22] = {.index = 45, .length = 3},
+  [23] = {.index = 48, .length = 2},
+  [24] = {.index = 50, .length = 3},
+  [25] = {.index = 53, .length = 3},
+  [26] = {.index = 56, .length = 5},
+  [27] = {.index = 61, .length = 5},
+  [28] = {.index = 66, .length = 2},
+  [29] = {.index = 68, .length = 1},
+  [30] = {.index = 69, .length = 3},
+  [31] = {.index = 72, .length = 2},
+  [32] = {.index = 74, .length = 2},
+  [33] = {.index = 76, .length = 1},
+  [34] = {.index = 77, .length = 2},
+  [35] = {.index = 79, .length = 2},
+  [36] = {.index = 81, .length = 2},
+  [37] = {.index = 83, .length = 2},
+  [38] = {.index = 85, .length = 2},
+  [39] = {.index = 87, .length = 3},
+  [40] = {.index = 90, .length = 1},
+  [41] = {.index = 91, .length = 2},
+  [42] = {.index = 93, .length = 3},
+  [43] = {.index = 96, .length = 1},
+  [44] = {.index = 97, .length = 1},
+  [45] = {.index = 98, .length = 2},
+  [46] = {.index = 100, .length = 3},
+  [47] = {.index = 103, .length = 2},
+  [48] = {.index = 105, .length = 2},
+  [49] = {.index = 107, .length = 2},
+  [50] = {.index = 109, .length = 2},
+  [51] = {.index = 111, .length = 2},
+  [52] = {.index = 113, .length = 3},
+  [53] = {.index = 116, .length = 3},
+  [54] = {.index = 119, .length = 3},
+  [55] = {.index = 122, .length = 2},
+  [56] = {.index = 124, .length = 2},
+  [57] = {.index = 126, .length = 2},
+  [58] = {.index = 128, .length = 4},
+  [59] = {.index = 132, .length = 3},
+  [60] = {.index = 135, .length = 2},
+  [61] = {.index = 137, .length = 3},
+  [62] = {.index = 140, .length = 4},
+  [63] = {.index = 144, .length = 2},
 };
 
 static const TSFieldMapEntry ts_field_map_entries[] = {
@@ -995,75 +1419,197 @@ static const TSFieldMapEntry ts_field_map_entries[] = {
     {field_keyword, 0},
     {field_preamble, 1},
   [11] =
+    {field_type, 0},
+  [12] =
     {field_body, 3},
     {field_name, 1},
     {field_parameters, 2},
-  [14] =
+  [15] =
     {field_body, 3},
     {field_name, 1},
-  [16] =
+  [17] =
+    {field_parameters, 1},
+  [18] =
     {field_name, 1},
     {field_type, 2},
-  [18] =
+  [20] =
+    {field_type, 1},
+  [21] =
     {field_name, 0},
     {field_type, 1},
-  [20] =
+  [23] =
+    {field_constraint, 1},
+    {field_name, 0},
+  [25] =
+    {field_body, 4},
+    {field_name, 1},
+    {field_parameters, 3},
+    {field_type_parameters, 2},
+  [29] =
     {field_body, 4},
     {field_name, 1},
     {field_parameters, 2},
     {field_return_type, 3},
-  [24] =
+  [33] =
     {field_body, 4},
     {field_name, 2},
     {field_parameters, 3},
     {field_receiver, 1},
-  [28] =
+  [37] =
+    {field_operand, 1},
+    {field_operator, 0},
+  [39] =
+    {field_body, 1},
+    {field_type, 0},
+  [41] =
+    {field_arguments, 1},
+    {field_function, 0},
+  [43] =
+    {field_name, 0},
+    {field_type, 2},
+  [45] =
+    {field_name, 0},
+    {field_name, 1, .inherited = true},
+    {field_type, 2},
+  [48] =
+    {field_name, 0, .inherited = true},
+    {field_name, 1, .inherited = true},
+  [50] =
+    {field_constraint, 2},
+    {field_name, 0},
+    {field_name, 1, .inherited = true},
+  [53] =
     {field_body, 5},
     {field_name, 2},
     {field_receiver, 1},
-  [31] =
+  [56] =
+    {field_body, 5},
+    {field_name, 1},
+    {field_parameters, 3},
+    {field_return_type, 4},
+    {field_type_parameters, 2},
+  [61] =
     {field_body, 5},
     {field_name, 2},
     {field_parameters, 3},
     {field_receiver, 1},
     {field_return_type, 4},
-  [36] =
+  [66] =
+    {field_body, 2},
+    {field_parameters, 1},
+  [68] =
+    {field_length, 1},
+  [69] =
+    {field_left, 0},
+    {field_operator, 1},
+    {field_right, 2},
+  [72] =
+    {field_field, 2},
+    {field_operand, 0},
+  [74] =
     {field_name, 0},
-  [37] =
+    {field_parameters, 1},
+  [76] =
+    {field_name, 0},
+  [77] =
     {field_body, 2},
     {field_clause, 1},
-  [39] =
+  [79] =
     {field_condition, 1},
     {field_consequence, 2},
-  [41] =
+  [81] =
     {field_arguments, 2},
     {field_name, 1},
-  [43] =
+  [83] =
     {field_name, 0},
     {field_value, 2},
-  [45] =
+  [85] =
     {field_initializer, 2},
     {field_name, 0},
-  [47] =
+  [87] =
+    {field_body, 3},
+    {field_parameters, 1},
+    {field_return_type, 2},
+  [90] =
+    {field_operand, 0},
+  [91] =
+    {field_index, 2},
+    {field_operand, 0},
+  [93] =
+    {field_name, 0},
+    {field_parameters, 1},
+    {field_return_type, 2},
+  [96] =
     {field_tag, 1},
-  [48] =
+  [97] =
+    {field_value, 1},
+  [98] =
     {field_name, 1},
     {field_value, 3},
-  [50] =
+  [100] =
     {field_arguments, 2},
     {field_children, 3},
     {field_name, 1},
-  [53] =
+  [103] =
+    {field_key, 0},
+    {field_value, 2},
+  [105] =
+    {field_end, 3},
+    {field_operand, 0},
+  [107] =
+    {field_operand, 0},
+    {field_start, 2},
+  [109] =
+    {field_operand, 0},
+    {field_type, 3},
+  [111] =
     {field_collection, 3},
     {field_value, 0},
-  [55] =
+  [113] =
     {field_alternative, 4},
     {field_condition, 1},
     {field_consequence, 2},
-  [58] =
+  [116] =
+    {field_arguments, 4},
+    {field_name, 3},
+    {field_qualifier, 1},
+  [119] =
+    {field_end, 4},
+    {field_operand, 0},
+    {field_start, 2},
+  [122] =
+    {field_closing_tag, 4},
+    {field_tag, 1},
+  [124] =
+    {field_value, 1},
+    {field_value, 2, .inherited = true},
+  [126] =
+    {field_value, 0, .inherited = true},
+    {field_value, 1, .inherited = true},
+  [128] =
+    {field_arguments, 4},
+    {field_children, 5},
+    {field_name, 3},
+    {field_qualifier, 1},
+  [132] =
+    {field_capacity, 5},
+    {field_end, 3},
+    {field_operand, 0},
+  [135] =
+    {field_closing_tag, 5},
+    {field_tag, 1},
+  [137] =
     {field_collection, 5},
     {field_index, 0},
     {field_value, 2},
+  [140] =
+    {field_capacity, 6},
+    {field_end, 4},
+    {field_operand, 0},
+    {field_start, 2},
+  [144] =
+    {field_closing_tag, 6},
+    {field_tag, 1},
 };
 
 static const TSSymbol ts_alias_sequences[PRODUCTION_ID_COUNT][MAX_ALIAS_SEQUENCE_LENGTH] = {
@@ -1089,91 +1635,91 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [11] = 11,
   [12] = 12,
   [13] = 13,
-  [14] = 7,
+  [14] = 14,
   [15] = 15,
   [16] = 16,
-  [17] = 15,
+  [17] = 13,
   [18] = 18,
-  [19] = 6,
-  [20] = 12,
-  [21] = 12,
-  [22] = 15,
-  [23] = 11,
-  [24] = 13,
-  [25] = 25,
-  [26] = 26,
-  [27] = 27,
-  [28] = 28,
-  [29] = 29,
-  [30] = 30,
-  [31] = 31,
+  [19] = 19,
+  [20] = 20,
+  [21] = 21,
+  [22] = 22,
+  [23] = 23,
+  [24] = 16,
+  [25] = 15,
+  [26] = 13,
+  [27] = 16,
+  [28] = 15,
+  [29] = 13,
+  [30] = 16,
+  [31] = 15,
   [32] = 32,
   [33] = 33,
   [34] = 34,
   [35] = 35,
-  [36] = 33,
-  [37] = 37,
-  [38] = 25,
+  [36] = 36,
+  [37] = 33,
+  [38] = 32,
   [39] = 39,
-  [40] = 39,
-  [41] = 26,
+  [40] = 32,
+  [41] = 41,
   [42] = 42,
   [43] = 43,
-  [44] = 28,
+  [44] = 44,
   [45] = 45,
   [46] = 46,
   [47] = 47,
   [48] = 48,
   [49] = 49,
-  [50] = 49,
+  [50] = 44,
   [51] = 51,
-  [52] = 52,
+  [52] = 43,
   [53] = 53,
-  [54] = 54,
-  [55] = 55,
-  [56] = 56,
+  [54] = 46,
+  [55] = 48,
+  [56] = 53,
   [57] = 57,
-  [58] = 58,
+  [58] = 47,
   [59] = 59,
   [60] = 60,
   [61] = 61,
-  [62] = 59,
-  [63] = 52,
-  [64] = 57,
-  [65] = 58,
+  [62] = 49,
+  [63] = 63,
+  [64] = 64,
+  [65] = 65,
   [66] = 66,
-  [67] = 56,
+  [67] = 67,
   [68] = 68,
-  [69] = 69,
-  [70] = 70,
+  [69] = 19,
+  [70] = 22,
   [71] = 71,
   [72] = 72,
   [73] = 73,
-  [74] = 74,
+  [74] = 23,
   [75] = 75,
-  [76] = 76,
+  [76] = 12,
   [77] = 77,
   [78] = 78,
-  [79] = 69,
+  [79] = 79,
   [80] = 80,
   [81] = 81,
   [82] = 82,
   [83] = 83,
-  [84] = 80,
+  [84] = 84,
   [85] = 85,
   [86] = 86,
-  [87] = 87,
+  [87] = 20,
   [88] = 88,
   [89] = 89,
   [90] = 90,
   [91] = 91,
   [92] = 92,
-  [93] = 93,
-  [94] = 94,
+  [93] = 21,
+  [94] = 18,
   [95] = 95,
-  [96] = 96,
-  [97] = 97,
-  [98] = 98,
+  [96] = 95,
+  [97] = 95,
+  [98] = 95,
   [99] = 99,
   [100] = 100,
   [101] = 101,
@@ -1183,69 +1729,69 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [105] = 105,
   [106] = 106,
   [107] = 107,
-  [108] = 96,
-  [109] = 97,
-  [110] = 3,
+  [108] = 108,
+  [109] = 109,
+  [110] = 109,
   [111] = 111,
   [112] = 112,
   [113] = 113,
-  [114] = 87,
-  [115] = 4,
+  [114] = 114,
+  [115] = 115,
   [116] = 116,
   [117] = 117,
-  [118] = 5,
+  [118] = 118,
   [119] = 119,
   [120] = 120,
-  [121] = 87,
+  [121] = 121,
   [122] = 122,
-  [123] = 97,
+  [123] = 123,
   [124] = 124,
-  [125] = 125,
-  [126] = 99,
+  [125] = 99,
+  [126] = 126,
   [127] = 127,
   [128] = 128,
-  [129] = 95,
-  [130] = 104,
-  [131] = 106,
-  [132] = 113,
+  [129] = 129,
+  [130] = 130,
+  [131] = 131,
+  [132] = 128,
   [133] = 133,
   [134] = 134,
-  [135] = 116,
-  [136] = 117,
-  [137] = 125,
-  [138] = 119,
-  [139] = 107,
-  [140] = 140,
-  [141] = 127,
-  [142] = 111,
-  [143] = 112,
-  [144] = 120,
+  [135] = 135,
+  [136] = 63,
+  [137] = 137,
+  [138] = 138,
+  [139] = 73,
+  [140] = 80,
+  [141] = 81,
+  [142] = 82,
+  [143] = 143,
+  [144] = 144,
   [145] = 145,
   [146] = 146,
-  [147] = 147,
-  [148] = 148,
-  [149] = 147,
+  [147] = 134,
+  [148] = 134,
+  [149] = 149,
   [150] = 150,
   [151] = 151,
   [152] = 152,
   [153] = 153,
   [154] = 154,
-  [155] = 155,
-  [156] = 156,
-  [157] = 157,
+  [155] = 99,
+  [156] = 150,
+  [157] = 154,
   [158] = 158,
   [159] = 159,
   [160] = 160,
   [161] = 161,
-  [162] = 162,
+  [162] = 158,
   [163] = 163,
   [164] = 164,
   [165] = 165,
-  [166] = 166,
+  [166] = 165,
   [167] = 167,
-  [168] = 168,
-  [169] = 169,
-  [170] = 170,
+  [168] = 159,
+  [169] = 164,
+  [170] = 167,
   [171] = 171,
   [172] = 172,
   [173] = 173,
@@ -1259,42 +1805,42 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [181] = 181,
   [182] = 182,
   [183] = 183,
-  [184] = 184,
+  [184] = 176,
   [185] = 185,
   [186] = 186,
   [187] = 187,
-  [188] = 188,
-  [189] = 189,
-  [190] = 190,
-  [191] = 191,
-  [192] = 192,
-  [193] = 193,
-  [194] = 194,
-  [195] = 195,
-  [196] = 194,
-  [197] = 197,
+  [188] = 177,
+  [189] = 180,
+  [190] = 178,
+  [191] = 176,
+  [192] = 177,
+  [193] = 178,
+  [194] = 180,
+  [195] = 182,
+  [196] = 185,
+  [197] = 179,
   [198] = 198,
   [199] = 199,
-  [200] = 200,
-  [201] = 201,
-  [202] = 202,
-  [203] = 203,
-  [204] = 204,
-  [205] = 205,
-  [206] = 206,
-  [207] = 207,
+  [200] = 182,
+  [201] = 172,
+  [202] = 186,
+  [203] = 172,
+  [204] = 199,
+  [205] = 186,
+  [206] = 185,
+  [207] = 199,
   [208] = 208,
   [209] = 209,
   [210] = 210,
   [211] = 211,
   [212] = 212,
-  [213] = 212,
-  [214] = 203,
+  [213] = 213,
+  [214] = 214,
   [215] = 215,
   [216] = 216,
   [217] = 217,
   [218] = 218,
-  [219] = 215,
+  [219] = 219,
   [220] = 220,
   [221] = 221,
   [222] = 222,
@@ -1306,10 +1852,10 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [228] = 228,
   [229] = 229,
   [230] = 230,
-  [231] = 146,
-  [232] = 224,
+  [231] = 231,
+  [232] = 232,
   [233] = 233,
-  [234] = 225,
+  [234] = 234,
   [235] = 235,
   [236] = 236,
   [237] = 237,
@@ -1317,31 +1863,31 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [239] = 239,
   [240] = 240,
   [241] = 241,
-  [242] = 151,
+  [242] = 242,
   [243] = 243,
   [244] = 244,
   [245] = 245,
-  [246] = 239,
+  [246] = 246,
   [247] = 247,
   [248] = 248,
   [249] = 249,
   [250] = 250,
   [251] = 251,
   [252] = 252,
-  [253] = 253,
+  [253] = 19,
   [254] = 254,
   [255] = 255,
   [256] = 256,
-  [257] = 257,
-  [258] = 258,
-  [259] = 254,
-  [260] = 260,
-  [261] = 261,
-  [262] = 248,
+  [257] = 20,
+  [258] = 21,
+  [259] = 22,
+  [260] = 23,
+  [261] = 12,
+  [262] = 262,
   [263] = 263,
   [264] = 264,
   [265] = 265,
-  [266] = 257,
+  [266] = 266,
   [267] = 267,
   [268] = 268,
   [269] = 269,
@@ -1357,14 +1903,14 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [279] = 279,
   [280] = 280,
   [281] = 281,
-  [282] = 268,
-  [283] = 283,
+  [282] = 282,
+  [283] = 280,
   [284] = 284,
   [285] = 285,
-  [286] = 286,
+  [286] = 240,
   [287] = 287,
   [288] = 288,
-  [289] = 286,
+  [289] = 289,
   [290] = 290,
   [291] = 291,
   [292] = 292,
@@ -1374,39 +1920,309 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [296] = 296,
   [297] = 297,
   [298] = 298,
-  [299] = 299,
-  [300] = 272,
+  [299] = 247,
+  [300] = 300,
   [301] = 301,
-  [302] = 293,
+  [302] = 246,
   [303] = 303,
-  [304] = 303,
-  [305] = 291,
+  [304] = 304,
+  [305] = 305,
   [306] = 306,
-  [307] = 307,
-  [308] = 292,
-  [309] = 309,
-  [310] = 271,
-  [311] = 288,
+  [307] = 244,
+  [308] = 308,
+  [309] = 249,
+  [310] = 252,
+  [311] = 242,
   [312] = 312,
-  [313] = 313,
-  [314] = 283,
-  [315] = 313,
-  [316] = 297,
-  [317] = 274,
-  [318] = 294,
-  [319] = 276,
-  [320] = 320,
-  [321] = 321,
-  [322] = 270,
-  [323] = 273,
-  [324] = 324,
-  [325] = 325,
-  [326] = 326,
-  [327] = 321,
-  [328] = 328,
-  [329] = 279,
-  [330] = 307,
-  [331] = 331,
+  [313] = 281,
+  [314] = 314,
+  [315] = 271,
+  [316] = 255,
+  [317] = 270,
+  [318] = 263,
+  [319] = 273,
+  [320] = 274,
+  [321] = 275,
+  [322] = 19,
+  [323] = 20,
+  [324] = 256,
+  [325] = 22,
+  [326] = 262,
+  [327] = 264,
+  [328] = 23,
+  [329] = 12,
+  [330] = 330,
+  [331] = 276,
+  [332] = 278,
+  [333] = 277,
+  [334] = 282,
+  [335] = 254,
+  [336] = 268,
+  [337] = 269,
+  [338] = 21,
+  [339] = 241,
+  [340] = 340,
+  [341] = 341,
+  [342] = 342,
+  [343] = 236,
+  [344] = 344,
+  [345] = 345,
+  [346] = 346,
+  [347] = 236,
+  [348] = 348,
+  [349] = 279,
+  [350] = 350,
+  [351] = 351,
+  [352] = 352,
+  [353] = 353,
+  [354] = 354,
+  [355] = 355,
+  [356] = 356,
+  [357] = 357,
+  [358] = 358,
+  [359] = 359,
+  [360] = 360,
+  [361] = 361,
+  [362] = 362,
+  [363] = 363,
+  [364] = 364,
+  [365] = 365,
+  [366] = 366,
+  [367] = 367,
+  [368] = 368,
+  [369] = 369,
+  [370] = 370,
+  [371] = 371,
+  [372] = 372,
+  [373] = 373,
+  [374] = 374,
+  [375] = 375,
+  [376] = 376,
+  [377] = 377,
+  [378] = 378,
+  [379] = 379,
+  [380] = 377,
+  [381] = 381,
+  [382] = 373,
+  [383] = 374,
+  [384] = 375,
+  [385] = 369,
+  [386] = 386,
+  [387] = 387,
+  [388] = 388,
+  [389] = 389,
+  [390] = 390,
+  [391] = 391,
+  [392] = 392,
+  [393] = 393,
+  [394] = 394,
+  [395] = 395,
+  [396] = 396,
+  [397] = 397,
+  [398] = 398,
+  [399] = 393,
+  [400] = 394,
+  [401] = 394,
+  [402] = 402,
+  [403] = 397,
+  [404] = 394,
+  [405] = 405,
+  [406] = 406,
+  [407] = 407,
+  [408] = 243,
+  [409] = 409,
+  [410] = 410,
+  [411] = 411,
+  [412] = 412,
+  [413] = 413,
+  [414] = 414,
+  [415] = 415,
+  [416] = 407,
+  [417] = 417,
+  [418] = 405,
+  [419] = 245,
+  [420] = 239,
+  [421] = 412,
+  [422] = 422,
+  [423] = 423,
+  [424] = 424,
+  [425] = 425,
+  [426] = 426,
+  [427] = 427,
+  [428] = 428,
+  [429] = 429,
+  [430] = 430,
+  [431] = 431,
+  [432] = 432,
+  [433] = 255,
+  [434] = 434,
+  [435] = 435,
+  [436] = 436,
+  [437] = 437,
+  [438] = 438,
+  [439] = 439,
+  [440] = 440,
+  [441] = 356,
+  [442] = 442,
+  [443] = 443,
+  [444] = 437,
+  [445] = 358,
+  [446] = 442,
+  [447] = 447,
+  [448] = 448,
+  [449] = 449,
+  [450] = 450,
+  [451] = 442,
+  [452] = 452,
+  [453] = 453,
+  [454] = 442,
+  [455] = 455,
+  [456] = 456,
+  [457] = 457,
+  [458] = 458,
+  [459] = 459,
+  [460] = 460,
+  [461] = 461,
+  [462] = 462,
+  [463] = 463,
+  [464] = 464,
+  [465] = 465,
+  [466] = 466,
+  [467] = 467,
+  [468] = 466,
+  [469] = 469,
+  [470] = 470,
+  [471] = 471,
+  [472] = 472,
+  [473] = 473,
+  [474] = 474,
+  [475] = 475,
+  [476] = 476,
+  [477] = 477,
+  [478] = 478,
+  [479] = 479,
+  [480] = 480,
+  [481] = 470,
+  [482] = 482,
+  [483] = 483,
+  [484] = 484,
+  [485] = 483,
+  [486] = 486,
+  [487] = 487,
+  [488] = 488,
+  [489] = 471,
+  [490] = 484,
+  [491] = 491,
+  [492] = 482,
+  [493] = 493,
+  [494] = 484,
+  [495] = 495,
+  [496] = 471,
+  [497] = 497,
+  [498] = 483,
+  [499] = 482,
+  [500] = 484,
+  [501] = 501,
+  [502] = 476,
+  [503] = 503,
+  [504] = 471,
+  [505] = 505,
+  [506] = 506,
+  [507] = 507,
+  [508] = 508,
+  [509] = 509,
+  [510] = 510,
+  [511] = 482,
+  [512] = 512,
+  [513] = 505,
+  [514] = 514,
+  [515] = 515,
+  [516] = 516,
+  [517] = 517,
+  [518] = 518,
+  [519] = 519,
+  [520] = 520,
+  [521] = 521,
+  [522] = 522,
+  [523] = 517,
+  [524] = 524,
+  [525] = 525,
+  [526] = 526,
+  [527] = 527,
+  [528] = 528,
+  [529] = 529,
+  [530] = 530,
+  [531] = 531,
+  [532] = 532,
+  [533] = 533,
+  [534] = 534,
+  [535] = 535,
+  [536] = 536,
+  [537] = 537,
+  [538] = 538,
+  [539] = 529,
+  [540] = 540,
+  [541] = 541,
+  [542] = 519,
+  [543] = 533,
+  [544] = 544,
+  [545] = 545,
+  [546] = 538,
+  [547] = 544,
+  [548] = 548,
+  [549] = 549,
+  [550] = 550,
+  [551] = 551,
+  [552] = 541,
+  [553] = 530,
+  [554] = 554,
+  [555] = 529,
+  [556] = 556,
+  [557] = 519,
+  [558] = 556,
+  [559] = 559,
+  [560] = 560,
+  [561] = 561,
+  [562] = 562,
+  [563] = 563,
+  [564] = 530,
+  [565] = 565,
+  [566] = 566,
+  [567] = 529,
+  [568] = 568,
+  [569] = 519,
+  [570] = 570,
+  [571] = 531,
+  [572] = 565,
+  [573] = 565,
+  [574] = 574,
+  [575] = 575,
+  [576] = 576,
+  [577] = 577,
+  [578] = 578,
+  [579] = 579,
+  [580] = 559,
+  [581] = 581,
+  [582] = 537,
+  [583] = 583,
+  [584] = 581,
+  [585] = 516,
+  [586] = 586,
+  [587] = 545,
+  [588] = 581,
+  [589] = 568,
+  [590] = 563,
+  [591] = 574,
+  [592] = 530,
+  [593] = 550,
+  [594] = 554,
+  [595] = 595,
+  [596] = 544,
+  [597] = 597,
+  [598] = 522,
+  [599] = 574,
+  [600] = 515,
+  [601] = 601,
 };
 
 static bool ts_lex(TSLexer *lexer, TSStateId state) {
@@ -1414,661 +2230,1049 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
   eof = lexer->eof(lexer);
   switch (state) {
     case 0:
-      if (eof) ADVANCE(28);
+      if (eof) ADVANCE(39);
       ADVANCE_MAP(
-        '!', 17,
-        '"', 2,
-        '&', 8,
-        '\'', 20,
-        '(', 29,
-        ')', 30,
-        '*', 36,
-        '+', 82,
-        ',', 31,
-        '-', 83,
-        '.', 33,
-        '/', 43,
-        ':', 18,
-        '<', 41,
-        '=', 54,
-        '>', 45,
-        '@', 56,
-        '[', 34,
-        ']', 35,
-        '`', 21,
-        'c', 88,
-        '{', 37,
-        '|', 22,
-        '}', 38,
+        '!', 89,
+        '"', 4,
+        '%', 93,
+        '&', 92,
+        '\'', 19,
+        '(', 40,
+        ')', 41,
+        '*', 51,
+        '+', 86,
+        ',', 43,
+        '-', 87,
+        '.', 50,
+        '/', 60,
+        '0', 112,
+        ':', 68,
+        ';', 55,
+        '<', 57,
+        '=', 65,
+        '>', 62,
+        '@', 69,
+        '[', 42,
+        ']', 44,
+        '^', 90,
+        '`', 23,
+        'c', 105,
+        '{', 53,
+        '|', 46,
+        '}', 54,
+        '~', 47,
       );
       if (('\t' <= lookahead && lookahead <= '\r') ||
           lookahead == ' ') SKIP(0);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(94);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(114);
       if (('A' <= lookahead && lookahead <= 'Z') ||
-          ('_' <= lookahead && lookahead <= 'z')) ADVANCE(93);
+          ('_' <= lookahead && lookahead <= 'z')) ADVANCE(110);
       END_STATE();
     case 1:
-      if (lookahead == '\n') ADVANCE(4);
-      if (lookahead == '"') ADVANCE(97);
-      if (lookahead != 0) ADVANCE(2);
+      if (lookahead == '"') ADVANCE(4);
+      if (lookahead == '\'') ADVANCE(19);
+      if (lookahead == '(') ADVANCE(40);
+      if (lookahead == ')') ADVANCE(41);
+      if (lookahead == '/') ADVANCE(60);
+      if (lookahead == '`') ADVANCE(23);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(157);
+      if (lookahead != 0) ADVANCE(158);
       END_STATE();
     case 2:
-      if (lookahead == '"') ADVANCE(74);
-      if (lookahead == '\\') ADVANCE(1);
-      if (lookahead != 0) ADVANCE(2);
+      if (lookahead == '"') ADVANCE(4);
+      if (lookahead == '\'') ADVANCE(19);
+      if (lookahead == '/') ADVANCE(60);
+      if (lookahead == '`') ADVANCE(23);
+      if (lookahead == 'c') ADVANCE(75);
+      if (lookahead == '{') ADVANCE(53);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(72);
+      if (lookahead != 0 &&
+          lookahead != '}') ADVANCE(81);
       END_STATE();
     case 3:
-      if (lookahead == '"') ADVANCE(74);
-      if (lookahead == '\\') ADVANCE(24);
-      if (lookahead != 0) ADVANCE(3);
+      if (lookahead == '"') ADVANCE(4);
+      if (lookahead == '\'') ADVANCE(19);
+      if (lookahead == '/') ADVANCE(60);
+      if (lookahead == '`') ADVANCE(23);
+      if (lookahead == '{') ADVANCE(53);
+      if (lookahead == '}') ADVANCE(54);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(80);
+      if (lookahead != 0) ADVANCE(81);
       END_STATE();
     case 4:
-      if (lookahead == '"') ADVANCE(96);
+      if (lookahead == '"') ADVANCE(82);
+      if (lookahead == '\\') ADVANCE(34);
       if (lookahead != 0) ADVANCE(4);
       END_STATE();
     case 5:
-      if (lookahead == '"') ADVANCE(3);
-      if (lookahead == '\'') ADVANCE(20);
-      if (lookahead == '(') ADVANCE(29);
-      if (lookahead == ')') ADVANCE(30);
-      if (lookahead == '/') ADVANCE(110);
-      if (lookahead == '`') ADVANCE(21);
-      if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') ADVANCE(113);
-      if (lookahead != 0) ADVANCE(114);
+      if (lookahead == '\'') ADVANCE(84);
       END_STATE();
     case 6:
-      if (lookahead == '"') ADVANCE(3);
-      if (lookahead == '\'') ADVANCE(20);
-      if (lookahead == '/') ADVANCE(61);
-      if (lookahead == '`') ADVANCE(21);
-      if (lookahead == 'c') ADVANCE(68);
-      if (lookahead == '{') ADVANCE(37);
-      if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') ADVANCE(64);
-      if (lookahead != 0 &&
-          lookahead != '}') ADVANCE(73);
+      if (lookahead == '\'') ADVANCE(85);
       END_STATE();
     case 7:
-      if (lookahead == '"') ADVANCE(3);
-      if (lookahead == '\'') ADVANCE(20);
-      if (lookahead == '/') ADVANCE(61);
-      if (lookahead == '`') ADVANCE(21);
-      if (lookahead == '{') ADVANCE(37);
-      if (lookahead == '}') ADVANCE(38);
+      ADVANCE_MAP(
+        '(', 40,
+        ')', 41,
+        '*', 51,
+        ',', 43,
+        '.', 49,
+        '/', 8,
+        '<', 12,
+        '[', 42,
+        '|', 45,
+        '}', 54,
+        '~', 47,
+      );
       if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') ADVANCE(65);
-      if (lookahead != 0) ADVANCE(73);
+          lookahead == ' ') SKIP(7);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(110);
       END_STATE();
     case 8:
-      if (lookahead == '&') ADVANCE(84);
+      if (lookahead == '*') ADVANCE(10);
+      if (lookahead == '/') ADVANCE(159);
       END_STATE();
     case 9:
-      if (lookahead == '\'') ADVANCE(76);
+      if (lookahead == '*') ADVANCE(9);
+      if (lookahead == '/') ADVANCE(160);
+      if (lookahead != 0) ADVANCE(10);
       END_STATE();
     case 10:
-      if (lookahead == '\'') ADVANCE(77);
+      if (lookahead == '*') ADVANCE(9);
+      if (lookahead != 0) ADVANCE(10);
       END_STATE();
     case 11:
-      if (lookahead == '*') ADVANCE(11);
-      if (lookahead == '/') ADVANCE(116);
-      if (lookahead != 0) ADVANCE(12);
+      ADVANCE_MAP(
+        ',', 43,
+        '/', 8,
+        ':', 17,
+        ';', 55,
+        '<', 58,
+        '@', 69,
+        '{', 53,
+        '}', 54,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(11);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(110);
       END_STATE();
     case 12:
-      if (lookahead == '*') ADVANCE(11);
-      if (lookahead != 0) ADVANCE(12);
+      if (lookahead == '-') ADVANCE(52);
       END_STATE();
     case 13:
       if (lookahead == '.') ADVANCE(14);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(120);
       END_STATE();
     case 14:
-      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == '.') ADVANCE(48);
       END_STATE();
     case 15:
-      ADVANCE_MAP(
-        '.', 13,
-        '/', 43,
-        '<', 39,
-        '=', 53,
-        '>', 44,
-        '@', 56,
-        '{', 37,
-        '}', 38,
-      );
-      if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') SKIP(15);
-      if (('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(93);
+      if (lookahead == '.') ADVANCE(33);
+      if (lookahead == '_') ADVANCE(32);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(119);
       END_STATE();
     case 16:
-      if (lookahead == '/') ADVANCE(47);
-      if (lookahead == '<') ADVANCE(40);
-      if (lookahead == '@') ADVANCE(56);
-      if (lookahead == '{') ADVANCE(37);
-      if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') SKIP(16);
-      if (('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(51);
-      if (lookahead != 0 &&
-          lookahead != '>' &&
-          lookahead != '}') ADVANCE(52);
+      if (lookahead == '=') ADVANCE(98);
       END_STATE();
     case 17:
-      if (lookahead == '=') ADVANCE(79);
+      if (lookahead == '=') ADVANCE(66);
       END_STATE();
     case 18:
-      if (lookahead == '=') ADVANCE(55);
+      if (lookahead == '=') ADVANCE(97);
       END_STATE();
     case 19:
-      if (lookahead == '=') ADVANCE(78);
+      if (lookahead == '\\') ADVANCE(35);
+      if (lookahead != 0 &&
+          lookahead != '\'') ADVANCE(5);
       END_STATE();
     case 20:
-      if (lookahead == '\\') ADVANCE(25);
-      if (lookahead != 0 &&
-          lookahead != '\'') ADVANCE(9);
+      if (lookahead == '_') ADVANCE(26);
+      if (lookahead == '0' ||
+          lookahead == '1') ADVANCE(123);
       END_STATE();
     case 21:
-      if (lookahead == '`') ADVANCE(75);
-      if (lookahead != 0) ADVANCE(21);
+      if (lookahead == '_') ADVANCE(28);
+      if (('0' <= lookahead && lookahead <= '7')) ADVANCE(124);
       END_STATE();
     case 22:
-      if (lookahead == '|') ADVANCE(85);
+      if (lookahead == '_') ADVANCE(33);
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(24);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(22);
       END_STATE();
     case 23:
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(95);
+      if (lookahead == '`') ADVANCE(83);
+      if (lookahead != 0) ADVANCE(23);
       END_STATE();
     case 24:
-      if (lookahead != 0 &&
-          lookahead != '\n') ADVANCE(3);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(30);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(126);
       END_STATE();
     case 25:
-      if (lookahead != 0 &&
-          lookahead != '\n') ADVANCE(10);
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(24);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(22);
       END_STATE();
     case 26:
-      if (eof) ADVANCE(28);
-      ADVANCE_MAP(
-        '!', 17,
-        '"', 4,
-        '&', 8,
-        '(', 29,
-        ')', 30,
-        '*', 36,
-        '+', 82,
-        ',', 31,
-        '-', 83,
-        '.', 32,
-        '/', 43,
-        '<', 42,
-        '=', 19,
-        '>', 45,
-        '@', 56,
-        '[', 34,
-        ']', 35,
-        '{', 37,
-        '|', 22,
-        '}', 38,
-      );
-      if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') SKIP(26);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(94);
-      if (('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(93);
+      if (lookahead == '0' ||
+          lookahead == '1') ADVANCE(123);
       END_STATE();
     case 27:
-      if (eof) ADVANCE(28);
-      if (lookahead == '(') ADVANCE(29);
-      if (lookahead == '/') ADVANCE(98);
-      if (lookahead == '{') ADVANCE(37);
-      if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') SKIP(27);
-      if (('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(93);
-      if (lookahead != 0 &&
-          lookahead != '"' &&
-          (lookahead < '\'' || ')' < lookahead) &&
-          (lookahead < '/' || '9' < lookahead) &&
-          (lookahead < '_' || '{' < lookahead)) ADVANCE(102);
+      if (lookahead == '8' ||
+          lookahead == '9') ADVANCE(118);
+      if (('0' <= lookahead && lookahead <= '7')) ADVANCE(117);
       END_STATE();
     case 28:
-      ACCEPT_TOKEN(ts_builtin_sym_end);
+      if (('0' <= lookahead && lookahead <= '7')) ADVANCE(124);
       END_STATE();
     case 29:
-      ACCEPT_TOKEN(anon_sym_LPAREN);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(120);
       END_STATE();
     case 30:
-      ACCEPT_TOKEN(anon_sym_RPAREN);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(126);
       END_STATE();
     case 31:
-      ACCEPT_TOKEN(anon_sym_COMMA);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(118);
       END_STATE();
     case 32:
-      ACCEPT_TOKEN(anon_sym_DOT);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(119);
       END_STATE();
     case 33:
-      ACCEPT_TOKEN(anon_sym_DOT);
-      if (lookahead == '.') ADVANCE(14);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(22);
       END_STATE();
     case 34:
-      ACCEPT_TOKEN(anon_sym_LBRACK);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(4);
       END_STATE();
     case 35:
-      ACCEPT_TOKEN(anon_sym_RBRACK);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(6);
       END_STATE();
     case 36:
-      ACCEPT_TOKEN(anon_sym_STAR);
+      if (eof) ADVANCE(39);
+      ADVANCE_MAP(
+        '!', 88,
+        '&', 91,
+        '(', 40,
+        ')', 41,
+        '*', 51,
+        '+', 86,
+        ',', 43,
+        '-', 87,
+        '.', 13,
+        '/', 60,
+        '0', 116,
+        ':', 67,
+        '<', 56,
+        '=', 64,
+        '>', 61,
+        '@', 69,
+        '[', 42,
+        ']', 44,
+        '^', 90,
+        '{', 53,
+        '|', 45,
+        '}', 54,
+        '~', 47,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(36);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(118);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(110);
       END_STATE();
     case 37:
-      ACCEPT_TOKEN(anon_sym_LBRACE);
+      if (eof) ADVANCE(39);
+      ADVANCE_MAP(
+        '!', 16,
+        '%', 93,
+        '&', 92,
+        '(', 40,
+        ')', 41,
+        '*', 51,
+        '+', 86,
+        ',', 43,
+        '-', 87,
+        '.', 49,
+        '/', 60,
+        ':', 67,
+        '<', 59,
+        '=', 18,
+        '>', 62,
+        '@', 69,
+        '[', 42,
+        ']', 44,
+        '^', 90,
+        '{', 53,
+        '|', 46,
+        '}', 54,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(37);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(110);
       END_STATE();
     case 38:
-      ACCEPT_TOKEN(anon_sym_RBRACE);
+      if (eof) ADVANCE(39);
+      if (lookahead == '"') ADVANCE(4);
+      if (lookahead == '\'') ADVANCE(19);
+      if (lookahead == '(') ADVANCE(40);
+      if (lookahead == '/') ADVANCE(146);
+      if (lookahead == '`') ADVANCE(23);
+      if (lookahead == '{') ADVANCE(53);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(38);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(145);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('_' <= lookahead && lookahead <= 'z')) ADVANCE(110);
+      if (lookahead != 0 &&
+          (lookahead < '\'' || ')' < lookahead)) ADVANCE(150);
       END_STATE();
     case 39:
-      ACCEPT_TOKEN(anon_sym_LT);
+      ACCEPT_TOKEN(ts_builtin_sym_end);
       END_STATE();
     case 40:
-      ACCEPT_TOKEN(anon_sym_LT);
-      if (lookahead == '/') ADVANCE(46);
+      ACCEPT_TOKEN(anon_sym_LPAREN);
       END_STATE();
     case 41:
-      ACCEPT_TOKEN(anon_sym_LT);
-      if (lookahead == '/') ADVANCE(46);
-      if (lookahead == '=') ADVANCE(80);
+      ACCEPT_TOKEN(anon_sym_RPAREN);
       END_STATE();
     case 42:
-      ACCEPT_TOKEN(anon_sym_LT);
-      if (lookahead == '=') ADVANCE(80);
+      ACCEPT_TOKEN(anon_sym_LBRACK);
       END_STATE();
     case 43:
-      ACCEPT_TOKEN(anon_sym_SLASH);
-      if (lookahead == '*') ADVANCE(12);
-      if (lookahead == '/') ADVANCE(115);
+      ACCEPT_TOKEN(anon_sym_COMMA);
       END_STATE();
     case 44:
-      ACCEPT_TOKEN(anon_sym_GT);
+      ACCEPT_TOKEN(anon_sym_RBRACK);
       END_STATE();
     case 45:
-      ACCEPT_TOKEN(anon_sym_GT);
-      if (lookahead == '=') ADVANCE(81);
+      ACCEPT_TOKEN(anon_sym_PIPE);
       END_STATE();
     case 46:
-      ACCEPT_TOKEN(anon_sym_LT_SLASH);
+      ACCEPT_TOKEN(anon_sym_PIPE);
+      if (lookahead == '|') ADVANCE(102);
       END_STATE();
     case 47:
-      ACCEPT_TOKEN(sym_text_content);
-      if (lookahead == '*') ADVANCE(49);
-      if (lookahead == '/') ADVANCE(50);
-      if (lookahead != 0 &&
-          lookahead != '\n' &&
-          lookahead != '<' &&
-          lookahead != '>' &&
-          lookahead != '@' &&
-          lookahead != '{' &&
-          lookahead != '}') ADVANCE(52);
+      ACCEPT_TOKEN(anon_sym_TILDE);
       END_STATE();
     case 48:
-      ACCEPT_TOKEN(sym_text_content);
-      ADVANCE_MAP(
-        '*', 48,
-        '/', 52,
-        '\n', 12,
-        '<', 12,
-        '>', 12,
-        '@', 12,
-        '{', 12,
-        '}', 12,
-      );
-      if (lookahead != 0) ADVANCE(49);
+      ACCEPT_TOKEN(anon_sym_DOT_DOT_DOT);
       END_STATE();
     case 49:
-      ACCEPT_TOKEN(sym_text_content);
-      if (lookahead == '*') ADVANCE(48);
-      if (lookahead == '\n' ||
-          lookahead == '<' ||
-          lookahead == '>' ||
-          lookahead == '@' ||
-          lookahead == '{' ||
-          lookahead == '}') ADVANCE(12);
-      if (lookahead != 0) ADVANCE(49);
+      ACCEPT_TOKEN(anon_sym_DOT);
+      if (lookahead == '.') ADVANCE(14);
       END_STATE();
     case 50:
-      ACCEPT_TOKEN(sym_text_content);
-      if (lookahead == '<' ||
-          lookahead == '>' ||
-          lookahead == '@' ||
-          lookahead == '{' ||
-          lookahead == '}') ADVANCE(115);
-      if (lookahead != 0 &&
-          lookahead != '\n') ADVANCE(50);
+      ACCEPT_TOKEN(anon_sym_DOT);
+      if (lookahead == '.') ADVANCE(14);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(120);
       END_STATE();
     case 51:
-      ACCEPT_TOKEN(sym_text_content);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(51);
-      if (lookahead != 0 &&
-          lookahead != '\n' &&
-          lookahead != '<' &&
-          lookahead != '>' &&
-          (lookahead < '@' || 'Z' < lookahead) &&
-          (lookahead < 'a' || '{' < lookahead) &&
-          lookahead != '}') ADVANCE(52);
+      ACCEPT_TOKEN(anon_sym_STAR);
       END_STATE();
     case 52:
-      ACCEPT_TOKEN(sym_text_content);
-      if (lookahead != 0 &&
-          lookahead != '\n' &&
-          lookahead != '<' &&
-          lookahead != '>' &&
-          lookahead != '@' &&
-          lookahead != '{' &&
-          lookahead != '}') ADVANCE(52);
+      ACCEPT_TOKEN(anon_sym_LT_DASH);
       END_STATE();
     case 53:
-      ACCEPT_TOKEN(anon_sym_EQ);
+      ACCEPT_TOKEN(anon_sym_LBRACE);
       END_STATE();
     case 54:
-      ACCEPT_TOKEN(anon_sym_EQ);
-      if (lookahead == '=') ADVANCE(78);
+      ACCEPT_TOKEN(anon_sym_RBRACE);
       END_STATE();
     case 55:
-      ACCEPT_TOKEN(anon_sym_COLON_EQ);
+      ACCEPT_TOKEN(anon_sym_SEMI);
       END_STATE();
     case 56:
-      ACCEPT_TOKEN(anon_sym_AT);
+      ACCEPT_TOKEN(anon_sym_LT);
+      if (lookahead == '-') ADVANCE(52);
       END_STATE();
     case 57:
-      ACCEPT_TOKEN(anon_sym_children);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(93);
+      ACCEPT_TOKEN(anon_sym_LT);
+      if (lookahead == '-') ADVANCE(52);
+      if (lookahead == '/') ADVANCE(63);
+      if (lookahead == '<') ADVANCE(94);
+      if (lookahead == '=') ADVANCE(99);
       END_STATE();
     case 58:
-      ACCEPT_TOKEN(anon_sym_children);
-      if (lookahead != 0 &&
-          lookahead != '"' &&
-          lookahead != '\'' &&
-          lookahead != '`' &&
-          lookahead != '{' &&
-          lookahead != '}') ADVANCE(73);
+      ACCEPT_TOKEN(anon_sym_LT);
+      if (lookahead == '/') ADVANCE(63);
       END_STATE();
     case 59:
-      ACCEPT_TOKEN(anon_sym_DOT_DOT_DOT);
+      ACCEPT_TOKEN(anon_sym_LT);
+      if (lookahead == '<') ADVANCE(94);
+      if (lookahead == '=') ADVANCE(99);
       END_STATE();
     case 60:
-      ACCEPT_TOKEN(aux_sym_expression_content_token1);
-      if (lookahead == '\n') ADVANCE(73);
-      if (lookahead == '"' ||
-          lookahead == '\'' ||
-          lookahead == '`' ||
-          lookahead == '{' ||
-          lookahead == '}') ADVANCE(115);
-      if (lookahead != 0) ADVANCE(60);
+      ACCEPT_TOKEN(anon_sym_SLASH);
+      if (lookahead == '*') ADVANCE(10);
+      if (lookahead == '/') ADVANCE(159);
       END_STATE();
     case 61:
-      ACCEPT_TOKEN(aux_sym_expression_content_token1);
-      if (lookahead == '*') ADVANCE(63);
-      if (lookahead == '/') ADVANCE(60);
-      if (lookahead != 0 &&
-          lookahead != '"' &&
-          lookahead != '\'' &&
-          lookahead != '`' &&
-          lookahead != '{' &&
-          lookahead != '}') ADVANCE(73);
+      ACCEPT_TOKEN(anon_sym_GT);
       END_STATE();
     case 62:
-      ACCEPT_TOKEN(aux_sym_expression_content_token1);
-      if (lookahead == '*') ADVANCE(62);
-      if (lookahead == '/') ADVANCE(73);
-      if (lookahead == '"' ||
-          lookahead == '\'' ||
-          lookahead == '`' ||
-          lookahead == '{' ||
-          lookahead == '}') ADVANCE(12);
-      if (lookahead != 0) ADVANCE(63);
+      ACCEPT_TOKEN(anon_sym_GT);
+      if (lookahead == '=') ADVANCE(100);
+      if (lookahead == '>') ADVANCE(95);
       END_STATE();
     case 63:
-      ACCEPT_TOKEN(aux_sym_expression_content_token1);
-      if (lookahead == '*') ADVANCE(62);
-      if (lookahead == '"' ||
-          lookahead == '\'' ||
-          lookahead == '`' ||
-          lookahead == '{' ||
-          lookahead == '}') ADVANCE(12);
-      if (lookahead != 0) ADVANCE(63);
+      ACCEPT_TOKEN(anon_sym_LT_SLASH);
       END_STATE();
     case 64:
+      ACCEPT_TOKEN(anon_sym_EQ);
+      END_STATE();
+    case 65:
+      ACCEPT_TOKEN(anon_sym_EQ);
+      if (lookahead == '=') ADVANCE(97);
+      END_STATE();
+    case 66:
+      ACCEPT_TOKEN(anon_sym_COLON_EQ);
+      END_STATE();
+    case 67:
+      ACCEPT_TOKEN(anon_sym_COLON);
+      END_STATE();
+    case 68:
+      ACCEPT_TOKEN(anon_sym_COLON);
+      if (lookahead == '=') ADVANCE(66);
+      END_STATE();
+    case 69:
+      ACCEPT_TOKEN(anon_sym_AT);
+      END_STATE();
+    case 70:
+      ACCEPT_TOKEN(anon_sym_children);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(110);
+      END_STATE();
+    case 71:
+      ACCEPT_TOKEN(anon_sym_children);
+      if (lookahead != 0 &&
+          lookahead != '"' &&
+          lookahead != '\'' &&
+          lookahead != '/' &&
+          lookahead != '`' &&
+          lookahead != '{' &&
+          lookahead != '}') ADVANCE(81);
+      END_STATE();
+    case 72:
       ACCEPT_TOKEN(aux_sym_expression_content_token1);
-      if (lookahead == '/') ADVANCE(61);
-      if (lookahead == 'c') ADVANCE(68);
+      if (lookahead == 'c') ADVANCE(75);
       if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') ADVANCE(64);
+          lookahead == ' ') ADVANCE(72);
       if (lookahead != 0 &&
           lookahead != '"' &&
           lookahead != '\'' &&
+          lookahead != '/' &&
           lookahead != '`' &&
           lookahead != '{' &&
-          lookahead != '}') ADVANCE(73);
+          lookahead != '}') ADVANCE(81);
       END_STATE();
-    case 65:
+    case 73:
       ACCEPT_TOKEN(aux_sym_expression_content_token1);
-      if (lookahead == '/') ADVANCE(61);
-      if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') ADVANCE(65);
+      if (lookahead == 'd') ADVANCE(79);
       if (lookahead != 0 &&
           lookahead != '"' &&
           lookahead != '\'' &&
+          lookahead != '/' &&
           lookahead != '`' &&
           lookahead != '{' &&
-          lookahead != '}') ADVANCE(73);
+          lookahead != '}') ADVANCE(81);
       END_STATE();
-    case 66:
+    case 74:
       ACCEPT_TOKEN(aux_sym_expression_content_token1);
-      if (lookahead == 'd') ADVANCE(72);
+      if (lookahead == 'e') ADVANCE(78);
       if (lookahead != 0 &&
           lookahead != '"' &&
           lookahead != '\'' &&
+          lookahead != '/' &&
           lookahead != '`' &&
           lookahead != '{' &&
-          lookahead != '}') ADVANCE(73);
+          lookahead != '}') ADVANCE(81);
       END_STATE();
-    case 67:
+    case 75:
       ACCEPT_TOKEN(aux_sym_expression_content_token1);
-      if (lookahead == 'e') ADVANCE(71);
+      if (lookahead == 'h') ADVANCE(76);
       if (lookahead != 0 &&
           lookahead != '"' &&
           lookahead != '\'' &&
+          lookahead != '/' &&
           lookahead != '`' &&
           lookahead != '{' &&
-          lookahead != '}') ADVANCE(73);
+          lookahead != '}') ADVANCE(81);
       END_STATE();
-    case 68:
+    case 76:
       ACCEPT_TOKEN(aux_sym_expression_content_token1);
-      if (lookahead == 'h') ADVANCE(69);
+      if (lookahead == 'i') ADVANCE(77);
       if (lookahead != 0 &&
           lookahead != '"' &&
           lookahead != '\'' &&
+          lookahead != '/' &&
           lookahead != '`' &&
           lookahead != '{' &&
-          lookahead != '}') ADVANCE(73);
+          lookahead != '}') ADVANCE(81);
       END_STATE();
-    case 69:
+    case 77:
       ACCEPT_TOKEN(aux_sym_expression_content_token1);
-      if (lookahead == 'i') ADVANCE(70);
+      if (lookahead == 'l') ADVANCE(73);
       if (lookahead != 0 &&
           lookahead != '"' &&
           lookahead != '\'' &&
+          lookahead != '/' &&
           lookahead != '`' &&
           lookahead != '{' &&
-          lookahead != '}') ADVANCE(73);
+          lookahead != '}') ADVANCE(81);
       END_STATE();
-    case 70:
+    case 78:
       ACCEPT_TOKEN(aux_sym_expression_content_token1);
-      if (lookahead == 'l') ADVANCE(66);
+      if (lookahead == 'n') ADVANCE(71);
       if (lookahead != 0 &&
           lookahead != '"' &&
           lookahead != '\'' &&
+          lookahead != '/' &&
           lookahead != '`' &&
           lookahead != '{' &&
-          lookahead != '}') ADVANCE(73);
+          lookahead != '}') ADVANCE(81);
       END_STATE();
-    case 71:
+    case 79:
       ACCEPT_TOKEN(aux_sym_expression_content_token1);
-      if (lookahead == 'n') ADVANCE(58);
+      if (lookahead == 'r') ADVANCE(74);
       if (lookahead != 0 &&
           lookahead != '"' &&
           lookahead != '\'' &&
+          lookahead != '/' &&
           lookahead != '`' &&
           lookahead != '{' &&
-          lookahead != '}') ADVANCE(73);
+          lookahead != '}') ADVANCE(81);
       END_STATE();
-    case 72:
+    case 80:
       ACCEPT_TOKEN(aux_sym_expression_content_token1);
-      if (lookahead == 'r') ADVANCE(67);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(80);
       if (lookahead != 0 &&
           lookahead != '"' &&
           lookahead != '\'' &&
+          lookahead != '/' &&
           lookahead != '`' &&
           lookahead != '{' &&
-          lookahead != '}') ADVANCE(73);
+          lookahead != '}') ADVANCE(81);
       END_STATE();
-    case 73:
+    case 81:
       ACCEPT_TOKEN(aux_sym_expression_content_token1);
       if (lookahead != 0 &&
           lookahead != '"' &&
           lookahead != '\'' &&
+          lookahead != '/' &&
           lookahead != '`' &&
           lookahead != '{' &&
-          lookahead != '}') ADVANCE(73);
+          lookahead != '}') ADVANCE(81);
       END_STATE();
-    case 74:
+    case 82:
       ACCEPT_TOKEN(aux_sym_go_string_literal_token1);
       END_STATE();
-    case 75:
+    case 83:
       ACCEPT_TOKEN(aux_sym_go_string_literal_token2);
       END_STATE();
-    case 76:
+    case 84:
       ACCEPT_TOKEN(aux_sym_go_string_literal_token3);
       END_STATE();
-    case 77:
+    case 85:
       ACCEPT_TOKEN(aux_sym_go_string_literal_token4);
       END_STATE();
-    case 78:
-      ACCEPT_TOKEN(anon_sym_EQ_EQ);
+    case 86:
+      ACCEPT_TOKEN(anon_sym_PLUS);
       END_STATE();
-    case 79:
-      ACCEPT_TOKEN(anon_sym_BANG_EQ);
+    case 87:
+      ACCEPT_TOKEN(anon_sym_DASH);
       END_STATE();
-    case 80:
-      ACCEPT_TOKEN(anon_sym_LT_EQ);
+    case 88:
+      ACCEPT_TOKEN(anon_sym_BANG);
       END_STATE();
-    case 81:
-      ACCEPT_TOKEN(anon_sym_GT_EQ);
+    case 89:
+      ACCEPT_TOKEN(anon_sym_BANG);
+      if (lookahead == '=') ADVANCE(98);
       END_STATE();
-    case 82:
-      ACCEPT_TOKEN(anon_sym_PLUS);
+    case 90:
+      ACCEPT_TOKEN(anon_sym_CARET);
       END_STATE();
-    case 83:
-      ACCEPT_TOKEN(anon_sym_DASH);
+    case 91:
+      ACCEPT_TOKEN(anon_sym_AMP);
       END_STATE();
-    case 84:
-      ACCEPT_TOKEN(anon_sym_AMP_AMP);
+    case 92:
+      ACCEPT_TOKEN(anon_sym_AMP);
+      if (lookahead == '&') ADVANCE(101);
+      if (lookahead == '^') ADVANCE(96);
       END_STATE();
-    case 85:
-      ACCEPT_TOKEN(anon_sym_PIPE_PIPE);
+    case 93:
+      ACCEPT_TOKEN(anon_sym_PERCENT);
       END_STATE();
-    case 86:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'd') ADVANCE(92);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(93);
+    case 94:
+      ACCEPT_TOKEN(anon_sym_LT_LT);
       END_STATE();
-    case 87:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'e') ADVANCE(91);
+    case 95:
+      ACCEPT_TOKEN(anon_sym_GT_GT);
+      END_STATE();
+    case 96:
+      ACCEPT_TOKEN(anon_sym_AMP_CARET);
+      END_STATE();
+    case 97:
+      ACCEPT_TOKEN(anon_sym_EQ_EQ);
+      END_STATE();
+    case 98:
+      ACCEPT_TOKEN(anon_sym_BANG_EQ);
+      END_STATE();
+    case 99:
+      ACCEPT_TOKEN(anon_sym_LT_EQ);
+      END_STATE();
+    case 100:
+      ACCEPT_TOKEN(anon_sym_GT_EQ);
+      END_STATE();
+    case 101:
+      ACCEPT_TOKEN(anon_sym_AMP_AMP);
+      END_STATE();
+    case 102:
+      ACCEPT_TOKEN(anon_sym_PIPE_PIPE);
+      END_STATE();
+    case 103:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'd') ADVANCE(109);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(93);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(110);
       END_STATE();
-    case 88:
+    case 104:
       ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'h') ADVANCE(89);
+      if (lookahead == 'e') ADVANCE(108);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(93);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(110);
       END_STATE();
-    case 89:
+    case 105:
       ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'i') ADVANCE(90);
+      if (lookahead == 'h') ADVANCE(106);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(93);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(110);
       END_STATE();
-    case 90:
+    case 106:
       ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'l') ADVANCE(86);
+      if (lookahead == 'i') ADVANCE(107);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(93);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(110);
       END_STATE();
-    case 91:
+    case 107:
       ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'n') ADVANCE(57);
+      if (lookahead == 'l') ADVANCE(103);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(93);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(110);
       END_STATE();
-    case 92:
+    case 108:
       ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'r') ADVANCE(87);
+      if (lookahead == 'n') ADVANCE(70);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(93);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(110);
       END_STATE();
-    case 93:
+    case 109:
       ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'r') ADVANCE(104);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(93);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(110);
       END_STATE();
-    case 94:
+    case 110:
+      ACCEPT_TOKEN(sym_identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(110);
+      END_STATE();
+    case 111:
       ACCEPT_TOKEN(sym_number);
-      if (lookahead == '.') ADVANCE(23);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(94);
       END_STATE();
-    case 95:
+    case 112:
+      ACCEPT_TOKEN(sym_number);
+      ADVANCE_MAP(
+        '.', 128,
+        '_', 138,
+        'i', 130,
+        'B', 132,
+        'b', 132,
+        'E', 135,
+        'e', 135,
+        'O', 133,
+        'o', 133,
+        'X', 131,
+        'x', 131,
+        '8', 114,
+        '9', 114,
+      );
+      if (('0' <= lookahead && lookahead <= '7')) ADVANCE(113);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(145);
+      END_STATE();
+    case 113:
       ACCEPT_TOKEN(sym_number);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(95);
+      if (lookahead == '.') ADVANCE(128);
+      if (lookahead == '_') ADVANCE(138);
+      if (lookahead == 'i') ADVANCE(130);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(135);
+      if (lookahead == '8' ||
+          lookahead == '9') ADVANCE(114);
+      if (('0' <= lookahead && lookahead <= '7')) ADVANCE(113);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(145);
       END_STATE();
-    case 96:
-      ACCEPT_TOKEN(sym_string);
+    case 114:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '.') ADVANCE(128);
+      if (lookahead == '_') ADVANCE(140);
+      if (lookahead == 'i') ADVANCE(130);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(135);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(114);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(145);
       END_STATE();
-    case 97:
-      ACCEPT_TOKEN(sym_string);
-      if (lookahead == '"') ADVANCE(74);
-      if (lookahead == '\\') ADVANCE(24);
-      if (lookahead != 0) ADVANCE(3);
+    case 115:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '.') ADVANCE(136);
+      if (lookahead == '_') ADVANCE(143);
+      if (lookahead == 'i') ADVANCE(130);
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(135);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(115);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(145);
       END_STATE();
-    case 98:
+    case 116:
+      ACCEPT_TOKEN(sym_number);
+      ADVANCE_MAP(
+        '.', 129,
+        '_', 27,
+        'i', 111,
+        'B', 20,
+        'b', 20,
+        'E', 24,
+        'e', 24,
+        'O', 21,
+        'o', 21,
+        'X', 15,
+        'x', 15,
+        '8', 118,
+        '9', 118,
+      );
+      if (('0' <= lookahead && lookahead <= '7')) ADVANCE(117);
+      END_STATE();
+    case 117:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '.') ADVANCE(129);
+      if (lookahead == '_') ADVANCE(27);
+      if (lookahead == 'i') ADVANCE(111);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(24);
+      if (lookahead == '8' ||
+          lookahead == '9') ADVANCE(118);
+      if (('0' <= lookahead && lookahead <= '7')) ADVANCE(117);
+      END_STATE();
+    case 118:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '.') ADVANCE(129);
+      if (lookahead == '_') ADVANCE(31);
+      if (lookahead == 'i') ADVANCE(111);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(24);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(118);
+      END_STATE();
+    case 119:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '.') ADVANCE(25);
+      if (lookahead == '_') ADVANCE(32);
+      if (lookahead == 'i') ADVANCE(111);
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(24);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(119);
+      END_STATE();
+    case 120:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '_') ADVANCE(29);
+      if (lookahead == 'i') ADVANCE(111);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(24);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(120);
+      END_STATE();
+    case 121:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '_') ADVANCE(137);
+      if (lookahead == 'i') ADVANCE(130);
+      if (lookahead == '0' ||
+          lookahead == '1') ADVANCE(121);
+      if (lookahead == '.' ||
+          ('2' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(145);
+      END_STATE();
+    case 122:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '_') ADVANCE(139);
+      if (lookahead == 'i') ADVANCE(130);
+      if (('0' <= lookahead && lookahead <= '7')) ADVANCE(122);
+      if (lookahead == '.' ||
+          lookahead == '8' ||
+          lookahead == '9' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(145);
+      END_STATE();
+    case 123:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '_') ADVANCE(26);
+      if (lookahead == 'i') ADVANCE(111);
+      if (lookahead == '0' ||
+          lookahead == '1') ADVANCE(123);
+      END_STATE();
+    case 124:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '_') ADVANCE(28);
+      if (lookahead == 'i') ADVANCE(111);
+      if (('0' <= lookahead && lookahead <= '7')) ADVANCE(124);
+      END_STATE();
+    case 125:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '_') ADVANCE(141);
+      if (lookahead == 'i') ADVANCE(130);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(135);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(125);
+      if (lookahead == '.' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(145);
+      END_STATE();
+    case 126:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '_') ADVANCE(30);
+      if (lookahead == 'i') ADVANCE(111);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(126);
+      END_STATE();
+    case 127:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '_') ADVANCE(142);
+      if (lookahead == 'i') ADVANCE(130);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(127);
+      if (lookahead == '.' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(145);
+      END_STATE();
+    case 128:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == 'i') ADVANCE(130);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(135);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(125);
+      if (lookahead == '.' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(145);
+      END_STATE();
+    case 129:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == 'i') ADVANCE(111);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(24);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(120);
+      END_STATE();
+    case 130:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '.' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(145);
+      END_STATE();
+    case 131:
+      ACCEPT_TOKEN(aux_sym_go_declaration_token1);
+      if (lookahead == '.') ADVANCE(144);
+      if (lookahead == '_') ADVANCE(143);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(115);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(145);
+      END_STATE();
+    case 132:
+      ACCEPT_TOKEN(aux_sym_go_declaration_token1);
+      if (lookahead == '_') ADVANCE(137);
+      if (lookahead == '0' ||
+          lookahead == '1') ADVANCE(121);
+      if (lookahead == '.' ||
+          ('2' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(145);
+      END_STATE();
+    case 133:
+      ACCEPT_TOKEN(aux_sym_go_declaration_token1);
+      if (lookahead == '_') ADVANCE(139);
+      if (('0' <= lookahead && lookahead <= '7')) ADVANCE(122);
+      if (lookahead == '.' ||
+          lookahead == '8' ||
+          lookahead == '9' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(145);
+      END_STATE();
+    case 134:
+      ACCEPT_TOKEN(aux_sym_go_declaration_token1);
+      if (lookahead == '_') ADVANCE(144);
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(135);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(134);
+      if (lookahead == '.' ||
+          ('G' <= lookahead && lookahead <= 'Z') ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(145);
+      END_STATE();
+    case 135:
+      ACCEPT_TOKEN(aux_sym_go_declaration_token1);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(30);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(127);
+      if (lookahead == '.' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(145);
+      END_STATE();
+    case 136:
+      ACCEPT_TOKEN(aux_sym_go_declaration_token1);
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(135);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(134);
+      if (lookahead == '.' ||
+          ('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(145);
+      END_STATE();
+    case 137:
+      ACCEPT_TOKEN(aux_sym_go_declaration_token1);
+      if (lookahead == '0' ||
+          lookahead == '1') ADVANCE(121);
+      if (lookahead == '.' ||
+          ('2' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(145);
+      END_STATE();
+    case 138:
+      ACCEPT_TOKEN(aux_sym_go_declaration_token1);
+      if (lookahead == '8' ||
+          lookahead == '9') ADVANCE(114);
+      if (('0' <= lookahead && lookahead <= '7')) ADVANCE(113);
+      if (lookahead == '.' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(145);
+      END_STATE();
+    case 139:
+      ACCEPT_TOKEN(aux_sym_go_declaration_token1);
+      if (('0' <= lookahead && lookahead <= '7')) ADVANCE(122);
+      if (lookahead == '.' ||
+          lookahead == '8' ||
+          lookahead == '9' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(145);
+      END_STATE();
+    case 140:
+      ACCEPT_TOKEN(aux_sym_go_declaration_token1);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(114);
+      if (lookahead == '.' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(145);
+      END_STATE();
+    case 141:
+      ACCEPT_TOKEN(aux_sym_go_declaration_token1);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(125);
+      if (lookahead == '.' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(145);
+      END_STATE();
+    case 142:
+      ACCEPT_TOKEN(aux_sym_go_declaration_token1);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(127);
+      if (lookahead == '.' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(145);
+      END_STATE();
+    case 143:
+      ACCEPT_TOKEN(aux_sym_go_declaration_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(115);
+      if (lookahead == '.' ||
+          ('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(145);
+      END_STATE();
+    case 144:
+      ACCEPT_TOKEN(aux_sym_go_declaration_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(134);
+      if (lookahead == '.' ||
+          ('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(145);
+      END_STATE();
+    case 145:
       ACCEPT_TOKEN(aux_sym_go_declaration_token1);
-      if (lookahead == '*') ADVANCE(100);
-      if (lookahead == '/') ADVANCE(101);
+      if (lookahead == '.' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(145);
+      END_STATE();
+    case 146:
+      ACCEPT_TOKEN(aux_sym_go_declaration_token2);
+      if (lookahead == '*') ADVANCE(148);
+      if (lookahead == '/') ADVANCE(149);
       if (lookahead != 0 &&
           (lookahead < '\t' || '\r' < lookahead) &&
           lookahead != ' ' &&
@@ -2076,35 +3280,35 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
           (lookahead < '\'' || '*' < lookahead) &&
           (lookahead < '/' || '9' < lookahead) &&
           (lookahead < 'A' || 'Z' < lookahead) &&
-          (lookahead < '_' || '{' < lookahead)) ADVANCE(102);
+          (lookahead < '_' || '{' < lookahead)) ADVANCE(150);
       END_STATE();
-    case 99:
-      ACCEPT_TOKEN(aux_sym_go_declaration_token1);
-      if (lookahead == '*') ADVANCE(99);
-      if (lookahead == '/') ADVANCE(102);
+    case 147:
+      ACCEPT_TOKEN(aux_sym_go_declaration_token2);
+      if (lookahead == '*') ADVANCE(147);
+      if (lookahead == '/') ADVANCE(150);
       if (('\t' <= lookahead && lookahead <= '\r') ||
           lookahead == ' ' ||
           lookahead == '"' ||
           ('\'' <= lookahead && lookahead <= ')') ||
           ('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
-          ('_' <= lookahead && lookahead <= '{')) ADVANCE(12);
-      if (lookahead != 0) ADVANCE(100);
+          ('_' <= lookahead && lookahead <= '{')) ADVANCE(10);
+      if (lookahead != 0) ADVANCE(148);
       END_STATE();
-    case 100:
-      ACCEPT_TOKEN(aux_sym_go_declaration_token1);
-      if (lookahead == '*') ADVANCE(99);
+    case 148:
+      ACCEPT_TOKEN(aux_sym_go_declaration_token2);
+      if (lookahead == '*') ADVANCE(147);
       if (('\t' <= lookahead && lookahead <= '\r') ||
           lookahead == ' ' ||
           lookahead == '"' ||
           ('\'' <= lookahead && lookahead <= ')') ||
           ('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
-          ('_' <= lookahead && lookahead <= '{')) ADVANCE(12);
-      if (lookahead != 0) ADVANCE(100);
+          ('_' <= lookahead && lookahead <= '{')) ADVANCE(10);
+      if (lookahead != 0) ADVANCE(148);
       END_STATE();
-    case 101:
-      ACCEPT_TOKEN(aux_sym_go_declaration_token1);
+    case 149:
+      ACCEPT_TOKEN(aux_sym_go_declaration_token2);
       if (lookahead == '\t' ||
           (0x0b <= lookahead && lookahead <= '\r') ||
           lookahead == ' ' ||
@@ -2112,12 +3316,12 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
           ('\'' <= lookahead && lookahead <= ')') ||
           ('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
-          ('_' <= lookahead && lookahead <= '{')) ADVANCE(115);
+          ('_' <= lookahead && lookahead <= '{')) ADVANCE(159);
       if (lookahead != 0 &&
-          (lookahead < '\t' || '\r' < lookahead)) ADVANCE(101);
+          (lookahead < '\t' || '\r' < lookahead)) ADVANCE(149);
       END_STATE();
-    case 102:
-      ACCEPT_TOKEN(aux_sym_go_declaration_token1);
+    case 150:
+      ACCEPT_TOKEN(aux_sym_go_declaration_token2);
       if (lookahead != 0 &&
           (lookahead < '\t' || '\r' < lookahead) &&
           lookahead != ' ' &&
@@ -2125,105 +3329,72 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
           (lookahead < '\'' || ')' < lookahead) &&
           (lookahead < '0' || '9' < lookahead) &&
           (lookahead < 'A' || 'Z' < lookahead) &&
-          (lookahead < '_' || '{' < lookahead)) ADVANCE(102);
+          (lookahead < '_' || '{' < lookahead)) ADVANCE(150);
       END_STATE();
-    case 103:
-      ACCEPT_TOKEN(aux_sym_go_declaration_token2);
-      if (lookahead == '\n') ADVANCE(108);
-      if (lookahead == ')') ADVANCE(115);
-      if (lookahead != 0) ADVANCE(103);
+    case 151:
+      ACCEPT_TOKEN(aux_sym_go_declaration_token3);
+      if (lookahead == '\n') ADVANCE(156);
+      if (lookahead == ')') ADVANCE(159);
+      if (lookahead != 0) ADVANCE(151);
       END_STATE();
-    case 104:
-      ACCEPT_TOKEN(aux_sym_go_declaration_token2);
-      if (lookahead == ')') ADVANCE(12);
-      if (lookahead == '*') ADVANCE(104);
-      if (lookahead == '/') ADVANCE(108);
-      if (lookahead != 0) ADVANCE(105);
+    case 152:
+      ACCEPT_TOKEN(aux_sym_go_declaration_token3);
+      if (lookahead == ')') ADVANCE(10);
+      if (lookahead == '*') ADVANCE(152);
+      if (lookahead == '/') ADVANCE(156);
+      if (lookahead != 0) ADVANCE(153);
       END_STATE();
-    case 105:
-      ACCEPT_TOKEN(aux_sym_go_declaration_token2);
-      if (lookahead == ')') ADVANCE(12);
-      if (lookahead == '*') ADVANCE(104);
-      if (lookahead != 0) ADVANCE(105);
+    case 153:
+      ACCEPT_TOKEN(aux_sym_go_declaration_token3);
+      if (lookahead == ')') ADVANCE(10);
+      if (lookahead == '*') ADVANCE(152);
+      if (lookahead != 0) ADVANCE(153);
       END_STATE();
-    case 106:
-      ACCEPT_TOKEN(aux_sym_go_declaration_token2);
-      if (lookahead == '*') ADVANCE(105);
-      if (lookahead == '/') ADVANCE(103);
+    case 154:
+      ACCEPT_TOKEN(aux_sym_go_declaration_token3);
+      if (lookahead == '*') ADVANCE(153);
+      if (lookahead == '/') ADVANCE(151);
       if (lookahead != 0 &&
           lookahead != ')' &&
-          lookahead != '*') ADVANCE(108);
+          lookahead != '*') ADVANCE(156);
       END_STATE();
-    case 107:
-      ACCEPT_TOKEN(aux_sym_go_declaration_token2);
-      if (lookahead == '/') ADVANCE(106);
+    case 155:
+      ACCEPT_TOKEN(aux_sym_go_declaration_token3);
+      if (lookahead == '/') ADVANCE(154);
       if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') ADVANCE(107);
+          lookahead == ' ') ADVANCE(155);
       if (lookahead != 0 &&
-          lookahead != ')') ADVANCE(108);
-      END_STATE();
-    case 108:
-      ACCEPT_TOKEN(aux_sym_go_declaration_token2);
-      if (lookahead != 0 &&
-          lookahead != ')') ADVANCE(108);
-      END_STATE();
-    case 109:
-      ACCEPT_TOKEN(aux_sym_go_paren_body_token1);
-      if (lookahead == '\n') ADVANCE(114);
-      if (lookahead == '"' ||
-          ('\'' <= lookahead && lookahead <= ')') ||
-          lookahead == '`') ADVANCE(115);
-      if (lookahead != 0) ADVANCE(109);
+          lookahead != ')') ADVANCE(156);
       END_STATE();
-    case 110:
-      ACCEPT_TOKEN(aux_sym_go_paren_body_token1);
-      if (lookahead == '*') ADVANCE(112);
-      if (lookahead == '/') ADVANCE(109);
+    case 156:
+      ACCEPT_TOKEN(aux_sym_go_declaration_token3);
       if (lookahead != 0 &&
-          lookahead != '"' &&
-          (lookahead < '\'' || '*' < lookahead) &&
-          lookahead != '`') ADVANCE(114);
-      END_STATE();
-    case 111:
-      ACCEPT_TOKEN(aux_sym_go_paren_body_token1);
-      if (lookahead == '*') ADVANCE(111);
-      if (lookahead == '/') ADVANCE(114);
-      if (lookahead == '"' ||
-          ('\'' <= lookahead && lookahead <= ')') ||
-          lookahead == '`') ADVANCE(12);
-      if (lookahead != 0) ADVANCE(112);
+          lookahead != ')') ADVANCE(156);
       END_STATE();
-    case 112:
-      ACCEPT_TOKEN(aux_sym_go_paren_body_token1);
-      if (lookahead == '*') ADVANCE(111);
-      if (lookahead == '"' ||
-          ('\'' <= lookahead && lookahead <= ')') ||
-          lookahead == '`') ADVANCE(12);
-      if (lookahead != 0) ADVANCE(112);
-      END_STATE();
-    case 113:
+    case 157:
       ACCEPT_TOKEN(aux_sym_go_paren_body_token1);
-      if (lookahead == '/') ADVANCE(110);
       if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') ADVANCE(113);
+          lookahead == ' ') ADVANCE(157);
       if (lookahead != 0 &&
           lookahead != '"' &&
           (lookahead < '\'' || ')' < lookahead) &&
-          lookahead != '`') ADVANCE(114);
+          lookahead != '/' &&
+          lookahead != '`') ADVANCE(158);
       END_STATE();
-    case 114:
+    case 158:
       ACCEPT_TOKEN(aux_sym_go_paren_body_token1);
       if (lookahead != 0 &&
           lookahead != '"' &&
           (lookahead < '\'' || ')' < lookahead) &&
-          lookahead != '`') ADVANCE(114);
+          lookahead != '/' &&
+          lookahead != '`') ADVANCE(158);
       END_STATE();
-    case 115:
+    case 159:
       ACCEPT_TOKEN(aux_sym_comment_token1);
       if (lookahead != 0 &&
-          lookahead != '\n') ADVANCE(115);
+          lookahead != '\n') ADVANCE(159);
       END_STATE();
-    case 116:
+    case 160:
       ACCEPT_TOKEN(aux_sym_comment_token2);
       END_STATE();
     default:
@@ -2238,568 +3409,928 @@ static bool ts_lex_keywords(TSLexer *lexer, TSStateId state) {
     case 0:
       ADVANCE_MAP(
         'c', 1,
-        'e', 2,
-        'f', 3,
-        'i', 4,
-        'm', 5,
-        'p', 6,
-        'r', 7,
-        's', 8,
-        't', 9,
-        'v', 10,
+        'd', 2,
+        'e', 3,
+        'f', 4,
+        'i', 5,
+        'm', 6,
+        'p', 7,
+        'r', 8,
+        's', 9,
+        't', 10,
+        'v', 11,
       );
       if (('\t' <= lookahead && lookahead <= '\r') ||
           lookahead == ' ') SKIP(0);
       END_STATE();
     case 1:
-      if (lookahead == 'o') ADVANCE(11);
+      if (lookahead == 'a') ADVANCE(12);
+      if (lookahead == 'h') ADVANCE(13);
+      if (lookahead == 'o') ADVANCE(14);
       END_STATE();
     case 2:
-      if (lookahead == 'l') ADVANCE(12);
+      if (lookahead == 'e') ADVANCE(15);
       END_STATE();
     case 3:
-      if (lookahead == 'a') ADVANCE(13);
-      if (lookahead == 'o') ADVANCE(14);
-      if (lookahead == 'u') ADVANCE(15);
+      if (lookahead == 'l') ADVANCE(16);
       END_STATE();
     case 4:
-      if (lookahead == 'f') ADVANCE(16);
-      if (lookahead == 'm') ADVANCE(17);
+      if (lookahead == 'a') ADVANCE(17);
+      if (lookahead == 'o') ADVANCE(18);
+      if (lookahead == 'u') ADVANCE(19);
       END_STATE();
     case 5:
-      if (lookahead == 'a') ADVANCE(18);
+      if (lookahead == 'f') ADVANCE(20);
+      if (lookahead == 'm') ADVANCE(21);
+      if (lookahead == 'n') ADVANCE(22);
       END_STATE();
     case 6:
-      if (lookahead == 'a') ADVANCE(19);
+      if (lookahead == 'a') ADVANCE(23);
       END_STATE();
     case 7:
-      if (lookahead == 'a') ADVANCE(20);
+      if (lookahead == 'a') ADVANCE(24);
       END_STATE();
     case 8:
-      if (lookahead == 't') ADVANCE(21);
+      if (lookahead == 'a') ADVANCE(25);
       END_STATE();
     case 9:
-      if (lookahead == 'e') ADVANCE(22);
-      if (lookahead == 'r') ADVANCE(23);
-      if (lookahead == 'y') ADVANCE(24);
+      if (lookahead == 't') ADVANCE(26);
+      if (lookahead == 'w') ADVANCE(27);
       END_STATE();
     case 10:
-      if (lookahead == 'a') ADVANCE(25);
+      if (lookahead == 'e') ADVANCE(28);
+      if (lookahead == 'r') ADVANCE(29);
+      if (lookahead == 'y') ADVANCE(30);
       END_STATE();
     case 11:
-      if (lookahead == 'n') ADVANCE(26);
+      if (lookahead == 'a') ADVANCE(31);
       END_STATE();
     case 12:
-      if (lookahead == 's') ADVANCE(27);
+      if (lookahead == 's') ADVANCE(32);
       END_STATE();
     case 13:
-      if (lookahead == 'l') ADVANCE(28);
+      if (lookahead == 'a') ADVANCE(33);
       END_STATE();
     case 14:
-      if (lookahead == 'r') ADVANCE(29);
+      if (lookahead == 'n') ADVANCE(34);
       END_STATE();
     case 15:
-      if (lookahead == 'n') ADVANCE(30);
+      if (lookahead == 'f') ADVANCE(35);
       END_STATE();
     case 16:
-      ACCEPT_TOKEN(anon_sym_if);
+      if (lookahead == 's') ADVANCE(36);
       END_STATE();
     case 17:
-      if (lookahead == 'p') ADVANCE(31);
+      if (lookahead == 'l') ADVANCE(37);
       END_STATE();
     case 18:
-      if (lookahead == 'p') ADVANCE(32);
+      if (lookahead == 'r') ADVANCE(38);
       END_STATE();
     case 19:
-      if (lookahead == 'c') ADVANCE(33);
+      if (lookahead == 'n') ADVANCE(39);
       END_STATE();
     case 20:
-      if (lookahead == 'n') ADVANCE(34);
+      ACCEPT_TOKEN(anon_sym_if);
       END_STATE();
     case 21:
-      if (lookahead == 'r') ADVANCE(35);
+      if (lookahead == 'p') ADVANCE(40);
       END_STATE();
     case 22:
-      if (lookahead == 'm') ADVANCE(36);
+      if (lookahead == 't') ADVANCE(41);
       END_STATE();
     case 23:
-      if (lookahead == 'u') ADVANCE(37);
+      if (lookahead == 'p') ADVANCE(42);
       END_STATE();
     case 24:
-      if (lookahead == 'p') ADVANCE(38);
+      if (lookahead == 'c') ADVANCE(43);
       END_STATE();
     case 25:
-      if (lookahead == 'r') ADVANCE(39);
+      if (lookahead == 'n') ADVANCE(44);
       END_STATE();
     case 26:
-      if (lookahead == 's') ADVANCE(40);
+      if (lookahead == 'r') ADVANCE(45);
       END_STATE();
     case 27:
-      if (lookahead == 'e') ADVANCE(41);
+      if (lookahead == 'i') ADVANCE(46);
       END_STATE();
     case 28:
-      if (lookahead == 's') ADVANCE(42);
+      if (lookahead == 'm') ADVANCE(47);
       END_STATE();
     case 29:
-      ACCEPT_TOKEN(anon_sym_for);
+      if (lookahead == 'u') ADVANCE(48);
       END_STATE();
     case 30:
-      if (lookahead == 'c') ADVANCE(43);
+      if (lookahead == 'p') ADVANCE(49);
       END_STATE();
     case 31:
-      if (lookahead == 'o') ADVANCE(44);
+      if (lookahead == 'r') ADVANCE(50);
       END_STATE();
     case 32:
-      ACCEPT_TOKEN(anon_sym_map);
+      if (lookahead == 'e') ADVANCE(51);
       END_STATE();
     case 33:
-      if (lookahead == 'k') ADVANCE(45);
+      if (lookahead == 'n') ADVANCE(52);
       END_STATE();
     case 34:
-      if (lookahead == 'g') ADVANCE(46);
+      if (lookahead == 's') ADVANCE(53);
       END_STATE();
     case 35:
-      if (lookahead == 'u') ADVANCE(47);
+      if (lookahead == 'a') ADVANCE(54);
       END_STATE();
     case 36:
-      if (lookahead == 'p') ADVANCE(48);
+      if (lookahead == 'e') ADVANCE(55);
       END_STATE();
     case 37:
-      if (lookahead == 'e') ADVANCE(49);
+      if (lookahead == 's') ADVANCE(56);
       END_STATE();
     case 38:
-      if (lookahead == 'e') ADVANCE(50);
+      ACCEPT_TOKEN(anon_sym_for);
       END_STATE();
     case 39:
-      ACCEPT_TOKEN(anon_sym_var);
+      if (lookahead == 'c') ADVANCE(57);
       END_STATE();
     case 40:
-      if (lookahead == 't') ADVANCE(51);
+      if (lookahead == 'o') ADVANCE(58);
       END_STATE();
     case 41:
-      ACCEPT_TOKEN(anon_sym_else);
+      if (lookahead == 'e') ADVANCE(59);
       END_STATE();
     case 42:
-      if (lookahead == 'e') ADVANCE(52);
+      ACCEPT_TOKEN(anon_sym_map);
       END_STATE();
     case 43:
-      ACCEPT_TOKEN(anon_sym_func);
+      if (lookahead == 'k') ADVANCE(60);
       END_STATE();
     case 44:
-      if (lookahead == 'r') ADVANCE(53);
+      if (lookahead == 'g') ADVANCE(61);
       END_STATE();
     case 45:
-      if (lookahead == 'a') ADVANCE(54);
+      if (lookahead == 'u') ADVANCE(62);
       END_STATE();
     case 46:
-      if (lookahead == 'e') ADVANCE(55);
+      if (lookahead == 't') ADVANCE(63);
       END_STATE();
     case 47:
-      if (lookahead == 'c') ADVANCE(56);
+      if (lookahead == 'p') ADVANCE(64);
       END_STATE();
     case 48:
-      if (lookahead == 'l') ADVANCE(57);
+      if (lookahead == 'e') ADVANCE(65);
       END_STATE();
     case 49:
-      ACCEPT_TOKEN(sym_true);
+      if (lookahead == 'e') ADVANCE(66);
       END_STATE();
     case 50:
-      ACCEPT_TOKEN(anon_sym_type);
+      ACCEPT_TOKEN(anon_sym_var);
       END_STATE();
     case 51:
-      ACCEPT_TOKEN(anon_sym_const);
+      ACCEPT_TOKEN(anon_sym_case);
       END_STATE();
     case 52:
-      ACCEPT_TOKEN(sym_false);
+      ACCEPT_TOKEN(anon_sym_chan);
       END_STATE();
     case 53:
-      if (lookahead == 't') ADVANCE(58);
+      if (lookahead == 't') ADVANCE(67);
       END_STATE();
     case 54:
-      if (lookahead == 'g') ADVANCE(59);
+      if (lookahead == 'u') ADVANCE(68);
       END_STATE();
     case 55:
-      ACCEPT_TOKEN(anon_sym_range);
+      ACCEPT_TOKEN(anon_sym_else);
       END_STATE();
     case 56:
-      if (lookahead == 't') ADVANCE(60);
+      if (lookahead == 'e') ADVANCE(69);
       END_STATE();
     case 57:
-      ACCEPT_TOKEN(anon_sym_templ);
+      ACCEPT_TOKEN(anon_sym_func);
       END_STATE();
     case 58:
-      ACCEPT_TOKEN(anon_sym_import);
+      if (lookahead == 'r') ADVANCE(70);
       END_STATE();
     case 59:
-      if (lookahead == 'e') ADVANCE(61);
+      if (lookahead == 'r') ADVANCE(71);
       END_STATE();
     case 60:
-      ACCEPT_TOKEN(anon_sym_struct);
+      if (lookahead == 'a') ADVANCE(72);
       END_STATE();
     case 61:
-      ACCEPT_TOKEN(anon_sym_package);
+      if (lookahead == 'e') ADVANCE(73);
       END_STATE();
-    default:
-      return false;
-  }
-}
-
-static const TSLexerMode ts_lex_modes[STATE_COUNT] = {
-  [0] = {.lex_state = 0},
-  [1] = {.lex_state = 26},
-  [2] = {.lex_state = 26},
-  [3] = {.lex_state = 26},
-  [4] = {.lex_state = 26},
-  [5] = {.lex_state = 26},
-  [6] = {.lex_state = 16},
-  [7] = {.lex_state = 16},
-  [8] = {.lex_state = 26},
-  [9] = {.lex_state = 26},
-  [10] = {.lex_state = 26},
-  [11] = {.lex_state = 16},
-  [12] = {.lex_state = 26},
-  [13] = {.lex_state = 16},
-  [14] = {.lex_state = 16},
-  [15] = {.lex_state = 26},
-  [16] = {.lex_state = 26},
-  [17] = {.lex_state = 26},
-  [18] = {.lex_state = 16},
-  [19] = {.lex_state = 16},
-  [20] = {.lex_state = 26},
-  [21] = {.lex_state = 26},
-  [22] = {.lex_state = 26},
-  [23] = {.lex_state = 16},
-  [24] = {.lex_state = 16},
-  [25] = {.lex_state = 26},
-  [26] = {.lex_state = 26},
-  [27] = {.lex_state = 26},
-  [28] = {.lex_state = 26},
-  [29] = {.lex_state = 26},
-  [30] = {.lex_state = 26},
-  [31] = {.lex_state = 26},
-  [32] = {.lex_state = 26},
-  [33] = {.lex_state = 26},
-  [34] = {.lex_state = 26},
-  [35] = {.lex_state = 26},
-  [36] = {.lex_state = 26},
-  [37] = {.lex_state = 26},
-  [38] = {.lex_state = 26},
-  [39] = {.lex_state = 26},
-  [40] = {.lex_state = 26},
-  [41] = {.lex_state = 26},
-  [42] = {.lex_state = 26},
-  [43] = {.lex_state = 26},
-  [44] = {.lex_state = 26},
-  [45] = {.lex_state = 26},
-  [46] = {.lex_state = 26},
-  [47] = {.lex_state = 26},
-  [48] = {.lex_state = 26},
-  [49] = {.lex_state = 26},
-  [50] = {.lex_state = 26},
-  [51] = {.lex_state = 26},
-  [52] = {.lex_state = 26},
-  [53] = {.lex_state = 26},
-  [54] = {.lex_state = 26},
-  [55] = {.lex_state = 26},
-  [56] = {.lex_state = 26},
-  [57] = {.lex_state = 26},
-  [58] = {.lex_state = 26},
-  [59] = {.lex_state = 26},
-  [60] = {.lex_state = 26},
-  [61] = {.lex_state = 26},
-  [62] = {.lex_state = 26},
-  [63] = {.lex_state = 26},
-  [64] = {.lex_state = 26},
-  [65] = {.lex_state = 26},
-  [66] = {.lex_state = 27},
-  [67] = {.lex_state = 26},
-  [68] = {.lex_state = 26},
-  [69] = {.lex_state = 6},
-  [70] = {.lex_state = 26},
-  [71] = {.lex_state = 26},
-  [72] = {.lex_state = 26},
-  [73] = {.lex_state = 26},
-  [74] = {.lex_state = 27},
-  [75] = {.lex_state = 26},
-  [76] = {.lex_state = 7},
-  [77] = {.lex_state = 7},
-  [78] = {.lex_state = 26},
-  [79] = {.lex_state = 6},
-  [80] = {.lex_state = 26},
-  [81] = {.lex_state = 27},
-  [82] = {.lex_state = 26},
-  [83] = {.lex_state = 26},
-  [84] = {.lex_state = 26},
-  [85] = {.lex_state = 26},
-  [86] = {.lex_state = 26},
-  [87] = {.lex_state = 16},
-  [88] = {.lex_state = 5},
-  [89] = {.lex_state = 5},
-  [90] = {.lex_state = 27},
-  [91] = {.lex_state = 7},
-  [92] = {.lex_state = 7},
-  [93] = {.lex_state = 7},
-  [94] = {.lex_state = 7},
-  [95] = {.lex_state = 16},
-  [96] = {.lex_state = 16},
-  [97] = {.lex_state = 16},
-  [98] = {.lex_state = 5},
-  [99] = {.lex_state = 15},
-  [100] = {.lex_state = 5},
-  [101] = {.lex_state = 5},
-  [102] = {.lex_state = 7},
-  [103] = {.lex_state = 7},
-  [104] = {.lex_state = 16},
-  [105] = {.lex_state = 16},
-  [106] = {.lex_state = 16},
-  [107] = {.lex_state = 16},
-  [108] = {.lex_state = 26},
-  [109] = {.lex_state = 16},
-  [110] = {.lex_state = 16},
-  [111] = {.lex_state = 16},
-  [112] = {.lex_state = 16},
-  [113] = {.lex_state = 16},
-  [114] = {.lex_state = 16},
-  [115] = {.lex_state = 16},
-  [116] = {.lex_state = 16},
-  [117] = {.lex_state = 16},
-  [118] = {.lex_state = 16},
-  [119] = {.lex_state = 16},
-  [120] = {.lex_state = 16},
-  [121] = {.lex_state = 26},
-  [122] = {.lex_state = 26},
-  [123] = {.lex_state = 26},
-  [124] = {.lex_state = 26},
-  [125] = {.lex_state = 16},
-  [126] = {.lex_state = 16},
-  [127] = {.lex_state = 16},
-  [128] = {.lex_state = 16},
-  [129] = {.lex_state = 26},
-  [130] = {.lex_state = 26},
-  [131] = {.lex_state = 26},
-  [132] = {.lex_state = 26},
-  [133] = {.lex_state = 26},
-  [134] = {.lex_state = 26},
-  [135] = {.lex_state = 26},
-  [136] = {.lex_state = 26},
-  [137] = {.lex_state = 26},
-  [138] = {.lex_state = 26},
-  [139] = {.lex_state = 26},
-  [140] = {.lex_state = 26},
-  [141] = {.lex_state = 26},
-  [142] = {.lex_state = 26},
-  [143] = {.lex_state = 26},
-  [144] = {.lex_state = 26},
-  [145] = {.lex_state = 26},
-  [146] = {.lex_state = 26},
-  [147] = {.lex_state = 5},
-  [148] = {.lex_state = 26},
-  [149] = {.lex_state = 7},
-  [150] = {.lex_state = 26},
-  [151] = {.lex_state = 26},
-  [152] = {.lex_state = 7},
-  [153] = {.lex_state = 26},
-  [154] = {.lex_state = 5},
-  [155] = {.lex_state = 26},
-  [156] = {.lex_state = 5},
-  [157] = {.lex_state = 26},
-  [158] = {.lex_state = 5},
-  [159] = {.lex_state = 26},
-  [160] = {.lex_state = 26},
-  [161] = {.lex_state = 7},
-  [162] = {.lex_state = 26},
-  [163] = {.lex_state = 26},
-  [164] = {.lex_state = 7},
-  [165] = {.lex_state = 26},
-  [166] = {.lex_state = 26},
-  [167] = {.lex_state = 26},
-  [168] = {.lex_state = 26},
-  [169] = {.lex_state = 26},
-  [170] = {.lex_state = 26},
-  [171] = {.lex_state = 26},
-  [172] = {.lex_state = 26},
-  [173] = {.lex_state = 26},
-  [174] = {.lex_state = 26},
-  [175] = {.lex_state = 26},
-  [176] = {.lex_state = 26},
-  [177] = {.lex_state = 26},
-  [178] = {.lex_state = 26},
-  [179] = {.lex_state = 0},
-  [180] = {.lex_state = 26},
-  [181] = {.lex_state = 26},
-  [182] = {.lex_state = 26},
-  [183] = {.lex_state = 26},
-  [184] = {.lex_state = 26},
-  [185] = {.lex_state = 26},
-  [186] = {.lex_state = 26},
-  [187] = {.lex_state = 26},
-  [188] = {.lex_state = 26},
-  [189] = {.lex_state = 26},
-  [190] = {.lex_state = 26},
-  [191] = {.lex_state = 26},
-  [192] = {.lex_state = 26},
-  [193] = {.lex_state = 26},
-  [194] = {.lex_state = 0},
-  [195] = {.lex_state = 26},
-  [196] = {.lex_state = 0},
-  [197] = {.lex_state = 26},
-  [198] = {.lex_state = 26},
-  [199] = {.lex_state = 26},
-  [200] = {.lex_state = 26},
-  [201] = {.lex_state = 26},
-  [202] = {.lex_state = 26},
-  [203] = {.lex_state = 15},
-  [204] = {.lex_state = 27},
-  [205] = {.lex_state = 26},
-  [206] = {.lex_state = 15},
-  [207] = {.lex_state = 26},
-  [208] = {.lex_state = 27},
-  [209] = {.lex_state = 26},
-  [210] = {.lex_state = 26},
-  [211] = {.lex_state = 26},
-  [212] = {.lex_state = 15},
-  [213] = {.lex_state = 15},
-  [214] = {.lex_state = 15},
-  [215] = {.lex_state = 26},
-  [216] = {.lex_state = 26},
-  [217] = {.lex_state = 26},
-  [218] = {.lex_state = 26},
-  [219] = {.lex_state = 26},
-  [220] = {.lex_state = 15},
-  [221] = {.lex_state = 0},
-  [222] = {.lex_state = 26},
-  [223] = {.lex_state = 0},
-  [224] = {.lex_state = 0},
-  [225] = {.lex_state = 0},
-  [226] = {.lex_state = 15},
-  [227] = {.lex_state = 0},
-  [228] = {.lex_state = 26},
-  [229] = {.lex_state = 26},
-  [230] = {.lex_state = 15},
-  [231] = {.lex_state = 26},
-  [232] = {.lex_state = 0},
-  [233] = {.lex_state = 0},
-  [234] = {.lex_state = 0},
-  [235] = {.lex_state = 26},
-  [236] = {.lex_state = 15},
-  [237] = {.lex_state = 0},
-  [238] = {.lex_state = 0},
-  [239] = {.lex_state = 0},
-  [240] = {.lex_state = 26},
-  [241] = {.lex_state = 0},
-  [242] = {.lex_state = 26},
-  [243] = {.lex_state = 0},
-  [244] = {.lex_state = 26},
-  [245] = {.lex_state = 0},
-  [246] = {.lex_state = 0},
-  [247] = {.lex_state = 0},
-  [248] = {.lex_state = 0},
-  [249] = {.lex_state = 0},
-  [250] = {.lex_state = 0},
-  [251] = {.lex_state = 26},
-  [252] = {.lex_state = 0},
-  [253] = {.lex_state = 0},
-  [254] = {.lex_state = 0},
-  [255] = {.lex_state = 0},
-  [256] = {.lex_state = 0},
-  [257] = {.lex_state = 26},
-  [258] = {.lex_state = 0},
-  [259] = {.lex_state = 0},
-  [260] = {.lex_state = 0},
-  [261] = {.lex_state = 26},
-  [262] = {.lex_state = 0},
-  [263] = {.lex_state = 26},
-  [264] = {.lex_state = 0},
-  [265] = {.lex_state = 0},
-  [266] = {.lex_state = 26},
-  [267] = {.lex_state = 0},
-  [268] = {.lex_state = 0},
-  [269] = {.lex_state = 26},
-  [270] = {.lex_state = 26},
-  [271] = {.lex_state = 26},
-  [272] = {.lex_state = 15},
-  [273] = {.lex_state = 0},
-  [274] = {.lex_state = 26},
-  [275] = {.lex_state = 0},
-  [276] = {.lex_state = 0},
-  [277] = {.lex_state = 0},
-  [278] = {.lex_state = 26},
-  [279] = {.lex_state = 0},
-  [280] = {.lex_state = 107},
-  [281] = {.lex_state = 26},
-  [282] = {.lex_state = 0},
-  [283] = {.lex_state = 26},
-  [284] = {.lex_state = 0},
-  [285] = {.lex_state = 0},
-  [286] = {.lex_state = 0},
-  [287] = {.lex_state = 0},
-  [288] = {.lex_state = 15},
-  [289] = {.lex_state = 0},
-  [290] = {.lex_state = 0},
-  [291] = {.lex_state = 0},
-  [292] = {.lex_state = 15},
-  [293] = {.lex_state = 15},
-  [294] = {.lex_state = 26},
-  [295] = {.lex_state = 26},
-  [296] = {.lex_state = 0},
-  [297] = {.lex_state = 26},
-  [298] = {.lex_state = 107},
-  [299] = {.lex_state = 0},
-  [300] = {.lex_state = 15},
-  [301] = {.lex_state = 26},
-  [302] = {.lex_state = 15},
-  [303] = {.lex_state = 15},
-  [304] = {.lex_state = 15},
-  [305] = {.lex_state = 0},
-  [306] = {.lex_state = 26},
-  [307] = {.lex_state = 15},
-  [308] = {.lex_state = 15},
-  [309] = {.lex_state = 0},
-  [310] = {.lex_state = 26},
-  [311] = {.lex_state = 15},
-  [312] = {.lex_state = 26},
-  [313] = {.lex_state = 15},
-  [314] = {.lex_state = 26},
-  [315] = {.lex_state = 15},
-  [316] = {.lex_state = 26},
-  [317] = {.lex_state = 26},
-  [318] = {.lex_state = 26},
-  [319] = {.lex_state = 0},
-  [320] = {.lex_state = 26},
-  [321] = {.lex_state = 26},
-  [322] = {.lex_state = 26},
-  [323] = {.lex_state = 0},
-  [324] = {.lex_state = 26},
-  [325] = {.lex_state = 26},
-  [326] = {.lex_state = 0},
-  [327] = {.lex_state = 26},
-  [328] = {.lex_state = 0},
-  [329] = {.lex_state = 0},
-  [330] = {.lex_state = 15},
-  [331] = {(TSStateId)(-1),},
-};
-
-static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
-  [STATE(0)] = {
-    [sym_comment] = STATE(0),
-    [ts_builtin_sym_end] = ACTIONS(1),
-    [sym_identifier] = ACTIONS(1),
+    case 62:
+      if (lookahead == 'c') ADVANCE(74);
+      END_STATE();
+    case 63:
+      if (lookahead == 'c') ADVANCE(75);
+      END_STATE();
+    case 64:
+      if (lookahead == 'l') ADVANCE(76);
+      END_STATE();
+    case 65:
+      ACCEPT_TOKEN(sym_true);
+      END_STATE();
+    case 66:
+      ACCEPT_TOKEN(anon_sym_type);
+      END_STATE();
+    case 67:
+      ACCEPT_TOKEN(anon_sym_const);
+      END_STATE();
+    case 68:
+      if (lookahead == 'l') ADVANCE(77);
+      END_STATE();
+    case 69:
+      ACCEPT_TOKEN(sym_false);
+      END_STATE();
+    case 70:
+      if (lookahead == 't') ADVANCE(78);
+      END_STATE();
+    case 71:
+      if (lookahead == 'f') ADVANCE(79);
+      END_STATE();
+    case 72:
+      if (lookahead == 'g') ADVANCE(80);
+      END_STATE();
+    case 73:
+      ACCEPT_TOKEN(anon_sym_range);
+      END_STATE();
+    case 74:
+      if (lookahead == 't') ADVANCE(81);
+      END_STATE();
+    case 75:
+      if (lookahead == 'h') ADVANCE(82);
+      END_STATE();
+    case 76:
+      ACCEPT_TOKEN(anon_sym_templ);
+      END_STATE();
+    case 77:
+      if (lookahead == 't') ADVANCE(83);
+      END_STATE();
+    case 78:
+      ACCEPT_TOKEN(anon_sym_import);
+      END_STATE();
+    case 79:
+      if (lookahead == 'a') ADVANCE(84);
+      END_STATE();
+    case 80:
+      if (lookahead == 'e') ADVANCE(85);
+      END_STATE();
+    case 81:
+      ACCEPT_TOKEN(anon_sym_struct);
+      END_STATE();
+    case 82:
+      ACCEPT_TOKEN(anon_sym_switch);
+      END_STATE();
+    case 83:
+      ACCEPT_TOKEN(anon_sym_default);
+      END_STATE();
+    case 84:
+      if (lookahead == 'c') ADVANCE(86);
+      END_STATE();
+    case 85:
+      ACCEPT_TOKEN(anon_sym_package);
+      END_STATE();
+    case 86:
+      if (lookahead == 'e') ADVANCE(87);
+      END_STATE();
+    case 87:
+      ACCEPT_TOKEN(anon_sym_interface);
+      END_STATE();
+    default:
+      return false;
+  }
+}
+
+static const TSLexerMode ts_lex_modes[STATE_COUNT] = {
+  [0] = {.lex_state = 0, .external_lex_state = 1},
+  [1] = {.lex_state = 36},
+  [2] = {.lex_state = 36, .external_lex_state = 2},
+  [3] = {.lex_state = 36, .external_lex_state = 2},
+  [4] = {.lex_state = 36, .external_lex_state = 2},
+  [5] = {.lex_state = 36, .external_lex_state = 2},
+  [6] = {.lex_state = 36, .external_lex_state = 2},
+  [7] = {.lex_state = 36, .external_lex_state = 2},
+  [8] = {.lex_state = 37},
+  [9] = {.lex_state = 37},
+  [10] = {.lex_state = 36, .external_lex_state = 2},
+  [11] = {.lex_state = 36, .external_lex_state = 2},
+  [12] = {.lex_state = 37, .external_lex_state = 3},
+  [13] = {.lex_state = 36, .external_lex_state = 2},
+  [14] = {.lex_state = 37, .external_lex_state = 3},
+  [15] = {.lex_state = 36, .external_lex_state = 2},
+  [16] = {.lex_state = 36, .external_lex_state = 2},
+  [17] = {.lex_state = 36, .external_lex_state = 2},
+  [18] = {.lex_state = 37, .external_lex_state = 3},
+  [19] = {.lex_state = 37, .external_lex_state = 3},
+  [20] = {.lex_state = 37, .external_lex_state = 3},
+  [21] = {.lex_state = 37, .external_lex_state = 3},
+  [22] = {.lex_state = 37, .external_lex_state = 3},
+  [23] = {.lex_state = 37, .external_lex_state = 3},
+  [24] = {.lex_state = 36, .external_lex_state = 2},
+  [25] = {.lex_state = 36, .external_lex_state = 2},
+  [26] = {.lex_state = 36, .external_lex_state = 2},
+  [27] = {.lex_state = 36, .external_lex_state = 2},
+  [28] = {.lex_state = 36, .external_lex_state = 2},
+  [29] = {.lex_state = 36, .external_lex_state = 2},
+  [30] = {.lex_state = 36, .external_lex_state = 2},
+  [31] = {.lex_state = 36, .external_lex_state = 2},
+  [32] = {.lex_state = 36, .external_lex_state = 2},
+  [33] = {.lex_state = 36, .external_lex_state = 2},
+  [34] = {.lex_state = 36, .external_lex_state = 2},
+  [35] = {.lex_state = 36, .external_lex_state = 2},
+  [36] = {.lex_state = 36, .external_lex_state = 2},
+  [37] = {.lex_state = 36, .external_lex_state = 2},
+  [38] = {.lex_state = 36, .external_lex_state = 2},
+  [39] = {.lex_state = 36, .external_lex_state = 2},
+  [40] = {.lex_state = 36, .external_lex_state = 2},
+  [41] = {.lex_state = 36, .external_lex_state = 2},
+  [42] = {.lex_state = 36, .external_lex_state = 2},
+  [43] = {.lex_state = 36, .external_lex_state = 2},
+  [44] = {.lex_state = 36, .external_lex_state = 2},
+  [45] = {.lex_state = 36, .external_lex_state = 2},
+  [46] = {.lex_state = 36, .external_lex_state = 2},
+  [47] = {.lex_state = 36, .external_lex_state = 2},
+  [48] = {.lex_state = 36, .external_lex_state = 2},
+  [49] = {.lex_state = 36, .external_lex_state = 2},
+  [50] = {.lex_state = 36, .external_lex_state = 2},
+  [51] = {.lex_state = 36, .external_lex_state = 2},
+  [52] = {.lex_state = 36, .external_lex_state = 2},
+  [53] = {.lex_state = 36, .external_lex_state = 2},
+  [54] = {.lex_state = 36, .external_lex_state = 2},
+  [55] = {.lex_state = 36, .external_lex_state = 2},
+  [56] = {.lex_state = 36, .external_lex_state = 2},
+  [57] = {.lex_state = 36, .external_lex_state = 2},
+  [58] = {.lex_state = 36, .external_lex_state = 2},
+  [59] = {.lex_state = 36, .external_lex_state = 2},
+  [60] = {.lex_state = 36, .external_lex_state = 2},
+  [61] = {.lex_state = 36, .external_lex_state = 2},
+  [62] = {.lex_state = 36, .external_lex_state = 2},
+  [63] = {.lex_state = 37},
+  [64] = {.lex_state = 37},
+  [65] = {.lex_state = 37},
+  [66] = {.lex_state = 37},
+  [67] = {.lex_state = 37},
+  [68] = {.lex_state = 37},
+  [69] = {.lex_state = 37},
+  [70] = {.lex_state = 37},
+  [71] = {.lex_state = 37},
+  [72] = {.lex_state = 37},
+  [73] = {.lex_state = 37},
+  [74] = {.lex_state = 37},
+  [75] = {.lex_state = 37},
+  [76] = {.lex_state = 37},
+  [77] = {.lex_state = 37},
+  [78] = {.lex_state = 37},
+  [79] = {.lex_state = 37},
+  [80] = {.lex_state = 37},
+  [81] = {.lex_state = 37},
+  [82] = {.lex_state = 37},
+  [83] = {.lex_state = 37},
+  [84] = {.lex_state = 37},
+  [85] = {.lex_state = 37},
+  [86] = {.lex_state = 37},
+  [87] = {.lex_state = 37},
+  [88] = {.lex_state = 37},
+  [89] = {.lex_state = 37},
+  [90] = {.lex_state = 37},
+  [91] = {.lex_state = 37},
+  [92] = {.lex_state = 37},
+  [93] = {.lex_state = 37},
+  [94] = {.lex_state = 37},
+  [95] = {.lex_state = 37},
+  [96] = {.lex_state = 37},
+  [97] = {.lex_state = 37},
+  [98] = {.lex_state = 37},
+  [99] = {.lex_state = 36},
+  [100] = {.lex_state = 36},
+  [101] = {.lex_state = 36},
+  [102] = {.lex_state = 36},
+  [103] = {.lex_state = 37},
+  [104] = {.lex_state = 36},
+  [105] = {.lex_state = 37},
+  [106] = {.lex_state = 37},
+  [107] = {.lex_state = 37},
+  [108] = {.lex_state = 36},
+  [109] = {.lex_state = 37},
+  [110] = {.lex_state = 37},
+  [111] = {.lex_state = 11, .external_lex_state = 3},
+  [112] = {.lex_state = 7},
+  [113] = {.lex_state = 37},
+  [114] = {.lex_state = 37},
+  [115] = {.lex_state = 11, .external_lex_state = 3},
+  [116] = {.lex_state = 37},
+  [117] = {.lex_state = 36},
+  [118] = {.lex_state = 11, .external_lex_state = 3},
+  [119] = {.lex_state = 11, .external_lex_state = 3},
+  [120] = {.lex_state = 37},
+  [121] = {.lex_state = 11, .external_lex_state = 3},
+  [122] = {.lex_state = 11, .external_lex_state = 3},
+  [123] = {.lex_state = 37},
+  [124] = {.lex_state = 11, .external_lex_state = 3},
+  [125] = {.lex_state = 36},
+  [126] = {.lex_state = 37},
+  [127] = {.lex_state = 36},
+  [128] = {.lex_state = 37},
+  [129] = {.lex_state = 37},
+  [130] = {.lex_state = 36},
+  [131] = {.lex_state = 36},
+  [132] = {.lex_state = 37},
+  [133] = {.lex_state = 37},
+  [134] = {.lex_state = 37},
+  [135] = {.lex_state = 37},
+  [136] = {.lex_state = 37},
+  [137] = {.lex_state = 36},
+  [138] = {.lex_state = 37},
+  [139] = {.lex_state = 37},
+  [140] = {.lex_state = 37},
+  [141] = {.lex_state = 37},
+  [142] = {.lex_state = 37},
+  [143] = {.lex_state = 36},
+  [144] = {.lex_state = 36},
+  [145] = {.lex_state = 36},
+  [146] = {.lex_state = 36},
+  [147] = {.lex_state = 37},
+  [148] = {.lex_state = 37},
+  [149] = {.lex_state = 36},
+  [150] = {.lex_state = 11, .external_lex_state = 3},
+  [151] = {.lex_state = 11, .external_lex_state = 3},
+  [152] = {.lex_state = 37},
+  [153] = {.lex_state = 11, .external_lex_state = 3},
+  [154] = {.lex_state = 11, .external_lex_state = 3},
+  [155] = {.lex_state = 36},
+  [156] = {.lex_state = 11, .external_lex_state = 3},
+  [157] = {.lex_state = 11, .external_lex_state = 3},
+  [158] = {.lex_state = 11, .external_lex_state = 4},
+  [159] = {.lex_state = 11, .external_lex_state = 4},
+  [160] = {.lex_state = 36},
+  [161] = {.lex_state = 11, .external_lex_state = 4},
+  [162] = {.lex_state = 11, .external_lex_state = 4},
+  [163] = {.lex_state = 36},
+  [164] = {.lex_state = 11, .external_lex_state = 4},
+  [165] = {.lex_state = 36},
+  [166] = {.lex_state = 36},
+  [167] = {.lex_state = 11, .external_lex_state = 4},
+  [168] = {.lex_state = 11, .external_lex_state = 4},
+  [169] = {.lex_state = 11, .external_lex_state = 4},
+  [170] = {.lex_state = 11, .external_lex_state = 4},
+  [171] = {.lex_state = 36},
+  [172] = {.lex_state = 36},
+  [173] = {.lex_state = 36},
+  [174] = {.lex_state = 36},
+  [175] = {.lex_state = 36},
+  [176] = {.lex_state = 36},
+  [177] = {.lex_state = 36},
+  [178] = {.lex_state = 36},
+  [179] = {.lex_state = 36},
+  [180] = {.lex_state = 36},
+  [181] = {.lex_state = 36},
+  [182] = {.lex_state = 36},
+  [183] = {.lex_state = 36},
+  [184] = {.lex_state = 36},
+  [185] = {.lex_state = 36},
+  [186] = {.lex_state = 36},
+  [187] = {.lex_state = 36},
+  [188] = {.lex_state = 36},
+  [189] = {.lex_state = 36},
+  [190] = {.lex_state = 36},
+  [191] = {.lex_state = 36},
+  [192] = {.lex_state = 36},
+  [193] = {.lex_state = 36},
+  [194] = {.lex_state = 36},
+  [195] = {.lex_state = 36},
+  [196] = {.lex_state = 36},
+  [197] = {.lex_state = 36},
+  [198] = {.lex_state = 36},
+  [199] = {.lex_state = 36},
+  [200] = {.lex_state = 36},
+  [201] = {.lex_state = 36},
+  [202] = {.lex_state = 36},
+  [203] = {.lex_state = 36},
+  [204] = {.lex_state = 36},
+  [205] = {.lex_state = 36},
+  [206] = {.lex_state = 36},
+  [207] = {.lex_state = 36},
+  [208] = {.lex_state = 38},
+  [209] = {.lex_state = 36},
+  [210] = {.lex_state = 38},
+  [211] = {.lex_state = 36},
+  [212] = {.lex_state = 36},
+  [213] = {.lex_state = 36},
+  [214] = {.lex_state = 36},
+  [215] = {.lex_state = 36},
+  [216] = {.lex_state = 36},
+  [217] = {.lex_state = 36},
+  [218] = {.lex_state = 36},
+  [219] = {.lex_state = 36},
+  [220] = {.lex_state = 36},
+  [221] = {.lex_state = 36},
+  [222] = {.lex_state = 36},
+  [223] = {.lex_state = 36},
+  [224] = {.lex_state = 36},
+  [225] = {.lex_state = 36},
+  [226] = {.lex_state = 36},
+  [227] = {.lex_state = 36},
+  [228] = {.lex_state = 36},
+  [229] = {.lex_state = 36},
+  [230] = {.lex_state = 36},
+  [231] = {.lex_state = 36},
+  [232] = {.lex_state = 38},
+  [233] = {.lex_state = 38},
+  [234] = {.lex_state = 7},
+  [235] = {.lex_state = 36},
+  [236] = {.lex_state = 38},
+  [237] = {.lex_state = 36},
+  [238] = {.lex_state = 36},
+  [239] = {.lex_state = 36},
+  [240] = {.lex_state = 11, .external_lex_state = 3},
+  [241] = {.lex_state = 7},
+  [242] = {.lex_state = 11, .external_lex_state = 3},
+  [243] = {.lex_state = 36},
+  [244] = {.lex_state = 11, .external_lex_state = 3},
+  [245] = {.lex_state = 36},
+  [246] = {.lex_state = 11, .external_lex_state = 3},
+  [247] = {.lex_state = 11, .external_lex_state = 3},
+  [248] = {.lex_state = 11, .external_lex_state = 3},
+  [249] = {.lex_state = 36},
+  [250] = {.lex_state = 11, .external_lex_state = 3},
+  [251] = {.lex_state = 3},
+  [252] = {.lex_state = 36},
+  [253] = {.lex_state = 11, .external_lex_state = 3},
+  [254] = {.lex_state = 11, .external_lex_state = 3},
+  [255] = {.lex_state = 11, .external_lex_state = 3},
+  [256] = {.lex_state = 11, .external_lex_state = 3},
+  [257] = {.lex_state = 11, .external_lex_state = 3},
+  [258] = {.lex_state = 11, .external_lex_state = 3},
+  [259] = {.lex_state = 11, .external_lex_state = 3},
+  [260] = {.lex_state = 11, .external_lex_state = 3},
+  [261] = {.lex_state = 11, .external_lex_state = 3},
+  [262] = {.lex_state = 11, .external_lex_state = 3},
+  [263] = {.lex_state = 11, .external_lex_state = 3},
+  [264] = {.lex_state = 11, .external_lex_state = 3},
+  [265] = {.lex_state = 36},
+  [266] = {.lex_state = 11, .external_lex_state = 3},
+  [267] = {.lex_state = 11, .external_lex_state = 3},
+  [268] = {.lex_state = 11, .external_lex_state = 3},
+  [269] = {.lex_state = 11, .external_lex_state = 3},
+  [270] = {.lex_state = 11, .external_lex_state = 3},
+  [271] = {.lex_state = 11, .external_lex_state = 3},
+  [272] = {.lex_state = 3},
+  [273] = {.lex_state = 11, .external_lex_state = 3},
+  [274] = {.lex_state = 11, .external_lex_state = 3},
+  [275] = {.lex_state = 11, .external_lex_state = 3},
+  [276] = {.lex_state = 11, .external_lex_state = 3},
+  [277] = {.lex_state = 11, .external_lex_state = 3},
+  [278] = {.lex_state = 11, .external_lex_state = 3},
+  [279] = {.lex_state = 36},
+  [280] = {.lex_state = 2},
+  [281] = {.lex_state = 11, .external_lex_state = 3},
+  [282] = {.lex_state = 11, .external_lex_state = 3},
+  [283] = {.lex_state = 2},
+  [284] = {.lex_state = 3},
+  [285] = {.lex_state = 38},
+  [286] = {.lex_state = 11, .external_lex_state = 4},
+  [287] = {.lex_state = 3},
+  [288] = {.lex_state = 3},
+  [289] = {.lex_state = 36},
+  [290] = {.lex_state = 36},
+  [291] = {.lex_state = 38},
+  [292] = {.lex_state = 3},
+  [293] = {.lex_state = 36},
+  [294] = {.lex_state = 3},
+  [295] = {.lex_state = 36},
+  [296] = {.lex_state = 36},
+  [297] = {.lex_state = 1},
+  [298] = {.lex_state = 1},
+  [299] = {.lex_state = 11, .external_lex_state = 4},
+  [300] = {.lex_state = 1},
+  [301] = {.lex_state = 1},
+  [302] = {.lex_state = 11, .external_lex_state = 4},
+  [303] = {.lex_state = 3},
+  [304] = {.lex_state = 36},
+  [305] = {.lex_state = 36},
+  [306] = {.lex_state = 1},
+  [307] = {.lex_state = 11, .external_lex_state = 4},
+  [308] = {.lex_state = 36},
+  [309] = {.lex_state = 36},
+  [310] = {.lex_state = 36},
+  [311] = {.lex_state = 11, .external_lex_state = 4},
+  [312] = {.lex_state = 36},
+  [313] = {.lex_state = 11, .external_lex_state = 4},
+  [314] = {.lex_state = 11, .external_lex_state = 4},
+  [315] = {.lex_state = 11, .external_lex_state = 4},
+  [316] = {.lex_state = 11, .external_lex_state = 4},
+  [317] = {.lex_state = 11, .external_lex_state = 4},
+  [318] = {.lex_state = 11, .external_lex_state = 4},
+  [319] = {.lex_state = 11, .external_lex_state = 4},
+  [320] = {.lex_state = 11, .external_lex_state = 4},
+  [321] = {.lex_state = 11, .external_lex_state = 4},
+  [322] = {.lex_state = 11, .external_lex_state = 4},
+  [323] = {.lex_state = 11, .external_lex_state = 4},
+  [324] = {.lex_state = 11, .external_lex_state = 4},
+  [325] = {.lex_state = 11, .external_lex_state = 4},
+  [326] = {.lex_state = 11, .external_lex_state = 4},
+  [327] = {.lex_state = 11, .external_lex_state = 4},
+  [328] = {.lex_state = 11, .external_lex_state = 4},
+  [329] = {.lex_state = 11, .external_lex_state = 4},
+  [330] = {.lex_state = 11, .external_lex_state = 4},
+  [331] = {.lex_state = 11, .external_lex_state = 4},
+  [332] = {.lex_state = 11, .external_lex_state = 4},
+  [333] = {.lex_state = 11, .external_lex_state = 4},
+  [334] = {.lex_state = 11, .external_lex_state = 4},
+  [335] = {.lex_state = 11, .external_lex_state = 4},
+  [336] = {.lex_state = 11, .external_lex_state = 4},
+  [337] = {.lex_state = 11, .external_lex_state = 4},
+  [338] = {.lex_state = 11, .external_lex_state = 4},
+  [339] = {.lex_state = 37},
+  [340] = {.lex_state = 36},
+  [341] = {.lex_state = 36},
+  [342] = {.lex_state = 3},
+  [343] = {.lex_state = 1},
+  [344] = {.lex_state = 1},
+  [345] = {.lex_state = 3},
+  [346] = {.lex_state = 1},
+  [347] = {.lex_state = 3},
+  [348] = {.lex_state = 1},
+  [349] = {.lex_state = 36},
+  [350] = {.lex_state = 3},
+  [351] = {.lex_state = 36},
+  [352] = {.lex_state = 36},
+  [353] = {.lex_state = 36},
+  [354] = {.lex_state = 36},
+  [355] = {.lex_state = 36},
+  [356] = {.lex_state = 36},
+  [357] = {.lex_state = 36},
+  [358] = {.lex_state = 36},
+  [359] = {.lex_state = 36},
+  [360] = {.lex_state = 36},
+  [361] = {.lex_state = 36},
+  [362] = {.lex_state = 36},
+  [363] = {.lex_state = 36},
+  [364] = {.lex_state = 36},
+  [365] = {.lex_state = 11},
+  [366] = {.lex_state = 36},
+  [367] = {.lex_state = 36},
+  [368] = {.lex_state = 36},
+  [369] = {.lex_state = 36},
+  [370] = {.lex_state = 36},
+  [371] = {.lex_state = 36},
+  [372] = {.lex_state = 36},
+  [373] = {.lex_state = 36},
+  [374] = {.lex_state = 36},
+  [375] = {.lex_state = 11},
+  [376] = {.lex_state = 36},
+  [377] = {.lex_state = 36},
+  [378] = {.lex_state = 36},
+  [379] = {.lex_state = 36},
+  [380] = {.lex_state = 36},
+  [381] = {.lex_state = 36},
+  [382] = {.lex_state = 36},
+  [383] = {.lex_state = 36},
+  [384] = {.lex_state = 11},
+  [385] = {.lex_state = 36},
+  [386] = {.lex_state = 36},
+  [387] = {.lex_state = 36},
+  [388] = {.lex_state = 36, .external_lex_state = 5},
+  [389] = {.lex_state = 36},
+  [390] = {.lex_state = 36},
+  [391] = {.lex_state = 36},
+  [392] = {.lex_state = 36},
+  [393] = {.lex_state = 36},
+  [394] = {.lex_state = 0},
+  [395] = {.lex_state = 36, .external_lex_state = 6},
+  [396] = {.lex_state = 36, .external_lex_state = 6},
+  [397] = {.lex_state = 36},
+  [398] = {.lex_state = 36, .external_lex_state = 6},
+  [399] = {.lex_state = 36},
+  [400] = {.lex_state = 0},
+  [401] = {.lex_state = 0},
+  [402] = {.lex_state = 36, .external_lex_state = 6},
+  [403] = {.lex_state = 36},
+  [404] = {.lex_state = 0},
+  [405] = {.lex_state = 36},
+  [406] = {.lex_state = 0},
+  [407] = {.lex_state = 36},
+  [408] = {.lex_state = 0},
+  [409] = {.lex_state = 36},
+  [410] = {.lex_state = 36},
+  [411] = {.lex_state = 36},
+  [412] = {.lex_state = 36},
+  [413] = {.lex_state = 0},
+  [414] = {.lex_state = 0},
+  [415] = {.lex_state = 0},
+  [416] = {.lex_state = 36},
+  [417] = {.lex_state = 0},
+  [418] = {.lex_state = 36},
+  [419] = {.lex_state = 0},
+  [420] = {.lex_state = 0},
+  [421] = {.lex_state = 36},
+  [422] = {.lex_state = 0},
+  [423] = {.lex_state = 36},
+  [424] = {.lex_state = 0},
+  [425] = {.lex_state = 0},
+  [426] = {.lex_state = 36},
+  [427] = {.lex_state = 0},
+  [428] = {.lex_state = 0},
+  [429] = {.lex_state = 36},
+  [430] = {.lex_state = 0},
+  [431] = {.lex_state = 0},
+  [432] = {.lex_state = 36},
+  [433] = {.lex_state = 36},
+  [434] = {.lex_state = 36, .external_lex_state = 6},
+  [435] = {.lex_state = 36},
+  [436] = {.lex_state = 0},
+  [437] = {.lex_state = 37},
+  [438] = {.lex_state = 36},
+  [439] = {.lex_state = 0},
+  [440] = {.lex_state = 36},
+  [441] = {.lex_state = 36, .external_lex_state = 6},
+  [442] = {.lex_state = 0},
+  [443] = {.lex_state = 0},
+  [444] = {.lex_state = 37},
+  [445] = {.lex_state = 36, .external_lex_state = 6},
+  [446] = {.lex_state = 0},
+  [447] = {.lex_state = 0},
+  [448] = {.lex_state = 36},
+  [449] = {.lex_state = 36},
+  [450] = {.lex_state = 36},
+  [451] = {.lex_state = 0},
+  [452] = {.lex_state = 0},
+  [453] = {.lex_state = 36},
+  [454] = {.lex_state = 0},
+  [455] = {.lex_state = 36},
+  [456] = {.lex_state = 0},
+  [457] = {.lex_state = 0},
+  [458] = {.lex_state = 0},
+  [459] = {.lex_state = 0},
+  [460] = {.lex_state = 0},
+  [461] = {.lex_state = 0},
+  [462] = {.lex_state = 36},
+  [463] = {.lex_state = 0},
+  [464] = {.lex_state = 0},
+  [465] = {.lex_state = 0},
+  [466] = {.lex_state = 0},
+  [467] = {.lex_state = 11},
+  [468] = {.lex_state = 0},
+  [469] = {.lex_state = 0},
+  [470] = {.lex_state = 0},
+  [471] = {.lex_state = 0},
+  [472] = {.lex_state = 36},
+  [473] = {.lex_state = 36},
+  [474] = {.lex_state = 0},
+  [475] = {.lex_state = 0},
+  [476] = {.lex_state = 0},
+  [477] = {.lex_state = 0},
+  [478] = {.lex_state = 0},
+  [479] = {.lex_state = 0},
+  [480] = {.lex_state = 0},
+  [481] = {.lex_state = 0},
+  [482] = {.lex_state = 0},
+  [483] = {.lex_state = 0},
+  [484] = {.lex_state = 0},
+  [485] = {.lex_state = 0},
+  [486] = {.lex_state = 0},
+  [487] = {.lex_state = 0},
+  [488] = {.lex_state = 36},
+  [489] = {.lex_state = 0},
+  [490] = {.lex_state = 0},
+  [491] = {.lex_state = 0},
+  [492] = {.lex_state = 0},
+  [493] = {.lex_state = 36},
+  [494] = {.lex_state = 0},
+  [495] = {.lex_state = 0},
+  [496] = {.lex_state = 0},
+  [497] = {.lex_state = 36},
+  [498] = {.lex_state = 0},
+  [499] = {.lex_state = 0},
+  [500] = {.lex_state = 0},
+  [501] = {.lex_state = 0},
+  [502] = {.lex_state = 0},
+  [503] = {.lex_state = 0},
+  [504] = {.lex_state = 0},
+  [505] = {.lex_state = 36},
+  [506] = {.lex_state = 0},
+  [507] = {.lex_state = 0},
+  [508] = {.lex_state = 0},
+  [509] = {.lex_state = 0},
+  [510] = {.lex_state = 0},
+  [511] = {.lex_state = 0},
+  [512] = {.lex_state = 0},
+  [513] = {.lex_state = 36},
+  [514] = {.lex_state = 0},
+  [515] = {.lex_state = 36},
+  [516] = {.lex_state = 36},
+  [517] = {.lex_state = 0},
+  [518] = {.lex_state = 36},
+  [519] = {.lex_state = 0},
+  [520] = {.lex_state = 36},
+  [521] = {.lex_state = 0},
+  [522] = {.lex_state = 0},
+  [523] = {.lex_state = 0},
+  [524] = {.lex_state = 36},
+  [525] = {.lex_state = 36},
+  [526] = {.lex_state = 0},
+  [527] = {.lex_state = 0},
+  [528] = {.lex_state = 0},
+  [529] = {.lex_state = 0},
+  [530] = {.lex_state = 0},
+  [531] = {.lex_state = 0},
+  [532] = {.lex_state = 0},
+  [533] = {.lex_state = 0},
+  [534] = {.lex_state = 36},
+  [535] = {.lex_state = 155},
+  [536] = {.lex_state = 0},
+  [537] = {.lex_state = 0},
+  [538] = {.lex_state = 0},
+  [539] = {.lex_state = 0},
+  [540] = {.lex_state = 36},
+  [541] = {.lex_state = 0},
+  [542] = {.lex_state = 0},
+  [543] = {.lex_state = 0},
+  [544] = {.lex_state = 0},
+  [545] = {.lex_state = 36},
+  [546] = {.lex_state = 0},
+  [547] = {.lex_state = 0},
+  [548] = {.lex_state = 36},
+  [549] = {.lex_state = 0},
+  [550] = {.lex_state = 36},
+  [551] = {.lex_state = 36},
+  [552] = {.lex_state = 0},
+  [553] = {.lex_state = 0},
+  [554] = {.lex_state = 36},
+  [555] = {.lex_state = 0},
+  [556] = {.lex_state = 0, .external_lex_state = 6},
+  [557] = {.lex_state = 0},
+  [558] = {.lex_state = 0, .external_lex_state = 6},
+  [559] = {.lex_state = 36},
+  [560] = {.lex_state = 11},
+  [561] = {.lex_state = 0},
+  [562] = {.lex_state = 11},
+  [563] = {.lex_state = 36},
+  [564] = {.lex_state = 0},
+  [565] = {.lex_state = 36},
+  [566] = {.lex_state = 0},
+  [567] = {.lex_state = 0},
+  [568] = {.lex_state = 36},
+  [569] = {.lex_state = 0},
+  [570] = {.lex_state = 0},
+  [571] = {.lex_state = 0},
+  [572] = {.lex_state = 36},
+  [573] = {.lex_state = 36},
+  [574] = {.lex_state = 0},
+  [575] = {.lex_state = 155},
+  [576] = {.lex_state = 11},
+  [577] = {.lex_state = 0},
+  [578] = {.lex_state = 0},
+  [579] = {.lex_state = 36},
+  [580] = {.lex_state = 36},
+  [581] = {.lex_state = 0},
+  [582] = {.lex_state = 0},
+  [583] = {.lex_state = 0},
+  [584] = {.lex_state = 0},
+  [585] = {.lex_state = 36},
+  [586] = {.lex_state = 36},
+  [587] = {.lex_state = 36},
+  [588] = {.lex_state = 0},
+  [589] = {.lex_state = 36},
+  [590] = {.lex_state = 36},
+  [591] = {.lex_state = 0},
+  [592] = {.lex_state = 0},
+  [593] = {.lex_state = 36},
+  [594] = {.lex_state = 36},
+  [595] = {.lex_state = 0},
+  [596] = {.lex_state = 0},
+  [597] = {.lex_state = 36},
+  [598] = {.lex_state = 0},
+  [599] = {.lex_state = 0},
+  [600] = {.lex_state = 36},
+  [601] = {(TSStateId)(-1),},
+};
+
+static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
+  [STATE(0)] = {
+    [sym_comment] = STATE(0),
+    [ts_builtin_sym_end] = ACTIONS(1),
+    [sym_identifier] = ACTIONS(1),
     [anon_sym_package] = ACTIONS(1),
     [anon_sym_import] = ACTIONS(1),
     [anon_sym_LPAREN] = ACTIONS(1),
     [anon_sym_RPAREN] = ACTIONS(1),
     [anon_sym_templ] = ACTIONS(1),
-    [anon_sym_COMMA] = ACTIONS(1),
-    [anon_sym_DOT] = ACTIONS(1),
     [anon_sym_LBRACK] = ACTIONS(1),
+    [anon_sym_COMMA] = ACTIONS(1),
     [anon_sym_RBRACK] = ACTIONS(1),
+    [anon_sym_PIPE] = ACTIONS(1),
+    [anon_sym_TILDE] = ACTIONS(1),
+    [anon_sym_DOT_DOT_DOT] = ACTIONS(1),
+    [anon_sym_DOT] = ACTIONS(1),
     [anon_sym_STAR] = ACTIONS(1),
     [anon_sym_map] = ACTIONS(1),
     [anon_sym_func] = ACTIONS(1),
-    [anon_sym_type] = ACTIONS(1),
+    [anon_sym_chan] = ACTIONS(1),
+    [anon_sym_LT_DASH] = ACTIONS(1),
     [anon_sym_struct] = ACTIONS(1),
+    [anon_sym_interface] = ACTIONS(1),
     [anon_sym_LBRACE] = ACTIONS(1),
     [anon_sym_RBRACE] = ACTIONS(1),
+    [anon_sym_type] = ACTIONS(1),
+    [anon_sym_SEMI] = ACTIONS(1),
     [anon_sym_LT] = ACTIONS(1),
     [anon_sym_SLASH] = ACTIONS(1),
     [anon_sym_GT] = ACTIONS(1),
@@ -2810,42 +4341,57 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_range] = ACTIONS(1),
     [anon_sym_if] = ACTIONS(1),
     [anon_sym_else] = ACTIONS(1),
+    [anon_sym_switch] = ACTIONS(1),
+    [anon_sym_case] = ACTIONS(1),
+    [anon_sym_COLON] = ACTIONS(1),
+    [anon_sym_default] = ACTIONS(1),
     [anon_sym_var] = ACTIONS(1),
     [anon_sym_AT] = ACTIONS(1),
     [anon_sym_children] = ACTIONS(1),
-    [anon_sym_DOT_DOT_DOT] = ACTIONS(1),
     [aux_sym_go_string_literal_token1] = ACTIONS(1),
     [aux_sym_go_string_literal_token2] = ACTIONS(1),
     [aux_sym_go_string_literal_token3] = ACTIONS(1),
     [aux_sym_go_string_literal_token4] = ACTIONS(1),
+    [anon_sym_PLUS] = ACTIONS(1),
+    [anon_sym_DASH] = ACTIONS(1),
+    [anon_sym_BANG] = ACTIONS(1),
+    [anon_sym_CARET] = ACTIONS(1),
+    [anon_sym_AMP] = ACTIONS(1),
+    [anon_sym_PERCENT] = ACTIONS(1),
+    [anon_sym_LT_LT] = ACTIONS(1),
+    [anon_sym_GT_GT] = ACTIONS(1),
+    [anon_sym_AMP_CARET] = ACTIONS(1),
     [anon_sym_EQ_EQ] = ACTIONS(1),
     [anon_sym_BANG_EQ] = ACTIONS(1),
     [anon_sym_LT_EQ] = ACTIONS(1),
     [anon_sym_GT_EQ] = ACTIONS(1),
-    [anon_sym_PLUS] = ACTIONS(1),
-    [anon_sym_DASH] = ACTIONS(1),
     [anon_sym_AMP_AMP] = ACTIONS(1),
     [anon_sym_PIPE_PIPE] = ACTIONS(1),
     [sym_number] = ACTIONS(1),
-    [sym_string] = ACTIONS(1),
     [sym_true] = ACTIONS(1),
     [sym_false] = ACTIONS(1),
     [anon_sym_const] = ACTIONS(1),
+    [aux_sym_go_declaration_token1] = ACTIONS(1),
     [aux_sym_comment_token1] = ACTIONS(3),
     [aux_sym_comment_token2] = ACTIONS(3),
+    [sym_string] = ACTIONS(1),
+    [sym_raw_string] = ACTIONS(1),
+    [sym_rune] = ACTIONS(1),
+    [sym_text_content] = ACTIONS(1),
+    [sym_go_statement] = ACTIONS(1),
   },
   [STATE(1)] = {
-    [sym_source_file] = STATE(328),
-    [sym_package_clause] = STATE(43),
-    [sym_import_section] = STATE(82),
-    [sym_import_declaration] = STATE(163),
-    [sym_component_declaration] = STATE(180),
-    [sym_type_struct_declaration] = STATE(180),
-    [sym_function_declaration] = STATE(180),
-    [sym_go_declaration] = STATE(180),
+    [sym_source_file] = STATE(526),
+    [sym_package_clause] = STATE(235),
+    [sym_import_section] = STATE(290),
+    [sym_import_declaration] = STATE(354),
+    [sym_component_declaration] = STATE(366),
+    [sym_type_struct_declaration] = STATE(366),
+    [sym_function_declaration] = STATE(366),
+    [sym_go_declaration] = STATE(366),
     [sym_comment] = STATE(1),
-    [aux_sym_source_file_repeat1] = STATE(75),
-    [aux_sym_import_section_repeat1] = STATE(124),
+    [aux_sym_source_file_repeat1] = STATE(312),
+    [aux_sym_import_section_repeat1] = STATE(340),
     [ts_builtin_sym_end] = ACTIONS(5),
     [anon_sym_package] = ACTIONS(7),
     [anon_sym_import] = ACTIONS(9),
@@ -2860,2046 +4406,11081 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
 };
 
 static const uint16_t ts_small_parse_table[] = {
-  [0] = 4,
+  [0] = 23,
+    ACTIONS(19), 1,
+      sym_identifier,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(25), 1,
+      anon_sym_RBRACK,
+    ACTIONS(27), 1,
+      anon_sym_STAR,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(31), 1,
+      anon_sym_func,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(35), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
     STATE(2), 1,
       sym_comment,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(134), 1,
+      sym__expression,
+    STATE(349), 1,
+      sym_qualified_type,
+    STATE(443), 1,
+      sym_type_expression,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(19), 7,
-      anon_sym_LT,
-      anon_sym_SLASH,
-      anon_sym_GT,
-      anon_sym_for,
-      anon_sym_if,
-      anon_sym_var,
-      sym_identifier,
-    ACTIONS(21), 15,
-      anon_sym_RPAREN,
-      anon_sym_COMMA,
-      anon_sym_DOT,
-      anon_sym_STAR,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(414), 2,
+      sym_slice_type,
+      sym_map_type,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 5,
       anon_sym_PLUS,
       anon_sym_DASH,
-      anon_sym_AMP_AMP,
-      anon_sym_PIPE_PIPE,
-  [34] = 4,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(230), 7,
+      sym_pointer_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [94] = 22,
+    ACTIONS(19), 1,
+      sym_identifier,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(27), 1,
+      anon_sym_STAR,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(31), 1,
+      anon_sym_func,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(35), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
     STATE(3), 1,
       sym_comment,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(64), 1,
+      sym__expression,
+    STATE(228), 1,
+      sym_type_expression,
+    STATE(349), 1,
+      sym_qualified_type,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(23), 7,
-      anon_sym_LT,
-      anon_sym_SLASH,
-      anon_sym_GT,
-      anon_sym_for,
-      anon_sym_if,
-      anon_sym_var,
-      sym_identifier,
-    ACTIONS(25), 15,
-      anon_sym_RPAREN,
-      anon_sym_COMMA,
-      anon_sym_DOT,
-      anon_sym_STAR,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(414), 2,
+      sym_slice_type,
+      sym_map_type,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 5,
       anon_sym_PLUS,
       anon_sym_DASH,
-      anon_sym_AMP_AMP,
-      anon_sym_PIPE_PIPE,
-  [68] = 4,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(230), 7,
+      sym_pointer_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [185] = 19,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(47), 1,
+      sym_identifier,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
+      anon_sym_func,
+    ACTIONS(53), 1,
+      anon_sym_LT,
+    ACTIONS(55), 1,
+      anon_sym_AT,
     STATE(4), 1,
       sym_comment,
+    STATE(14), 1,
+      sym_call_expression,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(152), 1,
+      sym__expression,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(27), 7,
-      anon_sym_LT,
-      anon_sym_SLASH,
-      anon_sym_GT,
-      anon_sym_for,
-      anon_sym_if,
-      anon_sym_var,
-      sym_identifier,
-    ACTIONS(29), 15,
-      anon_sym_RPAREN,
-      anon_sym_COMMA,
-      anon_sym_DOT,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(269), 2,
+      sym_self_closing_element,
+      sym_element_with_children,
+    STATE(275), 2,
+      sym_element,
+      sym_component_call,
+    STATE(469), 2,
+      sym_slice_type,
+      sym_map_type,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
       anon_sym_STAR,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
+      anon_sym_LT_DASH,
       anon_sym_PLUS,
       anon_sym_DASH,
-      anon_sym_AMP_AMP,
-      anon_sym_PIPE_PIPE,
-  [102] = 4,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 8,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [264] = 19,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(47), 1,
+      sym_identifier,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
+      anon_sym_func,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    ACTIONS(59), 1,
+      anon_sym_RBRACE,
     STATE(5), 1,
       sym_comment,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(107), 1,
+      sym__expression,
+    STATE(411), 1,
+      sym_literal_element,
+    STATE(449), 1,
+      sym_literal_value,
+    STATE(452), 1,
+      sym_keyed_element,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(31), 7,
-      anon_sym_LT,
-      anon_sym_SLASH,
-      anon_sym_GT,
-      anon_sym_for,
-      anon_sym_if,
-      anon_sym_var,
-      sym_identifier,
-    ACTIONS(33), 15,
-      anon_sym_RPAREN,
-      anon_sym_COMMA,
-      anon_sym_DOT,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
+      sym_slice_type,
+      sym_map_type,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
       anon_sym_STAR,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
+      anon_sym_LT_DASH,
       anon_sym_PLUS,
       anon_sym_DASH,
-      anon_sym_AMP_AMP,
-      anon_sym_PIPE_PIPE,
-  [136] = 15,
-    ACTIONS(35), 1,
-      sym_identifier,
-    ACTIONS(37), 1,
-      anon_sym_LBRACE,
-    ACTIONS(39), 1,
-      anon_sym_LT,
-    ACTIONS(41), 1,
-      anon_sym_LT_SLASH,
-    ACTIONS(43), 1,
-      sym_text_content,
-    ACTIONS(45), 1,
-      anon_sym_for,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [342] = 19,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
+      anon_sym_map,
     ACTIONS(47), 1,
-      anon_sym_if,
+      sym_identifier,
     ACTIONS(49), 1,
-      anon_sym_var,
+      anon_sym_LBRACK,
     ACTIONS(51), 1,
-      anon_sym_AT,
+      anon_sym_func,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    ACTIONS(61), 1,
+      anon_sym_RBRACE,
     STATE(6), 1,
       sym_comment,
-    STATE(18), 1,
-      aux_sym_element_with_children_repeat1,
-    STATE(105), 1,
-      sym__element_child,
-    ACTIONS(53), 2,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(107), 1,
+      sym__expression,
+    STATE(449), 1,
+      sym_literal_value,
+    STATE(450), 1,
+      sym_literal_element,
+    STATE(495), 1,
+      sym_keyed_element,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(125), 2,
-      sym_self_closing_element,
-      sym_element_with_children,
-    STATE(128), 7,
-      sym_element,
-      sym_for_statement,
-      sym_if_statement,
-      sym_let_binding,
-      sym_component_call,
-      sym_children_slot,
-      sym_go_expression,
-  [190] = 15,
-    ACTIONS(35), 1,
-      sym_identifier,
-    ACTIONS(37), 1,
-      anon_sym_LBRACE,
-    ACTIONS(39), 1,
-      anon_sym_LT,
-    ACTIONS(43), 1,
-      sym_text_content,
-    ACTIONS(45), 1,
-      anon_sym_for,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
+      sym_slice_type,
+      sym_map_type,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [420] = 19,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
+      anon_sym_map,
     ACTIONS(47), 1,
-      anon_sym_if,
+      sym_identifier,
     ACTIONS(49), 1,
-      anon_sym_var,
+      anon_sym_LBRACK,
     ACTIONS(51), 1,
-      anon_sym_AT,
-    ACTIONS(55), 1,
-      anon_sym_LT_SLASH,
-    STATE(6), 1,
-      aux_sym_element_with_children_repeat1,
+      anon_sym_func,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    ACTIONS(63), 1,
+      anon_sym_RBRACE,
     STATE(7), 1,
       sym_comment,
-    STATE(105), 1,
-      sym__element_child,
-    ACTIONS(53), 2,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(107), 1,
+      sym__expression,
+    STATE(449), 1,
+      sym_literal_value,
+    STATE(450), 1,
+      sym_literal_element,
+    STATE(495), 1,
+      sym_keyed_element,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(125), 2,
-      sym_self_closing_element,
-      sym_element_with_children,
-    STATE(128), 7,
-      sym_element,
-      sym_for_statement,
-      sym_if_statement,
-      sym_let_binding,
-      sym_component_call,
-      sym_children_slot,
-      sym_go_expression,
-  [244] = 7,
-    ACTIONS(63), 1,
-      anon_sym_LT,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
+      sym_slice_type,
+      sym_map_type,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [498] = 4,
     STATE(8), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(66), 2,
+    ACTIONS(67), 6,
+      anon_sym_PIPE,
+      anon_sym_DOT,
+      anon_sym_LT,
       anon_sym_SLASH,
       anon_sym_GT,
-    ACTIONS(61), 3,
+      anon_sym_AMP,
+    ACTIONS(65), 29,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_templ,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_STAR,
+      anon_sym_func,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
-      anon_sym_AT,
-    ACTIONS(57), 4,
-      anon_sym_for,
-      anon_sym_if,
+      anon_sym_type,
+      anon_sym_COLON,
       anon_sym_var,
-      sym_identifier,
-    ACTIONS(59), 10,
-      anon_sym_DOT,
-      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-      anon_sym_PLUS,
-      anon_sym_DASH,
       anon_sym_AMP_AMP,
       anon_sym_PIPE_PIPE,
-  [282] = 13,
-    ACTIONS(68), 1,
-      sym_identifier,
-    ACTIONS(71), 1,
-      anon_sym_LBRACE,
-    ACTIONS(74), 1,
-      anon_sym_RBRACE,
-    ACTIONS(76), 1,
-      anon_sym_LT,
-    ACTIONS(79), 1,
-      anon_sym_for,
-    ACTIONS(82), 1,
-      anon_sym_if,
-    ACTIONS(85), 1,
-      anon_sym_var,
-    ACTIONS(88), 1,
-      anon_sym_AT,
-    STATE(133), 1,
-      sym__child,
+      anon_sym_const,
+  [545] = 4,
+    STATE(9), 1,
+      sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(9), 2,
-      sym_comment,
-      aux_sym_component_body_repeat1,
-    STATE(137), 2,
-      sym_self_closing_element,
-      sym_element_with_children,
-    STATE(134), 8,
-      sym_element,
-      sym_for_statement,
-      sym_if_statement,
-      sym_let_binding,
-      sym_state_declaration,
-      sym_component_call,
-      sym_children_slot,
-      sym_go_expression,
-  [332] = 14,
-    ACTIONS(91), 1,
-      sym_identifier,
-    ACTIONS(93), 1,
+    ACTIONS(71), 6,
+      anon_sym_PIPE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(69), 29,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_templ,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_STAR,
+      anon_sym_func,
       anon_sym_LBRACE,
-    ACTIONS(95), 1,
       anon_sym_RBRACE,
-    ACTIONS(97), 1,
-      anon_sym_LT,
-    ACTIONS(99), 1,
-      anon_sym_for,
-    ACTIONS(101), 1,
-      anon_sym_if,
-    ACTIONS(103), 1,
+      anon_sym_type,
+      anon_sym_COLON,
       anon_sym_var,
-    ACTIONS(105), 1,
-      anon_sym_AT,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_const,
+  [592] = 18,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(47), 1,
+      sym_identifier,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
+      anon_sym_func,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
     STATE(10), 1,
       sym_comment,
-    STATE(16), 1,
-      aux_sym_component_body_repeat1,
-    STATE(133), 1,
-      sym__child,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(107), 1,
+      sym__expression,
+    STATE(449), 1,
+      sym_literal_value,
+    STATE(450), 1,
+      sym_literal_element,
+    STATE(495), 1,
+      sym_keyed_element,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(137), 2,
-      sym_self_closing_element,
-      sym_element_with_children,
-    STATE(134), 8,
-      sym_element,
-      sym_for_statement,
-      sym_if_statement,
-      sym_let_binding,
-      sym_state_declaration,
-      sym_component_call,
-      sym_children_slot,
-      sym_go_expression,
-  [384] = 15,
-    ACTIONS(35), 1,
-      sym_identifier,
-    ACTIONS(37), 1,
-      anon_sym_LBRACE,
-    ACTIONS(39), 1,
-      anon_sym_LT,
-    ACTIONS(43), 1,
-      sym_text_content,
-    ACTIONS(45), 1,
-      anon_sym_for,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
+      sym_slice_type,
+      sym_map_type,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [667] = 17,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
+      anon_sym_map,
     ACTIONS(47), 1,
-      anon_sym_if,
+      sym_identifier,
     ACTIONS(49), 1,
-      anon_sym_var,
+      anon_sym_LBRACK,
     ACTIONS(51), 1,
-      anon_sym_AT,
-    ACTIONS(107), 1,
-      anon_sym_LT_SLASH,
+      anon_sym_func,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
     STATE(11), 1,
       sym_comment,
-    STATE(13), 1,
-      aux_sym_element_with_children_repeat1,
-    STATE(105), 1,
-      sym__element_child,
-    ACTIONS(53), 2,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(107), 1,
+      sym__expression,
+    STATE(449), 1,
+      sym_literal_value,
+    STATE(507), 1,
+      sym_literal_element,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(125), 2,
-      sym_self_closing_element,
-      sym_element_with_children,
-    STATE(128), 7,
-      sym_element,
-      sym_for_statement,
-      sym_if_statement,
-      sym_let_binding,
-      sym_component_call,
-      sym_children_slot,
-      sym_go_expression,
-  [438] = 14,
-    ACTIONS(91), 1,
-      sym_identifier,
-    ACTIONS(93), 1,
-      anon_sym_LBRACE,
-    ACTIONS(97), 1,
-      anon_sym_LT,
-    ACTIONS(99), 1,
-      anon_sym_for,
-    ACTIONS(101), 1,
-      anon_sym_if,
-    ACTIONS(103), 1,
-      anon_sym_var,
-    ACTIONS(105), 1,
-      anon_sym_AT,
-    ACTIONS(109), 1,
-      anon_sym_RBRACE,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
+      sym_slice_type,
+      sym_map_type,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [739] = 4,
     STATE(12), 1,
       sym_comment,
-    STATE(15), 1,
-      aux_sym_component_body_repeat1,
-    STATE(133), 1,
-      sym__child,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(137), 2,
-      sym_self_closing_element,
-      sym_element_with_children,
-    STATE(134), 8,
-      sym_element,
-      sym_for_statement,
-      sym_if_statement,
-      sym_let_binding,
-      sym_state_declaration,
-      sym_component_call,
-      sym_children_slot,
-      sym_go_expression,
-  [490] = 15,
-    ACTIONS(35), 1,
-      sym_identifier,
-    ACTIONS(37), 1,
-      anon_sym_LBRACE,
-    ACTIONS(39), 1,
+    ACTIONS(73), 12,
+      anon_sym_PIPE,
       anon_sym_LT,
-    ACTIONS(43), 1,
-      sym_text_content,
-    ACTIONS(45), 1,
+      anon_sym_SLASH,
+      anon_sym_GT,
       anon_sym_for,
-    ACTIONS(47), 1,
       anon_sym_if,
-    ACTIONS(49), 1,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
       anon_sym_var,
-    ACTIONS(51), 1,
-      anon_sym_AT,
-    ACTIONS(111), 1,
-      anon_sym_LT_SLASH,
-    STATE(13), 1,
-      sym_comment,
-    STATE(18), 1,
-      aux_sym_element_with_children_repeat1,
-    STATE(105), 1,
-      sym__element_child,
-    ACTIONS(53), 2,
-      aux_sym_comment_token1,
-      aux_sym_comment_token2,
-    STATE(125), 2,
-      sym_self_closing_element,
-      sym_element_with_children,
-    STATE(128), 7,
-      sym_element,
-      sym_for_statement,
-      sym_if_statement,
-      sym_let_binding,
-      sym_component_call,
-      sym_children_slot,
-      sym_go_expression,
-  [544] = 15,
-    ACTIONS(35), 1,
+      anon_sym_AMP,
       sym_identifier,
-    ACTIONS(37), 1,
+    ACTIONS(75), 21,
+      sym_go_statement,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_DOT,
+      anon_sym_STAR,
       anon_sym_LBRACE,
-    ACTIONS(39), 1,
-      anon_sym_LT,
-    ACTIONS(43), 1,
-      sym_text_content,
-    ACTIONS(45), 1,
-      anon_sym_for,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [784] = 16,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
+      anon_sym_map,
     ACTIONS(47), 1,
-      anon_sym_if,
+      sym_identifier,
     ACTIONS(49), 1,
-      anon_sym_var,
+      anon_sym_LBRACK,
     ACTIONS(51), 1,
-      anon_sym_AT,
-    ACTIONS(111), 1,
-      anon_sym_LT_SLASH,
-    STATE(14), 1,
+      anon_sym_func,
+    ACTIONS(77), 1,
+      anon_sym_RPAREN,
+    STATE(13), 1,
       sym_comment,
-    STATE(19), 1,
-      aux_sym_element_with_children_repeat1,
-    STATE(105), 1,
-      sym__element_child,
-    ACTIONS(53), 2,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(106), 1,
+      sym__expression,
+    STATE(500), 1,
+      sym_variadic_argument,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(125), 2,
-      sym_self_closing_element,
-      sym_element_with_children,
-    STATE(128), 7,
-      sym_element,
-      sym_for_statement,
-      sym_if_statement,
-      sym_let_binding,
-      sym_component_call,
-      sym_children_slot,
-      sym_go_expression,
-  [598] = 14,
-    ACTIONS(91), 1,
-      sym_identifier,
-    ACTIONS(93), 1,
-      anon_sym_LBRACE,
-    ACTIONS(97), 1,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
+      sym_slice_type,
+      sym_map_type,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [853] = 7,
+    ACTIONS(87), 1,
       anon_sym_LT,
-    ACTIONS(99), 1,
-      anon_sym_for,
-    ACTIONS(101), 1,
-      anon_sym_if,
-    ACTIONS(103), 1,
-      anon_sym_var,
-    ACTIONS(105), 1,
-      anon_sym_AT,
-    ACTIONS(113), 1,
-      anon_sym_RBRACE,
-    STATE(9), 1,
-      aux_sym_component_body_repeat1,
-    STATE(15), 1,
+    STATE(14), 1,
       sym_comment,
-    STATE(133), 1,
-      sym__child,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(137), 2,
-      sym_self_closing_element,
-      sym_element_with_children,
-    STATE(134), 8,
-      sym_element,
-      sym_for_statement,
-      sym_if_statement,
-      sym_let_binding,
-      sym_state_declaration,
-      sym_component_call,
-      sym_children_slot,
-      sym_go_expression,
-  [650] = 14,
-    ACTIONS(91), 1,
-      sym_identifier,
-    ACTIONS(93), 1,
+    ACTIONS(83), 4,
+      anon_sym_PIPE,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(85), 4,
+      sym_go_statement,
       anon_sym_LBRACE,
-    ACTIONS(97), 1,
-      anon_sym_LT,
-    ACTIONS(99), 1,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(79), 7,
       anon_sym_for,
-    ACTIONS(101), 1,
       anon_sym_if,
-    ACTIONS(103), 1,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
       anon_sym_var,
-    ACTIONS(105), 1,
-      anon_sym_AT,
-    ACTIONS(115), 1,
-      anon_sym_RBRACE,
-    STATE(9), 1,
-      aux_sym_component_body_repeat1,
-    STATE(16), 1,
+      sym_identifier,
+    ACTIONS(81), 17,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_DOT,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [904] = 16,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(47), 1,
+      sym_identifier,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
+      anon_sym_func,
+    ACTIONS(90), 1,
+      anon_sym_RPAREN,
+    STATE(15), 1,
       sym_comment,
-    STATE(133), 1,
-      sym__child,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(106), 1,
+      sym__expression,
+    STATE(511), 1,
+      sym_variadic_argument,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(137), 2,
-      sym_self_closing_element,
-      sym_element_with_children,
-    STATE(134), 8,
-      sym_element,
-      sym_for_statement,
-      sym_if_statement,
-      sym_let_binding,
-      sym_state_declaration,
-      sym_component_call,
-      sym_children_slot,
-      sym_go_expression,
-  [702] = 14,
-    ACTIONS(91), 1,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
+      sym_slice_type,
+      sym_map_type,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [973] = 16,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(47), 1,
       sym_identifier,
-    ACTIONS(93), 1,
-      anon_sym_LBRACE,
-    ACTIONS(97), 1,
-      anon_sym_LT,
-    ACTIONS(99), 1,
-      anon_sym_for,
-    ACTIONS(101), 1,
-      anon_sym_if,
-    ACTIONS(103), 1,
-      anon_sym_var,
-    ACTIONS(105), 1,
-      anon_sym_AT,
-    ACTIONS(117), 1,
-      anon_sym_RBRACE,
-    STATE(9), 1,
-      aux_sym_component_body_repeat1,
-    STATE(17), 1,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
+      anon_sym_func,
+    ACTIONS(92), 1,
+      anon_sym_RPAREN,
+    STATE(16), 1,
       sym_comment,
-    STATE(133), 1,
-      sym__child,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(97), 1,
+      sym__expression,
+    STATE(504), 1,
+      sym_variadic_argument,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(137), 2,
-      sym_self_closing_element,
-      sym_element_with_children,
-    STATE(134), 8,
-      sym_element,
-      sym_for_statement,
-      sym_if_statement,
-      sym_let_binding,
-      sym_state_declaration,
-      sym_component_call,
-      sym_children_slot,
-      sym_go_expression,
-  [754] = 14,
-    ACTIONS(119), 1,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(400), 2,
+      sym_slice_type,
+      sym_map_type,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [1042] = 16,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(47), 1,
       sym_identifier,
-    ACTIONS(122), 1,
-      anon_sym_LBRACE,
-    ACTIONS(125), 1,
-      anon_sym_LT,
-    ACTIONS(128), 1,
-      anon_sym_LT_SLASH,
-    ACTIONS(130), 1,
-      sym_text_content,
-    ACTIONS(133), 1,
-      anon_sym_for,
-    ACTIONS(136), 1,
-      anon_sym_if,
-    ACTIONS(139), 1,
-      anon_sym_var,
-    ACTIONS(142), 1,
-      anon_sym_AT,
-    STATE(105), 1,
-      sym__element_child,
-    ACTIONS(53), 2,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
+      anon_sym_func,
+    ACTIONS(94), 1,
+      anon_sym_RPAREN,
+    STATE(17), 1,
+      sym_comment,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(106), 1,
+      sym__expression,
+    STATE(490), 1,
+      sym_variadic_argument,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(18), 2,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
+      sym_slice_type,
+      sym_map_type,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [1111] = 4,
+    STATE(18), 1,
       sym_comment,
-      aux_sym_element_with_children_repeat1,
-    STATE(125), 2,
-      sym_self_closing_element,
-      sym_element_with_children,
-    STATE(128), 7,
-      sym_element,
-      sym_for_statement,
-      sym_if_statement,
-      sym_let_binding,
-      sym_component_call,
-      sym_children_slot,
-      sym_go_expression,
-  [806] = 15,
-    ACTIONS(35), 1,
-      sym_identifier,
-    ACTIONS(37), 1,
-      anon_sym_LBRACE,
-    ACTIONS(39), 1,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(96), 12,
+      anon_sym_PIPE,
       anon_sym_LT,
-    ACTIONS(43), 1,
-      sym_text_content,
-    ACTIONS(45), 1,
+      anon_sym_SLASH,
+      anon_sym_GT,
       anon_sym_for,
-    ACTIONS(47), 1,
       anon_sym_if,
-    ACTIONS(49), 1,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
       anon_sym_var,
-    ACTIONS(51), 1,
+      anon_sym_AMP,
+      sym_identifier,
+    ACTIONS(98), 21,
+      sym_go_statement,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(145), 1,
-      anon_sym_LT_SLASH,
-    STATE(18), 1,
-      aux_sym_element_with_children_repeat1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [1156] = 4,
     STATE(19), 1,
       sym_comment,
-    STATE(105), 1,
-      sym__element_child,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(125), 2,
-      sym_self_closing_element,
-      sym_element_with_children,
-    STATE(128), 7,
-      sym_element,
-      sym_for_statement,
-      sym_if_statement,
-      sym_let_binding,
-      sym_component_call,
-      sym_children_slot,
-      sym_go_expression,
-  [860] = 14,
-    ACTIONS(91), 1,
-      sym_identifier,
-    ACTIONS(93), 1,
-      anon_sym_LBRACE,
-    ACTIONS(97), 1,
+    ACTIONS(100), 12,
+      anon_sym_PIPE,
       anon_sym_LT,
-    ACTIONS(99), 1,
+      anon_sym_SLASH,
+      anon_sym_GT,
       anon_sym_for,
-    ACTIONS(101), 1,
       anon_sym_if,
-    ACTIONS(103), 1,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
       anon_sym_var,
-    ACTIONS(105), 1,
-      anon_sym_AT,
-    ACTIONS(147), 1,
+      anon_sym_AMP,
+      sym_identifier,
+    ACTIONS(102), 21,
+      sym_go_statement,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
       anon_sym_RBRACE,
+      anon_sym_AT,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [1201] = 4,
     STATE(20), 1,
       sym_comment,
-    STATE(22), 1,
-      aux_sym_component_body_repeat1,
-    STATE(133), 1,
-      sym__child,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(137), 2,
-      sym_self_closing_element,
-      sym_element_with_children,
-    STATE(134), 8,
-      sym_element,
-      sym_for_statement,
-      sym_if_statement,
-      sym_let_binding,
-      sym_state_declaration,
-      sym_component_call,
-      sym_children_slot,
-      sym_go_expression,
-  [912] = 14,
-    ACTIONS(91), 1,
-      sym_identifier,
-    ACTIONS(93), 1,
-      anon_sym_LBRACE,
-    ACTIONS(97), 1,
+    ACTIONS(104), 12,
+      anon_sym_PIPE,
       anon_sym_LT,
-    ACTIONS(99), 1,
+      anon_sym_SLASH,
+      anon_sym_GT,
       anon_sym_for,
-    ACTIONS(101), 1,
       anon_sym_if,
-    ACTIONS(103), 1,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
       anon_sym_var,
-    ACTIONS(105), 1,
-      anon_sym_AT,
-    ACTIONS(149), 1,
+      anon_sym_AMP,
+      sym_identifier,
+    ACTIONS(106), 21,
+      sym_go_statement,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
       anon_sym_RBRACE,
-    STATE(17), 1,
-      aux_sym_component_body_repeat1,
+      anon_sym_AT,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [1246] = 4,
     STATE(21), 1,
       sym_comment,
-    STATE(133), 1,
-      sym__child,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(137), 2,
-      sym_self_closing_element,
-      sym_element_with_children,
-    STATE(134), 8,
-      sym_element,
-      sym_for_statement,
-      sym_if_statement,
-      sym_let_binding,
-      sym_state_declaration,
-      sym_component_call,
-      sym_children_slot,
-      sym_go_expression,
-  [964] = 14,
-    ACTIONS(91), 1,
-      sym_identifier,
-    ACTIONS(93), 1,
-      anon_sym_LBRACE,
-    ACTIONS(97), 1,
+    ACTIONS(108), 12,
+      anon_sym_PIPE,
       anon_sym_LT,
-    ACTIONS(99), 1,
+      anon_sym_SLASH,
+      anon_sym_GT,
       anon_sym_for,
-    ACTIONS(101), 1,
       anon_sym_if,
-    ACTIONS(103), 1,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
       anon_sym_var,
-    ACTIONS(105), 1,
-      anon_sym_AT,
-    ACTIONS(151), 1,
+      anon_sym_AMP,
+      sym_identifier,
+    ACTIONS(110), 21,
+      sym_go_statement,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
       anon_sym_RBRACE,
-    STATE(9), 1,
-      aux_sym_component_body_repeat1,
+      anon_sym_AT,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [1291] = 4,
     STATE(22), 1,
       sym_comment,
-    STATE(133), 1,
-      sym__child,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(137), 2,
-      sym_self_closing_element,
-      sym_element_with_children,
-    STATE(134), 8,
-      sym_element,
-      sym_for_statement,
-      sym_if_statement,
-      sym_let_binding,
-      sym_state_declaration,
-      sym_component_call,
-      sym_children_slot,
-      sym_go_expression,
-  [1016] = 15,
-    ACTIONS(35), 1,
-      sym_identifier,
-    ACTIONS(37), 1,
-      anon_sym_LBRACE,
-    ACTIONS(39), 1,
+    ACTIONS(112), 12,
+      anon_sym_PIPE,
       anon_sym_LT,
-    ACTIONS(43), 1,
-      sym_text_content,
-    ACTIONS(45), 1,
+      anon_sym_SLASH,
+      anon_sym_GT,
       anon_sym_for,
-    ACTIONS(47), 1,
       anon_sym_if,
-    ACTIONS(49), 1,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
       anon_sym_var,
-    ACTIONS(51), 1,
+      anon_sym_AMP,
+      sym_identifier,
+    ACTIONS(114), 21,
+      sym_go_statement,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(153), 1,
-      anon_sym_LT_SLASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [1336] = 4,
     STATE(23), 1,
       sym_comment,
-    STATE(24), 1,
-      aux_sym_element_with_children_repeat1,
-    STATE(105), 1,
-      sym__element_child,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(125), 2,
-      sym_self_closing_element,
-      sym_element_with_children,
-    STATE(128), 7,
-      sym_element,
-      sym_for_statement,
-      sym_if_statement,
-      sym_let_binding,
-      sym_component_call,
-      sym_children_slot,
-      sym_go_expression,
-  [1070] = 15,
-    ACTIONS(35), 1,
-      sym_identifier,
-    ACTIONS(37), 1,
-      anon_sym_LBRACE,
-    ACTIONS(39), 1,
+    ACTIONS(116), 12,
+      anon_sym_PIPE,
       anon_sym_LT,
-    ACTIONS(43), 1,
-      sym_text_content,
-    ACTIONS(45), 1,
+      anon_sym_SLASH,
+      anon_sym_GT,
       anon_sym_for,
-    ACTIONS(47), 1,
       anon_sym_if,
-    ACTIONS(49), 1,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
       anon_sym_var,
-    ACTIONS(51), 1,
+      anon_sym_AMP,
+      sym_identifier,
+    ACTIONS(118), 21,
+      sym_go_statement,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(55), 1,
-      anon_sym_LT_SLASH,
-    STATE(18), 1,
-      aux_sym_element_with_children_repeat1,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [1381] = 16,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(47), 1,
+      sym_identifier,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
+      anon_sym_func,
+    ACTIONS(120), 1,
+      anon_sym_RPAREN,
     STATE(24), 1,
       sym_comment,
-    STATE(105), 1,
-      sym__element_child,
-    ACTIONS(53), 2,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(95), 1,
+      sym__expression,
+    STATE(471), 1,
+      sym_variadic_argument,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(125), 2,
-      sym_self_closing_element,
-      sym_element_with_children,
-    STATE(128), 7,
-      sym_element,
-      sym_for_statement,
-      sym_if_statement,
-      sym_let_binding,
-      sym_component_call,
-      sym_children_slot,
-      sym_go_expression,
-  [1124] = 13,
-    ACTIONS(155), 1,
-      sym_identifier,
-    ACTIONS(157), 1,
-      anon_sym_LPAREN,
-    ACTIONS(161), 1,
-      anon_sym_LBRACK,
-    ACTIONS(163), 1,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(394), 2,
+      sym_slice_type,
+      sym_map_type,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
       anon_sym_STAR,
-    ACTIONS(165), 1,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [1450] = 16,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
       anon_sym_map,
-    ACTIONS(167), 1,
+    ACTIONS(47), 1,
+      sym_identifier,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
       anon_sym_func,
+    ACTIONS(122), 1,
+      anon_sym_RPAREN,
     STATE(25), 1,
       sym_comment,
-    STATE(157), 1,
-      sym_qualified_type,
-    STATE(173), 1,
-      sym_type_expression,
-    STATE(200), 1,
-      sym_return_type,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(106), 1,
+      sym__expression,
+    STATE(482), 1,
+      sym_variadic_argument,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(159), 4,
-      anon_sym_RPAREN,
-      anon_sym_COMMA,
-      anon_sym_RBRACK,
-      anon_sym_LBRACE,
-    STATE(195), 5,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
       sym_slice_type,
-      sym_pointer_type,
       sym_map_type,
-      sym_func_type,
-      sym_generic_type,
-  [1172] = 13,
-    ACTIONS(155), 1,
-      sym_identifier,
-    ACTIONS(157), 1,
-      anon_sym_LPAREN,
-    ACTIONS(161), 1,
-      anon_sym_LBRACK,
-    ACTIONS(163), 1,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
       anon_sym_STAR,
-    ACTIONS(165), 1,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [1519] = 16,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
       anon_sym_map,
-    ACTIONS(167), 1,
+    ACTIONS(47), 1,
+      sym_identifier,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
       anon_sym_func,
+    ACTIONS(124), 1,
+      anon_sym_RPAREN,
     STATE(26), 1,
       sym_comment,
-    STATE(157), 1,
-      sym_qualified_type,
-    STATE(173), 1,
-      sym_type_expression,
-    STATE(178), 1,
-      sym_return_type,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(106), 1,
+      sym__expression,
+    STATE(484), 1,
+      sym_variadic_argument,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(169), 4,
-      anon_sym_RPAREN,
-      anon_sym_COMMA,
-      anon_sym_RBRACK,
-      anon_sym_LBRACE,
-    STATE(195), 5,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
       sym_slice_type,
-      sym_pointer_type,
       sym_map_type,
-      sym_func_type,
-      sym_generic_type,
-  [1220] = 6,
-    ACTIONS(171), 1,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [1588] = 16,
+    ACTIONS(21), 1,
       anon_sym_LPAREN,
-    STATE(2), 1,
-      sym_argument_list,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(47), 1,
+      sym_identifier,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
+      anon_sym_func,
+    ACTIONS(126), 1,
+      anon_sym_RPAREN,
     STATE(27), 1,
       sym_comment,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(98), 1,
+      sym__expression,
+    STATE(489), 1,
+      sym_variadic_argument,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(66), 3,
-      anon_sym_LT,
-      anon_sym_SLASH,
-      anon_sym_GT,
-    ACTIONS(59), 13,
-      anon_sym_RPAREN,
-      anon_sym_COMMA,
-      anon_sym_DOT,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(401), 2,
+      sym_slice_type,
+      sym_map_type,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
       anon_sym_STAR,
-      anon_sym_LBRACE,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
+      anon_sym_LT_DASH,
       anon_sym_PLUS,
       anon_sym_DASH,
-      anon_sym_AMP_AMP,
-      anon_sym_PIPE_PIPE,
-  [1254] = 13,
-    ACTIONS(155), 1,
-      sym_identifier,
-    ACTIONS(157), 1,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [1657] = 16,
+    ACTIONS(21), 1,
       anon_sym_LPAREN,
-    ACTIONS(161), 1,
-      anon_sym_LBRACK,
-    ACTIONS(163), 1,
-      anon_sym_STAR,
-    ACTIONS(165), 1,
+    ACTIONS(29), 1,
       anon_sym_map,
-    ACTIONS(167), 1,
+    ACTIONS(47), 1,
+      sym_identifier,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
       anon_sym_func,
+    ACTIONS(128), 1,
+      anon_sym_RPAREN,
     STATE(28), 1,
       sym_comment,
-    STATE(157), 1,
-      sym_qualified_type,
-    STATE(173), 1,
-      sym_type_expression,
-    STATE(191), 1,
-      sym_return_type,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(106), 1,
+      sym__expression,
+    STATE(492), 1,
+      sym_variadic_argument,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(173), 4,
-      anon_sym_RPAREN,
-      anon_sym_COMMA,
-      anon_sym_RBRACK,
-      anon_sym_LBRACE,
-    STATE(195), 5,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
       sym_slice_type,
-      sym_pointer_type,
       sym_map_type,
-      sym_func_type,
-      sym_generic_type,
-  [1302] = 14,
-    ACTIONS(97), 1,
-      anon_sym_LT,
-    ACTIONS(105), 1,
-      anon_sym_AT,
-    ACTIONS(175), 1,
-      sym_identifier,
-    ACTIONS(177), 1,
-      anon_sym_LPAREN,
-    STATE(8), 1,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
       sym_call_expression,
-    STATE(27), 1,
-      sym_selector_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [1726] = 16,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(47), 1,
+      sym_identifier,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
+      anon_sym_func,
+    ACTIONS(130), 1,
+      anon_sym_RPAREN,
     STATE(29), 1,
       sym_comment,
-    STATE(48), 1,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(106), 1,
       sym__expression,
+    STATE(494), 1,
+      sym_variadic_argument,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(179), 2,
-      sym_number,
-      sym_string,
-    ACTIONS(181), 2,
+    ACTIONS(45), 2,
       sym_true,
       sym_false,
-    STATE(34), 2,
+    STATE(469), 2,
+      sym_slice_type,
+      sym_map_type,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
       sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
       sym_parenthesized_expression,
-    STATE(131), 2,
-      sym_element,
-      sym_component_call,
-    STATE(137), 2,
-      sym_self_closing_element,
-      sym_element_with_children,
-  [1351] = 4,
-    STATE(30), 1,
-      sym_comment,
-    ACTIONS(3), 2,
-      aux_sym_comment_token1,
-      aux_sym_comment_token2,
-    ACTIONS(185), 3,
-      anon_sym_LT,
-      anon_sym_SLASH,
-      anon_sym_GT,
-    ACTIONS(183), 14,
+  [1795] = 16,
+    ACTIONS(21), 1,
       anon_sym_LPAREN,
-      anon_sym_RPAREN,
-      anon_sym_COMMA,
-      anon_sym_DOT,
-      anon_sym_STAR,
-      anon_sym_LBRACE,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-      anon_sym_PLUS,
-      anon_sym_DASH,
-      anon_sym_AMP_AMP,
-      anon_sym_PIPE_PIPE,
-  [1380] = 14,
-    ACTIONS(155), 1,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(47), 1,
       sym_identifier,
-    ACTIONS(157), 1,
-      anon_sym_LPAREN,
-    ACTIONS(161), 1,
+    ACTIONS(49), 1,
       anon_sym_LBRACK,
-    ACTIONS(163), 1,
-      anon_sym_STAR,
-    ACTIONS(165), 1,
-      anon_sym_map,
-    ACTIONS(167), 1,
+    ACTIONS(51), 1,
       anon_sym_func,
-    ACTIONS(187), 1,
-      anon_sym_LBRACE,
-    STATE(31), 1,
+    ACTIONS(132), 1,
+      anon_sym_RPAREN,
+    STATE(30), 1,
       sym_comment,
-    STATE(157), 1,
-      sym_qualified_type,
-    STATE(173), 1,
-      sym_type_expression,
-    STATE(175), 1,
-      sym_function_body,
-    STATE(264), 1,
-      sym_return_type,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(96), 1,
+      sym__expression,
+    STATE(496), 1,
+      sym_variadic_argument,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(195), 5,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(404), 2,
       sym_slice_type,
-      sym_pointer_type,
       sym_map_type,
-      sym_func_type,
-      sym_generic_type,
-  [1428] = 5,
-    ACTIONS(191), 1,
-      anon_sym_DOT,
-    STATE(32), 1,
-      sym_comment,
-    ACTIONS(3), 2,
-      aux_sym_comment_token1,
-      aux_sym_comment_token2,
-    ACTIONS(193), 3,
-      anon_sym_LT,
-      anon_sym_SLASH,
-      anon_sym_GT,
-    ACTIONS(189), 12,
-      anon_sym_RPAREN,
-      anon_sym_COMMA,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
       anon_sym_STAR,
-      anon_sym_LBRACE,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
+      anon_sym_LT_DASH,
       anon_sym_PLUS,
       anon_sym_DASH,
-      anon_sym_AMP_AMP,
-      anon_sym_PIPE_PIPE,
-  [1458] = 8,
-    ACTIONS(191), 1,
-      anon_sym_DOT,
-    ACTIONS(195), 1,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [1864] = 16,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(47), 1,
+      sym_identifier,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
+      anon_sym_func,
+    ACTIONS(134), 1,
       anon_sym_RPAREN,
-    ACTIONS(197), 1,
-      anon_sym_COMMA,
-    STATE(33), 1,
+    STATE(31), 1,
       sym_comment,
-    STATE(239), 1,
-      aux_sym_argument_list_repeat1,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(106), 1,
+      sym__expression,
+    STATE(499), 1,
+      sym_variadic_argument,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(201), 3,
-      anon_sym_LT,
-      anon_sym_SLASH,
-      anon_sym_GT,
-    ACTIONS(199), 9,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
+      sym_slice_type,
+      sym_map_type,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
       anon_sym_STAR,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
+      anon_sym_LT_DASH,
       anon_sym_PLUS,
       anon_sym_DASH,
-      anon_sym_AMP_AMP,
-      anon_sym_PIPE_PIPE,
-  [1494] = 4,
-    STATE(34), 1,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [1933] = 15,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(25), 1,
+      anon_sym_RBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(47), 1,
+      sym_identifier,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
+      anon_sym_func,
+    STATE(32), 1,
       sym_comment,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(134), 1,
+      sym__expression,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(66), 3,
-      anon_sym_LT,
-      anon_sym_SLASH,
-      anon_sym_GT,
-    ACTIONS(59), 13,
-      anon_sym_RPAREN,
-      anon_sym_COMMA,
-      anon_sym_DOT,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
+      sym_slice_type,
+      sym_map_type,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
       anon_sym_STAR,
-      anon_sym_LBRACE,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
+      anon_sym_LT_DASH,
       anon_sym_PLUS,
       anon_sym_DASH,
-      anon_sym_AMP_AMP,
-      anon_sym_PIPE_PIPE,
-  [1522] = 14,
-    ACTIONS(155), 1,
-      sym_identifier,
-    ACTIONS(157), 1,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [1999] = 15,
+    ACTIONS(21), 1,
       anon_sym_LPAREN,
-    ACTIONS(161), 1,
-      anon_sym_LBRACK,
-    ACTIONS(163), 1,
-      anon_sym_STAR,
-    ACTIONS(165), 1,
+    ACTIONS(29), 1,
       anon_sym_map,
-    ACTIONS(167), 1,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
       anon_sym_func,
-    ACTIONS(187), 1,
+    ACTIONS(136), 1,
+      sym_identifier,
+    ACTIONS(140), 1,
       anon_sym_LBRACE,
-    STATE(35), 1,
+    STATE(33), 1,
       sym_comment,
-    STATE(157), 1,
-      sym_qualified_type,
-    STATE(173), 1,
-      sym_type_expression,
-    STATE(202), 1,
-      sym_function_body,
-    STATE(267), 1,
-      sym_return_type,
+    STATE(128), 1,
+      sym__expression,
+    STATE(136), 1,
+      sym_selector_expression,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(195), 5,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
       sym_slice_type,
-      sym_pointer_type,
       sym_map_type,
-      sym_func_type,
-      sym_generic_type,
-  [1570] = 8,
-    ACTIONS(191), 1,
-      anon_sym_DOT,
-    ACTIONS(197), 1,
-      anon_sym_COMMA,
-    ACTIONS(203), 1,
-      anon_sym_RPAREN,
-    STATE(36), 1,
-      sym_comment,
-    STATE(246), 1,
-      aux_sym_argument_list_repeat1,
-    ACTIONS(3), 2,
-      aux_sym_comment_token1,
-      aux_sym_comment_token2,
-    ACTIONS(201), 3,
-      anon_sym_LT,
-      anon_sym_SLASH,
-      anon_sym_GT,
-    ACTIONS(199), 9,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(138), 7,
       anon_sym_STAR,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
+      anon_sym_LT_DASH,
       anon_sym_PLUS,
       anon_sym_DASH,
-      anon_sym_AMP_AMP,
-      anon_sym_PIPE_PIPE,
-  [1606] = 4,
-    STATE(37), 1,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [2065] = 15,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(47), 1,
+      sym_identifier,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
+      anon_sym_func,
+    ACTIONS(142), 1,
+      anon_sym_COLON,
+    STATE(34), 1,
       sym_comment,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(113), 1,
+      sym__expression,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(207), 3,
-      anon_sym_LT,
-      anon_sym_SLASH,
-      anon_sym_GT,
-    ACTIONS(205), 13,
-      anon_sym_RPAREN,
-      anon_sym_COMMA,
-      anon_sym_DOT,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
+      sym_slice_type,
+      sym_map_type,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
       anon_sym_STAR,
-      anon_sym_LBRACE,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
+      anon_sym_LT_DASH,
       anon_sym_PLUS,
       anon_sym_DASH,
-      anon_sym_AMP_AMP,
-      anon_sym_PIPE_PIPE,
-  [1634] = 13,
-    ACTIONS(157), 1,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [2131] = 15,
+    ACTIONS(21), 1,
       anon_sym_LPAREN,
-    ACTIONS(159), 1,
-      anon_sym_RBRACE,
-    ACTIONS(209), 1,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(47), 1,
       sym_identifier,
-    ACTIONS(212), 1,
+    ACTIONS(49), 1,
       anon_sym_LBRACK,
-    ACTIONS(214), 1,
-      anon_sym_STAR,
-    ACTIONS(216), 1,
-      anon_sym_map,
-    ACTIONS(218), 1,
+    ACTIONS(51), 1,
       anon_sym_func,
-    STATE(38), 1,
+    ACTIONS(144), 1,
+      anon_sym_chan,
+    STATE(35), 1,
       sym_comment,
-    STATE(157), 1,
-      sym_qualified_type,
-    STATE(173), 1,
-      sym_type_expression,
-    STATE(200), 1,
-      sym_return_type,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(64), 1,
+      sym__expression,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(195), 5,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
       sym_slice_type,
-      sym_pointer_type,
       sym_map_type,
-      sym_func_type,
-      sym_generic_type,
-  [1679] = 7,
-    ACTIONS(191), 1,
-      anon_sym_DOT,
-    ACTIONS(220), 1,
-      anon_sym_LBRACE,
-    STATE(39), 1,
-      sym_comment,
-    STATE(129), 1,
-      sym_block,
-    ACTIONS(3), 2,
-      aux_sym_comment_token1,
-      aux_sym_comment_token2,
-    ACTIONS(201), 3,
-      anon_sym_LT,
-      anon_sym_SLASH,
-      anon_sym_GT,
-    ACTIONS(199), 9,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
       anon_sym_STAR,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
+      anon_sym_LT_DASH,
       anon_sym_PLUS,
       anon_sym_DASH,
-      anon_sym_AMP_AMP,
-      anon_sym_PIPE_PIPE,
-  [1712] = 7,
-    ACTIONS(191), 1,
-      anon_sym_DOT,
-    ACTIONS(222), 1,
-      anon_sym_LBRACE,
-    STATE(40), 1,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [2197] = 15,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(47), 1,
+      sym_identifier,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
+      anon_sym_func,
+    ACTIONS(146), 1,
+      anon_sym_RBRACK,
+    STATE(36), 1,
       sym_comment,
-    STATE(95), 1,
-      sym_block,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(114), 1,
+      sym__expression,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(201), 3,
-      anon_sym_LT,
-      anon_sym_SLASH,
-      anon_sym_GT,
-    ACTIONS(199), 9,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
+      sym_slice_type,
+      sym_map_type,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
       anon_sym_STAR,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
+      anon_sym_LT_DASH,
       anon_sym_PLUS,
       anon_sym_DASH,
-      anon_sym_AMP_AMP,
-      anon_sym_PIPE_PIPE,
-  [1745] = 13,
-    ACTIONS(157), 1,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [2263] = 15,
+    ACTIONS(21), 1,
       anon_sym_LPAREN,
-    ACTIONS(169), 1,
-      anon_sym_RBRACE,
-    ACTIONS(212), 1,
-      anon_sym_LBRACK,
-    ACTIONS(214), 1,
-      anon_sym_STAR,
-    ACTIONS(216), 1,
+    ACTIONS(29), 1,
       anon_sym_map,
-    ACTIONS(218), 1,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
       anon_sym_func,
-    ACTIONS(224), 1,
+    ACTIONS(136), 1,
       sym_identifier,
-    STATE(41), 1,
+    ACTIONS(148), 1,
+      anon_sym_LBRACE,
+    STATE(37), 1,
       sym_comment,
-    STATE(157), 1,
-      sym_qualified_type,
-    STATE(173), 1,
-      sym_type_expression,
-    STATE(178), 1,
-      sym_return_type,
+    STATE(132), 1,
+      sym__expression,
+    STATE(136), 1,
+      sym_selector_expression,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(195), 5,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
       sym_slice_type,
-      sym_pointer_type,
       sym_map_type,
-      sym_func_type,
-      sym_generic_type,
-  [1790] = 6,
-    ACTIONS(191), 1,
-      anon_sym_DOT,
-    STATE(42), 1,
-      sym_comment,
-    ACTIONS(3), 2,
-      aux_sym_comment_token1,
-      aux_sym_comment_token2,
-    ACTIONS(227), 2,
-      anon_sym_RPAREN,
-      anon_sym_COMMA,
-    ACTIONS(201), 3,
-      anon_sym_LT,
-      anon_sym_SLASH,
-      anon_sym_GT,
-    ACTIONS(199), 9,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(138), 7,
       anon_sym_STAR,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
+      anon_sym_LT_DASH,
       anon_sym_PLUS,
       anon_sym_DASH,
-      anon_sym_AMP_AMP,
-      anon_sym_PIPE_PIPE,
-  [1821] = 13,
-    ACTIONS(9), 1,
-      anon_sym_import,
-    ACTIONS(11), 1,
-      anon_sym_templ,
-    ACTIONS(13), 1,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [2329] = 15,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(47), 1,
+      sym_identifier,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
       anon_sym_func,
-    ACTIONS(15), 1,
-      anon_sym_type,
-    ACTIONS(229), 1,
-      ts_builtin_sym_end,
-    STATE(43), 1,
+    ACTIONS(150), 1,
+      anon_sym_RBRACK,
+    STATE(38), 1,
       sym_comment,
-    STATE(68), 1,
-      sym_import_section,
-    STATE(71), 1,
-      aux_sym_source_file_repeat1,
-    STATE(124), 1,
-      aux_sym_import_section_repeat1,
-    STATE(163), 1,
-      sym_import_declaration,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(147), 1,
+      sym__expression,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(17), 2,
-      anon_sym_var,
-      anon_sym_const,
-    STATE(180), 4,
-      sym_component_declaration,
-      sym_type_struct_declaration,
-      sym_function_declaration,
-      sym_go_declaration,
-  [1866] = 13,
-    ACTIONS(157), 1,
-      anon_sym_LPAREN,
-    ACTIONS(173), 1,
-      anon_sym_RBRACE,
-    ACTIONS(212), 1,
-      anon_sym_LBRACK,
-    ACTIONS(214), 1,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
+      sym_slice_type,
+      sym_map_type,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
       anon_sym_STAR,
-    ACTIONS(216), 1,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [2395] = 15,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
       anon_sym_map,
-    ACTIONS(218), 1,
-      anon_sym_func,
-    ACTIONS(231), 1,
+    ACTIONS(47), 1,
       sym_identifier,
-    STATE(44), 1,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
+      anon_sym_func,
+    ACTIONS(152), 1,
+      anon_sym_RBRACK,
+    STATE(39), 1,
       sym_comment,
-    STATE(157), 1,
-      sym_qualified_type,
-    STATE(173), 1,
-      sym_type_expression,
-    STATE(191), 1,
-      sym_return_type,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(116), 1,
+      sym__expression,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(195), 5,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
       sym_slice_type,
-      sym_pointer_type,
       sym_map_type,
-      sym_func_type,
-      sym_generic_type,
-  [1911] = 6,
-    ACTIONS(191), 1,
-      anon_sym_DOT,
-    ACTIONS(234), 1,
-      anon_sym_RPAREN,
-    STATE(45), 1,
-      sym_comment,
-    ACTIONS(3), 2,
-      aux_sym_comment_token1,
-      aux_sym_comment_token2,
-    ACTIONS(201), 3,
-      anon_sym_LT,
-      anon_sym_SLASH,
-      anon_sym_GT,
-    ACTIONS(199), 9,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
       anon_sym_STAR,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
+      anon_sym_LT_DASH,
       anon_sym_PLUS,
       anon_sym_DASH,
-      anon_sym_AMP_AMP,
-      anon_sym_PIPE_PIPE,
-  [1941] = 6,
-    ACTIONS(191), 1,
-      anon_sym_DOT,
-    ACTIONS(236), 1,
-      anon_sym_LBRACE,
-    STATE(46), 1,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [2461] = 15,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(47), 1,
+      sym_identifier,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
+      anon_sym_func,
+    ACTIONS(154), 1,
+      anon_sym_RBRACK,
+    STATE(40), 1,
       sym_comment,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(148), 1,
+      sym__expression,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(201), 3,
-      anon_sym_LT,
-      anon_sym_SLASH,
-      anon_sym_GT,
-    ACTIONS(199), 9,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
+      sym_slice_type,
+      sym_map_type,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
       anon_sym_STAR,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
+      anon_sym_LT_DASH,
       anon_sym_PLUS,
       anon_sym_DASH,
-      anon_sym_AMP_AMP,
-      anon_sym_PIPE_PIPE,
-  [1971] = 6,
-    ACTIONS(191), 1,
-      anon_sym_DOT,
-    ACTIONS(238), 1,
-      anon_sym_LBRACE,
-    STATE(47), 1,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [2527] = 14,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(47), 1,
+      sym_identifier,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
+      anon_sym_func,
+    STATE(41), 1,
       sym_comment,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(133), 1,
+      sym__expression,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(201), 3,
-      anon_sym_LT,
-      anon_sym_SLASH,
-      anon_sym_GT,
-    ACTIONS(199), 9,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
+      sym_slice_type,
+      sym_map_type,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
       anon_sym_STAR,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
+      anon_sym_LT_DASH,
       anon_sym_PLUS,
       anon_sym_DASH,
-      anon_sym_AMP_AMP,
-      anon_sym_PIPE_PIPE,
-  [2001] = 5,
-    ACTIONS(191), 1,
-      anon_sym_DOT,
-    STATE(48), 1,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [2590] = 14,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(47), 1,
+      sym_identifier,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
+      anon_sym_func,
+    STATE(42), 1,
       sym_comment,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(123), 1,
+      sym__expression,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(201), 3,
-      anon_sym_LT,
-      anon_sym_SLASH,
-      anon_sym_GT,
-    ACTIONS(199), 9,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
+      sym_slice_type,
+      sym_map_type,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
       anon_sym_STAR,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
+      anon_sym_LT_DASH,
       anon_sym_PLUS,
       anon_sym_DASH,
-      anon_sym_AMP_AMP,
-      anon_sym_PIPE_PIPE,
-  [2028] = 11,
-    ACTIONS(155), 1,
-      sym_identifier,
-    ACTIONS(161), 1,
-      anon_sym_LBRACK,
-    ACTIONS(163), 1,
-      anon_sym_STAR,
-    ACTIONS(165), 1,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [2653] = 14,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
       anon_sym_map,
-    ACTIONS(167), 1,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
       anon_sym_func,
-    ACTIONS(240), 1,
-      anon_sym_RPAREN,
-    STATE(49), 1,
+    ACTIONS(136), 1,
+      sym_identifier,
+    STATE(43), 1,
       sym_comment,
-    STATE(157), 1,
-      sym_qualified_type,
-    STATE(224), 1,
-      sym_type_expression,
+    STATE(136), 1,
+      sym_selector_expression,
+    STATE(139), 1,
+      sym__expression,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(195), 5,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
       sym_slice_type,
-      sym_pointer_type,
       sym_map_type,
-      sym_func_type,
-      sym_generic_type,
-  [2067] = 11,
-    ACTIONS(155), 1,
-      sym_identifier,
-    ACTIONS(161), 1,
-      anon_sym_LBRACK,
-    ACTIONS(163), 1,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(138), 7,
       anon_sym_STAR,
-    ACTIONS(165), 1,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [2716] = 14,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
       anon_sym_map,
-    ACTIONS(167), 1,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
       anon_sym_func,
-    ACTIONS(242), 1,
-      anon_sym_RPAREN,
-    STATE(50), 1,
+    ACTIONS(136), 1,
+      sym_identifier,
+    STATE(44), 1,
       sym_comment,
-    STATE(157), 1,
-      sym_qualified_type,
-    STATE(232), 1,
-      sym_type_expression,
+    STATE(65), 1,
+      sym__expression,
+    STATE(136), 1,
+      sym_selector_expression,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(195), 5,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
       sym_slice_type,
-      sym_pointer_type,
       sym_map_type,
-      sym_func_type,
-      sym_generic_type,
-  [2106] = 10,
-    ACTIONS(155), 1,
-      sym_identifier,
-    ACTIONS(161), 1,
-      anon_sym_LBRACK,
-    ACTIONS(163), 1,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(138), 7,
       anon_sym_STAR,
-    ACTIONS(165), 1,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [2779] = 14,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
       anon_sym_map,
-    ACTIONS(167), 1,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
       anon_sym_func,
-    STATE(51), 1,
+    ACTIONS(136), 1,
+      sym_identifier,
+    STATE(45), 1,
       sym_comment,
-    STATE(157), 1,
-      sym_qualified_type,
-    STATE(243), 1,
-      sym_type_expression,
+    STATE(135), 1,
+      sym__expression,
+    STATE(136), 1,
+      sym_selector_expression,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(195), 5,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
       sym_slice_type,
-      sym_pointer_type,
       sym_map_type,
-      sym_func_type,
-      sym_generic_type,
-  [2142] = 10,
-    ACTIONS(155), 1,
-      sym_identifier,
-    ACTIONS(161), 1,
-      anon_sym_LBRACK,
-    ACTIONS(163), 1,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(138), 7,
       anon_sym_STAR,
-    ACTIONS(165), 1,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [2842] = 14,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
       anon_sym_map,
-    ACTIONS(167), 1,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
       anon_sym_func,
-    STATE(52), 1,
+    ACTIONS(136), 1,
+      sym_identifier,
+    STATE(46), 1,
       sym_comment,
-    STATE(157), 1,
-      sym_qualified_type,
-    STATE(176), 1,
-      sym_type_expression,
+    STATE(136), 1,
+      sym_selector_expression,
+    STATE(140), 1,
+      sym__expression,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(195), 5,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
       sym_slice_type,
-      sym_pointer_type,
       sym_map_type,
-      sym_func_type,
-      sym_generic_type,
-  [2178] = 10,
-    ACTIONS(155), 1,
-      sym_identifier,
-    ACTIONS(212), 1,
-      anon_sym_LBRACK,
-    ACTIONS(214), 1,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(138), 7,
       anon_sym_STAR,
-    ACTIONS(216), 1,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [2905] = 14,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
       anon_sym_map,
-    ACTIONS(218), 1,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
       anon_sym_func,
-    STATE(53), 1,
+    ACTIONS(136), 1,
+      sym_identifier,
+    STATE(47), 1,
       sym_comment,
-    STATE(157), 1,
-      sym_qualified_type,
-    STATE(263), 1,
-      sym_type_expression,
+    STATE(136), 1,
+      sym_selector_expression,
+    STATE(142), 1,
+      sym__expression,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(195), 5,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
       sym_slice_type,
-      sym_pointer_type,
       sym_map_type,
-      sym_func_type,
-      sym_generic_type,
-  [2214] = 10,
-    ACTIONS(155), 1,
-      sym_identifier,
-    ACTIONS(161), 1,
-      anon_sym_LBRACK,
-    ACTIONS(163), 1,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(138), 7,
       anon_sym_STAR,
-    ACTIONS(165), 1,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [2968] = 14,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
       anon_sym_map,
-    ACTIONS(167), 1,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
       anon_sym_func,
-    STATE(54), 1,
+    ACTIONS(136), 1,
+      sym_identifier,
+    STATE(48), 1,
       sym_comment,
-    STATE(157), 1,
-      sym_qualified_type,
-    STATE(287), 1,
-      sym_type_expression,
+    STATE(136), 1,
+      sym_selector_expression,
+    STATE(141), 1,
+      sym__expression,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(195), 5,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
       sym_slice_type,
-      sym_pointer_type,
       sym_map_type,
-      sym_func_type,
-      sym_generic_type,
-  [2250] = 10,
-    ACTIONS(155), 1,
-      sym_identifier,
-    ACTIONS(161), 1,
-      anon_sym_LBRACK,
-    ACTIONS(163), 1,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(138), 7,
       anon_sym_STAR,
-    ACTIONS(165), 1,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [3031] = 14,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
       anon_sym_map,
-    ACTIONS(167), 1,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
       anon_sym_func,
-    STATE(55), 1,
+    ACTIONS(136), 1,
+      sym_identifier,
+    STATE(49), 1,
       sym_comment,
-    STATE(157), 1,
-      sym_qualified_type,
-    STATE(237), 1,
-      sym_type_expression,
+    STATE(110), 1,
+      sym__expression,
+    STATE(136), 1,
+      sym_selector_expression,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(195), 5,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
       sym_slice_type,
-      sym_pointer_type,
       sym_map_type,
-      sym_func_type,
-      sym_generic_type,
-  [2286] = 10,
-    ACTIONS(155), 1,
-      sym_identifier,
-    ACTIONS(161), 1,
-      anon_sym_LBRACK,
-    ACTIONS(163), 1,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(138), 7,
       anon_sym_STAR,
-    ACTIONS(165), 1,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [3094] = 14,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
       anon_sym_map,
-    ACTIONS(167), 1,
+    ACTIONS(47), 1,
+      sym_identifier,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
       anon_sym_func,
-    STATE(56), 1,
+    STATE(50), 1,
       sym_comment,
-    STATE(157), 1,
-      sym_qualified_type,
-    STATE(323), 1,
-      sym_type_expression,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(65), 1,
+      sym__expression,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(195), 5,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
       sym_slice_type,
-      sym_pointer_type,
       sym_map_type,
-      sym_func_type,
-      sym_generic_type,
-  [2322] = 10,
-    ACTIONS(155), 1,
-      sym_identifier,
-    ACTIONS(161), 1,
-      anon_sym_LBRACK,
-    ACTIONS(163), 1,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
       anon_sym_STAR,
-    ACTIONS(165), 1,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [3157] = 14,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
       anon_sym_map,
-    ACTIONS(167), 1,
+    ACTIONS(47), 1,
+      sym_identifier,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
       anon_sym_func,
-    STATE(57), 1,
+    STATE(51), 1,
       sym_comment,
-    STATE(157), 1,
-      sym_qualified_type,
-    STATE(199), 1,
-      sym_type_expression,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(103), 1,
+      sym__expression,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(195), 5,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
       sym_slice_type,
-      sym_pointer_type,
       sym_map_type,
-      sym_func_type,
-      sym_generic_type,
-  [2358] = 10,
-    ACTIONS(155), 1,
-      sym_identifier,
-    ACTIONS(161), 1,
-      anon_sym_LBRACK,
-    ACTIONS(163), 1,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
       anon_sym_STAR,
-    ACTIONS(165), 1,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [3220] = 14,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
       anon_sym_map,
-    ACTIONS(167), 1,
+    ACTIONS(47), 1,
+      sym_identifier,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
       anon_sym_func,
-    STATE(58), 1,
+    STATE(52), 1,
       sym_comment,
-    STATE(157), 1,
-      sym_qualified_type,
-    STATE(190), 1,
-      sym_type_expression,
-    ACTIONS(3), 2,
-      aux_sym_comment_token1,
-      aux_sym_comment_token2,
-    STATE(195), 5,
-      sym_slice_type,
-      sym_pointer_type,
-      sym_map_type,
-      sym_func_type,
-      sym_generic_type,
-  [2394] = 10,
-    ACTIONS(175), 1,
-      sym_identifier,
-    ACTIONS(177), 1,
-      anon_sym_LPAREN,
-    ACTIONS(244), 1,
-      anon_sym_RPAREN,
-    STATE(27), 1,
+    STATE(63), 1,
       sym_selector_expression,
-    STATE(33), 1,
+    STATE(73), 1,
       sym__expression,
-    STATE(59), 1,
-      sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(179), 2,
-      sym_number,
-      sym_string,
-    ACTIONS(181), 2,
+    ACTIONS(45), 2,
       sym_true,
       sym_false,
-    STATE(34), 3,
+    STATE(469), 2,
+      sym_slice_type,
+      sym_map_type,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
       sym_binary_expression,
       sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
       sym_parenthesized_expression,
-  [2430] = 10,
-    ACTIONS(155), 1,
+  [3283] = 14,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(47), 1,
       sym_identifier,
-    ACTIONS(161), 1,
+    ACTIONS(49), 1,
       anon_sym_LBRACK,
-    ACTIONS(163), 1,
-      anon_sym_STAR,
-    ACTIONS(165), 1,
-      anon_sym_map,
-    ACTIONS(167), 1,
+    ACTIONS(51), 1,
       anon_sym_func,
-    STATE(60), 1,
+    STATE(53), 1,
       sym_comment,
-    STATE(157), 1,
-      sym_qualified_type,
-    STATE(255), 1,
-      sym_type_expression,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(64), 1,
+      sym__expression,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(195), 5,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
       sym_slice_type,
-      sym_pointer_type,
       sym_map_type,
-      sym_func_type,
-      sym_generic_type,
-  [2466] = 10,
-    ACTIONS(155), 1,
-      sym_identifier,
-    ACTIONS(161), 1,
-      anon_sym_LBRACK,
-    ACTIONS(163), 1,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
       anon_sym_STAR,
-    ACTIONS(165), 1,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [3346] = 14,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
       anon_sym_map,
-    ACTIONS(167), 1,
+    ACTIONS(47), 1,
+      sym_identifier,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
       anon_sym_func,
-    STATE(61), 1,
+    STATE(54), 1,
       sym_comment,
-    STATE(157), 1,
-      sym_qualified_type,
-    STATE(247), 1,
-      sym_type_expression,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(80), 1,
+      sym__expression,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(195), 5,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
       sym_slice_type,
-      sym_pointer_type,
       sym_map_type,
-      sym_func_type,
-      sym_generic_type,
-  [2502] = 10,
-    ACTIONS(175), 1,
-      sym_identifier,
-    ACTIONS(177), 1,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [3409] = 14,
+    ACTIONS(21), 1,
       anon_sym_LPAREN,
-    ACTIONS(246), 1,
-      anon_sym_RPAREN,
-    STATE(27), 1,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(47), 1,
+      sym_identifier,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
+      anon_sym_func,
+    STATE(55), 1,
+      sym_comment,
+    STATE(63), 1,
       sym_selector_expression,
-    STATE(36), 1,
+    STATE(81), 1,
       sym__expression,
-    STATE(62), 1,
-      sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(179), 2,
-      sym_number,
-      sym_string,
-    ACTIONS(181), 2,
+    ACTIONS(45), 2,
       sym_true,
       sym_false,
-    STATE(34), 3,
+    STATE(469), 2,
+      sym_slice_type,
+      sym_map_type,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
       sym_binary_expression,
       sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
       sym_parenthesized_expression,
-  [2538] = 10,
-    ACTIONS(155), 1,
-      sym_identifier,
-    ACTIONS(212), 1,
-      anon_sym_LBRACK,
-    ACTIONS(214), 1,
-      anon_sym_STAR,
-    ACTIONS(216), 1,
+  [3472] = 14,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
       anon_sym_map,
-    ACTIONS(218), 1,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
       anon_sym_func,
-    STATE(63), 1,
+    ACTIONS(136), 1,
+      sym_identifier,
+    STATE(56), 1,
       sym_comment,
-    STATE(157), 1,
-      sym_qualified_type,
-    STATE(176), 1,
-      sym_type_expression,
+    STATE(64), 1,
+      sym__expression,
+    STATE(136), 1,
+      sym_selector_expression,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(195), 5,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
       sym_slice_type,
-      sym_pointer_type,
       sym_map_type,
-      sym_func_type,
-      sym_generic_type,
-  [2574] = 10,
-    ACTIONS(155), 1,
-      sym_identifier,
-    ACTIONS(212), 1,
-      anon_sym_LBRACK,
-    ACTIONS(214), 1,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(138), 7,
       anon_sym_STAR,
-    ACTIONS(216), 1,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [3535] = 14,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
       anon_sym_map,
-    ACTIONS(218), 1,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
       anon_sym_func,
-    STATE(64), 1,
+    ACTIONS(136), 1,
+      sym_identifier,
+    STATE(57), 1,
       sym_comment,
-    STATE(157), 1,
-      sym_qualified_type,
-    STATE(199), 1,
-      sym_type_expression,
+    STATE(129), 1,
+      sym__expression,
+    STATE(136), 1,
+      sym_selector_expression,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(195), 5,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
       sym_slice_type,
-      sym_pointer_type,
       sym_map_type,
-      sym_func_type,
-      sym_generic_type,
-  [2610] = 10,
-    ACTIONS(155), 1,
-      sym_identifier,
-    ACTIONS(212), 1,
-      anon_sym_LBRACK,
-    ACTIONS(214), 1,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(138), 7,
       anon_sym_STAR,
-    ACTIONS(216), 1,
-      anon_sym_map,
-    ACTIONS(218), 1,
-      anon_sym_func,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [3598] = 14,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(47), 1,
+      sym_identifier,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
+      anon_sym_func,
+    STATE(58), 1,
+      sym_comment,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(82), 1,
+      sym__expression,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
+      sym_slice_type,
+      sym_map_type,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [3661] = 14,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(47), 1,
+      sym_identifier,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
+      anon_sym_func,
+    STATE(59), 1,
+      sym_comment,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(138), 1,
+      sym__expression,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
+      sym_slice_type,
+      sym_map_type,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [3724] = 14,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(47), 1,
+      sym_identifier,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
+      anon_sym_func,
+    STATE(60), 1,
+      sym_comment,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(126), 1,
+      sym__expression,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
+      sym_slice_type,
+      sym_map_type,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [3787] = 14,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(47), 1,
+      sym_identifier,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
+      anon_sym_func,
+    STATE(61), 1,
+      sym_comment,
+    STATE(63), 1,
+      sym_selector_expression,
+    STATE(120), 1,
+      sym__expression,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
+      sym_slice_type,
+      sym_map_type,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(41), 7,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [3850] = 14,
+    ACTIONS(21), 1,
+      anon_sym_LPAREN,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(49), 1,
+      anon_sym_LBRACK,
+    ACTIONS(51), 1,
+      anon_sym_func,
+    ACTIONS(136), 1,
+      sym_identifier,
+    STATE(62), 1,
+      sym_comment,
+    STATE(109), 1,
+      sym__expression,
+    STATE(136), 1,
+      sym_selector_expression,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(45), 2,
+      sym_true,
+      sym_false,
+    STATE(469), 2,
+      sym_slice_type,
+      sym_map_type,
+    ACTIONS(43), 4,
+      sym_string,
+      sym_raw_string,
+      sym_rune,
+      sym_number,
+    ACTIONS(138), 7,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      anon_sym_CARET,
+      anon_sym_AMP,
+    STATE(72), 9,
+      sym_unary_expression,
+      sym_binary_expression,
+      sym_call_expression,
+      sym_index_expression,
+      sym_slice_expression,
+      sym_type_assertion_expression,
+      sym_composite_literal,
+      sym_func_literal,
+      sym_parenthesized_expression,
+  [3913] = 6,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    STATE(63), 1,
+      sym_comment,
+    STATE(90), 1,
+      sym_literal_value,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(83), 6,
+      anon_sym_PIPE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(81), 22,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_STAR,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [3959] = 8,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(164), 1,
+      anon_sym_DOT,
+    STATE(64), 1,
+      sym_comment,
+    STATE(94), 1,
+      sym_argument_list,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(162), 5,
+      anon_sym_PIPE,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(158), 21,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [4009] = 8,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(164), 1,
+      anon_sym_DOT,
     STATE(65), 1,
       sym_comment,
-    STATE(157), 1,
-      sym_qualified_type,
-    STATE(190), 1,
-      sym_type_expression,
+    STATE(94), 1,
+      sym_argument_list,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(168), 5,
+      anon_sym_PIPE,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(166), 21,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [4059] = 4,
+    STATE(66), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(172), 6,
+      anon_sym_PIPE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(170), 23,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [4100] = 4,
+    STATE(67), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(176), 6,
+      anon_sym_PIPE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(174), 23,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [4141] = 4,
+    STATE(68), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(180), 6,
+      anon_sym_PIPE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(178), 23,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [4182] = 4,
+    STATE(69), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(100), 6,
+      anon_sym_PIPE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(102), 23,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [4223] = 4,
+    STATE(70), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(112), 6,
+      anon_sym_PIPE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(114), 23,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [4264] = 4,
+    STATE(71), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(184), 6,
+      anon_sym_PIPE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(182), 23,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [4305] = 4,
+    STATE(72), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(83), 6,
+      anon_sym_PIPE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(81), 23,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [4346] = 10,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(164), 1,
+      anon_sym_DOT,
+    STATE(73), 1,
+      sym_comment,
+    STATE(94), 1,
+      sym_argument_list,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(188), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(168), 3,
+      anon_sym_PIPE,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(186), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+    ACTIONS(166), 15,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [4399] = 4,
+    STATE(74), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(116), 6,
+      anon_sym_PIPE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(118), 23,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [4440] = 4,
+    STATE(75), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(192), 6,
+      anon_sym_PIPE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(190), 23,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [4481] = 4,
+    STATE(76), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(73), 6,
+      anon_sym_PIPE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(75), 23,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [4522] = 4,
+    STATE(77), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(196), 6,
+      anon_sym_PIPE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(194), 23,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [4563] = 4,
+    STATE(78), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(200), 6,
+      anon_sym_PIPE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(198), 23,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [4604] = 4,
+    STATE(79), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(204), 6,
+      anon_sym_PIPE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(202), 23,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [4645] = 12,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(164), 1,
+      anon_sym_DOT,
+    ACTIONS(206), 1,
+      anon_sym_PIPE,
+    STATE(80), 1,
+      sym_comment,
+    STATE(94), 1,
+      sym_argument_list,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(168), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(188), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(208), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(186), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+    ACTIONS(166), 12,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [4702] = 13,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(164), 1,
+      anon_sym_DOT,
+    ACTIONS(206), 1,
+      anon_sym_PIPE,
+    STATE(81), 1,
+      sym_comment,
+    STATE(94), 1,
+      sym_argument_list,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(188), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(210), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(208), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(212), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(186), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+    ACTIONS(166), 8,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [4761] = 14,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(164), 1,
+      anon_sym_DOT,
+    ACTIONS(206), 1,
+      anon_sym_PIPE,
+    ACTIONS(214), 1,
+      anon_sym_AMP_AMP,
+    STATE(82), 1,
+      sym_comment,
+    STATE(94), 1,
+      sym_argument_list,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(188), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(210), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(208), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(212), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(186), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+    ACTIONS(166), 7,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_PIPE_PIPE,
+  [4822] = 4,
+    STATE(83), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(218), 6,
+      anon_sym_PIPE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(216), 23,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [4863] = 4,
+    STATE(84), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(222), 6,
+      anon_sym_PIPE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(220), 23,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [4904] = 4,
+    STATE(85), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(226), 6,
+      anon_sym_PIPE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(224), 23,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [4945] = 4,
+    STATE(86), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(230), 6,
+      anon_sym_PIPE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(228), 23,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [4986] = 4,
+    STATE(87), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(104), 6,
+      anon_sym_PIPE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(106), 23,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [5027] = 4,
+    STATE(88), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(234), 6,
+      anon_sym_PIPE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(232), 23,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [5068] = 4,
+    STATE(89), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(238), 6,
+      anon_sym_PIPE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(236), 23,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [5109] = 4,
+    STATE(90), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(242), 6,
+      anon_sym_PIPE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(240), 23,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [5150] = 4,
+    STATE(91), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(246), 6,
+      anon_sym_PIPE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(244), 23,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [5191] = 4,
+    STATE(92), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(250), 6,
+      anon_sym_PIPE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(248), 23,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [5232] = 4,
+    STATE(93), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(108), 6,
+      anon_sym_PIPE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(110), 23,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [5273] = 4,
+    STATE(94), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(96), 6,
+      anon_sym_PIPE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(98), 23,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [5314] = 18,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(164), 1,
+      anon_sym_DOT,
+    ACTIONS(206), 1,
+      anon_sym_PIPE,
+    ACTIONS(214), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(252), 1,
+      anon_sym_RPAREN,
+    ACTIONS(254), 1,
+      anon_sym_COMMA,
+    ACTIONS(256), 1,
+      anon_sym_DOT_DOT_DOT,
+    ACTIONS(258), 1,
+      anon_sym_PIPE_PIPE,
+    STATE(94), 1,
+      sym_argument_list,
+    STATE(95), 1,
+      sym_comment,
+    STATE(446), 1,
+      aux_sym_argument_list_repeat1,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(188), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(210), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(208), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(212), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(186), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+  [5381] = 18,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(164), 1,
+      anon_sym_DOT,
+    ACTIONS(206), 1,
+      anon_sym_PIPE,
+    ACTIONS(214), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(256), 1,
+      anon_sym_DOT_DOT_DOT,
+    ACTIONS(258), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(260), 1,
+      anon_sym_RPAREN,
+    ACTIONS(262), 1,
+      anon_sym_COMMA,
+    STATE(94), 1,
+      sym_argument_list,
+    STATE(96), 1,
+      sym_comment,
+    STATE(454), 1,
+      aux_sym_argument_list_repeat1,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(188), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(210), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(208), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(212), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(186), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+  [5448] = 18,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(164), 1,
+      anon_sym_DOT,
+    ACTIONS(206), 1,
+      anon_sym_PIPE,
+    ACTIONS(214), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(256), 1,
+      anon_sym_DOT_DOT_DOT,
+    ACTIONS(258), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(264), 1,
+      anon_sym_RPAREN,
+    ACTIONS(266), 1,
+      anon_sym_COMMA,
+    STATE(94), 1,
+      sym_argument_list,
+    STATE(97), 1,
+      sym_comment,
+    STATE(442), 1,
+      aux_sym_argument_list_repeat1,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(188), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(210), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(208), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(212), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(186), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+  [5515] = 18,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(164), 1,
+      anon_sym_DOT,
+    ACTIONS(206), 1,
+      anon_sym_PIPE,
+    ACTIONS(214), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(256), 1,
+      anon_sym_DOT_DOT_DOT,
+    ACTIONS(258), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(268), 1,
+      anon_sym_RPAREN,
+    ACTIONS(270), 1,
+      anon_sym_COMMA,
+    STATE(94), 1,
+      sym_argument_list,
+    STATE(98), 1,
+      sym_comment,
+    STATE(451), 1,
+      aux_sym_argument_list_repeat1,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(188), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(210), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(208), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(212), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(186), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+  [5582] = 17,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(274), 1,
+      anon_sym_LPAREN,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    STATE(99), 1,
+      sym_comment,
+    STATE(224), 1,
+      sym_type_expression,
+    STATE(225), 1,
+      sym_return_type,
+    STATE(349), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(276), 5,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_PIPE,
+      anon_sym_LBRACE,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [5647] = 19,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(274), 1,
+      anon_sym_LPAREN,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(284), 1,
+      anon_sym_LBRACE,
+    STATE(67), 1,
+      sym_function_body,
+    STATE(100), 1,
+      sym_comment,
+    STATE(224), 1,
+      sym_type_expression,
+    STATE(349), 1,
+      sym_qualified_type,
+    STATE(413), 1,
+      sym_return_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(276), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [5715] = 17,
+    ACTIONS(286), 1,
+      sym_identifier,
+    ACTIONS(289), 1,
+      anon_sym_LBRACK,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(295), 1,
+      anon_sym_STAR,
+    ACTIONS(298), 1,
+      anon_sym_map,
+    ACTIONS(301), 1,
+      anon_sym_func,
+    ACTIONS(304), 1,
+      anon_sym_chan,
+    ACTIONS(307), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(310), 1,
+      anon_sym_struct,
+    ACTIONS(313), 1,
+      anon_sym_interface,
+    ACTIONS(316), 1,
+      anon_sym_RBRACE,
+    STATE(279), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(101), 2,
+      sym_comment,
+      aux_sym_interface_type_repeat1,
+    STATE(245), 2,
+      sym_underlying_type,
+      sym_type_expression,
+    STATE(308), 2,
+      sym_type_constraint,
+      sym_method_spec,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [5779] = 18,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(318), 1,
+      sym_identifier,
+    ACTIONS(320), 1,
+      anon_sym_LBRACK,
+    ACTIONS(322), 1,
+      anon_sym_TILDE,
+    ACTIONS(324), 1,
+      anon_sym_STAR,
+    ACTIONS(326), 1,
+      anon_sym_map,
+    ACTIONS(328), 1,
+      anon_sym_func,
+    ACTIONS(330), 1,
+      anon_sym_chan,
+    ACTIONS(332), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(334), 1,
+      anon_sym_struct,
+    ACTIONS(336), 1,
+      anon_sym_RBRACE,
+    STATE(102), 1,
+      sym_comment,
+    STATE(104), 1,
+      aux_sym_interface_type_repeat1,
+    STATE(279), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(245), 2,
+      sym_underlying_type,
+      sym_type_expression,
+    STATE(308), 2,
+      sym_type_constraint,
+      sym_method_spec,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [5845] = 17,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(206), 1,
+      anon_sym_PIPE,
+    ACTIONS(214), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(258), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(338), 1,
+      anon_sym_COMMA,
+    ACTIONS(340), 1,
+      anon_sym_DOT,
+    ACTIONS(342), 1,
+      anon_sym_COLON,
+    STATE(94), 1,
+      sym_argument_list,
+    STATE(103), 1,
+      sym_comment,
+    STATE(423), 1,
+      aux_sym_case_clause_repeat1,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(188), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(210), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(208), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(212), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(186), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+  [5909] = 18,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(318), 1,
+      sym_identifier,
+    ACTIONS(320), 1,
+      anon_sym_LBRACK,
+    ACTIONS(322), 1,
+      anon_sym_TILDE,
+    ACTIONS(324), 1,
+      anon_sym_STAR,
+    ACTIONS(326), 1,
+      anon_sym_map,
+    ACTIONS(328), 1,
+      anon_sym_func,
+    ACTIONS(330), 1,
+      anon_sym_chan,
+    ACTIONS(332), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(334), 1,
+      anon_sym_struct,
+    ACTIONS(344), 1,
+      anon_sym_RBRACE,
+    STATE(101), 1,
+      aux_sym_interface_type_repeat1,
+    STATE(104), 1,
+      sym_comment,
+    STATE(279), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(245), 2,
+      sym_underlying_type,
+      sym_type_expression,
+    STATE(308), 2,
+      sym_type_constraint,
+      sym_method_spec,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [5975] = 10,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    ACTIONS(346), 1,
+      anon_sym_LBRACK,
+    ACTIONS(349), 1,
+      anon_sym_COMMA,
+    ACTIONS(351), 1,
+      anon_sym_RBRACK,
+    ACTIONS(354), 1,
+      anon_sym_DOT,
+    STATE(90), 1,
+      sym_literal_value,
+    STATE(105), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(83), 5,
+      anon_sym_PIPE,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(81), 15,
+      anon_sym_LPAREN,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [6025] = 16,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(164), 1,
+      anon_sym_DOT,
+    ACTIONS(206), 1,
+      anon_sym_PIPE,
+    ACTIONS(214), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(256), 1,
+      anon_sym_DOT_DOT_DOT,
+    ACTIONS(258), 1,
+      anon_sym_PIPE_PIPE,
+    STATE(94), 1,
+      sym_argument_list,
+    STATE(106), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(188), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(210), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(357), 2,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+    ACTIONS(208), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(212), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(186), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+  [6087] = 15,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(206), 1,
+      anon_sym_PIPE,
+    ACTIONS(214), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(258), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(340), 1,
+      anon_sym_DOT,
+    STATE(94), 1,
+      sym_argument_list,
+    STATE(107), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(188), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(210), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(208), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(359), 3,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+    ACTIONS(212), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(186), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+  [6147] = 18,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(361), 1,
+      anon_sym_COMMA,
+    ACTIONS(363), 1,
+      anon_sym_TILDE,
+    STATE(108), 1,
+      sym_comment,
+    STATE(117), 1,
+      aux_sym_type_parameter_declaration_repeat1,
+    STATE(349), 1,
+      sym_qualified_type,
+    STATE(487), 1,
+      sym_type_constraint,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(419), 2,
+      sym_underlying_type,
+      sym_type_expression,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [6212] = 16,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(340), 1,
+      anon_sym_DOT,
+    ACTIONS(365), 1,
+      anon_sym_PIPE,
+    ACTIONS(369), 1,
+      anon_sym_LBRACE,
+    ACTIONS(379), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(381), 1,
+      anon_sym_PIPE_PIPE,
+    STATE(94), 1,
+      sym_argument_list,
+    STATE(109), 1,
+      sym_comment,
+    STATE(299), 1,
+      sym_block,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(371), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(373), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(375), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(377), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(367), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+  [6273] = 16,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(340), 1,
+      anon_sym_DOT,
+    ACTIONS(365), 1,
+      anon_sym_PIPE,
+    ACTIONS(379), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(381), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(383), 1,
+      anon_sym_LBRACE,
+    STATE(94), 1,
+      sym_argument_list,
+    STATE(110), 1,
+      sym_comment,
+    STATE(247), 1,
+      sym_block,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(371), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(373), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(375), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(377), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(367), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+  [6334] = 16,
+    ACTIONS(385), 1,
+      sym_identifier,
+    ACTIONS(388), 1,
+      anon_sym_LBRACE,
+    ACTIONS(391), 1,
+      anon_sym_RBRACE,
+    ACTIONS(393), 1,
+      anon_sym_LT,
+    ACTIONS(396), 1,
+      anon_sym_for,
+    ACTIONS(399), 1,
+      anon_sym_if,
+    ACTIONS(402), 1,
+      anon_sym_switch,
+    ACTIONS(407), 1,
+      anon_sym_var,
+    ACTIONS(410), 1,
+      anon_sym_AT,
+    ACTIONS(413), 1,
+      sym_go_statement,
+    STATE(266), 1,
+      sym__child,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(405), 2,
+      anon_sym_case,
+      anon_sym_default,
+    STATE(111), 2,
+      sym_comment,
+      aux_sym_component_body_repeat1,
+    STATE(269), 2,
+      sym_self_closing_element,
+      sym_element_with_children,
+    STATE(267), 9,
+      sym_element,
+      sym_for_statement,
+      sym_if_statement,
+      sym_switch_statement,
+      sym_let_binding,
+      sym_state_declaration,
+      sym_component_call,
+      sym_children_slot,
+      sym_go_expression,
+  [6395] = 19,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(349), 1,
+      anon_sym_RPAREN,
+    ACTIONS(416), 1,
+      anon_sym_LBRACK,
+    ACTIONS(418), 1,
+      anon_sym_COMMA,
+    ACTIONS(421), 1,
+      anon_sym_DOT_DOT_DOT,
+    ACTIONS(423), 1,
+      anon_sym_DOT,
+    STATE(112), 1,
+      sym_comment,
+    STATE(160), 1,
+      aux_sym_type_parameter_declaration_repeat1,
+    STATE(349), 1,
+      sym_qualified_type,
+    STATE(474), 1,
+      sym_type_expression,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [6462] = 16,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(206), 1,
+      anon_sym_PIPE,
+    ACTIONS(214), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(258), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(340), 1,
+      anon_sym_DOT,
+    ACTIONS(425), 1,
+      anon_sym_RBRACK,
+    ACTIONS(427), 1,
+      anon_sym_COLON,
+    STATE(94), 1,
+      sym_argument_list,
+    STATE(113), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(188), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(210), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(208), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(212), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(186), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+  [6523] = 16,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(206), 1,
+      anon_sym_PIPE,
+    ACTIONS(214), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(258), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(340), 1,
+      anon_sym_DOT,
+    ACTIONS(429), 1,
+      anon_sym_RBRACK,
+    ACTIONS(431), 1,
+      anon_sym_COLON,
+    STATE(94), 1,
+      sym_argument_list,
+    STATE(114), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(188), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(210), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(208), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(212), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(186), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+  [6584] = 17,
+    ACTIONS(55), 1,
+      anon_sym_AT,
+    ACTIONS(433), 1,
+      sym_identifier,
+    ACTIONS(435), 1,
+      anon_sym_LBRACE,
+    ACTIONS(437), 1,
+      anon_sym_RBRACE,
+    ACTIONS(439), 1,
+      anon_sym_LT,
+    ACTIONS(441), 1,
+      anon_sym_for,
+    ACTIONS(443), 1,
+      anon_sym_if,
+    ACTIONS(445), 1,
+      anon_sym_switch,
+    ACTIONS(449), 1,
+      anon_sym_var,
+    ACTIONS(451), 1,
+      sym_go_statement,
+    STATE(115), 1,
+      sym_comment,
+    STATE(119), 1,
+      aux_sym_component_body_repeat1,
+    STATE(266), 1,
+      sym__child,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(447), 2,
+      anon_sym_case,
+      anon_sym_default,
+    STATE(269), 2,
+      sym_self_closing_element,
+      sym_element_with_children,
+    STATE(267), 9,
+      sym_element,
+      sym_for_statement,
+      sym_if_statement,
+      sym_switch_statement,
+      sym_let_binding,
+      sym_state_declaration,
+      sym_component_call,
+      sym_children_slot,
+      sym_go_expression,
+  [6647] = 16,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(206), 1,
+      anon_sym_PIPE,
+    ACTIONS(214), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(258), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(340), 1,
+      anon_sym_DOT,
+    ACTIONS(453), 1,
+      anon_sym_RBRACK,
+    ACTIONS(455), 1,
+      anon_sym_COLON,
+    STATE(94), 1,
+      sym_argument_list,
+    STATE(116), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(188), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(210), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(208), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(212), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(186), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+  [6708] = 18,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(361), 1,
+      anon_sym_COMMA,
+    ACTIONS(363), 1,
+      anon_sym_TILDE,
+    STATE(117), 1,
+      sym_comment,
+    STATE(265), 1,
+      aux_sym_type_parameter_declaration_repeat1,
+    STATE(349), 1,
+      sym_qualified_type,
+    STATE(464), 1,
+      sym_type_constraint,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(419), 2,
+      sym_underlying_type,
+      sym_type_expression,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [6773] = 17,
+    ACTIONS(55), 1,
+      anon_sym_AT,
+    ACTIONS(433), 1,
+      sym_identifier,
+    ACTIONS(435), 1,
+      anon_sym_LBRACE,
+    ACTIONS(439), 1,
+      anon_sym_LT,
+    ACTIONS(441), 1,
+      anon_sym_for,
+    ACTIONS(443), 1,
+      anon_sym_if,
+    ACTIONS(445), 1,
+      anon_sym_switch,
+    ACTIONS(449), 1,
+      anon_sym_var,
+    ACTIONS(451), 1,
+      sym_go_statement,
+    ACTIONS(457), 1,
+      anon_sym_RBRACE,
+    STATE(118), 1,
+      sym_comment,
+    STATE(121), 1,
+      aux_sym_component_body_repeat1,
+    STATE(266), 1,
+      sym__child,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(459), 2,
+      anon_sym_case,
+      anon_sym_default,
+    STATE(269), 2,
+      sym_self_closing_element,
+      sym_element_with_children,
+    STATE(267), 9,
+      sym_element,
+      sym_for_statement,
+      sym_if_statement,
+      sym_switch_statement,
+      sym_let_binding,
+      sym_state_declaration,
+      sym_component_call,
+      sym_children_slot,
+      sym_go_expression,
+  [6836] = 17,
+    ACTIONS(55), 1,
+      anon_sym_AT,
+    ACTIONS(433), 1,
+      sym_identifier,
+    ACTIONS(435), 1,
+      anon_sym_LBRACE,
+    ACTIONS(439), 1,
+      anon_sym_LT,
+    ACTIONS(441), 1,
+      anon_sym_for,
+    ACTIONS(443), 1,
+      anon_sym_if,
+    ACTIONS(445), 1,
+      anon_sym_switch,
+    ACTIONS(449), 1,
+      anon_sym_var,
+    ACTIONS(451), 1,
+      sym_go_statement,
+    ACTIONS(461), 1,
+      anon_sym_RBRACE,
+    STATE(111), 1,
+      aux_sym_component_body_repeat1,
+    STATE(119), 1,
+      sym_comment,
+    STATE(266), 1,
+      sym__child,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(463), 2,
+      anon_sym_case,
+      anon_sym_default,
+    STATE(269), 2,
+      sym_self_closing_element,
+      sym_element_with_children,
+    STATE(267), 9,
+      sym_element,
+      sym_for_statement,
+      sym_if_statement,
+      sym_switch_statement,
+      sym_let_binding,
+      sym_state_declaration,
+      sym_component_call,
+      sym_children_slot,
+      sym_go_expression,
+  [6899] = 15,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(206), 1,
+      anon_sym_PIPE,
+    ACTIONS(214), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(258), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(340), 1,
+      anon_sym_DOT,
+    STATE(94), 1,
+      sym_argument_list,
+    STATE(120), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(188), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(210), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(465), 2,
+      anon_sym_COMMA,
+      anon_sym_COLON,
+    ACTIONS(208), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(212), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(186), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+  [6958] = 17,
+    ACTIONS(55), 1,
+      anon_sym_AT,
+    ACTIONS(433), 1,
+      sym_identifier,
+    ACTIONS(435), 1,
+      anon_sym_LBRACE,
+    ACTIONS(439), 1,
+      anon_sym_LT,
+    ACTIONS(441), 1,
+      anon_sym_for,
+    ACTIONS(443), 1,
+      anon_sym_if,
+    ACTIONS(445), 1,
+      anon_sym_switch,
+    ACTIONS(449), 1,
+      anon_sym_var,
+    ACTIONS(451), 1,
+      sym_go_statement,
+    ACTIONS(467), 1,
+      anon_sym_RBRACE,
+    STATE(111), 1,
+      aux_sym_component_body_repeat1,
+    STATE(121), 1,
+      sym_comment,
+    STATE(266), 1,
+      sym__child,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(469), 2,
+      anon_sym_case,
+      anon_sym_default,
+    STATE(269), 2,
+      sym_self_closing_element,
+      sym_element_with_children,
+    STATE(267), 9,
+      sym_element,
+      sym_for_statement,
+      sym_if_statement,
+      sym_switch_statement,
+      sym_let_binding,
+      sym_state_declaration,
+      sym_component_call,
+      sym_children_slot,
+      sym_go_expression,
+  [7021] = 17,
+    ACTIONS(55), 1,
+      anon_sym_AT,
+    ACTIONS(433), 1,
+      sym_identifier,
+    ACTIONS(435), 1,
+      anon_sym_LBRACE,
+    ACTIONS(439), 1,
+      anon_sym_LT,
+    ACTIONS(441), 1,
+      anon_sym_for,
+    ACTIONS(443), 1,
+      anon_sym_if,
+    ACTIONS(445), 1,
+      anon_sym_switch,
+    ACTIONS(449), 1,
+      anon_sym_var,
+    ACTIONS(451), 1,
+      sym_go_statement,
+    ACTIONS(471), 1,
+      anon_sym_RBRACE,
+    STATE(122), 1,
+      sym_comment,
+    STATE(124), 1,
+      aux_sym_component_body_repeat1,
+    STATE(266), 1,
+      sym__child,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(473), 2,
+      anon_sym_case,
+      anon_sym_default,
+    STATE(269), 2,
+      sym_self_closing_element,
+      sym_element_with_children,
+    STATE(267), 9,
+      sym_element,
+      sym_for_statement,
+      sym_if_statement,
+      sym_switch_statement,
+      sym_let_binding,
+      sym_state_declaration,
+      sym_component_call,
+      sym_children_slot,
+      sym_go_expression,
+  [7084] = 15,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(206), 1,
+      anon_sym_PIPE,
+    ACTIONS(214), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(258), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(340), 1,
+      anon_sym_DOT,
+    STATE(94), 1,
+      sym_argument_list,
+    STATE(123), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(188), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(210), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(357), 2,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+    ACTIONS(208), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(212), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(186), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+  [7143] = 17,
+    ACTIONS(55), 1,
+      anon_sym_AT,
+    ACTIONS(433), 1,
+      sym_identifier,
+    ACTIONS(435), 1,
+      anon_sym_LBRACE,
+    ACTIONS(439), 1,
+      anon_sym_LT,
+    ACTIONS(441), 1,
+      anon_sym_for,
+    ACTIONS(443), 1,
+      anon_sym_if,
+    ACTIONS(445), 1,
+      anon_sym_switch,
+    ACTIONS(449), 1,
+      anon_sym_var,
+    ACTIONS(451), 1,
+      sym_go_statement,
+    ACTIONS(475), 1,
+      anon_sym_RBRACE,
+    STATE(111), 1,
+      aux_sym_component_body_repeat1,
+    STATE(124), 1,
+      sym_comment,
+    STATE(266), 1,
+      sym__child,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(477), 2,
+      anon_sym_case,
+      anon_sym_default,
+    STATE(269), 2,
+      sym_self_closing_element,
+      sym_element_with_children,
+    STATE(267), 9,
+      sym_element,
+      sym_for_statement,
+      sym_if_statement,
+      sym_switch_statement,
+      sym_let_binding,
+      sym_state_declaration,
+      sym_component_call,
+      sym_children_slot,
+      sym_go_expression,
+  [7206] = 17,
+    ACTIONS(274), 1,
+      anon_sym_LPAREN,
+    ACTIONS(479), 1,
+      sym_identifier,
+    ACTIONS(482), 1,
+      anon_sym_LBRACK,
+    ACTIONS(485), 1,
+      anon_sym_STAR,
+    ACTIONS(488), 1,
+      anon_sym_map,
+    ACTIONS(491), 1,
+      anon_sym_func,
+    ACTIONS(494), 1,
+      anon_sym_chan,
+    ACTIONS(497), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(500), 1,
+      anon_sym_struct,
+    ACTIONS(503), 1,
+      anon_sym_interface,
+    STATE(125), 1,
+      sym_comment,
+    STATE(224), 1,
+      sym_type_expression,
+    STATE(225), 1,
+      sym_return_type,
+    STATE(279), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(276), 3,
+      anon_sym_PIPE,
+      anon_sym_TILDE,
+      anon_sym_RBRACE,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [7269] = 15,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(206), 1,
+      anon_sym_PIPE,
+    ACTIONS(214), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(258), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(340), 1,
+      anon_sym_DOT,
+    ACTIONS(506), 1,
+      anon_sym_RBRACK,
+    STATE(94), 1,
+      sym_argument_list,
+    STATE(126), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(188), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(210), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(208), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(212), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(186), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+  [7327] = 17,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(508), 1,
+      sym_identifier,
+    ACTIONS(510), 1,
+      anon_sym_RPAREN,
+    ACTIONS(512), 1,
+      anon_sym_DOT_DOT_DOT,
+    STATE(127), 1,
+      sym_comment,
+    STATE(349), 1,
+      sym_qualified_type,
+    STATE(463), 1,
+      sym_type_expression,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(501), 2,
+      sym_parameter,
+      sym_variadic_parameter,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [7389] = 15,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(340), 1,
+      anon_sym_DOT,
+    ACTIONS(365), 1,
+      anon_sym_PIPE,
+    ACTIONS(379), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(381), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(514), 1,
+      anon_sym_LBRACE,
+    STATE(94), 1,
+      sym_argument_list,
+    STATE(128), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(371), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(373), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(375), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(377), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(367), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+  [7447] = 15,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(340), 1,
+      anon_sym_DOT,
+    ACTIONS(365), 1,
+      anon_sym_PIPE,
+    ACTIONS(379), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(381), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(516), 1,
+      anon_sym_LBRACE,
+    STATE(94), 1,
+      sym_argument_list,
+    STATE(129), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(371), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(373), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(375), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(377), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(367), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+  [7505] = 18,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(274), 1,
+      anon_sym_LPAREN,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(284), 1,
+      anon_sym_LBRACE,
+    STATE(130), 1,
+      sym_comment,
+    STATE(224), 1,
+      sym_type_expression,
+    STATE(349), 1,
+      sym_qualified_type,
+    STATE(359), 1,
+      sym_function_body,
+    STATE(461), 1,
+      sym_return_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [7569] = 17,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(274), 1,
+      anon_sym_LPAREN,
+    ACTIONS(320), 1,
+      anon_sym_LBRACK,
+    ACTIONS(324), 1,
+      anon_sym_STAR,
+    ACTIONS(326), 1,
+      anon_sym_map,
+    ACTIONS(328), 1,
+      anon_sym_func,
+    ACTIONS(330), 1,
+      anon_sym_chan,
+    ACTIONS(332), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(334), 1,
+      anon_sym_struct,
+    ACTIONS(518), 1,
+      sym_identifier,
+    STATE(131), 1,
+      sym_comment,
+    STATE(224), 1,
+      sym_type_expression,
+    STATE(279), 1,
+      sym_qualified_type,
+    STATE(289), 1,
+      sym_return_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(520), 2,
+      anon_sym_TILDE,
+      anon_sym_RBRACE,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [7631] = 15,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(340), 1,
+      anon_sym_DOT,
+    ACTIONS(365), 1,
+      anon_sym_PIPE,
+    ACTIONS(379), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(381), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(522), 1,
+      anon_sym_LBRACE,
+    STATE(94), 1,
+      sym_argument_list,
+    STATE(132), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(371), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(373), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(375), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(377), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(367), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+  [7689] = 15,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(206), 1,
+      anon_sym_PIPE,
+    ACTIONS(214), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(258), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(340), 1,
+      anon_sym_DOT,
+    ACTIONS(524), 1,
+      anon_sym_RBRACK,
+    STATE(94), 1,
+      sym_argument_list,
+    STATE(133), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(188), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(210), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(208), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(212), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(186), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+  [7747] = 15,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(206), 1,
+      anon_sym_PIPE,
+    ACTIONS(214), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(258), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(340), 1,
+      anon_sym_DOT,
+    ACTIONS(526), 1,
+      anon_sym_RBRACK,
+    STATE(94), 1,
+      sym_argument_list,
+    STATE(134), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(188), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(210), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(208), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(212), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(186), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+  [7805] = 15,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(340), 1,
+      anon_sym_DOT,
+    ACTIONS(365), 1,
+      anon_sym_PIPE,
+    ACTIONS(379), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(381), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(528), 1,
+      anon_sym_LBRACE,
+    STATE(94), 1,
+      sym_argument_list,
+    STATE(135), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(371), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(373), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(375), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(377), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(367), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+  [7863] = 5,
+    STATE(90), 1,
+      sym_literal_value,
+    STATE(136), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(83), 5,
+      anon_sym_PIPE,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      anon_sym_GT,
+      anon_sym_AMP,
+    ACTIONS(81), 18,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_DOT,
+      anon_sym_STAR,
+      anon_sym_LBRACE,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [7901] = 18,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(274), 1,
+      anon_sym_LPAREN,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(284), 1,
+      anon_sym_LBRACE,
+    STATE(137), 1,
+      sym_comment,
+    STATE(224), 1,
+      sym_type_expression,
+    STATE(349), 1,
+      sym_qualified_type,
+    STATE(370), 1,
+      sym_function_body,
+    STATE(486), 1,
+      sym_return_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [7965] = 15,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(206), 1,
+      anon_sym_PIPE,
+    ACTIONS(214), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(258), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(340), 1,
+      anon_sym_DOT,
+    ACTIONS(530), 1,
+      anon_sym_RPAREN,
+    STATE(94), 1,
+      sym_argument_list,
+    STATE(138), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(188), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(210), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(208), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(212), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(186), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+  [8023] = 10,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(340), 1,
+      anon_sym_DOT,
+    STATE(94), 1,
+      sym_argument_list,
+    STATE(139), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(373), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(168), 3,
+      anon_sym_PIPE,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(367), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+    ACTIONS(166), 10,
+      anon_sym_LBRACE,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [8071] = 12,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(340), 1,
+      anon_sym_DOT,
+    ACTIONS(365), 1,
+      anon_sym_PIPE,
+    STATE(94), 1,
+      sym_argument_list,
+    STATE(140), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(168), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(373), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(375), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(367), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+    ACTIONS(166), 7,
+      anon_sym_LBRACE,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+  [8123] = 13,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(340), 1,
+      anon_sym_DOT,
+    ACTIONS(365), 1,
+      anon_sym_PIPE,
+    STATE(94), 1,
+      sym_argument_list,
+    STATE(141), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(371), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(373), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(166), 3,
+      anon_sym_LBRACE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(375), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(377), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(367), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+  [8177] = 14,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(340), 1,
+      anon_sym_DOT,
+    ACTIONS(365), 1,
+      anon_sym_PIPE,
+    ACTIONS(379), 1,
+      anon_sym_AMP_AMP,
+    STATE(94), 1,
+      sym_argument_list,
+    STATE(142), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(166), 2,
+      anon_sym_LBRACE,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(371), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(373), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(375), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(377), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(367), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+  [8233] = 17,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(508), 1,
+      sym_identifier,
+    ACTIONS(512), 1,
+      anon_sym_DOT_DOT_DOT,
+    ACTIONS(532), 1,
+      anon_sym_RPAREN,
+    STATE(143), 1,
+      sym_comment,
+    STATE(349), 1,
+      sym_qualified_type,
+    STATE(463), 1,
+      sym_type_expression,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(424), 2,
+      sym_parameter,
+      sym_variadic_parameter,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [8295] = 18,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(274), 1,
+      anon_sym_LPAREN,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(284), 1,
+      anon_sym_LBRACE,
+    STATE(67), 1,
+      sym_function_body,
+    STATE(144), 1,
+      sym_comment,
+    STATE(224), 1,
+      sym_type_expression,
+    STATE(349), 1,
+      sym_qualified_type,
+    STATE(479), 1,
+      sym_return_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [8359] = 18,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(274), 1,
+      anon_sym_LPAREN,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(284), 1,
+      anon_sym_LBRACE,
+    STATE(145), 1,
+      sym_comment,
+    STATE(224), 1,
+      sym_type_expression,
+    STATE(349), 1,
+      sym_qualified_type,
+    STATE(381), 1,
+      sym_function_body,
+    STATE(459), 1,
+      sym_return_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [8423] = 17,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(508), 1,
+      sym_identifier,
+    ACTIONS(512), 1,
+      anon_sym_DOT_DOT_DOT,
+    ACTIONS(534), 1,
+      anon_sym_RPAREN,
+    STATE(146), 1,
+      sym_comment,
+    STATE(349), 1,
+      sym_qualified_type,
+    STATE(463), 1,
+      sym_type_expression,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(501), 2,
+      sym_parameter,
+      sym_variadic_parameter,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [8485] = 15,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(206), 1,
+      anon_sym_PIPE,
+    ACTIONS(214), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(258), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(340), 1,
+      anon_sym_DOT,
+    ACTIONS(536), 1,
+      anon_sym_RBRACK,
+    STATE(94), 1,
+      sym_argument_list,
+    STATE(147), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(188), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(210), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(208), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(212), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(186), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+  [8543] = 15,
+    ACTIONS(156), 1,
+      anon_sym_LPAREN,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(206), 1,
+      anon_sym_PIPE,
+    ACTIONS(214), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(258), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(340), 1,
+      anon_sym_DOT,
+    ACTIONS(538), 1,
+      anon_sym_RBRACK,
+    STATE(94), 1,
+      sym_argument_list,
+    STATE(148), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(188), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(210), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(208), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(212), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(186), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+  [8601] = 16,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(508), 1,
+      sym_identifier,
+    ACTIONS(512), 1,
+      anon_sym_DOT_DOT_DOT,
+    STATE(149), 1,
+      sym_comment,
+    STATE(349), 1,
+      sym_qualified_type,
+    STATE(463), 1,
+      sym_type_expression,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(501), 2,
+      sym_parameter,
+      sym_variadic_parameter,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [8660] = 16,
+    ACTIONS(55), 1,
+      anon_sym_AT,
+    ACTIONS(433), 1,
+      sym_identifier,
+    ACTIONS(435), 1,
+      anon_sym_LBRACE,
+    ACTIONS(439), 1,
+      anon_sym_LT,
+    ACTIONS(441), 1,
+      anon_sym_for,
+    ACTIONS(443), 1,
+      anon_sym_if,
+    ACTIONS(445), 1,
+      anon_sym_switch,
+    ACTIONS(449), 1,
+      anon_sym_var,
+    ACTIONS(451), 1,
+      sym_go_statement,
+    ACTIONS(540), 1,
+      anon_sym_RBRACE,
+    STATE(150), 1,
+      sym_comment,
+    STATE(154), 1,
+      aux_sym_component_body_repeat1,
+    STATE(266), 1,
+      sym__child,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(269), 2,
+      sym_self_closing_element,
+      sym_element_with_children,
+    STATE(267), 9,
+      sym_element,
+      sym_for_statement,
+      sym_if_statement,
+      sym_switch_statement,
+      sym_let_binding,
+      sym_state_declaration,
+      sym_component_call,
+      sym_children_slot,
+      sym_go_expression,
+  [8719] = 16,
+    ACTIONS(55), 1,
+      anon_sym_AT,
+    ACTIONS(433), 1,
+      sym_identifier,
+    ACTIONS(435), 1,
+      anon_sym_LBRACE,
+    ACTIONS(439), 1,
+      anon_sym_LT,
+    ACTIONS(441), 1,
+      anon_sym_for,
+    ACTIONS(443), 1,
+      anon_sym_if,
+    ACTIONS(445), 1,
+      anon_sym_switch,
+    ACTIONS(449), 1,
+      anon_sym_var,
+    ACTIONS(451), 1,
+      sym_go_statement,
+    ACTIONS(542), 1,
+      anon_sym_RBRACE,
+    STATE(111), 1,
+      aux_sym_component_body_repeat1,
+    STATE(151), 1,
+      sym_comment,
+    STATE(266), 1,
+      sym__child,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(269), 2,
+      sym_self_closing_element,
+      sym_element_with_children,
+    STATE(267), 9,
+      sym_element,
+      sym_for_statement,
+      sym_if_statement,
+      sym_switch_statement,
+      sym_let_binding,
+      sym_state_declaration,
+      sym_component_call,
+      sym_children_slot,
+      sym_go_expression,
+  [8778] = 14,
+    ACTIONS(160), 1,
+      anon_sym_LBRACK,
+    ACTIONS(206), 1,
+      anon_sym_PIPE,
+    ACTIONS(214), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(258), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(340), 1,
+      anon_sym_DOT,
+    ACTIONS(544), 1,
+      anon_sym_LPAREN,
+    STATE(18), 1,
+      sym_argument_list,
+    STATE(152), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(188), 2,
+      anon_sym_SLASH,
+      anon_sym_AMP,
+    ACTIONS(210), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(208), 3,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_CARET,
+    ACTIONS(212), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(186), 5,
+      anon_sym_STAR,
+      anon_sym_PERCENT,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_AMP_CARET,
+  [8833] = 16,
+    ACTIONS(55), 1,
+      anon_sym_AT,
+    ACTIONS(433), 1,
+      sym_identifier,
+    ACTIONS(435), 1,
+      anon_sym_LBRACE,
+    ACTIONS(439), 1,
+      anon_sym_LT,
+    ACTIONS(441), 1,
+      anon_sym_for,
+    ACTIONS(443), 1,
+      anon_sym_if,
+    ACTIONS(445), 1,
+      anon_sym_switch,
+    ACTIONS(449), 1,
+      anon_sym_var,
+    ACTIONS(451), 1,
+      sym_go_statement,
+    ACTIONS(546), 1,
+      anon_sym_RBRACE,
+    STATE(151), 1,
+      aux_sym_component_body_repeat1,
+    STATE(153), 1,
+      sym_comment,
+    STATE(266), 1,
+      sym__child,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(269), 2,
+      sym_self_closing_element,
+      sym_element_with_children,
+    STATE(267), 9,
+      sym_element,
+      sym_for_statement,
+      sym_if_statement,
+      sym_switch_statement,
+      sym_let_binding,
+      sym_state_declaration,
+      sym_component_call,
+      sym_children_slot,
+      sym_go_expression,
+  [8892] = 16,
+    ACTIONS(55), 1,
+      anon_sym_AT,
+    ACTIONS(433), 1,
+      sym_identifier,
+    ACTIONS(435), 1,
+      anon_sym_LBRACE,
+    ACTIONS(439), 1,
+      anon_sym_LT,
+    ACTIONS(441), 1,
+      anon_sym_for,
+    ACTIONS(443), 1,
+      anon_sym_if,
+    ACTIONS(445), 1,
+      anon_sym_switch,
+    ACTIONS(449), 1,
+      anon_sym_var,
+    ACTIONS(451), 1,
+      sym_go_statement,
+    ACTIONS(548), 1,
+      anon_sym_RBRACE,
+    STATE(111), 1,
+      aux_sym_component_body_repeat1,
+    STATE(154), 1,
+      sym_comment,
+    STATE(266), 1,
+      sym__child,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(269), 2,
+      sym_self_closing_element,
+      sym_element_with_children,
+    STATE(267), 9,
+      sym_element,
+      sym_for_statement,
+      sym_if_statement,
+      sym_switch_statement,
+      sym_let_binding,
+      sym_state_declaration,
+      sym_component_call,
+      sym_children_slot,
+      sym_go_expression,
+  [8951] = 17,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(274), 1,
+      anon_sym_LPAREN,
+    ACTIONS(276), 1,
+      anon_sym_RBRACE,
+    ACTIONS(334), 1,
+      anon_sym_struct,
+    ACTIONS(550), 1,
+      sym_identifier,
+    ACTIONS(553), 1,
+      anon_sym_LBRACK,
+    ACTIONS(555), 1,
+      anon_sym_STAR,
+    ACTIONS(557), 1,
+      anon_sym_map,
+    ACTIONS(559), 1,
+      anon_sym_func,
+    ACTIONS(561), 1,
+      anon_sym_chan,
+    ACTIONS(563), 1,
+      anon_sym_LT_DASH,
+    STATE(155), 1,
+      sym_comment,
+    STATE(224), 1,
+      sym_type_expression,
+    STATE(225), 1,
+      sym_return_type,
+    STATE(349), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [9012] = 16,
+    ACTIONS(55), 1,
+      anon_sym_AT,
+    ACTIONS(433), 1,
+      sym_identifier,
+    ACTIONS(435), 1,
+      anon_sym_LBRACE,
+    ACTIONS(439), 1,
+      anon_sym_LT,
+    ACTIONS(441), 1,
+      anon_sym_for,
+    ACTIONS(443), 1,
+      anon_sym_if,
+    ACTIONS(445), 1,
+      anon_sym_switch,
+    ACTIONS(449), 1,
+      anon_sym_var,
+    ACTIONS(451), 1,
+      sym_go_statement,
+    ACTIONS(565), 1,
+      anon_sym_RBRACE,
+    STATE(156), 1,
+      sym_comment,
+    STATE(157), 1,
+      aux_sym_component_body_repeat1,
+    STATE(266), 1,
+      sym__child,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(269), 2,
+      sym_self_closing_element,
+      sym_element_with_children,
+    STATE(267), 9,
+      sym_element,
+      sym_for_statement,
+      sym_if_statement,
+      sym_switch_statement,
+      sym_let_binding,
+      sym_state_declaration,
+      sym_component_call,
+      sym_children_slot,
+      sym_go_expression,
+  [9071] = 16,
+    ACTIONS(55), 1,
+      anon_sym_AT,
+    ACTIONS(433), 1,
+      sym_identifier,
+    ACTIONS(435), 1,
+      anon_sym_LBRACE,
+    ACTIONS(439), 1,
+      anon_sym_LT,
+    ACTIONS(441), 1,
+      anon_sym_for,
+    ACTIONS(443), 1,
+      anon_sym_if,
+    ACTIONS(445), 1,
+      anon_sym_switch,
+    ACTIONS(449), 1,
+      anon_sym_var,
+    ACTIONS(451), 1,
+      sym_go_statement,
+    ACTIONS(567), 1,
+      anon_sym_RBRACE,
+    STATE(111), 1,
+      aux_sym_component_body_repeat1,
+    STATE(157), 1,
+      sym_comment,
+    STATE(266), 1,
+      sym__child,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(269), 2,
+      sym_self_closing_element,
+      sym_element_with_children,
+    STATE(267), 9,
+      sym_element,
+      sym_for_statement,
+      sym_if_statement,
+      sym_switch_statement,
+      sym_let_binding,
+      sym_state_declaration,
+      sym_component_call,
+      sym_children_slot,
+      sym_go_expression,
+  [9130] = 16,
+    ACTIONS(569), 1,
+      sym_identifier,
+    ACTIONS(571), 1,
+      anon_sym_LBRACE,
+    ACTIONS(573), 1,
+      anon_sym_LT,
+    ACTIONS(575), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(577), 1,
+      anon_sym_for,
+    ACTIONS(579), 1,
+      anon_sym_if,
+    ACTIONS(581), 1,
+      anon_sym_switch,
+    ACTIONS(583), 1,
+      anon_sym_var,
+    ACTIONS(585), 1,
+      anon_sym_AT,
+    ACTIONS(587), 1,
+      sym_text_content,
+    STATE(158), 1,
+      sym_comment,
+    STATE(161), 1,
+      aux_sym_element_with_children_repeat1,
+    STATE(314), 1,
+      sym__element_child,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(337), 2,
+      sym_self_closing_element,
+      sym_element_with_children,
+    STATE(330), 8,
+      sym_element,
+      sym_for_statement,
+      sym_if_statement,
+      sym_switch_statement,
+      sym_let_binding,
+      sym_component_call,
+      sym_children_slot,
+      sym_go_expression,
+  [9188] = 16,
+    ACTIONS(569), 1,
+      sym_identifier,
+    ACTIONS(571), 1,
+      anon_sym_LBRACE,
+    ACTIONS(573), 1,
+      anon_sym_LT,
+    ACTIONS(577), 1,
+      anon_sym_for,
+    ACTIONS(579), 1,
+      anon_sym_if,
+    ACTIONS(581), 1,
+      anon_sym_switch,
+    ACTIONS(583), 1,
+      anon_sym_var,
+    ACTIONS(585), 1,
+      anon_sym_AT,
+    ACTIONS(587), 1,
+      sym_text_content,
+    ACTIONS(589), 1,
+      anon_sym_LT_SLASH,
+    STATE(158), 1,
+      aux_sym_element_with_children_repeat1,
+    STATE(159), 1,
+      sym_comment,
+    STATE(314), 1,
+      sym__element_child,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(337), 2,
+      sym_self_closing_element,
+      sym_element_with_children,
+    STATE(330), 8,
+      sym_element,
+      sym_for_statement,
+      sym_if_statement,
+      sym_switch_statement,
+      sym_let_binding,
+      sym_component_call,
+      sym_children_slot,
+      sym_go_expression,
+  [9246] = 16,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(361), 1,
+      anon_sym_COMMA,
+    STATE(160), 1,
+      sym_comment,
+    STATE(265), 1,
+      aux_sym_type_parameter_declaration_repeat1,
+    STATE(349), 1,
+      sym_qualified_type,
+    STATE(475), 1,
+      sym_type_expression,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [9304] = 15,
+    ACTIONS(591), 1,
+      sym_identifier,
+    ACTIONS(594), 1,
+      anon_sym_LBRACE,
+    ACTIONS(597), 1,
+      anon_sym_LT,
+    ACTIONS(600), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(602), 1,
+      anon_sym_for,
+    ACTIONS(605), 1,
+      anon_sym_if,
+    ACTIONS(608), 1,
+      anon_sym_switch,
+    ACTIONS(611), 1,
+      anon_sym_var,
+    ACTIONS(614), 1,
+      anon_sym_AT,
+    ACTIONS(617), 1,
+      sym_text_content,
+    STATE(314), 1,
+      sym__element_child,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(161), 2,
+      sym_comment,
+      aux_sym_element_with_children_repeat1,
+    STATE(337), 2,
+      sym_self_closing_element,
+      sym_element_with_children,
+    STATE(330), 8,
+      sym_element,
+      sym_for_statement,
+      sym_if_statement,
+      sym_switch_statement,
+      sym_let_binding,
+      sym_component_call,
+      sym_children_slot,
+      sym_go_expression,
+  [9360] = 16,
+    ACTIONS(569), 1,
+      sym_identifier,
+    ACTIONS(571), 1,
+      anon_sym_LBRACE,
+    ACTIONS(573), 1,
+      anon_sym_LT,
+    ACTIONS(577), 1,
+      anon_sym_for,
+    ACTIONS(579), 1,
+      anon_sym_if,
+    ACTIONS(581), 1,
+      anon_sym_switch,
+    ACTIONS(583), 1,
+      anon_sym_var,
+    ACTIONS(585), 1,
+      anon_sym_AT,
+    ACTIONS(587), 1,
+      sym_text_content,
+    ACTIONS(620), 1,
+      anon_sym_LT_SLASH,
+    STATE(161), 1,
+      aux_sym_element_with_children_repeat1,
+    STATE(162), 1,
+      sym_comment,
+    STATE(314), 1,
+      sym__element_child,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(337), 2,
+      sym_self_closing_element,
+      sym_element_with_children,
+    STATE(330), 8,
+      sym_element,
+      sym_for_statement,
+      sym_if_statement,
+      sym_switch_statement,
+      sym_let_binding,
+      sym_component_call,
+      sym_children_slot,
+      sym_go_expression,
+  [9418] = 16,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(334), 1,
+      anon_sym_struct,
+    ACTIONS(361), 1,
+      anon_sym_COMMA,
+    ACTIONS(553), 1,
+      anon_sym_LBRACK,
+    ACTIONS(555), 1,
+      anon_sym_STAR,
+    ACTIONS(557), 1,
+      anon_sym_map,
+    ACTIONS(559), 1,
+      anon_sym_func,
+    ACTIONS(561), 1,
+      anon_sym_chan,
+    ACTIONS(563), 1,
+      anon_sym_LT_DASH,
+    STATE(163), 1,
+      sym_comment,
+    STATE(265), 1,
+      aux_sym_type_parameter_declaration_repeat1,
+    STATE(349), 1,
+      sym_qualified_type,
+    STATE(497), 1,
+      sym_type_expression,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [9476] = 16,
+    ACTIONS(569), 1,
+      sym_identifier,
+    ACTIONS(571), 1,
+      anon_sym_LBRACE,
+    ACTIONS(573), 1,
+      anon_sym_LT,
+    ACTIONS(577), 1,
+      anon_sym_for,
+    ACTIONS(579), 1,
+      anon_sym_if,
+    ACTIONS(581), 1,
+      anon_sym_switch,
+    ACTIONS(583), 1,
+      anon_sym_var,
+    ACTIONS(585), 1,
+      anon_sym_AT,
+    ACTIONS(587), 1,
+      sym_text_content,
+    ACTIONS(622), 1,
+      anon_sym_LT_SLASH,
+    STATE(164), 1,
+      sym_comment,
+    STATE(167), 1,
+      aux_sym_element_with_children_repeat1,
+    STATE(314), 1,
+      sym__element_child,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(337), 2,
+      sym_self_closing_element,
+      sym_element_with_children,
+    STATE(330), 8,
+      sym_element,
+      sym_for_statement,
+      sym_if_statement,
+      sym_switch_statement,
+      sym_let_binding,
+      sym_component_call,
+      sym_children_slot,
+      sym_go_expression,
+  [9534] = 15,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(363), 1,
+      anon_sym_TILDE,
+    STATE(165), 1,
+      sym_comment,
+    STATE(349), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(238), 2,
+      sym_underlying_type,
+      sym_type_expression,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [9590] = 15,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(320), 1,
+      anon_sym_LBRACK,
+    ACTIONS(322), 1,
+      anon_sym_TILDE,
+    ACTIONS(324), 1,
+      anon_sym_STAR,
+    ACTIONS(326), 1,
+      anon_sym_map,
+    ACTIONS(328), 1,
+      anon_sym_func,
+    ACTIONS(330), 1,
+      anon_sym_chan,
+    ACTIONS(332), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(334), 1,
+      anon_sym_struct,
+    ACTIONS(518), 1,
+      sym_identifier,
+    STATE(166), 1,
+      sym_comment,
+    STATE(279), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(238), 2,
+      sym_underlying_type,
+      sym_type_expression,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [9646] = 16,
+    ACTIONS(569), 1,
+      sym_identifier,
+    ACTIONS(571), 1,
+      anon_sym_LBRACE,
+    ACTIONS(573), 1,
+      anon_sym_LT,
+    ACTIONS(577), 1,
+      anon_sym_for,
+    ACTIONS(579), 1,
+      anon_sym_if,
+    ACTIONS(581), 1,
+      anon_sym_switch,
+    ACTIONS(583), 1,
+      anon_sym_var,
+    ACTIONS(585), 1,
+      anon_sym_AT,
+    ACTIONS(587), 1,
+      sym_text_content,
+    ACTIONS(624), 1,
+      anon_sym_LT_SLASH,
+    STATE(161), 1,
+      aux_sym_element_with_children_repeat1,
+    STATE(167), 1,
+      sym_comment,
+    STATE(314), 1,
+      sym__element_child,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(337), 2,
+      sym_self_closing_element,
+      sym_element_with_children,
+    STATE(330), 8,
+      sym_element,
+      sym_for_statement,
+      sym_if_statement,
+      sym_switch_statement,
+      sym_let_binding,
+      sym_component_call,
+      sym_children_slot,
+      sym_go_expression,
+  [9704] = 16,
+    ACTIONS(569), 1,
+      sym_identifier,
+    ACTIONS(571), 1,
+      anon_sym_LBRACE,
+    ACTIONS(573), 1,
+      anon_sym_LT,
+    ACTIONS(577), 1,
+      anon_sym_for,
+    ACTIONS(579), 1,
+      anon_sym_if,
+    ACTIONS(581), 1,
+      anon_sym_switch,
+    ACTIONS(583), 1,
+      anon_sym_var,
+    ACTIONS(585), 1,
+      anon_sym_AT,
+    ACTIONS(587), 1,
+      sym_text_content,
+    ACTIONS(624), 1,
+      anon_sym_LT_SLASH,
+    STATE(162), 1,
+      aux_sym_element_with_children_repeat1,
+    STATE(168), 1,
+      sym_comment,
+    STATE(314), 1,
+      sym__element_child,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(337), 2,
+      sym_self_closing_element,
+      sym_element_with_children,
+    STATE(330), 8,
+      sym_element,
+      sym_for_statement,
+      sym_if_statement,
+      sym_switch_statement,
+      sym_let_binding,
+      sym_component_call,
+      sym_children_slot,
+      sym_go_expression,
+  [9762] = 16,
+    ACTIONS(569), 1,
+      sym_identifier,
+    ACTIONS(571), 1,
+      anon_sym_LBRACE,
+    ACTIONS(573), 1,
+      anon_sym_LT,
+    ACTIONS(577), 1,
+      anon_sym_for,
+    ACTIONS(579), 1,
+      anon_sym_if,
+    ACTIONS(581), 1,
+      anon_sym_switch,
+    ACTIONS(583), 1,
+      anon_sym_var,
+    ACTIONS(585), 1,
+      anon_sym_AT,
+    ACTIONS(587), 1,
+      sym_text_content,
+    ACTIONS(626), 1,
+      anon_sym_LT_SLASH,
+    STATE(169), 1,
+      sym_comment,
+    STATE(170), 1,
+      aux_sym_element_with_children_repeat1,
+    STATE(314), 1,
+      sym__element_child,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(337), 2,
+      sym_self_closing_element,
+      sym_element_with_children,
+    STATE(330), 8,
+      sym_element,
+      sym_for_statement,
+      sym_if_statement,
+      sym_switch_statement,
+      sym_let_binding,
+      sym_component_call,
+      sym_children_slot,
+      sym_go_expression,
+  [9820] = 16,
+    ACTIONS(569), 1,
+      sym_identifier,
+    ACTIONS(571), 1,
+      anon_sym_LBRACE,
+    ACTIONS(573), 1,
+      anon_sym_LT,
+    ACTIONS(577), 1,
+      anon_sym_for,
+    ACTIONS(579), 1,
+      anon_sym_if,
+    ACTIONS(581), 1,
+      anon_sym_switch,
+    ACTIONS(583), 1,
+      anon_sym_var,
+    ACTIONS(585), 1,
+      anon_sym_AT,
+    ACTIONS(587), 1,
+      sym_text_content,
+    ACTIONS(589), 1,
+      anon_sym_LT_SLASH,
+    STATE(161), 1,
+      aux_sym_element_with_children_repeat1,
+    STATE(170), 1,
+      sym_comment,
+    STATE(314), 1,
+      sym__element_child,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(337), 2,
+      sym_self_closing_element,
+      sym_element_with_children,
+    STATE(330), 8,
+      sym_element,
+      sym_for_statement,
+      sym_if_statement,
+      sym_switch_statement,
+      sym_let_binding,
+      sym_component_call,
+      sym_children_slot,
+      sym_go_expression,
+  [9878] = 16,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(334), 1,
+      anon_sym_struct,
+    ACTIONS(361), 1,
+      anon_sym_COMMA,
+    ACTIONS(553), 1,
+      anon_sym_LBRACK,
+    ACTIONS(555), 1,
+      anon_sym_STAR,
+    ACTIONS(557), 1,
+      anon_sym_map,
+    ACTIONS(559), 1,
+      anon_sym_func,
+    ACTIONS(561), 1,
+      anon_sym_chan,
+    ACTIONS(563), 1,
+      anon_sym_LT_DASH,
+    STATE(163), 1,
+      aux_sym_type_parameter_declaration_repeat1,
+    STATE(171), 1,
+      sym_comment,
+    STATE(349), 1,
+      sym_qualified_type,
+    STATE(472), 1,
+      sym_type_expression,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [9936] = 14,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(320), 1,
+      anon_sym_LBRACK,
+    ACTIONS(324), 1,
+      anon_sym_STAR,
+    ACTIONS(326), 1,
+      anon_sym_map,
+    ACTIONS(328), 1,
+      anon_sym_func,
+    ACTIONS(330), 1,
+      anon_sym_chan,
+    ACTIONS(332), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(334), 1,
+      anon_sym_struct,
+    ACTIONS(518), 1,
+      sym_identifier,
+    STATE(172), 1,
+      sym_comment,
+    STATE(228), 1,
+      sym_type_expression,
+    STATE(279), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [9988] = 14,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    STATE(173), 1,
+      sym_comment,
+    STATE(349), 1,
+      sym_qualified_type,
+    STATE(508), 1,
+      sym_type_expression,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [10040] = 14,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    STATE(174), 1,
+      sym_comment,
+    STATE(349), 1,
+      sym_qualified_type,
+    STATE(436), 1,
+      sym_type_expression,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [10092] = 14,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    STATE(175), 1,
+      sym_comment,
+    STATE(349), 1,
+      sym_qualified_type,
+    STATE(506), 1,
+      sym_type_expression,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [10144] = 14,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(320), 1,
+      anon_sym_LBRACK,
+    ACTIONS(324), 1,
+      anon_sym_STAR,
+    ACTIONS(326), 1,
+      anon_sym_map,
+    ACTIONS(328), 1,
+      anon_sym_func,
+    ACTIONS(330), 1,
+      anon_sym_chan,
+    ACTIONS(332), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(334), 1,
+      anon_sym_struct,
+    ACTIONS(518), 1,
+      sym_identifier,
+    STATE(176), 1,
+      sym_comment,
+    STATE(223), 1,
+      sym_type_expression,
+    STATE(279), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [10196] = 14,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(320), 1,
+      anon_sym_LBRACK,
+    ACTIONS(324), 1,
+      anon_sym_STAR,
+    ACTIONS(326), 1,
+      anon_sym_map,
+    ACTIONS(328), 1,
+      anon_sym_func,
+    ACTIONS(332), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(334), 1,
+      anon_sym_struct,
+    ACTIONS(518), 1,
+      sym_identifier,
+    ACTIONS(628), 1,
+      anon_sym_chan,
+    STATE(177), 1,
+      sym_comment,
+    STATE(227), 1,
+      sym_type_expression,
+    STATE(279), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [10248] = 14,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(320), 1,
+      anon_sym_LBRACK,
+    ACTIONS(324), 1,
+      anon_sym_STAR,
+    ACTIONS(326), 1,
+      anon_sym_map,
+    ACTIONS(328), 1,
+      anon_sym_func,
+    ACTIONS(330), 1,
+      anon_sym_chan,
+    ACTIONS(332), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(334), 1,
+      anon_sym_struct,
+    ACTIONS(518), 1,
+      sym_identifier,
+    STATE(178), 1,
+      sym_comment,
+    STATE(227), 1,
+      sym_type_expression,
+    STATE(279), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [10300] = 14,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(320), 1,
+      anon_sym_LBRACK,
+    ACTIONS(324), 1,
+      anon_sym_STAR,
+    ACTIONS(326), 1,
+      anon_sym_map,
+    ACTIONS(328), 1,
+      anon_sym_func,
+    ACTIONS(330), 1,
+      anon_sym_chan,
+    ACTIONS(332), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(334), 1,
+      anon_sym_struct,
+    ACTIONS(518), 1,
+      sym_identifier,
+    STATE(179), 1,
+      sym_comment,
+    STATE(237), 1,
+      sym_type_expression,
+    STATE(279), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [10352] = 14,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(320), 1,
+      anon_sym_LBRACK,
+    ACTIONS(324), 1,
+      anon_sym_STAR,
+    ACTIONS(326), 1,
+      anon_sym_map,
+    ACTIONS(328), 1,
+      anon_sym_func,
+    ACTIONS(330), 1,
+      anon_sym_chan,
+    ACTIONS(332), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(334), 1,
+      anon_sym_struct,
+    ACTIONS(518), 1,
+      sym_identifier,
+    STATE(180), 1,
+      sym_comment,
+    STATE(218), 1,
+      sym_type_expression,
+    STATE(279), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [10404] = 14,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    STATE(181), 1,
+      sym_comment,
+    STATE(349), 1,
+      sym_qualified_type,
+    STATE(427), 1,
+      sym_type_expression,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [10456] = 14,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(320), 1,
+      anon_sym_LBRACK,
+    ACTIONS(324), 1,
+      anon_sym_STAR,
+    ACTIONS(326), 1,
+      anon_sym_map,
+    ACTIONS(328), 1,
+      anon_sym_func,
+    ACTIONS(330), 1,
+      anon_sym_chan,
+    ACTIONS(334), 1,
+      anon_sym_struct,
+    ACTIONS(518), 1,
+      sym_identifier,
+    ACTIONS(630), 1,
+      anon_sym_LT_DASH,
+    STATE(182), 1,
+      sym_comment,
+    STATE(216), 1,
+      sym_type_expression,
+    STATE(279), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [10508] = 14,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    STATE(183), 1,
+      sym_comment,
+    STATE(349), 1,
+      sym_qualified_type,
+    STATE(528), 1,
+      sym_type_expression,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [10560] = 14,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    STATE(184), 1,
+      sym_comment,
+    STATE(223), 1,
+      sym_type_expression,
+    STATE(349), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [10612] = 14,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(320), 1,
+      anon_sym_LBRACK,
+    ACTIONS(324), 1,
+      anon_sym_STAR,
+    ACTIONS(326), 1,
+      anon_sym_map,
+    ACTIONS(328), 1,
+      anon_sym_func,
+    ACTIONS(330), 1,
+      anon_sym_chan,
+    ACTIONS(332), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(334), 1,
+      anon_sym_struct,
+    ACTIONS(518), 1,
+      sym_identifier,
+    STATE(185), 1,
+      sym_comment,
+    STATE(217), 1,
+      sym_type_expression,
+    STATE(279), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [10664] = 14,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    STATE(186), 1,
+      sym_comment,
+    STATE(349), 1,
+      sym_qualified_type,
+    STATE(584), 1,
+      sym_type_expression,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [10716] = 14,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    STATE(187), 1,
+      sym_comment,
+    STATE(349), 1,
+      sym_qualified_type,
+    STATE(532), 1,
+      sym_type_expression,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [10768] = 14,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(632), 1,
+      anon_sym_chan,
+    STATE(188), 1,
+      sym_comment,
+    STATE(227), 1,
+      sym_type_expression,
+    STATE(349), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [10820] = 14,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    STATE(189), 1,
+      sym_comment,
+    STATE(218), 1,
+      sym_type_expression,
+    STATE(349), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [10872] = 14,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    STATE(190), 1,
+      sym_comment,
+    STATE(227), 1,
+      sym_type_expression,
+    STATE(349), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [10924] = 14,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(334), 1,
+      anon_sym_struct,
+    ACTIONS(553), 1,
+      anon_sym_LBRACK,
+    ACTIONS(555), 1,
+      anon_sym_STAR,
+    ACTIONS(557), 1,
+      anon_sym_map,
+    ACTIONS(559), 1,
+      anon_sym_func,
+    ACTIONS(561), 1,
+      anon_sym_chan,
+    ACTIONS(563), 1,
+      anon_sym_LT_DASH,
+    STATE(191), 1,
+      sym_comment,
+    STATE(223), 1,
+      sym_type_expression,
+    STATE(349), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [10976] = 14,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(334), 1,
+      anon_sym_struct,
+    ACTIONS(553), 1,
+      anon_sym_LBRACK,
+    ACTIONS(555), 1,
+      anon_sym_STAR,
+    ACTIONS(557), 1,
+      anon_sym_map,
+    ACTIONS(559), 1,
+      anon_sym_func,
+    ACTIONS(563), 1,
+      anon_sym_LT_DASH,
+    ACTIONS(634), 1,
+      anon_sym_chan,
+    STATE(192), 1,
+      sym_comment,
+    STATE(227), 1,
+      sym_type_expression,
+    STATE(349), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [11028] = 14,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(334), 1,
+      anon_sym_struct,
+    ACTIONS(553), 1,
+      anon_sym_LBRACK,
+    ACTIONS(555), 1,
+      anon_sym_STAR,
+    ACTIONS(557), 1,
+      anon_sym_map,
+    ACTIONS(559), 1,
+      anon_sym_func,
+    ACTIONS(561), 1,
+      anon_sym_chan,
+    ACTIONS(563), 1,
+      anon_sym_LT_DASH,
+    STATE(193), 1,
+      sym_comment,
+    STATE(227), 1,
+      sym_type_expression,
+    STATE(349), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [11080] = 14,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(334), 1,
+      anon_sym_struct,
+    ACTIONS(553), 1,
+      anon_sym_LBRACK,
+    ACTIONS(555), 1,
+      anon_sym_STAR,
+    ACTIONS(557), 1,
+      anon_sym_map,
+    ACTIONS(559), 1,
+      anon_sym_func,
+    ACTIONS(561), 1,
+      anon_sym_chan,
+    ACTIONS(563), 1,
+      anon_sym_LT_DASH,
+    STATE(194), 1,
+      sym_comment,
+    STATE(218), 1,
+      sym_type_expression,
+    STATE(349), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [11132] = 14,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(334), 1,
+      anon_sym_struct,
+    ACTIONS(553), 1,
+      anon_sym_LBRACK,
+    ACTIONS(555), 1,
+      anon_sym_STAR,
+    ACTIONS(557), 1,
+      anon_sym_map,
+    ACTIONS(559), 1,
+      anon_sym_func,
+    ACTIONS(561), 1,
+      anon_sym_chan,
+    ACTIONS(636), 1,
+      anon_sym_LT_DASH,
+    STATE(195), 1,
+      sym_comment,
+    STATE(216), 1,
+      sym_type_expression,
+    STATE(349), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [11184] = 14,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(334), 1,
+      anon_sym_struct,
+    ACTIONS(553), 1,
+      anon_sym_LBRACK,
+    ACTIONS(555), 1,
+      anon_sym_STAR,
+    ACTIONS(557), 1,
+      anon_sym_map,
+    ACTIONS(559), 1,
+      anon_sym_func,
+    ACTIONS(561), 1,
+      anon_sym_chan,
+    ACTIONS(563), 1,
+      anon_sym_LT_DASH,
+    STATE(196), 1,
+      sym_comment,
+    STATE(217), 1,
+      sym_type_expression,
+    STATE(349), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [11236] = 14,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    STATE(197), 1,
+      sym_comment,
+    STATE(237), 1,
+      sym_type_expression,
+    STATE(349), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [11288] = 14,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    STATE(198), 1,
+      sym_comment,
+    STATE(349), 1,
+      sym_qualified_type,
+    STATE(443), 1,
+      sym_type_expression,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [11340] = 14,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(334), 1,
+      anon_sym_struct,
+    ACTIONS(553), 1,
+      anon_sym_LBRACK,
+    ACTIONS(555), 1,
+      anon_sym_STAR,
+    ACTIONS(557), 1,
+      anon_sym_map,
+    ACTIONS(559), 1,
+      anon_sym_func,
+    ACTIONS(561), 1,
+      anon_sym_chan,
+    ACTIONS(636), 1,
+      anon_sym_LT_DASH,
+    STATE(199), 1,
+      sym_comment,
+    STATE(214), 1,
+      sym_type_expression,
+    STATE(349), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [11392] = 14,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(638), 1,
+      anon_sym_LT_DASH,
+    STATE(200), 1,
+      sym_comment,
+    STATE(216), 1,
+      sym_type_expression,
+    STATE(349), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [11444] = 14,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(334), 1,
+      anon_sym_struct,
+    ACTIONS(553), 1,
+      anon_sym_LBRACK,
+    ACTIONS(555), 1,
+      anon_sym_STAR,
+    ACTIONS(557), 1,
+      anon_sym_map,
+    ACTIONS(559), 1,
+      anon_sym_func,
+    ACTIONS(561), 1,
+      anon_sym_chan,
+    ACTIONS(563), 1,
+      anon_sym_LT_DASH,
+    STATE(201), 1,
+      sym_comment,
+    STATE(228), 1,
+      sym_type_expression,
+    STATE(349), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [11496] = 14,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    STATE(202), 1,
+      sym_comment,
+    STATE(349), 1,
+      sym_qualified_type,
+    STATE(588), 1,
+      sym_type_expression,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [11548] = 14,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    STATE(203), 1,
+      sym_comment,
+    STATE(228), 1,
+      sym_type_expression,
+    STATE(349), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [11600] = 14,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(638), 1,
+      anon_sym_LT_DASH,
+    STATE(204), 1,
+      sym_comment,
+    STATE(214), 1,
+      sym_type_expression,
+    STATE(349), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [11652] = 14,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    STATE(205), 1,
+      sym_comment,
+    STATE(349), 1,
+      sym_qualified_type,
+    STATE(581), 1,
+      sym_type_expression,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [11704] = 14,
+    ACTIONS(23), 1,
+      anon_sym_LBRACK,
+    ACTIONS(29), 1,
+      anon_sym_map,
+    ACTIONS(33), 1,
+      anon_sym_chan,
+    ACTIONS(37), 1,
+      anon_sym_struct,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(272), 1,
+      sym_identifier,
+    ACTIONS(278), 1,
+      anon_sym_STAR,
+    ACTIONS(280), 1,
+      anon_sym_func,
+    ACTIONS(282), 1,
+      anon_sym_LT_DASH,
+    STATE(206), 1,
+      sym_comment,
+    STATE(217), 1,
+      sym_type_expression,
+    STATE(349), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [11756] = 14,
+    ACTIONS(39), 1,
+      anon_sym_interface,
+    ACTIONS(320), 1,
+      anon_sym_LBRACK,
+    ACTIONS(324), 1,
+      anon_sym_STAR,
+    ACTIONS(326), 1,
+      anon_sym_map,
+    ACTIONS(328), 1,
+      anon_sym_func,
+    ACTIONS(330), 1,
+      anon_sym_chan,
+    ACTIONS(334), 1,
+      anon_sym_struct,
+    ACTIONS(518), 1,
+      sym_identifier,
+    ACTIONS(630), 1,
+      anon_sym_LT_DASH,
+    STATE(207), 1,
+      sym_comment,
+    STATE(214), 1,
+      sym_type_expression,
+    STATE(279), 1,
+      sym_qualified_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(230), 9,
+      sym_slice_type,
+      sym_pointer_type,
+      sym_map_type,
+      sym_func_type,
+      sym_array_type,
+      sym_channel_type,
+      sym_struct_type,
+      sym_interface_type,
+      sym_generic_type,
+  [11808] = 12,
+    ACTIONS(640), 1,
+      ts_builtin_sym_end,
+    ACTIONS(644), 1,
+      anon_sym_LPAREN,
+    ACTIONS(648), 1,
+      anon_sym_LBRACE,
+    ACTIONS(652), 1,
+      aux_sym_go_declaration_token1,
+    STATE(208), 1,
+      sym_comment,
+    STATE(210), 1,
+      aux_sym_go_declaration_repeat1,
+    STATE(233), 1,
+      sym_go_string_literal,
+    STATE(368), 1,
+      sym_go_brace_body,
+    ACTIONS(642), 2,
+      sym_identifier,
+      aux_sym_go_declaration_token2,
+    ACTIONS(654), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(650), 4,
+      aux_sym_go_string_literal_token1,
+      aux_sym_go_string_literal_token2,
+      aux_sym_go_string_literal_token3,
+      aux_sym_go_string_literal_token4,
+    ACTIONS(646), 5,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
+      anon_sym_var,
+      anon_sym_const,
+  [11854] = 4,
+    STATE(209), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(656), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+    ACTIONS(658), 11,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_PIPE,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+  [11883] = 8,
+    ACTIONS(670), 1,
+      aux_sym_go_declaration_token1,
+    STATE(233), 1,
+      sym_go_string_literal,
+    ACTIONS(654), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(662), 2,
+      sym_identifier,
+      aux_sym_go_declaration_token2,
+    STATE(210), 2,
+      sym_comment,
+      aux_sym_go_declaration_repeat1,
+    ACTIONS(660), 3,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_LBRACE,
+    ACTIONS(667), 4,
+      aux_sym_go_string_literal_token1,
+      aux_sym_go_string_literal_token2,
+      aux_sym_go_string_literal_token3,
+      aux_sym_go_string_literal_token4,
+    ACTIONS(665), 5,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
+      anon_sym_var,
+      anon_sym_const,
+  [11920] = 4,
+    STATE(211), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(673), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+    ACTIONS(675), 11,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_PIPE,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+  [11949] = 4,
+    STATE(212), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(677), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+    ACTIONS(679), 11,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_PIPE,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+  [11978] = 4,
+    STATE(213), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(681), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+    ACTIONS(683), 11,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_PIPE,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+  [12007] = 4,
+    STATE(214), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(685), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+    ACTIONS(687), 10,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_PIPE,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+  [12035] = 4,
+    STATE(215), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(689), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+    ACTIONS(691), 10,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_PIPE,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+  [12063] = 4,
+    STATE(216), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(685), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+    ACTIONS(687), 10,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_PIPE,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+  [12091] = 4,
+    STATE(217), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(693), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+    ACTIONS(695), 10,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_PIPE,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+  [12119] = 4,
+    STATE(218), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(697), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+    ACTIONS(699), 10,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_PIPE,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+  [12147] = 4,
+    STATE(219), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(701), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+    ACTIONS(703), 10,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_PIPE,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+  [12175] = 4,
+    STATE(220), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(705), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+    ACTIONS(707), 10,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_PIPE,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+  [12203] = 4,
+    STATE(221), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(709), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+    ACTIONS(711), 10,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_PIPE,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+  [12231] = 4,
+    STATE(222), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(713), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+    ACTIONS(715), 10,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_PIPE,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+  [12259] = 4,
+    STATE(223), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(717), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+    ACTIONS(719), 10,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_PIPE,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+  [12287] = 4,
+    STATE(224), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(721), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+    ACTIONS(723), 10,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_PIPE,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+  [12315] = 4,
+    STATE(225), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(725), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+    ACTIONS(727), 10,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_PIPE,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+  [12343] = 4,
+    STATE(226), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(729), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+    ACTIONS(731), 10,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_PIPE,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+  [12371] = 4,
+    STATE(227), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(733), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+    ACTIONS(735), 10,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_PIPE,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+  [12399] = 4,
+    STATE(228), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(737), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+    ACTIONS(739), 10,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_PIPE,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+  [12427] = 4,
+    STATE(229), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(741), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+    ACTIONS(743), 10,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_PIPE,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+  [12455] = 4,
+    STATE(230), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(745), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+    ACTIONS(349), 10,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_PIPE,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+  [12483] = 4,
+    STATE(231), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(747), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+    ACTIONS(749), 10,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_PIPE,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+  [12511] = 5,
+    ACTIONS(755), 1,
+      anon_sym_struct,
+    STATE(232), 1,
+      sym_comment,
+    ACTIONS(654), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(753), 7,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
+      anon_sym_var,
+      sym_identifier,
+      anon_sym_const,
+      aux_sym_go_declaration_token2,
+    ACTIONS(751), 8,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_LBRACE,
+      aux_sym_go_string_literal_token1,
+      aux_sym_go_string_literal_token2,
+      aux_sym_go_string_literal_token3,
+      aux_sym_go_string_literal_token4,
+      aux_sym_go_declaration_token1,
+  [12541] = 4,
+    STATE(233), 1,
+      sym_comment,
+    ACTIONS(654), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(753), 7,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
+      anon_sym_var,
+      sym_identifier,
+      anon_sym_const,
+      aux_sym_go_declaration_token2,
+    ACTIONS(751), 8,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_LBRACE,
+      aux_sym_go_string_literal_token1,
+      aux_sym_go_string_literal_token2,
+      aux_sym_go_string_literal_token3,
+      aux_sym_go_string_literal_token4,
+      aux_sym_go_declaration_token1,
+  [12568] = 8,
+    ACTIONS(757), 1,
+      anon_sym_LPAREN,
+    ACTIONS(759), 1,
+      anon_sym_LBRACK,
+    ACTIONS(762), 1,
+      anon_sym_DOT,
+    STATE(131), 1,
+      sym_parameter_list,
+    STATE(234), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(349), 5,
+      anon_sym_PIPE,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_RBRACE,
+    ACTIONS(745), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+  [12603] = 13,
+    ACTIONS(9), 1,
+      anon_sym_import,
+    ACTIONS(11), 1,
+      anon_sym_templ,
+    ACTIONS(13), 1,
+      anon_sym_func,
+    ACTIONS(15), 1,
+      anon_sym_type,
+    ACTIONS(764), 1,
+      ts_builtin_sym_end,
+    STATE(235), 1,
+      sym_comment,
+    STATE(295), 1,
+      sym_import_section,
+    STATE(296), 1,
+      aux_sym_source_file_repeat1,
+    STATE(340), 1,
+      aux_sym_import_section_repeat1,
+    STATE(354), 1,
+      sym_import_declaration,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(17), 2,
+      anon_sym_var,
+      anon_sym_const,
+    STATE(366), 4,
+      sym_component_declaration,
+      sym_type_struct_declaration,
+      sym_function_declaration,
+      sym_go_declaration,
+  [12648] = 4,
+    STATE(236), 1,
+      sym_comment,
+    ACTIONS(654), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(768), 7,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
+      anon_sym_var,
+      sym_identifier,
+      anon_sym_const,
+      aux_sym_go_declaration_token2,
+    ACTIONS(766), 8,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_LBRACE,
+      aux_sym_go_string_literal_token1,
+      aux_sym_go_string_literal_token2,
+      aux_sym_go_string_literal_token3,
+      aux_sym_go_string_literal_token4,
+      aux_sym_go_declaration_token1,
+  [12675] = 4,
+    STATE(237), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(770), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+    ACTIONS(772), 8,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_PIPE,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_RBRACE,
+  [12701] = 4,
+    STATE(238), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(774), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+    ACTIONS(776), 8,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_PIPE,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_RBRACE,
+  [12727] = 6,
+    ACTIONS(782), 1,
+      anon_sym_PIPE,
+    STATE(239), 1,
+      sym_comment,
+    STATE(243), 1,
+      aux_sym_type_constraint_repeat1,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(780), 5,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_RBRACE,
+    ACTIONS(778), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+  [12756] = 6,
+    ACTIONS(383), 1,
+      anon_sym_LBRACE,
+    STATE(240), 1,
+      sym_comment,
+    STATE(271), 1,
+      sym_block,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(786), 4,
+      sym_go_statement,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(784), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [12785] = 6,
+    ACTIONS(759), 1,
+      anon_sym_LBRACK,
+    ACTIONS(762), 1,
+      anon_sym_DOT,
+    STATE(241), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(349), 5,
+      anon_sym_PIPE,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_RBRACE,
+    ACTIONS(745), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+  [12814] = 4,
+    STATE(242), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(790), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(788), 8,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_else,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [12839] = 5,
+    ACTIONS(792), 1,
+      anon_sym_PIPE,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(243), 2,
+      sym_comment,
+      aux_sym_type_constraint_repeat1,
+    ACTIONS(776), 5,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_RBRACE,
+    ACTIONS(774), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+  [12866] = 4,
+    STATE(244), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(797), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(795), 8,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_else,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [12891] = 6,
+    ACTIONS(782), 1,
+      anon_sym_PIPE,
+    STATE(239), 1,
+      aux_sym_type_constraint_repeat1,
+    STATE(245), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(801), 5,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_RBRACE,
+    ACTIONS(799), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+  [12920] = 6,
+    ACTIONS(383), 1,
+      anon_sym_LBRACE,
+    STATE(246), 1,
+      sym_comment,
+    STATE(282), 1,
+      sym_block,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(805), 4,
+      sym_go_statement,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(803), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [12949] = 5,
+    ACTIONS(811), 1,
+      anon_sym_else,
+    STATE(247), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(809), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(807), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [12976] = 5,
+    ACTIONS(817), 1,
+      anon_sym_SEMI,
+    STATE(248), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(815), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(813), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [13003] = 4,
+    STATE(249), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(819), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+    ACTIONS(821), 6,
+      anon_sym_LBRACK,
+      anon_sym_PIPE,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_RBRACE,
+  [13027] = 4,
+    STATE(250), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(825), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(823), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [13051] = 10,
+    ACTIONS(827), 1,
+      anon_sym_LBRACE,
+    ACTIONS(829), 1,
+      anon_sym_RBRACE,
+    ACTIONS(831), 1,
+      anon_sym_SLASH,
+    ACTIONS(833), 1,
+      aux_sym_expression_content_token1,
+    STATE(251), 1,
+      sym_comment,
+    STATE(303), 1,
+      aux_sym_expression_content_repeat1,
+    STATE(527), 1,
+      sym_go_code_content,
+    ACTIONS(654), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(350), 2,
+      sym_go_string_literal,
+      sym_nested_braces,
+    ACTIONS(835), 4,
+      aux_sym_go_string_literal_token1,
+      aux_sym_go_string_literal_token2,
+      aux_sym_go_string_literal_token3,
+      aux_sym_go_string_literal_token4,
+  [13087] = 4,
+    STATE(252), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(837), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+    ACTIONS(839), 6,
+      anon_sym_LBRACK,
+      anon_sym_PIPE,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_RBRACE,
+  [13111] = 4,
+    STATE(253), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(102), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(100), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [13135] = 4,
+    STATE(254), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(843), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(841), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [13159] = 4,
+    STATE(255), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(847), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(845), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [13183] = 4,
+    STATE(256), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(851), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(849), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [13207] = 4,
+    STATE(257), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(106), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(104), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [13231] = 4,
+    STATE(258), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(110), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(108), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [13255] = 4,
+    STATE(259), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(114), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(112), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [13279] = 4,
+    STATE(260), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(118), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(116), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [13303] = 4,
+    STATE(261), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(75), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(73), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [13327] = 4,
+    STATE(262), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(855), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(853), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [13351] = 4,
+    STATE(263), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(859), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(857), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [13375] = 4,
+    STATE(264), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(863), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(861), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [13399] = 5,
+    ACTIONS(869), 1,
+      anon_sym_COMMA,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(265), 2,
+      sym_comment,
+      aux_sym_type_parameter_declaration_repeat1,
+    ACTIONS(867), 4,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+    ACTIONS(865), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+  [13425] = 4,
+    STATE(266), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(874), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(872), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [13449] = 4,
+    STATE(267), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(815), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(813), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [13473] = 4,
+    STATE(268), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(878), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(876), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [13497] = 4,
+    STATE(269), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(882), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(880), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [13521] = 4,
+    STATE(270), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(886), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(884), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [13545] = 4,
+    STATE(271), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(890), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(888), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [13569] = 10,
+    ACTIONS(827), 1,
+      anon_sym_LBRACE,
+    ACTIONS(831), 1,
+      anon_sym_SLASH,
+    ACTIONS(833), 1,
+      aux_sym_expression_content_token1,
+    ACTIONS(892), 1,
+      anon_sym_RBRACE,
+    STATE(272), 1,
+      sym_comment,
+    STATE(303), 1,
+      aux_sym_expression_content_repeat1,
+    STATE(595), 1,
+      sym_go_code_content,
+    ACTIONS(654), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(350), 2,
+      sym_go_string_literal,
+      sym_nested_braces,
+    ACTIONS(835), 4,
+      aux_sym_go_string_literal_token1,
+      aux_sym_go_string_literal_token2,
+      aux_sym_go_string_literal_token3,
+      aux_sym_go_string_literal_token4,
+  [13605] = 4,
+    STATE(273), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(896), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(894), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [13629] = 4,
+    STATE(274), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(900), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(898), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [13653] = 4,
+    STATE(275), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(904), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(902), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [13677] = 4,
+    STATE(276), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(908), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(906), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [13701] = 4,
+    STATE(277), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(912), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(910), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [13725] = 4,
+    STATE(278), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(916), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(914), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [13749] = 5,
+    ACTIONS(759), 1,
+      anon_sym_LBRACK,
+    STATE(279), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(349), 5,
+      anon_sym_PIPE,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_RBRACE,
+    ACTIONS(745), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+  [13775] = 9,
+    ACTIONS(827), 1,
+      anon_sym_LBRACE,
+    ACTIONS(918), 1,
+      anon_sym_children,
+    STATE(280), 1,
+      sym_comment,
+    STATE(292), 1,
+      aux_sym_expression_content_repeat1,
+    STATE(596), 1,
+      sym_expression_content,
+    ACTIONS(654), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(831), 2,
+      anon_sym_SLASH,
+      aux_sym_expression_content_token1,
+    STATE(350), 2,
+      sym_go_string_literal,
+      sym_nested_braces,
+    ACTIONS(835), 4,
+      aux_sym_go_string_literal_token1,
+      aux_sym_go_string_literal_token2,
+      aux_sym_go_string_literal_token3,
+      aux_sym_go_string_literal_token4,
+  [13809] = 4,
+    STATE(281), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(922), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(920), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [13833] = 4,
+    STATE(282), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(926), 5,
+      sym_go_statement,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_AT,
+    ACTIONS(924), 7,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_case,
+      anon_sym_default,
+      anon_sym_var,
+      sym_identifier,
+  [13857] = 9,
+    ACTIONS(827), 1,
+      anon_sym_LBRACE,
+    ACTIONS(928), 1,
+      anon_sym_children,
+    STATE(283), 1,
+      sym_comment,
+    STATE(292), 1,
+      aux_sym_expression_content_repeat1,
+    STATE(544), 1,
+      sym_expression_content,
+    ACTIONS(654), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(831), 2,
+      anon_sym_SLASH,
+      aux_sym_expression_content_token1,
+    STATE(350), 2,
+      sym_go_string_literal,
+      sym_nested_braces,
+    ACTIONS(835), 4,
+      aux_sym_go_string_literal_token1,
+      aux_sym_go_string_literal_token2,
+      aux_sym_go_string_literal_token3,
+      aux_sym_go_string_literal_token4,
+  [13891] = 9,
+    ACTIONS(827), 1,
+      anon_sym_LBRACE,
+    ACTIONS(831), 1,
+      anon_sym_SLASH,
+    ACTIONS(833), 1,
+      aux_sym_expression_content_token1,
+    ACTIONS(930), 1,
+      anon_sym_RBRACE,
+    STATE(284), 1,
+      sym_comment,
+    STATE(287), 1,
+      aux_sym_expression_content_repeat1,
+    ACTIONS(654), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(350), 2,
+      sym_go_string_literal,
+      sym_nested_braces,
+    ACTIONS(835), 4,
+      aux_sym_go_string_literal_token1,
+      aux_sym_go_string_literal_token2,
+      aux_sym_go_string_literal_token3,
+      aux_sym_go_string_literal_token4,
+  [13924] = 10,
+    ACTIONS(642), 1,
+      aux_sym_go_declaration_token2,
+    ACTIONS(652), 1,
+      aux_sym_go_declaration_token1,
+    ACTIONS(932), 1,
+      sym_identifier,
+    ACTIONS(934), 1,
+      anon_sym_LPAREN,
+    STATE(208), 1,
+      aux_sym_go_declaration_repeat1,
+    STATE(233), 1,
+      sym_go_string_literal,
+    STATE(285), 1,
+      sym_comment,
+    STATE(363), 1,
+      sym_go_paren_body,
+    ACTIONS(654), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(650), 4,
+      aux_sym_go_string_literal_token1,
+      aux_sym_go_string_literal_token2,
+      aux_sym_go_string_literal_token3,
+      aux_sym_go_string_literal_token4,
+  [13959] = 6,
+    ACTIONS(369), 1,
+      anon_sym_LBRACE,
+    STATE(286), 1,
+      sym_comment,
+    STATE(315), 1,
+      sym_block,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(786), 3,
+      sym_text_content,
+      anon_sym_LT_SLASH,
+      anon_sym_AT,
+    ACTIONS(784), 6,
+      anon_sym_LT,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_var,
+      sym_identifier,
+  [13986] = 8,
+    ACTIONS(936), 1,
+      anon_sym_LBRACE,
+    ACTIONS(939), 1,
+      anon_sym_RBRACE,
+    ACTIONS(941), 1,
+      anon_sym_SLASH,
+    ACTIONS(944), 1,
+      aux_sym_expression_content_token1,
+    ACTIONS(654), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(287), 2,
+      sym_comment,
+      aux_sym_expression_content_repeat1,
+    STATE(350), 2,
+      sym_go_string_literal,
+      sym_nested_braces,
+    ACTIONS(947), 4,
+      aux_sym_go_string_literal_token1,
+      aux_sym_go_string_literal_token2,
+      aux_sym_go_string_literal_token3,
+      aux_sym_go_string_literal_token4,
+  [14017] = 9,
+    ACTIONS(827), 1,
+      anon_sym_LBRACE,
+    ACTIONS(831), 1,
+      anon_sym_SLASH,
+    ACTIONS(833), 1,
+      aux_sym_expression_content_token1,
+    STATE(288), 1,
+      sym_comment,
+    STATE(292), 1,
+      aux_sym_expression_content_repeat1,
+    STATE(547), 1,
+      sym_expression_content,
+    ACTIONS(654), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(350), 2,
+      sym_go_string_literal,
+      sym_nested_braces,
+    ACTIONS(835), 4,
+      aux_sym_go_string_literal_token1,
+      aux_sym_go_string_literal_token2,
+      aux_sym_go_string_literal_token3,
+      aux_sym_go_string_literal_token4,
+  [14050] = 4,
+    STATE(289), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(952), 5,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_RBRACE,
+    ACTIONS(950), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+  [14073] = 9,
+    ACTIONS(11), 1,
+      anon_sym_templ,
+    ACTIONS(13), 1,
+      anon_sym_func,
+    ACTIONS(15), 1,
+      anon_sym_type,
+    ACTIONS(764), 1,
+      ts_builtin_sym_end,
+    STATE(290), 1,
+      sym_comment,
+    STATE(296), 1,
+      aux_sym_source_file_repeat1,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(17), 2,
+      anon_sym_var,
+      anon_sym_const,
+    STATE(366), 4,
+      sym_component_declaration,
+      sym_type_struct_declaration,
+      sym_function_declaration,
+      sym_go_declaration,
+  [14106] = 9,
+    ACTIONS(642), 1,
+      aux_sym_go_declaration_token2,
+    ACTIONS(934), 1,
+      anon_sym_LPAREN,
+    STATE(208), 1,
+      aux_sym_go_declaration_repeat1,
+    STATE(233), 1,
+      sym_go_string_literal,
+    STATE(291), 1,
+      sym_comment,
+    STATE(363), 1,
+      sym_go_paren_body,
+    ACTIONS(652), 2,
+      sym_identifier,
+      aux_sym_go_declaration_token1,
+    ACTIONS(654), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(650), 4,
+      aux_sym_go_string_literal_token1,
+      aux_sym_go_string_literal_token2,
+      aux_sym_go_string_literal_token3,
+      aux_sym_go_string_literal_token4,
+  [14139] = 9,
+    ACTIONS(827), 1,
+      anon_sym_LBRACE,
+    ACTIONS(831), 1,
+      anon_sym_SLASH,
+    ACTIONS(833), 1,
+      aux_sym_expression_content_token1,
+    ACTIONS(954), 1,
+      anon_sym_RBRACE,
+    STATE(287), 1,
+      aux_sym_expression_content_repeat1,
+    STATE(292), 1,
+      sym_comment,
+    ACTIONS(654), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(350), 2,
+      sym_go_string_literal,
+      sym_nested_braces,
+    ACTIONS(835), 4,
+      aux_sym_go_string_literal_token1,
+      aux_sym_go_string_literal_token2,
+      aux_sym_go_string_literal_token3,
+      aux_sym_go_string_literal_token4,
+  [14172] = 4,
+    STATE(293), 1,
+      sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(195), 5,
-      sym_slice_type,
-      sym_pointer_type,
-      sym_map_type,
-      sym_func_type,
-      sym_generic_type,
-  [2646] = 9,
-    ACTIONS(248), 1,
-      ts_builtin_sym_end,
-    ACTIONS(252), 1,
-      anon_sym_LPAREN,
-    ACTIONS(256), 1,
+    ACTIONS(958), 5,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+    ACTIONS(956), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
+      anon_sym_struct,
+      anon_sym_interface,
+      sym_identifier,
+  [14195] = 9,
+    ACTIONS(827), 1,
       anon_sym_LBRACE,
-    STATE(66), 1,
+    ACTIONS(831), 1,
+      anon_sym_SLASH,
+    ACTIONS(833), 1,
+      aux_sym_expression_content_token1,
+    ACTIONS(960), 1,
+      anon_sym_RBRACE,
+    STATE(284), 1,
+      aux_sym_expression_content_repeat1,
+    STATE(294), 1,
       sym_comment,
-    STATE(81), 1,
-      aux_sym_go_declaration_repeat1,
-    STATE(185), 1,
-      sym_go_brace_body,
-    ACTIONS(53), 2,
+    ACTIONS(654), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(250), 2,
-      sym_identifier,
-      aux_sym_go_declaration_token1,
-    ACTIONS(254), 5,
+    STATE(350), 2,
+      sym_go_string_literal,
+      sym_nested_braces,
+    ACTIONS(835), 4,
+      aux_sym_go_string_literal_token1,
+      aux_sym_go_string_literal_token2,
+      aux_sym_go_string_literal_token3,
+      aux_sym_go_string_literal_token4,
+  [14228] = 9,
+    ACTIONS(11), 1,
       anon_sym_templ,
+    ACTIONS(13), 1,
       anon_sym_func,
+    ACTIONS(15), 1,
       anon_sym_type,
-      anon_sym_var,
-      anon_sym_const,
-  [2680] = 10,
-    ACTIONS(155), 1,
-      sym_identifier,
-    ACTIONS(161), 1,
-      anon_sym_LBRACK,
-    ACTIONS(163), 1,
-      anon_sym_STAR,
-    ACTIONS(165), 1,
-      anon_sym_map,
-    ACTIONS(167), 1,
-      anon_sym_func,
-    STATE(67), 1,
+    ACTIONS(962), 1,
+      ts_builtin_sym_end,
+    STATE(295), 1,
       sym_comment,
-    STATE(157), 1,
-      sym_qualified_type,
-    STATE(273), 1,
-      sym_type_expression,
+    STATE(305), 1,
+      aux_sym_source_file_repeat1,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(195), 5,
-      sym_slice_type,
-      sym_pointer_type,
-      sym_map_type,
-      sym_func_type,
-      sym_generic_type,
-  [2716] = 9,
+    ACTIONS(17), 2,
+      anon_sym_var,
+      anon_sym_const,
+    STATE(366), 4,
+      sym_component_declaration,
+      sym_type_struct_declaration,
+      sym_function_declaration,
+      sym_go_declaration,
+  [14261] = 9,
     ACTIONS(11), 1,
       anon_sym_templ,
     ACTIONS(13), 1,
       anon_sym_func,
     ACTIONS(15), 1,
       anon_sym_type,
-    ACTIONS(258), 1,
+    ACTIONS(962), 1,
       ts_builtin_sym_end,
-    STATE(68), 1,
+    STATE(296), 1,
       sym_comment,
-    STATE(85), 1,
+    STATE(304), 1,
       aux_sym_source_file_repeat1,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
@@ -4907,4298 +15488,5109 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(17), 2,
       anon_sym_var,
       anon_sym_const,
-    STATE(180), 4,
+    STATE(366), 4,
       sym_component_declaration,
       sym_type_struct_declaration,
       sym_function_declaration,
       sym_go_declaration,
-  [2749] = 9,
-    ACTIONS(260), 1,
-      anon_sym_LBRACE,
-    ACTIONS(262), 1,
-      anon_sym_children,
-    ACTIONS(264), 1,
-      aux_sym_expression_content_token1,
-    STATE(69), 1,
+  [14294] = 9,
+    ACTIONS(964), 1,
+      anon_sym_LPAREN,
+    ACTIONS(966), 1,
+      anon_sym_RPAREN,
+    ACTIONS(968), 1,
+      anon_sym_SLASH,
+    ACTIONS(972), 1,
+      aux_sym_go_paren_body_token1,
+    STATE(297), 1,
       sym_comment,
-    STATE(94), 1,
-      aux_sym_expression_content_repeat1,
-    STATE(289), 1,
-      sym_expression_content,
-    ACTIONS(53), 2,
+    STATE(301), 1,
+      aux_sym_go_paren_body_repeat1,
+    ACTIONS(654), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(161), 2,
+    STATE(348), 2,
       sym_go_string_literal,
-      sym_nested_braces,
-    ACTIONS(266), 4,
+      sym_nested_parens,
+    ACTIONS(970), 4,
       aux_sym_go_string_literal_token1,
       aux_sym_go_string_literal_token2,
       aux_sym_go_string_literal_token3,
       aux_sym_go_string_literal_token4,
-  [2782] = 9,
-    ACTIONS(175), 1,
+  [14327] = 9,
+    ACTIONS(964), 1,
+      anon_sym_LPAREN,
+    ACTIONS(968), 1,
+      anon_sym_SLASH,
+    ACTIONS(972), 1,
+      aux_sym_go_paren_body_token1,
+    ACTIONS(974), 1,
+      anon_sym_RPAREN,
+    STATE(298), 1,
+      sym_comment,
+    STATE(300), 1,
+      aux_sym_go_paren_body_repeat1,
+    ACTIONS(654), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(348), 2,
+      sym_go_string_literal,
+      sym_nested_parens,
+    ACTIONS(970), 4,
+      aux_sym_go_string_literal_token1,
+      aux_sym_go_string_literal_token2,
+      aux_sym_go_string_literal_token3,
+      aux_sym_go_string_literal_token4,
+  [14360] = 5,
+    ACTIONS(976), 1,
+      anon_sym_else,
+    STATE(299), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(809), 4,
+      sym_text_content,
+      anon_sym_LBRACE,
+      anon_sym_LT_SLASH,
+      anon_sym_AT,
+    ACTIONS(807), 6,
+      anon_sym_LT,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_var,
       sym_identifier,
-    ACTIONS(177), 1,
+  [14385] = 9,
+    ACTIONS(964), 1,
       anon_sym_LPAREN,
-    STATE(27), 1,
-      sym_selector_expression,
-    STATE(46), 1,
-      sym__expression,
-    STATE(70), 1,
+    ACTIONS(968), 1,
+      anon_sym_SLASH,
+    ACTIONS(972), 1,
+      aux_sym_go_paren_body_token1,
+    ACTIONS(978), 1,
+      anon_sym_RPAREN,
+    STATE(300), 1,
+      sym_comment,
+    STATE(306), 1,
+      aux_sym_go_paren_body_repeat1,
+    ACTIONS(654), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(348), 2,
+      sym_go_string_literal,
+      sym_nested_parens,
+    ACTIONS(970), 4,
+      aux_sym_go_string_literal_token1,
+      aux_sym_go_string_literal_token2,
+      aux_sym_go_string_literal_token3,
+      aux_sym_go_string_literal_token4,
+  [14418] = 9,
+    ACTIONS(964), 1,
+      anon_sym_LPAREN,
+    ACTIONS(968), 1,
+      anon_sym_SLASH,
+    ACTIONS(972), 1,
+      aux_sym_go_paren_body_token1,
+    ACTIONS(980), 1,
+      anon_sym_RPAREN,
+    STATE(301), 1,
+      sym_comment,
+    STATE(306), 1,
+      aux_sym_go_paren_body_repeat1,
+    ACTIONS(654), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(348), 2,
+      sym_go_string_literal,
+      sym_nested_parens,
+    ACTIONS(970), 4,
+      aux_sym_go_string_literal_token1,
+      aux_sym_go_string_literal_token2,
+      aux_sym_go_string_literal_token3,
+      aux_sym_go_string_literal_token4,
+  [14451] = 6,
+    ACTIONS(369), 1,
+      anon_sym_LBRACE,
+    STATE(302), 1,
       sym_comment,
+    STATE(334), 1,
+      sym_block,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(179), 2,
-      sym_number,
-      sym_string,
-    ACTIONS(181), 2,
-      sym_true,
-      sym_false,
-    STATE(34), 3,
-      sym_binary_expression,
-      sym_call_expression,
-      sym_parenthesized_expression,
-  [2815] = 9,
+    ACTIONS(805), 3,
+      sym_text_content,
+      anon_sym_LT_SLASH,
+      anon_sym_AT,
+    ACTIONS(803), 6,
+      anon_sym_LT,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_var,
+      sym_identifier,
+  [14478] = 9,
+    ACTIONS(827), 1,
+      anon_sym_LBRACE,
+    ACTIONS(831), 1,
+      anon_sym_SLASH,
+    ACTIONS(833), 1,
+      aux_sym_expression_content_token1,
+    ACTIONS(982), 1,
+      anon_sym_RBRACE,
+    STATE(287), 1,
+      aux_sym_expression_content_repeat1,
+    STATE(303), 1,
+      sym_comment,
+    ACTIONS(654), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(350), 2,
+      sym_go_string_literal,
+      sym_nested_braces,
+    ACTIONS(835), 4,
+      aux_sym_go_string_literal_token1,
+      aux_sym_go_string_literal_token2,
+      aux_sym_go_string_literal_token3,
+      aux_sym_go_string_literal_token4,
+  [14511] = 8,
+    ACTIONS(984), 1,
+      ts_builtin_sym_end,
+    ACTIONS(986), 1,
+      anon_sym_templ,
+    ACTIONS(989), 1,
+      anon_sym_func,
+    ACTIONS(992), 1,
+      anon_sym_type,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(995), 2,
+      anon_sym_var,
+      anon_sym_const,
+    STATE(304), 2,
+      sym_comment,
+      aux_sym_source_file_repeat1,
+    STATE(366), 4,
+      sym_component_declaration,
+      sym_type_struct_declaration,
+      sym_function_declaration,
+      sym_go_declaration,
+  [14542] = 9,
     ACTIONS(11), 1,
       anon_sym_templ,
     ACTIONS(13), 1,
       anon_sym_func,
     ACTIONS(15), 1,
       anon_sym_type,
-    ACTIONS(258), 1,
+    ACTIONS(998), 1,
       ts_builtin_sym_end,
-    STATE(71), 1,
-      sym_comment,
-    STATE(86), 1,
+    STATE(304), 1,
       aux_sym_source_file_repeat1,
+    STATE(305), 1,
+      sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
     ACTIONS(17), 2,
       anon_sym_var,
       anon_sym_const,
-    STATE(180), 4,
+    STATE(366), 4,
       sym_component_declaration,
       sym_type_struct_declaration,
       sym_function_declaration,
       sym_go_declaration,
-  [2848] = 9,
-    ACTIONS(175), 1,
-      sym_identifier,
-    ACTIONS(177), 1,
+  [14575] = 8,
+    ACTIONS(1000), 1,
       anon_sym_LPAREN,
-    STATE(27), 1,
-      sym_selector_expression,
-    STATE(42), 1,
-      sym__expression,
-    STATE(72), 1,
+    ACTIONS(1003), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1005), 1,
+      anon_sym_SLASH,
+    ACTIONS(1011), 1,
+      aux_sym_go_paren_body_token1,
+    ACTIONS(654), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(306), 2,
+      sym_comment,
+      aux_sym_go_paren_body_repeat1,
+    STATE(348), 2,
+      sym_go_string_literal,
+      sym_nested_parens,
+    ACTIONS(1008), 4,
+      aux_sym_go_string_literal_token1,
+      aux_sym_go_string_literal_token2,
+      aux_sym_go_string_literal_token3,
+      aux_sym_go_string_literal_token4,
+  [14606] = 4,
+    STATE(307), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(179), 2,
-      sym_number,
-      sym_string,
-    ACTIONS(181), 2,
-      sym_true,
-      sym_false,
-    STATE(34), 3,
-      sym_binary_expression,
-      sym_call_expression,
-      sym_parenthesized_expression,
-  [2881] = 9,
-    ACTIONS(175), 1,
+    ACTIONS(797), 4,
+      sym_text_content,
+      anon_sym_LBRACE,
+      anon_sym_LT_SLASH,
+      anon_sym_AT,
+    ACTIONS(795), 7,
+      anon_sym_LT,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_else,
+      anon_sym_switch,
+      anon_sym_var,
       sym_identifier,
-    ACTIONS(177), 1,
-      anon_sym_LPAREN,
-    STATE(27), 1,
-      sym_selector_expression,
-    STATE(47), 1,
-      sym__expression,
-    STATE(73), 1,
+  [14629] = 4,
+    STATE(308), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(179), 2,
-      sym_number,
-      sym_string,
-    ACTIONS(181), 2,
-      sym_true,
-      sym_false,
-    STATE(34), 3,
-      sym_binary_expression,
-      sym_call_expression,
-      sym_parenthesized_expression,
-  [2914] = 5,
-    ACTIONS(272), 1,
+    ACTIONS(1016), 5,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_STAR,
+      anon_sym_LT_DASH,
+      anon_sym_RBRACE,
+    ACTIONS(1014), 6,
+      anon_sym_map,
+      anon_sym_func,
+      anon_sym_chan,
       anon_sym_struct,
-    STATE(74), 1,
+      anon_sym_interface,
+      sym_identifier,
+  [14652] = 3,
+    STATE(309), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(268), 3,
+    ACTIONS(821), 11,
       ts_builtin_sym_end,
-      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_templ,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_PIPE,
+      anon_sym_func,
       anon_sym_LBRACE,
-    ACTIONS(270), 7,
+      anon_sym_type,
+      anon_sym_var,
+      anon_sym_const,
+  [14673] = 3,
+    STATE(310), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(839), 11,
+      ts_builtin_sym_end,
+      anon_sym_RPAREN,
       anon_sym_templ,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_PIPE,
       anon_sym_func,
+      anon_sym_LBRACE,
       anon_sym_type,
       anon_sym_var,
-      sym_identifier,
       anon_sym_const,
-      aux_sym_go_declaration_token1,
-  [2939] = 9,
+  [14694] = 4,
+    STATE(311), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(790), 4,
+      sym_text_content,
+      anon_sym_LBRACE,
+      anon_sym_LT_SLASH,
+      anon_sym_AT,
+    ACTIONS(788), 7,
+      anon_sym_LT,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_else,
+      anon_sym_switch,
+      anon_sym_var,
+      sym_identifier,
+  [14717] = 9,
     ACTIONS(11), 1,
       anon_sym_templ,
     ACTIONS(13), 1,
       anon_sym_func,
     ACTIONS(15), 1,
       anon_sym_type,
-    ACTIONS(229), 1,
+    ACTIONS(764), 1,
       ts_builtin_sym_end,
-    STATE(75), 1,
-      sym_comment,
-    STATE(86), 1,
+    STATE(304), 1,
       aux_sym_source_file_repeat1,
+    STATE(312), 1,
+      sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
     ACTIONS(17), 2,
       anon_sym_var,
       anon_sym_const,
-    STATE(180), 4,
+    STATE(366), 4,
       sym_component_declaration,
       sym_type_struct_declaration,
       sym_function_declaration,
       sym_go_declaration,
-  [2972] = 9,
-    ACTIONS(260), 1,
+  [14750] = 4,
+    STATE(313), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(922), 4,
+      sym_text_content,
       anon_sym_LBRACE,
-    ACTIONS(264), 1,
-      aux_sym_expression_content_token1,
-    ACTIONS(274), 1,
-      anon_sym_RBRACE,
-    STATE(76), 1,
+      anon_sym_LT_SLASH,
+      anon_sym_AT,
+    ACTIONS(920), 6,
+      anon_sym_LT,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_var,
+      sym_identifier,
+  [14772] = 4,
+    STATE(314), 1,
       sym_comment,
-    STATE(103), 1,
-      aux_sym_expression_content_repeat1,
-    STATE(277), 1,
-      sym_go_code_content,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(161), 2,
-      sym_go_string_literal,
-      sym_nested_braces,
-    ACTIONS(266), 4,
-      aux_sym_go_string_literal_token1,
-      aux_sym_go_string_literal_token2,
-      aux_sym_go_string_literal_token3,
-      aux_sym_go_string_literal_token4,
-  [3005] = 9,
-    ACTIONS(260), 1,
+    ACTIONS(1020), 4,
+      sym_text_content,
       anon_sym_LBRACE,
-    ACTIONS(264), 1,
-      aux_sym_expression_content_token1,
-    ACTIONS(276), 1,
-      anon_sym_RBRACE,
-    STATE(77), 1,
+      anon_sym_LT_SLASH,
+      anon_sym_AT,
+    ACTIONS(1018), 6,
+      anon_sym_LT,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_var,
+      sym_identifier,
+  [14794] = 4,
+    STATE(315), 1,
       sym_comment,
-    STATE(103), 1,
-      aux_sym_expression_content_repeat1,
-    STATE(290), 1,
-      sym_go_code_content,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(161), 2,
-      sym_go_string_literal,
-      sym_nested_braces,
-    ACTIONS(266), 4,
-      aux_sym_go_string_literal_token1,
-      aux_sym_go_string_literal_token2,
-      aux_sym_go_string_literal_token3,
-      aux_sym_go_string_literal_token4,
-  [3038] = 9,
-    ACTIONS(175), 1,
+    ACTIONS(890), 4,
+      sym_text_content,
+      anon_sym_LBRACE,
+      anon_sym_LT_SLASH,
+      anon_sym_AT,
+    ACTIONS(888), 6,
+      anon_sym_LT,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_var,
       sym_identifier,
-    ACTIONS(177), 1,
-      anon_sym_LPAREN,
-    STATE(27), 1,
-      sym_selector_expression,
-    STATE(45), 1,
-      sym__expression,
-    STATE(78), 1,
+  [14816] = 4,
+    STATE(316), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(179), 2,
-      sym_number,
-      sym_string,
-    ACTIONS(181), 2,
-      sym_true,
-      sym_false,
-    STATE(34), 3,
-      sym_binary_expression,
-      sym_call_expression,
-      sym_parenthesized_expression,
-  [3071] = 9,
-    ACTIONS(260), 1,
+    ACTIONS(847), 4,
+      sym_text_content,
       anon_sym_LBRACE,
-    ACTIONS(264), 1,
-      aux_sym_expression_content_token1,
-    ACTIONS(278), 1,
-      anon_sym_children,
-    STATE(79), 1,
+      anon_sym_LT_SLASH,
+      anon_sym_AT,
+    ACTIONS(845), 6,
+      anon_sym_LT,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_var,
+      sym_identifier,
+  [14838] = 4,
+    STATE(317), 1,
       sym_comment,
-    STATE(94), 1,
-      aux_sym_expression_content_repeat1,
-    STATE(286), 1,
-      sym_expression_content,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(161), 2,
-      sym_go_string_literal,
-      sym_nested_braces,
-    ACTIONS(266), 4,
-      aux_sym_go_string_literal_token1,
-      aux_sym_go_string_literal_token2,
-      aux_sym_go_string_literal_token3,
-      aux_sym_go_string_literal_token4,
-  [3104] = 9,
-    ACTIONS(175), 1,
+    ACTIONS(886), 4,
+      sym_text_content,
+      anon_sym_LBRACE,
+      anon_sym_LT_SLASH,
+      anon_sym_AT,
+    ACTIONS(884), 6,
+      anon_sym_LT,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_var,
       sym_identifier,
-    ACTIONS(177), 1,
-      anon_sym_LPAREN,
-    STATE(27), 1,
-      sym_selector_expression,
-    STATE(40), 1,
-      sym__expression,
-    STATE(80), 1,
+  [14860] = 4,
+    STATE(318), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(179), 2,
-      sym_number,
-      sym_string,
-    ACTIONS(181), 2,
-      sym_true,
-      sym_false,
-    STATE(34), 3,
-      sym_binary_expression,
-      sym_call_expression,
-      sym_parenthesized_expression,
-  [3137] = 5,
-    ACTIONS(53), 2,
+    ACTIONS(859), 4,
+      sym_text_content,
+      anon_sym_LBRACE,
+      anon_sym_LT_SLASH,
+      anon_sym_AT,
+    ACTIONS(857), 6,
+      anon_sym_LT,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_var,
+      sym_identifier,
+  [14882] = 4,
+    STATE(319), 1,
+      sym_comment,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(282), 2,
+    ACTIONS(896), 4,
+      sym_text_content,
+      anon_sym_LBRACE,
+      anon_sym_LT_SLASH,
+      anon_sym_AT,
+    ACTIONS(894), 6,
+      anon_sym_LT,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_var,
       sym_identifier,
-      aux_sym_go_declaration_token1,
-    STATE(81), 2,
+  [14904] = 4,
+    STATE(320), 1,
       sym_comment,
-      aux_sym_go_declaration_repeat1,
-    ACTIONS(280), 3,
-      ts_builtin_sym_end,
-      anon_sym_LPAREN,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(900), 4,
+      sym_text_content,
       anon_sym_LBRACE,
-    ACTIONS(285), 5,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
+      anon_sym_LT_SLASH,
+      anon_sym_AT,
+    ACTIONS(898), 6,
+      anon_sym_LT,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
       anon_sym_var,
-      anon_sym_const,
-  [3162] = 9,
-    ACTIONS(11), 1,
-      anon_sym_templ,
-    ACTIONS(13), 1,
-      anon_sym_func,
-    ACTIONS(15), 1,
-      anon_sym_type,
-    ACTIONS(229), 1,
-      ts_builtin_sym_end,
-    STATE(71), 1,
-      aux_sym_source_file_repeat1,
-    STATE(82), 1,
+      sym_identifier,
+  [14926] = 4,
+    STATE(321), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(904), 4,
+      sym_text_content,
+      anon_sym_LBRACE,
+      anon_sym_LT_SLASH,
+      anon_sym_AT,
+    ACTIONS(902), 6,
+      anon_sym_LT,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_var,
+      sym_identifier,
+  [14948] = 4,
+    STATE(322), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(17), 2,
+    ACTIONS(102), 4,
+      sym_text_content,
+      anon_sym_LBRACE,
+      anon_sym_LT_SLASH,
+      anon_sym_AT,
+    ACTIONS(100), 6,
+      anon_sym_LT,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
       anon_sym_var,
-      anon_sym_const,
-    STATE(180), 4,
-      sym_component_declaration,
-      sym_type_struct_declaration,
-      sym_function_declaration,
-      sym_go_declaration,
-  [3195] = 9,
-    ACTIONS(175), 1,
       sym_identifier,
-    ACTIONS(177), 1,
-      anon_sym_LPAREN,
-    STATE(27), 1,
-      sym_selector_expression,
-    STATE(32), 1,
-      sym__expression,
-    STATE(83), 1,
+  [14970] = 4,
+    STATE(323), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(179), 2,
-      sym_number,
-      sym_string,
-    ACTIONS(181), 2,
-      sym_true,
-      sym_false,
-    STATE(34), 3,
-      sym_binary_expression,
-      sym_call_expression,
-      sym_parenthesized_expression,
-  [3228] = 9,
-    ACTIONS(175), 1,
+    ACTIONS(106), 4,
+      sym_text_content,
+      anon_sym_LBRACE,
+      anon_sym_LT_SLASH,
+      anon_sym_AT,
+    ACTIONS(104), 6,
+      anon_sym_LT,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_var,
       sym_identifier,
-    ACTIONS(177), 1,
-      anon_sym_LPAREN,
-    STATE(27), 1,
-      sym_selector_expression,
-    STATE(39), 1,
-      sym__expression,
-    STATE(84), 1,
+  [14992] = 4,
+    STATE(324), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(179), 2,
-      sym_number,
-      sym_string,
-    ACTIONS(181), 2,
-      sym_true,
-      sym_false,
-    STATE(34), 3,
-      sym_binary_expression,
-      sym_call_expression,
-      sym_parenthesized_expression,
-  [3261] = 9,
-    ACTIONS(11), 1,
-      anon_sym_templ,
-    ACTIONS(13), 1,
-      anon_sym_func,
-    ACTIONS(15), 1,
-      anon_sym_type,
-    ACTIONS(287), 1,
-      ts_builtin_sym_end,
-    STATE(85), 1,
+    ACTIONS(851), 4,
+      sym_text_content,
+      anon_sym_LBRACE,
+      anon_sym_LT_SLASH,
+      anon_sym_AT,
+    ACTIONS(849), 6,
+      anon_sym_LT,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_var,
+      sym_identifier,
+  [15014] = 4,
+    STATE(325), 1,
       sym_comment,
-    STATE(86), 1,
-      aux_sym_source_file_repeat1,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(17), 2,
+    ACTIONS(114), 4,
+      sym_text_content,
+      anon_sym_LBRACE,
+      anon_sym_LT_SLASH,
+      anon_sym_AT,
+    ACTIONS(112), 6,
+      anon_sym_LT,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
       anon_sym_var,
-      anon_sym_const,
-    STATE(180), 4,
-      sym_component_declaration,
-      sym_type_struct_declaration,
-      sym_function_declaration,
-      sym_go_declaration,
-  [3294] = 8,
-    ACTIONS(289), 1,
-      ts_builtin_sym_end,
-    ACTIONS(291), 1,
-      anon_sym_templ,
-    ACTIONS(294), 1,
-      anon_sym_func,
-    ACTIONS(297), 1,
-      anon_sym_type,
+      sym_identifier,
+  [15036] = 4,
+    STATE(326), 1,
+      sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(300), 2,
+    ACTIONS(855), 4,
+      sym_text_content,
+      anon_sym_LBRACE,
+      anon_sym_LT_SLASH,
+      anon_sym_AT,
+    ACTIONS(853), 6,
+      anon_sym_LT,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
       anon_sym_var,
-      anon_sym_const,
-    STATE(86), 2,
-      sym_comment,
-      aux_sym_source_file_repeat1,
-    STATE(180), 4,
-      sym_component_declaration,
-      sym_type_struct_declaration,
-      sym_function_declaration,
-      sym_go_declaration,
-  [3325] = 4,
-    STATE(87), 1,
+      sym_identifier,
+  [15058] = 4,
+    STATE(327), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(305), 3,
+    ACTIONS(863), 4,
+      sym_text_content,
       anon_sym_LBRACE,
       anon_sym_LT_SLASH,
       anon_sym_AT,
-    ACTIONS(303), 7,
+    ACTIONS(861), 6,
       anon_sym_LT,
-      sym_text_content,
       anon_sym_for,
       anon_sym_if,
-      anon_sym_else,
+      anon_sym_switch,
       anon_sym_var,
       sym_identifier,
-  [3347] = 8,
-    ACTIONS(307), 1,
-      anon_sym_LPAREN,
-    ACTIONS(309), 1,
-      anon_sym_RPAREN,
-    ACTIONS(313), 1,
-      aux_sym_go_paren_body_token1,
-    STATE(88), 1,
+  [15080] = 4,
+    STATE(328), 1,
       sym_comment,
-    STATE(100), 1,
-      aux_sym_go_paren_body_repeat1,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(156), 2,
-      sym_go_string_literal,
-      sym_nested_parens,
-    ACTIONS(311), 4,
-      aux_sym_go_string_literal_token1,
-      aux_sym_go_string_literal_token2,
-      aux_sym_go_string_literal_token3,
-      aux_sym_go_string_literal_token4,
-  [3377] = 8,
-    ACTIONS(307), 1,
-      anon_sym_LPAREN,
-    ACTIONS(313), 1,
-      aux_sym_go_paren_body_token1,
-    ACTIONS(315), 1,
-      anon_sym_RPAREN,
-    STATE(89), 1,
+    ACTIONS(118), 4,
+      sym_text_content,
+      anon_sym_LBRACE,
+      anon_sym_LT_SLASH,
+      anon_sym_AT,
+    ACTIONS(116), 6,
+      anon_sym_LT,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_var,
+      sym_identifier,
+  [15102] = 4,
+    STATE(329), 1,
       sym_comment,
-    STATE(101), 1,
-      aux_sym_go_paren_body_repeat1,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(156), 2,
-      sym_go_string_literal,
-      sym_nested_parens,
-    ACTIONS(311), 4,
-      aux_sym_go_string_literal_token1,
-      aux_sym_go_string_literal_token2,
-      aux_sym_go_string_literal_token3,
-      aux_sym_go_string_literal_token4,
-  [3407] = 4,
-    STATE(90), 1,
+    ACTIONS(75), 4,
+      sym_text_content,
+      anon_sym_LBRACE,
+      anon_sym_LT_SLASH,
+      anon_sym_AT,
+    ACTIONS(73), 6,
+      anon_sym_LT,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_var,
+      sym_identifier,
+  [15124] = 4,
+    STATE(330), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(268), 3,
-      ts_builtin_sym_end,
-      anon_sym_LPAREN,
+    ACTIONS(1024), 4,
+      sym_text_content,
       anon_sym_LBRACE,
-    ACTIONS(270), 7,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
+      anon_sym_LT_SLASH,
+      anon_sym_AT,
+    ACTIONS(1022), 6,
+      anon_sym_LT,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
       anon_sym_var,
       sym_identifier,
-      anon_sym_const,
-      aux_sym_go_declaration_token1,
-  [3429] = 8,
-    ACTIONS(260), 1,
-      anon_sym_LBRACE,
-    ACTIONS(264), 1,
-      aux_sym_expression_content_token1,
-    STATE(91), 1,
+  [15146] = 4,
+    STATE(331), 1,
       sym_comment,
-    STATE(94), 1,
-      aux_sym_expression_content_repeat1,
-    STATE(289), 1,
-      sym_expression_content,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(161), 2,
-      sym_go_string_literal,
-      sym_nested_braces,
-    ACTIONS(266), 4,
-      aux_sym_go_string_literal_token1,
-      aux_sym_go_string_literal_token2,
-      aux_sym_go_string_literal_token3,
-      aux_sym_go_string_literal_token4,
-  [3459] = 8,
-    ACTIONS(260), 1,
+    ACTIONS(908), 4,
+      sym_text_content,
       anon_sym_LBRACE,
-    ACTIONS(264), 1,
-      aux_sym_expression_content_token1,
-    ACTIONS(317), 1,
-      anon_sym_RBRACE,
-    STATE(92), 1,
+      anon_sym_LT_SLASH,
+      anon_sym_AT,
+    ACTIONS(906), 6,
+      anon_sym_LT,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_var,
+      sym_identifier,
+  [15168] = 4,
+    STATE(332), 1,
       sym_comment,
-    STATE(93), 1,
-      aux_sym_expression_content_repeat1,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(161), 2,
-      sym_go_string_literal,
-      sym_nested_braces,
-    ACTIONS(266), 4,
-      aux_sym_go_string_literal_token1,
-      aux_sym_go_string_literal_token2,
-      aux_sym_go_string_literal_token3,
-      aux_sym_go_string_literal_token4,
-  [3489] = 7,
-    ACTIONS(319), 1,
+    ACTIONS(916), 4,
+      sym_text_content,
       anon_sym_LBRACE,
-    ACTIONS(322), 1,
-      anon_sym_RBRACE,
-    ACTIONS(324), 1,
-      aux_sym_expression_content_token1,
-    ACTIONS(53), 2,
+      anon_sym_LT_SLASH,
+      anon_sym_AT,
+    ACTIONS(914), 6,
+      anon_sym_LT,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_var,
+      sym_identifier,
+  [15190] = 4,
+    STATE(333), 1,
+      sym_comment,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(93), 2,
-      sym_comment,
-      aux_sym_expression_content_repeat1,
-    STATE(161), 2,
-      sym_go_string_literal,
-      sym_nested_braces,
-    ACTIONS(327), 4,
-      aux_sym_go_string_literal_token1,
-      aux_sym_go_string_literal_token2,
-      aux_sym_go_string_literal_token3,
-      aux_sym_go_string_literal_token4,
-  [3517] = 8,
-    ACTIONS(260), 1,
-      anon_sym_LBRACE,
-    ACTIONS(264), 1,
-      aux_sym_expression_content_token1,
-    ACTIONS(330), 1,
-      anon_sym_RBRACE,
-    STATE(93), 1,
-      aux_sym_expression_content_repeat1,
-    STATE(94), 1,
+    ACTIONS(912), 4,
+      sym_text_content,
+      anon_sym_LBRACE,
+      anon_sym_LT_SLASH,
+      anon_sym_AT,
+    ACTIONS(910), 6,
+      anon_sym_LT,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_var,
+      sym_identifier,
+  [15212] = 4,
+    STATE(334), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(161), 2,
-      sym_go_string_literal,
-      sym_nested_braces,
-    ACTIONS(266), 4,
-      aux_sym_go_string_literal_token1,
-      aux_sym_go_string_literal_token2,
-      aux_sym_go_string_literal_token3,
-      aux_sym_go_string_literal_token4,
-  [3547] = 5,
-    ACTIONS(336), 1,
-      anon_sym_else,
-    STATE(95), 1,
+    ACTIONS(926), 4,
+      sym_text_content,
+      anon_sym_LBRACE,
+      anon_sym_LT_SLASH,
+      anon_sym_AT,
+    ACTIONS(924), 6,
+      anon_sym_LT,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_var,
+      sym_identifier,
+  [15234] = 4,
+    STATE(335), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(334), 3,
+    ACTIONS(843), 4,
+      sym_text_content,
       anon_sym_LBRACE,
       anon_sym_LT_SLASH,
       anon_sym_AT,
-    ACTIONS(332), 6,
+    ACTIONS(841), 6,
       anon_sym_LT,
-      sym_text_content,
       anon_sym_for,
       anon_sym_if,
+      anon_sym_switch,
       anon_sym_var,
       sym_identifier,
-  [3571] = 6,
-    ACTIONS(340), 1,
-      anon_sym_LBRACE,
-    STATE(96), 1,
+  [15256] = 4,
+    STATE(336), 1,
       sym_comment,
-    STATE(112), 1,
-      sym_block,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(342), 2,
+    ACTIONS(878), 4,
+      sym_text_content,
+      anon_sym_LBRACE,
       anon_sym_LT_SLASH,
       anon_sym_AT,
-    ACTIONS(338), 6,
+    ACTIONS(876), 6,
       anon_sym_LT,
-      sym_text_content,
       anon_sym_for,
       anon_sym_if,
+      anon_sym_switch,
       anon_sym_var,
       sym_identifier,
-  [3597] = 4,
-    STATE(97), 1,
+  [15278] = 4,
+    STATE(337), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(346), 3,
+    ACTIONS(882), 4,
+      sym_text_content,
       anon_sym_LBRACE,
       anon_sym_LT_SLASH,
       anon_sym_AT,
-    ACTIONS(344), 7,
+    ACTIONS(880), 6,
       anon_sym_LT,
+      anon_sym_for,
+      anon_sym_if,
+      anon_sym_switch,
+      anon_sym_var,
+      sym_identifier,
+  [15300] = 4,
+    STATE(338), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(110), 4,
       sym_text_content,
+      anon_sym_LBRACE,
+      anon_sym_LT_SLASH,
+      anon_sym_AT,
+    ACTIONS(108), 6,
+      anon_sym_LT,
       anon_sym_for,
       anon_sym_if,
-      anon_sym_else,
+      anon_sym_switch,
       anon_sym_var,
       sym_identifier,
-  [3619] = 8,
-    ACTIONS(307), 1,
-      anon_sym_LPAREN,
-    ACTIONS(313), 1,
-      aux_sym_go_paren_body_token1,
-    ACTIONS(348), 1,
+  [15322] = 5,
+    ACTIONS(762), 1,
+      anon_sym_DOT,
+    ACTIONS(1026), 1,
+      anon_sym_LBRACK,
+    STATE(339), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(349), 7,
       anon_sym_RPAREN,
-    STATE(89), 1,
-      aux_sym_go_paren_body_repeat1,
-    STATE(98), 1,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_PIPE,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      sym_identifier,
+  [15345] = 6,
+    ACTIONS(9), 1,
+      anon_sym_import,
+    STATE(340), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    STATE(341), 1,
+      aux_sym_import_section_repeat1,
+    STATE(354), 1,
+      sym_import_declaration,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(156), 2,
-      sym_go_string_literal,
-      sym_nested_parens,
-    ACTIONS(311), 4,
+    ACTIONS(1028), 6,
+      ts_builtin_sym_end,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
+      anon_sym_var,
+      anon_sym_const,
+  [15370] = 5,
+    ACTIONS(1032), 1,
+      anon_sym_import,
+    STATE(354), 1,
+      sym_import_declaration,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(341), 2,
+      sym_comment,
+      aux_sym_import_section_repeat1,
+    ACTIONS(1030), 6,
+      ts_builtin_sym_end,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
+      anon_sym_var,
+      anon_sym_const,
+  [15393] = 4,
+    ACTIONS(1037), 1,
+      aux_sym_expression_content_token1,
+    STATE(342), 1,
+      sym_comment,
+    ACTIONS(654), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(1035), 7,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SLASH,
       aux_sym_go_string_literal_token1,
       aux_sym_go_string_literal_token2,
       aux_sym_go_string_literal_token3,
       aux_sym_go_string_literal_token4,
-  [3649] = 4,
-    STATE(99), 1,
+  [15413] = 4,
+    ACTIONS(766), 1,
+      aux_sym_go_paren_body_token1,
+    STATE(343), 1,
       sym_comment,
-    ACTIONS(3), 2,
+    ACTIONS(654), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(350), 5,
-      anon_sym_SLASH,
-      anon_sym_for,
-      anon_sym_if,
-      anon_sym_var,
-      sym_identifier,
-    ACTIONS(352), 5,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      anon_sym_LT,
-      anon_sym_GT,
-      anon_sym_AT,
-  [3671] = 8,
-    ACTIONS(307), 1,
+    ACTIONS(768), 7,
       anon_sym_LPAREN,
-    ACTIONS(313), 1,
+      anon_sym_RPAREN,
+      anon_sym_SLASH,
+      aux_sym_go_string_literal_token1,
+      aux_sym_go_string_literal_token2,
+      aux_sym_go_string_literal_token3,
+      aux_sym_go_string_literal_token4,
+  [15433] = 4,
+    ACTIONS(1041), 1,
       aux_sym_go_paren_body_token1,
-    ACTIONS(354), 1,
+    STATE(344), 1,
+      sym_comment,
+    ACTIONS(654), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(1039), 7,
+      anon_sym_LPAREN,
       anon_sym_RPAREN,
-    STATE(100), 1,
+      anon_sym_SLASH,
+      aux_sym_go_string_literal_token1,
+      aux_sym_go_string_literal_token2,
+      aux_sym_go_string_literal_token3,
+      aux_sym_go_string_literal_token4,
+  [15453] = 4,
+    ACTIONS(1045), 1,
+      aux_sym_expression_content_token1,
+    STATE(345), 1,
       sym_comment,
-    STATE(101), 1,
-      aux_sym_go_paren_body_repeat1,
-    ACTIONS(53), 2,
+    ACTIONS(654), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(156), 2,
-      sym_go_string_literal,
-      sym_nested_parens,
-    ACTIONS(311), 4,
+    ACTIONS(1043), 7,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SLASH,
       aux_sym_go_string_literal_token1,
       aux_sym_go_string_literal_token2,
       aux_sym_go_string_literal_token3,
       aux_sym_go_string_literal_token4,
-  [3701] = 7,
-    ACTIONS(356), 1,
-      anon_sym_LPAREN,
-    ACTIONS(359), 1,
-      anon_sym_RPAREN,
-    ACTIONS(364), 1,
+  [15473] = 4,
+    ACTIONS(1049), 1,
       aux_sym_go_paren_body_token1,
-    ACTIONS(53), 2,
+    STATE(346), 1,
+      sym_comment,
+    ACTIONS(654), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(101), 2,
-      sym_comment,
-      aux_sym_go_paren_body_repeat1,
-    STATE(156), 2,
-      sym_go_string_literal,
-      sym_nested_parens,
-    ACTIONS(361), 4,
+    ACTIONS(1047), 7,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_SLASH,
       aux_sym_go_string_literal_token1,
       aux_sym_go_string_literal_token2,
       aux_sym_go_string_literal_token3,
       aux_sym_go_string_literal_token4,
-  [3729] = 8,
-    ACTIONS(260), 1,
-      anon_sym_LBRACE,
-    ACTIONS(264), 1,
+  [15493] = 4,
+    ACTIONS(766), 1,
       aux_sym_expression_content_token1,
-    ACTIONS(367), 1,
+    STATE(347), 1,
+      sym_comment,
+    ACTIONS(654), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(768), 7,
+      anon_sym_LBRACE,
       anon_sym_RBRACE,
-    STATE(92), 1,
-      aux_sym_expression_content_repeat1,
-    STATE(102), 1,
+      anon_sym_SLASH,
+      aux_sym_go_string_literal_token1,
+      aux_sym_go_string_literal_token2,
+      aux_sym_go_string_literal_token3,
+      aux_sym_go_string_literal_token4,
+  [15513] = 4,
+    ACTIONS(1053), 1,
+      aux_sym_go_paren_body_token1,
+    STATE(348), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(654), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(161), 2,
-      sym_go_string_literal,
-      sym_nested_braces,
-    ACTIONS(266), 4,
+    ACTIONS(1051), 7,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_SLASH,
       aux_sym_go_string_literal_token1,
       aux_sym_go_string_literal_token2,
       aux_sym_go_string_literal_token3,
       aux_sym_go_string_literal_token4,
-  [3759] = 8,
-    ACTIONS(260), 1,
+  [15533] = 4,
+    ACTIONS(1026), 1,
+      anon_sym_LBRACK,
+    STATE(349), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(349), 7,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_PIPE,
       anon_sym_LBRACE,
-    ACTIONS(264), 1,
-      aux_sym_expression_content_token1,
-    ACTIONS(369), 1,
       anon_sym_RBRACE,
-    STATE(93), 1,
-      aux_sym_expression_content_repeat1,
-    STATE(103), 1,
+      sym_identifier,
+  [15553] = 4,
+    ACTIONS(1057), 1,
+      aux_sym_expression_content_token1,
+    STATE(350), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(654), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(161), 2,
-      sym_go_string_literal,
-      sym_nested_braces,
-    ACTIONS(266), 4,
+    ACTIONS(1055), 7,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SLASH,
       aux_sym_go_string_literal_token1,
       aux_sym_go_string_literal_token2,
       aux_sym_go_string_literal_token3,
       aux_sym_go_string_literal_token4,
-  [3789] = 4,
-    STATE(104), 1,
+  [15573] = 3,
+    STATE(351), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(1059), 7,
+      ts_builtin_sym_end,
+      anon_sym_import,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
+      anon_sym_var,
+      anon_sym_const,
+  [15590] = 3,
+    STATE(352), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(1061), 7,
+      ts_builtin_sym_end,
+      anon_sym_import,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
+      anon_sym_var,
+      anon_sym_const,
+  [15607] = 3,
+    STATE(353), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(1063), 7,
+      ts_builtin_sym_end,
+      anon_sym_import,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
+      anon_sym_var,
+      anon_sym_const,
+  [15624] = 3,
+    STATE(354), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(1065), 7,
+      ts_builtin_sym_end,
+      anon_sym_import,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
+      anon_sym_var,
+      anon_sym_const,
+  [15641] = 3,
+    STATE(355), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(1067), 7,
+      ts_builtin_sym_end,
+      anon_sym_import,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
+      anon_sym_var,
+      anon_sym_const,
+  [15658] = 3,
+    STATE(356), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(373), 3,
-      anon_sym_LBRACE,
-      anon_sym_LT_SLASH,
-      anon_sym_AT,
-    ACTIONS(371), 6,
-      anon_sym_LT,
-      sym_text_content,
-      anon_sym_for,
-      anon_sym_if,
+    ACTIONS(1069), 7,
+      ts_builtin_sym_end,
+      anon_sym_import,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
       anon_sym_var,
-      sym_identifier,
-  [3810] = 4,
-    STATE(105), 1,
+      anon_sym_const,
+  [15675] = 4,
+    ACTIONS(1073), 1,
+      anon_sym_LPAREN,
+    STATE(357), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(377), 3,
-      anon_sym_LBRACE,
-      anon_sym_LT_SLASH,
-      anon_sym_AT,
-    ACTIONS(375), 6,
-      anon_sym_LT,
-      sym_text_content,
-      anon_sym_for,
-      anon_sym_if,
+    ACTIONS(1071), 6,
+      ts_builtin_sym_end,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
       anon_sym_var,
-      sym_identifier,
-  [3831] = 4,
-    STATE(106), 1,
+      anon_sym_const,
+  [15694] = 3,
+    STATE(358), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(381), 3,
-      anon_sym_LBRACE,
-      anon_sym_LT_SLASH,
-      anon_sym_AT,
-    ACTIONS(379), 6,
-      anon_sym_LT,
-      sym_text_content,
-      anon_sym_for,
-      anon_sym_if,
+    ACTIONS(1075), 7,
+      ts_builtin_sym_end,
+      anon_sym_import,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
       anon_sym_var,
-      sym_identifier,
-  [3852] = 4,
-    STATE(107), 1,
+      anon_sym_const,
+  [15711] = 3,
+    STATE(359), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(385), 3,
-      anon_sym_LBRACE,
-      anon_sym_LT_SLASH,
-      anon_sym_AT,
-    ACTIONS(383), 6,
-      anon_sym_LT,
-      sym_text_content,
-      anon_sym_for,
-      anon_sym_if,
+    ACTIONS(1077), 6,
+      ts_builtin_sym_end,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
       anon_sym_var,
-      sym_identifier,
-  [3873] = 6,
-    ACTIONS(220), 1,
-      anon_sym_LBRACE,
-    STATE(108), 1,
+      anon_sym_const,
+  [15727] = 3,
+    STATE(360), 1,
       sym_comment,
-    STATE(143), 1,
-      sym_block,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(342), 3,
-      anon_sym_RBRACE,
-      anon_sym_LT,
-      anon_sym_AT,
-    ACTIONS(338), 4,
-      anon_sym_for,
-      anon_sym_if,
+    ACTIONS(1079), 6,
+      ts_builtin_sym_end,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
       anon_sym_var,
-      sym_identifier,
-  [3898] = 4,
-    STATE(109), 1,
+      anon_sym_const,
+  [15743] = 3,
+    STATE(361), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(346), 3,
-      anon_sym_LBRACE,
-      anon_sym_LT_SLASH,
-      anon_sym_AT,
-    ACTIONS(344), 6,
-      anon_sym_LT,
-      sym_text_content,
-      anon_sym_for,
-      anon_sym_if,
+    ACTIONS(1081), 6,
+      ts_builtin_sym_end,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
       anon_sym_var,
-      sym_identifier,
-  [3919] = 4,
-    STATE(110), 1,
+      anon_sym_const,
+  [15759] = 3,
+    STATE(362), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(25), 3,
-      anon_sym_LBRACE,
-      anon_sym_LT_SLASH,
-      anon_sym_AT,
-    ACTIONS(23), 6,
-      anon_sym_LT,
-      sym_text_content,
-      anon_sym_for,
-      anon_sym_if,
+    ACTIONS(1083), 6,
+      ts_builtin_sym_end,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
       anon_sym_var,
-      sym_identifier,
-  [3940] = 4,
-    STATE(111), 1,
+      anon_sym_const,
+  [15775] = 3,
+    STATE(363), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(389), 3,
-      anon_sym_LBRACE,
-      anon_sym_LT_SLASH,
-      anon_sym_AT,
-    ACTIONS(387), 6,
-      anon_sym_LT,
-      sym_text_content,
-      anon_sym_for,
-      anon_sym_if,
+    ACTIONS(1085), 6,
+      ts_builtin_sym_end,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
       anon_sym_var,
-      sym_identifier,
-  [3961] = 4,
-    STATE(112), 1,
+      anon_sym_const,
+  [15791] = 3,
+    STATE(364), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(393), 3,
-      anon_sym_LBRACE,
-      anon_sym_LT_SLASH,
+    ACTIONS(1087), 6,
+      ts_builtin_sym_end,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
+      anon_sym_var,
+      anon_sym_const,
+  [15807] = 6,
+    ACTIONS(585), 1,
       anon_sym_AT,
-    ACTIONS(391), 6,
+    ACTIONS(1089), 1,
       anon_sym_LT,
-      sym_text_content,
-      anon_sym_for,
-      anon_sym_if,
-      anon_sym_var,
-      sym_identifier,
-  [3982] = 4,
-    STATE(113), 1,
+    STATE(365), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(397), 3,
-      anon_sym_LBRACE,
-      anon_sym_LT_SLASH,
-      anon_sym_AT,
-    ACTIONS(395), 6,
-      anon_sym_LT,
-      sym_text_content,
-      anon_sym_for,
-      anon_sym_if,
-      anon_sym_var,
-      sym_identifier,
-  [4003] = 4,
-    STATE(114), 1,
+    STATE(321), 2,
+      sym_element,
+      sym_component_call,
+    STATE(337), 2,
+      sym_self_closing_element,
+      sym_element_with_children,
+  [15829] = 3,
+    STATE(366), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(305), 3,
-      anon_sym_LBRACE,
-      anon_sym_LT_SLASH,
-      anon_sym_AT,
-    ACTIONS(303), 6,
-      anon_sym_LT,
-      sym_text_content,
-      anon_sym_for,
-      anon_sym_if,
+    ACTIONS(1091), 6,
+      ts_builtin_sym_end,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
       anon_sym_var,
-      sym_identifier,
-  [4024] = 4,
-    STATE(115), 1,
+      anon_sym_const,
+  [15845] = 3,
+    STATE(367), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(29), 3,
-      anon_sym_LBRACE,
-      anon_sym_LT_SLASH,
-      anon_sym_AT,
-    ACTIONS(27), 6,
-      anon_sym_LT,
-      sym_text_content,
-      anon_sym_for,
-      anon_sym_if,
+    ACTIONS(1093), 6,
+      ts_builtin_sym_end,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
       anon_sym_var,
-      sym_identifier,
-  [4045] = 4,
-    STATE(116), 1,
+      anon_sym_const,
+  [15861] = 3,
+    STATE(368), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(401), 3,
-      anon_sym_LBRACE,
-      anon_sym_LT_SLASH,
-      anon_sym_AT,
-    ACTIONS(399), 6,
-      anon_sym_LT,
-      sym_text_content,
-      anon_sym_for,
-      anon_sym_if,
+    ACTIONS(1095), 6,
+      ts_builtin_sym_end,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
       anon_sym_var,
-      sym_identifier,
-  [4066] = 4,
-    STATE(117), 1,
+      anon_sym_const,
+  [15877] = 7,
+    ACTIONS(1097), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1099), 1,
+      anon_sym_case,
+    ACTIONS(1101), 1,
+      anon_sym_default,
+    STATE(369), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    STATE(389), 1,
+      aux_sym_switch_statement_repeat1,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(405), 3,
-      anon_sym_LBRACE,
-      anon_sym_LT_SLASH,
-      anon_sym_AT,
-    ACTIONS(403), 6,
-      anon_sym_LT,
-      sym_text_content,
-      anon_sym_for,
-      anon_sym_if,
+    STATE(432), 2,
+      sym_case_clause,
+      sym_default_clause,
+  [15901] = 3,
+    STATE(370), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(1103), 6,
+      ts_builtin_sym_end,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
       anon_sym_var,
-      sym_identifier,
-  [4087] = 4,
-    STATE(118), 1,
+      anon_sym_const,
+  [15917] = 3,
+    STATE(371), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(33), 3,
-      anon_sym_LBRACE,
-      anon_sym_LT_SLASH,
-      anon_sym_AT,
-    ACTIONS(31), 6,
-      anon_sym_LT,
-      sym_text_content,
-      anon_sym_for,
-      anon_sym_if,
+    ACTIONS(1105), 6,
+      ts_builtin_sym_end,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
       anon_sym_var,
-      sym_identifier,
-  [4108] = 4,
-    STATE(119), 1,
+      anon_sym_const,
+  [15933] = 3,
+    STATE(372), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(409), 3,
-      anon_sym_LBRACE,
-      anon_sym_LT_SLASH,
-      anon_sym_AT,
-    ACTIONS(407), 6,
-      anon_sym_LT,
-      sym_text_content,
-      anon_sym_for,
-      anon_sym_if,
+    ACTIONS(1107), 6,
+      ts_builtin_sym_end,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
       anon_sym_var,
-      sym_identifier,
-  [4129] = 4,
-    STATE(120), 1,
+      anon_sym_const,
+  [15949] = 7,
+    ACTIONS(1099), 1,
+      anon_sym_case,
+    ACTIONS(1101), 1,
+      anon_sym_default,
+    ACTIONS(1109), 1,
+      anon_sym_RBRACE,
+    STATE(373), 1,
+      sym_comment,
+    STATE(389), 1,
+      aux_sym_switch_statement_repeat1,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(432), 2,
+      sym_case_clause,
+      sym_default_clause,
+  [15973] = 7,
+    ACTIONS(1099), 1,
+      anon_sym_case,
+    ACTIONS(1101), 1,
+      anon_sym_default,
+    ACTIONS(1111), 1,
+      anon_sym_RBRACE,
+    STATE(369), 1,
+      aux_sym_switch_statement_repeat1,
+    STATE(374), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(413), 3,
-      anon_sym_LBRACE,
-      anon_sym_LT_SLASH,
+    STATE(432), 2,
+      sym_case_clause,
+      sym_default_clause,
+  [15997] = 6,
+    ACTIONS(55), 1,
       anon_sym_AT,
-    ACTIONS(411), 6,
+    ACTIONS(439), 1,
       anon_sym_LT,
-      sym_text_content,
-      anon_sym_for,
-      anon_sym_if,
-      anon_sym_var,
-      sym_identifier,
-  [4150] = 4,
-    STATE(121), 1,
+    STATE(375), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(305), 4,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      anon_sym_LT,
-      anon_sym_AT,
-    ACTIONS(303), 5,
-      anon_sym_for,
-      anon_sym_if,
-      anon_sym_else,
-      anon_sym_var,
-      sym_identifier,
-  [4171] = 5,
-    ACTIONS(417), 1,
-      anon_sym_import,
-    STATE(163), 1,
-      sym_import_declaration,
+    STATE(269), 2,
+      sym_self_closing_element,
+      sym_element_with_children,
+    STATE(281), 2,
+      sym_element,
+      sym_component_call,
+  [16019] = 3,
+    STATE(376), 1,
+      sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(122), 2,
-      sym_comment,
-      aux_sym_import_section_repeat1,
-    ACTIONS(415), 6,
+    ACTIONS(1113), 6,
       ts_builtin_sym_end,
       anon_sym_templ,
       anon_sym_func,
       anon_sym_type,
       anon_sym_var,
       anon_sym_const,
-  [4194] = 4,
-    STATE(123), 1,
+  [16035] = 7,
+    ACTIONS(1099), 1,
+      anon_sym_case,
+    ACTIONS(1101), 1,
+      anon_sym_default,
+    ACTIONS(1115), 1,
+      anon_sym_RBRACE,
+    STATE(373), 1,
+      aux_sym_switch_statement_repeat1,
+    STATE(377), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(346), 4,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      anon_sym_LT,
-      anon_sym_AT,
-    ACTIONS(344), 5,
-      anon_sym_for,
-      anon_sym_if,
-      anon_sym_else,
-      anon_sym_var,
-      sym_identifier,
-  [4215] = 6,
-    ACTIONS(9), 1,
-      anon_sym_import,
-    STATE(122), 1,
-      aux_sym_import_section_repeat1,
-    STATE(124), 1,
+    STATE(432), 2,
+      sym_case_clause,
+      sym_default_clause,
+  [16059] = 3,
+    STATE(378), 1,
       sym_comment,
-    STATE(163), 1,
-      sym_import_declaration,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(420), 6,
+    ACTIONS(1117), 6,
       ts_builtin_sym_end,
       anon_sym_templ,
       anon_sym_func,
       anon_sym_type,
       anon_sym_var,
       anon_sym_const,
-  [4240] = 4,
-    STATE(125), 1,
+  [16075] = 3,
+    STATE(379), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(424), 3,
-      anon_sym_LBRACE,
-      anon_sym_LT_SLASH,
-      anon_sym_AT,
-    ACTIONS(422), 6,
-      anon_sym_LT,
-      sym_text_content,
-      anon_sym_for,
-      anon_sym_if,
+    ACTIONS(1119), 6,
+      ts_builtin_sym_end,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
       anon_sym_var,
-      sym_identifier,
-  [4261] = 4,
-    STATE(126), 1,
+      anon_sym_const,
+  [16091] = 7,
+    ACTIONS(1099), 1,
+      anon_sym_case,
+    ACTIONS(1101), 1,
+      anon_sym_default,
+    ACTIONS(1121), 1,
+      anon_sym_RBRACE,
+    STATE(380), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    STATE(382), 1,
+      aux_sym_switch_statement_repeat1,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(352), 3,
-      anon_sym_LBRACE,
-      anon_sym_LT_SLASH,
-      anon_sym_AT,
-    ACTIONS(350), 6,
-      anon_sym_LT,
-      sym_text_content,
-      anon_sym_for,
-      anon_sym_if,
-      anon_sym_var,
-      sym_identifier,
-  [4282] = 4,
-    STATE(127), 1,
+    STATE(432), 2,
+      sym_case_clause,
+      sym_default_clause,
+  [16115] = 3,
+    STATE(381), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(428), 3,
-      anon_sym_LBRACE,
-      anon_sym_LT_SLASH,
-      anon_sym_AT,
-    ACTIONS(426), 6,
-      anon_sym_LT,
-      sym_text_content,
-      anon_sym_for,
-      anon_sym_if,
+    ACTIONS(1123), 6,
+      ts_builtin_sym_end,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
       anon_sym_var,
-      sym_identifier,
-  [4303] = 4,
-    STATE(128), 1,
+      anon_sym_const,
+  [16131] = 7,
+    ACTIONS(1099), 1,
+      anon_sym_case,
+    ACTIONS(1101), 1,
+      anon_sym_default,
+    ACTIONS(1125), 1,
+      anon_sym_RBRACE,
+    STATE(382), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    STATE(389), 1,
+      aux_sym_switch_statement_repeat1,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(432), 3,
-      anon_sym_LBRACE,
-      anon_sym_LT_SLASH,
+    STATE(432), 2,
+      sym_case_clause,
+      sym_default_clause,
+  [16155] = 7,
+    ACTIONS(1099), 1,
+      anon_sym_case,
+    ACTIONS(1101), 1,
+      anon_sym_default,
+    ACTIONS(1127), 1,
+      anon_sym_RBRACE,
+    STATE(383), 1,
+      sym_comment,
+    STATE(385), 1,
+      aux_sym_switch_statement_repeat1,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(432), 2,
+      sym_case_clause,
+      sym_default_clause,
+  [16179] = 6,
+    ACTIONS(585), 1,
       anon_sym_AT,
-    ACTIONS(430), 6,
+    ACTIONS(1089), 1,
       anon_sym_LT,
-      sym_text_content,
-      anon_sym_for,
-      anon_sym_if,
-      anon_sym_var,
-      sym_identifier,
-  [4324] = 5,
-    ACTIONS(434), 1,
-      anon_sym_else,
-    STATE(129), 1,
+    STATE(384), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(332), 4,
-      anon_sym_for,
-      anon_sym_if,
-      anon_sym_var,
-      sym_identifier,
-    ACTIONS(334), 4,
-      anon_sym_LBRACE,
+    STATE(313), 2,
+      sym_element,
+      sym_component_call,
+    STATE(337), 2,
+      sym_self_closing_element,
+      sym_element_with_children,
+  [16201] = 7,
+    ACTIONS(1099), 1,
+      anon_sym_case,
+    ACTIONS(1101), 1,
+      anon_sym_default,
+    ACTIONS(1129), 1,
       anon_sym_RBRACE,
-      anon_sym_LT,
-      anon_sym_AT,
-  [4347] = 4,
-    STATE(130), 1,
+    STATE(385), 1,
       sym_comment,
+    STATE(389), 1,
+      aux_sym_switch_statement_repeat1,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(371), 4,
-      anon_sym_for,
-      anon_sym_if,
-      anon_sym_var,
-      sym_identifier,
-    ACTIONS(373), 4,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      anon_sym_LT,
-      anon_sym_AT,
-  [4367] = 4,
-    STATE(131), 1,
+    STATE(432), 2,
+      sym_case_clause,
+      sym_default_clause,
+  [16225] = 3,
+    STATE(386), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(379), 4,
-      anon_sym_for,
-      anon_sym_if,
+    ACTIONS(1131), 6,
+      ts_builtin_sym_end,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
       anon_sym_var,
-      sym_identifier,
-    ACTIONS(381), 4,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      anon_sym_LT,
-      anon_sym_AT,
-  [4387] = 4,
-    STATE(132), 1,
+      anon_sym_const,
+  [16241] = 3,
+    STATE(387), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(395), 4,
-      anon_sym_for,
-      anon_sym_if,
+    ACTIONS(1133), 6,
+      ts_builtin_sym_end,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
       anon_sym_var,
-      sym_identifier,
-    ACTIONS(397), 4,
+      anon_sym_const,
+  [16257] = 6,
+    ACTIONS(1135), 1,
       anon_sym_LBRACE,
+    STATE(388), 1,
+      sym_comment,
+    STATE(448), 1,
+      sym_go_expression,
+    STATE(453), 1,
+      sym__attribute_value,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(1137), 3,
+      sym_string,
+      sym_raw_string,
+      sym_number,
+  [16279] = 6,
+    ACTIONS(1139), 1,
       anon_sym_RBRACE,
-      anon_sym_LT,
-      anon_sym_AT,
-  [4407] = 4,
-    STATE(133), 1,
+    ACTIONS(1141), 1,
+      anon_sym_case,
+    ACTIONS(1144), 1,
+      anon_sym_default,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(389), 2,
+      sym_comment,
+      aux_sym_switch_statement_repeat1,
+    STATE(432), 2,
+      sym_case_clause,
+      sym_default_clause,
+  [16301] = 3,
+    STATE(390), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(436), 4,
-      anon_sym_for,
-      anon_sym_if,
+    ACTIONS(1147), 6,
+      ts_builtin_sym_end,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
       anon_sym_var,
-      sym_identifier,
-    ACTIONS(438), 4,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      anon_sym_LT,
-      anon_sym_AT,
-  [4427] = 4,
-    STATE(134), 1,
+      anon_sym_const,
+  [16317] = 3,
+    STATE(391), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(440), 4,
-      anon_sym_for,
-      anon_sym_if,
+    ACTIONS(1149), 6,
+      ts_builtin_sym_end,
+      anon_sym_templ,
+      anon_sym_func,
+      anon_sym_type,
       anon_sym_var,
+      anon_sym_const,
+  [16333] = 6,
+    ACTIONS(1151), 1,
+      sym_identifier,
+    ACTIONS(1154), 1,
+      anon_sym_SLASH,
+    ACTIONS(1156), 1,
+      anon_sym_GT,
+    STATE(429), 1,
+      sym_attribute,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(392), 2,
+      sym_comment,
+      aux_sym_self_closing_element_repeat1,
+  [16354] = 7,
+    ACTIONS(1158), 1,
       sym_identifier,
-    ACTIONS(442), 4,
+    ACTIONS(1160), 1,
+      anon_sym_SLASH,
+    ACTIONS(1162), 1,
+      anon_sym_GT,
+    STATE(392), 1,
+      aux_sym_self_closing_element_repeat1,
+    STATE(393), 1,
+      sym_comment,
+    STATE(429), 1,
+      sym_attribute,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+  [16377] = 7,
+    ACTIONS(57), 1,
       anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      anon_sym_LT,
-      anon_sym_AT,
-  [4447] = 4,
-    STATE(135), 1,
+    ACTIONS(252), 1,
+      anon_sym_RPAREN,
+    ACTIONS(254), 1,
+      anon_sym_COMMA,
+    STATE(90), 1,
+      sym_literal_value,
+    STATE(394), 1,
       sym_comment,
+    STATE(446), 1,
+      aux_sym_argument_list_repeat1,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(399), 4,
-      anon_sym_for,
-      anon_sym_if,
-      anon_sym_var,
+  [16400] = 7,
+    ACTIONS(1164), 1,
       sym_identifier,
-    ACTIONS(401), 4,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      anon_sym_LT,
-      anon_sym_AT,
-  [4467] = 4,
-    STATE(136), 1,
+    ACTIONS(1166), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1168), 1,
+      sym_string,
+    STATE(395), 1,
       sym_comment,
+    STATE(402), 1,
+      aux_sym_import_spec_list_repeat1,
+    STATE(434), 1,
+      sym_import_spec,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(403), 4,
-      anon_sym_for,
-      anon_sym_if,
-      anon_sym_var,
+  [16423] = 6,
+    ACTIONS(1170), 1,
       sym_identifier,
-    ACTIONS(405), 4,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      anon_sym_LT,
-      anon_sym_AT,
-  [4487] = 4,
-    STATE(137), 1,
+    ACTIONS(1172), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1174), 1,
+      sym_string,
+    STATE(396), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(422), 4,
-      anon_sym_for,
-      anon_sym_if,
-      anon_sym_var,
+    STATE(352), 2,
+      sym_import_spec_list,
+      sym_import_spec,
+  [16444] = 7,
+    ACTIONS(1158), 1,
       sym_identifier,
-    ACTIONS(424), 4,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      anon_sym_LT,
-      anon_sym_AT,
-  [4507] = 4,
-    STATE(138), 1,
+    ACTIONS(1176), 1,
+      anon_sym_SLASH,
+    ACTIONS(1178), 1,
+      anon_sym_GT,
+    STATE(393), 1,
+      aux_sym_self_closing_element_repeat1,
+    STATE(397), 1,
       sym_comment,
+    STATE(429), 1,
+      sym_attribute,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(407), 4,
-      anon_sym_for,
-      anon_sym_if,
-      anon_sym_var,
+  [16467] = 7,
+    ACTIONS(1164), 1,
       sym_identifier,
-    ACTIONS(409), 4,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      anon_sym_LT,
-      anon_sym_AT,
-  [4527] = 4,
-    STATE(139), 1,
+    ACTIONS(1168), 1,
+      sym_string,
+    ACTIONS(1180), 1,
+      anon_sym_RPAREN,
+    STATE(395), 1,
+      aux_sym_import_spec_list_repeat1,
+    STATE(398), 1,
       sym_comment,
+    STATE(434), 1,
+      sym_import_spec,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(383), 4,
-      anon_sym_for,
-      anon_sym_if,
-      anon_sym_var,
+  [16490] = 7,
+    ACTIONS(1158), 1,
       sym_identifier,
-    ACTIONS(385), 4,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      anon_sym_LT,
-      anon_sym_AT,
-  [4547] = 5,
-    ACTIONS(446), 1,
-      anon_sym_DOT,
-    ACTIONS(448), 1,
-      anon_sym_LBRACK,
-    STATE(140), 1,
+    ACTIONS(1182), 1,
+      anon_sym_SLASH,
+    ACTIONS(1184), 1,
+      anon_sym_GT,
+    STATE(392), 1,
+      aux_sym_self_closing_element_repeat1,
+    STATE(399), 1,
       sym_comment,
+    STATE(429), 1,
+      sym_attribute,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(444), 6,
+  [16513] = 7,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    ACTIONS(264), 1,
       anon_sym_RPAREN,
+    ACTIONS(266), 1,
       anon_sym_COMMA,
-      anon_sym_RBRACK,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      sym_identifier,
-  [4569] = 4,
-    STATE(141), 1,
+    STATE(90), 1,
+      sym_literal_value,
+    STATE(400), 1,
       sym_comment,
+    STATE(442), 1,
+      aux_sym_argument_list_repeat1,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(426), 4,
-      anon_sym_for,
-      anon_sym_if,
-      anon_sym_var,
-      sym_identifier,
-    ACTIONS(428), 4,
+  [16536] = 7,
+    ACTIONS(57), 1,
       anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      anon_sym_LT,
-      anon_sym_AT,
-  [4589] = 4,
-    STATE(142), 1,
+    ACTIONS(268), 1,
+      anon_sym_RPAREN,
+    ACTIONS(270), 1,
+      anon_sym_COMMA,
+    STATE(90), 1,
+      sym_literal_value,
+    STATE(401), 1,
       sym_comment,
+    STATE(451), 1,
+      aux_sym_argument_list_repeat1,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(387), 4,
-      anon_sym_for,
-      anon_sym_if,
-      anon_sym_var,
+  [16559] = 6,
+    ACTIONS(1186), 1,
       sym_identifier,
-    ACTIONS(389), 4,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      anon_sym_LT,
-      anon_sym_AT,
-  [4609] = 4,
-    STATE(143), 1,
-      sym_comment,
+    ACTIONS(1189), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1191), 1,
+      sym_string,
+    STATE(434), 1,
+      sym_import_spec,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(391), 4,
-      anon_sym_for,
-      anon_sym_if,
-      anon_sym_var,
+    STATE(402), 2,
+      sym_comment,
+      aux_sym_import_spec_list_repeat1,
+  [16580] = 7,
+    ACTIONS(1158), 1,
       sym_identifier,
-    ACTIONS(393), 4,
+    ACTIONS(1194), 1,
+      anon_sym_SLASH,
+    ACTIONS(1196), 1,
+      anon_sym_GT,
+    STATE(399), 1,
+      aux_sym_self_closing_element_repeat1,
+    STATE(403), 1,
+      sym_comment,
+    STATE(429), 1,
+      sym_attribute,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+  [16603] = 7,
+    ACTIONS(57), 1,
       anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      anon_sym_LT,
-      anon_sym_AT,
-  [4629] = 4,
-    STATE(144), 1,
+    ACTIONS(260), 1,
+      anon_sym_RPAREN,
+    ACTIONS(262), 1,
+      anon_sym_COMMA,
+    STATE(90), 1,
+      sym_literal_value,
+    STATE(404), 1,
       sym_comment,
+    STATE(454), 1,
+      aux_sym_argument_list_repeat1,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(411), 4,
-      anon_sym_for,
-      anon_sym_if,
-      anon_sym_var,
+  [16626] = 6,
+    ACTIONS(1198), 1,
       sym_identifier,
-    ACTIONS(413), 4,
-      anon_sym_LBRACE,
+    ACTIONS(1200), 1,
       anon_sym_RBRACE,
-      anon_sym_LT,
-      anon_sym_AT,
-  [4649] = 4,
-    STATE(145), 1,
+    STATE(405), 1,
       sym_comment,
+    STATE(407), 1,
+      aux_sym_struct_body_repeat1,
+    STATE(488), 1,
+      sym_struct_field,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(450), 3,
-      anon_sym_map,
-      anon_sym_func,
-      sym_identifier,
-    ACTIONS(452), 4,
+  [16646] = 6,
+    ACTIONS(757), 1,
       anon_sym_LPAREN,
+    ACTIONS(1202), 1,
       anon_sym_LBRACK,
-      anon_sym_STAR,
-      anon_sym_LBRACE,
-  [4668] = 3,
-    STATE(146), 1,
+    STATE(406), 1,
       sym_comment,
+    STATE(478), 1,
+      sym_type_parameter_list,
+    STATE(514), 1,
+      sym_parameter_list,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(454), 7,
-      ts_builtin_sym_end,
-      anon_sym_import,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
-      anon_sym_var,
-      anon_sym_const,
-  [4685] = 3,
-    STATE(147), 1,
+  [16666] = 6,
+    ACTIONS(1198), 1,
+      sym_identifier,
+    ACTIONS(1204), 1,
+      anon_sym_RBRACE,
+    STATE(407), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    STATE(410), 1,
+      aux_sym_struct_body_repeat1,
+    STATE(488), 1,
+      sym_struct_field,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(456), 7,
-      anon_sym_LPAREN,
-      anon_sym_RPAREN,
-      aux_sym_go_string_literal_token1,
-      aux_sym_go_string_literal_token2,
-      aux_sym_go_string_literal_token3,
-      aux_sym_go_string_literal_token4,
-      aux_sym_go_paren_body_token1,
-  [4702] = 3,
-    STATE(148), 1,
-      sym_comment,
+  [16686] = 4,
+    ACTIONS(1206), 1,
+      anon_sym_PIPE,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(458), 7,
-      ts_builtin_sym_end,
-      anon_sym_import,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
-      anon_sym_var,
-      anon_sym_const,
-  [4719] = 3,
-    STATE(149), 1,
+    ACTIONS(776), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+    STATE(408), 2,
+      sym_comment,
+      aux_sym_type_constraint_repeat1,
+  [16702] = 5,
+    ACTIONS(1211), 1,
+      anon_sym_SLASH,
+    ACTIONS(1213), 1,
+      anon_sym_EQ,
+    STATE(409), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(456), 7,
-      anon_sym_LBRACE,
+    ACTIONS(1209), 2,
+      anon_sym_GT,
+      sym_identifier,
+  [16720] = 5,
+    ACTIONS(1215), 1,
+      sym_identifier,
+    ACTIONS(1218), 1,
       anon_sym_RBRACE,
-      aux_sym_expression_content_token1,
-      aux_sym_go_string_literal_token1,
-      aux_sym_go_string_literal_token2,
-      aux_sym_go_string_literal_token3,
-      aux_sym_go_string_literal_token4,
-  [4736] = 3,
-    STATE(150), 1,
-      sym_comment,
+    STATE(488), 1,
+      sym_struct_field,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(460), 7,
-      ts_builtin_sym_end,
-      anon_sym_import,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
-      anon_sym_var,
-      anon_sym_const,
-  [4753] = 3,
-    STATE(151), 1,
+    STATE(410), 2,
+      sym_comment,
+      aux_sym_struct_body_repeat1,
+  [16738] = 6,
+    ACTIONS(1220), 1,
+      anon_sym_COMMA,
+    ACTIONS(1222), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1224), 1,
+      anon_sym_COLON,
+    STATE(411), 1,
       sym_comment,
+    STATE(458), 1,
+      aux_sym_literal_value_repeat1,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(462), 7,
-      ts_builtin_sym_end,
-      anon_sym_import,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
-      anon_sym_var,
-      anon_sym_const,
-  [4770] = 3,
-    STATE(152), 1,
+  [16758] = 5,
+    ACTIONS(369), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1226), 1,
+      anon_sym_if,
+    STATE(412), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(464), 7,
+    STATE(324), 2,
+      sym_if_statement,
+      sym_block,
+  [16776] = 5,
+    ACTIONS(284), 1,
       anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      aux_sym_expression_content_token1,
-      aux_sym_go_string_literal_token1,
-      aux_sym_go_string_literal_token2,
-      aux_sym_go_string_literal_token3,
-      aux_sym_go_string_literal_token4,
-  [4787] = 4,
-    STATE(153), 1,
+    STATE(84), 1,
+      sym_function_body,
+    STATE(413), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(466), 3,
-      anon_sym_map,
-      anon_sym_func,
-      sym_identifier,
-    ACTIONS(468), 4,
-      anon_sym_LPAREN,
-      anon_sym_LBRACK,
-      anon_sym_STAR,
+    ACTIONS(727), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+  [16794] = 5,
+    ACTIONS(57), 1,
       anon_sym_LBRACE,
-  [4806] = 3,
-    STATE(154), 1,
+    STATE(90), 1,
+      sym_literal_value,
+    STATE(414), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(470), 7,
+    ACTIONS(349), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+  [16812] = 6,
+    ACTIONS(757), 1,
       anon_sym_LPAREN,
-      anon_sym_RPAREN,
-      aux_sym_go_string_literal_token1,
-      aux_sym_go_string_literal_token2,
-      aux_sym_go_string_literal_token3,
-      aux_sym_go_string_literal_token4,
-      aux_sym_go_paren_body_token1,
-  [4823] = 4,
-    STATE(155), 1,
+    ACTIONS(1202), 1,
+      anon_sym_LBRACK,
+    STATE(145), 1,
+      sym_parameter_list,
+    STATE(415), 1,
       sym_comment,
+    STATE(503), 1,
+      sym_type_parameter_list,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(472), 3,
-      anon_sym_map,
-      anon_sym_func,
+  [16832] = 6,
+    ACTIONS(1198), 1,
       sym_identifier,
-    ACTIONS(474), 4,
-      anon_sym_LPAREN,
-      anon_sym_LBRACK,
-      anon_sym_STAR,
-      anon_sym_LBRACE,
-  [4842] = 3,
-    STATE(156), 1,
+    ACTIONS(1228), 1,
+      anon_sym_RBRACE,
+    STATE(410), 1,
+      aux_sym_struct_body_repeat1,
+    STATE(416), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    STATE(488), 1,
+      sym_struct_field,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(476), 7,
-      anon_sym_LPAREN,
-      anon_sym_RPAREN,
-      aux_sym_go_string_literal_token1,
-      aux_sym_go_string_literal_token2,
-      aux_sym_go_string_literal_token3,
-      aux_sym_go_string_literal_token4,
-      aux_sym_go_paren_body_token1,
-  [4859] = 4,
-    ACTIONS(448), 1,
-      anon_sym_LBRACK,
-    STATE(157), 1,
-      sym_comment,
+  [16852] = 4,
+    ACTIONS(1232), 1,
+      anon_sym_COMMA,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(444), 6,
+    ACTIONS(1230), 2,
       anon_sym_RPAREN,
-      anon_sym_COMMA,
       anon_sym_RBRACK,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
+    STATE(417), 2,
+      sym_comment,
+      aux_sym_generic_type_repeat1,
+  [16868] = 6,
+    ACTIONS(1198), 1,
       sym_identifier,
-  [4878] = 3,
-    STATE(158), 1,
+    ACTIONS(1235), 1,
+      anon_sym_RBRACE,
+    STATE(416), 1,
+      aux_sym_struct_body_repeat1,
+    STATE(418), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    STATE(488), 1,
+      sym_struct_field,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(478), 7,
-      anon_sym_LPAREN,
-      anon_sym_RPAREN,
-      aux_sym_go_string_literal_token1,
-      aux_sym_go_string_literal_token2,
-      aux_sym_go_string_literal_token3,
-      aux_sym_go_string_literal_token4,
-      aux_sym_go_paren_body_token1,
-  [4895] = 3,
-    STATE(159), 1,
+  [16888] = 5,
+    ACTIONS(1237), 1,
+      anon_sym_PIPE,
+    STATE(419), 1,
       sym_comment,
+    STATE(420), 1,
+      aux_sym_type_constraint_repeat1,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(480), 7,
-      anon_sym_RPAREN,
+    ACTIONS(801), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+  [16906] = 5,
+    ACTIONS(1237), 1,
+      anon_sym_PIPE,
+    STATE(408), 1,
+      aux_sym_type_constraint_repeat1,
+    STATE(420), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(780), 2,
       anon_sym_COMMA,
-      anon_sym_LBRACK,
       anon_sym_RBRACK,
+  [16924] = 5,
+    ACTIONS(383), 1,
       anon_sym_LBRACE,
+    ACTIONS(1239), 1,
+      anon_sym_if,
+    STATE(421), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(256), 2,
+      sym_if_statement,
+      sym_block,
+  [16942] = 4,
+    ACTIONS(1241), 1,
+      anon_sym_COMMA,
+    ACTIONS(1244), 1,
       anon_sym_RBRACE,
-      sym_identifier,
-  [4912] = 3,
-    STATE(160), 1,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(422), 2,
+      sym_comment,
+      aux_sym_literal_value_repeat1,
+  [16957] = 5,
+    ACTIONS(338), 1,
+      anon_sym_COMMA,
+    ACTIONS(1246), 1,
+      anon_sym_COLON,
+    STATE(423), 1,
       sym_comment,
+    STATE(435), 1,
+      aux_sym_case_clause_repeat1,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(482), 7,
-      ts_builtin_sym_end,
-      anon_sym_import,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
-      anon_sym_var,
-      anon_sym_const,
-  [4929] = 3,
-    STATE(161), 1,
+  [16974] = 5,
+    ACTIONS(1248), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1250), 1,
+      anon_sym_COMMA,
+    STATE(424), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    STATE(447), 1,
+      aux_sym_parameter_list_repeat1,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(484), 7,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      aux_sym_expression_content_token1,
-      aux_sym_go_string_literal_token1,
-      aux_sym_go_string_literal_token2,
-      aux_sym_go_string_literal_token3,
-      aux_sym_go_string_literal_token4,
-  [4946] = 4,
-    STATE(162), 1,
+  [16991] = 5,
+    ACTIONS(1252), 1,
+      anon_sym_COMMA,
+    ACTIONS(1254), 1,
+      anon_sym_RBRACK,
+    STATE(417), 1,
+      aux_sym_generic_type_repeat1,
+    STATE(425), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(486), 3,
-      anon_sym_map,
-      anon_sym_func,
+  [17008] = 5,
+    ACTIONS(1256), 1,
       sym_identifier,
-    ACTIONS(488), 4,
-      anon_sym_LPAREN,
-      anon_sym_LBRACK,
-      anon_sym_STAR,
-      anon_sym_LBRACE,
-  [4965] = 3,
-    STATE(163), 1,
+    ACTIONS(1258), 1,
+      anon_sym_RBRACK,
+    STATE(426), 1,
       sym_comment,
+    STATE(491), 1,
+      sym_type_parameter_declaration,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(490), 7,
-      ts_builtin_sym_end,
-      anon_sym_import,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
-      anon_sym_var,
-      anon_sym_const,
-  [4982] = 3,
-    STATE(164), 1,
+  [17025] = 5,
+    ACTIONS(1252), 1,
+      anon_sym_COMMA,
+    ACTIONS(1260), 1,
+      anon_sym_RPAREN,
+    STATE(427), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    STATE(439), 1,
+      aux_sym_generic_type_repeat1,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(492), 7,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      aux_sym_expression_content_token1,
-      aux_sym_go_string_literal_token1,
-      aux_sym_go_string_literal_token2,
-      aux_sym_go_string_literal_token3,
-      aux_sym_go_string_literal_token4,
-  [4999] = 4,
-    ACTIONS(496), 1,
-      anon_sym_LPAREN,
-    STATE(165), 1,
-      sym_comment,
+  [17042] = 4,
+    ACTIONS(1262), 1,
+      anon_sym_COMMA,
+    ACTIONS(1265), 1,
+      anon_sym_RBRACK,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(494), 6,
-      ts_builtin_sym_end,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
-      anon_sym_var,
-      anon_sym_const,
-  [5018] = 3,
-    STATE(166), 1,
+    STATE(428), 2,
+      sym_comment,
+      aux_sym_type_parameter_list_repeat1,
+  [17057] = 4,
+    ACTIONS(1269), 1,
+      anon_sym_SLASH,
+    STATE(429), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(498), 7,
-      ts_builtin_sym_end,
-      anon_sym_import,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
-      anon_sym_var,
-      anon_sym_const,
-  [5035] = 3,
-    STATE(167), 1,
+    ACTIONS(1267), 2,
+      anon_sym_GT,
+      sym_identifier,
+  [17072] = 5,
+    ACTIONS(1271), 1,
+      anon_sym_COMMA,
+    ACTIONS(1273), 1,
+      anon_sym_RBRACK,
+    STATE(430), 1,
       sym_comment,
+    STATE(456), 1,
+      aux_sym_type_parameter_list_repeat1,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(500), 6,
-      ts_builtin_sym_end,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
-      anon_sym_var,
-      anon_sym_const,
-  [5051] = 3,
-    STATE(168), 1,
-      sym_comment,
+  [17089] = 4,
+    ACTIONS(357), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1275), 1,
+      anon_sym_COMMA,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(502), 6,
-      ts_builtin_sym_end,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
-      anon_sym_var,
-      anon_sym_const,
-  [5067] = 3,
-    STATE(169), 1,
+    STATE(431), 2,
+      sym_comment,
+      aux_sym_argument_list_repeat1,
+  [17104] = 3,
+    STATE(432), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(504), 6,
-      ts_builtin_sym_end,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
-      anon_sym_var,
-      anon_sym_const,
-  [5083] = 3,
-    STATE(170), 1,
+    ACTIONS(1278), 3,
+      anon_sym_RBRACE,
+      anon_sym_case,
+      anon_sym_default,
+  [17117] = 4,
+    ACTIONS(845), 1,
+      anon_sym_SLASH,
+    STATE(433), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(506), 6,
-      ts_builtin_sym_end,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
-      anon_sym_var,
-      anon_sym_const,
-  [5099] = 3,
-    STATE(171), 1,
+    ACTIONS(847), 2,
+      anon_sym_GT,
+      sym_identifier,
+  [17132] = 3,
+    STATE(434), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(508), 6,
-      ts_builtin_sym_end,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
-      anon_sym_var,
-      anon_sym_const,
-  [5115] = 3,
-    STATE(172), 1,
-      sym_comment,
+    ACTIONS(1280), 3,
+      sym_string,
+      anon_sym_RPAREN,
+      sym_identifier,
+  [17145] = 4,
+    ACTIONS(1282), 1,
+      anon_sym_COMMA,
+    ACTIONS(1285), 1,
+      anon_sym_COLON,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(510), 6,
-      ts_builtin_sym_end,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
-      anon_sym_var,
-      anon_sym_const,
-  [5131] = 3,
-    STATE(173), 1,
+    STATE(435), 2,
+      sym_comment,
+      aux_sym_case_clause_repeat1,
+  [17160] = 3,
+    STATE(436), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(512), 6,
+    ACTIONS(1230), 3,
       anon_sym_RPAREN,
       anon_sym_COMMA,
       anon_sym_RBRACK,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      sym_identifier,
-  [5147] = 3,
-    STATE(174), 1,
+  [17173] = 5,
+    ACTIONS(1287), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1289), 1,
+      anon_sym_DOT,
+    STATE(246), 1,
+      sym_argument_list,
+    STATE(437), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(514), 6,
-      ts_builtin_sym_end,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
-      anon_sym_var,
-      anon_sym_const,
-  [5163] = 3,
-    STATE(175), 1,
+  [17190] = 5,
+    ACTIONS(1291), 1,
+      sym_identifier,
+    ACTIONS(1293), 1,
+      anon_sym_LPAREN,
+    STATE(438), 1,
       sym_comment,
+    STATE(551), 1,
+      sym_receiver,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(516), 6,
-      ts_builtin_sym_end,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
-      anon_sym_var,
-      anon_sym_const,
-  [5179] = 3,
-    STATE(176), 1,
+  [17207] = 5,
+    ACTIONS(1252), 1,
+      anon_sym_COMMA,
+    ACTIONS(1295), 1,
+      anon_sym_RPAREN,
+    STATE(417), 1,
+      aux_sym_generic_type_repeat1,
+    STATE(439), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(518), 6,
-      anon_sym_RPAREN,
-      anon_sym_COMMA,
-      anon_sym_RBRACK,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
+  [17224] = 5,
+    ACTIONS(1293), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1297), 1,
       sym_identifier,
-  [5195] = 3,
-    STATE(177), 1,
+    STATE(440), 1,
       sym_comment,
+    STATE(548), 1,
+      sym_receiver,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(520), 6,
-      ts_builtin_sym_end,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
-      anon_sym_var,
-      anon_sym_const,
-  [5211] = 3,
-    STATE(178), 1,
+  [17241] = 3,
+    STATE(441), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(522), 6,
+    ACTIONS(1069), 3,
+      sym_string,
       anon_sym_RPAREN,
-      anon_sym_COMMA,
-      anon_sym_RBRACK,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
       sym_identifier,
-  [5227] = 6,
-    ACTIONS(51), 1,
-      anon_sym_AT,
-    ACTIONS(524), 1,
-      anon_sym_LT,
-    STATE(179), 1,
+  [17254] = 5,
+    ACTIONS(90), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1299), 1,
+      anon_sym_COMMA,
+    STATE(431), 1,
+      aux_sym_argument_list_repeat1,
+    STATE(442), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(106), 2,
-      sym_element,
-      sym_component_call,
-    STATE(125), 2,
-      sym_self_closing_element,
-      sym_element_with_children,
-  [5249] = 3,
-    STATE(180), 1,
+  [17271] = 5,
+    ACTIONS(1252), 1,
+      anon_sym_COMMA,
+    ACTIONS(1301), 1,
+      anon_sym_RBRACK,
+    STATE(425), 1,
+      aux_sym_generic_type_repeat1,
+    STATE(443), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(526), 6,
-      ts_builtin_sym_end,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
-      anon_sym_var,
-      anon_sym_const,
-  [5265] = 3,
-    STATE(181), 1,
+  [17288] = 5,
+    ACTIONS(1303), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1305), 1,
+      anon_sym_DOT,
+    STATE(302), 1,
+      sym_argument_list,
+    STATE(444), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(528), 6,
-      ts_builtin_sym_end,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
-      anon_sym_var,
-      anon_sym_const,
-  [5281] = 3,
-    STATE(182), 1,
+  [17305] = 3,
+    STATE(445), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(530), 6,
-      ts_builtin_sym_end,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
-      anon_sym_var,
-      anon_sym_const,
-  [5297] = 3,
-    STATE(183), 1,
+    ACTIONS(1075), 3,
+      sym_string,
+      anon_sym_RPAREN,
+      sym_identifier,
+  [17318] = 5,
+    ACTIONS(122), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1307), 1,
+      anon_sym_COMMA,
+    STATE(431), 1,
+      aux_sym_argument_list_repeat1,
+    STATE(446), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(532), 6,
-      ts_builtin_sym_end,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
-      anon_sym_var,
-      anon_sym_const,
-  [5313] = 3,
-    STATE(184), 1,
+  [17335] = 5,
+    ACTIONS(534), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1309), 1,
+      anon_sym_COMMA,
+    STATE(447), 1,
       sym_comment,
+    STATE(457), 1,
+      aux_sym_parameter_list_repeat1,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(534), 6,
-      ts_builtin_sym_end,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
-      anon_sym_var,
-      anon_sym_const,
-  [5329] = 3,
-    STATE(185), 1,
+  [17352] = 4,
+    ACTIONS(1313), 1,
+      anon_sym_SLASH,
+    STATE(448), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(536), 6,
-      ts_builtin_sym_end,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
-      anon_sym_var,
-      anon_sym_const,
-  [5345] = 3,
-    STATE(186), 1,
+    ACTIONS(1311), 2,
+      anon_sym_GT,
+      sym_identifier,
+  [17367] = 3,
+    STATE(449), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(538), 6,
-      ts_builtin_sym_end,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
-      anon_sym_var,
-      anon_sym_const,
-  [5361] = 3,
-    STATE(187), 1,
+    ACTIONS(359), 3,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+  [17380] = 4,
+    ACTIONS(1224), 1,
+      anon_sym_COLON,
+    STATE(450), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(540), 6,
-      anon_sym_RPAREN,
+    ACTIONS(1244), 2,
       anon_sym_COMMA,
-      anon_sym_RBRACK,
-      anon_sym_LBRACE,
       anon_sym_RBRACE,
-      sym_identifier,
-  [5377] = 3,
-    STATE(188), 1,
+  [17395] = 5,
+    ACTIONS(128), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1315), 1,
+      anon_sym_COMMA,
+    STATE(431), 1,
+      aux_sym_argument_list_repeat1,
+    STATE(451), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(542), 6,
-      ts_builtin_sym_end,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
-      anon_sym_var,
-      anon_sym_const,
-  [5393] = 3,
-    STATE(189), 1,
+  [17412] = 5,
+    ACTIONS(1220), 1,
+      anon_sym_COMMA,
+    ACTIONS(1222), 1,
+      anon_sym_RBRACE,
+    STATE(452), 1,
       sym_comment,
+    STATE(458), 1,
+      aux_sym_literal_value_repeat1,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(544), 6,
-      ts_builtin_sym_end,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
-      anon_sym_var,
-      anon_sym_const,
-  [5409] = 3,
-    STATE(190), 1,
+  [17429] = 4,
+    ACTIONS(1319), 1,
+      anon_sym_SLASH,
+    STATE(453), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(546), 6,
+    ACTIONS(1317), 2,
+      anon_sym_GT,
+      sym_identifier,
+  [17444] = 5,
+    ACTIONS(134), 1,
       anon_sym_RPAREN,
+    ACTIONS(1321), 1,
       anon_sym_COMMA,
-      anon_sym_RBRACK,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
+    STATE(431), 1,
+      aux_sym_argument_list_repeat1,
+    STATE(454), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+  [17461] = 5,
+    ACTIONS(1256), 1,
       sym_identifier,
-  [5425] = 3,
-    STATE(191), 1,
+    ACTIONS(1323), 1,
+      anon_sym_RBRACK,
+    STATE(455), 1,
       sym_comment,
+    STATE(491), 1,
+      sym_type_parameter_declaration,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(159), 6,
-      anon_sym_RPAREN,
-      anon_sym_COMMA,
+  [17478] = 5,
+    ACTIONS(1323), 1,
       anon_sym_RBRACK,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      sym_identifier,
-  [5441] = 3,
-    STATE(192), 1,
+    ACTIONS(1325), 1,
+      anon_sym_COMMA,
+    STATE(428), 1,
+      aux_sym_type_parameter_list_repeat1,
+    STATE(456), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(548), 6,
+  [17495] = 4,
+    ACTIONS(1327), 1,
       anon_sym_RPAREN,
+    ACTIONS(1329), 1,
       anon_sym_COMMA,
-      anon_sym_RBRACK,
-      anon_sym_LBRACE,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    STATE(457), 2,
+      sym_comment,
+      aux_sym_parameter_list_repeat1,
+  [17510] = 5,
+    ACTIONS(61), 1,
       anon_sym_RBRACE,
-      sym_identifier,
-  [5457] = 3,
-    STATE(193), 1,
+    ACTIONS(1332), 1,
+      anon_sym_COMMA,
+    STATE(422), 1,
+      aux_sym_literal_value_repeat1,
+    STATE(458), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(550), 6,
-      ts_builtin_sym_end,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
-      anon_sym_var,
-      anon_sym_const,
-  [5473] = 6,
-    ACTIONS(51), 1,
-      anon_sym_AT,
-    ACTIONS(524), 1,
-      anon_sym_LT,
-    STATE(194), 1,
+  [17527] = 4,
+    ACTIONS(284), 1,
+      anon_sym_LBRACE,
+    STATE(371), 1,
+      sym_function_body,
+    STATE(459), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(111), 2,
-      sym_element,
-      sym_component_call,
-    STATE(125), 2,
-      sym_self_closing_element,
-      sym_element_with_children,
-  [5495] = 3,
-    STATE(195), 1,
+  [17541] = 4,
+    ACTIONS(757), 1,
+      anon_sym_LPAREN,
+    STATE(100), 1,
+      sym_parameter_list,
+    STATE(460), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(444), 6,
-      anon_sym_RPAREN,
-      anon_sym_COMMA,
-      anon_sym_RBRACK,
+  [17555] = 4,
+    ACTIONS(284), 1,
       anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      sym_identifier,
-  [5511] = 6,
-    ACTIONS(97), 1,
-      anon_sym_LT,
-    ACTIONS(105), 1,
-      anon_sym_AT,
-    STATE(196), 1,
+    STATE(362), 1,
+      sym_function_body,
+    STATE(461), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(137), 2,
-      sym_self_closing_element,
-      sym_element_with_children,
-    STATE(142), 2,
-      sym_element,
-      sym_component_call,
-  [5533] = 3,
-    STATE(197), 1,
+  [17569] = 4,
+    ACTIONS(1256), 1,
+      sym_identifier,
+    STATE(462), 1,
       sym_comment,
+    STATE(491), 1,
+      sym_type_parameter_declaration,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(552), 6,
-      ts_builtin_sym_end,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
-      anon_sym_var,
-      anon_sym_const,
-  [5549] = 3,
-    STATE(198), 1,
+  [17583] = 3,
+    STATE(463), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(554), 6,
+    ACTIONS(1334), 2,
       anon_sym_RPAREN,
       anon_sym_COMMA,
-      anon_sym_RBRACK,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      sym_identifier,
-  [5565] = 3,
-    STATE(199), 1,
+  [17595] = 3,
+    STATE(464), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(556), 6,
-      anon_sym_RPAREN,
+    ACTIONS(1336), 2,
       anon_sym_COMMA,
       anon_sym_RBRACK,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      sym_identifier,
-  [5581] = 3,
-    STATE(200), 1,
+  [17607] = 3,
+    STATE(465), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(169), 6,
+    ACTIONS(1338), 2,
       anon_sym_RPAREN,
       anon_sym_COMMA,
-      anon_sym_RBRACK,
+  [17619] = 4,
+    ACTIONS(369), 1,
       anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      sym_identifier,
-  [5597] = 3,
-    STATE(201), 1,
+    STATE(317), 1,
+      sym_block,
+    STATE(466), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(558), 6,
-      anon_sym_RPAREN,
+  [17633] = 4,
+    ACTIONS(1340), 1,
       anon_sym_COMMA,
-      anon_sym_RBRACK,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      sym_identifier,
-  [5613] = 3,
-    STATE(202), 1,
+    ACTIONS(1342), 1,
+      anon_sym_COLON_EQ,
+    STATE(467), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(560), 6,
-      ts_builtin_sym_end,
-      anon_sym_templ,
-      anon_sym_func,
-      anon_sym_type,
-      anon_sym_var,
-      anon_sym_const,
-  [5629] = 7,
-    ACTIONS(562), 1,
-      sym_identifier,
-    ACTIONS(564), 1,
-      anon_sym_SLASH,
-    ACTIONS(566), 1,
-      anon_sym_GT,
-    STATE(203), 1,
+  [17647] = 4,
+    ACTIONS(383), 1,
+      anon_sym_LBRACE,
+    STATE(270), 1,
+      sym_block,
+    STATE(468), 1,
       sym_comment,
-    STATE(212), 1,
-      aux_sym_self_closing_element_repeat1,
-    STATE(230), 1,
-      sym_attribute,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [5652] = 7,
-    ACTIONS(250), 1,
-      aux_sym_go_declaration_token1,
-    ACTIONS(568), 1,
-      sym_identifier,
-    ACTIONS(570), 1,
-      anon_sym_LPAREN,
-    STATE(66), 1,
-      aux_sym_go_declaration_repeat1,
-    STATE(197), 1,
-      sym_go_paren_body,
-    STATE(204), 1,
+  [17661] = 4,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    STATE(90), 1,
+      sym_literal_value,
+    STATE(469), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [5675] = 6,
-    ACTIONS(572), 1,
-      sym_identifier,
-    ACTIONS(574), 1,
+  [17675] = 4,
+    ACTIONS(1287), 1,
       anon_sym_LPAREN,
-    ACTIONS(576), 1,
-      sym_string,
-    STATE(205), 1,
+    STATE(240), 1,
+      sym_argument_list,
+    STATE(470), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(148), 2,
-      sym_import_spec_list,
-      sym_import_spec,
-  [5696] = 6,
-    ACTIONS(578), 1,
-      sym_identifier,
-    ACTIONS(581), 1,
-      anon_sym_SLASH,
-    ACTIONS(583), 1,
-      anon_sym_GT,
-    STATE(230), 1,
-      sym_attribute,
+  [17689] = 4,
+    ACTIONS(252), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1344), 1,
+      anon_sym_COMMA,
+    STATE(471), 1,
+      sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(206), 2,
+  [17703] = 3,
+    STATE(472), 1,
       sym_comment,
-      aux_sym_self_closing_element_repeat1,
-  [5717] = 7,
-    ACTIONS(585), 1,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+    ACTIONS(1346), 2,
+      anon_sym_RBRACE,
       sym_identifier,
-    ACTIONS(587), 1,
-      anon_sym_RPAREN,
-    ACTIONS(589), 1,
-      sym_string,
-    STATE(207), 1,
+  [17715] = 4,
+    ACTIONS(1256), 1,
+      sym_identifier,
+    STATE(430), 1,
+      sym_type_parameter_declaration,
+    STATE(473), 1,
       sym_comment,
-    STATE(210), 1,
-      aux_sym_import_spec_list_repeat1,
-    STATE(244), 1,
-      sym_import_spec,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [5740] = 7,
-    ACTIONS(250), 1,
-      aux_sym_go_declaration_token1,
-    ACTIONS(570), 1,
-      anon_sym_LPAREN,
-    ACTIONS(591), 1,
-      sym_identifier,
-    STATE(66), 1,
-      aux_sym_go_declaration_repeat1,
-    STATE(197), 1,
-      sym_go_paren_body,
-    STATE(208), 1,
+  [17729] = 3,
+    STATE(474), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [5763] = 6,
-    ACTIONS(593), 1,
-      sym_identifier,
-    ACTIONS(596), 1,
+    ACTIONS(1348), 2,
       anon_sym_RPAREN,
-    ACTIONS(598), 1,
-      sym_string,
-    STATE(244), 1,
-      sym_import_spec,
+      anon_sym_COMMA,
+  [17741] = 3,
+    STATE(475), 1,
+      sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(209), 2,
-      sym_comment,
-      aux_sym_import_spec_list_repeat1,
-  [5784] = 7,
-    ACTIONS(585), 1,
-      sym_identifier,
-    ACTIONS(589), 1,
-      sym_string,
-    ACTIONS(601), 1,
+    ACTIONS(1350), 2,
       anon_sym_RPAREN,
-    STATE(209), 1,
-      aux_sym_import_spec_list_repeat1,
-    STATE(210), 1,
+      anon_sym_COMMA,
+  [17753] = 4,
+    ACTIONS(1352), 1,
+      anon_sym_LBRACE,
+    STATE(215), 1,
+      sym_struct_body,
+    STATE(476), 1,
       sym_comment,
-    STATE(244), 1,
-      sym_import_spec,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [5807] = 6,
-    ACTIONS(603), 1,
+  [17767] = 4,
+    ACTIONS(1354), 1,
       anon_sym_LBRACE,
-    STATE(211), 1,
+    STATE(364), 1,
+      sym_component_body,
+    STATE(477), 1,
       sym_comment,
-    STATE(226), 1,
-      sym_go_expression,
-    STATE(236), 1,
-      sym__attribute_value,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(605), 2,
-      sym_number,
-      sym_string,
-  [5828] = 7,
-    ACTIONS(562), 1,
-      sym_identifier,
-    ACTIONS(607), 1,
-      anon_sym_SLASH,
-    ACTIONS(609), 1,
-      anon_sym_GT,
-    STATE(206), 1,
-      aux_sym_self_closing_element_repeat1,
-    STATE(212), 1,
+  [17781] = 4,
+    ACTIONS(757), 1,
+      anon_sym_LPAREN,
+    STATE(477), 1,
+      sym_parameter_list,
+    STATE(478), 1,
       sym_comment,
-    STATE(230), 1,
-      sym_attribute,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [5851] = 7,
-    ACTIONS(562), 1,
-      sym_identifier,
-    ACTIONS(611), 1,
-      anon_sym_SLASH,
-    ACTIONS(613), 1,
-      anon_sym_GT,
-    STATE(206), 1,
-      aux_sym_self_closing_element_repeat1,
-    STATE(213), 1,
+  [17795] = 4,
+    ACTIONS(284), 1,
+      anon_sym_LBRACE,
+    STATE(84), 1,
+      sym_function_body,
+    STATE(479), 1,
       sym_comment,
-    STATE(230), 1,
-      sym_attribute,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [5874] = 7,
-    ACTIONS(562), 1,
-      sym_identifier,
-    ACTIONS(615), 1,
-      anon_sym_SLASH,
-    ACTIONS(617), 1,
-      anon_sym_GT,
-    STATE(213), 1,
-      aux_sym_self_closing_element_repeat1,
-    STATE(214), 1,
+  [17809] = 4,
+    ACTIONS(1354), 1,
+      anon_sym_LBRACE,
+    STATE(391), 1,
+      sym_component_body,
+    STATE(480), 1,
       sym_comment,
-    STATE(230), 1,
-      sym_attribute,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [5897] = 5,
-    ACTIONS(220), 1,
-      anon_sym_LBRACE,
-    ACTIONS(619), 1,
-      anon_sym_if,
-    STATE(215), 1,
+  [17823] = 4,
+    ACTIONS(1303), 1,
+      anon_sym_LPAREN,
+    STATE(286), 1,
+      sym_argument_list,
+    STATE(481), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(135), 2,
-      sym_if_statement,
-      sym_block,
-  [5915] = 6,
-    ACTIONS(621), 1,
-      sym_identifier,
-    ACTIONS(623), 1,
-      anon_sym_RBRACE,
-    STATE(216), 1,
+  [17837] = 4,
+    ACTIONS(124), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1356), 1,
+      anon_sym_COMMA,
+    STATE(482), 1,
       sym_comment,
-    STATE(217), 1,
-      aux_sym_struct_body_repeat1,
-    STATE(261), 1,
-      sym_struct_field,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [5935] = 6,
-    ACTIONS(621), 1,
-      sym_identifier,
-    ACTIONS(625), 1,
-      anon_sym_RBRACE,
-    STATE(217), 1,
+  [17851] = 4,
+    ACTIONS(757), 1,
+      anon_sym_LPAREN,
+    STATE(99), 1,
+      sym_parameter_list,
+    STATE(483), 1,
       sym_comment,
-    STATE(218), 1,
-      aux_sym_struct_body_repeat1,
-    STATE(261), 1,
-      sym_struct_field,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [5955] = 5,
-    ACTIONS(627), 1,
-      sym_identifier,
-    ACTIONS(630), 1,
-      anon_sym_RBRACE,
-    STATE(261), 1,
-      sym_struct_field,
+  [17865] = 4,
+    ACTIONS(1358), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1360), 1,
+      anon_sym_COMMA,
+    STATE(484), 1,
+      sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(218), 2,
+  [17879] = 4,
+    ACTIONS(757), 1,
+      anon_sym_LPAREN,
+    STATE(155), 1,
+      sym_parameter_list,
+    STATE(485), 1,
       sym_comment,
-      aux_sym_struct_body_repeat1,
-  [5973] = 5,
-    ACTIONS(340), 1,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+  [17893] = 4,
+    ACTIONS(284), 1,
       anon_sym_LBRACE,
-    ACTIONS(632), 1,
-      anon_sym_if,
-    STATE(219), 1,
+    STATE(361), 1,
+      sym_function_body,
+    STATE(486), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(116), 2,
-      sym_if_statement,
-      sym_block,
-  [5991] = 5,
-    ACTIONS(636), 1,
-      anon_sym_SLASH,
-    ACTIONS(638), 1,
-      anon_sym_EQ,
-    STATE(220), 1,
+  [17907] = 3,
+    STATE(487), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(634), 2,
-      anon_sym_GT,
-      sym_identifier,
-  [6009] = 4,
-    ACTIONS(642), 1,
+    ACTIONS(1362), 2,
       anon_sym_COMMA,
+      anon_sym_RBRACK,
+  [17919] = 3,
+    STATE(488), 1,
+      sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(640), 2,
-      anon_sym_RPAREN,
-      anon_sym_RBRACK,
-    STATE(221), 2,
-      sym_comment,
-      aux_sym_func_type_repeat1,
-  [6025] = 5,
-    ACTIONS(645), 1,
+    ACTIONS(1364), 2,
+      anon_sym_RBRACE,
       sym_identifier,
-    ACTIONS(647), 1,
+  [17931] = 4,
+    ACTIONS(268), 1,
       anon_sym_RPAREN,
-    STATE(222), 1,
+    ACTIONS(1366), 1,
+      anon_sym_COMMA,
+    STATE(489), 1,
       sym_comment,
-    STATE(253), 1,
-      sym_parameter,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6042] = 4,
-    ACTIONS(227), 1,
+  [17945] = 4,
+    ACTIONS(1368), 1,
       anon_sym_RPAREN,
-    ACTIONS(649), 1,
+    ACTIONS(1370), 1,
       anon_sym_COMMA,
+    STATE(490), 1,
+      sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(223), 2,
-      sym_comment,
-      aux_sym_argument_list_repeat1,
-  [6057] = 5,
-    ACTIONS(652), 1,
-      anon_sym_RPAREN,
-    ACTIONS(654), 1,
-      anon_sym_COMMA,
-    STATE(224), 1,
+  [17959] = 3,
+    STATE(491), 1,
       sym_comment,
-    STATE(225), 1,
-      aux_sym_func_type_repeat1,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6074] = 5,
-    ACTIONS(654), 1,
+    ACTIONS(1265), 2,
       anon_sym_COMMA,
-    ACTIONS(656), 1,
+      anon_sym_RBRACK,
+  [17971] = 4,
+    ACTIONS(130), 1,
       anon_sym_RPAREN,
-    STATE(221), 1,
-      aux_sym_func_type_repeat1,
-    STATE(225), 1,
+    ACTIONS(1372), 1,
+      anon_sym_COMMA,
+    STATE(492), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6091] = 4,
-    ACTIONS(660), 1,
-      anon_sym_SLASH,
-    STATE(226), 1,
+  [17985] = 4,
+    ACTIONS(1374), 1,
+      sym_identifier,
+    ACTIONS(1376), 1,
+      anon_sym_LPAREN,
+    STATE(493), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(658), 2,
-      anon_sym_GT,
-      sym_identifier,
-  [6106] = 5,
-    ACTIONS(654), 1,
+  [17999] = 4,
+    ACTIONS(1378), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1380), 1,
       anon_sym_COMMA,
-    ACTIONS(662), 1,
-      anon_sym_RBRACK,
-    STATE(221), 1,
-      aux_sym_func_type_repeat1,
-    STATE(227), 1,
+    STATE(494), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6123] = 5,
-    ACTIONS(664), 1,
-      sym_identifier,
-    ACTIONS(666), 1,
-      anon_sym_LPAREN,
-    STATE(228), 1,
+  [18013] = 3,
+    STATE(495), 1,
       sym_comment,
-    STATE(312), 1,
-      sym_receiver,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6140] = 5,
-    ACTIONS(666), 1,
-      anon_sym_LPAREN,
-    ACTIONS(668), 1,
-      sym_identifier,
-    STATE(229), 1,
+    ACTIONS(1244), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+  [18025] = 4,
+    ACTIONS(260), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1382), 1,
+      anon_sym_COMMA,
+    STATE(496), 1,
       sym_comment,
-    STATE(325), 1,
-      sym_receiver,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6157] = 4,
-    ACTIONS(672), 1,
-      anon_sym_SLASH,
-    STATE(230), 1,
+  [18039] = 3,
+    STATE(497), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(670), 2,
-      anon_sym_GT,
+    ACTIONS(1384), 2,
+      anon_sym_RBRACE,
       sym_identifier,
-  [6172] = 3,
-    STATE(231), 1,
+  [18051] = 4,
+    ACTIONS(757), 1,
+      anon_sym_LPAREN,
+    STATE(125), 1,
+      sym_parameter_list,
+    STATE(498), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(454), 3,
+  [18065] = 4,
+    ACTIONS(77), 1,
       anon_sym_RPAREN,
-      sym_identifier,
-      sym_string,
-  [6185] = 5,
-    ACTIONS(654), 1,
+    ACTIONS(1386), 1,
       anon_sym_COMMA,
-    ACTIONS(674), 1,
-      anon_sym_RPAREN,
-    STATE(232), 1,
+    STATE(499), 1,
       sym_comment,
-    STATE(234), 1,
-      aux_sym_func_type_repeat1,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6202] = 5,
-    ACTIONS(647), 1,
+  [18079] = 4,
+    ACTIONS(1388), 1,
       anon_sym_RPAREN,
-    ACTIONS(676), 1,
+    ACTIONS(1390), 1,
       anon_sym_COMMA,
-    STATE(233), 1,
+    STATE(500), 1,
       sym_comment,
-    STATE(245), 1,
-      aux_sym_parameter_list_repeat1,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6219] = 5,
-    ACTIONS(654), 1,
-      anon_sym_COMMA,
-    ACTIONS(678), 1,
-      anon_sym_RPAREN,
-    STATE(221), 1,
-      aux_sym_func_type_repeat1,
-    STATE(234), 1,
+  [18093] = 3,
+    STATE(501), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6236] = 5,
-    ACTIONS(645), 1,
-      sym_identifier,
-    ACTIONS(680), 1,
+    ACTIONS(1327), 2,
       anon_sym_RPAREN,
-    STATE(235), 1,
+      anon_sym_COMMA,
+  [18105] = 4,
+    ACTIONS(1392), 1,
+      anon_sym_LBRACE,
+    STATE(215), 1,
+      sym_struct_body,
+    STATE(502), 1,
       sym_comment,
-    STATE(241), 1,
-      sym_parameter,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6253] = 4,
-    ACTIONS(684), 1,
-      anon_sym_SLASH,
-    STATE(236), 1,
+  [18119] = 4,
+    ACTIONS(757), 1,
+      anon_sym_LPAREN,
+    STATE(137), 1,
+      sym_parameter_list,
+    STATE(503), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(682), 2,
-      anon_sym_GT,
-      sym_identifier,
-  [6268] = 5,
-    ACTIONS(654), 1,
+  [18133] = 4,
+    ACTIONS(264), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1394), 1,
       anon_sym_COMMA,
-    ACTIONS(686), 1,
-      anon_sym_RBRACK,
-    STATE(227), 1,
-      aux_sym_func_type_repeat1,
-    STATE(237), 1,
+    STATE(504), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6285] = 5,
-    ACTIONS(654), 1,
-      anon_sym_COMMA,
-    ACTIONS(688), 1,
-      anon_sym_RPAREN,
-    STATE(221), 1,
-      aux_sym_func_type_repeat1,
-    STATE(238), 1,
+  [18147] = 4,
+    ACTIONS(1396), 1,
+      sym_identifier,
+    STATE(466), 1,
+      sym_for_clause,
+    STATE(505), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6302] = 5,
-    ACTIONS(197), 1,
-      anon_sym_COMMA,
-    ACTIONS(690), 1,
-      anon_sym_RPAREN,
-    STATE(223), 1,
-      aux_sym_argument_list_repeat1,
-    STATE(239), 1,
+  [18161] = 3,
+    STATE(506), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6319] = 5,
-    ACTIONS(645), 1,
-      sym_identifier,
-    ACTIONS(692), 1,
+    ACTIONS(1398), 2,
       anon_sym_RPAREN,
-    STATE(240), 1,
+      anon_sym_COMMA,
+  [18173] = 3,
+    STATE(507), 1,
       sym_comment,
-    STATE(253), 1,
-      sym_parameter,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6336] = 5,
-    ACTIONS(694), 1,
-      anon_sym_RPAREN,
-    ACTIONS(696), 1,
+    ACTIONS(1400), 2,
       anon_sym_COMMA,
-    STATE(233), 1,
-      aux_sym_parameter_list_repeat1,
-    STATE(241), 1,
+      anon_sym_RBRACE,
+  [18185] = 3,
+    STATE(508), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6353] = 3,
-    STATE(242), 1,
+    ACTIONS(1402), 2,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+  [18197] = 4,
+    ACTIONS(757), 1,
+      anon_sym_LPAREN,
+    STATE(144), 1,
+      sym_parameter_list,
+    STATE(509), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(462), 3,
-      anon_sym_RPAREN,
-      sym_identifier,
-      sym_string,
-  [6366] = 3,
-    STATE(243), 1,
+  [18211] = 4,
+    ACTIONS(1352), 1,
+      anon_sym_LBRACE,
+    STATE(387), 1,
+      sym_struct_body,
+    STATE(510), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(640), 3,
+  [18225] = 4,
+    ACTIONS(94), 1,
       anon_sym_RPAREN,
+    ACTIONS(1404), 1,
       anon_sym_COMMA,
-      anon_sym_RBRACK,
-  [6379] = 3,
-    STATE(244), 1,
+    STATE(511), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(698), 3,
-      anon_sym_RPAREN,
-      sym_identifier,
-      sym_string,
-  [6392] = 4,
-    ACTIONS(700), 1,
-      anon_sym_RPAREN,
-    ACTIONS(702), 1,
-      anon_sym_COMMA,
+  [18239] = 4,
+    ACTIONS(757), 1,
+      anon_sym_LPAREN,
+    STATE(130), 1,
+      sym_parameter_list,
+    STATE(512), 1,
+      sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    STATE(245), 2,
-      sym_comment,
-      aux_sym_parameter_list_repeat1,
-  [6407] = 5,
-    ACTIONS(197), 1,
-      anon_sym_COMMA,
-    ACTIONS(705), 1,
-      anon_sym_RPAREN,
-    STATE(223), 1,
-      aux_sym_argument_list_repeat1,
-    STATE(246), 1,
+  [18253] = 4,
+    ACTIONS(1396), 1,
+      sym_identifier,
+    STATE(468), 1,
+      sym_for_clause,
+    STATE(513), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6424] = 5,
-    ACTIONS(654), 1,
-      anon_sym_COMMA,
-    ACTIONS(707), 1,
-      anon_sym_RPAREN,
-    STATE(238), 1,
-      aux_sym_func_type_repeat1,
-    STATE(247), 1,
+  [18267] = 4,
+    ACTIONS(1354), 1,
+      anon_sym_LBRACE,
+    STATE(376), 1,
+      sym_component_body,
+    STATE(514), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6441] = 4,
-    ACTIONS(709), 1,
-      anon_sym_LPAREN,
-    STATE(96), 1,
-      sym_argument_list,
-    STATE(248), 1,
+  [18281] = 3,
+    ACTIONS(1406), 1,
+      sym_identifier,
+    STATE(515), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6455] = 4,
-    ACTIONS(711), 1,
-      anon_sym_LPAREN,
-    STATE(249), 1,
+  [18292] = 3,
+    ACTIONS(1408), 1,
+      anon_sym_EQ,
+    STATE(516), 1,
       sym_comment,
-    STATE(252), 1,
-      sym_parameter_list,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6469] = 4,
-    ACTIONS(713), 1,
-      anon_sym_COMMA,
-    ACTIONS(715), 1,
-      anon_sym_COLON_EQ,
-    STATE(250), 1,
+  [18303] = 3,
+    ACTIONS(1410), 1,
+      anon_sym_GT,
+    STATE(517), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6483] = 4,
-    ACTIONS(645), 1,
+  [18314] = 3,
+    ACTIONS(1412), 1,
       sym_identifier,
-    STATE(251), 1,
+    STATE(518), 1,
       sym_comment,
-    STATE(253), 1,
-      sym_parameter,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6497] = 4,
-    ACTIONS(717), 1,
-      anon_sym_LBRACE,
-    STATE(170), 1,
-      sym_component_body,
-    STATE(252), 1,
+  [18325] = 3,
+    ACTIONS(1414), 1,
+      anon_sym_RPAREN,
+    STATE(519), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6511] = 3,
-    STATE(253), 1,
+  [18336] = 3,
+    ACTIONS(1416), 1,
+      sym_identifier,
+    STATE(520), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(700), 2,
-      anon_sym_RPAREN,
-      anon_sym_COMMA,
-  [6523] = 4,
-    ACTIONS(340), 1,
-      anon_sym_LBRACE,
-    STATE(127), 1,
-      sym_block,
-    STATE(254), 1,
+  [18347] = 3,
+    ACTIONS(25), 1,
+      anon_sym_RBRACK,
+    STATE(521), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6537] = 3,
-    STATE(255), 1,
+  [18358] = 3,
+    ACTIONS(1418), 1,
+      anon_sym_RBRACE,
+    STATE(522), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(719), 2,
-      anon_sym_RPAREN,
-      anon_sym_COMMA,
-  [6549] = 4,
-    ACTIONS(711), 1,
-      anon_sym_LPAREN,
-    STATE(35), 1,
-      sym_parameter_list,
-    STATE(256), 1,
+  [18369] = 3,
+    ACTIONS(1420), 1,
+      anon_sym_GT,
+    STATE(523), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6563] = 4,
-    ACTIONS(721), 1,
+  [18380] = 3,
+    ACTIONS(1422), 1,
       sym_identifier,
-    STATE(254), 1,
-      sym_for_clause,
-    STATE(257), 1,
+    STATE(524), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6577] = 4,
-    ACTIONS(717), 1,
-      anon_sym_LBRACE,
-    STATE(186), 1,
-      sym_component_body,
-    STATE(258), 1,
+  [18391] = 3,
+    ACTIONS(1424), 1,
+      anon_sym_range,
+    STATE(525), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6591] = 4,
-    ACTIONS(220), 1,
-      anon_sym_LBRACE,
-    STATE(141), 1,
-      sym_block,
-    STATE(259), 1,
+  [18402] = 3,
+    ACTIONS(1426), 1,
+      ts_builtin_sym_end,
+    STATE(526), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6605] = 4,
-    ACTIONS(711), 1,
-      anon_sym_LPAREN,
-    STATE(31), 1,
-      sym_parameter_list,
-    STATE(260), 1,
+  [18413] = 3,
+    ACTIONS(1428), 1,
+      anon_sym_RBRACE,
+    STATE(527), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6619] = 3,
-    STATE(261), 1,
+  [18424] = 3,
+    ACTIONS(1430), 1,
+      anon_sym_RPAREN,
+    STATE(528), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(723), 2,
-      anon_sym_RBRACE,
-      sym_identifier,
-  [6631] = 4,
-    ACTIONS(171), 1,
-      anon_sym_LPAREN,
-    STATE(108), 1,
-      sym_argument_list,
-    STATE(262), 1,
+  [18435] = 3,
+    ACTIONS(1368), 1,
+      anon_sym_RPAREN,
+    STATE(529), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6645] = 3,
-    STATE(263), 1,
+  [18446] = 3,
+    ACTIONS(122), 1,
+      anon_sym_RPAREN,
+    STATE(530), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-    ACTIONS(725), 2,
-      anon_sym_RBRACE,
-      sym_identifier,
-  [6657] = 4,
-    ACTIONS(187), 1,
-      anon_sym_LBRACE,
-    STATE(169), 1,
-      sym_function_body,
-    STATE(264), 1,
+  [18457] = 3,
+    ACTIONS(1432), 1,
+      anon_sym_GT,
+    STATE(531), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6671] = 4,
-    ACTIONS(727), 1,
-      anon_sym_LBRACE,
-    STATE(182), 1,
-      sym_struct_body,
-    STATE(265), 1,
+  [18468] = 3,
+    ACTIONS(1434), 1,
+      anon_sym_RPAREN,
+    STATE(532), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6685] = 4,
-    ACTIONS(721), 1,
-      sym_identifier,
-    STATE(259), 1,
-      sym_for_clause,
-    STATE(266), 1,
+  [18479] = 3,
+    ACTIONS(1436), 1,
+      anon_sym_GT,
+    STATE(533), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6699] = 4,
-    ACTIONS(187), 1,
-      anon_sym_LBRACE,
-    STATE(167), 1,
-      sym_function_body,
-    STATE(267), 1,
+  [18490] = 3,
+    ACTIONS(1438), 1,
+      anon_sym_COLON,
+    STATE(534), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6713] = 3,
-    ACTIONS(729), 1,
-      anon_sym_LPAREN,
-    STATE(268), 1,
+  [18501] = 3,
+    ACTIONS(1440), 1,
+      aux_sym_go_declaration_token3,
+    STATE(535), 1,
       sym_comment,
-    ACTIONS(3), 2,
+    ACTIONS(654), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6724] = 3,
-    ACTIONS(731), 1,
-      sym_identifier,
-    STATE(269), 1,
+  [18512] = 3,
+    ACTIONS(1442), 1,
+      anon_sym_LPAREN,
+    STATE(536), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6735] = 3,
-    ACTIONS(733), 1,
-      sym_identifier,
-    STATE(270), 1,
+  [18523] = 3,
+    ACTIONS(1444), 1,
+      anon_sym_DOT_DOT_DOT,
+    STATE(537), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6746] = 3,
-    ACTIONS(735), 1,
-      sym_identifier,
-    STATE(271), 1,
+  [18534] = 3,
+    ACTIONS(1446), 1,
+      anon_sym_GT,
+    STATE(538), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6757] = 3,
-    ACTIONS(737), 1,
-      anon_sym_GT,
-    STATE(272), 1,
+  [18545] = 3,
+    ACTIONS(1358), 1,
+      anon_sym_RPAREN,
+    STATE(539), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6768] = 3,
-    ACTIONS(739), 1,
-      anon_sym_RBRACK,
-    STATE(273), 1,
+  [18556] = 3,
+    ACTIONS(1448), 1,
+      sym_identifier,
+    STATE(540), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6779] = 3,
-    ACTIONS(741), 1,
-      sym_identifier,
-    STATE(274), 1,
+  [18567] = 3,
+    ACTIONS(1450), 1,
+      anon_sym_GT,
+    STATE(541), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6790] = 3,
-    ACTIONS(743), 1,
-      anon_sym_COLON_EQ,
-    STATE(275), 1,
+  [18578] = 3,
+    ACTIONS(1452), 1,
+      anon_sym_RPAREN,
+    STATE(542), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6801] = 3,
-    ACTIONS(745), 1,
-      anon_sym_RBRACK,
-    STATE(276), 1,
+  [18589] = 3,
+    ACTIONS(1454), 1,
+      anon_sym_GT,
+    STATE(543), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6812] = 3,
-    ACTIONS(747), 1,
+  [18600] = 3,
+    ACTIONS(1456), 1,
       anon_sym_RBRACE,
-    STATE(277), 1,
+    STATE(544), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6823] = 3,
-    ACTIONS(749), 1,
+  [18611] = 3,
+    ACTIONS(1458), 1,
       sym_identifier,
-    STATE(278), 1,
+    STATE(545), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6834] = 3,
-    ACTIONS(751), 1,
-      anon_sym_LBRACK,
-    STATE(279), 1,
+  [18622] = 3,
+    ACTIONS(1460), 1,
+      anon_sym_GT,
+    STATE(546), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6845] = 3,
-    ACTIONS(753), 1,
-      aux_sym_go_declaration_token2,
-    STATE(280), 1,
+  [18633] = 3,
+    ACTIONS(1462), 1,
+      anon_sym_RBRACE,
+    STATE(547), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6856] = 3,
-    ACTIONS(755), 1,
-      anon_sym_range,
-    STATE(281), 1,
+  [18644] = 3,
+    ACTIONS(1464), 1,
+      sym_identifier,
+    STATE(548), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6867] = 3,
-    ACTIONS(757), 1,
+  [18655] = 3,
+    ACTIONS(1466), 1,
       anon_sym_LPAREN,
-    STATE(282), 1,
+    STATE(549), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6878] = 3,
-    ACTIONS(759), 1,
-      sym_string,
-    STATE(283), 1,
+  [18666] = 3,
+    ACTIONS(1468), 1,
+      sym_identifier,
+    STATE(550), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6889] = 3,
-    ACTIONS(761), 1,
-      anon_sym_RPAREN,
-    STATE(284), 1,
+  [18677] = 3,
+    ACTIONS(1470), 1,
+      sym_identifier,
+    STATE(551), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6900] = 3,
-    ACTIONS(763), 1,
-      anon_sym_LPAREN,
-    STATE(285), 1,
+  [18688] = 3,
+    ACTIONS(1472), 1,
+      anon_sym_GT,
+    STATE(552), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6911] = 3,
-    ACTIONS(765), 1,
-      anon_sym_RBRACE,
-    STATE(286), 1,
+  [18699] = 3,
+    ACTIONS(128), 1,
+      anon_sym_RPAREN,
+    STATE(553), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+  [18710] = 3,
+    ACTIONS(1474), 1,
+      sym_identifier,
+    STATE(554), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6922] = 3,
-    ACTIONS(767), 1,
+  [18721] = 3,
+    ACTIONS(1378), 1,
       anon_sym_RPAREN,
-    STATE(287), 1,
+    STATE(555), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6933] = 3,
-    ACTIONS(769), 1,
-      anon_sym_DOT_DOT_DOT,
-    STATE(288), 1,
+  [18732] = 3,
+    ACTIONS(1476), 1,
+      sym_string,
+    STATE(556), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6944] = 3,
-    ACTIONS(771), 1,
-      anon_sym_RBRACE,
-    STATE(289), 1,
+  [18743] = 3,
+    ACTIONS(1478), 1,
+      anon_sym_RPAREN,
+    STATE(557), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6955] = 3,
-    ACTIONS(773), 1,
-      anon_sym_RBRACE,
-    STATE(290), 1,
+  [18754] = 3,
+    ACTIONS(1480), 1,
+      sym_string,
+    STATE(558), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6966] = 3,
-    ACTIONS(775), 1,
-      anon_sym_RBRACE,
-    STATE(291), 1,
+  [18765] = 3,
+    ACTIONS(1482), 1,
+      sym_identifier,
+    STATE(559), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6977] = 3,
-    ACTIONS(777), 1,
-      anon_sym_GT,
-    STATE(292), 1,
+  [18776] = 3,
+    ACTIONS(1484), 1,
+      anon_sym_COLON_EQ,
+    STATE(560), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6988] = 3,
-    ACTIONS(779), 1,
-      anon_sym_GT,
-    STATE(293), 1,
+  [18787] = 3,
+    ACTIONS(1486), 1,
+      anon_sym_RPAREN,
+    STATE(561), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [6999] = 3,
-    ACTIONS(781), 1,
-      sym_identifier,
-    STATE(294), 1,
+  [18798] = 3,
+    ACTIONS(1488), 1,
+      anon_sym_COLON_EQ,
+    STATE(562), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7010] = 3,
-    ACTIONS(783), 1,
+  [18809] = 3,
+    ACTIONS(1490), 1,
       sym_identifier,
-    STATE(295), 1,
+    STATE(563), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7021] = 3,
-    ACTIONS(785), 1,
+  [18820] = 3,
+    ACTIONS(134), 1,
       anon_sym_RPAREN,
-    STATE(296), 1,
+    STATE(564), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7032] = 3,
-    ACTIONS(787), 1,
-      sym_identifier,
-    STATE(297), 1,
+  [18831] = 3,
+    ACTIONS(1492), 1,
+      anon_sym_chan,
+    STATE(565), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7043] = 3,
-    ACTIONS(789), 1,
-      aux_sym_go_declaration_token2,
-    STATE(298), 1,
+  [18842] = 3,
+    ACTIONS(1494), 1,
+      anon_sym_RPAREN,
+    STATE(566), 1,
       sym_comment,
-    ACTIONS(53), 2,
+    ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7054] = 3,
-    ACTIONS(791), 1,
-      anon_sym_COLON_EQ,
-    STATE(299), 1,
+  [18853] = 3,
+    ACTIONS(1388), 1,
+      anon_sym_RPAREN,
+    STATE(567), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7065] = 3,
-    ACTIONS(793), 1,
-      anon_sym_GT,
-    STATE(300), 1,
+  [18864] = 3,
+    ACTIONS(1496), 1,
+      sym_identifier,
+    STATE(568), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7076] = 3,
-    ACTIONS(795), 1,
-      sym_identifier,
-    STATE(301), 1,
+  [18875] = 3,
+    ACTIONS(1498), 1,
+      anon_sym_RPAREN,
+    STATE(569), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7087] = 3,
-    ACTIONS(797), 1,
-      anon_sym_GT,
-    STATE(302), 1,
+  [18886] = 3,
+    ACTIONS(1500), 1,
+      anon_sym_RPAREN,
+    STATE(570), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7098] = 3,
-    ACTIONS(799), 1,
+  [18897] = 3,
+    ACTIONS(1502), 1,
       anon_sym_GT,
-    STATE(303), 1,
+    STATE(571), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7109] = 3,
-    ACTIONS(801), 1,
-      anon_sym_GT,
-    STATE(304), 1,
+  [18908] = 3,
+    ACTIONS(1504), 1,
+      anon_sym_chan,
+    STATE(572), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7120] = 3,
-    ACTIONS(803), 1,
-      anon_sym_RBRACE,
-    STATE(305), 1,
+  [18919] = 3,
+    ACTIONS(1506), 1,
+      anon_sym_chan,
+    STATE(573), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7131] = 3,
-    ACTIONS(805), 1,
-      sym_identifier,
-    STATE(306), 1,
+  [18930] = 3,
+    ACTIONS(1508), 1,
+      anon_sym_LBRACK,
+    STATE(574), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7142] = 3,
-    ACTIONS(807), 1,
-      anon_sym_GT,
-    STATE(307), 1,
+  [18941] = 3,
+    ACTIONS(1510), 1,
+      aux_sym_go_declaration_token3,
+    STATE(575), 1,
+      sym_comment,
+    ACTIONS(654), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+  [18952] = 3,
+    ACTIONS(1512), 1,
+      anon_sym_COLON_EQ,
+    STATE(576), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7153] = 3,
-    ACTIONS(809), 1,
-      anon_sym_GT,
-    STATE(308), 1,
+  [18963] = 3,
+    ACTIONS(1514), 1,
+      anon_sym_LPAREN,
+    STATE(577), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7164] = 3,
-    ACTIONS(811), 1,
-      anon_sym_COLON_EQ,
-    STATE(309), 1,
+  [18974] = 3,
+    ACTIONS(1516), 1,
+      anon_sym_LBRACE,
+    STATE(578), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7175] = 3,
-    ACTIONS(813), 1,
+  [18985] = 3,
+    ACTIONS(1518), 1,
       sym_identifier,
-    STATE(310), 1,
+    STATE(579), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7186] = 3,
-    ACTIONS(815), 1,
-      anon_sym_DOT_DOT_DOT,
-    STATE(311), 1,
+  [18996] = 3,
+    ACTIONS(1520), 1,
+      sym_identifier,
+    STATE(580), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7197] = 3,
-    ACTIONS(817), 1,
-      sym_identifier,
-    STATE(312), 1,
+  [19007] = 3,
+    ACTIONS(1522), 1,
+      anon_sym_RBRACK,
+    STATE(581), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7208] = 3,
-    ACTIONS(819), 1,
-      anon_sym_EQ,
-    STATE(313), 1,
+  [19018] = 3,
+    ACTIONS(1524), 1,
+      anon_sym_DOT_DOT_DOT,
+    STATE(582), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7219] = 3,
-    ACTIONS(821), 1,
-      sym_string,
-    STATE(314), 1,
+  [19029] = 3,
+    ACTIONS(1526), 1,
+      anon_sym_LPAREN,
+    STATE(583), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7230] = 3,
-    ACTIONS(823), 1,
-      anon_sym_EQ,
-    STATE(315), 1,
+  [19040] = 3,
+    ACTIONS(1528), 1,
+      anon_sym_RBRACK,
+    STATE(584), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7241] = 3,
-    ACTIONS(825), 1,
-      sym_identifier,
-    STATE(316), 1,
+  [19051] = 3,
+    ACTIONS(1530), 1,
+      anon_sym_EQ,
+    STATE(585), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7252] = 3,
-    ACTIONS(827), 1,
-      sym_identifier,
-    STATE(317), 1,
+  [19062] = 3,
+    ACTIONS(1532), 1,
+      anon_sym_range,
+    STATE(586), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7263] = 3,
-    ACTIONS(829), 1,
+  [19073] = 3,
+    ACTIONS(1534), 1,
       sym_identifier,
-    STATE(318), 1,
+    STATE(587), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7274] = 3,
-    ACTIONS(831), 1,
+  [19084] = 3,
+    ACTIONS(1536), 1,
       anon_sym_RBRACK,
-    STATE(319), 1,
+    STATE(588), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7285] = 3,
-    ACTIONS(833), 1,
+  [19095] = 3,
+    ACTIONS(1538), 1,
       sym_identifier,
-    STATE(320), 1,
+    STATE(589), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7296] = 3,
-    ACTIONS(835), 1,
+  [19106] = 3,
+    ACTIONS(1540), 1,
       sym_identifier,
-    STATE(321), 1,
+    STATE(590), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7307] = 3,
-    ACTIONS(837), 1,
-      sym_identifier,
-    STATE(322), 1,
+  [19117] = 3,
+    ACTIONS(1542), 1,
+      anon_sym_LBRACK,
+    STATE(591), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7318] = 3,
-    ACTIONS(839), 1,
-      anon_sym_RBRACK,
-    STATE(323), 1,
+  [19128] = 3,
+    ACTIONS(90), 1,
+      anon_sym_RPAREN,
+    STATE(592), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7329] = 3,
-    ACTIONS(841), 1,
-      anon_sym_range,
-    STATE(324), 1,
+  [19139] = 3,
+    ACTIONS(1544), 1,
+      sym_identifier,
+    STATE(593), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7340] = 3,
-    ACTIONS(843), 1,
+  [19150] = 3,
+    ACTIONS(1546), 1,
       sym_identifier,
-    STATE(325), 1,
+    STATE(594), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7351] = 3,
-    ACTIONS(845), 1,
-      anon_sym_RPAREN,
-    STATE(326), 1,
+  [19161] = 3,
+    ACTIONS(1548), 1,
+      anon_sym_RBRACE,
+    STATE(595), 1,
+      sym_comment,
+    ACTIONS(3), 2,
+      aux_sym_comment_token1,
+      aux_sym_comment_token2,
+  [19172] = 3,
+    ACTIONS(1550), 1,
+      anon_sym_RBRACE,
+    STATE(596), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7362] = 3,
-    ACTIONS(847), 1,
+  [19183] = 3,
+    ACTIONS(1552), 1,
       sym_identifier,
-    STATE(327), 1,
+    STATE(597), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7373] = 3,
-    ACTIONS(849), 1,
-      ts_builtin_sym_end,
-    STATE(328), 1,
+  [19194] = 3,
+    ACTIONS(1554), 1,
+      anon_sym_RBRACE,
+    STATE(598), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7384] = 3,
-    ACTIONS(851), 1,
+  [19205] = 3,
+    ACTIONS(1556), 1,
       anon_sym_LBRACK,
-    STATE(329), 1,
+    STATE(599), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7395] = 3,
-    ACTIONS(853), 1,
-      anon_sym_GT,
-    STATE(330), 1,
+  [19216] = 3,
+    ACTIONS(1558), 1,
+      sym_identifier,
+    STATE(600), 1,
       sym_comment,
     ACTIONS(3), 2,
       aux_sym_comment_token1,
       aux_sym_comment_token2,
-  [7406] = 1,
-    ACTIONS(855), 1,
+  [19227] = 1,
+    ACTIONS(1560), 1,
       ts_builtin_sym_end,
 };
 
 static const uint32_t ts_small_parse_table_map[] = {
   [SMALL_STATE(2)] = 0,
-  [SMALL_STATE(3)] = 34,
-  [SMALL_STATE(4)] = 68,
-  [SMALL_STATE(5)] = 102,
-  [SMALL_STATE(6)] = 136,
-  [SMALL_STATE(7)] = 190,
-  [SMALL_STATE(8)] = 244,
-  [SMALL_STATE(9)] = 282,
-  [SMALL_STATE(10)] = 332,
-  [SMALL_STATE(11)] = 384,
-  [SMALL_STATE(12)] = 438,
-  [SMALL_STATE(13)] = 490,
-  [SMALL_STATE(14)] = 544,
-  [SMALL_STATE(15)] = 598,
-  [SMALL_STATE(16)] = 650,
-  [SMALL_STATE(17)] = 702,
-  [SMALL_STATE(18)] = 754,
-  [SMALL_STATE(19)] = 806,
-  [SMALL_STATE(20)] = 860,
-  [SMALL_STATE(21)] = 912,
-  [SMALL_STATE(22)] = 964,
-  [SMALL_STATE(23)] = 1016,
-  [SMALL_STATE(24)] = 1070,
-  [SMALL_STATE(25)] = 1124,
-  [SMALL_STATE(26)] = 1172,
-  [SMALL_STATE(27)] = 1220,
-  [SMALL_STATE(28)] = 1254,
-  [SMALL_STATE(29)] = 1302,
-  [SMALL_STATE(30)] = 1351,
-  [SMALL_STATE(31)] = 1380,
-  [SMALL_STATE(32)] = 1428,
-  [SMALL_STATE(33)] = 1458,
-  [SMALL_STATE(34)] = 1494,
-  [SMALL_STATE(35)] = 1522,
-  [SMALL_STATE(36)] = 1570,
-  [SMALL_STATE(37)] = 1606,
-  [SMALL_STATE(38)] = 1634,
-  [SMALL_STATE(39)] = 1679,
-  [SMALL_STATE(40)] = 1712,
-  [SMALL_STATE(41)] = 1745,
-  [SMALL_STATE(42)] = 1790,
-  [SMALL_STATE(43)] = 1821,
-  [SMALL_STATE(44)] = 1866,
-  [SMALL_STATE(45)] = 1911,
-  [SMALL_STATE(46)] = 1941,
-  [SMALL_STATE(47)] = 1971,
-  [SMALL_STATE(48)] = 2001,
-  [SMALL_STATE(49)] = 2028,
-  [SMALL_STATE(50)] = 2067,
-  [SMALL_STATE(51)] = 2106,
-  [SMALL_STATE(52)] = 2142,
-  [SMALL_STATE(53)] = 2178,
-  [SMALL_STATE(54)] = 2214,
-  [SMALL_STATE(55)] = 2250,
-  [SMALL_STATE(56)] = 2286,
-  [SMALL_STATE(57)] = 2322,
-  [SMALL_STATE(58)] = 2358,
-  [SMALL_STATE(59)] = 2394,
-  [SMALL_STATE(60)] = 2430,
-  [SMALL_STATE(61)] = 2466,
-  [SMALL_STATE(62)] = 2502,
-  [SMALL_STATE(63)] = 2538,
-  [SMALL_STATE(64)] = 2574,
-  [SMALL_STATE(65)] = 2610,
-  [SMALL_STATE(66)] = 2646,
-  [SMALL_STATE(67)] = 2680,
-  [SMALL_STATE(68)] = 2716,
-  [SMALL_STATE(69)] = 2749,
-  [SMALL_STATE(70)] = 2782,
-  [SMALL_STATE(71)] = 2815,
-  [SMALL_STATE(72)] = 2848,
-  [SMALL_STATE(73)] = 2881,
-  [SMALL_STATE(74)] = 2914,
-  [SMALL_STATE(75)] = 2939,
-  [SMALL_STATE(76)] = 2972,
-  [SMALL_STATE(77)] = 3005,
-  [SMALL_STATE(78)] = 3038,
-  [SMALL_STATE(79)] = 3071,
-  [SMALL_STATE(80)] = 3104,
-  [SMALL_STATE(81)] = 3137,
-  [SMALL_STATE(82)] = 3162,
-  [SMALL_STATE(83)] = 3195,
-  [SMALL_STATE(84)] = 3228,
-  [SMALL_STATE(85)] = 3261,
-  [SMALL_STATE(86)] = 3294,
-  [SMALL_STATE(87)] = 3325,
-  [SMALL_STATE(88)] = 3347,
-  [SMALL_STATE(89)] = 3377,
-  [SMALL_STATE(90)] = 3407,
-  [SMALL_STATE(91)] = 3429,
-  [SMALL_STATE(92)] = 3459,
-  [SMALL_STATE(93)] = 3489,
-  [SMALL_STATE(94)] = 3517,
-  [SMALL_STATE(95)] = 3547,
-  [SMALL_STATE(96)] = 3571,
-  [SMALL_STATE(97)] = 3597,
-  [SMALL_STATE(98)] = 3619,
-  [SMALL_STATE(99)] = 3649,
-  [SMALL_STATE(100)] = 3671,
-  [SMALL_STATE(101)] = 3701,
-  [SMALL_STATE(102)] = 3729,
-  [SMALL_STATE(103)] = 3759,
-  [SMALL_STATE(104)] = 3789,
-  [SMALL_STATE(105)] = 3810,
-  [SMALL_STATE(106)] = 3831,
-  [SMALL_STATE(107)] = 3852,
-  [SMALL_STATE(108)] = 3873,
-  [SMALL_STATE(109)] = 3898,
-  [SMALL_STATE(110)] = 3919,
-  [SMALL_STATE(111)] = 3940,
-  [SMALL_STATE(112)] = 3961,
-  [SMALL_STATE(113)] = 3982,
-  [SMALL_STATE(114)] = 4003,
-  [SMALL_STATE(115)] = 4024,
-  [SMALL_STATE(116)] = 4045,
-  [SMALL_STATE(117)] = 4066,
-  [SMALL_STATE(118)] = 4087,
-  [SMALL_STATE(119)] = 4108,
-  [SMALL_STATE(120)] = 4129,
-  [SMALL_STATE(121)] = 4150,
-  [SMALL_STATE(122)] = 4171,
-  [SMALL_STATE(123)] = 4194,
-  [SMALL_STATE(124)] = 4215,
-  [SMALL_STATE(125)] = 4240,
-  [SMALL_STATE(126)] = 4261,
-  [SMALL_STATE(127)] = 4282,
-  [SMALL_STATE(128)] = 4303,
-  [SMALL_STATE(129)] = 4324,
-  [SMALL_STATE(130)] = 4347,
-  [SMALL_STATE(131)] = 4367,
-  [SMALL_STATE(132)] = 4387,
-  [SMALL_STATE(133)] = 4407,
-  [SMALL_STATE(134)] = 4427,
-  [SMALL_STATE(135)] = 4447,
-  [SMALL_STATE(136)] = 4467,
-  [SMALL_STATE(137)] = 4487,
-  [SMALL_STATE(138)] = 4507,
-  [SMALL_STATE(139)] = 4527,
-  [SMALL_STATE(140)] = 4547,
-  [SMALL_STATE(141)] = 4569,
-  [SMALL_STATE(142)] = 4589,
-  [SMALL_STATE(143)] = 4609,
-  [SMALL_STATE(144)] = 4629,
-  [SMALL_STATE(145)] = 4649,
-  [SMALL_STATE(146)] = 4668,
-  [SMALL_STATE(147)] = 4685,
-  [SMALL_STATE(148)] = 4702,
-  [SMALL_STATE(149)] = 4719,
-  [SMALL_STATE(150)] = 4736,
-  [SMALL_STATE(151)] = 4753,
-  [SMALL_STATE(152)] = 4770,
-  [SMALL_STATE(153)] = 4787,
-  [SMALL_STATE(154)] = 4806,
-  [SMALL_STATE(155)] = 4823,
-  [SMALL_STATE(156)] = 4842,
-  [SMALL_STATE(157)] = 4859,
-  [SMALL_STATE(158)] = 4878,
-  [SMALL_STATE(159)] = 4895,
-  [SMALL_STATE(160)] = 4912,
-  [SMALL_STATE(161)] = 4929,
-  [SMALL_STATE(162)] = 4946,
-  [SMALL_STATE(163)] = 4965,
-  [SMALL_STATE(164)] = 4982,
-  [SMALL_STATE(165)] = 4999,
-  [SMALL_STATE(166)] = 5018,
-  [SMALL_STATE(167)] = 5035,
-  [SMALL_STATE(168)] = 5051,
-  [SMALL_STATE(169)] = 5067,
-  [SMALL_STATE(170)] = 5083,
-  [SMALL_STATE(171)] = 5099,
-  [SMALL_STATE(172)] = 5115,
-  [SMALL_STATE(173)] = 5131,
-  [SMALL_STATE(174)] = 5147,
-  [SMALL_STATE(175)] = 5163,
-  [SMALL_STATE(176)] = 5179,
-  [SMALL_STATE(177)] = 5195,
-  [SMALL_STATE(178)] = 5211,
-  [SMALL_STATE(179)] = 5227,
-  [SMALL_STATE(180)] = 5249,
-  [SMALL_STATE(181)] = 5265,
-  [SMALL_STATE(182)] = 5281,
-  [SMALL_STATE(183)] = 5297,
-  [SMALL_STATE(184)] = 5313,
-  [SMALL_STATE(185)] = 5329,
-  [SMALL_STATE(186)] = 5345,
-  [SMALL_STATE(187)] = 5361,
-  [SMALL_STATE(188)] = 5377,
-  [SMALL_STATE(189)] = 5393,
-  [SMALL_STATE(190)] = 5409,
-  [SMALL_STATE(191)] = 5425,
-  [SMALL_STATE(192)] = 5441,
-  [SMALL_STATE(193)] = 5457,
-  [SMALL_STATE(194)] = 5473,
-  [SMALL_STATE(195)] = 5495,
-  [SMALL_STATE(196)] = 5511,
-  [SMALL_STATE(197)] = 5533,
-  [SMALL_STATE(198)] = 5549,
-  [SMALL_STATE(199)] = 5565,
-  [SMALL_STATE(200)] = 5581,
-  [SMALL_STATE(201)] = 5597,
-  [SMALL_STATE(202)] = 5613,
-  [SMALL_STATE(203)] = 5629,
-  [SMALL_STATE(204)] = 5652,
-  [SMALL_STATE(205)] = 5675,
-  [SMALL_STATE(206)] = 5696,
-  [SMALL_STATE(207)] = 5717,
-  [SMALL_STATE(208)] = 5740,
-  [SMALL_STATE(209)] = 5763,
-  [SMALL_STATE(210)] = 5784,
-  [SMALL_STATE(211)] = 5807,
-  [SMALL_STATE(212)] = 5828,
-  [SMALL_STATE(213)] = 5851,
-  [SMALL_STATE(214)] = 5874,
-  [SMALL_STATE(215)] = 5897,
-  [SMALL_STATE(216)] = 5915,
-  [SMALL_STATE(217)] = 5935,
-  [SMALL_STATE(218)] = 5955,
-  [SMALL_STATE(219)] = 5973,
-  [SMALL_STATE(220)] = 5991,
-  [SMALL_STATE(221)] = 6009,
-  [SMALL_STATE(222)] = 6025,
-  [SMALL_STATE(223)] = 6042,
-  [SMALL_STATE(224)] = 6057,
-  [SMALL_STATE(225)] = 6074,
-  [SMALL_STATE(226)] = 6091,
-  [SMALL_STATE(227)] = 6106,
-  [SMALL_STATE(228)] = 6123,
-  [SMALL_STATE(229)] = 6140,
-  [SMALL_STATE(230)] = 6157,
-  [SMALL_STATE(231)] = 6172,
-  [SMALL_STATE(232)] = 6185,
-  [SMALL_STATE(233)] = 6202,
-  [SMALL_STATE(234)] = 6219,
-  [SMALL_STATE(235)] = 6236,
-  [SMALL_STATE(236)] = 6253,
-  [SMALL_STATE(237)] = 6268,
-  [SMALL_STATE(238)] = 6285,
-  [SMALL_STATE(239)] = 6302,
-  [SMALL_STATE(240)] = 6319,
-  [SMALL_STATE(241)] = 6336,
-  [SMALL_STATE(242)] = 6353,
-  [SMALL_STATE(243)] = 6366,
-  [SMALL_STATE(244)] = 6379,
-  [SMALL_STATE(245)] = 6392,
-  [SMALL_STATE(246)] = 6407,
-  [SMALL_STATE(247)] = 6424,
-  [SMALL_STATE(248)] = 6441,
-  [SMALL_STATE(249)] = 6455,
-  [SMALL_STATE(250)] = 6469,
-  [SMALL_STATE(251)] = 6483,
-  [SMALL_STATE(252)] = 6497,
-  [SMALL_STATE(253)] = 6511,
-  [SMALL_STATE(254)] = 6523,
-  [SMALL_STATE(255)] = 6537,
-  [SMALL_STATE(256)] = 6549,
-  [SMALL_STATE(257)] = 6563,
-  [SMALL_STATE(258)] = 6577,
-  [SMALL_STATE(259)] = 6591,
-  [SMALL_STATE(260)] = 6605,
-  [SMALL_STATE(261)] = 6619,
-  [SMALL_STATE(262)] = 6631,
-  [SMALL_STATE(263)] = 6645,
-  [SMALL_STATE(264)] = 6657,
-  [SMALL_STATE(265)] = 6671,
-  [SMALL_STATE(266)] = 6685,
-  [SMALL_STATE(267)] = 6699,
-  [SMALL_STATE(268)] = 6713,
-  [SMALL_STATE(269)] = 6724,
-  [SMALL_STATE(270)] = 6735,
-  [SMALL_STATE(271)] = 6746,
-  [SMALL_STATE(272)] = 6757,
-  [SMALL_STATE(273)] = 6768,
-  [SMALL_STATE(274)] = 6779,
-  [SMALL_STATE(275)] = 6790,
-  [SMALL_STATE(276)] = 6801,
-  [SMALL_STATE(277)] = 6812,
-  [SMALL_STATE(278)] = 6823,
-  [SMALL_STATE(279)] = 6834,
-  [SMALL_STATE(280)] = 6845,
-  [SMALL_STATE(281)] = 6856,
-  [SMALL_STATE(282)] = 6867,
-  [SMALL_STATE(283)] = 6878,
-  [SMALL_STATE(284)] = 6889,
-  [SMALL_STATE(285)] = 6900,
-  [SMALL_STATE(286)] = 6911,
-  [SMALL_STATE(287)] = 6922,
-  [SMALL_STATE(288)] = 6933,
-  [SMALL_STATE(289)] = 6944,
-  [SMALL_STATE(290)] = 6955,
-  [SMALL_STATE(291)] = 6966,
-  [SMALL_STATE(292)] = 6977,
-  [SMALL_STATE(293)] = 6988,
-  [SMALL_STATE(294)] = 6999,
-  [SMALL_STATE(295)] = 7010,
-  [SMALL_STATE(296)] = 7021,
-  [SMALL_STATE(297)] = 7032,
-  [SMALL_STATE(298)] = 7043,
-  [SMALL_STATE(299)] = 7054,
-  [SMALL_STATE(300)] = 7065,
-  [SMALL_STATE(301)] = 7076,
-  [SMALL_STATE(302)] = 7087,
-  [SMALL_STATE(303)] = 7098,
-  [SMALL_STATE(304)] = 7109,
-  [SMALL_STATE(305)] = 7120,
-  [SMALL_STATE(306)] = 7131,
-  [SMALL_STATE(307)] = 7142,
-  [SMALL_STATE(308)] = 7153,
-  [SMALL_STATE(309)] = 7164,
-  [SMALL_STATE(310)] = 7175,
-  [SMALL_STATE(311)] = 7186,
-  [SMALL_STATE(312)] = 7197,
-  [SMALL_STATE(313)] = 7208,
-  [SMALL_STATE(314)] = 7219,
-  [SMALL_STATE(315)] = 7230,
-  [SMALL_STATE(316)] = 7241,
-  [SMALL_STATE(317)] = 7252,
-  [SMALL_STATE(318)] = 7263,
-  [SMALL_STATE(319)] = 7274,
-  [SMALL_STATE(320)] = 7285,
-  [SMALL_STATE(321)] = 7296,
-  [SMALL_STATE(322)] = 7307,
-  [SMALL_STATE(323)] = 7318,
-  [SMALL_STATE(324)] = 7329,
-  [SMALL_STATE(325)] = 7340,
-  [SMALL_STATE(326)] = 7351,
-  [SMALL_STATE(327)] = 7362,
-  [SMALL_STATE(328)] = 7373,
-  [SMALL_STATE(329)] = 7384,
-  [SMALL_STATE(330)] = 7395,
-  [SMALL_STATE(331)] = 7406,
+  [SMALL_STATE(3)] = 94,
+  [SMALL_STATE(4)] = 185,
+  [SMALL_STATE(5)] = 264,
+  [SMALL_STATE(6)] = 342,
+  [SMALL_STATE(7)] = 420,
+  [SMALL_STATE(8)] = 498,
+  [SMALL_STATE(9)] = 545,
+  [SMALL_STATE(10)] = 592,
+  [SMALL_STATE(11)] = 667,
+  [SMALL_STATE(12)] = 739,
+  [SMALL_STATE(13)] = 784,
+  [SMALL_STATE(14)] = 853,
+  [SMALL_STATE(15)] = 904,
+  [SMALL_STATE(16)] = 973,
+  [SMALL_STATE(17)] = 1042,
+  [SMALL_STATE(18)] = 1111,
+  [SMALL_STATE(19)] = 1156,
+  [SMALL_STATE(20)] = 1201,
+  [SMALL_STATE(21)] = 1246,
+  [SMALL_STATE(22)] = 1291,
+  [SMALL_STATE(23)] = 1336,
+  [SMALL_STATE(24)] = 1381,
+  [SMALL_STATE(25)] = 1450,
+  [SMALL_STATE(26)] = 1519,
+  [SMALL_STATE(27)] = 1588,
+  [SMALL_STATE(28)] = 1657,
+  [SMALL_STATE(29)] = 1726,
+  [SMALL_STATE(30)] = 1795,
+  [SMALL_STATE(31)] = 1864,
+  [SMALL_STATE(32)] = 1933,
+  [SMALL_STATE(33)] = 1999,
+  [SMALL_STATE(34)] = 2065,
+  [SMALL_STATE(35)] = 2131,
+  [SMALL_STATE(36)] = 2197,
+  [SMALL_STATE(37)] = 2263,
+  [SMALL_STATE(38)] = 2329,
+  [SMALL_STATE(39)] = 2395,
+  [SMALL_STATE(40)] = 2461,
+  [SMALL_STATE(41)] = 2527,
+  [SMALL_STATE(42)] = 2590,
+  [SMALL_STATE(43)] = 2653,
+  [SMALL_STATE(44)] = 2716,
+  [SMALL_STATE(45)] = 2779,
+  [SMALL_STATE(46)] = 2842,
+  [SMALL_STATE(47)] = 2905,
+  [SMALL_STATE(48)] = 2968,
+  [SMALL_STATE(49)] = 3031,
+  [SMALL_STATE(50)] = 3094,
+  [SMALL_STATE(51)] = 3157,
+  [SMALL_STATE(52)] = 3220,
+  [SMALL_STATE(53)] = 3283,
+  [SMALL_STATE(54)] = 3346,
+  [SMALL_STATE(55)] = 3409,
+  [SMALL_STATE(56)] = 3472,
+  [SMALL_STATE(57)] = 3535,
+  [SMALL_STATE(58)] = 3598,
+  [SMALL_STATE(59)] = 3661,
+  [SMALL_STATE(60)] = 3724,
+  [SMALL_STATE(61)] = 3787,
+  [SMALL_STATE(62)] = 3850,
+  [SMALL_STATE(63)] = 3913,
+  [SMALL_STATE(64)] = 3959,
+  [SMALL_STATE(65)] = 4009,
+  [SMALL_STATE(66)] = 4059,
+  [SMALL_STATE(67)] = 4100,
+  [SMALL_STATE(68)] = 4141,
+  [SMALL_STATE(69)] = 4182,
+  [SMALL_STATE(70)] = 4223,
+  [SMALL_STATE(71)] = 4264,
+  [SMALL_STATE(72)] = 4305,
+  [SMALL_STATE(73)] = 4346,
+  [SMALL_STATE(74)] = 4399,
+  [SMALL_STATE(75)] = 4440,
+  [SMALL_STATE(76)] = 4481,
+  [SMALL_STATE(77)] = 4522,
+  [SMALL_STATE(78)] = 4563,
+  [SMALL_STATE(79)] = 4604,
+  [SMALL_STATE(80)] = 4645,
+  [SMALL_STATE(81)] = 4702,
+  [SMALL_STATE(82)] = 4761,
+  [SMALL_STATE(83)] = 4822,
+  [SMALL_STATE(84)] = 4863,
+  [SMALL_STATE(85)] = 4904,
+  [SMALL_STATE(86)] = 4945,
+  [SMALL_STATE(87)] = 4986,
+  [SMALL_STATE(88)] = 5027,
+  [SMALL_STATE(89)] = 5068,
+  [SMALL_STATE(90)] = 5109,
+  [SMALL_STATE(91)] = 5150,
+  [SMALL_STATE(92)] = 5191,
+  [SMALL_STATE(93)] = 5232,
+  [SMALL_STATE(94)] = 5273,
+  [SMALL_STATE(95)] = 5314,
+  [SMALL_STATE(96)] = 5381,
+  [SMALL_STATE(97)] = 5448,
+  [SMALL_STATE(98)] = 5515,
+  [SMALL_STATE(99)] = 5582,
+  [SMALL_STATE(100)] = 5647,
+  [SMALL_STATE(101)] = 5715,
+  [SMALL_STATE(102)] = 5779,
+  [SMALL_STATE(103)] = 5845,
+  [SMALL_STATE(104)] = 5909,
+  [SMALL_STATE(105)] = 5975,
+  [SMALL_STATE(106)] = 6025,
+  [SMALL_STATE(107)] = 6087,
+  [SMALL_STATE(108)] = 6147,
+  [SMALL_STATE(109)] = 6212,
+  [SMALL_STATE(110)] = 6273,
+  [SMALL_STATE(111)] = 6334,
+  [SMALL_STATE(112)] = 6395,
+  [SMALL_STATE(113)] = 6462,
+  [SMALL_STATE(114)] = 6523,
+  [SMALL_STATE(115)] = 6584,
+  [SMALL_STATE(116)] = 6647,
+  [SMALL_STATE(117)] = 6708,
+  [SMALL_STATE(118)] = 6773,
+  [SMALL_STATE(119)] = 6836,
+  [SMALL_STATE(120)] = 6899,
+  [SMALL_STATE(121)] = 6958,
+  [SMALL_STATE(122)] = 7021,
+  [SMALL_STATE(123)] = 7084,
+  [SMALL_STATE(124)] = 7143,
+  [SMALL_STATE(125)] = 7206,
+  [SMALL_STATE(126)] = 7269,
+  [SMALL_STATE(127)] = 7327,
+  [SMALL_STATE(128)] = 7389,
+  [SMALL_STATE(129)] = 7447,
+  [SMALL_STATE(130)] = 7505,
+  [SMALL_STATE(131)] = 7569,
+  [SMALL_STATE(132)] = 7631,
+  [SMALL_STATE(133)] = 7689,
+  [SMALL_STATE(134)] = 7747,
+  [SMALL_STATE(135)] = 7805,
+  [SMALL_STATE(136)] = 7863,
+  [SMALL_STATE(137)] = 7901,
+  [SMALL_STATE(138)] = 7965,
+  [SMALL_STATE(139)] = 8023,
+  [SMALL_STATE(140)] = 8071,
+  [SMALL_STATE(141)] = 8123,
+  [SMALL_STATE(142)] = 8177,
+  [SMALL_STATE(143)] = 8233,
+  [SMALL_STATE(144)] = 8295,
+  [SMALL_STATE(145)] = 8359,
+  [SMALL_STATE(146)] = 8423,
+  [SMALL_STATE(147)] = 8485,
+  [SMALL_STATE(148)] = 8543,
+  [SMALL_STATE(149)] = 8601,
+  [SMALL_STATE(150)] = 8660,
+  [SMALL_STATE(151)] = 8719,
+  [SMALL_STATE(152)] = 8778,
+  [SMALL_STATE(153)] = 8833,
+  [SMALL_STATE(154)] = 8892,
+  [SMALL_STATE(155)] = 8951,
+  [SMALL_STATE(156)] = 9012,
+  [SMALL_STATE(157)] = 9071,
+  [SMALL_STATE(158)] = 9130,
+  [SMALL_STATE(159)] = 9188,
+  [SMALL_STATE(160)] = 9246,
+  [SMALL_STATE(161)] = 9304,
+  [SMALL_STATE(162)] = 9360,
+  [SMALL_STATE(163)] = 9418,
+  [SMALL_STATE(164)] = 9476,
+  [SMALL_STATE(165)] = 9534,
+  [SMALL_STATE(166)] = 9590,
+  [SMALL_STATE(167)] = 9646,
+  [SMALL_STATE(168)] = 9704,
+  [SMALL_STATE(169)] = 9762,
+  [SMALL_STATE(170)] = 9820,
+  [SMALL_STATE(171)] = 9878,
+  [SMALL_STATE(172)] = 9936,
+  [SMALL_STATE(173)] = 9988,
+  [SMALL_STATE(174)] = 10040,
+  [SMALL_STATE(175)] = 10092,
+  [SMALL_STATE(176)] = 10144,
+  [SMALL_STATE(177)] = 10196,
+  [SMALL_STATE(178)] = 10248,
+  [SMALL_STATE(179)] = 10300,
+  [SMALL_STATE(180)] = 10352,
+  [SMALL_STATE(181)] = 10404,
+  [SMALL_STATE(182)] = 10456,
+  [SMALL_STATE(183)] = 10508,
+  [SMALL_STATE(184)] = 10560,
+  [SMALL_STATE(185)] = 10612,
+  [SMALL_STATE(186)] = 10664,
+  [SMALL_STATE(187)] = 10716,
+  [SMALL_STATE(188)] = 10768,
+  [SMALL_STATE(189)] = 10820,
+  [SMALL_STATE(190)] = 10872,
+  [SMALL_STATE(191)] = 10924,
+  [SMALL_STATE(192)] = 10976,
+  [SMALL_STATE(193)] = 11028,
+  [SMALL_STATE(194)] = 11080,
+  [SMALL_STATE(195)] = 11132,
+  [SMALL_STATE(196)] = 11184,
+  [SMALL_STATE(197)] = 11236,
+  [SMALL_STATE(198)] = 11288,
+  [SMALL_STATE(199)] = 11340,
+  [SMALL_STATE(200)] = 11392,
+  [SMALL_STATE(201)] = 11444,
+  [SMALL_STATE(202)] = 11496,
+  [SMALL_STATE(203)] = 11548,
+  [SMALL_STATE(204)] = 11600,
+  [SMALL_STATE(205)] = 11652,
+  [SMALL_STATE(206)] = 11704,
+  [SMALL_STATE(207)] = 11756,
+  [SMALL_STATE(208)] = 11808,
+  [SMALL_STATE(209)] = 11854,
+  [SMALL_STATE(210)] = 11883,
+  [SMALL_STATE(211)] = 11920,
+  [SMALL_STATE(212)] = 11949,
+  [SMALL_STATE(213)] = 11978,
+  [SMALL_STATE(214)] = 12007,
+  [SMALL_STATE(215)] = 12035,
+  [SMALL_STATE(216)] = 12063,
+  [SMALL_STATE(217)] = 12091,
+  [SMALL_STATE(218)] = 12119,
+  [SMALL_STATE(219)] = 12147,
+  [SMALL_STATE(220)] = 12175,
+  [SMALL_STATE(221)] = 12203,
+  [SMALL_STATE(222)] = 12231,
+  [SMALL_STATE(223)] = 12259,
+  [SMALL_STATE(224)] = 12287,
+  [SMALL_STATE(225)] = 12315,
+  [SMALL_STATE(226)] = 12343,
+  [SMALL_STATE(227)] = 12371,
+  [SMALL_STATE(228)] = 12399,
+  [SMALL_STATE(229)] = 12427,
+  [SMALL_STATE(230)] = 12455,
+  [SMALL_STATE(231)] = 12483,
+  [SMALL_STATE(232)] = 12511,
+  [SMALL_STATE(233)] = 12541,
+  [SMALL_STATE(234)] = 12568,
+  [SMALL_STATE(235)] = 12603,
+  [SMALL_STATE(236)] = 12648,
+  [SMALL_STATE(237)] = 12675,
+  [SMALL_STATE(238)] = 12701,
+  [SMALL_STATE(239)] = 12727,
+  [SMALL_STATE(240)] = 12756,
+  [SMALL_STATE(241)] = 12785,
+  [SMALL_STATE(242)] = 12814,
+  [SMALL_STATE(243)] = 12839,
+  [SMALL_STATE(244)] = 12866,
+  [SMALL_STATE(245)] = 12891,
+  [SMALL_STATE(246)] = 12920,
+  [SMALL_STATE(247)] = 12949,
+  [SMALL_STATE(248)] = 12976,
+  [SMALL_STATE(249)] = 13003,
+  [SMALL_STATE(250)] = 13027,
+  [SMALL_STATE(251)] = 13051,
+  [SMALL_STATE(252)] = 13087,
+  [SMALL_STATE(253)] = 13111,
+  [SMALL_STATE(254)] = 13135,
+  [SMALL_STATE(255)] = 13159,
+  [SMALL_STATE(256)] = 13183,
+  [SMALL_STATE(257)] = 13207,
+  [SMALL_STATE(258)] = 13231,
+  [SMALL_STATE(259)] = 13255,
+  [SMALL_STATE(260)] = 13279,
+  [SMALL_STATE(261)] = 13303,
+  [SMALL_STATE(262)] = 13327,
+  [SMALL_STATE(263)] = 13351,
+  [SMALL_STATE(264)] = 13375,
+  [SMALL_STATE(265)] = 13399,
+  [SMALL_STATE(266)] = 13425,
+  [SMALL_STATE(267)] = 13449,
+  [SMALL_STATE(268)] = 13473,
+  [SMALL_STATE(269)] = 13497,
+  [SMALL_STATE(270)] = 13521,
+  [SMALL_STATE(271)] = 13545,
+  [SMALL_STATE(272)] = 13569,
+  [SMALL_STATE(273)] = 13605,
+  [SMALL_STATE(274)] = 13629,
+  [SMALL_STATE(275)] = 13653,
+  [SMALL_STATE(276)] = 13677,
+  [SMALL_STATE(277)] = 13701,
+  [SMALL_STATE(278)] = 13725,
+  [SMALL_STATE(279)] = 13749,
+  [SMALL_STATE(280)] = 13775,
+  [SMALL_STATE(281)] = 13809,
+  [SMALL_STATE(282)] = 13833,
+  [SMALL_STATE(283)] = 13857,
+  [SMALL_STATE(284)] = 13891,
+  [SMALL_STATE(285)] = 13924,
+  [SMALL_STATE(286)] = 13959,
+  [SMALL_STATE(287)] = 13986,
+  [SMALL_STATE(288)] = 14017,
+  [SMALL_STATE(289)] = 14050,
+  [SMALL_STATE(290)] = 14073,
+  [SMALL_STATE(291)] = 14106,
+  [SMALL_STATE(292)] = 14139,
+  [SMALL_STATE(293)] = 14172,
+  [SMALL_STATE(294)] = 14195,
+  [SMALL_STATE(295)] = 14228,
+  [SMALL_STATE(296)] = 14261,
+  [SMALL_STATE(297)] = 14294,
+  [SMALL_STATE(298)] = 14327,
+  [SMALL_STATE(299)] = 14360,
+  [SMALL_STATE(300)] = 14385,
+  [SMALL_STATE(301)] = 14418,
+  [SMALL_STATE(302)] = 14451,
+  [SMALL_STATE(303)] = 14478,
+  [SMALL_STATE(304)] = 14511,
+  [SMALL_STATE(305)] = 14542,
+  [SMALL_STATE(306)] = 14575,
+  [SMALL_STATE(307)] = 14606,
+  [SMALL_STATE(308)] = 14629,
+  [SMALL_STATE(309)] = 14652,
+  [SMALL_STATE(310)] = 14673,
+  [SMALL_STATE(311)] = 14694,
+  [SMALL_STATE(312)] = 14717,
+  [SMALL_STATE(313)] = 14750,
+  [SMALL_STATE(314)] = 14772,
+  [SMALL_STATE(315)] = 14794,
+  [SMALL_STATE(316)] = 14816,
+  [SMALL_STATE(317)] = 14838,
+  [SMALL_STATE(318)] = 14860,
+  [SMALL_STATE(319)] = 14882,
+  [SMALL_STATE(320)] = 14904,
+  [SMALL_STATE(321)] = 14926,
+  [SMALL_STATE(322)] = 14948,
+  [SMALL_STATE(323)] = 14970,
+  [SMALL_STATE(324)] = 14992,
+  [SMALL_STATE(325)] = 15014,
+  [SMALL_STATE(326)] = 15036,
+  [SMALL_STATE(327)] = 15058,
+  [SMALL_STATE(328)] = 15080,
+  [SMALL_STATE(329)] = 15102,
+  [SMALL_STATE(330)] = 15124,
+  [SMALL_STATE(331)] = 15146,
+  [SMALL_STATE(332)] = 15168,
+  [SMALL_STATE(333)] = 15190,
+  [SMALL_STATE(334)] = 15212,
+  [SMALL_STATE(335)] = 15234,
+  [SMALL_STATE(336)] = 15256,
+  [SMALL_STATE(337)] = 15278,
+  [SMALL_STATE(338)] = 15300,
+  [SMALL_STATE(339)] = 15322,
+  [SMALL_STATE(340)] = 15345,
+  [SMALL_STATE(341)] = 15370,
+  [SMALL_STATE(342)] = 15393,
+  [SMALL_STATE(343)] = 15413,
+  [SMALL_STATE(344)] = 15433,
+  [SMALL_STATE(345)] = 15453,
+  [SMALL_STATE(346)] = 15473,
+  [SMALL_STATE(347)] = 15493,
+  [SMALL_STATE(348)] = 15513,
+  [SMALL_STATE(349)] = 15533,
+  [SMALL_STATE(350)] = 15553,
+  [SMALL_STATE(351)] = 15573,
+  [SMALL_STATE(352)] = 15590,
+  [SMALL_STATE(353)] = 15607,
+  [SMALL_STATE(354)] = 15624,
+  [SMALL_STATE(355)] = 15641,
+  [SMALL_STATE(356)] = 15658,
+  [SMALL_STATE(357)] = 15675,
+  [SMALL_STATE(358)] = 15694,
+  [SMALL_STATE(359)] = 15711,
+  [SMALL_STATE(360)] = 15727,
+  [SMALL_STATE(361)] = 15743,
+  [SMALL_STATE(362)] = 15759,
+  [SMALL_STATE(363)] = 15775,
+  [SMALL_STATE(364)] = 15791,
+  [SMALL_STATE(365)] = 15807,
+  [SMALL_STATE(366)] = 15829,
+  [SMALL_STATE(367)] = 15845,
+  [SMALL_STATE(368)] = 15861,
+  [SMALL_STATE(369)] = 15877,
+  [SMALL_STATE(370)] = 15901,
+  [SMALL_STATE(371)] = 15917,
+  [SMALL_STATE(372)] = 15933,
+  [SMALL_STATE(373)] = 15949,
+  [SMALL_STATE(374)] = 15973,
+  [SMALL_STATE(375)] = 15997,
+  [SMALL_STATE(376)] = 16019,
+  [SMALL_STATE(377)] = 16035,
+  [SMALL_STATE(378)] = 16059,
+  [SMALL_STATE(379)] = 16075,
+  [SMALL_STATE(380)] = 16091,
+  [SMALL_STATE(381)] = 16115,
+  [SMALL_STATE(382)] = 16131,
+  [SMALL_STATE(383)] = 16155,
+  [SMALL_STATE(384)] = 16179,
+  [SMALL_STATE(385)] = 16201,
+  [SMALL_STATE(386)] = 16225,
+  [SMALL_STATE(387)] = 16241,
+  [SMALL_STATE(388)] = 16257,
+  [SMALL_STATE(389)] = 16279,
+  [SMALL_STATE(390)] = 16301,
+  [SMALL_STATE(391)] = 16317,
+  [SMALL_STATE(392)] = 16333,
+  [SMALL_STATE(393)] = 16354,
+  [SMALL_STATE(394)] = 16377,
+  [SMALL_STATE(395)] = 16400,
+  [SMALL_STATE(396)] = 16423,
+  [SMALL_STATE(397)] = 16444,
+  [SMALL_STATE(398)] = 16467,
+  [SMALL_STATE(399)] = 16490,
+  [SMALL_STATE(400)] = 16513,
+  [SMALL_STATE(401)] = 16536,
+  [SMALL_STATE(402)] = 16559,
+  [SMALL_STATE(403)] = 16580,
+  [SMALL_STATE(404)] = 16603,
+  [SMALL_STATE(405)] = 16626,
+  [SMALL_STATE(406)] = 16646,
+  [SMALL_STATE(407)] = 16666,
+  [SMALL_STATE(408)] = 16686,
+  [SMALL_STATE(409)] = 16702,
+  [SMALL_STATE(410)] = 16720,
+  [SMALL_STATE(411)] = 16738,
+  [SMALL_STATE(412)] = 16758,
+  [SMALL_STATE(413)] = 16776,
+  [SMALL_STATE(414)] = 16794,
+  [SMALL_STATE(415)] = 16812,
+  [SMALL_STATE(416)] = 16832,
+  [SMALL_STATE(417)] = 16852,
+  [SMALL_STATE(418)] = 16868,
+  [SMALL_STATE(419)] = 16888,
+  [SMALL_STATE(420)] = 16906,
+  [SMALL_STATE(421)] = 16924,
+  [SMALL_STATE(422)] = 16942,
+  [SMALL_STATE(423)] = 16957,
+  [SMALL_STATE(424)] = 16974,
+  [SMALL_STATE(425)] = 16991,
+  [SMALL_STATE(426)] = 17008,
+  [SMALL_STATE(427)] = 17025,
+  [SMALL_STATE(428)] = 17042,
+  [SMALL_STATE(429)] = 17057,
+  [SMALL_STATE(430)] = 17072,
+  [SMALL_STATE(431)] = 17089,
+  [SMALL_STATE(432)] = 17104,
+  [SMALL_STATE(433)] = 17117,
+  [SMALL_STATE(434)] = 17132,
+  [SMALL_STATE(435)] = 17145,
+  [SMALL_STATE(436)] = 17160,
+  [SMALL_STATE(437)] = 17173,
+  [SMALL_STATE(438)] = 17190,
+  [SMALL_STATE(439)] = 17207,
+  [SMALL_STATE(440)] = 17224,
+  [SMALL_STATE(441)] = 17241,
+  [SMALL_STATE(442)] = 17254,
+  [SMALL_STATE(443)] = 17271,
+  [SMALL_STATE(444)] = 17288,
+  [SMALL_STATE(445)] = 17305,
+  [SMALL_STATE(446)] = 17318,
+  [SMALL_STATE(447)] = 17335,
+  [SMALL_STATE(448)] = 17352,
+  [SMALL_STATE(449)] = 17367,
+  [SMALL_STATE(450)] = 17380,
+  [SMALL_STATE(451)] = 17395,
+  [SMALL_STATE(452)] = 17412,
+  [SMALL_STATE(453)] = 17429,
+  [SMALL_STATE(454)] = 17444,
+  [SMALL_STATE(455)] = 17461,
+  [SMALL_STATE(456)] = 17478,
+  [SMALL_STATE(457)] = 17495,
+  [SMALL_STATE(458)] = 17510,
+  [SMALL_STATE(459)] = 17527,
+  [SMALL_STATE(460)] = 17541,
+  [SMALL_STATE(461)] = 17555,
+  [SMALL_STATE(462)] = 17569,
+  [SMALL_STATE(463)] = 17583,
+  [SMALL_STATE(464)] = 17595,
+  [SMALL_STATE(465)] = 17607,
+  [SMALL_STATE(466)] = 17619,
+  [SMALL_STATE(467)] = 17633,
+  [SMALL_STATE(468)] = 17647,
+  [SMALL_STATE(469)] = 17661,
+  [SMALL_STATE(470)] = 17675,
+  [SMALL_STATE(471)] = 17689,
+  [SMALL_STATE(472)] = 17703,
+  [SMALL_STATE(473)] = 17715,
+  [SMALL_STATE(474)] = 17729,
+  [SMALL_STATE(475)] = 17741,
+  [SMALL_STATE(476)] = 17753,
+  [SMALL_STATE(477)] = 17767,
+  [SMALL_STATE(478)] = 17781,
+  [SMALL_STATE(479)] = 17795,
+  [SMALL_STATE(480)] = 17809,
+  [SMALL_STATE(481)] = 17823,
+  [SMALL_STATE(482)] = 17837,
+  [SMALL_STATE(483)] = 17851,
+  [SMALL_STATE(484)] = 17865,
+  [SMALL_STATE(485)] = 17879,
+  [SMALL_STATE(486)] = 17893,
+  [SMALL_STATE(487)] = 17907,
+  [SMALL_STATE(488)] = 17919,
+  [SMALL_STATE(489)] = 17931,
+  [SMALL_STATE(490)] = 17945,
+  [SMALL_STATE(491)] = 17959,
+  [SMALL_STATE(492)] = 17971,
+  [SMALL_STATE(493)] = 17985,
+  [SMALL_STATE(494)] = 17999,
+  [SMALL_STATE(495)] = 18013,
+  [SMALL_STATE(496)] = 18025,
+  [SMALL_STATE(497)] = 18039,
+  [SMALL_STATE(498)] = 18051,
+  [SMALL_STATE(499)] = 18065,
+  [SMALL_STATE(500)] = 18079,
+  [SMALL_STATE(501)] = 18093,
+  [SMALL_STATE(502)] = 18105,
+  [SMALL_STATE(503)] = 18119,
+  [SMALL_STATE(504)] = 18133,
+  [SMALL_STATE(505)] = 18147,
+  [SMALL_STATE(506)] = 18161,
+  [SMALL_STATE(507)] = 18173,
+  [SMALL_STATE(508)] = 18185,
+  [SMALL_STATE(509)] = 18197,
+  [SMALL_STATE(510)] = 18211,
+  [SMALL_STATE(511)] = 18225,
+  [SMALL_STATE(512)] = 18239,
+  [SMALL_STATE(513)] = 18253,
+  [SMALL_STATE(514)] = 18267,
+  [SMALL_STATE(515)] = 18281,
+  [SMALL_STATE(516)] = 18292,
+  [SMALL_STATE(517)] = 18303,
+  [SMALL_STATE(518)] = 18314,
+  [SMALL_STATE(519)] = 18325,
+  [SMALL_STATE(520)] = 18336,
+  [SMALL_STATE(521)] = 18347,
+  [SMALL_STATE(522)] = 18358,
+  [SMALL_STATE(523)] = 18369,
+  [SMALL_STATE(524)] = 18380,
+  [SMALL_STATE(525)] = 18391,
+  [SMALL_STATE(526)] = 18402,
+  [SMALL_STATE(527)] = 18413,
+  [SMALL_STATE(528)] = 18424,
+  [SMALL_STATE(529)] = 18435,
+  [SMALL_STATE(530)] = 18446,
+  [SMALL_STATE(531)] = 18457,
+  [SMALL_STATE(532)] = 18468,
+  [SMALL_STATE(533)] = 18479,
+  [SMALL_STATE(534)] = 18490,
+  [SMALL_STATE(535)] = 18501,
+  [SMALL_STATE(536)] = 18512,
+  [SMALL_STATE(537)] = 18523,
+  [SMALL_STATE(538)] = 18534,
+  [SMALL_STATE(539)] = 18545,
+  [SMALL_STATE(540)] = 18556,
+  [SMALL_STATE(541)] = 18567,
+  [SMALL_STATE(542)] = 18578,
+  [SMALL_STATE(543)] = 18589,
+  [SMALL_STATE(544)] = 18600,
+  [SMALL_STATE(545)] = 18611,
+  [SMALL_STATE(546)] = 18622,
+  [SMALL_STATE(547)] = 18633,
+  [SMALL_STATE(548)] = 18644,
+  [SMALL_STATE(549)] = 18655,
+  [SMALL_STATE(550)] = 18666,
+  [SMALL_STATE(551)] = 18677,
+  [SMALL_STATE(552)] = 18688,
+  [SMALL_STATE(553)] = 18699,
+  [SMALL_STATE(554)] = 18710,
+  [SMALL_STATE(555)] = 18721,
+  [SMALL_STATE(556)] = 18732,
+  [SMALL_STATE(557)] = 18743,
+  [SMALL_STATE(558)] = 18754,
+  [SMALL_STATE(559)] = 18765,
+  [SMALL_STATE(560)] = 18776,
+  [SMALL_STATE(561)] = 18787,
+  [SMALL_STATE(562)] = 18798,
+  [SMALL_STATE(563)] = 18809,
+  [SMALL_STATE(564)] = 18820,
+  [SMALL_STATE(565)] = 18831,
+  [SMALL_STATE(566)] = 18842,
+  [SMALL_STATE(567)] = 18853,
+  [SMALL_STATE(568)] = 18864,
+  [SMALL_STATE(569)] = 18875,
+  [SMALL_STATE(570)] = 18886,
+  [SMALL_STATE(571)] = 18897,
+  [SMALL_STATE(572)] = 18908,
+  [SMALL_STATE(573)] = 18919,
+  [SMALL_STATE(574)] = 18930,
+  [SMALL_STATE(575)] = 18941,
+  [SMALL_STATE(576)] = 18952,
+  [SMALL_STATE(577)] = 18963,
+  [SMALL_STATE(578)] = 18974,
+  [SMALL_STATE(579)] = 18985,
+  [SMALL_STATE(580)] = 18996,
+  [SMALL_STATE(581)] = 19007,
+  [SMALL_STATE(582)] = 19018,
+  [SMALL_STATE(583)] = 19029,
+  [SMALL_STATE(584)] = 19040,
+  [SMALL_STATE(585)] = 19051,
+  [SMALL_STATE(586)] = 19062,
+  [SMALL_STATE(587)] = 19073,
+  [SMALL_STATE(588)] = 19084,
+  [SMALL_STATE(589)] = 19095,
+  [SMALL_STATE(590)] = 19106,
+  [SMALL_STATE(591)] = 19117,
+  [SMALL_STATE(592)] = 19128,
+  [SMALL_STATE(593)] = 19139,
+  [SMALL_STATE(594)] = 19150,
+  [SMALL_STATE(595)] = 19161,
+  [SMALL_STATE(596)] = 19172,
+  [SMALL_STATE(597)] = 19183,
+  [SMALL_STATE(598)] = 19194,
+  [SMALL_STATE(599)] = 19205,
+  [SMALL_STATE(600)] = 19216,
+  [SMALL_STATE(601)] = 19227,
 };
 
 static const TSParseActionEntry ts_parse_actions[] = {
   [0] = {.entry = {.count = 0, .reusable = false}},
   [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
-  [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT(331),
+  [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT(601),
   [5] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 0, 0, 0),
-  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(278),
-  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(205),
-  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(228),
-  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(229),
-  [15] = {.entry = {.count = 1, .reusable = true}}, SHIFT(208),
-  [17] = {.entry = {.count = 1, .reusable = true}}, SHIFT(204),
-  [19] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_call_expression, 2, 0, 0),
-  [21] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_call_expression, 2, 0, 0),
-  [23] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_argument_list, 2, 0, 0),
-  [25] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list, 2, 0, 0),
-  [27] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_argument_list, 3, 0, 0),
-  [29] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list, 3, 0, 0),
-  [31] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_argument_list, 4, 0, 0),
-  [33] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list, 4, 0, 0),
-  [35] = {.entry = {.count = 1, .reusable = false}}, SHIFT(299),
-  [37] = {.entry = {.count = 1, .reusable = true}}, SHIFT(79),
-  [39] = {.entry = {.count = 1, .reusable = false}}, SHIFT(321),
-  [41] = {.entry = {.count = 1, .reusable = true}}, SHIFT(318),
-  [43] = {.entry = {.count = 1, .reusable = false}}, SHIFT(128),
-  [45] = {.entry = {.count = 1, .reusable = false}}, SHIFT(257),
-  [47] = {.entry = {.count = 1, .reusable = false}}, SHIFT(80),
-  [49] = {.entry = {.count = 1, .reusable = false}}, SHIFT(322),
-  [51] = {.entry = {.count = 1, .reusable = true}}, SHIFT(310),
-  [53] = {.entry = {.count = 1, .reusable = false}}, SHIFT(331),
-  [55] = {.entry = {.count = 1, .reusable = true}}, SHIFT(317),
-  [57] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_state_declaration, 3, 0, 20),
-  [59] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__expression, 1, 0, 0),
-  [61] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_state_declaration, 3, 0, 20),
-  [63] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym__expression, 1, 0, 0), REDUCE(sym_state_declaration, 3, 0, 20),
-  [66] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__expression, 1, 0, 0),
-  [68] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_component_body_repeat1, 2, 0, 0), SHIFT_REPEAT(275),
-  [71] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_component_body_repeat1, 2, 0, 0), SHIFT_REPEAT(69),
-  [74] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_component_body_repeat1, 2, 0, 0),
-  [76] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_component_body_repeat1, 2, 0, 0), SHIFT_REPEAT(327),
-  [79] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_component_body_repeat1, 2, 0, 0), SHIFT_REPEAT(266),
-  [82] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_component_body_repeat1, 2, 0, 0), SHIFT_REPEAT(84),
-  [85] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_component_body_repeat1, 2, 0, 0), SHIFT_REPEAT(270),
-  [88] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_component_body_repeat1, 2, 0, 0), SHIFT_REPEAT(271),
-  [91] = {.entry = {.count = 1, .reusable = false}}, SHIFT(275),
-  [93] = {.entry = {.count = 1, .reusable = true}}, SHIFT(69),
-  [95] = {.entry = {.count = 1, .reusable = true}}, SHIFT(193),
-  [97] = {.entry = {.count = 1, .reusable = true}}, SHIFT(327),
-  [99] = {.entry = {.count = 1, .reusable = false}}, SHIFT(266),
-  [101] = {.entry = {.count = 1, .reusable = false}}, SHIFT(84),
-  [103] = {.entry = {.count = 1, .reusable = false}}, SHIFT(270),
-  [105] = {.entry = {.count = 1, .reusable = true}}, SHIFT(271),
-  [107] = {.entry = {.count = 1, .reusable = true}}, SHIFT(297),
-  [109] = {.entry = {.count = 1, .reusable = true}}, SHIFT(123),
-  [111] = {.entry = {.count = 1, .reusable = true}}, SHIFT(274),
-  [113] = {.entry = {.count = 1, .reusable = true}}, SHIFT(121),
-  [115] = {.entry = {.count = 1, .reusable = true}}, SHIFT(184),
-  [117] = {.entry = {.count = 1, .reusable = true}}, SHIFT(114),
-  [119] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_element_with_children_repeat1, 2, 0, 0), SHIFT_REPEAT(299),
-  [122] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_element_with_children_repeat1, 2, 0, 0), SHIFT_REPEAT(79),
-  [125] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_element_with_children_repeat1, 2, 0, 0), SHIFT_REPEAT(321),
-  [128] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_element_with_children_repeat1, 2, 0, 0),
-  [130] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_element_with_children_repeat1, 2, 0, 0), SHIFT_REPEAT(128),
-  [133] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_element_with_children_repeat1, 2, 0, 0), SHIFT_REPEAT(257),
-  [136] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_element_with_children_repeat1, 2, 0, 0), SHIFT_REPEAT(80),
-  [139] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_element_with_children_repeat1, 2, 0, 0), SHIFT_REPEAT(322),
-  [142] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_element_with_children_repeat1, 2, 0, 0), SHIFT_REPEAT(310),
-  [145] = {.entry = {.count = 1, .reusable = true}}, SHIFT(294),
-  [147] = {.entry = {.count = 1, .reusable = true}}, SHIFT(97),
-  [149] = {.entry = {.count = 1, .reusable = true}}, SHIFT(109),
-  [151] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
-  [153] = {.entry = {.count = 1, .reusable = true}}, SHIFT(316),
-  [155] = {.entry = {.count = 1, .reusable = false}}, SHIFT(140),
-  [157] = {.entry = {.count = 1, .reusable = true}}, SHIFT(61),
-  [159] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_func_type, 4, 0, 0),
-  [161] = {.entry = {.count = 1, .reusable = true}}, SHIFT(276),
-  [163] = {.entry = {.count = 1, .reusable = true}}, SHIFT(58),
-  [165] = {.entry = {.count = 1, .reusable = false}}, SHIFT(279),
-  [167] = {.entry = {.count = 1, .reusable = false}}, SHIFT(282),
-  [169] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_func_type, 5, 0, 0),
-  [171] = {.entry = {.count = 1, .reusable = true}}, SHIFT(62),
-  [173] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_func_type, 3, 0, 0),
-  [175] = {.entry = {.count = 1, .reusable = false}}, SHIFT(27),
-  [177] = {.entry = {.count = 1, .reusable = true}}, SHIFT(78),
-  [179] = {.entry = {.count = 1, .reusable = true}}, SHIFT(34),
-  [181] = {.entry = {.count = 1, .reusable = false}}, SHIFT(34),
-  [183] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_selector_expression, 3, 0, 0),
-  [185] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_selector_expression, 3, 0, 0),
-  [187] = {.entry = {.count = 1, .reusable = true}}, SHIFT(77),
-  [189] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binary_expression, 3, 0, 0),
-  [191] = {.entry = {.count = 1, .reusable = true}}, SHIFT(269),
-  [193] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_binary_expression, 3, 0, 0),
-  [195] = {.entry = {.count = 1, .reusable = true}}, SHIFT(115),
-  [197] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
-  [199] = {.entry = {.count = 1, .reusable = true}}, SHIFT(83),
-  [201] = {.entry = {.count = 1, .reusable = false}}, SHIFT(83),
-  [203] = {.entry = {.count = 1, .reusable = true}}, SHIFT(4),
-  [205] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parenthesized_expression, 3, 0, 0),
-  [207] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parenthesized_expression, 3, 0, 0),
-  [209] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_func_type, 4, 0, 0), SHIFT(140),
-  [212] = {.entry = {.count = 1, .reusable = true}}, SHIFT(319),
-  [214] = {.entry = {.count = 1, .reusable = true}}, SHIFT(65),
-  [216] = {.entry = {.count = 1, .reusable = false}}, SHIFT(329),
-  [218] = {.entry = {.count = 1, .reusable = false}}, SHIFT(268),
-  [220] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
-  [222] = {.entry = {.count = 1, .reusable = true}}, SHIFT(20),
-  [224] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_func_type, 5, 0, 0), SHIFT(140),
-  [227] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_argument_list_repeat1, 2, 0, 0),
-  [229] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1, 0, 0),
-  [231] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_func_type, 3, 0, 0), SHIFT(140),
-  [234] = {.entry = {.count = 1, .reusable = true}}, SHIFT(37),
-  [236] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_clause, 4, 0, 24),
-  [238] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_clause, 6, 0, 26),
-  [240] = {.entry = {.count = 1, .reusable = true}}, SHIFT(28),
-  [242] = {.entry = {.count = 1, .reusable = true}}, SHIFT(44),
-  [244] = {.entry = {.count = 1, .reusable = true}}, SHIFT(110),
-  [246] = {.entry = {.count = 1, .reusable = true}}, SHIFT(3),
-  [248] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_go_declaration, 2, 0, 4),
-  [250] = {.entry = {.count = 1, .reusable = false}}, SHIFT(90),
-  [252] = {.entry = {.count = 1, .reusable = true}}, SHIFT(298),
-  [254] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_go_declaration, 2, 0, 4),
-  [256] = {.entry = {.count = 1, .reusable = true}}, SHIFT(76),
-  [258] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2, 0, 0),
-  [260] = {.entry = {.count = 1, .reusable = false}}, SHIFT(102),
-  [262] = {.entry = {.count = 1, .reusable = false}}, SHIFT(288),
-  [264] = {.entry = {.count = 1, .reusable = false}}, SHIFT(161),
-  [266] = {.entry = {.count = 1, .reusable = false}}, SHIFT(149),
-  [268] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_go_declaration_repeat1, 1, 0, 0),
-  [270] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_go_declaration_repeat1, 1, 0, 0),
-  [272] = {.entry = {.count = 1, .reusable = false}}, SHIFT(265),
-  [274] = {.entry = {.count = 1, .reusable = false}}, SHIFT(183),
-  [276] = {.entry = {.count = 1, .reusable = false}}, SHIFT(168),
-  [278] = {.entry = {.count = 1, .reusable = false}}, SHIFT(311),
-  [280] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_go_declaration_repeat1, 2, 0, 0),
-  [282] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_go_declaration_repeat1, 2, 0, 0), SHIFT_REPEAT(90),
-  [285] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_go_declaration_repeat1, 2, 0, 0),
-  [287] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 3, 0, 0),
-  [289] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0),
-  [291] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(228),
-  [294] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(229),
-  [297] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(208),
-  [300] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(204),
-  [303] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block, 3, 0, 0),
-  [305] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 3, 0, 0),
-  [307] = {.entry = {.count = 1, .reusable = false}}, SHIFT(88),
-  [309] = {.entry = {.count = 1, .reusable = false}}, SHIFT(154),
-  [311] = {.entry = {.count = 1, .reusable = false}}, SHIFT(147),
-  [313] = {.entry = {.count = 1, .reusable = false}}, SHIFT(156),
-  [315] = {.entry = {.count = 1, .reusable = false}}, SHIFT(181),
-  [317] = {.entry = {.count = 1, .reusable = false}}, SHIFT(152),
-  [319] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_expression_content_repeat1, 2, 0, 0), SHIFT_REPEAT(102),
-  [322] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_expression_content_repeat1, 2, 0, 0),
-  [324] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_expression_content_repeat1, 2, 0, 0), SHIFT_REPEAT(161),
-  [327] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_expression_content_repeat1, 2, 0, 0), SHIFT_REPEAT(149),
-  [330] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression_content, 1, 0, 0),
-  [332] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_statement, 3, 0, 17),
-  [334] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_statement, 3, 0, 17),
-  [336] = {.entry = {.count = 1, .reusable = false}}, SHIFT(219),
-  [338] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_component_call, 3, 0, 18),
-  [340] = {.entry = {.count = 1, .reusable = true}}, SHIFT(21),
-  [342] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component_call, 3, 0, 18),
-  [344] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block, 2, 0, 0),
-  [346] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 2, 0, 0),
-  [348] = {.entry = {.count = 1, .reusable = false}}, SHIFT(172),
-  [350] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_go_expression, 3, 0, 0),
-  [352] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_go_expression, 3, 0, 0),
-  [354] = {.entry = {.count = 1, .reusable = false}}, SHIFT(158),
-  [356] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_go_paren_body_repeat1, 2, 0, 0), SHIFT_REPEAT(88),
-  [359] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_go_paren_body_repeat1, 2, 0, 0),
-  [361] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_go_paren_body_repeat1, 2, 0, 0), SHIFT_REPEAT(147),
-  [364] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_go_paren_body_repeat1, 2, 0, 0), SHIFT_REPEAT(156),
-  [367] = {.entry = {.count = 1, .reusable = false}}, SHIFT(164),
-  [369] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_go_code_content, 1, 0, 0),
-  [371] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_self_closing_element, 4, 0, 21),
-  [373] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_self_closing_element, 4, 0, 21),
-  [375] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_element_with_children_repeat1, 1, 0, 0),
-  [377] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_element_with_children_repeat1, 1, 0, 0),
-  [379] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_let_binding, 3, 0, 19),
-  [381] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_let_binding, 3, 0, 19),
-  [383] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_children_slot, 4, 0, 0),
-  [385] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_children_slot, 4, 0, 0),
-  [387] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_let_binding, 4, 0, 22),
-  [389] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_let_binding, 4, 0, 22),
-  [391] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_component_call, 4, 0, 23),
-  [393] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component_call, 4, 0, 23),
-  [395] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_self_closing_element, 5, 0, 21),
-  [397] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_self_closing_element, 5, 0, 21),
-  [399] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_statement, 5, 0, 25),
-  [401] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_statement, 5, 0, 25),
-  [403] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element_with_children, 6, 0, 21),
-  [405] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element_with_children, 6, 0, 21),
-  [407] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element_with_children, 7, 0, 21),
-  [409] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element_with_children, 7, 0, 21),
-  [411] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element_with_children, 8, 0, 21),
-  [413] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element_with_children, 8, 0, 21),
-  [415] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_import_section_repeat1, 2, 0, 0),
-  [417] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_import_section_repeat1, 2, 0, 0), SHIFT_REPEAT(205),
-  [420] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import_section, 1, 0, 0),
-  [422] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 1, 0, 0),
-  [424] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 1, 0, 0),
-  [426] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_for_statement, 3, 0, 16),
-  [428] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_statement, 3, 0, 16),
-  [430] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__element_child, 1, 0, 0),
-  [432] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__element_child, 1, 0, 0),
-  [434] = {.entry = {.count = 1, .reusable = false}}, SHIFT(215),
-  [436] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_component_body_repeat1, 1, 0, 0),
-  [438] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_component_body_repeat1, 1, 0, 0),
-  [440] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__child, 1, 0, 0),
-  [442] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__child, 1, 0, 0),
-  [444] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_expression, 1, 0, 0),
-  [446] = {.entry = {.count = 1, .reusable = true}}, SHIFT(295),
-  [448] = {.entry = {.count = 1, .reusable = true}}, SHIFT(55),
-  [450] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parameter_list, 2, 0, 0),
-  [452] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parameter_list, 2, 0, 0),
-  [454] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import_spec, 1, 0, 2),
-  [456] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_go_string_literal, 1, 0, 0),
-  [458] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import_declaration, 2, 0, 0),
-  [460] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import_spec_list, 2, 0, 0),
-  [462] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import_spec, 2, 0, 5),
-  [464] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_nested_braces, 3, 0, 0),
-  [466] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parameter_list, 3, 0, 0),
-  [468] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parameter_list, 3, 0, 0),
-  [470] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_nested_parens, 2, 0, 0),
-  [472] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parameter_list, 5, 0, 0),
-  [474] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parameter_list, 5, 0, 0),
-  [476] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_go_paren_body_repeat1, 1, 0, 0),
-  [478] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_nested_parens, 3, 0, 0),
-  [480] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_qualified_type, 3, 0, 0),
-  [482] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_package_clause, 2, 0, 1),
-  [484] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_expression_content_repeat1, 1, 0, 0),
-  [486] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parameter_list, 4, 0, 0),
-  [488] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parameter_list, 4, 0, 0),
-  [490] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_import_section_repeat1, 1, 0, 0),
-  [492] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_nested_braces, 2, 0, 0),
-  [494] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_go_declaration, 5, 0, 4),
-  [496] = {.entry = {.count = 1, .reusable = true}}, SHIFT(280),
-  [498] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import_spec_list, 3, 0, 0),
-  [500] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_declaration, 6, 0, 14),
-  [502] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_body, 2, 0, 0),
-  [504] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_declaration, 5, 0, 11),
-  [506] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component_declaration, 4, 0, 7),
-  [508] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_body, 2, 0, 0),
-  [510] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_go_paren_body, 2, 0, 0),
-  [512] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_return_type, 1, 0, 0),
-  [514] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_go_brace_body, 3, 0, 0),
-  [516] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_declaration, 4, 0, 7),
-  [518] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_slice_type, 3, 0, 0),
-  [520] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_go_declaration, 8, 0, 4),
-  [522] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_func_type, 6, 0, 0),
-  [524] = {.entry = {.count = 1, .reusable = true}}, SHIFT(321),
-  [526] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 1, 0, 0),
-  [528] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_go_paren_body, 3, 0, 0),
-  [530] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_struct_declaration, 4, 0, 8),
-  [532] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_go_brace_body, 2, 0, 0),
-  [534] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component_body, 3, 0, 0),
-  [536] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_go_declaration, 3, 0, 6),
-  [538] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component_declaration, 6, 0, 13),
-  [540] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_return_type, 3, 0, 0),
-  [542] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_body, 3, 0, 0),
-  [544] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_body, 3, 0, 0),
-  [546] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pointer_type, 2, 0, 0),
-  [548] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_generic_type, 4, 0, 0),
-  [550] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component_body, 2, 0, 0),
-  [552] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_go_declaration, 2, 0, 3),
-  [554] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_return_type, 4, 0, 0),
-  [556] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_map_type, 5, 0, 0),
-  [558] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_generic_type, 5, 0, 0),
-  [560] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_declaration, 5, 0, 12),
-  [562] = {.entry = {.count = 1, .reusable = true}}, SHIFT(220),
-  [564] = {.entry = {.count = 1, .reusable = false}}, SHIFT(308),
-  [566] = {.entry = {.count = 1, .reusable = true}}, SHIFT(11),
-  [568] = {.entry = {.count = 1, .reusable = true}}, SHIFT(90),
-  [570] = {.entry = {.count = 1, .reusable = true}}, SHIFT(98),
-  [572] = {.entry = {.count = 1, .reusable = true}}, SHIFT(314),
-  [574] = {.entry = {.count = 1, .reusable = true}}, SHIFT(207),
-  [576] = {.entry = {.count = 1, .reusable = true}}, SHIFT(146),
-  [578] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_self_closing_element_repeat1, 2, 0, 0), SHIFT_REPEAT(220),
-  [581] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_self_closing_element_repeat1, 2, 0, 0),
-  [583] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_self_closing_element_repeat1, 2, 0, 0),
-  [585] = {.entry = {.count = 1, .reusable = true}}, SHIFT(283),
-  [587] = {.entry = {.count = 1, .reusable = true}}, SHIFT(150),
-  [589] = {.entry = {.count = 1, .reusable = true}}, SHIFT(231),
-  [591] = {.entry = {.count = 1, .reusable = true}}, SHIFT(74),
-  [593] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_import_spec_list_repeat1, 2, 0, 0), SHIFT_REPEAT(283),
-  [596] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_import_spec_list_repeat1, 2, 0, 0),
-  [598] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_import_spec_list_repeat1, 2, 0, 0), SHIFT_REPEAT(231),
-  [601] = {.entry = {.count = 1, .reusable = true}}, SHIFT(166),
-  [603] = {.entry = {.count = 1, .reusable = true}}, SHIFT(91),
-  [605] = {.entry = {.count = 1, .reusable = true}}, SHIFT(226),
-  [607] = {.entry = {.count = 1, .reusable = false}}, SHIFT(307),
-  [609] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
-  [611] = {.entry = {.count = 1, .reusable = false}}, SHIFT(330),
-  [613] = {.entry = {.count = 1, .reusable = true}}, SHIFT(7),
-  [615] = {.entry = {.count = 1, .reusable = false}}, SHIFT(292),
-  [617] = {.entry = {.count = 1, .reusable = true}}, SHIFT(23),
-  [619] = {.entry = {.count = 1, .reusable = true}}, SHIFT(84),
-  [621] = {.entry = {.count = 1, .reusable = true}}, SHIFT(53),
-  [623] = {.entry = {.count = 1, .reusable = true}}, SHIFT(171),
-  [625] = {.entry = {.count = 1, .reusable = true}}, SHIFT(189),
-  [627] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_struct_body_repeat1, 2, 0, 0), SHIFT_REPEAT(53),
-  [630] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_struct_body_repeat1, 2, 0, 0),
-  [632] = {.entry = {.count = 1, .reusable = true}}, SHIFT(80),
-  [634] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 1, 0, 15),
-  [636] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute, 1, 0, 15),
-  [638] = {.entry = {.count = 1, .reusable = true}}, SHIFT(211),
-  [640] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_func_type_repeat1, 2, 0, 0),
-  [642] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_func_type_repeat1, 2, 0, 0), SHIFT_REPEAT(51),
-  [645] = {.entry = {.count = 1, .reusable = true}}, SHIFT(60),
-  [647] = {.entry = {.count = 1, .reusable = true}}, SHIFT(162),
-  [649] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_argument_list_repeat1, 2, 0, 0), SHIFT_REPEAT(72),
-  [652] = {.entry = {.count = 1, .reusable = true}}, SHIFT(25),
-  [654] = {.entry = {.count = 1, .reusable = true}}, SHIFT(51),
-  [656] = {.entry = {.count = 1, .reusable = true}}, SHIFT(26),
-  [658] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__attribute_value, 1, 0, 0),
-  [660] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__attribute_value, 1, 0, 0),
-  [662] = {.entry = {.count = 1, .reusable = true}}, SHIFT(201),
-  [664] = {.entry = {.count = 1, .reusable = true}}, SHIFT(249),
-  [666] = {.entry = {.count = 1, .reusable = true}}, SHIFT(306),
-  [668] = {.entry = {.count = 1, .reusable = true}}, SHIFT(260),
-  [670] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_self_closing_element_repeat1, 1, 0, 0),
-  [672] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_self_closing_element_repeat1, 1, 0, 0),
-  [674] = {.entry = {.count = 1, .reusable = true}}, SHIFT(38),
-  [676] = {.entry = {.count = 1, .reusable = true}}, SHIFT(240),
-  [678] = {.entry = {.count = 1, .reusable = true}}, SHIFT(41),
-  [680] = {.entry = {.count = 1, .reusable = true}}, SHIFT(145),
-  [682] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 3, 0, 19),
-  [684] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute, 3, 0, 19),
-  [686] = {.entry = {.count = 1, .reusable = true}}, SHIFT(192),
-  [688] = {.entry = {.count = 1, .reusable = true}}, SHIFT(198),
-  [690] = {.entry = {.count = 1, .reusable = true}}, SHIFT(118),
-  [692] = {.entry = {.count = 1, .reusable = true}}, SHIFT(155),
-  [694] = {.entry = {.count = 1, .reusable = true}}, SHIFT(153),
-  [696] = {.entry = {.count = 1, .reusable = true}}, SHIFT(222),
-  [698] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_import_spec_list_repeat1, 1, 0, 0),
-  [700] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_parameter_list_repeat1, 2, 0, 0),
-  [702] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_parameter_list_repeat1, 2, 0, 0), SHIFT_REPEAT(251),
-  [705] = {.entry = {.count = 1, .reusable = true}}, SHIFT(5),
-  [707] = {.entry = {.count = 1, .reusable = true}}, SHIFT(187),
-  [709] = {.entry = {.count = 1, .reusable = true}}, SHIFT(59),
-  [711] = {.entry = {.count = 1, .reusable = true}}, SHIFT(235),
-  [713] = {.entry = {.count = 1, .reusable = true}}, SHIFT(320),
-  [715] = {.entry = {.count = 1, .reusable = true}}, SHIFT(324),
-  [717] = {.entry = {.count = 1, .reusable = true}}, SHIFT(10),
-  [719] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parameter, 2, 0, 10),
-  [721] = {.entry = {.count = 1, .reusable = true}}, SHIFT(250),
-  [723] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_struct_body_repeat1, 1, 0, 0),
-  [725] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_field, 2, 0, 10),
-  [727] = {.entry = {.count = 1, .reusable = true}}, SHIFT(216),
-  [729] = {.entry = {.count = 1, .reusable = true}}, SHIFT(50),
-  [731] = {.entry = {.count = 1, .reusable = true}}, SHIFT(30),
-  [733] = {.entry = {.count = 1, .reusable = true}}, SHIFT(315),
-  [735] = {.entry = {.count = 1, .reusable = true}}, SHIFT(262),
-  [737] = {.entry = {.count = 1, .reusable = true}}, SHIFT(136),
-  [739] = {.entry = {.count = 1, .reusable = true}}, SHIFT(57),
-  [741] = {.entry = {.count = 1, .reusable = true}}, SHIFT(293),
-  [743] = {.entry = {.count = 1, .reusable = true}}, SHIFT(29),
-  [745] = {.entry = {.count = 1, .reusable = true}}, SHIFT(52),
-  [747] = {.entry = {.count = 1, .reusable = true}}, SHIFT(174),
-  [749] = {.entry = {.count = 1, .reusable = true}}, SHIFT(160),
-  [751] = {.entry = {.count = 1, .reusable = true}}, SHIFT(67),
-  [753] = {.entry = {.count = 1, .reusable = false}}, SHIFT(284),
-  [755] = {.entry = {.count = 1, .reusable = true}}, SHIFT(73),
-  [757] = {.entry = {.count = 1, .reusable = true}}, SHIFT(49),
-  [759] = {.entry = {.count = 1, .reusable = true}}, SHIFT(242),
-  [761] = {.entry = {.count = 1, .reusable = true}}, SHIFT(177),
-  [763] = {.entry = {.count = 1, .reusable = true}}, SHIFT(296),
-  [765] = {.entry = {.count = 1, .reusable = true}}, SHIFT(126),
-  [767] = {.entry = {.count = 1, .reusable = true}}, SHIFT(301),
-  [769] = {.entry = {.count = 1, .reusable = true}}, SHIFT(305),
-  [771] = {.entry = {.count = 1, .reusable = true}}, SHIFT(99),
-  [773] = {.entry = {.count = 1, .reusable = true}}, SHIFT(188),
-  [775] = {.entry = {.count = 1, .reusable = true}}, SHIFT(107),
-  [777] = {.entry = {.count = 1, .reusable = true}}, SHIFT(104),
-  [779] = {.entry = {.count = 1, .reusable = true}}, SHIFT(138),
-  [781] = {.entry = {.count = 1, .reusable = true}}, SHIFT(304),
-  [783] = {.entry = {.count = 1, .reusable = true}}, SHIFT(159),
-  [785] = {.entry = {.count = 1, .reusable = true}}, SHIFT(258),
-  [787] = {.entry = {.count = 1, .reusable = true}}, SHIFT(272),
-  [789] = {.entry = {.count = 1, .reusable = false}}, SHIFT(326),
-  [791] = {.entry = {.count = 1, .reusable = true}}, SHIFT(179),
-  [793] = {.entry = {.count = 1, .reusable = true}}, SHIFT(117),
-  [795] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_receiver, 4, 0, 9),
-  [797] = {.entry = {.count = 1, .reusable = true}}, SHIFT(119),
-  [799] = {.entry = {.count = 1, .reusable = true}}, SHIFT(120),
-  [801] = {.entry = {.count = 1, .reusable = true}}, SHIFT(144),
-  [803] = {.entry = {.count = 1, .reusable = true}}, SHIFT(139),
-  [805] = {.entry = {.count = 1, .reusable = true}}, SHIFT(54),
-  [807] = {.entry = {.count = 1, .reusable = true}}, SHIFT(132),
-  [809] = {.entry = {.count = 1, .reusable = true}}, SHIFT(130),
-  [811] = {.entry = {.count = 1, .reusable = true}}, SHIFT(281),
-  [813] = {.entry = {.count = 1, .reusable = true}}, SHIFT(248),
-  [815] = {.entry = {.count = 1, .reusable = true}}, SHIFT(291),
-  [817] = {.entry = {.count = 1, .reusable = true}}, SHIFT(285),
-  [819] = {.entry = {.count = 1, .reusable = true}}, SHIFT(194),
-  [821] = {.entry = {.count = 1, .reusable = true}}, SHIFT(151),
-  [823] = {.entry = {.count = 1, .reusable = true}}, SHIFT(196),
-  [825] = {.entry = {.count = 1, .reusable = true}}, SHIFT(300),
-  [827] = {.entry = {.count = 1, .reusable = true}}, SHIFT(302),
-  [829] = {.entry = {.count = 1, .reusable = true}}, SHIFT(303),
-  [831] = {.entry = {.count = 1, .reusable = true}}, SHIFT(63),
-  [833] = {.entry = {.count = 1, .reusable = true}}, SHIFT(309),
-  [835] = {.entry = {.count = 1, .reusable = true}}, SHIFT(214),
-  [837] = {.entry = {.count = 1, .reusable = true}}, SHIFT(313),
-  [839] = {.entry = {.count = 1, .reusable = true}}, SHIFT(64),
-  [841] = {.entry = {.count = 1, .reusable = true}}, SHIFT(70),
-  [843] = {.entry = {.count = 1, .reusable = true}}, SHIFT(256),
-  [845] = {.entry = {.count = 1, .reusable = true}}, SHIFT(165),
-  [847] = {.entry = {.count = 1, .reusable = true}}, SHIFT(203),
-  [849] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
-  [851] = {.entry = {.count = 1, .reusable = true}}, SHIFT(56),
-  [853] = {.entry = {.count = 1, .reusable = true}}, SHIFT(113),
-  [855] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comment, 1, 0, 0),
+  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(518),
+  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(396),
+  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(440),
+  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(438),
+  [15] = {.entry = {.count = 1, .reusable = true}}, SHIFT(285),
+  [17] = {.entry = {.count = 1, .reusable = true}}, SHIFT(291),
+  [19] = {.entry = {.count = 1, .reusable = false}}, SHIFT(105),
+  [21] = {.entry = {.count = 1, .reusable = true}}, SHIFT(59),
+  [23] = {.entry = {.count = 1, .reusable = true}}, SHIFT(32),
+  [25] = {.entry = {.count = 1, .reusable = true}}, SHIFT(184),
+  [27] = {.entry = {.count = 1, .reusable = true}}, SHIFT(3),
+  [29] = {.entry = {.count = 1, .reusable = false}}, SHIFT(574),
+  [31] = {.entry = {.count = 1, .reusable = false}}, SHIFT(460),
+  [33] = {.entry = {.count = 1, .reusable = false}}, SHIFT(204),
+  [35] = {.entry = {.count = 1, .reusable = true}}, SHIFT(35),
+  [37] = {.entry = {.count = 1, .reusable = false}}, SHIFT(476),
+  [39] = {.entry = {.count = 1, .reusable = false}}, SHIFT(578),
+  [41] = {.entry = {.count = 1, .reusable = true}}, SHIFT(53),
+  [43] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
+  [45] = {.entry = {.count = 1, .reusable = false}}, SHIFT(72),
+  [47] = {.entry = {.count = 1, .reusable = false}}, SHIFT(63),
+  [49] = {.entry = {.count = 1, .reusable = true}}, SHIFT(521),
+  [51] = {.entry = {.count = 1, .reusable = false}}, SHIFT(509),
+  [53] = {.entry = {.count = 1, .reusable = false}}, SHIFT(550),
+  [55] = {.entry = {.count = 1, .reusable = true}}, SHIFT(559),
+  [57] = {.entry = {.count = 1, .reusable = true}}, SHIFT(5),
+  [59] = {.entry = {.count = 1, .reusable = true}}, SHIFT(83),
+  [61] = {.entry = {.count = 1, .reusable = true}}, SHIFT(91),
+  [63] = {.entry = {.count = 1, .reusable = true}}, SHIFT(68),
+  [65] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_body, 3, 0, 0),
+  [67] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_function_body, 3, 0, 0),
+  [69] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_body, 2, 0, 0),
+  [71] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_function_body, 2, 0, 0),
+  [73] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_argument_list, 7, 0, 0),
+  [75] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list, 7, 0, 0),
+  [77] = {.entry = {.count = 1, .reusable = true}}, SHIFT(325),
+  [79] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_state_declaration, 3, 0, 38),
+  [81] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__expression, 1, 0, 0),
+  [83] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__expression, 1, 0, 0),
+  [85] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_state_declaration, 3, 0, 38),
+  [87] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym__expression, 1, 0, 0), REDUCE(sym_state_declaration, 3, 0, 38),
+  [90] = {.entry = {.count = 1, .reusable = true}}, SHIFT(93),
+  [92] = {.entry = {.count = 1, .reusable = true}}, SHIFT(69),
+  [94] = {.entry = {.count = 1, .reusable = true}}, SHIFT(70),
+  [96] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_call_expression, 2, 0, 20),
+  [98] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_call_expression, 2, 0, 20),
+  [100] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_argument_list, 2, 0, 0),
+  [102] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list, 2, 0, 0),
+  [104] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_argument_list, 3, 0, 0),
+  [106] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list, 3, 0, 0),
+  [108] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_argument_list, 4, 0, 0),
+  [110] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list, 4, 0, 0),
+  [112] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_argument_list, 5, 0, 0),
+  [114] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list, 5, 0, 0),
+  [116] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_argument_list, 6, 0, 0),
+  [118] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list, 6, 0, 0),
+  [120] = {.entry = {.count = 1, .reusable = true}}, SHIFT(253),
+  [122] = {.entry = {.count = 1, .reusable = true}}, SHIFT(258),
+  [124] = {.entry = {.count = 1, .reusable = true}}, SHIFT(259),
+  [126] = {.entry = {.count = 1, .reusable = true}}, SHIFT(19),
+  [128] = {.entry = {.count = 1, .reusable = true}}, SHIFT(21),
+  [130] = {.entry = {.count = 1, .reusable = true}}, SHIFT(22),
+  [132] = {.entry = {.count = 1, .reusable = true}}, SHIFT(322),
+  [134] = {.entry = {.count = 1, .reusable = true}}, SHIFT(338),
+  [136] = {.entry = {.count = 1, .reusable = false}}, SHIFT(136),
+  [138] = {.entry = {.count = 1, .reusable = true}}, SHIFT(56),
+  [140] = {.entry = {.count = 1, .reusable = true}}, SHIFT(377),
+  [142] = {.entry = {.count = 1, .reusable = true}}, SHIFT(36),
+  [144] = {.entry = {.count = 1, .reusable = false}}, SHIFT(190),
+  [146] = {.entry = {.count = 1, .reusable = true}}, SHIFT(88),
+  [148] = {.entry = {.count = 1, .reusable = true}}, SHIFT(380),
+  [150] = {.entry = {.count = 1, .reusable = true}}, SHIFT(176),
+  [152] = {.entry = {.count = 1, .reusable = true}}, SHIFT(79),
+  [154] = {.entry = {.count = 1, .reusable = true}}, SHIFT(191),
+  [156] = {.entry = {.count = 1, .reusable = true}}, SHIFT(16),
+  [158] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unary_expression, 2, 0, 18),
+  [160] = {.entry = {.count = 1, .reusable = true}}, SHIFT(34),
+  [162] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unary_expression, 2, 0, 18),
+  [164] = {.entry = {.count = 1, .reusable = false}}, SHIFT(493),
+  [166] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binary_expression, 3, 0, 30),
+  [168] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_binary_expression, 3, 0, 30),
+  [170] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_slice_expression, 5, 0, 48),
+  [172] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_slice_expression, 5, 0, 48),
+  [174] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_func_literal, 3, 0, 28),
+  [176] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_func_literal, 3, 0, 28),
+  [178] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_literal_value, 5, 0, 0),
+  [180] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_literal_value, 5, 0, 0),
+  [182] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_slice_expression, 6, 0, 54),
+  [184] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_slice_expression, 6, 0, 54),
+  [186] = {.entry = {.count = 1, .reusable = true}}, SHIFT(50),
+  [188] = {.entry = {.count = 1, .reusable = false}}, SHIFT(50),
+  [190] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_slice_expression, 7, 0, 59),
+  [192] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_slice_expression, 7, 0, 59),
+  [194] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_slice_expression, 8, 0, 62),
+  [196] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_slice_expression, 8, 0, 62),
+  [198] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_selector_expression, 3, 0, 31),
+  [200] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_selector_expression, 3, 0, 31),
+  [202] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_slice_expression, 5, 0, 49),
+  [204] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_slice_expression, 5, 0, 49),
+  [206] = {.entry = {.count = 1, .reusable = false}}, SHIFT(52),
+  [208] = {.entry = {.count = 1, .reusable = true}}, SHIFT(52),
+  [210] = {.entry = {.count = 1, .reusable = false}}, SHIFT(54),
+  [212] = {.entry = {.count = 1, .reusable = true}}, SHIFT(54),
+  [214] = {.entry = {.count = 1, .reusable = true}}, SHIFT(55),
+  [216] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_literal_value, 2, 0, 0),
+  [218] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_literal_value, 2, 0, 0),
+  [220] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_func_literal, 4, 0, 39),
+  [222] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_func_literal, 4, 0, 39),
+  [224] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_assertion_expression, 5, 0, 50),
+  [226] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_assertion_expression, 5, 0, 50),
+  [228] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_literal_value, 3, 0, 0),
+  [230] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_literal_value, 3, 0, 0),
+  [232] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_slice_expression, 4, 0, 40),
+  [234] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_slice_expression, 4, 0, 40),
+  [236] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_index_expression, 4, 0, 41),
+  [238] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_index_expression, 4, 0, 41),
+  [240] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_composite_literal, 2, 0, 19),
+  [242] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_composite_literal, 2, 0, 19),
+  [244] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_literal_value, 4, 0, 0),
+  [246] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_literal_value, 4, 0, 0),
+  [248] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parenthesized_expression, 3, 0, 0),
+  [250] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parenthesized_expression, 3, 0, 0),
+  [252] = {.entry = {.count = 1, .reusable = true}}, SHIFT(257),
+  [254] = {.entry = {.count = 1, .reusable = true}}, SHIFT(25),
+  [256] = {.entry = {.count = 1, .reusable = true}}, SHIFT(465),
+  [258] = {.entry = {.count = 1, .reusable = true}}, SHIFT(58),
+  [260] = {.entry = {.count = 1, .reusable = true}}, SHIFT(323),
+  [262] = {.entry = {.count = 1, .reusable = true}}, SHIFT(31),
+  [264] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
+  [266] = {.entry = {.count = 1, .reusable = true}}, SHIFT(15),
+  [268] = {.entry = {.count = 1, .reusable = true}}, SHIFT(20),
+  [270] = {.entry = {.count = 1, .reusable = true}}, SHIFT(28),
+  [272] = {.entry = {.count = 1, .reusable = false}}, SHIFT(339),
+  [274] = {.entry = {.count = 1, .reusable = true}}, SHIFT(181),
+  [276] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_func_type, 2, 0, 10),
+  [278] = {.entry = {.count = 1, .reusable = true}}, SHIFT(203),
+  [280] = {.entry = {.count = 1, .reusable = false}}, SHIFT(483),
+  [282] = {.entry = {.count = 1, .reusable = true}}, SHIFT(573),
+  [284] = {.entry = {.count = 1, .reusable = true}}, SHIFT(251),
+  [286] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_interface_type_repeat1, 2, 0, 0), SHIFT_REPEAT(234),
+  [289] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_interface_type_repeat1, 2, 0, 0), SHIFT_REPEAT(38),
+  [292] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_interface_type_repeat1, 2, 0, 0), SHIFT_REPEAT(179),
+  [295] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_interface_type_repeat1, 2, 0, 0), SHIFT_REPEAT(172),
+  [298] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_interface_type_repeat1, 2, 0, 0), SHIFT_REPEAT(591),
+  [301] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_interface_type_repeat1, 2, 0, 0), SHIFT_REPEAT(498),
+  [304] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_interface_type_repeat1, 2, 0, 0), SHIFT_REPEAT(207),
+  [307] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_interface_type_repeat1, 2, 0, 0), SHIFT_REPEAT(565),
+  [310] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_interface_type_repeat1, 2, 0, 0), SHIFT_REPEAT(502),
+  [313] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_interface_type_repeat1, 2, 0, 0), SHIFT_REPEAT(578),
+  [316] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_interface_type_repeat1, 2, 0, 0),
+  [318] = {.entry = {.count = 1, .reusable = false}}, SHIFT(234),
+  [320] = {.entry = {.count = 1, .reusable = true}}, SHIFT(38),
+  [322] = {.entry = {.count = 1, .reusable = true}}, SHIFT(179),
+  [324] = {.entry = {.count = 1, .reusable = true}}, SHIFT(172),
+  [326] = {.entry = {.count = 1, .reusable = false}}, SHIFT(591),
+  [328] = {.entry = {.count = 1, .reusable = false}}, SHIFT(498),
+  [330] = {.entry = {.count = 1, .reusable = false}}, SHIFT(207),
+  [332] = {.entry = {.count = 1, .reusable = true}}, SHIFT(565),
+  [334] = {.entry = {.count = 1, .reusable = false}}, SHIFT(502),
+  [336] = {.entry = {.count = 1, .reusable = true}}, SHIFT(226),
+  [338] = {.entry = {.count = 1, .reusable = true}}, SHIFT(61),
+  [340] = {.entry = {.count = 1, .reusable = true}}, SHIFT(493),
+  [342] = {.entry = {.count = 1, .reusable = true}}, SHIFT(118),
+  [344] = {.entry = {.count = 1, .reusable = true}}, SHIFT(219),
+  [346] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym__expression, 1, 0, 0), SHIFT(198),
+  [349] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_expression, 1, 0, 0),
+  [351] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_type_expression, 1, 0, 0), REDUCE(sym__expression, 1, 0, 0),
+  [354] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym__expression, 1, 0, 0), SHIFT(579),
+  [357] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_argument_list_repeat1, 2, 0, 0),
+  [359] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_literal_element, 1, 0, 0),
+  [361] = {.entry = {.count = 1, .reusable = true}}, SHIFT(540),
+  [363] = {.entry = {.count = 1, .reusable = true}}, SHIFT(197),
+  [365] = {.entry = {.count = 1, .reusable = false}}, SHIFT(43),
+  [367] = {.entry = {.count = 1, .reusable = true}}, SHIFT(44),
+  [369] = {.entry = {.count = 1, .reusable = true}}, SHIFT(156),
+  [371] = {.entry = {.count = 1, .reusable = false}}, SHIFT(46),
+  [373] = {.entry = {.count = 1, .reusable = false}}, SHIFT(44),
+  [375] = {.entry = {.count = 1, .reusable = true}}, SHIFT(43),
+  [377] = {.entry = {.count = 1, .reusable = true}}, SHIFT(46),
+  [379] = {.entry = {.count = 1, .reusable = true}}, SHIFT(48),
+  [381] = {.entry = {.count = 1, .reusable = true}}, SHIFT(47),
+  [383] = {.entry = {.count = 1, .reusable = true}}, SHIFT(150),
+  [385] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_component_body_repeat1, 2, 0, 0), SHIFT_REPEAT(562),
+  [388] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_component_body_repeat1, 2, 0, 0), SHIFT_REPEAT(283),
+  [391] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_component_body_repeat1, 2, 0, 0),
+  [393] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_component_body_repeat1, 2, 0, 0), SHIFT_REPEAT(550),
+  [396] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_component_body_repeat1, 2, 0, 0), SHIFT_REPEAT(513),
+  [399] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_component_body_repeat1, 2, 0, 0), SHIFT_REPEAT(49),
+  [402] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_component_body_repeat1, 2, 0, 0), SHIFT_REPEAT(33),
+  [405] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_component_body_repeat1, 2, 0, 0),
+  [407] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_component_body_repeat1, 2, 0, 0), SHIFT_REPEAT(554),
+  [410] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_component_body_repeat1, 2, 0, 0), SHIFT_REPEAT(559),
+  [413] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_component_body_repeat1, 2, 0, 0), SHIFT_REPEAT(248),
+  [416] = {.entry = {.count = 1, .reusable = true}}, SHIFT(2),
+  [418] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_type_expression, 1, 0, 0), SHIFT(540),
+  [421] = {.entry = {.count = 1, .reusable = true}}, SHIFT(173),
+  [423] = {.entry = {.count = 1, .reusable = false}}, SHIFT(579),
+  [425] = {.entry = {.count = 1, .reusable = true}}, SHIFT(89),
+  [427] = {.entry = {.count = 1, .reusable = true}}, SHIFT(39),
+  [429] = {.entry = {.count = 1, .reusable = true}}, SHIFT(66),
+  [431] = {.entry = {.count = 1, .reusable = true}}, SHIFT(60),
+  [433] = {.entry = {.count = 1, .reusable = false}}, SHIFT(562),
+  [435] = {.entry = {.count = 1, .reusable = true}}, SHIFT(283),
+  [437] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_default_clause, 2, 0, 0),
+  [439] = {.entry = {.count = 1, .reusable = true}}, SHIFT(550),
+  [441] = {.entry = {.count = 1, .reusable = false}}, SHIFT(513),
+  [443] = {.entry = {.count = 1, .reusable = false}}, SHIFT(49),
+  [445] = {.entry = {.count = 1, .reusable = false}}, SHIFT(33),
+  [447] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_default_clause, 2, 0, 0),
+  [449] = {.entry = {.count = 1, .reusable = false}}, SHIFT(554),
+  [451] = {.entry = {.count = 1, .reusable = true}}, SHIFT(248),
+  [453] = {.entry = {.count = 1, .reusable = true}}, SHIFT(71),
+  [455] = {.entry = {.count = 1, .reusable = true}}, SHIFT(41),
+  [457] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_case_clause, 3, 0, 44),
+  [459] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_case_clause, 3, 0, 44),
+  [461] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_default_clause, 3, 0, 0),
+  [463] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_default_clause, 3, 0, 0),
+  [465] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_case_clause_repeat1, 2, 0, 44),
+  [467] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_case_clause, 4, 0, 44),
+  [469] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_case_clause, 4, 0, 44),
+  [471] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_case_clause, 4, 0, 56),
+  [473] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_case_clause, 4, 0, 56),
+  [475] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_case_clause, 5, 0, 56),
+  [477] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_case_clause, 5, 0, 56),
+  [479] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_func_type, 2, 0, 10), SHIFT(241),
+  [482] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_func_type, 2, 0, 10), SHIFT(38),
+  [485] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_func_type, 2, 0, 10), SHIFT(172),
+  [488] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_func_type, 2, 0, 10), SHIFT(591),
+  [491] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_func_type, 2, 0, 10), SHIFT(498),
+  [494] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_func_type, 2, 0, 10), SHIFT(207),
+  [497] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_func_type, 2, 0, 10), SHIFT(565),
+  [500] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_func_type, 2, 0, 10), SHIFT(502),
+  [503] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_func_type, 2, 0, 10), SHIFT(578),
+  [506] = {.entry = {.count = 1, .reusable = true}}, SHIFT(75),
+  [508] = {.entry = {.count = 1, .reusable = false}}, SHIFT(112),
+  [510] = {.entry = {.count = 1, .reusable = true}}, SHIFT(213),
+  [512] = {.entry = {.count = 1, .reusable = true}}, SHIFT(175),
+  [514] = {.entry = {.count = 1, .reusable = true}}, SHIFT(374),
+  [516] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_clause, 4, 0, 51),
+  [518] = {.entry = {.count = 1, .reusable = false}}, SHIFT(241),
+  [520] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_method_spec, 2, 0, 32),
+  [522] = {.entry = {.count = 1, .reusable = true}}, SHIFT(383),
+  [524] = {.entry = {.count = 1, .reusable = true}}, SHIFT(77),
+  [526] = {.entry = {.count = 1, .reusable = true}}, SHIFT(189),
+  [528] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_clause, 6, 0, 61),
+  [530] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
+  [532] = {.entry = {.count = 1, .reusable = true}}, SHIFT(209),
+  [534] = {.entry = {.count = 1, .reusable = true}}, SHIFT(211),
+  [536] = {.entry = {.count = 1, .reusable = true}}, SHIFT(180),
+  [538] = {.entry = {.count = 1, .reusable = true}}, SHIFT(194),
+  [540] = {.entry = {.count = 1, .reusable = true}}, SHIFT(244),
+  [542] = {.entry = {.count = 1, .reusable = true}}, SHIFT(379),
+  [544] = {.entry = {.count = 1, .reusable = true}}, SHIFT(27),
+  [546] = {.entry = {.count = 1, .reusable = true}}, SHIFT(367),
+  [548] = {.entry = {.count = 1, .reusable = true}}, SHIFT(242),
+  [550] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_func_type, 2, 0, 10), SHIFT(339),
+  [553] = {.entry = {.count = 1, .reusable = true}}, SHIFT(40),
+  [555] = {.entry = {.count = 1, .reusable = true}}, SHIFT(201),
+  [557] = {.entry = {.count = 1, .reusable = false}}, SHIFT(599),
+  [559] = {.entry = {.count = 1, .reusable = false}}, SHIFT(485),
+  [561] = {.entry = {.count = 1, .reusable = false}}, SHIFT(199),
+  [563] = {.entry = {.count = 1, .reusable = true}}, SHIFT(572),
+  [565] = {.entry = {.count = 1, .reusable = true}}, SHIFT(307),
+  [567] = {.entry = {.count = 1, .reusable = true}}, SHIFT(311),
+  [569] = {.entry = {.count = 1, .reusable = false}}, SHIFT(560),
+  [571] = {.entry = {.count = 1, .reusable = true}}, SHIFT(280),
+  [573] = {.entry = {.count = 1, .reusable = false}}, SHIFT(593),
+  [575] = {.entry = {.count = 1, .reusable = true}}, SHIFT(590),
+  [577] = {.entry = {.count = 1, .reusable = false}}, SHIFT(505),
+  [579] = {.entry = {.count = 1, .reusable = false}}, SHIFT(62),
+  [581] = {.entry = {.count = 1, .reusable = false}}, SHIFT(37),
+  [583] = {.entry = {.count = 1, .reusable = false}}, SHIFT(594),
+  [585] = {.entry = {.count = 1, .reusable = true}}, SHIFT(580),
+  [587] = {.entry = {.count = 1, .reusable = true}}, SHIFT(330),
+  [589] = {.entry = {.count = 1, .reusable = true}}, SHIFT(589),
+  [591] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_element_with_children_repeat1, 2, 0, 0), SHIFT_REPEAT(560),
+  [594] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_element_with_children_repeat1, 2, 0, 0), SHIFT_REPEAT(280),
+  [597] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_element_with_children_repeat1, 2, 0, 0), SHIFT_REPEAT(593),
+  [600] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_element_with_children_repeat1, 2, 0, 0),
+  [602] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_element_with_children_repeat1, 2, 0, 0), SHIFT_REPEAT(505),
+  [605] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_element_with_children_repeat1, 2, 0, 0), SHIFT_REPEAT(62),
+  [608] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_element_with_children_repeat1, 2, 0, 0), SHIFT_REPEAT(37),
+  [611] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_element_with_children_repeat1, 2, 0, 0), SHIFT_REPEAT(594),
+  [614] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_element_with_children_repeat1, 2, 0, 0), SHIFT_REPEAT(580),
+  [617] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_element_with_children_repeat1, 2, 0, 0), SHIFT_REPEAT(330),
+  [620] = {.entry = {.count = 1, .reusable = true}}, SHIFT(563),
+  [622] = {.entry = {.count = 1, .reusable = true}}, SHIFT(600),
+  [624] = {.entry = {.count = 1, .reusable = true}}, SHIFT(568),
+  [626] = {.entry = {.count = 1, .reusable = true}}, SHIFT(515),
+  [628] = {.entry = {.count = 1, .reusable = false}}, SHIFT(182),
+  [630] = {.entry = {.count = 1, .reusable = true}}, SHIFT(177),
+  [632] = {.entry = {.count = 1, .reusable = false}}, SHIFT(200),
+  [634] = {.entry = {.count = 1, .reusable = false}}, SHIFT(195),
+  [636] = {.entry = {.count = 1, .reusable = true}}, SHIFT(192),
+  [638] = {.entry = {.count = 1, .reusable = true}}, SHIFT(188),
+  [640] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_go_declaration, 2, 0, 4),
+  [642] = {.entry = {.count = 1, .reusable = false}}, SHIFT(233),
+  [644] = {.entry = {.count = 1, .reusable = true}}, SHIFT(535),
+  [646] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_go_declaration, 2, 0, 4),
+  [648] = {.entry = {.count = 1, .reusable = true}}, SHIFT(272),
+  [650] = {.entry = {.count = 1, .reusable = true}}, SHIFT(236),
+  [652] = {.entry = {.count = 1, .reusable = true}}, SHIFT(233),
+  [654] = {.entry = {.count = 1, .reusable = false}}, SHIFT(601),
+  [656] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parameter_list, 2, 0, 0),
+  [658] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parameter_list, 2, 0, 0),
+  [660] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_go_declaration_repeat1, 2, 0, 0),
+  [662] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_go_declaration_repeat1, 2, 0, 0), SHIFT_REPEAT(233),
+  [665] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_go_declaration_repeat1, 2, 0, 0),
+  [667] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_go_declaration_repeat1, 2, 0, 0), SHIFT_REPEAT(236),
+  [670] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_go_declaration_repeat1, 2, 0, 0), SHIFT_REPEAT(233),
+  [673] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parameter_list, 4, 0, 0),
+  [675] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parameter_list, 4, 0, 0),
+  [677] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parameter_list, 3, 0, 0),
+  [679] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parameter_list, 3, 0, 0),
+  [681] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parameter_list, 5, 0, 0),
+  [683] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parameter_list, 5, 0, 0),
+  [685] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_channel_type, 2, 0, 0),
+  [687] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_channel_type, 2, 0, 0),
+  [689] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_struct_type, 2, 0, 0),
+  [691] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_type, 2, 0, 0),
+  [693] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_map_type, 5, 0, 0),
+  [695] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_map_type, 5, 0, 0),
+  [697] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_type, 4, 0, 29),
+  [699] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_type, 4, 0, 29),
+  [701] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interface_type, 4, 0, 0),
+  [703] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_type, 4, 0, 0),
+  [705] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_generic_type, 4, 0, 0),
+  [707] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_generic_type, 4, 0, 0),
+  [709] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_generic_type, 5, 0, 0),
+  [711] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_generic_type, 5, 0, 0),
+  [713] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_return_type, 4, 0, 0),
+  [715] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_return_type, 4, 0, 0),
+  [717] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_slice_type, 3, 0, 0),
+  [719] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_slice_type, 3, 0, 0),
+  [721] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_return_type, 1, 0, 0),
+  [723] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_return_type, 1, 0, 0),
+  [725] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_func_type, 3, 0, 10),
+  [727] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_func_type, 3, 0, 10),
+  [729] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interface_type, 3, 0, 0),
+  [731] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_type, 3, 0, 0),
+  [733] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_channel_type, 3, 0, 0),
+  [735] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_channel_type, 3, 0, 0),
+  [737] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pointer_type, 2, 0, 0),
+  [739] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pointer_type, 2, 0, 0),
+  [741] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_qualified_type, 3, 0, 0),
+  [743] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_qualified_type, 3, 0, 0),
+  [745] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_expression, 1, 0, 0),
+  [747] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_return_type, 3, 0, 0),
+  [749] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_return_type, 3, 0, 0),
+  [751] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_go_declaration_repeat1, 1, 0, 0),
+  [753] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_go_declaration_repeat1, 1, 0, 0),
+  [755] = {.entry = {.count = 1, .reusable = false}}, SHIFT(510),
+  [757] = {.entry = {.count = 1, .reusable = true}}, SHIFT(143),
+  [759] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_type_expression, 1, 0, 0), SHIFT(198),
+  [762] = {.entry = {.count = 1, .reusable = true}}, SHIFT(579),
+  [764] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1, 0, 0),
+  [766] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_go_string_literal, 1, 0, 0),
+  [768] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_go_string_literal, 1, 0, 0),
+  [770] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_underlying_type, 2, 0, 0),
+  [772] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_underlying_type, 2, 0, 0),
+  [774] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_type_constraint_repeat1, 2, 0, 0),
+  [776] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_type_constraint_repeat1, 2, 0, 0),
+  [778] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_constraint, 2, 0, 0),
+  [780] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_constraint, 2, 0, 0),
+  [782] = {.entry = {.count = 1, .reusable = true}}, SHIFT(166),
+  [784] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_component_call, 5, 0, 53),
+  [786] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component_call, 5, 0, 53),
+  [788] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block, 3, 0, 0),
+  [790] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 3, 0, 0),
+  [792] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_type_constraint_repeat1, 2, 0, 0), SHIFT_REPEAT(166),
+  [795] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block, 2, 0, 0),
+  [797] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 2, 0, 0),
+  [799] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_constraint, 1, 0, 0),
+  [801] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_constraint, 1, 0, 0),
+  [803] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_component_call, 3, 0, 36),
+  [805] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component_call, 3, 0, 36),
+  [807] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_statement, 3, 0, 35),
+  [809] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_statement, 3, 0, 35),
+  [811] = {.entry = {.count = 1, .reusable = false}}, SHIFT(421),
+  [813] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__child, 1, 0, 0),
+  [815] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__child, 1, 0, 0),
+  [817] = {.entry = {.count = 1, .reusable = true}}, SHIFT(250),
+  [819] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_struct_body, 2, 0, 0),
+  [821] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_body, 2, 0, 0),
+  [823] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__child, 2, 0, 0),
+  [825] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__child, 2, 0, 0),
+  [827] = {.entry = {.count = 1, .reusable = false}}, SHIFT(294),
+  [829] = {.entry = {.count = 1, .reusable = false}}, SHIFT(9),
+  [831] = {.entry = {.count = 1, .reusable = false}}, SHIFT(350),
+  [833] = {.entry = {.count = 1, .reusable = true}}, SHIFT(350),
+  [835] = {.entry = {.count = 1, .reusable = false}}, SHIFT(347),
+  [837] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_struct_body, 3, 0, 0),
+  [839] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_body, 3, 0, 0),
+  [841] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_self_closing_element, 5, 0, 43),
+  [843] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_self_closing_element, 5, 0, 43),
+  [845] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_go_expression, 3, 0, 0),
+  [847] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_go_expression, 3, 0, 0),
+  [849] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_statement, 5, 0, 52),
+  [851] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_statement, 5, 0, 52),
+  [853] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_children_slot, 4, 0, 0),
+  [855] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_children_slot, 4, 0, 0),
+  [857] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_switch_statement, 5, 0, 44),
+  [859] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_switch_statement, 5, 0, 44),
+  [861] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_self_closing_element, 4, 0, 43),
+  [863] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_self_closing_element, 4, 0, 43),
+  [865] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_type_parameter_declaration_repeat1, 2, 0, 23),
+  [867] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_type_parameter_declaration_repeat1, 2, 0, 23),
+  [869] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_type_parameter_declaration_repeat1, 2, 0, 23), SHIFT_REPEAT(540),
+  [872] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_component_body_repeat1, 1, 0, 0),
+  [874] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_component_body_repeat1, 1, 0, 0),
+  [876] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element_with_children, 6, 0, 55),
+  [878] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element_with_children, 6, 0, 55),
+  [880] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 1, 0, 0),
+  [882] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 1, 0, 0),
+  [884] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_for_statement, 3, 0, 34),
+  [886] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_statement, 3, 0, 34),
+  [888] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_component_call, 6, 0, 58),
+  [890] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component_call, 6, 0, 58),
+  [892] = {.entry = {.count = 1, .reusable = false}}, SHIFT(390),
+  [894] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_switch_statement, 3, 0, 0),
+  [896] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_switch_statement, 3, 0, 0),
+  [898] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element_with_children, 7, 0, 60),
+  [900] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element_with_children, 7, 0, 60),
+  [902] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_let_binding, 3, 0, 37),
+  [904] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_let_binding, 3, 0, 37),
+  [906] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_switch_statement, 4, 0, 0),
+  [908] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_switch_statement, 4, 0, 0),
+  [910] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element_with_children, 8, 0, 63),
+  [912] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element_with_children, 8, 0, 63),
+  [914] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_switch_statement, 4, 0, 44),
+  [916] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_switch_statement, 4, 0, 44),
+  [918] = {.entry = {.count = 1, .reusable = false}}, SHIFT(582),
+  [920] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_let_binding, 4, 0, 45),
+  [922] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_let_binding, 4, 0, 45),
+  [924] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_component_call, 4, 0, 46),
+  [926] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component_call, 4, 0, 46),
+  [928] = {.entry = {.count = 1, .reusable = false}}, SHIFT(537),
+  [930] = {.entry = {.count = 1, .reusable = false}}, SHIFT(342),
+  [932] = {.entry = {.count = 1, .reusable = true}}, SHIFT(232),
+  [934] = {.entry = {.count = 1, .reusable = true}}, SHIFT(298),
+  [936] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_expression_content_repeat1, 2, 0, 0), SHIFT_REPEAT(294),
+  [939] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_expression_content_repeat1, 2, 0, 0),
+  [941] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_expression_content_repeat1, 2, 0, 0), SHIFT_REPEAT(350),
+  [944] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_expression_content_repeat1, 2, 0, 0), SHIFT_REPEAT(350),
+  [947] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_expression_content_repeat1, 2, 0, 0), SHIFT_REPEAT(347),
+  [950] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_method_spec, 3, 0, 42),
+  [952] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_method_spec, 3, 0, 42),
+  [954] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression_content, 1, 0, 0),
+  [956] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_type_parameter_declaration_repeat1, 2, 0, 1),
+  [958] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_type_parameter_declaration_repeat1, 2, 0, 1),
+  [960] = {.entry = {.count = 1, .reusable = false}}, SHIFT(345),
+  [962] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2, 0, 0),
+  [964] = {.entry = {.count = 1, .reusable = false}}, SHIFT(297),
+  [966] = {.entry = {.count = 1, .reusable = false}}, SHIFT(346),
+  [968] = {.entry = {.count = 1, .reusable = false}}, SHIFT(348),
+  [970] = {.entry = {.count = 1, .reusable = false}}, SHIFT(343),
+  [972] = {.entry = {.count = 1, .reusable = true}}, SHIFT(348),
+  [974] = {.entry = {.count = 1, .reusable = false}}, SHIFT(372),
+  [976] = {.entry = {.count = 1, .reusable = false}}, SHIFT(412),
+  [978] = {.entry = {.count = 1, .reusable = false}}, SHIFT(386),
+  [980] = {.entry = {.count = 1, .reusable = false}}, SHIFT(344),
+  [982] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_go_code_content, 1, 0, 0),
+  [984] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0),
+  [986] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(440),
+  [989] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(438),
+  [992] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(285),
+  [995] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(291),
+  [998] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 3, 0, 0),
+  [1000] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_go_paren_body_repeat1, 2, 0, 0), SHIFT_REPEAT(297),
+  [1003] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_go_paren_body_repeat1, 2, 0, 0),
+  [1005] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_go_paren_body_repeat1, 2, 0, 0), SHIFT_REPEAT(348),
+  [1008] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_go_paren_body_repeat1, 2, 0, 0), SHIFT_REPEAT(343),
+  [1011] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_go_paren_body_repeat1, 2, 0, 0), SHIFT_REPEAT(348),
+  [1014] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_interface_type_repeat1, 1, 0, 0),
+  [1016] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_interface_type_repeat1, 1, 0, 0),
+  [1018] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_element_with_children_repeat1, 1, 0, 0),
+  [1020] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_element_with_children_repeat1, 1, 0, 0),
+  [1022] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__element_child, 1, 0, 0),
+  [1024] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__element_child, 1, 0, 0),
+  [1026] = {.entry = {.count = 1, .reusable = true}}, SHIFT(198),
+  [1028] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import_section, 1, 0, 0),
+  [1030] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_import_section_repeat1, 2, 0, 0),
+  [1032] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_import_section_repeat1, 2, 0, 0), SHIFT_REPEAT(396),
+  [1035] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_nested_braces, 3, 0, 0),
+  [1037] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_nested_braces, 3, 0, 0),
+  [1039] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_nested_parens, 3, 0, 0),
+  [1041] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_nested_parens, 3, 0, 0),
+  [1043] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_nested_braces, 2, 0, 0),
+  [1045] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_nested_braces, 2, 0, 0),
+  [1047] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_nested_parens, 2, 0, 0),
+  [1049] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_nested_parens, 2, 0, 0),
+  [1051] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_go_paren_body_repeat1, 1, 0, 0),
+  [1053] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_go_paren_body_repeat1, 1, 0, 0),
+  [1055] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_expression_content_repeat1, 1, 0, 0),
+  [1057] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_expression_content_repeat1, 1, 0, 0),
+  [1059] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import_spec_list, 2, 0, 0),
+  [1061] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import_declaration, 2, 0, 0),
+  [1063] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import_spec_list, 3, 0, 0),
+  [1065] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_import_section_repeat1, 1, 0, 0),
+  [1067] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_package_clause, 2, 0, 1),
+  [1069] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import_spec, 1, 0, 2),
+  [1071] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_go_declaration, 5, 0, 4),
+  [1073] = {.entry = {.count = 1, .reusable = true}}, SHIFT(575),
+  [1075] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import_spec, 2, 0, 5),
+  [1077] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_declaration, 5, 0, 17),
+  [1079] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_go_declaration, 8, 0, 4),
+  [1081] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_declaration, 6, 0, 26),
+  [1083] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_declaration, 6, 0, 27),
+  [1085] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_go_declaration, 2, 0, 3),
+  [1087] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component_declaration, 5, 0, 15),
+  [1089] = {.entry = {.count = 1, .reusable = true}}, SHIFT(593),
+  [1091] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 1, 0, 0),
+  [1093] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component_body, 2, 0, 0),
+  [1095] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_go_declaration, 3, 0, 6),
+  [1097] = {.entry = {.count = 1, .reusable = true}}, SHIFT(263),
+  [1099] = {.entry = {.count = 1, .reusable = true}}, SHIFT(51),
+  [1101] = {.entry = {.count = 1, .reusable = true}}, SHIFT(534),
+  [1103] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_declaration, 5, 0, 15),
+  [1105] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_declaration, 5, 0, 16),
+  [1107] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_go_paren_body, 2, 0, 0),
+  [1109] = {.entry = {.count = 1, .reusable = true}}, SHIFT(276),
+  [1111] = {.entry = {.count = 1, .reusable = true}}, SHIFT(278),
+  [1113] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component_declaration, 4, 0, 8),
+  [1115] = {.entry = {.count = 1, .reusable = true}}, SHIFT(273),
+  [1117] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_go_brace_body, 3, 0, 0),
+  [1119] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component_body, 3, 0, 0),
+  [1121] = {.entry = {.count = 1, .reusable = true}}, SHIFT(319),
+  [1123] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_declaration, 4, 0, 8),
+  [1125] = {.entry = {.count = 1, .reusable = true}}, SHIFT(331),
+  [1127] = {.entry = {.count = 1, .reusable = true}}, SHIFT(332),
+  [1129] = {.entry = {.count = 1, .reusable = true}}, SHIFT(318),
+  [1131] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_go_paren_body, 3, 0, 0),
+  [1133] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_struct_declaration, 4, 0, 9),
+  [1135] = {.entry = {.count = 1, .reusable = true}}, SHIFT(288),
+  [1137] = {.entry = {.count = 1, .reusable = true}}, SHIFT(448),
+  [1139] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_switch_statement_repeat1, 2, 0, 0),
+  [1141] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_switch_statement_repeat1, 2, 0, 0), SHIFT_REPEAT(51),
+  [1144] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_switch_statement_repeat1, 2, 0, 0), SHIFT_REPEAT(534),
+  [1147] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_go_brace_body, 2, 0, 0),
+  [1149] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component_declaration, 6, 0, 25),
+  [1151] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_self_closing_element_repeat1, 2, 0, 0), SHIFT_REPEAT(409),
+  [1154] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_self_closing_element_repeat1, 2, 0, 0),
+  [1156] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_self_closing_element_repeat1, 2, 0, 0),
+  [1158] = {.entry = {.count = 1, .reusable = true}}, SHIFT(409),
+  [1160] = {.entry = {.count = 1, .reusable = false}}, SHIFT(571),
+  [1162] = {.entry = {.count = 1, .reusable = true}}, SHIFT(168),
+  [1164] = {.entry = {.count = 1, .reusable = true}}, SHIFT(556),
+  [1166] = {.entry = {.count = 1, .reusable = true}}, SHIFT(353),
+  [1168] = {.entry = {.count = 1, .reusable = true}}, SHIFT(441),
+  [1170] = {.entry = {.count = 1, .reusable = true}}, SHIFT(558),
+  [1172] = {.entry = {.count = 1, .reusable = true}}, SHIFT(398),
+  [1174] = {.entry = {.count = 1, .reusable = true}}, SHIFT(356),
+  [1176] = {.entry = {.count = 1, .reusable = false}}, SHIFT(517),
+  [1178] = {.entry = {.count = 1, .reusable = true}}, SHIFT(164),
+  [1180] = {.entry = {.count = 1, .reusable = true}}, SHIFT(351),
+  [1182] = {.entry = {.count = 1, .reusable = false}}, SHIFT(531),
+  [1184] = {.entry = {.count = 1, .reusable = true}}, SHIFT(159),
+  [1186] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_import_spec_list_repeat1, 2, 0, 0), SHIFT_REPEAT(556),
+  [1189] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_import_spec_list_repeat1, 2, 0, 0),
+  [1191] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_import_spec_list_repeat1, 2, 0, 0), SHIFT_REPEAT(441),
+  [1194] = {.entry = {.count = 1, .reusable = false}}, SHIFT(523),
+  [1196] = {.entry = {.count = 1, .reusable = true}}, SHIFT(169),
+  [1198] = {.entry = {.count = 1, .reusable = true}}, SHIFT(171),
+  [1200] = {.entry = {.count = 1, .reusable = true}}, SHIFT(249),
+  [1202] = {.entry = {.count = 1, .reusable = true}}, SHIFT(473),
+  [1204] = {.entry = {.count = 1, .reusable = true}}, SHIFT(252),
+  [1206] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_type_constraint_repeat1, 2, 0, 0), SHIFT_REPEAT(165),
+  [1209] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 1, 0, 33),
+  [1211] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute, 1, 0, 33),
+  [1213] = {.entry = {.count = 1, .reusable = true}}, SHIFT(388),
+  [1215] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_struct_body_repeat1, 2, 0, 0), SHIFT_REPEAT(171),
+  [1218] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_struct_body_repeat1, 2, 0, 0),
+  [1220] = {.entry = {.count = 1, .reusable = true}}, SHIFT(6),
+  [1222] = {.entry = {.count = 1, .reusable = true}}, SHIFT(86),
+  [1224] = {.entry = {.count = 1, .reusable = true}}, SHIFT(11),
+  [1226] = {.entry = {.count = 1, .reusable = true}}, SHIFT(62),
+  [1228] = {.entry = {.count = 1, .reusable = true}}, SHIFT(310),
+  [1230] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_generic_type_repeat1, 2, 0, 0),
+  [1232] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_generic_type_repeat1, 2, 0, 0), SHIFT_REPEAT(174),
+  [1235] = {.entry = {.count = 1, .reusable = true}}, SHIFT(309),
+  [1237] = {.entry = {.count = 1, .reusable = true}}, SHIFT(165),
+  [1239] = {.entry = {.count = 1, .reusable = true}}, SHIFT(49),
+  [1241] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_literal_value_repeat1, 2, 0, 0), SHIFT_REPEAT(10),
+  [1244] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_literal_value_repeat1, 2, 0, 0),
+  [1246] = {.entry = {.count = 1, .reusable = true}}, SHIFT(122),
+  [1248] = {.entry = {.count = 1, .reusable = true}}, SHIFT(212),
+  [1250] = {.entry = {.count = 1, .reusable = true}}, SHIFT(146),
+  [1252] = {.entry = {.count = 1, .reusable = true}}, SHIFT(174),
+  [1254] = {.entry = {.count = 1, .reusable = true}}, SHIFT(221),
+  [1256] = {.entry = {.count = 1, .reusable = true}}, SHIFT(108),
+  [1258] = {.entry = {.count = 1, .reusable = true}}, SHIFT(583),
+  [1260] = {.entry = {.count = 1, .reusable = true}}, SHIFT(231),
+  [1262] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_type_parameter_list_repeat1, 2, 0, 0), SHIFT_REPEAT(462),
+  [1265] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_type_parameter_list_repeat1, 2, 0, 0),
+  [1267] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_self_closing_element_repeat1, 1, 0, 0),
+  [1269] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_self_closing_element_repeat1, 1, 0, 0),
+  [1271] = {.entry = {.count = 1, .reusable = true}}, SHIFT(455),
+  [1273] = {.entry = {.count = 1, .reusable = true}}, SHIFT(536),
+  [1275] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_argument_list_repeat1, 2, 0, 0), SHIFT_REPEAT(42),
+  [1278] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_switch_statement_repeat1, 1, 0, 0),
+  [1280] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_import_spec_list_repeat1, 1, 0, 0),
+  [1282] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_case_clause_repeat1, 2, 0, 57), SHIFT_REPEAT(61),
+  [1285] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_case_clause_repeat1, 2, 0, 57),
+  [1287] = {.entry = {.count = 1, .reusable = true}}, SHIFT(24),
+  [1289] = {.entry = {.count = 1, .reusable = true}}, SHIFT(545),
+  [1291] = {.entry = {.count = 1, .reusable = true}}, SHIFT(415),
+  [1293] = {.entry = {.count = 1, .reusable = true}}, SHIFT(597),
+  [1295] = {.entry = {.count = 1, .reusable = true}}, SHIFT(222),
+  [1297] = {.entry = {.count = 1, .reusable = true}}, SHIFT(406),
+  [1299] = {.entry = {.count = 1, .reusable = true}}, SHIFT(17),
+  [1301] = {.entry = {.count = 1, .reusable = true}}, SHIFT(220),
+  [1303] = {.entry = {.count = 1, .reusable = true}}, SHIFT(30),
+  [1305] = {.entry = {.count = 1, .reusable = true}}, SHIFT(587),
+  [1307] = {.entry = {.count = 1, .reusable = true}}, SHIFT(26),
+  [1309] = {.entry = {.count = 1, .reusable = true}}, SHIFT(127),
+  [1311] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__attribute_value, 1, 0, 0),
+  [1313] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__attribute_value, 1, 0, 0),
+  [1315] = {.entry = {.count = 1, .reusable = true}}, SHIFT(29),
+  [1317] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 3, 0, 37),
+  [1319] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute, 3, 0, 37),
+  [1321] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
+  [1323] = {.entry = {.count = 1, .reusable = true}}, SHIFT(577),
+  [1325] = {.entry = {.count = 1, .reusable = true}}, SHIFT(426),
+  [1327] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_parameter_list_repeat1, 2, 0, 0),
+  [1329] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_parameter_list_repeat1, 2, 0, 0), SHIFT_REPEAT(149),
+  [1332] = {.entry = {.count = 1, .reusable = true}}, SHIFT(7),
+  [1334] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parameter, 1, -1, 7),
+  [1336] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_parameter_declaration, 3, 0, 24),
+  [1338] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variadic_argument, 2, 0, 0),
+  [1340] = {.entry = {.count = 1, .reusable = true}}, SHIFT(524),
+  [1342] = {.entry = {.count = 1, .reusable = true}}, SHIFT(525),
+  [1344] = {.entry = {.count = 1, .reusable = true}}, SHIFT(530),
+  [1346] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_field, 2, 0, 13),
+  [1348] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parameter, 2, 0, 13),
+  [1350] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parameter, 3, 0, 22),
+  [1352] = {.entry = {.count = 1, .reusable = true}}, SHIFT(418),
+  [1354] = {.entry = {.count = 1, .reusable = true}}, SHIFT(153),
+  [1356] = {.entry = {.count = 1, .reusable = true}}, SHIFT(539),
+  [1358] = {.entry = {.count = 1, .reusable = true}}, SHIFT(260),
+  [1360] = {.entry = {.count = 1, .reusable = true}}, SHIFT(542),
+  [1362] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_parameter_declaration, 2, 0, 14),
+  [1364] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_struct_body_repeat1, 1, 0, 0),
+  [1366] = {.entry = {.count = 1, .reusable = true}}, SHIFT(553),
+  [1368] = {.entry = {.count = 1, .reusable = true}}, SHIFT(74),
+  [1370] = {.entry = {.count = 1, .reusable = true}}, SHIFT(519),
+  [1372] = {.entry = {.count = 1, .reusable = true}}, SHIFT(555),
+  [1374] = {.entry = {.count = 1, .reusable = true}}, SHIFT(78),
+  [1376] = {.entry = {.count = 1, .reusable = true}}, SHIFT(183),
+  [1378] = {.entry = {.count = 1, .reusable = true}}, SHIFT(23),
+  [1380] = {.entry = {.count = 1, .reusable = true}}, SHIFT(557),
+  [1382] = {.entry = {.count = 1, .reusable = true}}, SHIFT(564),
+  [1384] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_field, 3, 0, 22),
+  [1386] = {.entry = {.count = 1, .reusable = true}}, SHIFT(567),
+  [1388] = {.entry = {.count = 1, .reusable = true}}, SHIFT(328),
+  [1390] = {.entry = {.count = 1, .reusable = true}}, SHIFT(569),
+  [1392] = {.entry = {.count = 1, .reusable = true}}, SHIFT(405),
+  [1394] = {.entry = {.count = 1, .reusable = true}}, SHIFT(592),
+  [1396] = {.entry = {.count = 1, .reusable = true}}, SHIFT(467),
+  [1398] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variadic_parameter, 2, 0, 12),
+  [1400] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_keyed_element, 3, 0, 47),
+  [1402] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variadic_parameter, 3, 0, 21),
+  [1404] = {.entry = {.count = 1, .reusable = true}}, SHIFT(529),
+  [1406] = {.entry = {.count = 1, .reusable = true}}, SHIFT(538),
+  [1408] = {.entry = {.count = 1, .reusable = true}}, SHIFT(375),
+  [1410] = {.entry = {.count = 1, .reusable = true}}, SHIFT(264),
+  [1412] = {.entry = {.count = 1, .reusable = true}}, SHIFT(355),
+  [1414] = {.entry = {.count = 1, .reusable = true}}, SHIFT(76),
+  [1416] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_receiver, 4, 0, 11),
+  [1418] = {.entry = {.count = 1, .reusable = true}}, SHIFT(326),
+  [1420] = {.entry = {.count = 1, .reusable = true}}, SHIFT(327),
+  [1422] = {.entry = {.count = 1, .reusable = true}}, SHIFT(576),
+  [1424] = {.entry = {.count = 1, .reusable = true}}, SHIFT(57),
+  [1426] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [1428] = {.entry = {.count = 1, .reusable = true}}, SHIFT(8),
+  [1430] = {.entry = {.count = 1, .reusable = true}}, SHIFT(85),
+  [1432] = {.entry = {.count = 1, .reusable = true}}, SHIFT(335),
+  [1434] = {.entry = {.count = 1, .reusable = true}}, SHIFT(520),
+  [1436] = {.entry = {.count = 1, .reusable = true}}, SHIFT(277),
+  [1438] = {.entry = {.count = 1, .reusable = true}}, SHIFT(115),
+  [1440] = {.entry = {.count = 1, .reusable = false}}, SHIFT(566),
+  [1442] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_parameter_list, 3, 0, 0),
+  [1444] = {.entry = {.count = 1, .reusable = true}}, SHIFT(598),
+  [1446] = {.entry = {.count = 1, .reusable = true}}, SHIFT(336),
+  [1448] = {.entry = {.count = 1, .reusable = true}}, SHIFT(293),
+  [1450] = {.entry = {.count = 1, .reusable = true}}, SHIFT(320),
+  [1452] = {.entry = {.count = 1, .reusable = true}}, SHIFT(261),
+  [1454] = {.entry = {.count = 1, .reusable = true}}, SHIFT(333),
+  [1456] = {.entry = {.count = 1, .reusable = true}}, SHIFT(255),
+  [1458] = {.entry = {.count = 1, .reusable = true}}, SHIFT(470),
+  [1460] = {.entry = {.count = 1, .reusable = true}}, SHIFT(268),
+  [1462] = {.entry = {.count = 1, .reusable = true}}, SHIFT(433),
+  [1464] = {.entry = {.count = 1, .reusable = true}}, SHIFT(549),
+  [1466] = {.entry = {.count = 1, .reusable = true}}, SHIFT(570),
+  [1468] = {.entry = {.count = 1, .reusable = true}}, SHIFT(397),
+  [1470] = {.entry = {.count = 1, .reusable = true}}, SHIFT(512),
+  [1472] = {.entry = {.count = 1, .reusable = true}}, SHIFT(274),
+  [1474] = {.entry = {.count = 1, .reusable = true}}, SHIFT(516),
+  [1476] = {.entry = {.count = 1, .reusable = true}}, SHIFT(445),
+  [1478] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
+  [1480] = {.entry = {.count = 1, .reusable = true}}, SHIFT(358),
+  [1482] = {.entry = {.count = 1, .reusable = true}}, SHIFT(437),
+  [1484] = {.entry = {.count = 1, .reusable = true}}, SHIFT(365),
+  [1486] = {.entry = {.count = 1, .reusable = true}}, SHIFT(360),
+  [1488] = {.entry = {.count = 1, .reusable = true}}, SHIFT(4),
+  [1490] = {.entry = {.count = 1, .reusable = true}}, SHIFT(533),
+  [1492] = {.entry = {.count = 1, .reusable = true}}, SHIFT(178),
+  [1494] = {.entry = {.count = 1, .reusable = true}}, SHIFT(357),
+  [1496] = {.entry = {.count = 1, .reusable = true}}, SHIFT(552),
+  [1498] = {.entry = {.count = 1, .reusable = true}}, SHIFT(329),
+  [1500] = {.entry = {.count = 1, .reusable = true}}, SHIFT(480),
+  [1502] = {.entry = {.count = 1, .reusable = true}}, SHIFT(254),
+  [1504] = {.entry = {.count = 1, .reusable = true}}, SHIFT(193),
+  [1506] = {.entry = {.count = 1, .reusable = true}}, SHIFT(190),
+  [1508] = {.entry = {.count = 1, .reusable = true}}, SHIFT(186),
+  [1510] = {.entry = {.count = 1, .reusable = false}}, SHIFT(561),
+  [1512] = {.entry = {.count = 1, .reusable = true}}, SHIFT(586),
+  [1514] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_parameter_list, 4, 0, 0),
+  [1516] = {.entry = {.count = 1, .reusable = true}}, SHIFT(102),
+  [1518] = {.entry = {.count = 1, .reusable = true}}, SHIFT(229),
+  [1520] = {.entry = {.count = 1, .reusable = true}}, SHIFT(444),
+  [1522] = {.entry = {.count = 1, .reusable = true}}, SHIFT(196),
+  [1524] = {.entry = {.count = 1, .reusable = true}}, SHIFT(522),
+  [1526] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_parameter_list, 5, 0, 0),
+  [1528] = {.entry = {.count = 1, .reusable = true}}, SHIFT(206),
+  [1530] = {.entry = {.count = 1, .reusable = true}}, SHIFT(384),
+  [1532] = {.entry = {.count = 1, .reusable = true}}, SHIFT(45),
+  [1534] = {.entry = {.count = 1, .reusable = true}}, SHIFT(481),
+  [1536] = {.entry = {.count = 1, .reusable = true}}, SHIFT(185),
+  [1538] = {.entry = {.count = 1, .reusable = true}}, SHIFT(541),
+  [1540] = {.entry = {.count = 1, .reusable = true}}, SHIFT(543),
+  [1542] = {.entry = {.count = 1, .reusable = true}}, SHIFT(202),
+  [1544] = {.entry = {.count = 1, .reusable = true}}, SHIFT(403),
+  [1546] = {.entry = {.count = 1, .reusable = true}}, SHIFT(585),
+  [1548] = {.entry = {.count = 1, .reusable = true}}, SHIFT(378),
+  [1550] = {.entry = {.count = 1, .reusable = true}}, SHIFT(316),
+  [1552] = {.entry = {.count = 1, .reusable = true}}, SHIFT(187),
+  [1554] = {.entry = {.count = 1, .reusable = true}}, SHIFT(262),
+  [1556] = {.entry = {.count = 1, .reusable = true}}, SHIFT(205),
+  [1558] = {.entry = {.count = 1, .reusable = true}}, SHIFT(546),
+  [1560] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comment, 1, 0, 0),
+};
+
+enum ts_external_scanner_symbol_identifiers {
+  ts_external_token_string = 0,
+  ts_external_token_raw_string = 1,
+  ts_external_token_rune = 2,
+  ts_external_token_text_content = 3,
+  ts_external_token_go_statement = 4,
+};
+
+static const TSSymbol ts_external_scanner_symbol_map[EXTERNAL_TOKEN_COUNT] = {
+  [ts_external_token_string] = sym_string,
+  [ts_external_token_raw_string] = sym_raw_string,
+  [ts_external_token_rune] = sym_rune,
+  [ts_external_token_text_content] = sym_text_content,
+  [ts_external_token_go_statement] = sym_go_statement,
+};
+
+static const bool ts_external_scanner_states[7][EXTERNAL_TOKEN_COUNT] = {
+  [1] = {
+    [ts_external_token_string] = true,
+    [ts_external_token_raw_string] = true,
+    [ts_external_token_rune] = true,
+    [ts_external_token_text_content] = true,
+    [ts_external_token_go_statement] = true,
+  },
+  [2] = {
+    [ts_external_token_string] = true,
+    [ts_external_token_raw_string] = true,
+    [ts_external_token_rune] = true,
+  },
+  [3] = {
+    [ts_external_token_go_statement] = true,
+  },
+  [4] = {
+    [ts_external_token_text_content] = true,
+  },
+  [5] = {
+    [ts_external_token_string] = true,
+    [ts_external_token_raw_string] = true,
+  },
+  [6] = {
+    [ts_external_token_string] = true,
+  },
 };
 
 #ifdef __cplusplus
 extern "C" {
 #endif
+void *tree_sitter_gsx_external_scanner_create(void);
+void tree_sitter_gsx_external_scanner_destroy(void *);
+bool tree_sitter_gsx_external_scanner_scan(void *, TSLexer *, const bool *);
+unsigned tree_sitter_gsx_external_scanner_serialize(void *, char *);
+void tree_sitter_gsx_external_scanner_deserialize(void *, const char *, unsigned);
+
 #ifdef TREE_SITTER_HIDE_SYMBOLS
 #define TS_PUBLIC
 #elif defined(_WIN32)
@@ -9236,6 +20628,15 @@ TS_PUBLIC const TSLanguage *tree_sitter_gsx(void) {
     .lex_fn = ts_lex,
     .keyword_lex_fn = ts_lex_keywords,
     .keyword_capture_token = sym_identifier,
+    .external_scanner = {
+      &ts_external_scanner_states[0][0],
+      ts_external_scanner_symbol_map,
+      tree_sitter_gsx_external_scanner_create,
+      tree_sitter_gsx_external_scanner_destroy,
+      tree_sitter_gsx_external_scanner_scan,
+      tree_sitter_gsx_external_scanner_serialize,
+      tree_sitter_gsx_external_scanner_deserialize,
+    },
     .primary_state_ids = ts_primary_state_ids,
     .name = "gsx",
     .max_reserved_word_set_size = 0,
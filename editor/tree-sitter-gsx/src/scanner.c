@@ -0,0 +1,633 @@
+#include "tree_sitter/parser.h"
+
+#include <string.h>
+#include <wctype.h>
+
+// External scanner for GSX. Handles the tokens whose escapes a regex can't
+// express cleanly, following the rules of internal/tuigen/lexer_strings.go:
+//
+//   string        "..." with backslash escapes; a newline ends it unterminated
+//   raw_string    `...` spanning lines, no escapes
+//   rune          'x', '\n', '\x41', 'é'
+//   text_content  a run of element text up to a newline or one of < > { } @,
+//                 where \{ \} \@ \< \> and \\ stand for the character itself;
+//                 a run can't start with control flow or a let binding
+//   go_statement  a raw Go statement in a component body, following
+//                 parseGoStatement in internal/tuigen/parser_expr.go
+
+enum TokenType {
+  STRING,
+  RAW_STRING,
+  RUNE,
+  TEXT_CONTENT,
+  GO_STATEMENT,
+};
+
+static inline void advance(TSLexer *lexer) { lexer->advance(lexer, false); }
+
+static inline void skip(TSLexer *lexer) { lexer->advance(lexer, true); }
+
+static bool is_text_delimiter(int32_t c) {
+  switch (c) {
+    case '<':
+    case '>':
+    case '{':
+    case '}':
+    case '@':
+    case '\n':
+      return true;
+    default:
+      return false;
+  }
+}
+
+static bool is_hex_digit(int32_t c) {
+  return (c >= '0' && c <= '9') || (c >= 'a' && c <= 'f') ||
+         (c >= 'A' && c <= 'F');
+}
+
+static bool scan_string(TSLexer *lexer) {
+  advance(lexer);
+  for (;;) {
+    if (lexer->eof(lexer) || lexer->lookahead == '\n') {
+      return false;
+    }
+    switch (lexer->lookahead) {
+      case '"':
+        advance(lexer);
+        lexer->result_symbol = STRING;
+        return true;
+      case '\\':
+        // Unknown escapes are kept as-is, like tuigen does, so any character
+        // but a newline may follow the backslash.
+        advance(lexer);
+        if (lexer->eof(lexer) || lexer->lookahead == '\n') {
+          return false;
+        }
+        advance(lexer);
+        break;
+      default:
+        advance(lexer);
+        break;
+    }
+  }
+}
+
+static bool scan_raw_string(TSLexer *lexer) {
+  advance(lexer);
+  while (!lexer->eof(lexer)) {
+    if (lexer->lookahead == '`') {
+      advance(lexer);
+      lexer->result_symbol = RAW_STRING;
+      return true;
+    }
+    advance(lexer);
+  }
+  return false;
+}
+
+static bool scan_rune(TSLexer *lexer) {
+  advance(lexer);
+  switch (lexer->lookahead) {
+    case '\'':
+    case '\n':
+      return false;
+    case '\\':
+      advance(lexer);
+      if (lexer->eof(lexer) || lexer->lookahead == '\n') {
+        return false;
+      }
+      if (lexer->lookahead == 'x' || lexer->lookahead == 'u' ||
+          lexer->lookahead == 'U') {
+        advance(lexer);
+        while (is_hex_digit(lexer->lookahead)) {
+          advance(lexer);
+        }
+      } else if (lexer->lookahead >= '0' && lexer->lookahead <= '7') {
+        while (lexer->lookahead >= '0' && lexer->lookahead <= '7') {
+          advance(lexer);
+        }
+      } else {
+        advance(lexer);
+      }
+      break;
+    default:
+      if (lexer->eof(lexer)) {
+        return false;
+      }
+      advance(lexer);
+      break;
+  }
+  if (lexer->lookahead != '\'') {
+    return false;
+  }
+  advance(lexer);
+  lexer->result_symbol = RUNE;
+  return true;
+}
+
+static bool is_ident_char(int32_t c) { return iswalnum(c) || c == '_'; }
+
+static bool is_keyword(const char *word) {
+  return strcmp(word, "for") == 0 || strcmp(word, "if") == 0 ||
+         strcmp(word, "else") == 0 || strcmp(word, "switch") == 0 ||
+         strcmp(word, "var") == 0;
+}
+
+typedef enum {
+  TEXT_CONTINUES,
+  TEXT_ENDS,
+  NOT_TEXT,
+} TextStart;
+
+// Reads the word at the start of a text run and reports whether it starts a
+// child that isn't text instead: control flow, or a `name := <element>` or
+// `name := @Call()` binding. tuigen tries those before coalescing text.
+static TextStart scan_text_start(TSLexer *lexer) {
+  char word[8];
+  size_t len = 0;
+  while (is_ident_char(lexer->lookahead)) {
+    if (len < sizeof(word) - 1) {
+      word[len] = (char)lexer->lookahead;
+    }
+    len++;
+    advance(lexer);
+    lexer->mark_end(lexer);
+  }
+  if (len == 0) {
+    return TEXT_CONTINUES;
+  }
+  word[len < sizeof(word) ? len : sizeof(word) - 1] = '\0';
+  int32_t next = lexer->lookahead;
+  if (len < sizeof(word) && is_keyword(word) &&
+      (next == ' ' || next == '\t' || next == '{')) {
+    return NOT_TEXT;
+  }
+
+  while (lexer->lookahead == ' ' || lexer->lookahead == '\t') {
+    advance(lexer);
+    lexer->mark_end(lexer);
+  }
+  if (lexer->lookahead != ':') {
+    return TEXT_CONTINUES;
+  }
+  advance(lexer);
+  lexer->mark_end(lexer);
+  if (lexer->lookahead != '=') {
+    return TEXT_CONTINUES;
+  }
+  advance(lexer);
+  lexer->mark_end(lexer);
+  while (lexer->lookahead == ' ' || lexer->lookahead == '\t') {
+    advance(lexer);
+    lexer->mark_end(lexer);
+  }
+  if (lexer->lookahead != '\n') {
+    int32_t c = lexer->lookahead;
+    return c == '<' || c == '@' ? NOT_TEXT : TEXT_CONTINUES;
+  }
+  // The value may start on a later line, so look past the end of the text
+  // without marking it.
+  while (iswspace(lexer->lookahead)) {
+    advance(lexer);
+  }
+  int32_t c = lexer->lookahead;
+  return c == '<' || c == '@' ? NOT_TEXT : TEXT_ENDS;
+}
+
+static bool scan_text(TSLexer *lexer) {
+  if (lexer->eof(lexer) || is_text_delimiter(lexer->lookahead)) {
+    return false;
+  }
+  switch (scan_text_start(lexer)) {
+    case NOT_TEXT:
+      return false;
+    case TEXT_ENDS:
+      lexer->result_symbol = TEXT_CONTENT;
+      return true;
+    case TEXT_CONTINUES:
+      break;
+  }
+  // A comment at the start of a run is a comment, as in tuigen.
+  if (lexer->lookahead == '/') {
+    advance(lexer);
+    if (lexer->lookahead == '/' || lexer->lookahead == '*') {
+      return false;
+    }
+    lexer->mark_end(lexer);
+  }
+
+  while (!lexer->eof(lexer)) {
+    int32_t c = lexer->lookahead;
+    if (c == '\\') {
+      advance(lexer);
+      switch (lexer->lookahead) {
+        case '<':
+        case '>':
+        case '{':
+        case '}':
+        case '@':
+        case '\\':
+          advance(lexer);
+          break;
+        default:
+          // A lone backslash is plain text.
+          break;
+      }
+    } else if (is_text_delimiter(c)) {
+      break;
+    } else {
+      advance(lexer);
+    }
+    lexer->mark_end(lexer);
+  }
+  lexer->result_symbol = TEXT_CONTENT;
+  return true;
+}
+
+static bool is_ident_start(int32_t c) { return iswalpha(c) || c == '_'; }
+
+// Reads an identifier, storing up to size - 1 of its characters in word.
+// Returns its length.
+static size_t scan_word(TSLexer *lexer, char *word, size_t size) {
+  size_t len = 0;
+  while (is_ident_char(lexer->lookahead)) {
+    if (len < size - 1) {
+      word[len] = (char)lexer->lookahead;
+    }
+    len++;
+    advance(lexer);
+    lexer->mark_end(lexer);
+  }
+  word[len < size ? len : size - 1] = '\0';
+  return len;
+}
+
+static bool is_word(const char *word, size_t len, const char *keyword) {
+  return len == strlen(keyword) && strcmp(word, keyword) == 0;
+}
+
+static void skip_blanks(TSLexer *lexer) {
+  while (lexer->lookahead == ' ' || lexer->lookahead == '\t' ||
+         lexer->lookahead == '\r') {
+    advance(lexer);
+  }
+}
+
+// Consumes a Go string, raw string or rune literal. Like tuigen's lexer, a
+// newline ends an unterminated interpreted literal.
+static void skip_go_literal(TSLexer *lexer) {
+  int32_t quote = lexer->lookahead;
+  advance(lexer);
+  while (!lexer->eof(lexer)) {
+    int32_t c = lexer->lookahead;
+    if (c == quote) {
+      advance(lexer);
+      break;
+    }
+    if (quote != '`' && c == '\n') {
+      break;
+    }
+    advance(lexer);
+    if (quote != '`' && c == '\\' && lexer->lookahead != '\n') {
+      advance(lexer);
+    }
+  }
+  lexer->mark_end(lexer);
+}
+
+// Consumes a comment after its leading slash has been read, without marking
+// it as part of the token.
+static void skip_go_comment(TSLexer *lexer) {
+  if (lexer->lookahead == '/') {
+    while (!lexer->eof(lexer) && lexer->lookahead != '\n') {
+      advance(lexer);
+    }
+    return;
+  }
+  advance(lexer);
+  while (!lexer->eof(lexer)) {
+    int32_t c = lexer->lookahead;
+    advance(lexer);
+    if (c == '*' && lexer->lookahead == '/') {
+      advance(lexer);
+      return;
+    }
+  }
+}
+
+// Scans Go code up to the end of the statement: a newline or `;` outside
+// brackets, or the `}` that closes the enclosing block. `;` doesn't end a
+// `for` clause before its body. The token ends after the last character of
+// code, leaving out trailing space and a trailing line comment.
+static bool scan_go_code(TSLexer *lexer, int depth, bool for_header) {
+  while (!lexer->eof(lexer)) {
+    int32_t c = lexer->lookahead;
+    switch (c) {
+      case '\n':
+      case ';':
+        if (depth == 0 && (c == '\n' || !for_header)) {
+          lexer->result_symbol = GO_STATEMENT;
+          return true;
+        }
+        break;
+      case '}':
+        if (depth == 0) {
+          lexer->result_symbol = GO_STATEMENT;
+          return true;
+        }
+        depth--;
+        break;
+      case '{':
+        for_header = false;
+        depth++;
+        break;
+      case '(':
+      case '[':
+        depth++;
+        break;
+      case ')':
+      case ']':
+        if (depth > 0) {
+          depth--;
+        }
+        break;
+      case '"':
+      case '`':
+      case '\'':
+        skip_go_literal(lexer);
+        continue;
+      case '/':
+        advance(lexer);
+        if (lexer->lookahead == '/' && depth == 0) {
+          lexer->result_symbol = GO_STATEMENT;
+          return true;
+        }
+        if (lexer->lookahead == '/' || lexer->lookahead == '*') {
+          skip_go_comment(lexer);
+        } else {
+          lexer->mark_end(lexer);
+        }
+        continue;
+      default:
+        break;
+    }
+    advance(lexer);
+    if (!iswspace(c)) {
+      lexer->mark_end(lexer);
+    }
+  }
+  lexer->result_symbol = GO_STATEMENT;
+  return true;
+}
+
+// Consumes a bracketed group such as a call's arguments.
+static void skip_go_group(TSLexer *lexer) {
+  int depth = 0;
+  while (!lexer->eof(lexer)) {
+    int32_t c = lexer->lookahead;
+    switch (c) {
+      case '(':
+      case '[':
+      case '{':
+        depth++;
+        break;
+      case ')':
+      case ']':
+      case '}':
+        depth--;
+        break;
+      case '"':
+      case '`':
+      case '\'':
+        skip_go_literal(lexer);
+        continue;
+      case '/':
+        advance(lexer);
+        if (lexer->lookahead == '/' || lexer->lookahead == '*') {
+          skip_go_comment(lexer);
+        }
+        continue;
+      default:
+        break;
+    }
+    advance(lexer);
+    lexer->mark_end(lexer);
+    if (depth == 0) {
+      return;
+    }
+  }
+}
+
+// After `name :=` or `var name =`: a following element or component call
+// makes a let binding, and with `:=` a single call such as
+// `tui.NewState(0)` makes a state_declaration; anything else is Go code.
+static bool scan_go_assignment(TSLexer *lexer, bool short_form) {
+  while (iswspace(lexer->lookahead)) {
+    advance(lexer);
+  }
+  if (lexer->lookahead == '@') {
+    return false;
+  }
+  if (lexer->lookahead == '<') {
+    advance(lexer);
+    if (lexer->lookahead != '-') {
+      return false;
+    }
+    lexer->mark_end(lexer);
+    return scan_go_code(lexer, 0, false);
+  }
+  if (!short_form || !is_ident_start(lexer->lookahead)) {
+    return scan_go_code(lexer, 0, false);
+  }
+
+  char word[8];
+  scan_word(lexer, word, sizeof(word));
+  bool call = false;
+  for (;;) {
+    int32_t c = lexer->lookahead;
+    if (c == '.') {
+      advance(lexer);
+      lexer->mark_end(lexer);
+      if (!is_ident_start(lexer->lookahead)) {
+        break;
+      }
+      scan_word(lexer, word, sizeof(word));
+      call = false;
+    } else if (c == '(' || c == '[') {
+      skip_go_group(lexer);
+      call = c == '(';
+    } else {
+      break;
+    }
+  }
+  skip_blanks(lexer);
+  if (call) {
+    switch (lexer->lookahead) {
+      case '\n':
+      case '}':
+        return false;
+      case '/':
+        advance(lexer);
+        if (lexer->lookahead == '/') {
+          return false;
+        }
+        if (lexer->lookahead == '*') {
+          skip_go_comment(lexer);
+        } else {
+          lexer->mark_end(lexer);
+        }
+        break;
+      default:
+        if (lexer->eof(lexer)) {
+          return false;
+        }
+        break;
+    }
+  }
+  return scan_go_code(lexer, 0, false);
+}
+
+// Scans the header of a `for` or `switch` up to its `{`. A `for` is a
+// for_statement when the header has `:= range`, as in tuigen's
+// isRangeForLoop; a `switch` is a switch_statement unless it has an init
+// statement or is a type switch, which the grammar doesn't parse.
+static bool scan_go_header(TSLexer *lexer, bool is_for) {
+  bool go = is_for;
+  while (!lexer->eof(lexer) && lexer->lookahead != '{') {
+    int32_t c = lexer->lookahead;
+    if (c == '"' || c == '`' || c == '\'') {
+      skip_go_literal(lexer);
+      continue;
+    }
+    advance(lexer);
+    if (!iswspace(c)) {
+      lexer->mark_end(lexer);
+    }
+    if (c == ';') {
+      go = true;
+    } else if (c == ':' && lexer->lookahead == '=') {
+      advance(lexer);
+      lexer->mark_end(lexer);
+      skip_blanks(lexer);
+      char word[8];
+      size_t len = scan_word(lexer, word, sizeof(word));
+      if (is_for && is_word(word, len, "range")) {
+        return false;
+      }
+      go = true;
+    } else if (c == '.' && lexer->lookahead == '(') {
+      advance(lexer);
+      lexer->mark_end(lexer);
+      char word[8];
+      size_t len = scan_word(lexer, word, sizeof(word));
+      if (is_word(word, len, "type")) {
+        go = true;
+      }
+    }
+  }
+  return go && scan_go_code(lexer, 0, is_for);
+}
+
+// Scans a statement starting with an identifier or keyword, or returns false
+// when it starts one of the grammar's own body nodes.
+static bool scan_go_statement(TSLexer *lexer) {
+  char word[8];
+  size_t len = scan_word(lexer, word, sizeof(word));
+  if (is_word(word, len, "if") || is_word(word, len, "else") ||
+      is_word(word, len, "case") || is_word(word, len, "default")) {
+    return false;
+  }
+  if (is_word(word, len, "for") || is_word(word, len, "switch")) {
+    return scan_go_header(lexer, word[0] == 'f');
+  }
+  if (is_word(word, len, "var")) {
+    // var name = <element> is a let binding.
+    skip_blanks(lexer);
+    if (!is_ident_start(lexer->lookahead)) {
+      return scan_go_code(lexer, 0, false);
+    }
+    scan_word(lexer, word, sizeof(word));
+    skip_blanks(lexer);
+    if (lexer->lookahead != '=') {
+      return scan_go_code(lexer, 0, false);
+    }
+    advance(lexer);
+    lexer->mark_end(lexer);
+    return scan_go_assignment(lexer, false);
+  }
+
+  skip_blanks(lexer);
+  if (lexer->lookahead != ':') {
+    return scan_go_code(lexer, 0, false);
+  }
+  advance(lexer);
+  lexer->mark_end(lexer);
+  if (lexer->lookahead != '=') {
+    return scan_go_code(lexer, 0, false);
+  }
+  advance(lexer);
+  lexer->mark_end(lexer);
+  return scan_go_assignment(lexer, true);
+}
+
+void *tree_sitter_gsx_external_scanner_create(void) { return NULL; }
+
+void tree_sitter_gsx_external_scanner_destroy(void *payload) {
+  (void)payload;
+}
+
+unsigned tree_sitter_gsx_external_scanner_serialize(void *payload,
+                                                    char *buffer) {
+  (void)payload;
+  (void)buffer;
+  return 0;
+}
+
+void tree_sitter_gsx_external_scanner_deserialize(void *payload,
+                                                  const char *buffer,
+                                                  unsigned length) {
+  (void)payload;
+  (void)buffer;
+  (void)length;
+}
+
+bool tree_sitter_gsx_external_scanner_scan(void *payload, TSLexer *lexer,
+                                           const bool *valid_symbols) {
+  (void)payload;
+
+  // Every symbol is valid during error recovery. Strings can still be
+  // recognised there, but text would swallow whatever follows.
+  bool recovering = valid_symbols[STRING] && valid_symbols[TEXT_CONTENT];
+
+  while (iswspace(lexer->lookahead)) {
+    skip(lexer);
+  }
+
+  switch (lexer->lookahead) {
+    case '"':
+      if (valid_symbols[STRING]) {
+        return scan_string(lexer);
+      }
+      break;
+    case '`':
+      if (valid_symbols[RAW_STRING]) {
+        return scan_raw_string(lexer);
+      }
+      break;
+    case '\'':
+      if (valid_symbols[RUNE]) {
+        return scan_rune(lexer);
+      }
+      break;
+  }
+
+  if (valid_symbols[TEXT_CONTENT] && !recovering) {
+    return scan_text(lexer);
+  }
+  if (valid_symbols[GO_STATEMENT] && !recovering &&
+      is_ident_start(lexer->lookahead)) {
+    return scan_go_statement(lexer);
+  }
+  return false;
+}
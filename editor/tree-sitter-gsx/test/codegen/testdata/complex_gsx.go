@@ -0,0 +1,353 @@
+// Code generated by tui generate. DO NOT EDIT.
+// Source: complex.gsx
+
+package testdata
+
+import (
+	"fmt"
+
+	tui "github.com/grindlemire/go-tui"
+)
+
+func helperFunction(s string) string {
+	return fmt.Sprintf("[%s]", s)
+}
+
+type ItemListView struct {
+	Root      *tui.Element
+	watchers  []tui.Watcher
+	bindApp   func(*tui.App)
+	unbindApp func()
+}
+
+func (v *ItemListView) UnbindApp() {
+	if v.unbindApp != nil {
+		v.unbindApp()
+	}
+}
+
+func (v *ItemListView) GetRoot() *tui.Element { return v.Root }
+
+func (v *ItemListView) GetWatchers() []tui.Watcher { return v.watchers }
+
+func (v *ItemListView) Render(app *tui.App) *tui.Element { return v.Root }
+
+func (v *ItemListView) BindApp(app *tui.App) {
+	if v.bindApp != nil {
+		v.bindApp(app)
+	}
+}
+
+func (v *ItemListView) UpdateProps(fresh tui.Component) {
+	f, ok := fresh.(*ItemListView)
+	if !ok {
+		return
+	}
+	v.Root = f.Root
+	v.watchers = f.watchers
+	v.bindApp = f.bindApp
+	v.unbindApp = f.unbindApp
+}
+
+var _ tui.AppBinder = (*ItemListView)(nil)
+
+var _ tui.AppUnbinder = (*ItemListView)(nil)
+
+var _ tui.PropsUpdater = (*ItemListView)(nil)
+
+func ItemList(items []string, selected int) *ItemListView {
+	var view ItemListView
+	var watchers []tui.Watcher
+
+	__tui_0 := tui.New(
+		tui.WithDirection(tui.Column),
+		tui.WithGap(1),
+	)
+	for i, item := range items {
+		_ = i
+		if i == selected {
+			__tui_1 := tui.New(
+				tui.WithBorder(tui.BorderSingle),
+			)
+			__tui_2 := tui.New(
+				tui.WithText(fmt.Sprintf("> %s" /* ItemList item */, item)),
+			)
+			__tui_1.AddChild(__tui_2)
+			__tui_0.AddChild(__tui_1)
+		} else {
+			__tui_3 := tui.New(
+				tui.WithText(fmt.Sprintf("  %s", item)),
+			)
+			__tui_0.AddChild(__tui_3)
+		}
+	}
+
+	__bindApp := func(app *tui.App) {
+	}
+
+	__unbindApp := func() {
+	}
+
+	view = ItemListView{
+		Root:      __tui_0,
+		watchers:  watchers,
+		bindApp:   __bindApp,
+		unbindApp: __unbindApp,
+	}
+	return &view
+}
+
+type CounterView struct {
+	Root      *tui.Element
+	watchers  []tui.Watcher
+	bindApp   func(*tui.App)
+	unbindApp func()
+}
+
+func (v *CounterView) UnbindApp() {
+	if v.unbindApp != nil {
+		v.unbindApp()
+	}
+}
+
+func (v *CounterView) GetRoot() *tui.Element { return v.Root }
+
+func (v *CounterView) GetWatchers() []tui.Watcher { return v.watchers }
+
+func (v *CounterView) Render(app *tui.App) *tui.Element { return v.Root }
+
+func (v *CounterView) BindApp(app *tui.App) {
+	if v.bindApp != nil {
+		v.bindApp(app)
+	}
+}
+
+func (v *CounterView) UpdateProps(fresh tui.Component) {
+	f, ok := fresh.(*CounterView)
+	if !ok {
+		return
+	}
+	v.Root = f.Root
+	v.watchers = f.watchers
+	v.bindApp = f.bindApp
+	v.unbindApp = f.unbindApp
+}
+
+var _ tui.AppBinder = (*CounterView)(nil)
+
+var _ tui.AppUnbinder = (*CounterView)(nil)
+
+var _ tui.PropsUpdater = (*CounterView)(nil)
+
+func Counter(count int, label string) *CounterView {
+	var view CounterView
+	var watchers []tui.Watcher
+
+	countText := tui.New(
+		tui.WithText(fmt.Sprintf("%d", count)),
+	)
+	__tui_0 := tui.New(
+		tui.WithDirection(tui.Column),
+		tui.WithGap(1),
+		tui.WithPadding(1),
+	)
+	__tui_1 := tui.New(
+		tui.WithText(label),
+		tui.WithTextStyle(tui.NewStyle().Bold()),
+	)
+	__tui_0.AddChild(__tui_1)
+	__tui_0.AddChild(countText)
+
+	__bindApp := func(app *tui.App) {
+	}
+
+	__unbindApp := func() {
+	}
+
+	view = CounterView{
+		Root:      __tui_0,
+		watchers:  watchers,
+		bindApp:   __bindApp,
+		unbindApp: __unbindApp,
+	}
+	return &view
+}
+
+type ConditionalContentView struct {
+	Root      *tui.Element
+	watchers  []tui.Watcher
+	bindApp   func(*tui.App)
+	unbindApp func()
+}
+
+func (v *ConditionalContentView) UnbindApp() {
+	if v.unbindApp != nil {
+		v.unbindApp()
+	}
+}
+
+func (v *ConditionalContentView) GetRoot() *tui.Element { return v.Root }
+
+func (v *ConditionalContentView) GetWatchers() []tui.Watcher { return v.watchers }
+
+func (v *ConditionalContentView) Render(app *tui.App) *tui.Element { return v.Root }
+
+func (v *ConditionalContentView) BindApp(app *tui.App) {
+	if v.bindApp != nil {
+		v.bindApp(app)
+	}
+}
+
+func (v *ConditionalContentView) UpdateProps(fresh tui.Component) {
+	f, ok := fresh.(*ConditionalContentView)
+	if !ok {
+		return
+	}
+	v.Root = f.Root
+	v.watchers = f.watchers
+	v.bindApp = f.bindApp
+	v.unbindApp = f.unbindApp
+}
+
+var _ tui.AppBinder = (*ConditionalContentView)(nil)
+
+var _ tui.AppUnbinder = (*ConditionalContentView)(nil)
+
+var _ tui.PropsUpdater = (*ConditionalContentView)(nil)
+
+func ConditionalContent(showHeader bool, showFooter bool) *ConditionalContentView {
+	var view ConditionalContentView
+	var watchers []tui.Watcher
+
+	__tui_0 := tui.New(
+		tui.WithDirection(tui.Column),
+	)
+	if showHeader {
+		__tui_1 := tui.New(
+			tui.WithText("Header"),
+		)
+		__tui_0.AddChild(__tui_1)
+	}
+	__tui_2 := tui.New(
+		tui.WithText("Main Content"),
+	)
+	__tui_0.AddChild(__tui_2)
+	if showFooter {
+		__tui_3 := tui.New(
+			tui.WithText("Footer"),
+		)
+		__tui_0.AddChild(__tui_3)
+	} else {
+		__tui_4 := tui.New(
+			tui.WithText("No Footer"),
+		)
+		__tui_0.AddChild(__tui_4)
+	}
+
+	__bindApp := func(app *tui.App) {
+	}
+
+	__unbindApp := func() {
+	}
+
+	view = ConditionalContentView{
+		Root:      __tui_0,
+		watchers:  watchers,
+		bindApp:   __bindApp,
+		unbindApp: __unbindApp,
+	}
+	return &view
+}
+
+type WithHelperView struct {
+	Root      *tui.Element
+	watchers  []tui.Watcher
+	bindApp   func(*tui.App)
+	unbindApp func()
+}
+
+func (v *WithHelperView) UnbindApp() {
+	if v.unbindApp != nil {
+		v.unbindApp()
+	}
+}
+
+func (v *WithHelperView) GetRoot() *tui.Element { return v.Root }
+
+func (v *WithHelperView) GetWatchers() []tui.Watcher { return v.watchers }
+
+func (v *WithHelperView) Render(app *tui.App) *tui.Element { return v.Root }
+
+func (v *WithHelperView) BindApp(app *tui.App) {
+	if v.bindApp != nil {
+		v.bindApp(app)
+	}
+}
+
+func (v *WithHelperView) UpdateProps(fresh tui.Component) {
+	f, ok := fresh.(*WithHelperView)
+	if !ok {
+		return
+	}
+	v.Root = f.Root
+	v.watchers = f.watchers
+	v.bindApp = f.bindApp
+	v.unbindApp = f.unbindApp
+}
+
+var _ tui.AppBinder = (*WithHelperView)(nil)
+
+var _ tui.AppUnbinder = (*WithHelperView)(nil)
+
+var _ tui.PropsUpdater = (*WithHelperView)(nil)
+
+func WithHelper(text string) *WithHelperView {
+	var view WithHelperView
+	var watchers []tui.Watcher
+
+	var __tui_2 *ConditionalContentView
+	shouldShowHeader := true
+	otherHelperFunction("test")
+	__tui_0 := tui.New()
+	__tui_1 := tui.New(
+		tui.WithText(helperFunction(text)),
+	)
+	__tui_0.AddChild(__tui_1)
+	if shouldShowHeader {
+		__tui_2 = ConditionalContent(true, false)
+		__tui_0.AddChild(__tui_2.Root)
+	} else {
+		__tui_3 := tui.New(
+			tui.WithText("False"),
+		)
+		__tui_0.AddChild(__tui_3)
+	}
+
+	if __tui_2 != nil {
+		watchers = append(watchers, __tui_2.GetWatchers()...)
+	}
+
+	__bindApp := func(app *tui.App) {
+		if __tui_2 != nil {
+			if binder, ok := any(__tui_2).(tui.AppBinder); ok {
+				binder.BindApp(app)
+			}
+		}
+	}
+
+	__unbindApp := func() {
+		if __tui_2 != nil {
+			if unbinder, ok := any(__tui_2).(tui.AppUnbinder); ok {
+				unbinder.UnbindApp()
+			}
+		}
+	}
+
+	view = WithHelperView{
+		Root:      __tui_0,
+		watchers:  watchers,
+		bindApp:   __bindApp,
+		unbindApp: __unbindApp,
+	}
+	return &view
+}
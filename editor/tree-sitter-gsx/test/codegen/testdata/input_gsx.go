@@ -0,0 +1,60 @@
+// Code generated by tui generate. DO NOT EDIT.
+// Source: input.gsx
+
+package testdata
+
+import (
+	tui "github.com/grindlemire/go-tui"
+)
+
+type myInput struct {
+	app *tui.App
+}
+
+func MyInput() *myInput {
+	return &myInput{}
+}
+
+func (c *myInput) handleSubmit(text string) {}
+
+func (c *myInput) Render(app *tui.App) *tui.Element {
+	__tui_0 := tui.New(
+		tui.WithDisplay(tui.DisplayFlex), tui.WithDirection(tui.Column),
+		tui.WithGap(1),
+	)
+	__tui_1 := app.MountPersistent(c, 0, func() tui.Component {
+		return tui.NewInput(
+			tui.WithInputPlaceholder("Type here..."),
+			tui.WithInputValue(tui.NewState("hello")),
+			tui.WithInputWidth(30),
+			tui.WithInputBorder(tui.BorderRounded),
+			tui.WithInputOnSubmit(c.handleSubmit),
+		)
+	})
+	__tui_0.AddChild(__tui_1)
+
+	return __tui_0
+}
+
+func (c *myInput) UpdateProps(fresh tui.Component) {
+	f, ok := fresh.(*myInput)
+	if !ok {
+		return
+	}
+	c.app = f.app
+}
+
+var _ tui.PropsUpdater = (*myInput)(nil)
+
+// bindAppFields is generated. It wires the component's *tui.App,
+// State, Events, and TextArea fields to app. When you override BindApp,
+// call this helper instead of hand-maintaining the delegation list.
+func (c *myInput) bindAppFields(app *tui.App) {
+	c.app = app
+}
+
+func (c *myInput) BindApp(app *tui.App) {
+	c.bindAppFields(app)
+}
+
+var _ tui.AppBinder = (*myInput)(nil)
@@ -0,0 +1,113 @@
+// Code generated by tui generate. DO NOT EDIT.
+// Source: modal.gsx
+
+package testdata
+
+import (
+	tui "github.com/grindlemire/go-tui"
+)
+
+type myModal struct {
+	app        *tui.App
+	showModal  *tui.State[bool]
+	gameOver   *tui.State[bool]
+	confirmBtn tui.Ref
+}
+
+func MyModal() *myModal {
+	return &myModal{
+		showModal: tui.NewState(false),
+		gameOver:  tui.NewState(false),
+	}
+}
+
+func (c *myModal) gameOverKeys() tui.KeyMap {
+	return tui.KeyMap{
+		tui.OnPreemptStop(tui.Rune('n'), func(ke tui.KeyEvent) {}),
+		tui.OnPreemptStop(tui.Rune('q'), func(ke tui.KeyEvent) {}),
+	}
+}
+
+func (c *myModal) Render(app *tui.App) *tui.Element {
+	__tui_0 := tui.New(
+		tui.WithDisplay(tui.DisplayFlex), tui.WithDirection(tui.Column),
+	)
+	__tui_1 := tui.New(
+		tui.WithText("Background content"),
+	)
+	__tui_0.AddChild(__tui_1)
+	__tui_2 := app.MountPersistent(c, 0, func() tui.Component {
+		return tui.NewModal(
+			tui.WithModalOpen(c.showModal),
+			tui.WithModalBackdrop("dim"),
+			tui.WithModalElementOptions(tui.WithJustify(tui.JustifyCenter), tui.WithAlign(tui.AlignCenter)),
+		)
+	})
+	__tui_3 := tui.New(
+		tui.WithWidth(40),
+		tui.WithBorder(tui.BorderRounded),
+		tui.WithPadding(2),
+		tui.WithDisplay(tui.DisplayFlex), tui.WithDirection(tui.Column),
+		tui.WithGap(1),
+	)
+	__tui_4 := tui.New(
+		tui.WithText("Are you sure?"),
+		tui.WithTextStyle(tui.NewStyle().Bold()),
+	)
+	__tui_3.AddChild(__tui_4)
+	__tui_5 := tui.New()
+	c.confirmBtn.Set(__tui_5)
+	__tui_6 := tui.New(tui.WithText("OK"))
+	__tui_5.AddChild(__tui_6)
+	__tui_3.AddChild(__tui_5)
+	__tui_2.AddChild(__tui_3)
+	__tui_0.AddChild(__tui_2)
+	__tui_7 := app.MountPersistent(c, 1, func() tui.Component {
+		return tui.NewModal(
+			tui.WithModalOpen(c.gameOver),
+			tui.WithModalKeyMap(c.gameOverKeys()),
+			tui.WithModalTrapFocus(false),
+		)
+	})
+	__tui_8 := tui.New(
+		tui.WithBorder(tui.BorderRounded),
+		tui.WithPadding(2),
+	)
+	__tui_9 := tui.New(
+		tui.WithText("Game Over"),
+	)
+	__tui_8.AddChild(__tui_9)
+	__tui_7.AddChild(__tui_8)
+	__tui_0.AddChild(__tui_7)
+
+	return __tui_0
+}
+
+func (c *myModal) UpdateProps(fresh tui.Component) {
+	f, ok := fresh.(*myModal)
+	if !ok {
+		return
+	}
+	c.app = f.app
+}
+
+var _ tui.PropsUpdater = (*myModal)(nil)
+
+// bindAppFields is generated. It wires the component's *tui.App,
+// State, Events, and TextArea fields to app. When you override BindApp,
+// call this helper instead of hand-maintaining the delegation list.
+func (c *myModal) bindAppFields(app *tui.App) {
+	c.app = app
+	if c.showModal != nil {
+		c.showModal.BindApp(app)
+	}
+	if c.gameOver != nil {
+		c.gameOver.BindApp(app)
+	}
+}
+
+func (c *myModal) BindApp(app *tui.App) {
+	c.bindAppFields(app)
+}
+
+var _ tui.AppBinder = (*myModal)(nil)
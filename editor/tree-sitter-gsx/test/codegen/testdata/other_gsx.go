@@ -0,0 +1,12 @@
+// Code generated by tui generate. DO NOT EDIT.
+// Source: other.gsx
+
+package testdata
+
+import (
+	"fmt"
+)
+
+func otherHelperFunction(s string) string {
+	return fmt.Sprintf("[%s]", s)
+}
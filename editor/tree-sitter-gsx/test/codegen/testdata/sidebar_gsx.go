@@ -0,0 +1,169 @@
+// Code generated by tui generate. DO NOT EDIT.
+// Source: sidebar.gsx
+
+package main
+
+import (
+	tui "github.com/grindlemire/go-tui"
+)
+
+type sidebar struct {
+	category *tui.State[string]
+	expanded *tui.State[bool]
+	selected *tui.State[int]
+}
+
+var categories = []string{"Documents", "Images", "Music", "Projects", "Downloads"}
+
+func Sidebar(category *tui.State[string]) *sidebar {
+	return &sidebar{
+		category: category,
+		expanded: tui.NewState(true),
+		selected: tui.NewState(0),
+	}
+}
+
+func (s *sidebar) KeyMap() tui.KeyMap {
+	km := tui.KeyMap{
+		tui.On(tui.Rune('b').Ctrl(), func(ke tui.KeyEvent) {
+			s.expanded.Set(!s.expanded.Get())
+		}),
+	}
+	if s.expanded.Get() {
+		km = append(km, tui.On(tui.Rune('j'), func(ke tui.KeyEvent) { s.moveDown() }))
+		km = append(km, tui.On(tui.Rune('k'), func(ke tui.KeyEvent) { s.moveUp() }))
+		km = append(km, tui.On(tui.KeyDown, func(ke tui.KeyEvent) { s.moveDown() }))
+		km = append(km, tui.On(tui.KeyUp, func(ke tui.KeyEvent) { s.moveUp() }))
+		km = append(km, tui.On(tui.KeyEnter, func(ke tui.KeyEvent) {
+			idx := s.selected.Get()
+			if idx >= 0 && idx < len(categories) {
+				s.category.Set(categories[idx])
+			}
+		}))
+	}
+	return km
+}
+
+func (s *sidebar) moveDown() {
+	s.selected.Update(func(v int) int {
+		if v >= len(categories)-1 {
+			return 0
+		}
+		return v + 1
+	})
+	s.category.Set(categories[s.selected.Get()])
+}
+
+func (s *sidebar) moveUp() {
+	s.selected.Update(func(v int) int {
+		if v <= 0 {
+			return len(categories) - 1
+		}
+		return v - 1
+	})
+	s.category.Set(categories[s.selected.Get()])
+}
+
+func (s *sidebar) sidebarWidth() int {
+	if s.expanded.Get() {
+		return 22
+	}
+	return 5
+}
+
+func (s *sidebar) Render(app *tui.App) *tui.Element {
+	__tui_0 := tui.New(
+		tui.WithDisplay(tui.DisplayFlex), tui.WithDirection(tui.Column),
+		tui.WithBorder(tui.BorderSingle),
+		tui.WithFlexShrink(0),
+		tui.WithWidth(s.sidebarWidth()),
+	)
+	if s.expanded.Get() {
+		__tui_1 := tui.New(
+			tui.WithDisplay(tui.DisplayFlex), tui.WithDirection(tui.Column),
+			tui.WithPaddingTRBL(0, 1, 0, 1),
+		)
+		__tui_2 := tui.New(
+			tui.WithText("Folders"),
+			tui.WithTextGradient(tui.NewGradient(tui.Cyan, tui.Magenta).WithDirection(tui.GradientHorizontal)),
+			tui.WithTextStyle(tui.NewStyle().Bold()),
+		)
+		__tui_1.AddChild(__tui_2)
+		__tui_0.AddChild(__tui_1)
+		__tui_3 := tui.New(
+			tui.WithHR(),
+		)
+		__tui_0.AddChild(__tui_3)
+		__tui_4 := tui.New(
+			tui.WithDisplay(tui.DisplayFlex), tui.WithDirection(tui.Column),
+			tui.WithFlexGrow(1),
+			tui.WithPaddingTRBL(0, 1, 0, 1),
+		)
+		for i, cat := range categories {
+			_ = i
+			if i == s.selected.Get() {
+				__tui_5 := tui.New(
+					tui.WithText("> "+cat),
+					tui.WithTextStyle(tui.NewStyle().Foreground(tui.Cyan).Bold()),
+				)
+				__tui_4.AddChild(__tui_5)
+			} else {
+				__tui_6 := tui.New(
+					tui.WithText("  "+cat),
+					tui.WithTextStyle(tui.NewStyle().Dim()),
+				)
+				__tui_4.AddChild(__tui_6)
+			}
+		}
+		__tui_0.AddChild(__tui_4)
+		__tui_7 := tui.New(
+			tui.WithHR(),
+		)
+		__tui_0.AddChild(__tui_7)
+		__tui_8 := tui.New(
+			tui.WithDisplay(tui.DisplayFlex), tui.WithDirection(tui.Column),
+			tui.WithPaddingTRBL(0, 1, 0, 1),
+		)
+		__tui_9 := tui.New(
+			tui.WithText("Ctrl+B hide"),
+			tui.WithTextStyle(tui.NewStyle().Dim().Foreground(tui.Cyan)),
+		)
+		__tui_8.AddChild(__tui_9)
+		__tui_0.AddChild(__tui_8)
+	} else {
+		__tui_10 := tui.New(
+			tui.WithText("F"),
+			tui.WithPaddingTRBL(0, 1, 0, 1),
+			tui.WithTextStyle(tui.NewStyle().Foreground(tui.Cyan).Bold()),
+		)
+		__tui_0.AddChild(__tui_10)
+	}
+
+	return __tui_0
+}
+
+// bindAppFields is generated. It wires the component's *tui.App,
+// State, Events, and TextArea fields to app. When you override BindApp,
+// call this helper instead of hand-maintaining the delegation list.
+func (s *sidebar) bindAppFields(app *tui.App) {
+	if s.category != nil {
+		s.category.BindApp(app)
+	}
+	if s.expanded != nil {
+		s.expanded.BindApp(app)
+	}
+	if s.selected != nil {
+		s.selected.BindApp(app)
+	}
+}
+
+func (s *sidebar) BindApp(app *tui.App) {
+	s.bindAppFields(app)
+}
+
+var _ tui.AppBinder = (*sidebar)(nil)
+
+// Compile-time interface satisfaction checks.
+var (
+	_ tui.KeyListener = (*sidebar)(nil)
+)
@@ -0,0 +1,262 @@
+// Code generated by tui generate. DO NOT EDIT.
+// Source: watchers.gsx
+
+package main
+
+import (
+	"fmt"
+	"time"
+
+	tui "github.com/grindlemire/go-tui"
+)
+
+type watcherApp struct {
+	stopwatchSec *tui.State[int]
+	stopwatchOn  *tui.State[bool]
+	messages     *tui.State[[]string]
+	msgCh        chan string
+	msgCount     *tui.State[int]
+	feed         *tui.Ref
+	scrollY      *tui.State[int]
+}
+
+func WatcherApp() *watcherApp {
+	msgCh := make(chan string, 100)
+	app := &watcherApp{
+		stopwatchSec: tui.NewState(0),
+		stopwatchOn:  tui.NewState(false),
+		messages:     tui.NewState([]string{}),
+		msgCh:        msgCh,
+		msgCount:     tui.NewState(0),
+		feed:         tui.NewRef(),
+		scrollY:      tui.NewState(0),
+	}
+
+	go func() {
+		msgs := []string{
+			"Hello from producer",
+			"Data packet received",
+			"Processing complete",
+			"Checkpoint saved",
+			"Heartbeat OK",
+		}
+		i := 0
+		for {
+			time.Sleep(3 * time.Second)
+			msgCh <- msgs[i%len(msgs)]
+			i++
+		}
+	}()
+
+	return app
+}
+
+func (w *watcherApp) KeyMap() tui.KeyMap {
+	return tui.KeyMap{
+		tui.On(tui.KeyEscape, func(ke tui.KeyEvent) { ke.App().Stop() }),
+		tui.On(tui.Rune('q'), func(ke tui.KeyEvent) { ke.App().Stop() }),
+		tui.On(tui.Rune('s'), func(ke tui.KeyEvent) {
+			w.stopwatchOn.Set(!w.stopwatchOn.Get())
+		}),
+		tui.On(tui.Rune('r'), func(ke tui.KeyEvent) {
+			w.stopwatchSec.Set(0)
+			w.stopwatchOn.Set(false)
+		}),
+	}
+}
+
+func (w *watcherApp) Watchers() []tui.Watcher {
+	return []tui.Watcher{
+		tui.OnTimer(time.Second, w.tick),
+		tui.Watch(w.msgCh, w.addMessage),
+		tui.OnChange(w.messages, w.autoScrollToBottom),
+	}
+}
+
+func (w *watcherApp) tick() {
+	if w.stopwatchOn.Get() {
+		w.stopwatchSec.Update(func(v int) int { return v + 1 })
+	}
+}
+
+func (w *watcherApp) addMessage(msg string) {
+	w.msgCount.Update(func(v int) int { return v + 1 })
+	ts := time.Now().Format("15:04:05")
+	entry := fmt.Sprintf("[%s] #%d: %s", ts, w.msgCount.Get(), msg)
+	w.messages.Set(append(w.messages.Get(), entry))
+}
+
+func (w *watcherApp) autoScrollToBottom(_ []string) {
+	el := w.feed.El()
+	if el != nil {
+		_, maxY := el.MaxScroll()
+		w.scrollY.Set(maxY + 1)
+	}
+}
+
+func (w *watcherApp) scrollBy(delta int) {
+	el := w.feed.El()
+	if el == nil {
+		return
+	}
+	_, maxY := el.MaxScroll()
+	newY := w.scrollY.Get() + delta
+	if newY < 0 {
+		newY = 0
+	}
+	if newY > maxY {
+		newY = maxY
+	}
+	w.scrollY.Set(newY)
+}
+
+func (w *watcherApp) HandleMouse(me tui.MouseEvent) bool {
+	switch me.Button {
+	case tui.MouseWheelUp:
+		w.scrollBy(-3)
+		return true
+	case tui.MouseWheelDown:
+		w.scrollBy(3)
+		return true
+	}
+	return false
+}
+
+func formatDuration(seconds int) string {
+	m := seconds / 60
+	s := seconds - m*60
+	return fmt.Sprintf("%02d:%02d", m, s)
+}
+
+func (w *watcherApp) Render(app *tui.App) *tui.Element {
+	__tui_0 := tui.New(
+		tui.WithDisplay(tui.DisplayFlex), tui.WithDirection(tui.Column),
+		tui.WithPadding(1),
+		tui.WithGap(1),
+		tui.WithBorder(tui.BorderRounded),
+		tui.WithBorderStyle(tui.NewStyle().Foreground(tui.Cyan)),
+	)
+	__tui_1 := tui.New(
+		tui.WithText("Timers & Watchers"),
+		tui.WithTextGradient(tui.NewGradient(tui.Cyan, tui.Magenta).WithDirection(tui.GradientHorizontal)),
+		tui.WithTextStyle(tui.NewStyle().Bold()),
+	)
+	__tui_0.AddChild(__tui_1)
+	__tui_2 := tui.New(
+		tui.WithDisplay(tui.DisplayFlex), tui.WithDirection(tui.Row),
+		tui.WithGap(2),
+		tui.WithFlexShrink(0),
+	)
+	__tui_3 := tui.New(
+		tui.WithText("Stopwatch"),
+		tui.WithTextStyle(tui.NewStyle().Bold()),
+	)
+	__tui_2.AddChild(__tui_3)
+	__tui_4 := tui.New(
+		tui.WithText(formatDuration(w.stopwatchSec.Get())),
+		tui.WithTextStyle(tui.NewStyle().Foreground(tui.Cyan).Bold()),
+	)
+	__tui_2.AddChild(__tui_4)
+	if w.stopwatchOn.Get() {
+		__tui_5 := tui.New(
+			tui.WithText("Running"),
+			tui.WithTextStyle(tui.NewStyle().Foreground(tui.Green).Bold()),
+		)
+		__tui_2.AddChild(__tui_5)
+	} else {
+		__tui_6 := tui.New(
+			tui.WithText("Paused"),
+			tui.WithTextStyle(tui.NewStyle().Foreground(tui.Yellow)),
+		)
+		__tui_2.AddChild(__tui_6)
+	}
+	__tui_7 := tui.New(
+		tui.WithText("[s] toggle [r] reset"),
+		tui.WithTextStyle(tui.NewStyle().Dim()),
+	)
+	__tui_2.AddChild(__tui_7)
+	__tui_0.AddChild(__tui_2)
+	__tui_8 := tui.New(
+		tui.WithDisplay(tui.DisplayFlex), tui.WithDirection(tui.Column),
+		tui.WithBorder(tui.BorderRounded),
+		tui.WithPadding(1),
+		tui.WithFlexGrow(1),
+		tui.WithScrollable(tui.ScrollVertical),
+		tui.WithScrollbarStyle(tui.NewStyle().Foreground(tui.Cyan)),
+		tui.WithScrollbarThumbStyle(tui.NewStyle().Foreground(tui.BrightCyan)),
+		tui.WithScrollOffset(0, w.scrollY.Get()),
+	)
+	w.feed.Set(__tui_8)
+	__tui_9 := tui.New(
+		tui.WithDisplay(tui.DisplayFlex), tui.WithDirection(tui.Row),
+		tui.WithGap(2),
+	)
+	__tui_10 := tui.New(
+		tui.WithText("Live Feed"),
+		tui.WithTextStyle(tui.NewStyle().Bold()),
+	)
+	__tui_9.AddChild(__tui_10)
+	__tui_11 := tui.New(
+		tui.WithText(fmt.Sprintf("(%d received)", w.msgCount.Get())),
+		tui.WithTextStyle(tui.NewStyle().Dim()),
+	)
+	__tui_9.AddChild(__tui_11)
+	__tui_8.AddChild(__tui_9)
+	for __idx_0, msg := range w.messages.Get() {
+		_ = __idx_0
+		__tui_12 := tui.New(
+			tui.WithText(msg),
+			tui.WithTextStyle(tui.NewStyle().Foreground(tui.Green)),
+		)
+		__tui_8.AddChild(__tui_12)
+	}
+	if len(w.messages.Get()) == 0 {
+		__tui_13 := tui.New(
+			tui.WithText("Waitingmessages..."),
+			tui.WithTextStyle(tui.NewStyle().Dim()),
+		)
+		__tui_8.AddChild(__tui_13)
+	}
+	__tui_0.AddChild(__tui_8)
+	__tui_14 := tui.New(
+		tui.WithText("s stopwatch | r reset | q quit"),
+		tui.WithTextStyle(tui.NewStyle().Dim()),
+	)
+	__tui_0.AddChild(__tui_14)
+
+	return __tui_0
+}
+
+// bindAppFields is generated. It wires the component's *tui.App,
+// State, Events, and TextArea fields to app. When you override BindApp,
+// call this helper instead of hand-maintaining the delegation list.
+func (w *watcherApp) bindAppFields(app *tui.App) {
+	if w.stopwatchSec != nil {
+		w.stopwatchSec.BindApp(app)
+	}
+	if w.stopwatchOn != nil {
+		w.stopwatchOn.BindApp(app)
+	}
+	if w.messages != nil {
+		w.messages.BindApp(app)
+	}
+	if w.msgCount != nil {
+		w.msgCount.BindApp(app)
+	}
+	if w.scrollY != nil {
+		w.scrollY.BindApp(app)
+	}
+}
+
+func (w *watcherApp) BindApp(app *tui.App) {
+	w.bindAppFields(app)
+}
+
+var _ tui.AppBinder = (*watcherApp)(nil)
+
+// Compile-time interface satisfaction checks.
+var (
+	_ tui.KeyListener     = (*watcherApp)(nil)
+	_ tui.MouseListener   = (*watcherApp)(nil)
+	_ tui.WatcherProvider = (*watcherApp)(nil)
+)